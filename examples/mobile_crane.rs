@@ -1,4 +1,4 @@
-use crane_core::equipment::{Crane, MobileCrane};
+use crane_core::equipment::{Kinematic, MobileCrane, RatedCapacity};
 use crane_core::types::*;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
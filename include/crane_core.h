@@ -0,0 +1,67 @@
+/* C interface to crane_core, built with `cargo build --release --features ffi`.
+ *
+ * Units: feet, pounds, degrees, psi. Every function returns a status; on
+ * failure crane_core_last_error() describes it until the next failing call
+ * on the same thread. Handles are opaque and must be freed by their
+ * matching *_free function.
+ */
+#ifndef CRANE_CORE_H
+#define CRANE_CORE_H
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef enum CraneCoreStatus {
+    CRANE_CORE_OK = 0,
+    CRANE_CORE_NULL_POINTER = 1,
+    CRANE_CORE_INVALID_ARGUMENT = 2,
+    CRANE_CORE_CALCULATION_ERROR = 3,
+    CRANE_CORE_PANIC = 4,
+} CraneCoreStatus;
+
+typedef struct CraneCoreChartPackage CraneCoreChartPackage;
+typedef struct CraneCoreCrane CraneCoreCrane;
+
+typedef struct CraneCoreLoadCheck {
+    double radius_ft;
+    double gross_capacity_lb;
+    double net_capacity_lb;
+    double utilization_pct;
+    int within_capacity;
+} CraneCoreLoadCheck;
+
+const char *crane_core_last_error(void);
+
+CraneCoreStatus crane_core_package_from_json(const char *json, CraneCoreChartPackage **out);
+void crane_core_package_free(CraneCoreChartPackage *package);
+CraneCoreStatus crane_core_chart_capacity(const CraneCoreChartPackage *package,
+                                          const char *chart_id,
+                                          double boom_length_ft,
+                                          double radius_ft,
+                                          double *out_capacity_lb);
+
+CraneCoreStatus crane_core_crane_new(const char *manufacturer,
+                                     const char *model,
+                                     double boom_length_ft,
+                                     double boom_base_height_ft,
+                                     CraneCoreCrane **out);
+void crane_core_crane_free(CraneCoreCrane *crane);
+CraneCoreStatus crane_core_crane_set_load_charts(CraneCoreCrane *crane,
+                                                 const CraneCoreChartPackage *package);
+CraneCoreStatus crane_core_crane_set_configuration(CraneCoreCrane *crane,
+                                                   double boom_length_ft,
+                                                   double boom_angle_deg,
+                                                   double swing_deg);
+CraneCoreStatus crane_core_crane_check_load(const CraneCoreCrane *crane,
+                                            double load_lb,
+                                            CraneCoreLoadCheck *out);
+CraneCoreStatus crane_core_crane_max_pad_pressure(const CraneCoreCrane *crane,
+                                                  double load_lb,
+                                                  double *out_psi);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* CRANE_CORE_H */
@@ -0,0 +1,173 @@
+//! Compact binary cache for chart packages
+//!
+//! Parsing hundreds of JSON packages on startup is slow. A cache file holds
+//! the package in postcard encoding behind a small header:
+//!
+//! - magic bytes `CRNC`
+//! - cache format version ([`CACHE_FORMAT_VERSION`])
+//! - SHA-256 of the source file the cache was built from
+//!
+//! [`load_cached`] rebuilds the cache whenever the version changes or the
+//! source file's hash no longer matches, so a stale cache is never used.
+
+use crate::capacity::chart_io::{ChartFormat, ChartIoError};
+use crate::capacity::load_chart::LoadChartPackage;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::Path;
+
+const MAGIC: [u8; 4] = *b"CRNC";
+
+/// Bump when the encoded layout of `LoadChartPackage` changes
+pub const CACHE_FORMAT_VERSION: u32 = 4;
+
+#[derive(Debug, thiserror::Error)]
+pub enum BinaryCacheError {
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
+
+    #[error("Encoding error: {0}")]
+    EncodingError(#[from] postcard::Error),
+
+    #[error("Chart IO error: {0}")]
+    ChartIoError(#[from] ChartIoError),
+
+    #[error("Not a chart cache file")]
+    BadMagic,
+
+    #[error("Cache format version {found}, expected {expected}")]
+    VersionMismatch { found: u32, expected: u32 },
+
+    #[error("Cache was built from a different source file")]
+    StaleSource,
+}
+
+/// Whether [`load_cached`] used the cache
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheStatus {
+    /// Cache was valid and used
+    Hit,
+    /// No usable cache; source parsed and cache written
+    Rebuilt,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheHeader {
+    magic: [u8; 4],
+    version: u32,
+    source_hash: [u8; 32],
+}
+
+fn source_hash(source: &[u8]) -> [u8; 32] {
+    Sha256::digest(source).into()
+}
+
+/// Encode a package, recording the hash of the source it came from
+pub fn encode(package: &LoadChartPackage, source: &[u8]) -> Result<Vec<u8>, BinaryCacheError> {
+    let header = CacheHeader {
+        magic: MAGIC,
+        version: CACHE_FORMAT_VERSION,
+        source_hash: source_hash(source),
+    };
+    let mut bytes = postcard::to_stdvec(&header)?;
+    bytes.extend(postcard::to_stdvec(package)?);
+    Ok(bytes)
+}
+
+/// Decode a cache, checking version and (if given) the source it was built from
+pub fn decode(bytes: &[u8], source: Option<&[u8]>) -> Result<LoadChartPackage, BinaryCacheError> {
+    let (header, rest): (CacheHeader, _) =
+        postcard::take_from_bytes(bytes).map_err(|_| BinaryCacheError::BadMagic)?;
+
+    if header.magic != MAGIC {
+        return Err(BinaryCacheError::BadMagic);
+    }
+    if header.version != CACHE_FORMAT_VERSION {
+        return Err(BinaryCacheError::VersionMismatch {
+            found: header.version,
+            expected: CACHE_FORMAT_VERSION,
+        });
+    }
+    if let Some(source) = source
+        && header.source_hash != source_hash(source)
+    {
+        return Err(BinaryCacheError::StaleSource);
+    }
+
+    Ok(postcard::from_bytes(rest)?)
+}
+
+/// Load a package through a cache file, rebuilding it if missing or stale
+///
+/// The source is still read to check its hash, but only parsed on a miss.
+pub fn load_cached(
+    source_path: impl AsRef<Path>,
+    format: ChartFormat,
+    cache_path: impl AsRef<Path>,
+) -> Result<(LoadChartPackage, CacheStatus), BinaryCacheError> {
+    let source = std::fs::read(source_path)?;
+
+    if let Ok(cache) = std::fs::read(cache_path.as_ref())
+        && let Ok(package) = decode(&cache, Some(&source))
+    {
+        return Ok((package, CacheStatus::Hit));
+    }
+
+    let package = LoadChartPackage::from_bytes_as(&source, format)?;
+    std::fs::write(cache_path, encode(&package, &source)?)?;
+    Ok((package, CacheStatus::Rebuilt))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::capacity::load_chart::TelescopeMode;
+    use crate::test_fixtures::chart_package;
+
+    #[test]
+    fn test_encode_decode() {
+        let mut package = chart_package();
+        package.charts[0].configuration.additional.insert("hook_block".into(), "5-sheave".into());
+        let bytes = encode(&package, b"source").unwrap();
+
+        let decoded = decode(&bytes, Some(b"source")).unwrap();
+        assert_eq!(decoded.charts[0].id, "main_100");
+        assert_eq!(decoded.charts[0].configuration.additional["hook_block"], "5-sheave");
+
+        assert!(matches!(decode(&bytes, Some(b"edited")), Err(BinaryCacheError::StaleSource)));
+        assert!(matches!(decode(b"garbage!", None), Err(BinaryCacheError::BadMagic)));
+
+        // The telescope mode and insert code survive with or without a value
+        assert!(decoded.charts[0].configuration.boom.telescope_mode.is_none());
+        assert!(decoded.charts[0].configuration.boom.boom_inserts.is_none());
+        let mut package = chart_package();
+        package.charts[0].configuration.boom.telescope_mode = Some(TelescopeMode::from_code("0/50/90/100"));
+        package.charts[0].configuration.boom.boom_inserts = Some("I20+I10".into());
+        let decoded = decode(&encode(&package, b"source").unwrap(), None).unwrap();
+        assert_eq!(decoded.charts[0].configuration.boom.telescope_mode.as_ref().unwrap().code, "0/50/90/100");
+        assert_eq!(decoded.charts[0].configuration.boom.boom_inserts.as_deref(), Some("I20+I10"));
+    }
+
+    #[test]
+    fn test_cache_invalidation() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("package.json");
+        let cache = dir.path().join("package.bin");
+
+        chart_package().to_json_file(source.to_str().unwrap()).unwrap();
+
+        let (_, status) = load_cached(&source, ChartFormat::Json, &cache).unwrap();
+        assert_eq!(status, CacheStatus::Rebuilt);
+        let (_, status) = load_cached(&source, ChartFormat::Json, &cache).unwrap();
+        assert_eq!(status, CacheStatus::Hit);
+
+        // Editing the source invalidates the cache
+        let mut package = chart_package();
+        package.charts[0].description = "edited".into();
+        package.to_json_file(source.to_str().unwrap()).unwrap();
+
+        let (loaded, status) = load_cached(&source, ChartFormat::Json, &cache).unwrap();
+        assert_eq!(status, CacheStatus::Rebuilt);
+        assert_eq!(loaded.charts[0].description, "edited");
+    }
+}
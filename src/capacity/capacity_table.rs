@@ -0,0 +1,427 @@
+//! N-dimensional capacity tables
+//!
+//! [`CapacityData`] is strictly boom length × radius. Charts that also vary
+//! by jib length, jib offset or counterweight would otherwise be exploded
+//! into many near-duplicate [`LoadChart`](crate::capacity::load_chart::LoadChart)s.
+//! A [`CapacityTable`] holds one grid with any number of named axes and
+//! interpolates multi-linearly between grid points.
+//!
+//! Cells are stored row-major: the last axis varies fastest. Blank cells
+//! (`None`) mark configurations the manufacturer doesn't rate.
+
+use crate::capacity::load_chart::CapacityData;
+use crate::types::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Standard axis names
+pub const AXIS_RADIUS: &str = "radius";
+pub const AXIS_BOOM_LENGTH: &str = "boom_length";
+pub const AXIS_JIB_LENGTH: &str = "jib_length";
+pub const AXIS_JIB_OFFSET: &str = "jib_offset";
+pub const AXIS_COUNTERWEIGHT: &str = "counterweight";
+
+/// Physical quantity measured along an axis
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AxisQuantity {
+    Length,
+    Angle,
+    Mass,
+}
+
+/// A query coordinate on one axis
+#[derive(Debug, Clone, Copy)]
+pub enum AxisValue {
+    Length(Length),
+    Angle(Angle),
+    Mass(Mass),
+}
+
+impl AxisValue {
+    fn quantity(&self) -> AxisQuantity {
+        match self {
+            AxisValue::Length(_) => AxisQuantity::Length,
+            AxisValue::Angle(_) => AxisQuantity::Angle,
+            AxisValue::Mass(_) => AxisQuantity::Mass,
+        }
+    }
+
+    /// Value in internal units (ft, deg, lbs)
+    fn internal(&self) -> f64 {
+        match self {
+            AxisValue::Length(l) => l.get::<foot>(),
+            AxisValue::Angle(a) => a.get::<degree>(),
+            AxisValue::Mass(m) => m.get::<pound>(),
+        }
+    }
+}
+
+/// One named, sorted axis of a capacity table
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TableAxis {
+    pub name: String,
+    pub quantity: AxisQuantity,
+    pub unit: String,
+
+    /// Grid values in `unit`, strictly increasing
+    pub values: Vec<f64>,
+}
+
+impl TableAxis {
+    pub fn new(
+        name: impl Into<String>,
+        quantity: AxisQuantity,
+        unit: impl Into<String>,
+        values: Vec<f64>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            quantity,
+            unit: unit.into(),
+            values,
+        }
+    }
+
+    /// Grid values converted to internal units (ft, deg, lbs)
+    fn internal_values(&self) -> Result<Vec<f64>, UnitError> {
+        self.values
+            .iter()
+            .map(|&v| match self.quantity {
+                AxisQuantity::Length => Ok(LengthValue::new(v, self.unit.as_str()).to_distance()?.get::<foot>()),
+                AxisQuantity::Angle => Ok(AngleValue::new(v, self.unit.as_str()).to_angle()?.get::<degree>()),
+                AxisQuantity::Mass => Ok(MassValue::new(v, self.unit.as_str()).to_mass()?.get::<pound>()),
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CapacityTableError {
+    #[error("Unit conversion error: {0}")]
+    UnitError(#[from] UnitError),
+
+    #[error("Table has {actual} cells but axes require {expected}")]
+    ShapeMismatch { expected: usize, actual: usize },
+
+    #[error("Axis '{0}' values must be strictly increasing")]
+    UnsortedAxis(String),
+
+    #[error("Unknown axis '{0}'")]
+    UnknownAxis(String),
+
+    #[error("No value given for axis '{0}'")]
+    MissingAxis(String),
+
+    #[error("Axis '{0}' given a value of the wrong quantity")]
+    QuantityMismatch(String),
+
+    #[error("Value {value} on axis '{axis}' is outside the table")]
+    OutOfRange { axis: String, value: f64 },
+
+    #[error("Table has no rating at this configuration")]
+    Unrated,
+}
+
+/// Capacity grid over any number of named axes
+///
+/// Deserializing validates, as [`CapacityTable::new`] does.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "UncheckedCapacityTable"))]
+pub struct CapacityTable {
+    pub axes: Vec<TableAxis>,
+
+    /// Unit of every cell
+    pub capacity_unit: String,
+
+    /// Row-major cells, last axis fastest; `None` is unrated
+    pub cells: Vec<Option<f64>>,
+}
+
+/// A table as read, before [`CapacityTable::validate`]
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct UncheckedCapacityTable {
+    axes: Vec<TableAxis>,
+    capacity_unit: String,
+    cells: Vec<Option<f64>>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<UncheckedCapacityTable> for CapacityTable {
+    type Error = CapacityTableError;
+
+    fn try_from(table: UncheckedCapacityTable) -> Result<Self, Self::Error> {
+        Self::new(table.axes, table.capacity_unit, table.cells)
+    }
+}
+
+impl CapacityTable {
+    /// Create a table, checking axis order and cell count
+    pub fn new(
+        axes: Vec<TableAxis>,
+        capacity_unit: impl Into<String>,
+        cells: Vec<Option<f64>>,
+    ) -> Result<Self, CapacityTableError> {
+        let table = Self {
+            axes,
+            capacity_unit: capacity_unit.into(),
+            cells,
+        };
+        table.validate()?;
+        Ok(table)
+    }
+
+    /// Check axes are sorted and the cell count matches their product
+    pub fn validate(&self) -> Result<(), CapacityTableError> {
+        for axis in &self.axes {
+            if axis.values.is_empty() || axis.values.windows(2).any(|w| w[1] <= w[0]) {
+                return Err(CapacityTableError::UnsortedAxis(axis.name.clone()));
+            }
+        }
+
+        let expected: usize = self.axes.iter().map(|a| a.values.len()).product();
+        if expected != self.cells.len() {
+            return Err(CapacityTableError::ShapeMismatch {
+                expected,
+                actual: self.cells.len(),
+            });
+        }
+
+        MassValue::new(0.0, self.capacity_unit.as_str()).to_mass()?;
+        Ok(())
+    }
+
+    /// Index of an axis by name
+    pub fn axis_index(&self, name: &str) -> Option<usize> {
+        self.axes.iter().position(|a| a.name == name)
+    }
+
+    /// Multi-linear interpolated capacity
+    ///
+    /// `coords` must give a value for every axis, in any order.
+    pub fn capacity(&self, coords: &[(&str, AxisValue)]) -> Result<Mass, CapacityTableError> {
+        for (name, _) in coords {
+            if self.axis_index(name).is_none() {
+                return Err(CapacityTableError::UnknownAxis(name.to_string()));
+            }
+        }
+
+        // Bracketing indices and weight of the upper index for each axis
+        let mut brackets = Vec::with_capacity(self.axes.len());
+        for axis in &self.axes {
+            let value = coords
+                .iter()
+                .find(|(name, _)| *name == axis.name)
+                .map(|(_, v)| *v)
+                .ok_or_else(|| CapacityTableError::MissingAxis(axis.name.clone()))?;
+            if value.quantity() != axis.quantity {
+                return Err(CapacityTableError::QuantityMismatch(axis.name.clone()));
+            }
+            brackets.push(bracket(&axis.internal_values()?, value.internal()).ok_or_else(|| {
+                CapacityTableError::OutOfRange {
+                    axis: axis.name.clone(),
+                    value: value.internal(),
+                }
+            })?);
+        }
+
+        // Row-major strides
+        let mut strides = vec![1usize; self.axes.len()];
+        for i in (0..self.axes.len().saturating_sub(1)).rev() {
+            strides[i] = strides[i + 1] * self.axes[i + 1].values.len();
+        }
+
+        // Sum over the 2^N corners of the enclosing cell
+        let mut total = 0.0;
+        for corner in 0..(1usize << self.axes.len()) {
+            let mut weight = 1.0;
+            let mut offset = 0;
+            for (axis, &(lower, upper, t)) in brackets.iter().enumerate() {
+                let use_upper = corner & (1 << axis) != 0;
+                weight *= if use_upper { t } else { 1.0 - t };
+                offset += strides[axis] * if use_upper { upper } else { lower };
+            }
+            if weight == 0.0 {
+                continue;
+            }
+            // The fields are public, so the shape may have changed since validation
+            let cell = self.cells.get(offset).ok_or(CapacityTableError::ShapeMismatch {
+                expected: self.axes.iter().map(|a| a.values.len()).product(),
+                actual: self.cells.len(),
+            })?;
+            let cell = cell.ok_or(CapacityTableError::Unrated)?;
+            total += weight * cell;
+        }
+
+        Ok(MassValue::new(total, self.capacity_unit.as_str()).to_mass()?)
+    }
+
+    /// Boom length × radius slice at fixed values of every other axis
+    ///
+    /// Produces standard [`CapacityData`] so a slice can back an ordinary
+    /// `LoadChart`. Unrated cells are left out of each row.
+    pub fn slice(&self, fixed: &[(&str, AxisValue)]) -> Result<CapacityData, CapacityTableError> {
+        let boom_idx = self
+            .axis_index(AXIS_BOOM_LENGTH)
+            .ok_or_else(|| CapacityTableError::MissingAxis(AXIS_BOOM_LENGTH.into()))?;
+        let radius_idx = self
+            .axis_index(AXIS_RADIUS)
+            .ok_or_else(|| CapacityTableError::MissingAxis(AXIS_RADIUS.into()))?;
+        let boom_axis = &self.axes[boom_idx];
+        let radius_axis = &self.axes[radius_idx];
+
+        let mut data = CapacityData::new();
+        for &boom in &boom_axis.values {
+            let boom_value = LengthValue::new(boom, boom_axis.unit.as_str());
+            let boom_length = boom_value.to_distance()?;
+
+            let mut row = Vec::new();
+            for &radius in &radius_axis.values {
+                let radius_value = LengthValue::new(radius, radius_axis.unit.as_str());
+                let mut coords = fixed.to_vec();
+                coords.push((AXIS_BOOM_LENGTH, AxisValue::Length(boom_length)));
+                coords.push((AXIS_RADIUS, AxisValue::Length(radius_value.to_distance()?)));
+
+                match self.capacity(&coords) {
+                    Ok(capacity) => row.push((
+                        radius_value,
+                        MassValue::from_mass(capacity, &self.capacity_unit)?,
+                    )),
+                    Err(CapacityTableError::Unrated) => {}
+                    Err(e) => return Err(e),
+                }
+            }
+
+            data.boom_lengths.push(boom_value);
+            data.data.push(row);
+        }
+
+        Ok(data)
+    }
+}
+
+/// Lower index, upper index and interpolation weight of `value` on a sorted axis
+fn bracket(values: &[f64], value: f64) -> Option<(usize, usize, f64)> {
+    const EPS: f64 = 1e-6;
+    let first = *values.first()?;
+    let last = *values.last()?;
+    if value < first - EPS || value > last + EPS {
+        return None;
+    }
+
+    let upper = values.partition_point(|&v| v < value - EPS);
+    if upper >= values.len() {
+        return Some((values.len() - 1, values.len() - 1, 0.0));
+    }
+    if (values[upper] - value).abs() <= EPS || upper == 0 {
+        return Some((upper, upper, 0.0));
+    }
+
+    let lower = upper - 1;
+    let t = (value - values[lower]) / (values[upper] - values[lower]);
+    Some((lower, upper, t))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    /// counterweight × boom × radius
+    fn test_table() -> CapacityTable {
+        CapacityTable::new(
+            vec![
+                TableAxis::new(AXIS_COUNTERWEIGHT, AxisQuantity::Mass, "lbs", vec![50000.0, 100000.0]),
+                TableAxis::new(AXIS_BOOM_LENGTH, AxisQuantity::Length, "ft", vec![100.0, 150.0]),
+                TableAxis::new(AXIS_RADIUS, AxisQuantity::Length, "ft", vec![20.0, 60.0, 120.0]),
+            ],
+            "lbs",
+            vec![
+                // 50k cw
+                Some(80000.0), Some(30000.0), None,
+                Some(60000.0), Some(25000.0), Some(8000.0),
+                // 100k cw
+                Some(120000.0), Some(50000.0), None,
+                Some(100000.0), Some(40000.0), Some(15000.0),
+            ],
+        )
+        .unwrap()
+    }
+
+    fn coords(cw: f64, boom: f64, radius: f64) -> Vec<(&'static str, AxisValue)> {
+        vec![
+            (AXIS_RADIUS, AxisValue::Length(Length::new::<foot>(radius))),
+            (AXIS_BOOM_LENGTH, AxisValue::Length(Length::new::<foot>(boom))),
+            (AXIS_COUNTERWEIGHT, AxisValue::Mass(Mass::new::<pound>(cw))),
+        ]
+    }
+
+    #[test]
+    fn test_grid_points_and_trilinear() {
+        let table = test_table();
+
+        let cap = table.capacity(&coords(100000.0, 150.0, 60.0)).unwrap();
+        assert_relative_eq!(cap.get::<pound>(), 40000.0);
+
+        // Centre of the cw/boom/radius(20..60) cell: mean of the eight corners
+        let cap = table.capacity(&coords(75000.0, 125.0, 40.0)).unwrap();
+        let mean = (80000.0 + 30000.0 + 60000.0 + 25000.0 + 120000.0 + 50000.0 + 100000.0 + 40000.0) / 8.0;
+        assert_relative_eq!(cap.get::<pound>(), mean, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_unrated_and_errors() {
+        let table = test_table();
+
+        assert!(matches!(
+            table.capacity(&coords(50000.0, 125.0, 90.0)),
+            Err(CapacityTableError::Unrated)
+        ));
+        assert!(matches!(
+            table.capacity(&coords(50000.0, 200.0, 40.0)),
+            Err(CapacityTableError::OutOfRange { .. })
+        ));
+        assert!(matches!(
+            table.capacity(&coords(50000.0, 100.0, 40.0)[..2]),
+            Err(CapacityTableError::MissingAxis(_))
+        ));
+        assert!(matches!(
+            CapacityTable::new(test_table().axes, "lbs", vec![Some(1.0)]),
+            Err(CapacityTableError::ShapeMismatch { .. })
+        ));
+
+        let mut truncated = test_table();
+        truncated.cells.truncate(6);
+        assert!(matches!(
+            truncated.capacity(&coords(100000.0, 150.0, 60.0)),
+            Err(CapacityTableError::ShapeMismatch { expected: 12, actual: 6 })
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_validates() {
+        let mut json = serde_json::to_value(test_table()).unwrap();
+        assert!(serde_json::from_value::<CapacityTable>(json.clone()).is_ok());
+
+        json["cells"].as_array_mut().unwrap().truncate(6);
+        let error = serde_json::from_value::<CapacityTable>(json).unwrap_err();
+        assert!(error.to_string().contains("12"));
+    }
+
+    #[test]
+    fn test_slice_to_capacity_data() {
+        let table = test_table();
+        let data = table
+            .slice(&[(AXIS_COUNTERWEIGHT, AxisValue::Mass(Mass::new::<pound>(75000.0)))])
+            .unwrap();
+
+        assert_eq!(data.boom_lengths.len(), 2);
+        assert_eq!(data.data[0].len(), 2);
+        assert_eq!(data.data[1].len(), 3);
+        assert_relative_eq!(data.data[1][2].1.value, 11500.0);
+    }
+}
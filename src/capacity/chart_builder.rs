@@ -0,0 +1,344 @@
+//! Fluent builder for load charts
+//!
+//! ```
+//! use crane_core::capacity::chart_builder::LoadChartBuilder;
+//!
+//! let chart = LoadChartBuilder::new("gmk5250l_full")
+//!     .description("GMK5250L, full outriggers, 360°")
+//!     .outriggers_full()
+//!     .boom(154.2, "ft")
+//!     .point(20.0, 242_500.0)
+//!     .point(40.0, 152_000.0)
+//!     .build()
+//!     .unwrap();
+//! assert_eq!(chart.capacity_data.data[0].len(), 2);
+//! ```
+//!
+//! Radii use the unit of the boom they belong to; capacities use the mass
+//! unit (pounds unless changed with [`LoadChartBuilder::mass_unit`]).
+
+use crate::capacity::chart_validation::validate_chart;
+use crate::capacity::load_chart::*;
+use crate::types::*;
+use std::collections::HashMap;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ChartBuildError {
+    #[error("Chart has no boom lengths")]
+    NoBooms,
+
+    #[error("Point added before any boom length")]
+    PointWithoutBoom,
+
+    #[error("Boom length {0} has no capacity points")]
+    EmptyBoom(usize),
+
+    #[error("Chart failed validation: {}", .0.join("; "))]
+    Invalid(Vec<String>),
+}
+
+/// Builds a [`LoadChart`] one boom length at a time
+#[derive(Debug, Clone)]
+pub struct LoadChartBuilder {
+    id: String,
+    description: String,
+    support: SupportConfiguration,
+    boom_length: Option<LengthValue>,
+    angle_range: Option<AngleRange>,
+    jib: Option<JibConfiguration>,
+    telescope_mode: Option<TelescopeMode>,
+    boom_inserts: Option<String>,
+    counterweight: Option<CounterweightConfiguration>,
+    additional: HashMap<String, String>,
+    mass_unit: String,
+    capacity_data: CapacityData,
+    notes: Vec<String>,
+    provenance: ChartProvenance,
+    point_without_boom: bool,
+}
+
+impl LoadChartBuilder {
+    /// Start a chart; support defaults to full outriggers, 360°
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            description: String::new(),
+            support: SupportConfiguration::OnOutriggers {
+                extension: OutriggerExtension::Full,
+                swing_restriction: Some(SwingRestriction::Full360),
+            },
+            boom_length: None,
+            angle_range: None,
+            jib: None,
+            telescope_mode: None,
+            boom_inserts: None,
+            counterweight: None,
+            additional: HashMap::new(),
+            mass_unit: "lbs".into(),
+            capacity_data: CapacityData::new(),
+            notes: Vec::new(),
+            provenance: ChartProvenance::default(),
+            point_without_boom: false,
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    pub fn note(mut self, note: impl Into<String>) -> Self {
+        self.notes.push(note.into());
+        self
+    }
+
+    // Support configuration
+
+    pub fn support(mut self, support: SupportConfiguration) -> Self {
+        self.support = support;
+        self
+    }
+
+    pub fn outriggers_full(self) -> Self {
+        self.outriggers(OutriggerExtension::Full)
+    }
+
+    pub fn outriggers_intermediate(self, percent: f64) -> Self {
+        self.outriggers(OutriggerExtension::Intermediate { percent })
+    }
+
+    pub fn outriggers_minimum(self) -> Self {
+        self.outriggers(OutriggerExtension::Minimum)
+    }
+
+    fn outriggers(mut self, extension: OutriggerExtension) -> Self {
+        let swing_restriction = match &self.support {
+            SupportConfiguration::OnOutriggers { swing_restriction, .. } => swing_restriction.clone(),
+            _ => Some(SwingRestriction::Full360),
+        };
+        self.support = SupportConfiguration::OnOutriggers {
+            extension,
+            swing_restriction,
+        };
+        self
+    }
+
+    /// Swing restriction when on outriggers
+    pub fn swing(mut self, restriction: SwingRestriction) -> Self {
+        if let SupportConfiguration::OnOutriggers { swing_restriction, .. } = &mut self.support {
+            *swing_restriction = Some(restriction);
+        }
+        self
+    }
+
+    pub fn on_rubber(mut self) -> Self {
+        self.support = SupportConfiguration::OnRubber {
+            speed_restriction: Some("Stationary".into()),
+        };
+        self
+    }
+
+    pub fn on_crawlers(mut self, track_config: impl Into<String>) -> Self {
+        self.support = SupportConfiguration::OnCrawlers {
+            track_config: track_config.into(),
+        };
+        self
+    }
+
+    pub fn on_barge(mut self, max_list: f64, unit: impl Into<String>) -> Self {
+        self.support = SupportConfiguration::OnBarge {
+            max_list: AngleValue::new(max_list, unit),
+        };
+        self
+    }
+
+    // Boom, jib and counterweight
+
+    /// Configured boom length for chart matching (defaults to the longest boom)
+    pub fn configured_boom(mut self, length: f64, unit: impl Into<String>) -> Self {
+        self.boom_length = Some(LengthValue::new(length, unit));
+        self
+    }
+
+    pub fn angle_range(mut self, min: f64, max: f64, unit: &str) -> Self {
+        self.angle_range = Some(AngleRange {
+            min: AngleValue::new(min, unit),
+            max: AngleValue::new(max, unit),
+        });
+        self
+    }
+
+    pub fn jib(mut self, length: f64, length_unit: &str, angle: f64, angle_unit: &str) -> Self {
+        self.jib = Some(JibConfiguration {
+            length: LengthValue::new(length, length_unit),
+            angle: AngleValue::new(angle, angle_unit),
+            offset: None,
+        });
+        self
+    }
+
+    /// Telescope extension mode, as a code such as "EM2" or "0/50/90/100"
+    pub fn telescope_mode(mut self, code: impl Into<String>) -> Self {
+        self.telescope_mode = Some(TelescopeMode::from_code(code));
+        self
+    }
+
+    /// Lattice boom insert code, e.g. "I20+I20+I10"
+    pub fn boom_inserts(mut self, code: impl Into<String>) -> Self {
+        self.boom_inserts = Some(code.into());
+        self
+    }
+
+    pub fn counterweight(mut self, weight: f64, unit: &str, configuration: impl Into<String>) -> Self {
+        self.counterweight = Some(CounterweightConfiguration {
+            weight: MassValue::new(weight, unit),
+            configuration: configuration.into(),
+        });
+        self
+    }
+
+    pub fn additional(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.additional.insert(key.into(), value.into());
+        self
+    }
+
+    pub fn provenance(mut self, provenance: ChartProvenance) -> Self {
+        self.provenance = provenance;
+        self
+    }
+
+    // Capacity data
+
+    /// Unit for capacities added after this call
+    pub fn mass_unit(mut self, unit: impl Into<String>) -> Self {
+        self.mass_unit = unit.into();
+        self
+    }
+
+    /// Start a new boom length row
+    pub fn boom(mut self, length: f64, unit: impl Into<String>) -> Self {
+        self.capacity_data.boom_lengths.push(LengthValue::new(length, unit));
+        self.capacity_data.data.push(Vec::new());
+        self
+    }
+
+    /// Add a (radius, capacity) point to the current boom length
+    pub fn point(mut self, radius: f64, capacity: f64) -> Self {
+        let Some(boom) = self.capacity_data.boom_lengths.last() else {
+            self.point_without_boom = true;
+            return self;
+        };
+        let radius = LengthValue::new(radius, boom.unit.as_str());
+        let capacity = MassValue::new(capacity, self.mass_unit.as_str());
+        if let Some(row) = self.capacity_data.data.last_mut() {
+            row.push((radius, capacity));
+        }
+        self
+    }
+
+    /// Add several (radius, capacity) points to the current boom length
+    pub fn points(self, points: &[(f64, f64)]) -> Self {
+        points.iter().fold(self, |b, &(r, c)| b.point(r, c))
+    }
+
+    /// Assemble and validate the chart
+    pub fn build(self) -> Result<LoadChart, ChartBuildError> {
+        if self.point_without_boom {
+            return Err(ChartBuildError::PointWithoutBoom);
+        }
+        if self.capacity_data.boom_lengths.is_empty() {
+            return Err(ChartBuildError::NoBooms);
+        }
+        if let Some(idx) = self.capacity_data.data.iter().position(|row| row.is_empty()) {
+            return Err(ChartBuildError::EmptyBoom(idx));
+        }
+
+        let boom_length = match self.boom_length {
+            Some(length) => length,
+            None => self
+                .capacity_data
+                .boom_lengths
+                .iter()
+                .max_by(|a, b| {
+                    let a = a.to_distance().map(|l| l.get::<foot>()).unwrap_or(f64::MIN);
+                    let b = b.to_distance().map(|l| l.get::<foot>()).unwrap_or(f64::MIN);
+                    a.partial_cmp(&b).unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .cloned()
+                .ok_or(ChartBuildError::NoBooms)?,
+        };
+
+        let chart = LoadChart {
+            id: self.id,
+            description: self.description,
+            configuration: ChartConfiguration {
+                support: self.support,
+                boom: BoomConfiguration {
+                    length: boom_length,
+                    angle_range: self.angle_range,
+                    jib: self.jib,
+                    telescope_mode: self.telescope_mode,
+                    boom_inserts: self.boom_inserts,
+                },
+                counterweight: self.counterweight,
+                additional: self.additional,
+            },
+            capacity_data: self.capacity_data,
+            notes: self.notes,
+            provenance: self.provenance,
+        };
+
+        validate_chart(&chart).map_err(ChartBuildError::Invalid)?;
+        Ok(chart)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_build_multi_boom_chart() {
+        let chart = LoadChartBuilder::new("test")
+            .outriggers_intermediate(50.0)
+            .swing(SwingRestriction::OverRear)
+            .counterweight(110200.0, "lbs", "Standard")
+            .boom(100.0, "ft")
+            .points(&[(20.0, 100000.0), (60.0, 40000.0)])
+            .boom(47.0, "m")
+            .mass_unit("kg")
+            .point(6.0, 110000.0)
+            .point(12.0, 69000.0)
+            .build()
+            .unwrap();
+
+        assert_eq!(chart.capacity_data.boom_lengths.len(), 2);
+        assert_eq!(chart.capacity_data.data[1][0].0.unit, "m");
+        assert_eq!(chart.capacity_data.data[1][0].1.unit, "kg");
+        // Configured boom defaults to the longest
+        assert_eq!(chart.configuration.boom.length.unit, "m");
+
+        let cap = chart
+            .capacity_interpolated(Length::new::<foot>(100.0), Length::new::<foot>(40.0))
+            .unwrap();
+        assert_relative_eq!(cap.get::<pound>(), 70000.0);
+    }
+
+    #[test]
+    fn test_build_validation() {
+        assert!(matches!(LoadChartBuilder::new("empty").build(), Err(ChartBuildError::NoBooms)));
+        assert!(matches!(
+            LoadChartBuilder::new("orphan").point(20.0, 1000.0).boom(100.0, "ft").point(20.0, 1000.0).build(),
+            Err(ChartBuildError::PointWithoutBoom)
+        ));
+        assert!(matches!(
+            LoadChartBuilder::new("rising")
+                .boom(100.0, "ft")
+                .point(20.0, 40000.0)
+                .point(40.0, 50000.0)
+                .build(),
+            Err(ChartBuildError::Invalid(_))
+        ));
+    }
+}
@@ -0,0 +1,166 @@
+//! Reader/writer based chart package IO
+//!
+//! The `*_file` methods on [`LoadChartPackage`] go through `std::fs`. These
+//! work on any `Read`/`Write` or byte slice instead, so packages can come
+//! from embedded assets, HTTP responses or a database, and so the crate
+//! builds for targets without a filesystem (e.g. WASM).
+
+use crate::capacity::load_chart::{CraneInfo, LoadChartPackage};
+use serde::Deserialize;
+use std::io::{Read, Write};
+
+/// Serialization format of a chart package
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChartFormat {
+    Json,
+    #[cfg(feature = "yaml")]
+    Yaml,
+    #[cfg(feature = "toml")]
+    Toml,
+}
+
+impl ChartFormat {
+    /// Format for a file extension, if supported in this build
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension.to_ascii_lowercase().as_str() {
+            "json" => Some(ChartFormat::Json),
+            #[cfg(feature = "yaml")]
+            "yaml" | "yml" => Some(ChartFormat::Yaml),
+            #[cfg(feature = "toml")]
+            "toml" => Some(ChartFormat::Toml),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ChartIoError {
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
+
+    #[error("JSON error: {0}")]
+    JsonError(#[from] serde_json::Error),
+
+    #[cfg(feature = "yaml")]
+    #[error("YAML error: {0}")]
+    YamlError(#[from] serde_yaml::Error),
+
+    #[cfg(feature = "toml")]
+    #[error("TOML error: {0}")]
+    TomlDeError(#[from] toml::de::Error),
+
+    #[cfg(feature = "toml")]
+    #[error("TOML error: {0}")]
+    TomlSerError(#[from] toml::ser::Error),
+
+    #[error("Invalid UTF-8: {0}")]
+    Utf8Error(#[from] std::str::Utf8Error),
+
+    #[error("Safety policy {field} = {value} is out of range ({range})")]
+    InvalidPolicy { field: &'static str, value: f64, range: &'static str },
+}
+
+impl LoadChartPackage {
+    /// Read a JSON package from any reader
+    pub fn from_reader(reader: impl Read) -> Result<Self, ChartIoError> {
+        Ok(serde_json::from_reader(reader)?)
+    }
+
+    /// Write a package as pretty JSON to any writer
+    pub fn to_writer(&self, writer: impl Write) -> Result<(), ChartIoError> {
+        Ok(serde_json::to_writer_pretty(writer, self)?)
+    }
+
+    /// Parse a JSON package from bytes (embedded assets, HTTP bodies)
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ChartIoError> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+
+    /// Read a package in the given format
+    pub fn from_reader_as(mut reader: impl Read, format: ChartFormat) -> Result<Self, ChartIoError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        Self::from_bytes_as(&bytes, format)
+    }
+
+    /// Parse a package from bytes in the given format
+    pub fn from_bytes_as(bytes: &[u8], format: ChartFormat) -> Result<Self, ChartIoError> {
+        match format {
+            ChartFormat::Json => Self::from_bytes(bytes),
+            #[cfg(feature = "yaml")]
+            ChartFormat::Yaml => Ok(serde_yaml::from_slice(bytes)?),
+            #[cfg(feature = "toml")]
+            ChartFormat::Toml => Ok(toml::from_str(std::str::from_utf8(bytes)?)?),
+        }
+    }
+
+    /// Write a package in the given format
+    pub fn to_writer_as(&self, writer: impl Write, format: ChartFormat) -> Result<(), ChartIoError> {
+        match format {
+            ChartFormat::Json => self.to_writer(writer),
+            #[cfg(feature = "yaml")]
+            ChartFormat::Yaml => Ok(serde_yaml::to_writer(writer, self)?),
+            #[cfg(feature = "toml")]
+            ChartFormat::Toml => {
+                let mut writer = writer;
+                Ok(writer.write_all(toml::to_string_pretty(self)?.as_bytes())?)
+            }
+        }
+    }
+}
+
+/// Just the crane identification of a package; charts are skipped
+#[derive(Deserialize)]
+struct PackageHeader {
+    crane_info: CraneInfo,
+}
+
+/// Read only the crane info from a serialized package
+///
+/// Used to index chart directories without building every chart.
+pub fn read_crane_info(bytes: &[u8], format: ChartFormat) -> Result<CraneInfo, ChartIoError> {
+    let header: PackageHeader = match format {
+        ChartFormat::Json => serde_json::from_slice(bytes)?,
+        #[cfg(feature = "yaml")]
+        ChartFormat::Yaml => serde_yaml::from_slice(bytes)?,
+        #[cfg(feature = "toml")]
+        ChartFormat::Toml => toml::from_str(std::str::from_utf8(bytes)?)?,
+    };
+    Ok(header.crane_info)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::chart_package;
+
+    #[test]
+    fn test_reader_writer_round_trip() {
+        let package = chart_package();
+
+        let mut buffer = Vec::new();
+        package.to_writer(&mut buffer).unwrap();
+
+        let from_reader = LoadChartPackage::from_reader(buffer.as_slice()).unwrap();
+        let from_bytes = LoadChartPackage::from_bytes(&buffer).unwrap();
+
+        assert_eq!(from_reader.charts[0].id, "main_100");
+        assert_eq!(from_bytes.charts[0].capacity_data.data[0].len(), 8);
+    }
+
+    #[test]
+    fn test_format_dispatch() {
+        assert_eq!(ChartFormat::from_extension("JSON"), Some(ChartFormat::Json));
+        assert_eq!(ChartFormat::from_extension("csv"), None);
+
+        let mut buffer = Vec::new();
+        chart_package().to_writer_as(&mut buffer, ChartFormat::Json).unwrap();
+        let loaded = LoadChartPackage::from_reader_as(buffer.as_slice(), ChartFormat::Json).unwrap();
+        assert_eq!(loaded.charts.len(), 2);
+
+        assert!(matches!(
+            LoadChartPackage::from_bytes(b"not json"),
+            Err(ChartIoError::JsonError(_))
+        ));
+    }
+}
@@ -1,8 +1,11 @@
+use crate::capacity::chart_io::{read_crane_info, ChartFormat, ChartIoError};
 use crate::capacity::load_chart::*;
+pub use crate::capacity::chart_validation::*;
 
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::fs;
+use std::sync::OnceLock;
 
 /// Error types for chart library operations
 #[derive(Debug, thiserror::Error)]
@@ -13,6 +16,9 @@ pub enum ChartLibraryError {
     #[error("JSON error: {0}")]
     JsonError(#[from] serde_json::Error),
 
+    #[error("Chart IO error: {0}")]
+    ChartIoError(#[from] ChartIoError),
+
     #[error("Chart package not found for crane: {0} {1}")]
     PackageNotFound(String, String),
 
@@ -25,20 +31,81 @@ pub enum ChartLibraryError {
 
 
 /// Library of load chart packagees for multiple cranes
+///
+/// Packages are either loaded eagerly or indexed from a directory and
+/// parsed on first access (see [`ChartLibrary::index_directory`]).
 #[derive(Debug, Default)]
 pub struct ChartLibrary {
     /// Maps "Manufacturer:Model" -> LoadChartPackage
     packages: HashMap<String, LoadChartPackage>,
 
+    /// Indexed packages not yet parsed, same keys as `packages`
+    lazy: HashMap<String, LazyPackage>,
+
     /// Base directory where chart files are stored
     base_path: Option<PathBuf>,
 }
 
+/// A package file indexed by crane info, parsed on first access
+#[derive(Debug)]
+struct LazyPackage {
+    path: PathBuf,
+    format: ChartFormat,
+    crane_info: CraneInfo,
+    package: OnceLock<Result<LoadChartPackage, String>>,
+}
+
+impl LazyPackage {
+    fn get(&self) -> Result<&LoadChartPackage, ChartLibraryError> {
+        self.package
+            .get_or_init(|| read_package(&self.path, self.format).map_err(|e| e.to_string()))
+            .as_ref()
+            .map_err(|e| self.load_error(e))
+    }
+
+    fn into_package(self) -> Result<LoadChartPackage, ChartLibraryError> {
+        match self.package.into_inner() {
+            Some(result) => result.map_err(ChartLibraryError::InvalidFormat),
+            None => read_package(&self.path, self.format),
+        }
+    }
+
+    /// Take the parsed package, reading the file again if an earlier parse failed
+    fn take_package(&mut self) -> Result<LoadChartPackage, ChartLibraryError> {
+        match self.package.take() {
+            Some(Ok(package)) => Ok(package),
+            _ => read_package(&self.path, self.format),
+        }
+    }
+
+    fn load_error(&self, message: &str) -> ChartLibraryError {
+        ChartLibraryError::InvalidFormat(format!("{}: {}", self.path.display(), message))
+    }
+}
+
+/// Outcome of loading or indexing a directory
+#[derive(Debug, Default)]
+pub struct LoadSummary {
+    /// Files loaded or indexed
+    pub loaded: Vec<PathBuf>,
+
+    /// Files that could not be read, with the reason
+    pub skipped: Vec<(PathBuf, String)>,
+}
+
+impl LoadSummary {
+    /// True if every chart file was read
+    pub fn is_complete(&self) -> bool {
+        self.skipped.is_empty()
+    }
+}
+
 impl ChartLibrary {
     /// Create a new empty chart library
     pub fn new() -> Self {
         Self {
             packages: HashMap::new(),
+            lazy: HashMap::new(),
             base_path: None,
         }
     }
@@ -51,55 +118,140 @@ impl ChartLibrary {
         Ok(library)
     }
 
-    /// Load all JSON chart files from a directory
-    pub fn load_all_from_directory(&mut self, path: impl AsRef<Path>) -> Result<(), ChartLibraryError> {
-        let dir = fs::read_dir(path)?;
+    /// Create a chart library that parses packages on first access
+    pub fn from_directory_lazy(path: impl AsRef<Path>) -> Result<Self, ChartLibraryError> {
+        let mut library = Self::new();
+        library.base_path = Some(path.as_ref().to_path_buf());
+        library.index_directory(path)?;
+        Ok(library)
+    }
 
-        for entry in dir {
-            let entry = entry?;
-            let path = entry.path();
+    /// Load all chart files from a directory
+    ///
+    /// Reads `.json`, plus `.yaml`/`.yml` and `.toml` when those features are enabled.
+    pub fn load_all_from_directory(&mut self, path: impl AsRef<Path>) -> Result<LoadSummary, ChartLibraryError> {
+        let mut summary = LoadSummary::default();
 
-            if path.extension().and_then(|s| s.to_str()) == Some("json") {
-                match self.load_package_from_file(&path) {
-                    Ok(_) => println!("Loaded: {}", path.display()),
-                    Err(e) => eprintln!("Skipped {}: {}", path.display(), e),
-                }
+        for path in chart_files(path.as_ref())? {
+            match self.load_package_from_file(&path) {
+                Ok(_) => summary.loaded.push(path),
+                Err(e) => summary.skipped.push((path, e.to_string())),
             }
         }
-        Ok(())
+        Ok(summary)
     }
 
+    /// Index chart files by crane, deferring chart parsing to first access
+    ///
+    /// Only each file's crane info is read here.
+    pub fn index_directory(&mut self, path: impl AsRef<Path>) -> Result<LoadSummary, ChartLibraryError> {
+        let mut summary = LoadSummary::default();
 
+        for path in chart_files(path.as_ref())? {
+            let format = file_format(&path);
+            let indexed = fs::read(&path)
+                .map_err(ChartLibraryError::from)
+                .and_then(|bytes| Ok(read_crane_info(&bytes, format)?));
 
-    /// Load a chart package from a JSON file
+            match indexed {
+                Ok(crane_info) => {
+                    self.insert_lazy(path.clone(), format, crane_info);
+                    summary.loaded.push(path);
+                }
+                Err(e) => summary.skipped.push((path, e.to_string())),
+            }
+        }
+        Ok(summary)
+    }
+
+    fn insert_lazy(&mut self, path: PathBuf, format: ChartFormat, crane_info: CraneInfo) {
+        let key = package_key(&crane_info.manufacturer, &crane_info.model);
+        self.packages.remove(&key);
+        self.lazy.insert(
+            key,
+            LazyPackage {
+                path,
+                format,
+                crane_info,
+                package: OnceLock::new(),
+            },
+        );
+    }
+
+    /// Load a chart package from a file, choosing the format by extension
     pub fn load_package_from_file(&mut self, path: impl AsRef<Path>) -> Result<(), ChartLibraryError> {
-        let json = fs::read_to_string(path.as_ref())?;
-        let package: LoadChartPackage = serde_json::from_str(&json)?;
+        let package = read_package(path.as_ref(), file_format(path.as_ref()))?;
+        self.add_package(package);
+        Ok(())
+    }
 
-        let key = format!("{}:{}", package.crane_info.manufacturer, package.crane_info.model);
-        self.packages.insert(key, package);
-        
+    /// Load a chart package from any reader
+    pub fn load_package_from_reader(
+        &mut self,
+        reader: impl std::io::Read,
+        format: ChartFormat,
+    ) -> Result<(), ChartLibraryError> {
+        self.add_package(LoadChartPackage::from_reader_as(reader, format)?);
         Ok(())
     }
 
     /// Add a chart package directly
     pub fn add_package(&mut self, package: LoadChartPackage) {
-        let key = format!("{}:{}", package.crane_info.manufacturer, package.crane_info.model);
+        let key = package_key(&package.crane_info.manufacturer, &package.crane_info.model);
+        self.lazy.remove(&key);
         self.packages.insert(key, package);
     }
 
     /// Get a chart package by manufacturer and model
+    ///
+    /// Parses an indexed package on first access; `None` if it fails to
+    /// parse (see [`try_get_package`](Self::try_get_package) for the reason).
     pub fn get_package(&self, manufacturer: &str, model: &str) -> Option<&LoadChartPackage> {
-        let key = format!("{}:{}", manufacturer, model);
-        self.packages.get(&key)
+        self.try_get_package(manufacturer, model).ok()
+    }
+
+    /// Get a chart package, reporting why it is unavailable
+    pub fn try_get_package(&self, manufacturer: &str, model: &str) -> Result<&LoadChartPackage, ChartLibraryError> {
+        let key = package_key(manufacturer, model);
+        if let Some(package) = self.packages.get(&key) {
+            return Ok(package);
+        }
+        self.lazy
+            .get(&key)
+            .ok_or_else(|| ChartLibraryError::PackageNotFound(manufacturer.to_string(), model.to_string()))?
+            .get()
     }
 
     /// Get a mutable chart package
+    ///
+    /// An indexed package stays indexed until its file parses, so a file
+    /// that fails can be fixed and read again.
     pub fn get_package_mut(&mut self, manufacturer: &str, model: &str) -> Option<&mut LoadChartPackage> {
-        let key = format!("{}:{}", manufacturer, model);
+        let key = package_key(manufacturer, model);
+        if let Some(lazy) = self.lazy.get_mut(&key) {
+            let package = lazy.take_package().ok()?;
+            self.lazy.remove(&key);
+            self.packages.insert(key.clone(), package);
+        }
         self.packages.get_mut(&key)
     }
 
+    /// Every package with its key, parsing indexed packages as needed
+    fn all_packages(&self) -> impl Iterator<Item = (&String, Result<&LoadChartPackage, ChartLibraryError>)> {
+        self.packages
+            .iter()
+            .map(|(key, package)| (key, Ok(package)))
+            .chain(self.lazy.iter().map(|(key, lazy)| (key, lazy.get())))
+    }
+
+    /// Crane info of every package, without parsing indexed packages
+    fn all_crane_info(&self) -> impl Iterator<Item = &CraneInfo> {
+        self.packages
+            .values()
+            .map(|p| &p.crane_info)
+            .chain(self.lazy.values().map(|l| &l.crane_info))
+    }
+
     /// Find the best matching chart for a configuration
     pub fn find_chart(
         &self,
@@ -119,9 +271,9 @@ impl ChartLibrary {
 
         /// Get all available manufacturers
         pub fn manufacturers(&self) -> Vec<String> {
-            let mut manufacturers: Vec<String> = self.packages
-            .values()
-            .map(|p| p.crane_info.manufacturer.clone())
+            let mut manufacturers: Vec<String> = self
+            .all_crane_info()
+            .map(|info| info.manufacturer.clone())
             .collect();
 
         manufacturers.sort();
@@ -131,84 +283,259 @@ impl ChartLibrary {
 
     /// Get all models for a manufacturer
     pub fn models(&self, manufacturer: &str) -> Vec<String> {
-        self.packages
-            .values()
-            .filter(|p| p.crane_info.manufacturer == manufacturer)
-            .map(|p| p.crane_info.model.clone())
+        self.all_crane_info()
+            .filter(|info| info.manufacturer == manufacturer)
+            .map(|info| info.model.clone())
             .collect()
     }
 
     /// Count total number of charts across all packages
+    ///
+    /// Parses any indexed packages not yet loaded.
     pub fn total_charts(&self) -> usize {
-        self.packages.values()
+        self.all_packages()
+            .filter_map(|(_, p)| p.ok())
             .map(|p| p.charts.len())
             .sum()
     }
 
     /// Validate all charts in the library
+    ///
+    /// With the `rayon` feature packages are validated (and lazily indexed
+    /// packages parsed) in parallel.
     pub fn validate_all(&self) -> Result<ValidationReport, ChartLibraryError> {
-        let mut report = ValidationReport::new();
+        #[cfg(feature = "rayon")]
+        let results: Vec<PackageValidation> = {
+            use rayon::prelude::*;
+            self.packages
+                .par_iter()
+                .map(|(key, package)| validate_package(key, Ok(package)))
+                .chain(self.lazy.par_iter().map(|(key, lazy)| validate_package(key, lazy.get())))
+                .collect()
+        };
 
-        for (key, package) in &self.packages {
-            for chart in &package.charts {
-                if let Err(errors) = validate_chart(chart) {
-                    report.add_errors(key, &chart.id, errors);
-                }
-            }
-        }
+        #[cfg(not(feature = "rayon"))]
+        let results: Vec<PackageValidation> = self
+            .all_packages()
+            .map(|(key, package)| validate_package(key, package))
+            .collect();
 
+        let mut report = ValidationReport::new();
+        for result in results {
+            report.add_errors(&result.key, result.errors);
+            report.add_warnings(&result.key, result.warnings);
+        }
         Ok(report)
     }
 
     /// Remove a package from the library
     pub fn remove_package(&mut self, manufacturer: &str, model: &str) -> Option<LoadChartPackage> {
-        let key = format!("{}:{}", manufacturer, model);
-        self.packages.remove(&key)
+        let key = package_key(manufacturer, model);
+        self.packages
+            .remove(&key)
+            .or_else(|| self.lazy.remove(&key).and_then(|l| l.into_package().ok()))
     }
 
     /// Clear all packages
     pub fn clear(&mut self) {
         self.packages.clear();
+        self.lazy.clear();
     }
 
     /// Check if library is empty
     pub fn is_empty(&self) -> bool {
-        self.packages.is_empty()
+        self.packages.is_empty() && self.lazy.is_empty()
     }
 
     /// Get number of packages
     pub fn package_count(&self) -> usize {
-        self.packages.len()
+        self.packages.len() + self.lazy.len()
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl ChartLibrary {
+    /// Index chart files without blocking the async runtime
+    pub async fn index_directory_async(
+        &mut self,
+        path: impl AsRef<Path>,
+    ) -> Result<LoadSummary, ChartLibraryError> {
+        let mut summary = LoadSummary::default();
+
+        let mut files = Vec::new();
+        let mut dir = tokio::fs::read_dir(path).await?;
+        while let Some(entry) = dir.next_entry().await? {
+            let path = entry.path();
+            if is_chart_file(&path) {
+                files.push(path);
+            }
+        }
+        files.sort();
+
+        for path in files {
+            let format = file_format(&path);
+            let indexed = match tokio::fs::read(&path).await {
+                Ok(bytes) => read_crane_info(&bytes, format).map_err(ChartLibraryError::from),
+                Err(e) => Err(e.into()),
+            };
+
+            match indexed {
+                Ok(crane_info) => {
+                    self.insert_lazy(path.clone(), format, crane_info);
+                    summary.loaded.push(path);
+                }
+                Err(e) => summary.skipped.push((path, e.to_string())),
+            }
+        }
+        Ok(summary)
+    }
+
+    /// Get a chart package, reading an indexed file asynchronously
+    pub async fn get_package_async(
+        &self,
+        manufacturer: &str,
+        model: &str,
+    ) -> Result<&LoadChartPackage, ChartLibraryError> {
+        let key = package_key(manufacturer, model);
+        if let Some(package) = self.packages.get(&key) {
+            return Ok(package);
+        }
+
+        let lazy = self
+            .lazy
+            .get(&key)
+            .ok_or_else(|| ChartLibraryError::PackageNotFound(manufacturer.to_string(), model.to_string()))?;
+
+        if lazy.package.get().is_none() {
+            let result = match tokio::fs::read(&lazy.path).await {
+                Ok(bytes) => LoadChartPackage::from_bytes_as(&bytes, lazy.format).map_err(|e| e.to_string()),
+                Err(e) => Err(e.to_string()),
+            };
+            // Another task may have finished first; either result is the same file
+            let _ = lazy.package.set(result);
+        }
+        lazy.get()
     }
 }
 
+fn package_key(manufacturer: &str, model: &str) -> String {
+    format!("{}:{}", manufacturer, model)
+}
+
+fn extension(path: &Path) -> Option<&str> {
+    path.extension().and_then(|s| s.to_str())
+}
+
+fn is_chart_file(path: &Path) -> bool {
+    extension(path).and_then(ChartFormat::from_extension).is_some()
+}
+
+/// Format for a chart file, JSON if the extension is unrecognised
+fn file_format(path: &Path) -> ChartFormat {
+    extension(path)
+        .and_then(ChartFormat::from_extension)
+        .unwrap_or(ChartFormat::Json)
+}
+
+/// Chart files in a directory, sorted for deterministic load order
+fn chart_files(dir: &Path) -> Result<Vec<PathBuf>, ChartLibraryError> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if is_chart_file(&path) {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn read_package(path: &Path, format: ChartFormat) -> Result<LoadChartPackage, ChartLibraryError> {
+    let bytes = fs::read(path)?;
+    Ok(LoadChartPackage::from_bytes_as(&bytes, format)?)
+}
+
 /// Validation report for charts
 #[derive(Debug, Default)]
 pub struct ValidationReport {
     errors: HashMap<String, Vec<ChartError>>,
+    warnings: HashMap<String, Vec<ChartError>>,
 }
 
-#[derive(Debug)]
-pub struct ChartError {
-    pub chart_id: String,
-    pub error: String,
+/// Errors and warnings found in one package
+struct PackageValidation {
+    key: String,
+    errors: Vec<ChartError>,
+    warnings: Vec<ChartError>,
+}
+
+fn validate_package(key: &str, package: Result<&LoadChartPackage, ChartLibraryError>) -> PackageValidation {
+    let mut result = PackageValidation {
+        key: key.to_string(),
+        errors: Vec::new(),
+        warnings: Vec::new(),
+    };
+    match package {
+        Ok(package) => {
+            for chart in &package.charts {
+                result.errors.extend(chart_errors(chart));
+                result.warnings.extend(chart_warnings(chart));
+            }
+        }
+        Err(e) => result
+            .errors
+            .push(ChartError::new("<package>", ValidationCategory::Load, e.to_string())),
+    }
+    result
 }
 
 impl ValidationReport {
     pub fn new() -> Self {
         Self {
             errors: HashMap::new(),
+            warnings: HashMap::new(),
         }
     }
 
-    pub fn add_errors(&mut self, package_key: &str, chart_id: &str, errors: Vec<String>) {
-        let entry = self.errors.entry(package_key.to_string()).or_insert_with(Vec::new);
-        for error in errors {
-            entry.push(ChartError {
-                chart_id: chart_id.to_string(),
-                error,
-            });
+    pub fn add_errors(&mut self, package_key: &str, errors: Vec<ChartError>) {
+        if errors.is_empty() {
+            return;
         }
+        self.errors.entry(package_key.to_string()).or_default().extend(errors);
+    }
+
+    /// Record warnings; these do not make the report invalid
+    pub fn add_warnings(&mut self, package_key: &str, warnings: Vec<ChartError>) {
+        if warnings.is_empty() {
+            return;
+        }
+        self.warnings.entry(package_key.to_string()).or_default().extend(warnings);
+    }
+
+    /// Errors by package key
+    pub fn errors(&self) -> &HashMap<String, Vec<ChartError>> {
+        &self.errors
+    }
+
+    /// Warnings by package key
+    pub fn warnings(&self) -> &HashMap<String, Vec<ChartError>> {
+        &self.warnings
+    }
+
+    /// Errors of one category, with their package keys
+    pub fn errors_in(&self, category: ValidationCategory) -> impl Iterator<Item = (&str, &ChartError)> {
+        self.errors
+            .iter()
+            .flat_map(|(key, errors)| errors.iter().map(move |e| (key.as_str(), e)))
+            .filter(move |(_, e)| e.category == category)
+    }
+
+    /// Warnings of one category, with their package keys
+    pub fn warnings_in(&self, category: ValidationCategory) -> impl Iterator<Item = (&str, &ChartError)> {
+        self.warnings
+            .iter()
+            .flat_map(|(key, warnings)| warnings.iter().map(move |w| (key.as_str(), w)))
+            .filter(move |(_, w)| w.category == category)
     }
 
     pub fn is_valid(&self) -> bool {
@@ -219,7 +546,18 @@ impl ValidationReport {
         self.errors.values().map(|v| v.len()).sum()
     }
 
+    pub fn warning_count(&self) -> usize {
+        self.warnings.values().map(|v| v.len()).sum()
+    }
+
     pub fn print_report(&self) {
+        for (package, warnings) in &self.warnings {
+            println!("\n{} (warnings)", package);
+            for warning in warnings {
+                println!(" - [{}] {}", warning.chart_id, warning.error);
+            }
+        }
+
         if self.is_valid() {
             println!("All charts valid");
             return;
@@ -235,58 +573,6 @@ impl ValidationReport {
     }
 }
 
-/// Validate a single chart
-fn validate_chart(chart: &LoadChart) -> Result<(), Vec<String>> {
-    let mut errors = Vec::new();
-
-    
-    // Check boom lengths have valid units
-    for (i, boom) in chart.capacity_data.boom_lengths.iter().enumerate() {
-        if let Err(e) = boom.to_distance() {
-            errors.push(format!("Boom length {}: {}", i , e));
-        }
-    }
-
-    // Check capacity data has valid units
-    for (boom_idx, row) in chart.capacity_data.data.iter().enumerate() {
-        for (point_idx, (radius, capacity)) in row.iter().enumerate() {
-            if let Err(e) = radius.to_distance() {
-                errors.push(format!("Boom {} point {}: invalid radius unit - {}", boom_idx, point_idx, e));
-            }
-            if let Err(e) = capacity.to_mass() {
-                errors.push(format!("Boom {} point {}: invalid capacity unit - {}", boom_idx, point_idx, e));
-            }
-        }
-    }
-
-    // Check boom configuration
-    if let Err(e) = chart.configuration.boom.length_distance() {
-        errors.push(format!("Boom configuration: {}", e));
-    }
-
-    // Check counterweight if present
-    if let Some(ref cw) = chart.configuration.counterweight {
-        if let Err(e) = cw.to_uom_mass() {
-            errors.push(format!("Counterweight configuration: {}", e));
-        }
-    }
-
-    // Check data consistency
-    if chart.capacity_data.boom_lengths.len() != chart.capacity_data.data.len() {
-        errors.push(format!(
-            "Mismatch: {} boom lengths but {} data rows",
-            chart.capacity_data.boom_lengths.len(),
-            chart.capacity_data.data.len()
-        ));
-    }
-
-    if errors.is_empty() {
-        Ok(())
-    } else {
-        Err(errors)
-    }
-}
-
 
 #[cfg(test)]
 mod tests {
@@ -327,6 +613,8 @@ mod tests {
                     length: LengthValue::new(154.2, "ft"),
                     angle_range: None,
                     jib: None,
+                    telescope_mode: None,
+                    boom_inserts: None,
                 },
                 counterweight: Some(CounterweightConfiguration {
                     weight: MassValue::new(110200.0, "lbs"),
@@ -336,11 +624,14 @@ mod tests {
             },
             capacity_data,
             notes: vec![],
+            provenance: ChartProvenance::default(),
         };
 
         LoadChartPackage {
             crane_info,
             charts: vec![chart],
+            provenance: ChartProvenance::default(),
+            extension_interpolation: ExtensionInterpolation::default(),
         }
     }
 
@@ -400,4 +691,143 @@ mod tests {
         assert_eq!(library.package_count(), 1);
         assert_eq!(library.total_charts(), 1);
     }
+
+    #[test]
+    fn test_non_monotonic_chart() {
+        let mut chart = create_test_package().charts.into_iter().next().unwrap();
+
+        // Capacity rises with radius, and a duplicate radius
+        chart.capacity_data.data[0].push((LengthValue::new(60.0, "ft"), MassValue::new(160000.0, "lbs")));
+        chart.capacity_data.data[0].push((LengthValue::new(60.0, "ft"), MassValue::new(90000.0, "lbs")));
+
+        let errors = validate_chart(&chart).unwrap_err();
+        assert!(errors.iter().any(|e| e.contains("capacity increases")));
+        assert!(errors.iter().any(|e| e.contains("duplicate radius")));
+    }
+
+    #[test]
+    fn test_unsorted_booms() {
+        let mut chart = create_test_package().charts.into_iter().next().unwrap();
+        chart.capacity_data.boom_lengths.push(LengthValue::new(100.0, "ft"));
+        chart.capacity_data.data.push(chart.capacity_data.data[0].clone());
+
+        let errors = validate_chart(&chart).unwrap_err();
+        assert!(errors.iter().any(|e| e.contains("not sorted")));
+    }
+
+    #[test]
+    fn test_suspicious_magnitude_warning() {
+        let mut package = create_test_package();
+        package.charts[0].capacity_data.data[0][1].1 = MassValue::new(242.0, "lbs");
+
+        assert!(validate_chart(&package.charts[0]).is_ok());
+        assert!(!chart_warnings(&package.charts[0]).is_empty());
+
+        let mut library = ChartLibrary::new();
+        library.add_package(package);
+        let report = library.validate_all().unwrap();
+        assert!(report.warning_count() > 0);
+    }
+
+    #[test]
+    fn test_error_categories() {
+        let mut package = create_test_package();
+        let chart = &mut package.charts[0];
+        chart.configuration.counterweight.as_mut().unwrap().weight = MassValue::new(110200.0, "furlongs");
+        chart.capacity_data.data[0].push((LengthValue::new(60.0, "ft"), MassValue::new(160000.0, "lbs")));
+        chart.capacity_data.boom_lengths.push(LengthValue::new(200.0, "ft"));
+
+        let mut library = ChartLibrary::new();
+        library.add_package(package);
+        let report = library.validate_all().unwrap();
+
+        assert_eq!(report.errors_in(ValidationCategory::Unit).count(), 1);
+        assert_eq!(report.errors_in(ValidationCategory::Monotonicity).count(), 1);
+        assert_eq!(report.errors_in(ValidationCategory::Consistency).count(), 1);
+        let (key, error) = report.errors_in(ValidationCategory::Unit).next().unwrap();
+        assert_eq!(key, "Grove:GMK5250L");
+        assert_eq!(error.chart_id, "test_chart");
+    }
+
+    #[test]
+    fn test_unreadable_package_reported() {
+        let dir = write_package_dir();
+        let library = ChartLibrary::from_directory_lazy(dir.path()).unwrap();
+        fs::write(dir.path().join("grove.json"), "{ truncated").unwrap();
+
+        let report = library.validate_all().unwrap();
+        assert_eq!(report.errors_in(ValidationCategory::Load).count(), 1);
+    }
+
+    fn write_package_dir() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        create_test_package()
+            .to_json_file(dir.path().join("grove.json").to_str().unwrap())
+            .unwrap();
+        fs::write(dir.path().join("broken.json"), "{ not json").unwrap();
+        fs::write(dir.path().join("readme.txt"), "ignored").unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_load_summary() {
+        let dir = write_package_dir();
+        let mut library = ChartLibrary::new();
+
+        let summary = library.load_all_from_directory(dir.path()).unwrap();
+        assert_eq!(summary.loaded.len(), 1);
+        assert_eq!(summary.skipped.len(), 1);
+        assert!(!summary.is_complete());
+        assert_eq!(library.package_count(), 1);
+    }
+
+    #[test]
+    fn test_lazy_loading() {
+        let dir = write_package_dir();
+        let library = ChartLibrary::from_directory_lazy(dir.path()).unwrap();
+
+        assert_eq!(library.package_count(), 1);
+        assert_eq!(library.manufacturers(), vec!["Grove"]);
+        let lazy = library.lazy.values().next().unwrap();
+        assert!(lazy.package.get().is_none());
+
+        let package = library.get_package("Grove", "GMK5250L").unwrap();
+        assert_eq!(package.charts.len(), 1);
+        assert!(lazy.package.get().is_some());
+        assert_eq!(library.total_charts(), 1);
+    }
+
+    #[test]
+    fn test_corrupt_package_stays_indexed() {
+        let dir = write_package_dir();
+        let mut library = ChartLibrary::from_directory_lazy(dir.path()).unwrap();
+        fs::write(dir.path().join("grove.json"), "{ truncated").unwrap();
+
+        assert!(library.get_package_mut("Grove", "GMK5250L").is_none());
+        assert_eq!(library.package_count(), 1);
+
+        create_test_package()
+            .to_json_file(dir.path().join("grove.json").to_str().unwrap())
+            .unwrap();
+        let package = library.get_package_mut("Grove", "GMK5250L").unwrap();
+        assert_eq!(package.charts[0].id, "test_chart");
+        assert!(library.lazy.is_empty());
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_async_lazy_loading() {
+        let dir = write_package_dir();
+        let mut library = ChartLibrary::new();
+
+        let summary = library.index_directory_async(dir.path()).await.unwrap();
+        assert_eq!(summary.loaded.len(), 1);
+
+        let package = library.get_package_async("Grove", "GMK5250L").await.unwrap();
+        assert_eq!(package.charts[0].id, "test_chart");
+        assert!(matches!(
+            library.get_package_async("Liebherr", "LTM1250").await,
+            Err(ChartLibraryError::PackageNotFound(..))
+        ));
+    }
 }
@@ -0,0 +1,191 @@
+//! Load chart validation
+//!
+//! Unit, ordering and consistency checks on a single [`LoadChart`]. Used
+//! by [`LoadChartBuilder`](crate::capacity::chart_builder::LoadChartBuilder)
+//! and by the chart library when packages are loaded.
+
+use crate::capacity::load_chart::*;
+use crate::types::*;
+
+/// Kind of problem found in a chart, so tools can filter or auto-fix
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValidationCategory {
+    /// Unknown unit string, or a magnitude that suggests the wrong unit
+    Unit,
+    /// Boom lengths or radii out of order, or capacity rising with radius
+    Monotonicity,
+    /// Mismatched row counts, duplicates, radius beyond boom length
+    Consistency,
+    /// Package could not be read at all
+    Load,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChartError {
+    pub chart_id: String,
+    pub category: ValidationCategory,
+    pub error: String,
+}
+
+impl ChartError {
+    pub fn new(chart_id: &str, category: ValidationCategory, error: String) -> Self {
+        Self {
+            chart_id: chart_id.to_string(),
+            category,
+            error,
+        }
+    }
+}
+
+/// Validate a single chart
+pub fn validate_chart(chart: &LoadChart) -> Result<(), Vec<String>> {
+    let errors = chart_errors(chart);
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors.into_iter().map(|e| e.error).collect())
+    }
+}
+
+/// Validation errors for a single chart, with their categories
+pub fn chart_errors(chart: &LoadChart) -> Vec<ChartError> {
+    let mut errors = Vec::new();
+    let mut push = |category, error| errors.push(ChartError::new(&chart.id, category, error));
+
+    // Check boom lengths have valid units
+    for (i, boom) in chart.capacity_data.boom_lengths.iter().enumerate() {
+        if let Err(e) = boom.to_distance() {
+            push(ValidationCategory::Unit, format!("Boom length {}: {}", i , e));
+        }
+    }
+
+    // Check capacity data has valid units
+    for (boom_idx, row) in chart.capacity_data.data.iter().enumerate() {
+        for (point_idx, (radius, capacity)) in row.iter().enumerate() {
+            if let Err(e) = radius.to_distance() {
+                push(ValidationCategory::Unit, format!("Boom {} point {}: invalid radius unit - {}", boom_idx, point_idx, e));
+            }
+            if let Err(e) = capacity.to_mass() {
+                push(ValidationCategory::Unit, format!("Boom {} point {}: invalid capacity unit - {}", boom_idx, point_idx, e));
+            }
+        }
+    }
+
+    // Check boom configuration
+    if let Err(e) = chart.configuration.boom.length_distance() {
+        push(ValidationCategory::Unit, format!("Boom configuration: {}", e));
+    }
+
+    // Check counterweight if present
+    if let Some(ref cw) = chart.configuration.counterweight
+        && let Err(e) = cw.to_uom_mass()
+    {
+        push(ValidationCategory::Unit, format!("Counterweight configuration: {}", e));
+    }
+
+    // Check data consistency
+    if chart.capacity_data.boom_lengths.len() != chart.capacity_data.data.len() {
+        push(ValidationCategory::Consistency, format!(
+            "Mismatch: {} boom lengths but {} data rows",
+            chart.capacity_data.boom_lengths.len(),
+            chart.capacity_data.data.len()
+        ));
+    }
+
+    // Boom lengths must be sorted ascending without duplicates
+    if let Ok(booms) = chart.capacity_data.boom_lengths() {
+        for (i, pair) in booms.windows(2).enumerate() {
+            let (a, b) = (pair[0].get::<foot>(), pair[1].get::<foot>());
+            if (b - a).abs() < 0.01 {
+                push(ValidationCategory::Consistency, format!("Boom lengths {} and {}: duplicate {:.1} ft", i, i + 1, a));
+            } else if b < a {
+                push(ValidationCategory::Monotonicity, format!("Boom lengths {} and {}: not sorted ({:.1} ft before {:.1} ft)", i, i + 1, a, b));
+            }
+        }
+    }
+
+    // Radii strictly increasing, capacity non-increasing with radius
+    for boom_idx in 0..chart.capacity_data.data.len() {
+        let Ok(points) = chart.capacity_data.capacity_points(boom_idx) else {
+            continue;
+        };
+
+        for (i, pair) in points.windows(2).enumerate() {
+            let (r0, c0) = (pair[0].0.get::<foot>(), pair[0].1.get::<pound>());
+            let (r1, c1) = (pair[1].0.get::<foot>(), pair[1].1.get::<pound>());
+
+            if (r1 - r0).abs() < 0.01 {
+                push(ValidationCategory::Consistency, format!("Boom {} points {} and {}: duplicate radius {:.1} ft", boom_idx, i, i + 1, r0));
+            } else if r1 < r0 {
+                push(ValidationCategory::Monotonicity, format!("Boom {} points {} and {}: radius not increasing ({:.1} ft then {:.1} ft)", boom_idx, i, i + 1, r0, r1));
+            }
+
+            if c1 > c0 + 0.5 {
+                push(ValidationCategory::Monotonicity, format!("Boom {} points {} and {}: capacity increases with radius ({:.0} lbs then {:.0} lbs)", boom_idx, i, i + 1, c0, c1));
+            }
+        }
+    }
+
+    errors
+}
+
+/// Engineering sanity warnings that don't invalidate a chart
+///
+/// Flags magnitudes that usually mean a wrong unit string, e.g. `242` entered
+/// as "lbs" instead of "kips" next to 152,000 lbs.
+pub fn chart_warnings(chart: &LoadChart) -> Vec<ChartError> {
+    let mut warnings = Vec::new();
+    let mut push = |category, warning| warnings.push(ChartError::new(&chart.id, category, warning));
+
+    // Below this a capacity is almost certainly in the wrong unit
+    const MIN_PLAUSIBLE_CAPACITY_LBS: f64 = 100.0;
+    // Adjacent points differing by more than this suggest mixed units
+    const MAX_ADJACENT_RATIO: f64 = 10.0;
+
+    let booms = chart.capacity_data.boom_lengths().unwrap_or_default();
+    let mut max_capacity: f64 = 0.0;
+
+    for boom_idx in 0..chart.capacity_data.data.len() {
+        let Ok(points) = chart.capacity_data.capacity_points(boom_idx) else {
+            continue;
+        };
+
+        for (i, (radius, capacity)) in points.iter().enumerate() {
+            let c = capacity.get::<pound>();
+            max_capacity = max_capacity.max(c);
+
+            if c > 0.0 && c < MIN_PLAUSIBLE_CAPACITY_LBS {
+                push(ValidationCategory::Unit, format!("Boom {} point {}: capacity {:.1} lbs is implausibly small, check units", boom_idx, i, c));
+            }
+
+            if let Some(boom) = booms.get(boom_idx)
+                && *radius > *boom
+            {
+                push(ValidationCategory::Consistency, format!("Boom {} point {}: radius {:.1} ft exceeds boom length {:.1} ft", boom_idx, i, radius.get::<foot>(), boom.get::<foot>()));
+            }
+        }
+
+        for (i, pair) in points.windows(2).enumerate() {
+            let (c0, c1) = (pair[0].1.get::<pound>(), pair[1].1.get::<pound>());
+            let (hi, lo) = (c0.max(c1), c0.min(c1));
+            if lo > 0.0 && hi / lo > MAX_ADJACENT_RATIO {
+                push(ValidationCategory::Unit, format!("Boom {} points {} and {}: capacity jumps from {:.0} lbs to {:.0} lbs, check units", boom_idx, i, i + 1, c0, c1));
+            }
+        }
+    }
+
+    // A crane rated for a tiny fraction of its own counterweight is suspect
+    if let Some(cw) = &chart.configuration.counterweight
+        && let Ok(cw) = cw.to_uom_mass()
+        && max_capacity > 0.0
+        && max_capacity < cw.get::<pound>() / 100.0
+    {
+        push(ValidationCategory::Unit, format!(
+            "Maximum capacity {:.0} lbs is under 1% of counterweight {:.0} lbs, check units",
+            max_capacity,
+            cw.get::<pound>()
+        ));
+    }
+
+    warnings
+}
@@ -0,0 +1,212 @@
+//! Pre-lift checklist for the lift director
+//!
+//! Built from a lift plan and its validation report, so the field
+//! paperwork carries the same numbers as the engineering check: the gear
+//! to inspect and its WLL, the load under each pad, the wind limits for
+//! the day, and the points where the lift stops until someone signs off.
+
+use crate::capacity::lift_validation::*;
+use crate::capacity::load_chart::{OutriggerExtension, SupportConfiguration};
+use crate::equipment::Crane;
+use crate::types::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rigging to inspect and tag before the lift
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RiggingGearItem {
+    pub description: String,
+    pub item_type: String,
+    pub working_load_limit: Mass,
+    pub weight: Mass,
+}
+
+/// Load under one outrigger pad or mat
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PadLoad {
+    pub support: String,
+    pub reaction: Force,
+    pub pressure: Pressure,
+    pub allowable: Pressure,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DayWindLimits {
+    /// Wind the plan was checked at
+    pub planned: Velocity,
+    pub caution: Velocity,
+    pub shutdown: Velocity,
+}
+
+/// Point where the lift stops until the item is confirmed
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HoldPoint {
+    pub description: String,
+
+    /// Raised by a validation warning or failure rather than standard practice
+    pub from_validation: bool,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LiftChecklist {
+    pub status: ValidationStatus,
+    pub hook_load: Mass,
+    pub critical_lift: bool,
+    pub rigging: Vec<RiggingGearItem>,
+
+    /// Empty for cranes without a support model
+    pub pad_loads: Vec<PadLoad>,
+    pub wind: DayWindLimits,
+
+    /// In the order they come up during the lift
+    pub hold_points: Vec<HoldPoint>,
+}
+
+impl LiftChecklist {
+    /// Checklist for `plan` on `crane`, validated in `report`
+    pub fn new<C: Crane>(crane: &C, plan: &LiftPlan, report: &ValidationReport) -> Self {
+        let rigging = plan
+            .rigging
+            .hardware
+            .iter()
+            .map(|h| RiggingGearItem {
+                description: h.description.clone(),
+                item_type: h.item_type.clone(),
+                working_load_limit: h.capacity,
+                weight: h.weight,
+            })
+            .collect();
+
+        let allowable = plan.allowable_bearing();
+        let pad_loads = plan
+            .ground_bearing(crane)
+            .and_then(Result::ok)
+            .map(|result| {
+                result
+                    .reactions
+                    .into_iter()
+                    .map(|r| PadLoad { support: r.name, reaction: r.force, pressure: r.pressure, allowable })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let (caution, shutdown) = plan.safety_factors.operating_wind_limits(&plan.wind_analysis(crane));
+        let wind = DayWindLimits { planned: plan.environment.wind_speed, caution, shutdown };
+
+        let critical_lift = plan.lift_class(crane) == LiftClass::Critical;
+
+        let mut hold_points: Vec<HoldPoint> = report
+            .checks
+            .iter()
+            .filter(|c| c.status != CheckStatus::Pass)
+            .map(|c| HoldPoint { description: format!("{}: {}", c.name, c.details), from_validation: true })
+            .collect();
+        let standard = |description: String| HoldPoint { description, from_validation: false };
+        if critical_lift {
+            hold_points.insert(0, standard("Critical lift plan signed by the lift director".into()));
+        }
+        hold_points.extend([
+            standard(setup_hold_point(crane.support_configuration())),
+            standard("Rigging inspected and tagged, WLLs match the gear list".into()),
+            standard(format!(
+                "Wind below {:.0} mph at the boom tip; stop at {:.0} mph",
+                caution.get::<mile_per_hour>(),
+                shutdown.get::<mile_per_hour>()
+            )),
+            standard("Trial lift: raise a few inches and hold to check brakes, rigging and ground".into()),
+        ]);
+
+        Self {
+            status: report.overall_status,
+            hook_load: plan.hook_load(),
+            critical_lift,
+            rigging,
+            pad_loads,
+            wind,
+            hold_points,
+        }
+    }
+}
+
+/// Hold point for the crane standing as its chart was chosen for
+fn setup_hold_point(support: Option<SupportConfiguration>) -> String {
+    match support {
+        Some(SupportConfiguration::OnOutriggers { extension, .. }) => {
+            let extension = match extension {
+                OutriggerExtension::Full => "fully extended".to_string(),
+                OutriggerExtension::Intermediate { percent } => format!("extended to {:.0}%", percent),
+                OutriggerExtension::Minimum => "at minimum extension".to_string(),
+                OutriggerExtension::Custom { distance } => {
+                    format!("extended to {} {}", distance.value, distance.unit)
+                }
+                OutriggerExtension::PerCorner { .. } => "extended per corner as planned".to_string(),
+            };
+            format!("Ground and mats set as planned, outriggers {} and level", extension)
+        }
+        Some(SupportConfiguration::OnRubber { .. }) => {
+            "Ground set as planned, crane on rubber with tires at rated pressure".into()
+        }
+        Some(SupportConfiguration::OnCrawlers { track_config }) => {
+            format!("Ground and mats set as planned, crawler tracks {} and level", track_config)
+        }
+        Some(SupportConfiguration::OnBarge { max_list }) => {
+            format!("Barge ballasted with list and trim inside {} {}", max_list.value, max_list.unit)
+        }
+        None => "Ground and mats set as planned".into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::equipment::DutyClass;
+    use crate::test_fixtures::{lift_plan as plan, mobile_crane};
+
+    #[test]
+    fn test_checklist_from_validation() {
+        // 10,000 lbs at 50 ft on the fixture chart
+        let crane = mobile_crane();
+
+        let routine = plan(5_000.0);
+        let checklist = LiftChecklist::new(&crane, &routine, &validate_lift(&crane, &routine));
+        assert!(!checklist.critical_lift);
+        assert_eq!(checklist.rigging[0].working_load_limit, Mass::new::<pound>(50_000.0));
+        assert_eq!(checklist.pad_loads.len(), 4);
+        assert!(checklist.pad_loads.iter().all(|p| p.allowable == routine.allowable_bearing()));
+        assert_eq!(checklist.wind.shutdown, Velocity::new::<mile_per_hour>(30.0));
+        assert!(checklist.hold_points.last().unwrap().description.starts_with("Trial lift"));
+        let setup = |checklist: &LiftChecklist| {
+            checklist.hold_points.iter().find(|h| h.description.starts_with("Ground")).unwrap().description.clone()
+        };
+        assert!(setup(&checklist).contains("outriggers fully extended"));
+
+        // The setup hold point follows the crane's supports
+        let mut on_rubber = mobile_crane();
+        on_rubber.on_outriggers = false;
+        let checklist = LiftChecklist::new(&on_rubber, &routine, &validate_lift(&on_rubber, &routine));
+        assert!(!setup(&checklist).contains("outriggers"));
+        assert!(checklist.pad_loads.is_empty());
+        let mut half_out = mobile_crane();
+        half_out.outrigger_extension = OutriggerExtension::Intermediate { percent: 50.0 };
+        let checklist = LiftChecklist::new(&half_out, &routine, &validate_lift(&half_out, &routine));
+        assert!(setup(&checklist).contains("outriggers extended to 50%"));
+
+        // 80% of chart: critical, signed off before anything else
+        let heavy = plan(8_000.0);
+        let checklist = LiftChecklist::new(&crane, &heavy, &validate_lift(&crane, &heavy));
+        assert!(checklist.critical_lift);
+        assert!(checklist.hold_points[0].description.starts_with("Critical lift plan"));
+
+        // Concrete bucket duty: 6,500 lbs is 81% of the 8,000 lb derated chart
+        let bucket = LiftPlan { duty: DutyClass::ConcreteBucket, ..plan(6_500.0) };
+        let report = validate_lift(&crane, &bucket);
+        assert!(LiftChecklist::new(&crane, &bucket, &report).critical_lift);
+        assert!(report.check("Capacity").unwrap().details.contains("concrete bucket duty 0.80"));
+        assert!(report.recommendations.iter().any(|r| r.starts_with("Chart derated to 80%")));
+    }
+}
@@ -0,0 +1,228 @@
+//! What-if comparison of crane setups for one lift
+//!
+//! Planners rarely settle on a setup first time: a longer boom, more
+//! counterweight or a different outrigger extension each trade capacity
+//! against ground pressure. [`compare_configurations`] runs the same
+//! [`LiftPlan`] through [`validate_lift`] for each candidate setup and
+//! lines the results up side by side.
+
+use crate::capacity::lift_validation::*;
+use crate::equipment::Crane;
+use crate::types::*;
+use std::fmt;
+
+/// A named candidate setup
+#[derive(Debug, Clone)]
+pub struct CraneSetup<C: Crane> {
+    pub name: String,
+    pub crane: C,
+}
+
+impl<C: Crane> CraneSetup<C> {
+    pub fn new(name: impl Into<String>, crane: C) -> Self {
+        Self {
+            name: name.into(),
+            crane,
+        }
+    }
+}
+
+/// One setup's results
+#[derive(Debug, Clone)]
+pub struct ComparisonRow {
+    pub name: String,
+    pub status: ValidationStatus,
+
+    pub boom_length: Length,
+    pub radius: Length,
+    pub capacity: Mass,
+
+    /// Hook load (with dynamic amplification) / rated capacity
+    pub utilization: f64,
+
+    /// Highest pad pressure; `None` if the support model failed (e.g. a
+    /// pad lifted off)
+    pub ground_pressure: Option<Pressure>,
+
+    /// Percentage margins from the validation checks
+    pub capacity_margin: Option<f64>,
+    pub ground_margin: Option<f64>,
+    pub rigging_margin: Option<f64>,
+
+    /// Full validation report for this setup
+    pub report: ValidationReport,
+}
+
+/// Side-by-side results for every setup, in the order given
+#[derive(Debug, Clone)]
+pub struct ConfigurationComparison {
+    pub rows: Vec<ComparisonRow>,
+}
+
+impl ConfigurationComparison {
+    /// Setups that pass validation (possibly with warnings)
+    pub fn approved(&self) -> impl Iterator<Item = &ComparisonRow> {
+        self.rows.iter().filter(|r| r.status != ValidationStatus::Rejected)
+    }
+
+    /// Approved setup with the lowest capacity utilization; setups whose
+    /// utilization couldn't be worked out (NaN) are never best
+    pub fn best(&self) -> Option<&ComparisonRow> {
+        self.approved()
+            .filter(|r| !r.utilization.is_nan())
+            .min_by(|a, b| a.utilization.total_cmp(&b.utilization))
+    }
+
+    pub fn row(&self, name: &str) -> Option<&ComparisonRow> {
+        self.rows.iter().find(|r| r.name == name)
+    }
+}
+
+impl fmt::Display for ConfigurationComparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let percent = |value: Option<f64>| value.map_or("-".to_string(), |v| format!("{:.1}%", v));
+
+        writeln!(
+            f,
+            "{:<20} {:>8} {:>8} {:>10} {:>7} {:>9} {:>9} {:>9} {:>9}  Status",
+            "Setup", "Boom ft", "Rad ft", "Cap lbs", "Util", "GBP psi", "Cap mgn", "GBP mgn", "Rig mgn"
+        )?;
+        writeln!(f, "{}", "─".repeat(113))?;
+
+        for row in &self.rows {
+            writeln!(
+                f,
+                "{:<20} {:>8.1} {:>8.1} {:>10.0} {:>6.1}% {:>9} {:>9} {:>9} {:>9}  {:?}",
+                row.name,
+                row.boom_length.get::<foot>(),
+                row.radius.get::<foot>(),
+                row.capacity.get::<pound>(),
+                row.utilization * 100.0,
+                row.ground_pressure.map_or("-".to_string(), |p| format!("{:.1}", p.get::<psi>())),
+                percent(row.capacity_margin),
+                percent(row.ground_margin),
+                percent(row.rigging_margin),
+                row.status,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Validate one lift plan against several crane setups
+pub fn compare_configurations<C: Crane>(plan: &LiftPlan, setups: &[CraneSetup<C>]) -> ConfigurationComparison {
+    let rows = setups
+        .iter()
+        .map(|setup| {
+            let crane = &setup.crane;
+            let config = crane.configuration();
+            let capacity = crane.rated_capacity();
+            let report = validate_lift(crane, plan);
+            let margin = |name: &str| report.check(name).and_then(|c| c.margin);
+
+            let utilization = if capacity.get::<pound>() > 0.0 {
+                plan.dynamic_suspended_load(crane).get::<pound>() / capacity.get::<pound>()
+            } else {
+                f64::INFINITY
+            };
+
+            ComparisonRow {
+                name: setup.name.clone(),
+                status: report.overall_status,
+                boom_length: config.boom_length,
+                radius: config.radius,
+                capacity,
+                utilization,
+                ground_pressure: ground_bearing_pressure(crane, plan).ok().map(|(pressure, _)| pressure),
+                capacity_margin: margin("Capacity"),
+                ground_margin: margin("Ground Bearing"),
+                rigging_margin: margin("Rigging Capacity"),
+                report,
+            }
+        })
+        .collect();
+
+    ConfigurationComparison { rows }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::equipment::MobileCrane;
+    use crate::test_fixtures::{lift_plan as plan, mobile_crane};
+    use approx::assert_relative_eq;
+
+    // 10,000 lbs at 50 ft on the fixture chart
+    fn crane(counterweight: f64, spread: f64) -> MobileCrane {
+        let mut crane = mobile_crane();
+        crane.counterweight = Mass::new::<pound>(counterweight);
+        crane.outrigger_spread = Length::new::<foot>(spread);
+        crane
+    }
+
+    #[test]
+    fn test_compare_outrigger_spread() {
+        let setups = [
+            CraneSetup::new("Full outriggers", crane(10_000.0, 24.0)),
+            CraneSetup::new("Short outriggers", crane(10_000.0, 16.0)),
+        ];
+        let comparison = compare_configurations(&plan(8_000.0), &setups);
+
+        assert_eq!(comparison.rows.len(), 2);
+        let full = comparison.row("Full outriggers").unwrap();
+        let short = comparison.row("Short outriggers").unwrap();
+        assert_relative_eq!(full.utilization, 0.8, epsilon = 1e-9);
+
+        // Same load, same radius: the narrower footprint loads the front pads harder
+        assert!(short.ground_pressure.unwrap() > full.ground_pressure.unwrap());
+        assert!(short.ground_margin.unwrap() < full.ground_margin.unwrap());
+
+        let table = comparison.to_string();
+        assert!(table.contains("Full outriggers"));
+        assert!(table.contains("Short outriggers"));
+    }
+
+    #[test]
+    fn test_best_skips_rejected() {
+        let setups = [
+            CraneSetup::new("Overloaded", crane(10_000.0, 24.0)),
+            CraneSetup::new("More counterweight", crane(20_000.0, 24.0)),
+        ];
+        let comparison = compare_configurations(&plan(12_000.0), &setups);
+
+        // Counterweight doesn't change the 10,000 lb chart rating: both are over capacity
+        assert!(comparison.rows.iter().all(|r| r.status == ValidationStatus::Rejected));
+        assert!(comparison.best().is_none());
+
+        let mut comparison = compare_configurations(&plan(6_000.0), &setups);
+        assert_eq!(comparison.approved().count(), 2);
+        assert!(comparison.best().is_some());
+
+        // A NaN utilization is passed over whichever order the rows are in
+        comparison.rows[0].utilization = f64::NAN;
+        assert_eq!(comparison.best().unwrap().name, "More counterweight");
+        comparison.rows.reverse();
+        assert_eq!(comparison.best().unwrap().name, "More counterweight");
+    }
+
+    #[test]
+    fn test_rigging_weight_counts_toward_hook_load() {
+        let mut rigged = plan(9_500.0);
+        rigged.rigging.hardware[0].weight = Mass::new::<pound>(200.0);
+        assert_relative_eq!(rigged.hook_load().get::<pound>(), 9_700.0, epsilon = 1e-9);
+
+        // The hook block comes off the crane, not the plan
+        let mut rigged_crane = crane(10_000.0, 24.0);
+        rigged_crane.deductions.hook_block = Mass::new::<pound>(400.0);
+        assert_relative_eq!(rigged.suspended_load(&rigged_crane).get::<pound>(), 10_100.0, epsilon = 1e-9);
+
+        let setups = [CraneSetup::new("Rigged", rigged_crane)];
+        let comparison = compare_configurations(&rigged, &setups);
+        assert_relative_eq!(comparison.rows[0].utilization, 1.01, epsilon = 1e-9);
+        assert_eq!(comparison.rows[0].status, ValidationStatus::Rejected);
+
+        let report = validate_lift(&setups[0].crane, &rigged);
+        let hook_load = report.checks.iter().find(|c| c.name == "Hook Load").unwrap();
+        assert!(hook_load.details.contains("Hook block 400 lbs"));
+    }
+}
@@ -0,0 +1,287 @@
+//! Precompiled load chart for fast repeated queries
+//!
+//! [`LoadChart`] keeps values with their unit strings, so every lookup
+//! re-parses units and allocates. [`CompiledLoadChart`] converts once to
+//! sorted `f64` arrays in internal units (feet, pounds) and answers queries
+//! with binary search and no allocation. Results match
+//! [`LoadChart::capacity_interpolated`].
+
+use crate::capacity::load_chart::*;
+use crate::types::*;
+
+/// Matching tolerance (ft), same as the interpolation path in `LoadChart`
+const EPSILON_FT: f64 = 0.1;
+
+/// One boom length's (radius, capacity) curve, sorted by radius
+#[derive(Debug, Clone)]
+struct CompiledRow {
+    radii: Vec<f64>,
+    capacities: Vec<f64>,
+}
+
+impl CompiledRow {
+    /// Capacity (lbs) at a radius (ft) by linear interpolation
+    fn capacity(&self, radius: f64) -> Option<f64> {
+        // Lower: largest radius <= radius + eps
+        let lower = self.radii.partition_point(|&r| r <= radius + EPSILON_FT);
+        if lower == 0 {
+            return None;
+        }
+        let lower = lower - 1;
+
+        // Upper: smallest radius >= radius - eps
+        let upper = self.radii.partition_point(|&r| r < radius - EPSILON_FT);
+        if upper == self.radii.len() {
+            return None;
+        }
+
+        let (r0, r1) = (self.radii[lower], self.radii[upper]);
+        if (r0 - r1).abs() < EPSILON_FT {
+            return Some(self.capacities[lower]);
+        }
+
+        let ratio = (radius - r0) / (r1 - r0);
+        Some(self.capacities[lower] + ratio * (self.capacities[upper] - self.capacities[lower]))
+    }
+}
+
+/// Load chart compiled to plain arrays in feet and pounds
+#[derive(Debug, Clone)]
+pub struct CompiledLoadChart {
+    /// Source chart identifier
+    pub id: String,
+
+    /// Source chart context for errors
+    context: ChartContext,
+
+    /// Boom lengths (ft), ascending
+    booms: Vec<f64>,
+
+    /// Capacity curve for each entry in `booms`
+    rows: Vec<CompiledRow>,
+}
+
+impl CompiledLoadChart {
+    /// Compile a chart, converting all values to internal units
+    ///
+    /// Errors on a chart without any boom length, or with a boom length
+    /// that has no capacities, so a compiled chart is never empty.
+    pub fn compile(chart: &LoadChart) -> Result<Self, LoadChartError> {
+        let booms = chart.boom_lengths()?;
+        if chart.capacity_data.data.len() != booms.len() {
+            return Err(LoadChartError::NoData);
+        }
+
+        let mut entries = Vec::with_capacity(booms.len());
+        for (idx, boom) in booms.iter().enumerate() {
+            let mut points: Vec<(f64, f64)> = chart
+                .capacity_points(idx)?
+                .into_iter()
+                .map(|(r, c)| (r.get::<foot>(), c.get::<pound>()))
+                .collect();
+            points.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+
+            entries.push((
+                boom.get::<foot>(),
+                CompiledRow {
+                    radii: points.iter().map(|p| p.0).collect(),
+                    capacities: points.iter().map(|p| p.1).collect(),
+                },
+            ));
+        }
+        entries.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+
+        let (booms, rows) = entries.into_iter().unzip();
+        Ok(Self {
+            id: chart.id.clone(),
+            context: chart.context(),
+            booms,
+            rows,
+        })
+    }
+
+    /// Interpolated capacity in pounds for boom length and radius in feet
+    ///
+    /// Hot path for sweeps: no unit conversion and no allocation. Returns
+    /// `None` outside the charted boom or radius range.
+    pub fn capacity_ft_lb(&self, boom_ft: f64, radius_ft: f64) -> Option<f64> {
+        let lower = self.booms.partition_point(|&b| b <= boom_ft + EPSILON_FT);
+        if lower == 0 {
+            return None;
+        }
+        let lower = lower - 1;
+
+        let upper = self.booms.partition_point(|&b| b < boom_ft - EPSILON_FT);
+        if upper == self.booms.len() {
+            return None;
+        }
+
+        let cap_lower = self.rows[lower].capacity(radius_ft)?;
+        if lower == upper {
+            return Some(cap_lower);
+        }
+        let cap_upper = self.rows[upper].capacity(radius_ft)?;
+
+        let (b0, b1) = (self.booms[lower], self.booms[upper]);
+        let ratio = (boom_ft - b0) / (b1 - b0);
+        Some(cap_lower + ratio * (cap_upper - cap_lower))
+    }
+
+    /// Interpolated capacity at any boom length and radius
+    pub fn capacity(&self, boom_length: Length, radius: Length) -> Result<Mass, LoadChartError> {
+        let boom_ft = boom_length.get::<foot>();
+        let (min, max) = self.boom_range_ft();
+        if boom_ft < min - EPSILON_FT || boom_ft > max + EPSILON_FT {
+            return Err(LoadChartError::BoomLengthNotFound {
+                length: DisplayLength(boom_length),
+                context: self.context.clone(),
+            });
+        }
+
+        self.capacity_ft_lb(boom_ft, radius.get::<foot>())
+            .map(Mass::new::<pound>)
+            .ok_or_else(|| LoadChartError::RadiusOutOfRange {
+                radius: DisplayLength(radius),
+                context: self.context.clone(),
+            })
+    }
+
+    /// Interpolated capacity with explicit behavior at chart edges
+    pub fn capacity_with_policy(
+        &self,
+        boom_length: Length,
+        radius: Length,
+        policy: OutOfRangePolicy,
+    ) -> Result<Mass, LoadChartError> {
+        match policy {
+            OutOfRangePolicy::Error => self.capacity(boom_length, radius),
+            OutOfRangePolicy::ZeroCapacity => Ok(Mass::new::<pound>(
+                self.capacity_ft_lb(boom_length.get::<foot>(), radius.get::<foot>())
+                    .unwrap_or(0.0),
+            )),
+            OutOfRangePolicy::ClampToNearest => {
+                let (boom_ft, radius_ft) = self.clamp_ft(boom_length.get::<foot>(), radius.get::<foot>());
+                self.capacity_ft_lb(boom_ft, radius_ft)
+                    .map(Mass::new::<pound>)
+                    .ok_or(LoadChartError::NoData)
+            }
+        }
+    }
+
+    /// Clamp boom length and radius (ft) to the charted range
+    fn clamp_ft(&self, boom_ft: f64, radius_ft: f64) -> (f64, f64) {
+        let (min, max) = self.boom_range_ft();
+        let boom_ft = boom_ft.clamp(min, max);
+
+        let lower = self.booms.partition_point(|&b| b <= boom_ft + EPSILON_FT).saturating_sub(1);
+        let upper = self.booms.partition_point(|&b| b < boom_ft - EPSILON_FT).min(self.booms.len() - 1);
+
+        let mut r_min = f64::MIN;
+        let mut r_max = f64::MAX;
+        for row in [&self.rows[lower], &self.rows[upper]] {
+            if let (Some(first), Some(last)) = (row.radii.first(), row.radii.last()) {
+                r_min = r_min.max(*first);
+                r_max = r_max.min(*last);
+            }
+        }
+
+        (boom_ft, radius_ft.clamp(r_min, r_max.max(r_min)))
+    }
+
+    /// Boom lengths (ft), ascending
+    pub fn boom_lengths_ft(&self) -> &[f64] {
+        &self.booms
+    }
+
+    /// Compiling rejects empty charts, so there is always a boom length
+    fn boom_range_ft(&self) -> (f64, f64) {
+        (self.booms[0], self.booms[self.booms.len() - 1])
+    }
+}
+
+impl TryFrom<&LoadChart> for CompiledLoadChart {
+    type Error = LoadChartError;
+
+    fn try_from(chart: &LoadChart) -> Result<Self, Self::Error> {
+        Self::compile(chart)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::capacity::chart_builder::LoadChartBuilder;
+    use approx::assert_relative_eq;
+
+    fn test_chart() -> LoadChart {
+        let mut chart = LoadChartBuilder::new("compiled")
+            .description("Compiled chart test")
+            .boom(150.0, "ft")
+            .points(&[(30.0, 80000.0), (80.0, 30000.0), (130.0, 10000.0)])
+            .build()
+            .unwrap();
+
+        // Deliberately mixed units and unsorted booms, past what the builder accepts
+        chart.capacity_data.boom_lengths.push(LengthValue::new(30.48, "m"));
+        chart.capacity_data.data.push(vec![
+            (LengthValue::new(20.0, "ft"), MassValue::new(100000.0, "lbs")),
+            (LengthValue::new(60.0, "ft"), MassValue::new(40000.0, "lbs")),
+            (LengthValue::new(90.0, "ft"), MassValue::new(20000.0, "lbs")),
+        ]);
+        chart
+    }
+
+    #[test]
+    fn test_matches_load_chart_interpolation() {
+        let chart = test_chart();
+        let compiled = CompiledLoadChart::compile(&chart).unwrap();
+
+        for boom in [100.0, 112.5, 125.0, 149.95, 150.0] {
+            for radius in [30.0, 45.0, 60.0, 77.7, 90.0] {
+                let boom = Length::new::<foot>(boom);
+                let radius = Length::new::<foot>(radius);
+                let expected = chart.capacity_interpolated(boom, radius).unwrap();
+                let actual = compiled.capacity(boom, radius).unwrap();
+                assert_relative_eq!(actual.get::<pound>(), expected.get::<pound>(), epsilon = 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        let compiled = CompiledLoadChart::compile(&test_chart()).unwrap();
+
+        assert!(compiled.capacity_ft_lb(90.0, 40.0).is_none());
+        assert!(compiled.capacity_ft_lb(100.0, 95.0).is_none());
+        assert!(matches!(
+            compiled.capacity(Length::new::<foot>(200.0), Length::new::<foot>(40.0)),
+            Err(LoadChartError::BoomLengthNotFound { .. })
+        ));
+        assert_eq!(compiled.boom_lengths_ft().len(), 2);
+        assert!(compiled.boom_lengths_ft()[0] < compiled.boom_lengths_ft()[1]);
+
+        let mut empty = test_chart();
+        empty.capacity_data = CapacityData::new();
+        assert!(matches!(CompiledLoadChart::compile(&empty), Err(LoadChartError::NoData)));
+        empty.capacity_data.boom_lengths = vec![LengthValue::new(100.0, "ft")];
+        assert!(matches!(CompiledLoadChart::compile(&empty), Err(LoadChartError::NoData)));
+        empty.capacity_data.data = vec![Vec::new()];
+        assert!(matches!(CompiledLoadChart::compile(&empty), Err(LoadChartError::NoData)));
+    }
+
+    #[test]
+    fn test_policy_matches_load_chart() {
+        let chart = test_chart();
+        let compiled = CompiledLoadChart::compile(&chart).unwrap();
+
+        for policy in [OutOfRangePolicy::ClampToNearest, OutOfRangePolicy::ZeroCapacity] {
+            for (boom, radius) in [(90.0, 40.0), (125.0, 10.0), (100.0, 95.0), (170.0, 140.0)] {
+                let boom = Length::new::<foot>(boom);
+                let radius = Length::new::<foot>(radius);
+                let expected = chart.capacity_with_policy(boom, radius, policy).unwrap();
+                let actual = compiled.capacity_with_policy(boom, radius, policy).unwrap();
+                assert_relative_eq!(actual.get::<pound>(), expected.get::<pound>(), epsilon = 1e-6);
+            }
+        }
+    }
+}
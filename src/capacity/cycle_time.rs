@@ -0,0 +1,259 @@
+//! Lift cycle time and productivity for a pick list
+//!
+//! Capacity says whether a crane can make the picks; cycle time says how
+//! long they take. Each pick is one cycle from a fixed crane position:
+//!
+//! 1. Rig the load at the pick point
+//! 2. Hoist to clear the higher of the pick and set points
+//! 3. Swing, and travel in or out on the radius
+//! 4. Lower, land and unhook at the set point
+//! 5. Return the empty hook the same way
+//!
+//! Hook speed is the single-line speed over the parts of line. Motions run
+//! one after another unless swing and radius travel are combined, when the
+//! longer of the two sets the time.
+
+use crate::capacity::placement::Pick;
+use crate::types::*;
+use nalgebra as na;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Crane motion speeds and handling times
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CraneSpeeds {
+    /// Single-line speed at the drum
+    pub line_speed: Velocity,
+    pub parts_of_line: u32,
+    pub swing_speed: AngularVelocity,
+
+    /// Hook travel in and out on the radius, by trolley or luffing
+    pub radius_speed: Velocity,
+
+    /// Hooking up at the pick point
+    pub rigging_time: Time,
+
+    /// Landing and unhooking at the set point
+    pub landing_time: Time,
+
+    /// Hook height over the higher of the pick and set points while swinging
+    pub clearance: Length,
+
+    /// Swing and radius travel at the same time
+    pub combined_motions: bool,
+}
+
+impl CraneSpeeds {
+    /// 60 ft/min radius travel, 5 minutes each to rig and land, 10 ft
+    /// clearance, motions one at a time
+    pub fn new(line_speed: Velocity, parts_of_line: u32, swing_speed: AngularVelocity) -> Self {
+        Self {
+            line_speed,
+            parts_of_line,
+            swing_speed,
+            radius_speed: Velocity::new::<foot_per_minute>(60.0),
+            rigging_time: Time::new::<minute>(5.0),
+            landing_time: Time::new::<minute>(5.0),
+            clearance: Length::new::<foot>(10.0),
+            combined_motions: false,
+        }
+    }
+
+    pub fn with_radius_speed(mut self, speed: Velocity) -> Self {
+        self.radius_speed = speed;
+        self
+    }
+
+    pub fn with_handling(mut self, rigging: Time, landing: Time) -> Self {
+        self.rigging_time = rigging;
+        self.landing_time = landing;
+        self
+    }
+
+    pub fn with_clearance(mut self, clearance: Length) -> Self {
+        self.clearance = clearance;
+        self
+    }
+
+    pub fn with_combined_motions(mut self) -> Self {
+        self.combined_motions = true;
+        self
+    }
+
+    /// Hook speed: line speed over the parts of line
+    pub fn hook_speed(&self) -> Velocity {
+        self.line_speed / self.parts_of_line.max(1) as f64
+    }
+
+    /// Time for one pick from a crane with its slew center at `position`
+    pub fn cycle(&self, position: na::Point3<f64>, pick: &Pick) -> PickCycle {
+        let plan = |p: &na::Point3<f64>| na::Vector2::new(p.x - position.x, p.z - position.z);
+        let (from, to) = (plan(&pick.pick_point), plan(&pick.set_point));
+        let swing = if from.norm() > 1e-9 && to.norm() > 1e-9 {
+            from.perp(&to).atan2(from.dot(&to)).abs()
+        } else {
+            0.0
+        };
+        let radius_travel = (to.norm() - from.norm()).abs();
+        let top = pick.pick_point.y.max(pick.set_point.y) + self.clearance.get::<foot>();
+        let hoist_distance = 2.0 * ((top - pick.pick_point.y) + (top - pick.set_point.y));
+
+        // Minutes; each motion there and back
+        let hoist = hoist_distance / self.hook_speed().get::<foot_per_minute>();
+        let swing = 2.0 * swing / self.swing_speed.get::<radian_per_second>() / 60.0;
+        let travel = 2.0 * radius_travel / self.radius_speed.get::<foot_per_minute>();
+        let handling = (self.rigging_time + self.landing_time).get::<minute>();
+        let moving = if self.combined_motions { swing.max(travel) } else { swing + travel };
+
+        let minutes = Time::new::<minute>;
+        PickCycle {
+            pick: pick.name.clone(),
+            hoist_distance: Length::new::<foot>(hoist_distance),
+            hoist_time: minutes(hoist),
+            swing_time: minutes(swing),
+            travel_time: minutes(travel),
+            handling_time: minutes(handling),
+            cycle_time: minutes(hoist + moving + handling),
+        }
+    }
+
+    /// Cycle times for a pick list from one crane position
+    pub fn estimate(&self, name: impl Into<String>, position: na::Point3<f64>, picks: &[Pick]) -> CycleEstimate {
+        CycleEstimate {
+            name: name.into(),
+            position,
+            cycles: picks.iter().map(|pick| self.cycle(position, pick)).collect(),
+        }
+    }
+}
+
+/// One pick's cycle, there and back
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PickCycle {
+    pub pick: String,
+
+    /// Hook travel up and down, loaded and empty
+    pub hoist_distance: Length,
+    pub hoist_time: Time,
+    pub swing_time: Time,
+    pub travel_time: Time,
+    pub handling_time: Time,
+
+    /// Whole cycle; less than the sum of the motions when swing and travel
+    /// are combined
+    pub cycle_time: Time,
+}
+
+/// Every pick on the list for one crane option, in list order
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CycleEstimate {
+    pub name: String,
+
+    /// Crane slew center at ground level (feet)
+    pub position: na::Point3<f64>,
+    pub cycles: Vec<PickCycle>,
+}
+
+impl CycleEstimate {
+    pub fn total_time(&self) -> Time {
+        self.cycles.iter().fold(Time::new::<minute>(0.0), |sum, c| sum + c.cycle_time)
+    }
+
+    /// Average picks per hour over the list
+    pub fn picks_per_hour(&self) -> f64 {
+        let hours = self.total_time().get::<hour>();
+        if hours > 0.0 { self.cycles.len() as f64 / hours } else { 0.0 }
+    }
+
+    /// Pick with the longest cycle
+    pub fn slowest(&self) -> Option<&PickCycle> {
+        self.cycles.iter().max_by(|a, b| a.cycle_time.value.total_cmp(&b.cycle_time.value))
+    }
+
+    pub fn cycle(&self, pick: &str) -> Option<&PickCycle> {
+        self.cycles.iter().find(|c| c.pick == pick)
+    }
+}
+
+/// The option that gets through its pick list soonest
+pub fn fastest(estimates: &[CycleEstimate]) -> Option<&CycleEstimate> {
+    estimates.iter().min_by(|a, b| a.total_time().value.total_cmp(&b.total_time().value))
+}
+
+impl fmt::Display for CycleEstimate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.name)?;
+        writeln!(
+            f,
+            "{:<16} {:>9} {:>9} {:>9} {:>9} {:>9}",
+            "Pick", "Hoist min", "Swing min", "Trav min", "Hand min", "Cycle min"
+        )?;
+        writeln!(f, "{}", "─".repeat(66))?;
+
+        for cycle in &self.cycles {
+            writeln!(
+                f,
+                "{:<16} {:>9.1} {:>9.1} {:>9.1} {:>9.1} {:>9.1}",
+                cycle.pick,
+                cycle.hoist_time.get::<minute>(),
+                cycle.swing_time.get::<minute>(),
+                cycle.travel_time.get::<minute>(),
+                cycle.handling_time.get::<minute>(),
+                cycle.cycle_time.get::<minute>(),
+            )?;
+        }
+        writeln!(
+            f,
+            "Total {:.1} h, {:.1} picks/h",
+            self.total_time().get::<hour>(),
+            self.picks_per_hour()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_cycle_time_and_comparison() {
+        let pick = Pick {
+            name: "AHU-1".into(),
+            load: Mass::new::<pound>(12_000.0),
+            pick_point: na::Point3::new(30.0, 0.0, 0.0),
+            set_point: na::Point3::new(0.0, 40.0, 50.0),
+        };
+        let fast = CraneSpeeds::new(
+            Velocity::new::<foot_per_minute>(400.0),
+            4,
+            AngularVelocity::new::<revolution_per_minute>(1.0),
+        )
+        .with_radius_speed(Velocity::new::<foot_per_minute>(40.0));
+
+        // 100 ft/min hook over 120 ft, 90° swing at 1 rpm and 20 ft of
+        // radius travel each way, plus 10 minutes handling
+        let cycle = fast.cycle(na::Point3::origin(), &pick);
+        assert_relative_eq!(cycle.hoist_distance.get::<foot>(), 120.0, epsilon = 1e-9);
+        assert_relative_eq!(cycle.hoist_time.get::<minute>(), 1.2, epsilon = 1e-9);
+        assert_relative_eq!(cycle.swing_time.get::<minute>(), 0.5, epsilon = 1e-9);
+        assert_relative_eq!(cycle.travel_time.get::<minute>(), 1.0, epsilon = 1e-9);
+        assert_relative_eq!(cycle.cycle_time.get::<minute>(), 12.7, epsilon = 1e-9);
+
+        let combined = fast.with_combined_motions().cycle(na::Point3::origin(), &pick);
+        assert_relative_eq!(combined.cycle_time.get::<minute>(), 12.2, epsilon = 1e-9);
+
+        let slow = CraneSpeeds { parts_of_line: 8, ..fast };
+        let estimates = [
+            slow.estimate("8 parts", na::Point3::origin(), std::slice::from_ref(&pick)),
+            fast.estimate("4 parts", na::Point3::origin(), &[pick]),
+        ];
+        assert_eq!(fastest(&estimates).unwrap().name, "4 parts");
+        assert_relative_eq!(estimates[1].picks_per_hour(), 60.0 / 12.7, max_relative = 1e-9);
+        assert!(estimates[0].to_string().contains("picks/h"));
+    }
+}
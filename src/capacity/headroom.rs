@@ -0,0 +1,246 @@
+//! Height-under-hook budget
+//!
+//! Boom tip height is not lift height. Between the tip and the set
+//! elevation hang the two-block clearance, the hook block, the rigging and
+//! the load itself. Forgetting one of these is how lifts get planned on a
+//! boom that can't get the load over the wall.
+//!
+//! ```text
+//!   boom tip
+//!     | two-block clearance
+//!   top of hook block       <- hook height
+//!     | hook block
+//!   hook saddle
+//!     | rigging (hardware, slings, spreader)
+//!   top of load
+//!     | load height
+//!   underside of load
+//!     | clearance (must be >= 0)
+//!   set elevation
+//! ```
+
+use crate::equipment::HookBlock;
+use crate::types::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, thiserror::Error)]
+pub enum HeadroomError {
+    #[error("Sling of length {length} can't reach a pick point {offset} off center")]
+    SlingTooShort {
+        length: DisplayLength,
+        offset: DisplayLength,
+    },
+}
+
+/// One element of the rigging between hook and load
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RiggingElement {
+    /// Rigid element: shackle, master link, spreader bar depth
+    Rigid { name: String, height: Length },
+
+    /// Sling legs from a common point to pick points offset horizontally
+    Sling {
+        length: Length,
+        horizontal_offset: Length,
+    },
+}
+
+impl RiggingElement {
+    pub fn rigid(name: impl Into<String>, height: Length) -> Self {
+        RiggingElement::Rigid {
+            name: name.into(),
+            height,
+        }
+    }
+
+    pub fn sling(length: Length, horizontal_offset: Length) -> Self {
+        RiggingElement::Sling {
+            length,
+            horizontal_offset,
+        }
+    }
+
+    /// Vertical height taken up by this element
+    pub fn vertical_height(&self) -> Result<Length, HeadroomError> {
+        match self {
+            RiggingElement::Rigid { height, .. } => Ok(*height),
+            RiggingElement::Sling {
+                length,
+                horizontal_offset,
+            } => {
+                let (l, h) = (length.get::<foot>(), horizontal_offset.get::<foot>());
+                if h >= l {
+                    return Err(HeadroomError::SlingTooShort {
+                        length: DisplayLength(*length),
+                        offset: DisplayLength(*horizontal_offset),
+                    });
+                }
+                Ok(Length::new::<foot>((l * l - h * h).sqrt()))
+            }
+        }
+    }
+}
+
+/// Everything that hangs below the boom tip, and where it has to go
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HeadroomBudget {
+    /// Minimum clearance between boom tip and top of hook block
+    pub two_block_clearance: Length,
+
+    /// Top of block to hook saddle
+    pub hook_block_length: Length,
+
+    /// Rigging from hook saddle to the top of the load, top down
+    pub rigging: Vec<RiggingElement>,
+
+    /// Top of load (rigging points) to its underside
+    pub load_height: Length,
+
+    /// Elevation the underside of the load must clear
+    pub set_elevation: Length,
+
+    /// Clearance below which the lift is flagged as tight
+    pub min_clearance: Length,
+}
+
+impl HeadroomBudget {
+    /// Budget with no hook block or rigging, 2 ft two-block and minimum clearances
+    pub fn new(load_height: Length, set_elevation: Length) -> Self {
+        Self {
+            two_block_clearance: Length::new::<foot>(2.0),
+            hook_block_length: Length::new::<foot>(0.0),
+            rigging: Vec::new(),
+            load_height,
+            set_elevation,
+            min_clearance: Length::new::<foot>(2.0),
+        }
+    }
+
+    pub fn with_hook_block(mut self, block: &HookBlock) -> Self {
+        self.hook_block_length = block.length;
+        self
+    }
+
+    pub fn with_rigging(mut self, element: RiggingElement) -> Self {
+        self.rigging.push(element);
+        self
+    }
+
+    /// Total vertical height of the rigging
+    pub fn rigging_height(&self) -> Result<Length, HeadroomError> {
+        self.rigging
+            .iter()
+            .try_fold(Length::new::<foot>(0.0), |sum, e| Ok(sum + e.vertical_height()?))
+    }
+
+    /// Hook block, rigging and load, top of block to underside of load
+    pub fn stack_height(&self) -> Result<Length, HeadroomError> {
+        Ok(self.hook_block_length + self.rigging_height()? + self.load_height)
+    }
+
+    /// Highest hook position for a given boom tip height
+    pub fn max_hook_height(&self, tip_height: Length) -> Length {
+        tip_height - self.two_block_clearance
+    }
+
+    /// Hook height needed to just clear the set elevation with `min_clearance`
+    pub fn required_hook_height(&self) -> Result<Length, HeadroomError> {
+        Ok(self.set_elevation + self.min_clearance + self.stack_height()?)
+    }
+
+    /// Boom tip height needed to just clear the set elevation with `min_clearance`
+    pub fn required_tip_height(&self) -> Result<Length, HeadroomError> {
+        Ok(self.required_hook_height()? + self.two_block_clearance)
+    }
+
+    /// Budget for a hook at `hook_height` (top of hook block)
+    pub fn analyze(&self, hook_height: Length) -> Result<HeadroomAnalysis, HeadroomError> {
+        let underside_of_load = hook_height - self.stack_height()?;
+        let clearance = underside_of_load - self.set_elevation;
+        Ok(HeadroomAnalysis {
+            hook_height,
+            underside_of_load,
+            clearance,
+            shortfall: self.required_hook_height()? - hook_height,
+            min_clearance: self.min_clearance,
+        })
+    }
+}
+
+/// Result of a headroom budget for one hook height
+#[derive(Debug, Clone)]
+pub struct HeadroomAnalysis {
+    pub hook_height: Length,
+
+    /// Elevation of the underside of the load
+    pub underside_of_load: Length,
+
+    /// Underside of load above the set elevation; negative if it can't clear
+    pub clearance: Length,
+
+    /// Extra hook height needed to reach `min_clearance`; negative if there's spare
+    pub shortfall: Length,
+
+    min_clearance: Length,
+}
+
+impl HeadroomAnalysis {
+    /// The load clears the set elevation
+    pub fn clears(&self) -> bool {
+        self.clearance >= Length::new::<foot>(0.0)
+    }
+
+    /// The load clears with at least the budget's minimum clearance
+    pub fn has_margin(&self) -> bool {
+        self.clearance >= self.min_clearance
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::equipment::hook_blocks;
+    use approx::assert_relative_eq;
+
+    fn budget() -> HeadroomBudget {
+        // 7 ft block, 2 ft shackles, 13 ft slings 5 ft off center (12 ft tall), 3 ft spreader, 10 ft load
+        HeadroomBudget::new(Length::new::<foot>(10.0), Length::new::<foot>(80.0))
+            .with_hook_block(&hook_blocks::five_sheave_100t())
+            .with_rigging(RiggingElement::rigid("Shackles", Length::new::<foot>(2.0)))
+            .with_rigging(RiggingElement::sling(Length::new::<foot>(13.0), Length::new::<foot>(5.0)))
+            .with_rigging(RiggingElement::rigid("Spreader", Length::new::<foot>(3.0)))
+    }
+
+    #[test]
+    fn test_stack_height() {
+        let budget = budget();
+        assert_relative_eq!(budget.rigging_height().unwrap().get::<foot>(), 17.0, epsilon = 1e-9);
+        assert_relative_eq!(budget.stack_height().unwrap().get::<foot>(), 34.0, epsilon = 1e-9);
+        // 80 set + 2 clearance + 34 stack + 2 two-block
+        assert_relative_eq!(budget.required_tip_height().unwrap().get::<foot>(), 118.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_analyze() {
+        let budget = budget();
+
+        let tight = budget.analyze(budget.max_hook_height(Length::new::<foot>(117.0))).unwrap();
+        assert!(tight.clears());
+        assert!(!tight.has_margin());
+        assert_relative_eq!(tight.shortfall.get::<foot>(), 1.0, epsilon = 1e-9);
+
+        let short = budget.analyze(Length::new::<foot>(110.0)).unwrap();
+        assert!(!short.clears());
+        assert_relative_eq!(short.clearance.get::<foot>(), -4.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_sling_too_short() {
+        let budget = HeadroomBudget::new(Length::new::<foot>(10.0), Length::new::<foot>(0.0))
+            .with_rigging(RiggingElement::sling(Length::new::<foot>(4.0), Length::new::<foot>(5.0)));
+        assert!(matches!(budget.analyze(Length::new::<foot>(50.0)), Err(HeadroomError::SlingTooShort { .. })));
+    }
+}
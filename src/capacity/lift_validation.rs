@@ -7,13 +7,36 @@
 //! - Rigging adequacy
 //! - Stability margins
 //! - Configuration validity
+//! - Headroom under the hook
+//! - Work-area limits
+//! - Boom side load
+//! - Cold-weather limits
+//! - Duty-cycle derating
 
-use crate::equipment::CraneType;
-use crate::physics::{WindAnalysis, WindCondition};
-use crate::{equipment::Crane, physics::ground_bearing::*, types::*};
+use crate::capacity::headroom::HeadroomBudget;
+use crate::equipment::DutyClass;
+use crate::kinematics::WorkArea;
+use crate::rigging::{
+    BridleLegGeometry, DerivedLegAngle, Hardware, LegSharing, RopeService, Sling, SlingMaterial, derive_leg_angles,
+};
+use crate::physics::{
+    OffshoreConditions, SideLoadConditions, SoilCapacity, SoilConditions, TemperatureAssessment, TemperatureRules,
+    WindAnalysis, WindCondition,
+};
+use crate::{
+    equipment::{Crane, Kinematic, RatedCapacity, StabilityAnalyzed, check_boom_angle},
+    physics::ground_bearing::*,
+    types::*,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+pub use crate::compliance::SafetyFactors;
+pub use crate::report::{AnalysisReport, CheckStatus, NumericResult, ValidationCheck};
 
 /// A complete lift plan for validation
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LiftPlan {
     /// Load weight
     pub load_weight: Mass,
@@ -30,11 +53,119 @@ pub struct LiftPlan {
     /// Environmental conditions
     pub environment: EnvironmentalConditions,
     
-    /// Safety factors to apply
+    /// Safety policy and the standard the lift is planned under
     pub safety_factors: SafetyFactors,
+
+    /// Hook block, rigging and set elevation, if headroom is to be checked
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub headroom: Option<HeadroomBudget>,
+
+    /// Job-site operating envelope, if one is set up
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub work_area: Option<WorkArea>,
+
+    /// Rope leads and tagline pull, if side load is to be checked
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub side_load: Option<SideLoadConditions>,
+
+    /// Clamshell, dragline and similar duty derate the chart
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub duty: DutyClass,
+}
+
+impl LiftPlan {
+    /// Dynamic amplification factor for this lift, on everything below the
+    /// boom tip: payload, rigging, hook block and hoist rope
+    ///
+    /// 1.0 unless offshore conditions are specified.
+    pub fn dynamic_amplification_factor<C: RatedCapacity>(&self, crane: &C) -> f64 {
+        self.environment
+            .offshore
+            .map(|offshore| offshore.dynamic_amplification_factor(self.suspended_load(crane)))
+            .unwrap_or(1.0)
+    }
+
+    /// Payload plus the rigging listed in the plan
+    pub fn hook_load(&self) -> Mass {
+        self.load_weight + self.rigging.total_weight()
+    }
+
+    /// Hook load including dynamic amplification
+    pub fn dynamic_hook_load<C: RatedCapacity>(&self, crane: &C) -> Mass {
+        self.hook_load() * self.dynamic_amplification_factor(crane)
+    }
+
+    /// Everything below the boom tip: the hook load plus the crane's own
+    /// deductions (hook block, hoist rope), all rated against gross chart
+    pub fn suspended_load<C: RatedCapacity>(&self, crane: &C) -> Mass {
+        self.hook_load() + crane.deductions().total()
+    }
+
+    /// Suspended load including dynamic amplification
+    pub fn dynamic_suspended_load<C: RatedCapacity>(&self, crane: &C) -> Mass {
+        self.suspended_load(crane) * self.dynamic_amplification_factor(crane)
+    }
+
+    /// Wind on the crane as configured and the load
+    pub fn wind_analysis<C: Kinematic>(&self, crane: &C) -> WindAnalysis {
+        let config = crane.configuration();
+        WindAnalysis::new(
+            crane.crane_type(),
+            config.boom_length,
+            config.boom_angle,
+            self.load_dimensions.sail_area(),
+            self.environment.wind_speed,
+        )
+    }
+
+    /// Allowable ground bearing under the mats, after site conditions and
+    /// the policy's safety factor
+    pub fn allowable_bearing(&self) -> Pressure {
+        let soil = self.ground.conditions.apply(self.ground.soil_type.bearing_capacity_on(self.ground.mat_area));
+        self.safety_factors.allowable_bearing(soil)
+    }
+
+    /// Reactions under the crane's supports with the suspended load,
+    /// dynamically amplified, on the hook, each checked against the
+    /// allowable bearing under the policy
+    ///
+    /// `None` for cranes without a support model.
+    pub fn ground_bearing<C: StabilityAnalyzed + RatedCapacity>(
+        &self,
+        crane: &C,
+    ) -> Option<Result<GroundBearingResult, GroundBearingError>> {
+        let daf = self.dynamic_amplification_factor(crane);
+        crane
+            .ground_bearing_analysis(self.suspended_load(crane), Some(self.ground.mat_area))
+            .map(|analysis| analysis.with_dynamic_factor(daf).evaluate(self.allowable_bearing()))
+    }
+
+    /// Duty of the plan or the crane, whichever derates more
+    pub fn duty_class<C: RatedCapacity>(&self, crane: &C) -> DutyClass {
+        let crane_duty = crane.duty_class();
+        if crane_duty.capacity_factor() < self.duty.capacity_factor() { crane_duty } else { self.duty }
+    }
+
+    /// Chart capacity factor from cold weather and duty cycle together
+    pub fn capacity_factor<C: RatedCapacity>(&self, crane: &C) -> f64 {
+        self.temperature_assessment().capacity_factor * self.duty_class(crane).capacity_factor()
+    }
+
+    /// Class of the lift by the share of chart it uses
+    pub fn lift_class<C: RatedCapacity>(&self, crane: &C) -> LiftClass {
+        let chart = crane.rated_capacity() * self.capacity_factor(crane);
+        self.safety_factors.lift_class((self.dynamic_suspended_load(crane) / chart).value * 100.0)
+    }
+
+    /// Cold-weather rules applied to the lift temperature and rigging
+    pub fn temperature_assessment(&self) -> TemperatureAssessment {
+        let synthetic = self.rigging.hardware.iter().any(RiggingHardware::is_synthetic_sling);
+        self.environment.temperature_rules.assess(self.environment.temperature, synthetic)
+    }
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LoadDimensions {
     pub length: Length,
     pub width: Length,
@@ -58,12 +189,35 @@ impl LoadDimensions {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RiggingConfiguration {
     pub configuration: RiggingConfig,
     pub hardware: Vec<RiggingHardware>,
 }
 
+impl RiggingConfiguration {
+    /// Weight of each rigging item, leaving out weightless ones
+    ///
+    /// The hook block isn't rigging: it comes from the crane's
+    /// [`CapacityDeductions`](crate::equipment::CapacityDeductions).
+    pub fn weight_breakdown(&self) -> Vec<(String, Mass)> {
+        self.hardware
+            .iter()
+            .filter(|h| h.weight > Mass::new::<pound>(0.0))
+            .map(|h| (h.description.clone(), h.weight))
+            .collect()
+    }
+
+    /// Slings, shackles and spreaders together
+    pub fn total_weight(&self) -> Mass {
+        self.hardware
+            .iter()
+            .fold(Mass::new::<pound>(0.0), |sum, h| sum + h.weight)
+    }
+}
+
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum RiggingConfig {
     /// Single vertical hitch
     Vertical,
@@ -74,25 +228,122 @@ pub enum RiggingConfig {
     /// Basket hitch
     Basket,
     
-    /// Bridle with specified angles
-    Bridle { leg_angle: Angle, num_legs: usize },
+    /// Bridle with specified angles, rated on the legs assumed to share the load
+    Bridle {
+        leg_angle: Angle,
+        num_legs: usize,
+        #[cfg_attr(feature = "serde", serde(default))]
+        sharing: LegSharing,
+    },
+
+    /// Bridle with leg angles derived from the attachments and sling
+    /// lengths, hook over the load CG at `hook_height` above it
+    BridleGeometry {
+        legs: Vec<BridleLegGeometry>,
+        hook_height: Length,
+        #[cfg_attr(feature = "serde", serde(default))]
+        sharing: LegSharing,
+    },
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RiggingHardware {
+    /// Label for reports, e.g. "Shackle"
     pub item_type: String,
+
+    /// What the item is, for checks that depend on it
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub kind: RiggingKind,
     pub capacity: Mass,
     pub description: String,
+
+    /// Item weight, added to the hook load; leave it out of the crane's
+    /// deductions so it isn't counted twice
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub weight: Mass,
+}
+
+/// Kind of rigging item
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RiggingKind {
+    WireRopeSling,
+    SyntheticSling,
+    ChainSling,
+
+    /// Shackles, hooks, links and other fittings
+    Hardware,
+
+    /// Not recorded
+    #[default]
+    Other,
+}
+
+impl RiggingKind {
+    /// Design factor the item's rated capacity is typically given at:
+    /// 5:1 for wire rope (ASME B30.9) and synthetic slings and for
+    /// hardware, 4:1 for alloy chain
+    pub fn rated_design_factor(&self) -> f64 {
+        match self {
+            RiggingKind::WireRopeSling => RopeService::Sling.design_factor(),
+            RiggingKind::ChainSling => 4.0,
+            RiggingKind::SyntheticSling | RiggingKind::Hardware | RiggingKind::Other => 5.0,
+        }
+    }
+}
+
+impl RiggingHardware {
+    pub fn is_synthetic_sling(&self) -> bool {
+        self.kind == RiggingKind::SyntheticSling
+    }
+}
+
+impl From<&Sling> for RiggingHardware {
+    /// Rated at the sling's vertical capacity
+    fn from(sling: &Sling) -> Self {
+        let (item_type, kind) = match sling.material {
+            SlingMaterial::WireRope { .. } => ("Wire Rope Sling", RiggingKind::WireRopeSling),
+            SlingMaterial::Synthetic { .. } => ("Synthetic Sling", RiggingKind::SyntheticSling),
+            SlingMaterial::Chain { .. } => ("Chain Sling", RiggingKind::ChainSling),
+        };
+        Self {
+            item_type: item_type.into(),
+            kind,
+            capacity: sling.rated_capacity,
+            description: sling.description(),
+            weight: Mass::new::<pound>(0.0),
+        }
+    }
+}
+
+impl From<&Hardware> for RiggingHardware {
+    /// Hardware records carry no weight; set it on the result if known
+    fn from(hardware: &Hardware) -> Self {
+        Self {
+            item_type: hardware.hardware_type.category().into(),
+            kind: RiggingKind::Hardware,
+            capacity: hardware.rated_capacity,
+            description: hardware.description(),
+            weight: Mass::new::<pound>(0.0),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GroundConditions {
     pub soil_type: SoilType,
     pub mat_area: Area,
+
+    /// Saturation, frost and slopes that reduce the soil capacity
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub conditions: SoilConditions,
     pub notes: String,
 }
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SoilType {
     SoftClay,
     MediumClay,
@@ -103,10 +354,18 @@ pub enum SoilType {
     Rock,
     Paved,
     Custom(Pressure),  // PSI
+    /// From soil class or field tests
+    Site(SoilCapacity),
 }
 
 impl SoilType {
+    /// Capacity under a 4 ft square pad
     pub fn bearing_capacity(&self) -> Pressure {
+        self.bearing_capacity_on(Area::new::<square_foot>(16.0))
+    }
+
+    /// Capacity under a square mat of `mat_area`; only site soils depend on the size
+    pub fn bearing_capacity_on(&self, mat_area: Area) -> Pressure {
         match self {
             SoilType::SoftClay => soil_capacities::soft_clay(),
             SoilType::MediumClay => soil_capacities::medium_clay(),
@@ -116,52 +375,77 @@ impl SoilType {
             SoilType::Gravel => soil_capacities::gravel(),
             SoilType::Rock => soil_capacities::hard_rock(),
             SoilType::Paved => soil_capacities::paved_surface(),
-            SoilType::Custom(press) => Pressure::new::<psi>(press.get::<psi>())
+            SoilType::Custom(press) => Pressure::new::<psi>(press.get::<psi>()),
+            SoilType::Site(soil) => {
+                soil.allowable_bearing(Length::new::<foot>(mat_area.get::<square_foot>().sqrt()))
+            }
         }
     }
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EnvironmentalConditions {
     pub wind_speed: Velocity,
-    pub temperature: f64,
+    pub temperature: ThermodynamicTemperature,
+
+    /// Cold-weather derating and equipment limits
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub temperature_rules: TemperatureRules,
     pub visibility: String,
     pub notes: String,
-}
 
-#[derive(Debug, Clone)]
-pub struct SafetyFactors {
-    /// Capacity safety factor (typically 1.0, already in load charts)
-    pub capacity: f64,
-    
-    /// Ground bearing safety factor (typically 2.0)
-    pub ground_bearing: f64,
-    
-    /// Rigging safety factor (typically 5:1 minimum)
-    pub rigging: f64,
+    /// Sea state and crane tip motion for lifts from a vessel
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub offshore: Option<OffshoreConditions>,
 }
 
-impl Default for SafetyFactors {
-    fn default() -> Self {
-        Self {
-            capacity: 1.0,
-            ground_bearing: 2.0,
-            rigging: 5.0,
-        }
-    }
+/// Lift classes with their own required capacity margin
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LiftClass {
+    Routine,
+
+    /// Needs a written critical lift plan
+    Critical,
 }
 
 /// Result of lift validation
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ValidationReport {
     pub overall_status: ValidationStatus,
     pub checks: Vec<ValidationCheck>,
     pub warnings: Vec<String>,
     pub critical_issues: Vec<String>,
     pub recommendations: Vec<String>,
+
+    /// What the validation was run on, to show later that a saved report
+    /// matches its inputs
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub audit: Option<AuditRecord>,
+}
+
+/// When and on what inputs a validation was run
+///
+/// Hashes are SHA-256 of the canonical JSON, as for chart provenance.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AuditRecord {
+    /// Since the Unix epoch
+    pub timestamp: Time,
+    pub crate_version: String,
+    pub chart_revision: Option<String>,
+
+    /// Content hash of the chart package used
+    pub chart_hash: Option<String>,
+
+    /// Hash of the lift plan, crane and chart package together
+    pub input_hash: String,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ValidationStatus {
     /// All checks passed
     Approved,
@@ -173,20 +457,6 @@ pub enum ValidationStatus {
     Rejected,
 }
 
-#[derive(Debug, Clone)]
-pub struct ValidationCheck {
-    pub name: String,
-    pub status: CheckStatus,
-    pub details: String,
-    pub margin: Option<f64>,  // Percentage margin (if applicable)
-}
-
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum CheckStatus {
-    Pass,
-    Warning,
-    Fail,
-}
 
 impl ValidationReport {
     pub fn new() -> Self {
@@ -196,8 +466,15 @@ impl ValidationReport {
             warnings: Vec::new(),
             critical_issues: Vec::new(),
             recommendations: Vec::new(),
+            audit: None,
         }
     }
+
+    /// Attach the audit record of the run
+    pub fn with_audit(mut self, audit: AuditRecord) -> Self {
+        self.audit = Some(audit);
+        self
+    }
     
     pub fn add_check(&mut self, check: ValidationCheck) {
         match check.status {
@@ -216,6 +493,23 @@ impl ValidationReport {
         self.checks.push(check);
     }
     
+    /// Check by name, e.g. "Capacity"
+    pub fn check(&self, name: &str) -> Option<&ValidationCheck> {
+        self.checks.iter().find(|c| c.name == name)
+    }
+
+    /// Add every check of a sub-analysis, named "`title: check`"
+    pub fn add_analysis(&mut self, analysis: &dyn AnalysisReport) {
+        let title = analysis.title();
+        for check in analysis.checks() {
+            self.add_check(ValidationCheck {
+                name: format!("{}: {}", title, check.name),
+                ..check
+            });
+        }
+        self.recommendations.extend(analysis.recommendations());
+    }
+    
     pub fn add_recommendation(&mut self, rec: String) {
         self.recommendations.push(rec);
     }
@@ -278,20 +572,53 @@ impl ValidationReport {
                 println!("  • {}", rec);
             }
         }
+
+        if let Some(audit) = &self.audit {
+            println!("\nAudit: crate {}, plan {}", audit.crate_version, audit.input_hash);
+            if let Some(hash) = &audit.chart_hash {
+                println!("       chart {} {}", audit.chart_revision.as_deref().unwrap_or("(no revision)"), hash);
+            }
+        }
         
         println!("\n{}", "═".repeat(50));
     }
 }
 
+impl AnalysisReport for ValidationReport {
+    fn title(&self) -> String {
+        "Lift Validation".into()
+    }
+
+    fn checks(&self) -> Vec<ValidationCheck> {
+        self.checks.clone()
+    }
+
+    /// Margin of each check that has one
+    fn results(&self) -> Vec<NumericResult> {
+        self.checks
+            .iter()
+            .filter_map(|c| Some(NumericResult::new(format!("{} margin", c.name), c.margin?, "%")))
+            .collect()
+    }
+
+    fn recommendations(&self) -> Vec<String> {
+        self.recommendations.clone()
+    }
+}
+
 /// Validate a complete lift plan
+///
+/// Each check only asks for the capability it uses, so e.g. the ground
+/// bearing check needs [`StabilityAnalyzed`] but not a load chart.
 pub fn validate_lift<C: Crane>(
     crane: &C,
     plan: &LiftPlan,
 ) -> ValidationReport {
     let mut report = ValidationReport::new();
+    let temperature = plan.temperature_assessment();
     
     // 1. Capacity check
-    validate_capacity(crane, plan, &mut report);
+    validate_capacity(crane, plan, &temperature, &mut report);
     
     // 2. Wind check
     validate_wind(crane, plan, &mut report);
@@ -304,67 +631,146 @@ pub fn validate_lift<C: Crane>(
     
     // 5. Configuration check
     validate_configuration(crane, plan, &mut report);
+
+    // 6. Boom angle check
+    validate_boom_angle(crane, &mut report);
+    
+    // 7. Headroom check
+    validate_headroom(crane, plan, &mut report);
+    
+    // 8. Work area check
+    validate_work_area(crane, plan, &mut report);
+
+    // 9. Side load check
+    validate_side_load(crane, plan, &mut report);
+
+    // 10. Cold-weather check
+    report.add_analysis(&temperature);
     
     report
 }
 
-fn validate_capacity<C: Crane>(
+fn validate_capacity<C: Kinematic + RatedCapacity>(
     crane: &C,
     plan: &LiftPlan,
+    temperature: &TemperatureAssessment,
     report: &mut ValidationReport,
 ) {
     let config = crane.configuration();
     let rated_capacity = crane.rated_capacity();
     
-    let capacity_lb = rated_capacity.get::<pound>();
-    let load_lb = plan.load_weight.get::<pound>();
+    let policy = &plan.safety_factors;
+    let temperature_factor = temperature.capacity_factor;
+    let duty = plan.duty_class(crane);
+    let factor = temperature_factor * duty.capacity_factor();
+    let capacity_lb = policy.allowable_capacity(rated_capacity).get::<pound>() * factor;
+    let load_lb = plan.dynamic_suspended_load(crane).get::<pound>();
+    let daf = plan.dynamic_amplification_factor(crane);
     let margin = ((capacity_lb - load_lb) / capacity_lb) * 100.0;
+    // Same basis as `LiftPlan::lift_class`
+    let class = policy.lift_class(load_lb / (rated_capacity.get::<pound>() * factor) * 100.0);
+    let required_margin = policy.required_margin(class);
     
     let status = if load_lb > capacity_lb {
         CheckStatus::Fail
-    } else if margin < 10.0 {
+    } else if margin < required_margin {
         CheckStatus::Warning
     } else {
         CheckStatus::Pass
     };
     
+    let load_details = if daf > 1.0 {
+        format!("Load: {:.0} lbs (DAF {:.2})", load_lb, daf)
+    } else {
+        format!("Load: {:.0} lbs", load_lb)
+    };
+    let mut deratings = Vec::new();
+    if temperature_factor < 1.0 {
+        deratings.push(format!("cold derating {:.2}", temperature_factor));
+    }
+    if duty.is_derated() {
+        deratings.push(format!("{} duty {:.2}", duty, duty.capacity_factor()));
+    }
+    let rated_details = if deratings.is_empty() {
+        format!("Rated: {:.0} lbs", capacity_lb)
+    } else {
+        format!("Rated: {:.0} lbs ({})", capacity_lb, deratings.join(", "))
+    };
+    let class_details = match class {
+        LiftClass::Critical => ", critical lift",
+        LiftClass::Routine => "",
+    };
+
+    let deductions = crane.deductions();
+    let mut breakdown = plan.rigging.weight_breakdown();
+    breakdown.extend([
+        ("Hook block".to_string(), deductions.hook_block),
+        ("Crane rigging".into(), deductions.rigging),
+        ("Hoist rope".into(), deductions.hoist_rope),
+    ]);
+    breakdown.extend(deductions.other.iter().cloned());
+    breakdown.retain(|(_, weight)| *weight > Mass::new::<pound>(0.0));
+    if !breakdown.is_empty() {
+        let items: Vec<String> = breakdown
+            .iter()
+            .map(|(item, weight)| format!("{} {:.0} lbs", item, weight.get::<pound>()))
+            .collect();
+        let suspended = plan.suspended_load(crane);
+        report.add_check(ValidationCheck {
+            name: "Hook Load".into(),
+            status: CheckStatus::Pass,
+            details: format!(
+                "Payload {:.0} lbs + rigging and block {:.0} lbs ({}) = {:.0} lbs",
+                plan.load_weight.get::<pound>(),
+                (suspended - plan.load_weight).get::<pound>(),
+                items.join(", "),
+                suspended.get::<pound>()
+            ),
+            margin: None,
+        });
+    }
+
     report.add_check(ValidationCheck {
         name: "Capacity".into(),
         status,
         details: format!(
-            "Load: {:.0} lbs, Rated: {:.0} lbs at {:.1} ft radius, {:.1} ft boom",
-            load_lb,
-            capacity_lb,
+            "{}, {} at {:.1} ft radius, {:.1} ft boom{}",
+            load_details,
+            rated_details,
             config.radius.get::<foot>(),
             config.boom_length.get::<foot>(),
+            class_details,
         ),
         margin: Some(margin),
     });
     
-    if margin < 20.0 && margin >= 10.0 {
+    if duty.is_derated() {
+        report.add_recommendation(format!(
+            "Chart derated to {:.0}% for {} duty; confirm the manufacturer's duty-cycle rating",
+            duty.capacity_factor() * 100.0,
+            duty
+        ));
+    }
+    if class == LiftClass::Critical {
+        report.add_recommendation(
+            "Critical lift: prepare a written critical lift plan".into()
+        );
+    }
+    if margin < 2.0 * required_margin && margin >= required_margin {
         report.add_recommendation(
             "Consider using a larger crane for better safety margin".into()
         );
     }
 }
 
-fn validate_wind<C: Crane>(
+fn validate_wind<C: Kinematic>(
     crane: &C,
     plan: &LiftPlan,
     report: &mut ValidationReport,
 ) {
-    let config = crane.configuration();
-    let sail_area = plan.load_dimensions.sail_area();
+    let analysis = plan.wind_analysis(crane);
     
-    let analysis = WindAnalysis::new(
-        CraneType::AllTerrain,  // Should come from crane
-        config.boom_length,
-        config.boom_angle,
-        sail_area,
-        plan.environment.wind_speed,
-    );
-    
-    let condition = analysis.wind_condition();
+    let condition = plan.safety_factors.wind_condition(&analysis);
     let wind_mph = plan.environment.wind_speed.get::<mile_per_hour>();
     let derating = (1.0 - analysis.derating_factor()) * 100.0;
     
@@ -391,31 +797,54 @@ fn validate_wind<C: Crane>(
     }
 }
 
-fn validate_ground_bearing<C: Crane>(
-    _crane: &C,
+/// Highest pad (or mat) pressure for the plan, and the load on that pad
+///
+/// Uses the crane's support model when it has one; otherwise shares the
+/// crane's operating weight and the suspended load equally over four pads.
+pub fn ground_bearing_pressure<C: StabilityAnalyzed + RatedCapacity>(
+    crane: &C,
+    plan: &LiftPlan,
+) -> Result<(Pressure, Force), GroundBearingError> {
+    if let Some(result) = plan.ground_bearing(crane) {
+        let result = result?;
+        return Ok((result.max_pressure, result.max_reaction));
+    }
+
+    let crane_weight = crane.operating_weight().ok_or_else(|| {
+        GroundBearingError::InvalidConfiguration("no support model or operating weight for the crane".into())
+    })?;
+    let total_weight = (crane_weight + plan.dynamic_suspended_load(crane)).get::<pound>();
+    let pad_load = total_weight / 4.0;
+    let pressure = pad_load / plan.ground.mat_area.get::<square_inch>();
+    Ok((Pressure::new::<psi>(pressure), Force::new::<pound_force>(pad_load)))
+}
+
+fn validate_ground_bearing<C: StabilityAnalyzed + RatedCapacity>(
+    crane: &C,
     plan: &LiftPlan,
     report: &mut ValidationReport,
 ) {
-    // TODO:
-    // This would use crane.ground_bearing_analysis()
-    // For now, simplified check
-    
-    let soil_capacity = plan.ground.soil_type.bearing_capacity();
-    let soil_psi = soil_capacity.get::<psi>();
-    
-    // Simplified: assume equal distribution (conservative in reality)
-    let total_weight = plan.load_weight.get::<pound>() + 100000.0; // crane weight estimate
-    let mat_area_sqin = plan.ground.mat_area.get::<square_inch>();
-    let num_outriggers = 4.0;
-    
-    let pressure_psi = (total_weight / num_outriggers) / mat_area_sqin;
-    let allowable_psi = soil_psi / plan.safety_factors.ground_bearing;
+
+    let (pressure, pad_load) = match ground_bearing_pressure(crane, plan) {
+        Ok(result) => result,
+        Err(e) => {
+            report.add_check(ValidationCheck {
+                name: "Ground Bearing".into(),
+                status: CheckStatus::Fail,
+                details: e.to_string(),
+                margin: None,
+            });
+            return;
+        }
+    };
+    let pressure_psi = pressure.get::<psi>();
+    let allowable_psi = plan.allowable_bearing().get::<psi>();
     
     let margin = ((allowable_psi - pressure_psi) / allowable_psi) * 100.0;
     
     let status = if pressure_psi > allowable_psi {
         CheckStatus::Fail
-    } else if margin < 20.0 {
+    } else if margin < plan.safety_factors.ground_bearing_margin {
         CheckStatus::Warning
     } else {
         CheckStatus::Pass
@@ -435,12 +864,27 @@ fn validate_ground_bearing<C: Crane>(
     });
     
     if pressure_psi > allowable_psi {
-        let required_area = (total_weight / num_outriggers) / allowable_psi;
+        let required_area = pad_load.get::<pound_force>() / allowable_psi;
         let side = (required_area / 144.0).sqrt(); // Convert to ft
         report.add_recommendation(
             format!("Use larger mats: minimum {:.1} ft x {:.1} ft required", side, side)
         );
     }
+
+    for warning in plan.ground.conditions.warnings() {
+        report.add_check(ValidationCheck {
+            name: "Ground Conditions".into(),
+            status: CheckStatus::Warning,
+            details: warning,
+            margin: None,
+        });
+    }
+    let setback = plan.ground.conditions.additional_setback();
+    if setback > Length::new::<foot>(0.0) {
+        report.add_recommendation(
+            format!("Move outriggers {:.1} ft further back from the slope crest", setback.get::<foot>())
+        );
+    }
 }
 
 fn validate_rigging(
@@ -454,19 +898,34 @@ fn validate_rigging(
         RiggingConfig::Vertical => load_lb,
         RiggingConfig::Choker { efficiency } => load_lb / efficiency,
         RiggingConfig::Basket => load_lb / 2.0,
-        RiggingConfig::Bridle { leg_angle, num_legs } => {
+        RiggingConfig::Bridle { leg_angle, num_legs, sharing } => {
             let angle_deg = leg_angle.get::<degree>();
             let angle_factor = 1.0 / (angle_deg.to_radians().cos());
-            load_lb * angle_factor / (*num_legs as f64)
+            load_lb * angle_factor / (sharing.load_bearing_legs(*num_legs) as f64)
+        }
+        RiggingConfig::BridleGeometry { legs, hook_height, sharing } => {
+            match bridle_leg_load(load_lb, legs, *hook_height, *sharing) {
+                Ok((load, check)) => {
+                    report.add_check(check);
+                    load
+                }
+                Err(check) => {
+                    report.add_check(check);
+                    return;
+                }
+            }
         }
     };
     
-    // Check each piece of hardware
+    // Check each piece of hardware at its capacity under the policy's
+    // design factor
     let mut min_margin = f64::MAX;
     let mut weakest_component = String::new();
-    
+    let mut derated = false;
+
     for hardware in &plan.rigging.hardware {
-        let capacity = hardware.capacity.get::<pound>();
+        let capacity = plan.safety_factors.allowable_rigging(hardware).get::<pound>();
+        derated |= capacity < hardware.capacity.get::<pound>();
         let margin = ((capacity - rigging_load) / capacity) * 100.0;
         
         if margin < min_margin {
@@ -475,25 +934,23 @@ fn validate_rigging(
         }
     }
     
-    let required_capacity = rigging_load * plan.safety_factors.rigging;
     let status = if min_margin < 0.0 {
         CheckStatus::Fail
-    } else if required_capacity > plan.rigging.hardware.iter()
-        .map(|h| h.capacity.get::<pound>())
-        .min_by(|a, b| a.partial_cmp(b).unwrap())
-        .unwrap_or(0.0)
-    {
-        CheckStatus::Warning
     } else {
         CheckStatus::Pass
     };
+    let design_factor = if derated {
+        format!(", derated to {}:1", plan.safety_factors.rigging)
+    } else {
+        String::new()
+    };
     
     report.add_check(ValidationCheck {
         name: "Rigging Capacity".into(),
         status,
         details: format!(
-            "Load per leg: {:.0} lbs, Min margin: {:.1}% ({})",
-            rigging_load, min_margin, weakest_component
+            "Load per leg: {:.0} lbs, Min margin: {:.1}% ({}{})",
+            rigging_load, min_margin, weakest_component, design_factor
         ),
         margin: Some(min_margin),
     });
@@ -505,7 +962,52 @@ fn validate_rigging(
     }
 }
 
-fn validate_configuration<C: Crane>(
+/// Worst leg load for a bridle rigged as `legs`, with a geometry check
+/// to report; fails the check when the slings cannot be rigged as given
+fn bridle_leg_load(
+    load_lb: f64,
+    legs: &[BridleLegGeometry],
+    hook_height: Length,
+    sharing: LegSharing,
+) -> Result<(f64, ValidationCheck), ValidationCheck> {
+    let check = |status, details: String| ValidationCheck {
+        name: "Rigging Geometry".into(),
+        status,
+        details,
+        margin: None,
+    };
+
+    let derived = derive_leg_angles(legs, hook_height)
+        .map_err(|e| check(CheckStatus::Fail, e.to_string()))?;
+    let taut: Vec<&DerivedLegAngle> = derived.iter().filter(|leg| leg.is_taut()).collect();
+    let bearing_legs = sharing.load_bearing_legs(taut.len()).max(1) as f64;
+    let max_angle = taut
+        .iter()
+        .map(|leg| leg.angle_from_vertical.get::<degree>())
+        .fold(0.0, f64::max);
+    let leg_load = load_lb / bearing_legs / max_angle.to_radians().cos();
+
+    let slack = derived.len() - taut.len();
+    let result = if slack > 0 {
+        check(
+            CheckStatus::Warning,
+            format!(
+                "{} of {} legs hang slack and carry no load; max leg angle {:.1}° from vertical",
+                slack,
+                derived.len(),
+                max_angle
+            ),
+        )
+    } else {
+        check(
+            CheckStatus::Pass,
+            format!("{} legs, max leg angle {:.1}° from vertical", derived.len(), max_angle),
+        )
+    };
+    Ok((leg_load, result))
+}
+
+fn validate_configuration<C: Kinematic>(
     crane: &C,
     _plan: &LiftPlan,
     report: &mut ValidationReport,
@@ -536,3 +1038,307 @@ fn validate_configuration<C: Crane>(
         margin: None,
     });
 }
+
+fn validate_boom_angle<C: Kinematic + RatedCapacity>(
+    crane: &C,
+    report: &mut ValidationReport,
+) {
+    // Charts without an angle range don't restrict the boom
+    let Some(range) = crane.boom_angle_range() else {
+        return;
+    };
+    let angle = crane.configuration().boom_angle;
+
+    let (status, details) = match check_boom_angle(range, angle) {
+        Ok(()) => (CheckStatus::Pass, format!("Boom angle {} is within the chart range", DisplayAngle(angle))),
+        Err(e) => (CheckStatus::Fail, e.to_string()),
+    };
+    report.add_check(ValidationCheck {
+        name: "Boom Angle".into(),
+        status,
+        details,
+        margin: None,
+    });
+}
+
+fn validate_headroom<C: Kinematic>(
+    crane: &C,
+    plan: &LiftPlan,
+    report: &mut ValidationReport,
+) {
+    let Some(budget) = &plan.headroom else {
+        return;
+    };
+
+    // Configuration height is the boom tip
+    let tip_height = crane.configuration().height;
+    let analysis = match budget.analyze(budget.max_hook_height(tip_height)) {
+        Ok(analysis) => analysis,
+        Err(e) => {
+            report.add_check(ValidationCheck {
+                name: "Headroom".into(),
+                status: CheckStatus::Fail,
+                details: e.to_string(),
+                margin: None,
+            });
+            return;
+        }
+    };
+
+    let status = if !analysis.clears() {
+        CheckStatus::Fail
+    } else if !analysis.has_margin() {
+        CheckStatus::Warning
+    } else {
+        CheckStatus::Pass
+    };
+
+    report.add_check(ValidationCheck {
+        name: "Headroom".into(),
+        status,
+        details: format!(
+            "Tip: {:.1} ft, Underside of load: {:.1} ft, Set elevation: {:.1} ft, Clearance: {:.1} ft",
+            tip_height.get::<foot>(),
+            analysis.underside_of_load.get::<foot>(),
+            budget.set_elevation.get::<foot>(),
+            analysis.clearance.get::<foot>(),
+        ),
+        margin: None,
+    });
+
+    if analysis.shortfall > Length::new::<foot>(0.0) {
+        report.add_recommendation(format!(
+            "Raise boom tip by {:.1} ft (longer boom or higher angle) or shorten rigging",
+            analysis.shortfall.get::<foot>()
+        ));
+    }
+}
+
+fn validate_work_area<C: Kinematic>(
+    crane: &C,
+    plan: &LiftPlan,
+    report: &mut ValidationReport,
+) {
+    let Some(work_area) = &plan.work_area else {
+        return;
+    };
+
+    let fk = crane.forward_kinematics();
+    let joints = crane.joint_config();
+    let violations = work_area.violations(&fk, &joints);
+    let warnings = work_area.warnings(&fk, &joints);
+
+    let (status, details) = if !violations.is_empty() {
+        let limits: Vec<String> = violations
+            .iter()
+            .map(|v| format!("{} exceeded by {:.1} ft", v.limit, -v.distance.get::<foot>()))
+            .collect();
+        (CheckStatus::Fail, limits.join(", "))
+    } else if !warnings.is_empty() {
+        let limits: Vec<String> = warnings
+            .iter()
+            .map(|w| format!("{:.1} ft to {}", w.distance.get::<foot>(), w.limit))
+            .collect();
+        (CheckStatus::Warning, limits.join(", "))
+    } else {
+        (CheckStatus::Pass, "All work area limits clear".to_string())
+    };
+
+    report.add_check(ValidationCheck {
+        name: "Work Area".into(),
+        status,
+        details,
+        margin: None,
+    });
+}
+
+fn validate_side_load<C: Kinematic + RatedCapacity>(
+    crane: &C,
+    plan: &LiftPlan,
+    report: &mut ValidationReport,
+) {
+    let Some(conditions) = &plan.side_load else {
+        return;
+    };
+
+    let analysis = match conditions.analyze(plan.dynamic_suspended_load(crane), crane.rated_capacity()) {
+        Ok(analysis) => analysis,
+        Err(e) => {
+            report.add_check(ValidationCheck {
+                name: "Side Load".into(),
+                status: CheckStatus::Fail,
+                details: e.to_string(),
+                margin: None,
+            });
+            return;
+        }
+    };
+    let margin = (1.0 - analysis.utilization()) * 100.0;
+    let status = if !analysis.is_acceptable() {
+        CheckStatus::Fail
+    } else if analysis.utilization() > 0.8 {
+        CheckStatus::Warning
+    } else {
+        CheckStatus::Pass
+    };
+
+    report.add_check(ValidationCheck {
+        name: "Side Load".into(),
+        status,
+        details: format!(
+            "Side force: {:.0} lbs ({:.2}% of rated, allowable {:.1}%), side lead {:.1}°, off-lead {:.1}°",
+            analysis.side_force.get::<pound_force>().abs(),
+            analysis.side_load_percent,
+            analysis.allowable_percent,
+            conditions.side_lead.get::<degree>(),
+            conditions.off_lead.get::<degree>(),
+        ),
+        margin: Some(margin),
+    });
+
+    if status != CheckStatus::Pass {
+        report.add_recommendation(
+            "Plumb the hoist line over the load's center of gravity and ease tagline pull across the boom".into()
+        );
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_cold_weather_by_rigging_kind() {
+        let mut plan = crate::test_fixtures::lift_plan(5_000.0);
+        plan.environment.temperature = ThermodynamicTemperature::new::<degree_fahrenheit>(-45.0);
+        plan.rigging.hardware[0].item_type = "Synthetic Sling".into();
+        let crane = crate::test_fixtures::mobile_crane();
+
+        // The label alone doesn't make it a synthetic sling, and the
+        // default rules warn without derating the chart
+        let report = validate_lift(&crane, &plan);
+        assert_eq!(report.check("Temperature: Cold Weather").unwrap().status, CheckStatus::Warning);
+        assert!(report.check("Capacity").unwrap().details.contains("Rated: 10000 lbs at"));
+
+        plan.rigging.hardware[0].kind = RiggingKind::SyntheticSling;
+        let report = validate_lift(&crane, &plan);
+        assert_eq!(report.check("Temperature: Cold Weather").unwrap().status, CheckStatus::Fail);
+    }
+
+    #[test]
+    fn test_policy_applies_to_rigging_and_ground_bearing() {
+        let mut plan = crate::test_fixtures::lift_plan(8_000.0);
+        plan.rigging.hardware[0].capacity = Mass::new::<pound>(15_000.0);
+        let crane = crate::test_fixtures::mobile_crane();
+        let report = validate_lift(&crane, &plan);
+        assert_eq!(report.check("Rigging Capacity").unwrap().status, CheckStatus::Pass);
+        let bearing_margin = report.check("Ground Bearing").unwrap().margin.unwrap();
+
+        // A shackle rated at 5:1 is good for half its rating at 10:1
+        plan.safety_factors = SafetyFactors { rigging: 10.0, ground_bearing: 3.0, ..SafetyFactors::default() };
+        let report = validate_lift(&crane, &plan);
+        let rigging = report.check("Rigging Capacity").unwrap();
+        assert_eq!(rigging.status, CheckStatus::Fail);
+        assert!(rigging.details.contains("derated to 10:1"));
+        assert!(report.check("Ground Bearing").unwrap().margin.unwrap() < bearing_margin);
+
+        let result = plan.ground_bearing(&crane).unwrap().unwrap();
+        assert_eq!(result.allowable_pressure, Some(plan.allowable_bearing()));
+    }
+
+    #[test]
+    fn test_boom_angle_outside_chart_range() {
+        use crate::capacity::load_chart::AngleRange;
+        use crate::equipment::MobileCrane;
+
+        let plan = crate::test_fixtures::lift_plan(5_000.0);
+        let mut crane = crate::test_fixtures::mobile_crane();
+        let report = validate_lift(&crane, &plan);
+        assert!(report.check("Boom Angle").is_none());
+
+        // The fixture sits at 60°, below the chart's minimum
+        let set_range = |crane: &mut MobileCrane, unit: &str| {
+            for chart in &mut crane.load_charts.as_mut().unwrap().charts {
+                chart.configuration.boom.angle_range = Some(AngleRange {
+                    min: AngleValue::new(65.0, unit),
+                    max: AngleValue::new(82.0, unit),
+                });
+            }
+        };
+        set_range(&mut crane, "deg");
+        let report = validate_lift(&crane, &plan);
+        let check = report.check("Boom Angle").unwrap();
+        assert_eq!(check.status, CheckStatus::Fail);
+        assert!(check.details.contains("outside the chart range"));
+
+        crane.boom_angle = Angle::new::<degree>(70.0);
+        let report = validate_lift(&crane, &plan);
+        assert_eq!(report.check("Boom Angle").unwrap().status, CheckStatus::Pass);
+
+        set_range(&mut crane, "furlong");
+        let report = validate_lift(&crane, &plan);
+        let check = report.check("Boom Angle").unwrap();
+        assert_eq!(check.status, CheckStatus::Fail);
+        assert!(check.details.contains("can't be read"));
+    }
+
+    fn offshore() -> OffshoreConditions {
+        use crate::physics::{LiftLocation, SeaState};
+
+        OffshoreConditions {
+            location: LiftLocation::Offshore,
+            sea_state: SeaState {
+                significant_wave_height: Length::new::<foot>(6.0),
+                peak_period: Time::new::<second>(8.0),
+            },
+            heave_rao: 0.5,
+            hoisting_velocity: Velocity::new::<foot_per_minute>(30.0),
+            rope_stiffness: 100_000.0,
+        }
+    }
+
+    #[test]
+    fn test_daf_on_suspended_load() {
+        let mut plan = crate::test_fixtures::lift_plan(8_000.0);
+        let offshore = offshore();
+        plan.environment.offshore = Some(offshore);
+        let mut crane = crate::test_fixtures::mobile_crane();
+        crane.deductions.hook_block = Mass::new::<pound>(1_500.0);
+
+        // The hook block is lifted off the deck with the load
+        let suspended = plan.suspended_load(&crane);
+        assert_eq!(suspended, plan.hook_load() + Mass::new::<pound>(1_500.0));
+        let daf = plan.dynamic_amplification_factor(&crane);
+        assert_eq!(daf, offshore.dynamic_amplification_factor(suspended));
+        assert_ne!(daf, offshore.dynamic_amplification_factor(plan.hook_load()));
+    }
+
+    #[test]
+    fn test_ground_bearing_on_amplified_suspended_load() {
+        let mut plan = crate::test_fixtures::lift_plan(3_000.0);
+        plan.environment.offshore = Some(offshore());
+        let mut crane = crate::test_fixtures::mobile_crane();
+        crane.deductions.hook_block = Mass::new::<pound>(1_500.0);
+        let daf = plan.dynamic_amplification_factor(&crane);
+        assert!(daf > 1.0);
+
+        // 3,000 lb load and 1,500 lb block, both amplified; the crane isn't
+        let suspended = Mass::new::<pound>(4_500.0);
+        let pressure_with = |load: Mass| {
+            let analysis = crane.ground_bearing_analysis(load, Some(plan.ground.mat_area)).unwrap();
+            analysis.calculate_reactions().unwrap().max_pressure.get::<psi>()
+        };
+        let result = plan.ground_bearing(&crane).unwrap().unwrap();
+        let expected = pressure_with(suspended * daf);
+        assert_relative_eq!(result.max_pressure.get::<psi>(), expected, max_relative = 1e-12);
+        let static_pressure = pressure_with(suspended);
+        assert_relative_eq!(result.static_max_pressure.get::<psi>(), static_pressure, max_relative = 1e-12);
+        assert!(result.max_pressure > result.static_max_pressure);
+
+        let report = validate_lift(&crane, &plan);
+        let details = &report.check("Ground Bearing").unwrap().details;
+        assert!(details.starts_with(&format!("Pressure: {:.1} PSI", expected)));
+    }
+}
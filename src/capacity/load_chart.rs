@@ -1,19 +1,72 @@
+#[cfg(feature = "charts-io")]
+use crate::capacity::chart_io::ChartFormat;
 use crate::types::*;
 use crate::equipment::*;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Complete load chart package for a crane model
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LoadChartPackage {
     /// Crane identification
     pub crane_info: CraneInfo,
 
     /// All available load charts for this crane
     pub charts: Vec<LoadChart>,
+
+    /// Where the package came from and its recorded hash
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub provenance: ChartProvenance,
+
+    /// How capacities between outrigger extension charts are resolved
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub extension_interpolation: ExtensionInterpolation,
+}
+
+/// How a partial outrigger extension without its own chart is rated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ExtensionInterpolation {
+    /// Use the chart for the next shorter extension
+    #[default]
+    Conservative,
+
+    /// Interpolate linearly between the charts either side
+    ///
+    /// Only set this where the manufacturer permits it.
+    Linear,
+}
+
+/// Capacity at a partial outrigger extension and the charts behind it
+#[derive(Debug, Clone)]
+pub struct ExtensionCapacity {
+    pub capacity: Mass,
+
+    /// Chart at or below the requested extension
+    pub lower_chart: String,
+
+    /// Chart above the requested extension, if interpolated
+    pub upper_chart: Option<String>,
+
+    /// Set when a shorter extension chart was used in place of interpolation
+    pub warning: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Capacity in the working quadrant of a per-corner outrigger setup
+#[derive(Debug, Clone)]
+pub struct QuadrantCapacity {
+    pub quadrant: SlewQuadrant,
+
+    /// Extension of the shortest supporting beam, percent of full
+    pub governing_extension: f64,
+
+    pub rated: ExtensionCapacity,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CraneInfo {
     pub manufacturer: String,
     pub model: String,
@@ -25,7 +78,8 @@ pub struct CraneInfo {
 
 
 /// A single load chart for a specific configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LoadChart {
     /// Unique identifier for this chart
     pub id: String,
@@ -41,6 +95,27 @@ pub struct LoadChart {
 
     /// Notes and warnings
     pub notes: Vec<String>,
+
+    /// Source document and recorded hash
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub provenance: ChartProvenance,
+}
+
+/// Source and audit information for a chart or package
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ChartProvenance {
+    /// Manufacturer document the data was taken from
+    pub source_document: Option<String>,
+
+    /// Page number within the source document
+    pub page: Option<u32>,
+
+    /// Date the chart takes effect (ISO 8601, e.g. "2020-03-01")
+    pub effective_date: Option<String>,
+
+    /// SHA-256 of the content when recorded, hex encoded
+    pub content_hash: Option<String>,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -48,18 +123,90 @@ pub enum LoadChartError {
     #[error("Unit conversion error: {0}")]
     UnitError(#[from] UnitError),
 
-    #[error("Boom length {0} ft not found")]
-    BoomLengthNotFound(DisplayLength),
+    #[error("Boom length {length} not found in {context}")]
+    BoomLengthNotFound {
+        length: DisplayLength,
+        context: ChartContext,
+    },
 
-    #[error("Radius {0} ft out of range")]
-    RadiusOutOfRange(DisplayLength),
+    #[error("Radius {radius} out of range in {context}")]
+    RadiusOutOfRange {
+        radius: DisplayLength,
+        context: ChartContext,
+    },
 
     #[error("No data available for interpolation")]
     NoData,
+
+    #[error("No load chart on {crane_model} matches configuration: {configuration}")]
+    NoMatchingChart {
+        crane_model: String,
+        configuration: String,
+    },
+}
+
+impl LoadChartError {
+    /// Attach the crane model to a chart lookup error
+    pub fn with_crane_model(mut self, model: impl Into<String>) -> Self {
+        match &mut self {
+            LoadChartError::BoomLengthNotFound { context, .. }
+            | LoadChartError::RadiusOutOfRange { context, .. } => {
+                context.crane_model = Some(model.into());
+            }
+            _ => {}
+        }
+        self
+    }
+
+    /// Chart the error came from, if known
+    pub fn context(&self) -> Option<&ChartContext> {
+        match self {
+            LoadChartError::BoomLengthNotFound { context, .. }
+            | LoadChartError::RadiusOutOfRange { context, .. } => Some(context),
+            _ => None,
+        }
+    }
+}
+
+/// Identifies the chart a lookup ran against
+#[derive(Debug, Clone, Default)]
+pub struct ChartContext {
+    pub chart_id: String,
+    pub crane_model: Option<String>,
+    pub configuration: String,
+}
+
+impl std::fmt::Display for ChartContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "chart '{}'", self.chart_id)?;
+        if let Some(model) = &self.crane_model {
+            write!(f, " of {}", model)?;
+        }
+        write!(f, " [{}]", self.configuration)
+    }
+}
+
+/// How a capacity query behaves outside the tabulated boom lengths and radii
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum OutOfRangePolicy {
+    /// Return `BoomLengthNotFound` / `RadiusOutOfRange`
+    #[default]
+    Error,
+
+    /// Clamp boom length and radius to the nearest charted values
+    ///
+    /// Not conservative below minimum radius; intended for UI sliders and
+    /// optimizers, not for lift validation.
+    ClampToNearest,
+
+    /// Report zero capacity
+    ZeroCapacity,
 }
 
 /// Configuration parameters that determine which chart to use
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ChartConfiguration {
     /// Support configuration
     pub support: SupportConfiguration,
@@ -74,7 +221,8 @@ pub struct ChartConfiguration {
     pub additional: HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SupportConfiguration {
     /// On tires/rubber
     OnRubber {
@@ -96,17 +244,97 @@ pub enum SupportConfiguration {
         /// Track configuration
         track_config: String,
     },
+
+    /// Floating, mounted on a barge
+    OnBarge {
+        /// Maximum list/trim the chart is rated for
+        max_list: AngleValue,
+    },
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum OutriggerExtension {
     Full,
     Intermediate { percent: f64 },
     Minimum,
     Custom { distance: LengthValue },
+
+    /// Each beam at its own extension
+    PerCorner { corners: CornerExtensions },
+}
+
+impl OutriggerExtension {
+    /// Extension as a percentage of full; `None` for a custom distance
+    ///
+    /// Per-corner setups report the shortest beam.
+    pub fn percent(&self) -> Option<f64> {
+        match self {
+            OutriggerExtension::Full => Some(100.0),
+            OutriggerExtension::Intermediate { percent } => Some(*percent),
+            OutriggerExtension::Minimum => Some(0.0),
+            OutriggerExtension::Custom { .. } => None,
+            OutriggerExtension::PerCorner { corners } => Some(corners.shortest()),
+        }
+    }
+}
+
+/// Extension of each outrigger beam, as a percentage of full
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CornerExtensions {
+    pub front_left: f64,
+    pub front_right: f64,
+    pub rear_left: f64,
+    pub rear_right: f64,
+}
+
+impl CornerExtensions {
+    pub fn shortest(&self) -> f64 {
+        self.front_left
+            .min(self.front_right)
+            .min(self.rear_left)
+            .min(self.rear_right)
+    }
+
+    /// Shortest of the beams supporting a quadrant
+    pub fn governing(&self, quadrant: SlewQuadrant) -> f64 {
+        match quadrant {
+            SlewQuadrant::OverFront => self.front_left.min(self.front_right),
+            SlewQuadrant::OverRear => self.rear_left.min(self.rear_right),
+            SlewQuadrant::OverLeft => self.front_left.min(self.rear_left),
+            SlewQuadrant::OverRight => self.front_right.min(self.rear_right),
+        }
+    }
+}
+
+/// Working quadrant of the superstructure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlewQuadrant {
+    OverFront,
+    OverRear,
+    OverLeft,
+    OverRight,
+}
+
+impl SlewQuadrant {
+    /// Quadrant for a swing angle (0° over the front, positive to the right)
+    ///
+    /// Boundaries are taken on the diagonals. On the machine they run
+    /// through the outrigger pads and shift with the extensions.
+    pub fn from_swing(swing: Angle) -> Self {
+        let degrees = swing.get::<degree>().rem_euclid(360.0);
+        match degrees {
+            d if !(45.0..315.0).contains(&d) => SlewQuadrant::OverFront,
+            d if d < 135.0 => SlewQuadrant::OverRight,
+            d if d < 225.0 => SlewQuadrant::OverRear,
+            _ => SlewQuadrant::OverLeft,
+        }
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SwingRestriction {
     Full360,
     OverFront,
@@ -114,7 +342,10 @@ pub enum SwingRestriction {
     OverSide,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Serialized by hand so chart files without a telescope mode or boom
+/// inserts come out as they did before the fields existed
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
 pub struct BoomConfiguration {
     /// Boom length (raw value)
     pub length: LengthValue,
@@ -124,6 +355,85 @@ pub struct BoomConfiguration {
 
     /// Jib configuration (if present)
     pub jib: Option<JibConfiguration>,
+
+    /// Telescope extension mode (EM code); charts without one match on length alone
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub telescope_mode: Option<TelescopeMode>,
+
+    /// Lattice insert code ("I20+I20+I10"); charts without one match on length alone
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub boom_inserts: Option<String>,
+}
+
+/// Chart files and content hashes skip an unset telescope mode or insert
+/// code, like `skip_serializing_if`; the binary cache can't skip fields and
+/// always writes both
+#[cfg(feature = "serde")]
+impl Serialize for BoomConfiguration {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let readable = serializer.is_human_readable();
+        let skip_mode = self.telescope_mode.is_none() && readable;
+        let skip_inserts = self.boom_inserts.is_none() && readable;
+        let len = 5 - usize::from(skip_mode) - usize::from(skip_inserts);
+        let mut state = serializer.serialize_struct("BoomConfiguration", len)?;
+        state.serialize_field("length", &self.length)?;
+        state.serialize_field("angle_range", &self.angle_range)?;
+        state.serialize_field("jib", &self.jib)?;
+        if skip_mode {
+            state.skip_field("telescope_mode")?;
+        } else {
+            state.serialize_field("telescope_mode", &self.telescope_mode)?;
+        }
+        if skip_inserts {
+            state.skip_field("boom_inserts")?;
+        } else {
+            state.serialize_field("boom_inserts", &self.boom_inserts)?;
+        }
+        state.end()
+    }
+}
+
+impl ChartConfiguration {
+    /// Short human-readable description for error messages and reports
+    pub fn summary(&self) -> String {
+        let support = match &self.support {
+            SupportConfiguration::OnRubber { .. } => "on rubber".to_string(),
+            SupportConfiguration::OnOutriggers { extension, .. } => match extension {
+                OutriggerExtension::Full => "outriggers full".to_string(),
+                OutriggerExtension::Intermediate { percent } => format!("outriggers {}%", percent),
+                OutriggerExtension::Minimum => "outriggers minimum".to_string(),
+                OutriggerExtension::Custom { distance } => {
+                    format!("outriggers {} {}", distance.value, distance.unit)
+                }
+                OutriggerExtension::PerCorner { corners } => format!(
+                    "outriggers FL {}% FR {}% RL {}% RR {}%",
+                    corners.front_left, corners.front_right, corners.rear_left, corners.rear_right
+                ),
+            },
+            SupportConfiguration::OnCrawlers { track_config } => format!("crawlers {}", track_config),
+            SupportConfiguration::OnBarge { .. } => "on barge".to_string(),
+        };
+
+        let mut summary = format!(
+            "{}, {} {} boom",
+            support, self.boom.length.value, self.boom.length.unit
+        );
+        if let Some(mode) = &self.boom.telescope_mode {
+            summary.push_str(&format!(" ({})", mode.code));
+        }
+        if let Some(inserts) = &self.boom.boom_inserts {
+            summary.push_str(&format!(" ({})", inserts));
+        }
+        if let Some(jib) = &self.boom.jib {
+            summary.push_str(&format!(", {} {} jib", jib.length.value, jib.length.unit));
+        }
+        if let Some(cw) = &self.counterweight {
+            summary.push_str(&format!(", {} {} counterweight", cw.weight.value, cw.weight.unit));
+        }
+        summary
+    }
 }
 
 /// TODO: DO WE NEED THIS???
@@ -132,13 +442,74 @@ impl BoomConfiguration {
         self.length.to_distance()
     }
 }
-#[derive(Debug, Clone, Serialize, Deserialize)]
+
+/// Telescope extension schedule (EM mode)
+///
+/// Two booms of the same total length can be built from different section
+/// extensions, and manufacturers rate each schedule separately. The code is
+/// the manufacturer's designation ("EM2", "0/50/90/100"); `section_extension`
+/// holds each telescoping section's extension in percent, base outward.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TelescopeMode {
+    pub code: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub section_extension: Vec<f64>,
+}
+
+impl TelescopeMode {
+    pub fn new(code: impl Into<String>, section_extension: Vec<f64>) -> Self {
+        Self { code: code.into(), section_extension }
+    }
+
+    /// Mode from a code, reading section percentages from "0/50/90/100" style codes
+    pub fn from_code(code: impl Into<String>) -> Self {
+        let code = code.into();
+        let section_extension = code
+            .split('/')
+            .map(|part| part.trim().trim_end_matches('%').parse::<f64>())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_or_default();
+        Self { code, section_extension }
+    }
+
+    /// Same mode by code (case-insensitive) or by section schedule
+    pub fn matches(&self, other: &Self) -> bool {
+        if self.code.trim().eq_ignore_ascii_case(other.code.trim()) {
+            return true;
+        }
+        !self.section_extension.is_empty()
+            && self.section_extension.len() == other.section_extension.len()
+            && self
+                .section_extension
+                .iter()
+                .zip(&other.section_extension)
+                .all(|(a, b)| (a - b).abs() < 0.5)
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AngleRange {
     pub min: AngleValue,
     pub max: AngleValue,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl AngleRange {
+    /// Lowest and highest rated boom angle; errors if either unit can't be read
+    pub fn bounds(&self) -> Result<(Angle, Angle), UnitError> {
+        Ok((self.min.to_angle()?, self.max.to_angle()?))
+    }
+
+    /// Whether `angle` lies in the range, with 0.01° tolerance at the ends
+    pub fn contains(&self, angle: Angle) -> Result<bool, UnitError> {
+        let (min, max) = self.bounds()?;
+        let tolerance = Angle::new::<degree>(0.01);
+        Ok(angle >= min - tolerance && angle <= max + tolerance)
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct JibConfiguration {
     pub length: LengthValue,
     pub angle: AngleValue,
@@ -159,7 +530,8 @@ impl JibConfiguration {
     //}
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CounterweightConfiguration {
     /// Mass  (weight)
     pub weight: MassValue,
@@ -175,7 +547,8 @@ impl CounterweightConfiguration {
 
 /// Capacity data stored as raw f64 values
 /// Units are specified in the parent LoadChart's `units` field
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CapacityData {
     /// Boom lengths (in units specified by chart.units.length)
     pub boom_lengths: Vec<LengthValue>,
@@ -233,6 +606,29 @@ impl CapacityData {
 }
 
 impl LoadChart {
+    /// Context identifying this chart in errors
+    pub fn context(&self) -> ChartContext {
+        ChartContext {
+            chart_id: self.id.clone(),
+            crane_model: None,
+            configuration: self.configuration.summary(),
+        }
+    }
+
+    fn boom_not_found(&self, length: Length) -> LoadChartError {
+        LoadChartError::BoomLengthNotFound {
+            length: DisplayLength(length),
+            context: self.context(),
+        }
+    }
+
+    fn radius_out_of_range(&self, radius: Length) -> LoadChartError {
+        LoadChartError::RadiusOutOfRange {
+            radius: DisplayLength(radius),
+            context: self.context(),
+        }
+    }
+
     /// Get capacity at exact boom length and radius (converts to/from chart units)
     pub fn capacity_exact(
         &self,
@@ -243,7 +639,7 @@ impl LoadChart {
         let boom_idx = booms
             .iter()
             .position(|&b| (b - boom_length).abs().get::<foot>() < 0.01)
-            .ok_or_else(|| LoadChartError::BoomLengthNotFound(DisplayLength(boom_length)))?;
+            .ok_or_else(|| self.boom_not_found(boom_length))?;
 
         let points = self.capacity_data.capacity_points(boom_idx)?;
         for (r, w) in points {
@@ -251,7 +647,7 @@ impl LoadChart {
                 return Ok(w);
             }
         }
-        Err(LoadChartError::RadiusOutOfRange(DisplayLength(radius)))
+        Err(self.radius_out_of_range(radius))
     }
 
     /// Get interpolated capacity at any boom length and radius
@@ -295,17 +691,17 @@ impl LoadChart {
             .iter()
             .enumerate()
             .filter(|&(_, &b)| b <= boom_length + epsilon)
-            .max_by(|a, b| a.1.partial_cmp(b.1).unwrap())
+            .max_by(|a, b| a.1.value.total_cmp(&b.1.value))
             .map(|(i, _)| i)
-            .ok_or_else(|| LoadChartError::BoomLengthNotFound(DisplayLength(boom_length)))?;
+            .ok_or_else(|| self.boom_not_found(boom_length))?;
 
         let upper_idx = booms
             .iter()
             .enumerate()
             .filter(|&(_, &b)| b >= boom_length - epsilon)
-            .min_by(|a, b| a.1.partial_cmp(b.1).unwrap())
+            .min_by(|a, b| a.1.value.total_cmp(&b.1.value))
             .map(|(i, _)| i)
-            .ok_or_else(|| LoadChartError::BoomLengthNotFound(DisplayLength(boom_length)))?;
+            .ok_or_else(|| self.boom_not_found(boom_length))?;
 
         Ok((lower_idx, upper_idx))
     }
@@ -328,15 +724,15 @@ impl LoadChart {
         let lower = points
             .iter()
             .filter(|(r, _)| *r <= radius + epsilon)
-            .max_by(|a, b| a.0.partial_cmp(&b.0).unwrap())
-            .ok_or_else(|| LoadChartError::RadiusOutOfRange(DisplayLength(radius)))?;
+            .max_by(|a, b| a.0.value.total_cmp(&b.0.value))
+            .ok_or_else(|| self.radius_out_of_range(radius))?;
 
         // Find upper radius
         let upper = points
             .iter()
             .filter(|(r, _)| *r >= radius - epsilon)
-            .min_by(|a, b| a.0.partial_cmp(&b.0).unwrap())
-            .ok_or_else(|| LoadChartError::RadiusOutOfRange(DisplayLength(radius)))?;
+            .min_by(|a, b| a.0.value.total_cmp(&b.0.value))
+            .ok_or_else(|| self.radius_out_of_range(radius))?;
 
         // If radii are the same, no interpolation needed
         if (lower.0 - upper.0).abs() < epsilon {
@@ -350,6 +746,34 @@ impl LoadChart {
         Ok(cap)
     }
 
+    /// Interpolated capacity with explicit behavior at chart edges
+    pub fn capacity_with_policy(
+        &self,
+        boom_length: Length,
+        radius: Length,
+        policy: OutOfRangePolicy,
+    ) -> Result<Mass, LoadChartError> {
+        match policy {
+            OutOfRangePolicy::Error => self.capacity_interpolated(boom_length, radius),
+            OutOfRangePolicy::ZeroCapacity => match self.capacity_interpolated(boom_length, radius) {
+                Err(LoadChartError::BoomLengthNotFound { .. } | LoadChartError::RadiusOutOfRange { .. }) => {
+                    Ok(Mass::new::<pound>(0.0))
+                }
+                result => result,
+            },
+            OutOfRangePolicy::ClampToNearest => {
+                let (boom_min, boom_max) = self.boom_range()?;
+                let boom_length = boom_length.max(boom_min).min(boom_max);
+
+                let (lower_idx, upper_idx) = self.find_boom_bounds(boom_length)?;
+                let (radius_min, radius_max) = self.common_radius_range(lower_idx, upper_idx)?;
+                let radius = radius.max(radius_min).min(radius_max);
+
+                self.capacity_interpolated(boom_length, radius)
+            }
+        }
+    }
+
     /// Check if this chart matches the given configuration
     pub fn matches_configuration(&self, config: &ChartConfiguration) -> bool {
         // Compare support configuration
@@ -558,13 +982,11 @@ impl LoadChart {
         radius: Length,
     ) -> Result<(), LoadChartError> {
         if !self.is_boom_valid(boom_length)? {
-            return Err(LoadChartError::BoomLengthNotFound(DisplayLength(
-                boom_length,
-            )));
+            return Err(self.boom_not_found(boom_length));
         }
 
         if !self.is_radius_valid(boom_length, radius)? {
-            return Err(LoadChartError::RadiusOutOfRange(DisplayLength(radius)));
+            return Err(self.radius_out_of_range(radius));
         }
 
         Ok(())
@@ -580,6 +1002,154 @@ impl LoadChart {
         let capacity = self.capacity_interpolated(boom_length, radius)?;
         Ok(Mass::new::<pound>(capacity.get::<pound>() * factor))
     }
+
+    /// Convert every stored length, mass and angle to one unit system
+    pub fn normalize_units(&mut self, system: UnitSystem) -> Result<(), UnitError> {
+        let config = &mut self.configuration;
+
+        match &mut config.support {
+            SupportConfiguration::OnOutriggers {
+                extension: OutriggerExtension::Custom { distance },
+                ..
+            } => distance.normalize(system)?,
+            SupportConfiguration::OnBarge { max_list } => max_list.normalize(system)?,
+            _ => {}
+        }
+
+        config.boom.length.normalize(system)?;
+        if let Some(range) = &mut config.boom.angle_range {
+            range.min.normalize(system)?;
+            range.max.normalize(system)?;
+        }
+        if let Some(jib) = &mut config.boom.jib {
+            jib.length.normalize(system)?;
+            jib.angle.normalize(system)?;
+            if let Some(offset) = &mut jib.offset {
+                offset.normalize(system)?;
+            }
+        }
+        if let Some(cw) = &mut config.counterweight {
+            cw.weight.normalize(system)?;
+        }
+
+        for boom in &mut self.capacity_data.boom_lengths {
+            boom.normalize(system)?;
+        }
+        for row in &mut self.capacity_data.data {
+            for (radius, capacity) in row {
+                radius.normalize(system)?;
+                capacity.normalize(system)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Maximum radius at which `load` is within chart for a boom length
+    ///
+    /// Inverse of [`capacity_interpolated`](Self::capacity_interpolated).
+    /// Returns `None` if the load exceeds capacity at every charted radius.
+    pub fn radius_for_capacity(
+        &self,
+        boom_length: Length,
+        load: Mass,
+    ) -> Result<Option<Length>, LoadChartError> {
+        let (lower_idx, upper_idx) = self.find_boom_bounds(boom_length)?;
+
+        let curve = if lower_idx == upper_idx {
+            self.capacity_points(lower_idx)?
+        } else {
+            // Between boom lengths the interpolated curve is piecewise
+            // linear with breaks at either boom's charted radii
+            let (min, max) = self.common_radius_range(lower_idx, upper_idx)?;
+            let mut radii: Vec<Length> = self
+                .capacity_data
+                .radii_for_boom(lower_idx)?
+                .into_iter()
+                .chain(self.capacity_data.radii_for_boom(upper_idx)?)
+                .filter(|r| *r >= min && *r <= max)
+                .collect();
+            radii.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+            radii.dedup_by(|a, b| (*a - *b).abs().get::<foot>() < 0.01);
+
+            radii
+                .into_iter()
+                .map(|r| Ok((r, self.capacity_interpolated(boom_length, r)?)))
+                .collect::<Result<Vec<_>, LoadChartError>>()?
+        };
+
+        Ok(max_radius_on_curve(&curve, load))
+    }
+
+    /// For each charted boom length, the maximum radius at which `load` is within chart
+    ///
+    /// Boom lengths that cannot carry the load at any radius are omitted.
+    pub fn envelope_for_load(&self, load: Mass) -> Result<Vec<(Length, Length)>, LoadChartError> {
+        let mut envelope = Vec::new();
+        for boom_length in self.boom_lengths()? {
+            if let Some(radius) = self.radius_for_capacity(boom_length, load)? {
+                envelope.push((boom_length, radius));
+            }
+        }
+        envelope.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+        Ok(envelope)
+    }
+
+    /// Radius range charted at both boom indices
+    fn common_radius_range(
+        &self,
+        lower_idx: usize,
+        upper_idx: usize,
+    ) -> Result<(Length, Length), LoadChartError> {
+        let mut min = Length::new::<foot>(f64::MIN);
+        let mut max = Length::new::<foot>(f64::MAX);
+
+        for idx in [lower_idx, upper_idx] {
+            let radii = self.capacity_data.radii_for_boom(idx)?;
+            let lo = radii
+                .iter()
+                .min_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Less))
+                .ok_or(LoadChartError::NoData)?;
+            let hi = radii
+                .iter()
+                .max_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Greater))
+                .ok_or(LoadChartError::NoData)?;
+            min = min.max(*lo);
+            max = max.min(*hi);
+        }
+
+        if min > max {
+            return Err(LoadChartError::NoData);
+        }
+
+        Ok((min, max))
+    }
+}
+
+/// Largest radius on a (radius, capacity) curve where capacity >= load
+fn max_radius_on_curve(points: &[(Length, Mass)], load: Mass) -> Option<Length> {
+    let mut points = points.to_vec();
+    points.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+
+    let last_ok = points.iter().rposition(|(_, c)| *c >= load)?;
+    let (r0, c0) = points[last_ok];
+
+    // Walk out towards the next point, where capacity has dropped below the load
+    match points.get(last_ok + 1) {
+        Some(&(r1, c1)) => {
+            let ratio = (c0 - load) / (c0 - c1);
+            Some(r0 + ratio * (r1 - r0))
+        }
+        None => Some(r0),
+    }
+}
+
+/// Maximum radius for a load at one boom length of one chart
+#[derive(Debug, Clone)]
+pub struct LoadEnvelopePoint {
+    pub chart_id: String,
+    pub boom_length: Length,
+    pub max_radius: Length,
 }
 
 // Helper trait for matching configurations
@@ -605,10 +1175,40 @@ impl ConfigurationMatch for SupportConfiguration {
 
 impl ConfigurationMatch for BoomConfiguration {
     fn matches(&self, other: &Self) -> bool {
-        match (self.length_distance(), other.length_distance()) {
+        let length_matches = match (self.length_distance(), other.length_distance()) {
             (Ok(my_length), Ok(other_length)) => (my_length - other_length).abs().get::<foot>() < 0.01,
             _ => false,
-        }
+        };
+        let mode_matches = match (&self.telescope_mode, &other.telescope_mode) {
+            (Some(mine), Some(theirs)) => mine.matches(theirs),
+            _ => true,
+        };
+        // Same length from different inserts is a different boom
+        let inserts_match = match (&self.boom_inserts, &other.boom_inserts) {
+            (Some(mine), Some(theirs)) => mine.trim().eq_ignore_ascii_case(theirs.trim()),
+            _ => true,
+        };
+        // Main boom charts never rate a jib, and jib charts only their own jib
+        let jib_matches = match (&self.jib, &other.jib) {
+            (Some(mine), Some(theirs)) => mine.matches(theirs),
+            (None, None) => true,
+            _ => false,
+        };
+        length_matches && mode_matches && inserts_match && jib_matches
+    }
+}
+
+impl ConfigurationMatch for JibConfiguration {
+    fn matches(&self, other: &Self) -> bool {
+        let length_matches = match (self.length_distance(), other.length_distance()) {
+            (Ok(my_length), Ok(other_length)) => (my_length - other_length).abs().get::<foot>() < 0.01,
+            _ => false,
+        };
+        let angle_matches = match (self.angle_value(), other.angle_value()) {
+            (Ok(my_angle), Ok(other_angle)) => (my_angle - other_angle).abs().get::<degree>() < 0.5,
+            _ => false,
+        };
+        length_matches && angle_matches
     }
 }
 
@@ -627,6 +1227,8 @@ impl LoadChartPackage {
         Self {
             crane_info,
             charts: Vec::new(),
+            provenance: ChartProvenance::default(),
+            extension_interpolation: ExtensionInterpolation::default(),
         }
     }
 
@@ -636,16 +1238,52 @@ impl LoadChartPackage {
     }
 
     /// Load from JSON file
+    #[cfg(feature = "charts-io")]
     pub fn from_json_file(path: &str) -> Result<Self, Box<dyn std::error::Error>> {
-        let json = std::fs::read_to_string(path)?;
-        let package = serde_json::from_str(&json)?;
-        Ok(package)
+        Self::from_file_as(path, ChartFormat::Json)
     }
 
     /// Save to JSON file
+    #[cfg(feature = "charts-io")]
     pub fn to_json_file(&self, path: &str) -> Result<(), Box<dyn std::error::Error>> {
-        let json = serde_json::to_string_pretty(self)?;
-        std::fs::write(path, json)?;
+        self.to_file_as(path, ChartFormat::Json)
+    }
+
+    /// Load from YAML file
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml_file(path: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        Self::from_file_as(path, ChartFormat::Yaml)
+    }
+
+    /// Save to YAML file
+    #[cfg(feature = "yaml")]
+    pub fn to_yaml_file(&self, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+        self.to_file_as(path, ChartFormat::Yaml)
+    }
+
+    /// Load from TOML file
+    #[cfg(feature = "toml")]
+    pub fn from_toml_file(path: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        Self::from_file_as(path, ChartFormat::Toml)
+    }
+
+    /// Save to TOML file
+    #[cfg(feature = "toml")]
+    pub fn to_toml_file(&self, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+        self.to_file_as(path, ChartFormat::Toml)
+    }
+
+    #[cfg(feature = "charts-io")]
+    fn from_file_as(path: &str, format: ChartFormat) -> Result<Self, Box<dyn std::error::Error>> {
+        let bytes = std::fs::read(path)?;
+        Ok(Self::from_bytes_as(&bytes, format)?)
+    }
+
+    #[cfg(feature = "charts-io")]
+    fn to_file_as(&self, path: &str, format: ChartFormat) -> Result<(), Box<dyn std::error::Error>> {
+        let mut bytes = Vec::new();
+        self.to_writer_as(&mut bytes, format)?;
+        std::fs::write(path, bytes)?;
         Ok(())
     }
 
@@ -656,6 +1294,128 @@ impl LoadChartPackage {
             .find(|chart| chart.matches_configuration(config))
     }
 
+    /// Capacity at a partial outrigger extension
+    ///
+    /// Uses the chart for the requested extension if there is one.
+    /// Otherwise, with [`ExtensionInterpolation::Linear`], interpolates
+    /// between the charts either side; failing that, rates on the next
+    /// shorter extension chart and sets a warning. Charts must match
+    /// `config` on boom and counterweight.
+    pub fn capacity_at_extension(
+        &self,
+        config: &ChartConfiguration,
+        boom_length: Length,
+        radius: Length,
+    ) -> Result<ExtensionCapacity, LoadChartError> {
+        let no_chart = || LoadChartError::NoMatchingChart {
+            crane_model: self.crane_info.model.clone(),
+            configuration: config.summary(),
+        };
+        let percent = match &config.support {
+            SupportConfiguration::OnOutriggers { extension, .. } => extension.percent().ok_or_else(no_chart)?,
+            _ => return Err(no_chart()),
+        };
+
+        let mut candidates: Vec<(f64, &LoadChart)> = self
+            .charts
+            .iter()
+            .filter(|chart| {
+                chart.configuration.boom.matches(&config.boom)
+                    && match (&chart.configuration.counterweight, &config.counterweight) {
+                        (Some(mine), Some(requested)) => mine.matches(requested),
+                        _ => true,
+                    }
+            })
+            .filter_map(|chart| match &chart.configuration.support {
+                SupportConfiguration::OnOutriggers { extension, .. } => Some((extension.percent()?, chart)),
+                _ => None,
+            })
+            .collect();
+        candidates.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let capacity = |chart: &LoadChart| {
+            chart
+                .capacity_interpolated(boom_length, radius)
+                .map_err(|e| e.with_crane_model(&self.crane_info.model))
+        };
+
+        let lower = candidates
+            .iter()
+            .rev()
+            .find(|(p, _)| *p <= percent + 1e-6)
+            .ok_or_else(no_chart)?;
+        if (lower.0 - percent).abs() < 1e-6 {
+            return Ok(ExtensionCapacity {
+                capacity: capacity(lower.1)?,
+                lower_chart: lower.1.id.clone(),
+                upper_chart: None,
+                warning: None,
+            });
+        }
+
+        let upper = candidates.iter().find(|(p, _)| *p > percent);
+        if let (ExtensionInterpolation::Linear, Some(upper)) = (self.extension_interpolation, upper) {
+            let (low, high) = (capacity(lower.1)?, capacity(upper.1)?);
+            let ratio = (percent - lower.0) / (upper.0 - lower.0);
+            return Ok(ExtensionCapacity {
+                capacity: low + ratio * (high - low),
+                lower_chart: lower.1.id.clone(),
+                upper_chart: Some(upper.1.id.clone()),
+                warning: None,
+            });
+        }
+
+        Ok(ExtensionCapacity {
+            capacity: capacity(lower.1)?,
+            lower_chart: lower.1.id.clone(),
+            upper_chart: None,
+            warning: Some(format!(
+                "No chart for outriggers at {}%; rated on {}% chart '{}'",
+                percent, lower.0, lower.1.id
+            )),
+        })
+    }
+
+    /// Capacity with each beam at its own extension
+    ///
+    /// Rates the lift on the shortest beam supporting the quadrant `swing`
+    /// falls in, through [`Self::capacity_at_extension`].
+    pub fn capacity_in_quadrant(
+        &self,
+        config: &ChartConfiguration,
+        swing: Angle,
+        boom_length: Length,
+        radius: Length,
+    ) -> Result<QuadrantCapacity, LoadChartError> {
+        let quadrant = SlewQuadrant::from_swing(swing);
+        let SupportConfiguration::OnOutriggers {
+            extension: OutriggerExtension::PerCorner { corners },
+            swing_restriction,
+        } = &config.support
+        else {
+            return Err(LoadChartError::NoMatchingChart {
+                crane_model: self.crane_info.model.clone(),
+                configuration: config.summary(),
+            });
+        };
+        let governing_extension = corners.governing(quadrant);
+
+        let mut governing = config.clone();
+        governing.support = SupportConfiguration::OnOutriggers {
+            extension: OutriggerExtension::Intermediate {
+                percent: governing_extension,
+            },
+            swing_restriction: swing_restriction.clone(),
+        };
+        let rated = self.capacity_at_extension(&governing, boom_length, radius)?;
+
+        Ok(QuadrantCapacity {
+            quadrant,
+            governing_extension,
+            rated,
+        })
+    }
+
     /// Get all charts for a specific support configuration
     pub fn charts_for_support(&self, support: &SupportConfiguration) -> Vec<&LoadChart> {
         self.charts
@@ -663,6 +1423,34 @@ impl LoadChartPackage {
             .filter(|chart| chart.configuration.support.matches(support))
             .collect()
     }
+
+    /// Convert every chart in the package to one unit system
+    ///
+    /// Exported files become consistent and queries skip mixed-unit parsing.
+    pub fn normalize_units(&mut self, system: UnitSystem) -> Result<(), UnitError> {
+        for chart in &mut self.charts {
+            chart.normalize_units(system)?;
+        }
+        Ok(())
+    }
+
+    /// Where a load can be lifted: maximum radius per chart and boom length
+    pub fn envelope_for_load(&self, load: Mass) -> Result<Vec<LoadEnvelopePoint>, LoadChartError> {
+        let mut envelope = Vec::new();
+        for chart in &self.charts {
+            let points = chart
+                .envelope_for_load(load)
+                .map_err(|e| e.with_crane_model(&self.crane_info.model))?;
+            for (boom_length, max_radius) in points {
+                envelope.push(LoadEnvelopePoint {
+                    chart_id: chart.id.clone(),
+                    boom_length,
+                    max_radius,
+                });
+            }
+        }
+        Ok(envelope)
+    }
 }
 
 impl Default for LoadChartPackage {
@@ -677,6 +1465,8 @@ impl Default for LoadChartPackage {
                 chart_revision: None,
             },
             charts: Vec::new(),
+            provenance: ChartProvenance::default(),
+            extension_interpolation: ExtensionInterpolation::default(),
         }
     }
 }
@@ -717,12 +1507,15 @@ mod tests {
                     length: LengthValue::new(154.2, "ft"),
                     angle_range: None,
                     jib: None,
+                    telescope_mode: None,
+                    boom_inserts: None,
                 },
                 counterweight: None,
                 additional: HashMap::new(),
             },
             capacity_data,
             notes: Vec::new(),
+            provenance: ChartProvenance::default(),
         }
     }
 
@@ -750,12 +1543,15 @@ mod tests {
                     length: LengthValue::new(47.0, "m"),
                     angle_range: None,
                     jib: None,
+                    telescope_mode: None,
+                    boom_inserts: None,
                 },
                 counterweight: None,
                 additional: HashMap::new(),
             },
             capacity_data,
             notes: Vec::new(),
+            provenance: ChartProvenance::default(),
         }
     }
 
@@ -860,6 +1656,8 @@ mod tests {
             length: LengthValue::new(154.2, "ft"),
             angle_range: None,
             jib: None,
+            telescope_mode: None,
+            boom_inserts: None,
         };
 
         let distance = boom.length_distance().unwrap();
@@ -870,6 +1668,8 @@ mod tests {
             length: LengthValue::new(47.0, "m"),
             angle_range: None,
             jib: None,
+            telescope_mode: None,
+            boom_inserts: None,
         };
 
         let distance_metric = boom_metric.length_distance().unwrap();
@@ -896,5 +1696,256 @@ mod tests {
         assert_relative_eq!(weight_metric.get::<kilogram>(), 50000.0);
         assert_relative_eq!(weight_metric.get::<pound>(), 110231.0, epsilon = 1.0);
     }
-}
 
+    #[test]
+    fn test_radius_for_capacity() {
+        let chart = create_test_chart_us();
+        let boom = Length::new::<foot>(154.2);
+
+        // Halfway between 242,500 at 20 ft and 152,000 at 40 ft
+        let radius = chart
+            .radius_for_capacity(boom, Mass::new::<pound>(197250.0))
+            .unwrap()
+            .unwrap();
+        assert_relative_eq!(radius.get::<foot>(), 30.0, epsilon = 1e-6);
+
+        // Light load is within chart out to the last radius
+        let radius = chart
+            .radius_for_capacity(boom, Mass::new::<pound>(40000.0))
+            .unwrap()
+            .unwrap();
+        assert_relative_eq!(radius.get::<foot>(), 60.0, epsilon = 1e-6);
+
+        // Too heavy anywhere
+        assert!(chart
+            .radius_for_capacity(boom, Mass::new::<pound>(300000.0))
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_package_envelope_for_load() {
+        let mut package = LoadChartPackage::default();
+        package.add_chart(create_test_chart_us());
+        package.add_chart(create_test_chart_metric());
+
+        let envelope = package
+            .envelope_for_load(Mass::new::<pound>(152000.0))
+            .unwrap();
+
+        assert_eq!(envelope.len(), 2);
+        assert_eq!(envelope[0].chart_id, "test_us");
+        assert_relative_eq!(envelope[0].max_radius.get::<foot>(), 40.0, epsilon = 1e-6);
+        assert_relative_eq!(envelope[1].max_radius.get::<meter>(), 12.0, epsilon = 0.05);
+    }
+
+    #[test]
+    fn test_normalize_units() {
+        let mut package = LoadChartPackage::default();
+        package.add_chart(create_test_chart_us());
+        package.add_chart(create_test_chart_metric());
+
+        package.normalize_units(UnitSystem::Imperial).unwrap();
+
+        let metric = &package.charts[1];
+        assert_eq!(metric.capacity_data.boom_lengths[0].unit, "ft");
+        assert_eq!(metric.configuration.boom.length.unit, "ft");
+        assert_relative_eq!(metric.capacity_data.boom_lengths[0].value, 154.199, epsilon = 1e-3);
+
+        let (radius, capacity) = &metric.capacity_data.data[0][1];
+        assert_eq!(radius.unit, "ft");
+        assert_eq!(capacity.unit, "lbs");
+        assert_relative_eq!(capacity.value, 152118.96, epsilon = 1.0);
+
+        // Lookups are unchanged by normalization
+        let cap = metric
+            .capacity_exact(Length::new::<meter>(47.0), Length::new::<meter>(12.0))
+            .unwrap();
+        assert_relative_eq!(cap.get::<kilogram>(), 69000.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_out_of_range_policy() {
+        let chart = create_test_chart_us();
+        let boom = Length::new::<foot>(154.2);
+        let far = Length::new::<foot>(80.0);
+
+        assert!(chart
+            .capacity_with_policy(boom, far, OutOfRangePolicy::Error)
+            .is_err());
+
+        let zero = chart
+            .capacity_with_policy(boom, far, OutOfRangePolicy::ZeroCapacity)
+            .unwrap();
+        assert_relative_eq!(zero.get::<pound>(), 0.0);
+
+        let clamped = chart
+            .capacity_with_policy(boom, far, OutOfRangePolicy::ClampToNearest)
+            .unwrap();
+        assert_relative_eq!(clamped.get::<pound>(), 97000.0);
+
+        // Boom length is clamped too
+        let clamped = chart
+            .capacity_with_policy(Length::new::<foot>(200.0), Length::new::<foot>(10.0), OutOfRangePolicy::ClampToNearest)
+            .unwrap();
+        assert_relative_eq!(clamped.get::<pound>(), 242500.0);
+    }
+
+    /// Full, 50% and minimum extension charts at 100%, 60% and 30% of the US chart
+    fn create_extension_package() -> LoadChartPackage {
+        let chart_at = |id: &str, extension: OutriggerExtension, scale: f64| {
+            let mut chart = create_test_chart_us();
+            chart.id = id.into();
+            chart.configuration.support = SupportConfiguration::OnOutriggers {
+                extension,
+                swing_restriction: Some(SwingRestriction::Full360),
+            };
+            for (_, capacity) in &mut chart.capacity_data.data[0] {
+                capacity.value *= scale;
+            }
+            chart
+        };
+        let mut package = LoadChartPackage::default();
+        package.add_chart(chart_at("full", OutriggerExtension::Full, 1.0));
+        package.add_chart(chart_at("half", OutriggerExtension::Intermediate { percent: 50.0 }, 0.6));
+        package.add_chart(chart_at("min", OutriggerExtension::Minimum, 0.3));
+        package
+    }
+
+    #[test]
+    fn test_capacity_at_extension() {
+        let mut package = create_extension_package();
+
+        let mut config = create_test_chart_us().configuration;
+        let mut query = |package: &LoadChartPackage, percent: f64| {
+            config.support = SupportConfiguration::OnOutriggers {
+                extension: OutriggerExtension::Intermediate { percent },
+                swing_restriction: None,
+            };
+            package.capacity_at_extension(&config, Length::new::<foot>(154.2), Length::new::<foot>(40.0))
+        };
+
+        // Exact chart
+        let rated = query(&package, 50.0).unwrap();
+        assert_eq!(rated.lower_chart, "half");
+        assert!(rated.warning.is_none());
+        assert_relative_eq!(rated.capacity.get::<pound>(), 91200.0, epsilon = 1e-6);
+
+        // Conservative by default: 75% rates on the 50% chart
+        let rated = query(&package, 75.0).unwrap();
+        assert_eq!(rated.lower_chart, "half");
+        assert!(rated.upper_chart.is_none());
+        assert!(rated.warning.is_some());
+        assert_relative_eq!(rated.capacity.get::<pound>(), 91200.0, epsilon = 1e-6);
+
+        package.extension_interpolation = ExtensionInterpolation::Linear;
+        let rated = query(&package, 75.0).unwrap();
+        assert_eq!(rated.upper_chart.as_deref(), Some("full"));
+        assert!(rated.warning.is_none());
+        assert_relative_eq!(rated.capacity.get::<pound>(), 121600.0, epsilon = 1e-6);
+
+        // A chart with a non-numeric extension is never rated on, and doesn't panic the sort
+        let mut corrupt = package.charts[0].clone();
+        corrupt.id = "corrupt".into();
+        corrupt.configuration.support = SupportConfiguration::OnOutriggers {
+            extension: OutriggerExtension::Intermediate { percent: f64::NAN },
+            swing_restriction: None,
+        };
+        package.charts.push(corrupt);
+        assert_eq!(query(&package, 50.0).unwrap().lower_chart, "half");
+        package.charts.retain(|chart| chart.id != "corrupt");
+
+        // Below every chart there's nothing to rate on
+        package.charts.retain(|chart| chart.id != "min");
+        assert!(matches!(query(&package, 25.0), Err(LoadChartError::NoMatchingChart { .. })));
+    }
+
+    #[test]
+    fn test_slew_quadrant() {
+        let quadrant = |deg: f64| SlewQuadrant::from_swing(Angle::new::<degree>(deg));
+        assert_eq!(quadrant(0.0), SlewQuadrant::OverFront);
+        assert_eq!(quadrant(-30.0), SlewQuadrant::OverFront);
+        assert_eq!(quadrant(90.0), SlewQuadrant::OverRight);
+        assert_eq!(quadrant(180.0), SlewQuadrant::OverRear);
+        assert_eq!(quadrant(-90.0), SlewQuadrant::OverLeft);
+    }
+
+    #[test]
+    fn test_capacity_in_quadrant() {
+        let package = create_extension_package();
+        let mut config = create_test_chart_us().configuration;
+        config.support = SupportConfiguration::OnOutriggers {
+            extension: OutriggerExtension::PerCorner {
+                corners: CornerExtensions {
+                    front_left: 100.0,
+                    front_right: 100.0,
+                    rear_left: 100.0,
+                    rear_right: 50.0,
+                },
+            },
+            swing_restriction: None,
+        };
+        let query = |deg: f64| {
+            package
+                .capacity_in_quadrant(
+                    &config,
+                    Angle::new::<degree>(deg),
+                    Length::new::<foot>(154.2),
+                    Length::new::<foot>(40.0),
+                )
+                .unwrap()
+        };
+
+        // Both front beams full: full chart over the front and left
+        let front = query(0.0);
+        assert_relative_eq!(front.governing_extension, 100.0);
+        assert_eq!(front.rated.lower_chart, "full");
+        assert_relative_eq!(front.rated.capacity.get::<pound>(), 152000.0, epsilon = 1e-6);
+        assert_eq!(query(-90.0).rated.lower_chart, "full");
+
+        // Short rear right beam governs over the right side and rear
+        let right = query(90.0);
+        assert_eq!(right.quadrant, SlewQuadrant::OverRight);
+        assert_eq!(right.rated.lower_chart, "half");
+        assert_relative_eq!(right.rated.capacity.get::<pound>(), 91200.0, epsilon = 1e-6);
+        assert_eq!(query(180.0).rated.lower_chart, "half");
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_yaml_round_trip() {
+        let mut package = LoadChartPackage::default();
+        package.add_chart(create_test_chart_us());
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("package.yaml");
+        let path = path.to_str().unwrap();
+
+        package.to_yaml_file(path).unwrap();
+        let loaded = LoadChartPackage::from_yaml_file(path).unwrap();
+
+        let cap = loaded.charts[0]
+            .capacity_exact(Length::new::<foot>(154.2), Length::new::<foot>(40.0))
+            .unwrap();
+        assert_relative_eq!(cap.get::<pound>(), 152000.0);
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_toml_round_trip() {
+        let mut package = LoadChartPackage::default();
+        package.add_chart(create_test_chart_metric());
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("package.toml");
+        let path = path.to_str().unwrap();
+
+        package.to_toml_file(path).unwrap();
+        let loaded = LoadChartPackage::from_toml_file(path).unwrap();
+
+        let cap = loaded.charts[0]
+            .capacity_exact(Length::new::<meter>(47.0), Length::new::<meter>(12.0))
+            .unwrap();
+        assert_relative_eq!(cap.get::<kilogram>(), 69000.0);
+    }
+}
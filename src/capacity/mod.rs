@@ -1,3 +1,26 @@
 pub mod load_chart;
+#[cfg(feature = "charts-io")]
 pub mod chart_library;
+pub mod chart_validation;
 pub mod lift_validation;
+pub mod checklist;
+pub mod placement;
+pub mod pick_study;
+pub mod cycle_time;
+pub mod range_diagram;
+#[cfg(feature = "plotting")]
+pub mod plotting;
+pub mod compiled_chart;
+pub mod capacity_table;
+#[cfg(feature = "charts-io")]
+pub mod provenance;
+pub mod chart_builder;
+#[cfg(feature = "charts-io")]
+pub mod chart_io;
+#[cfg(feature = "charts-io")]
+pub mod plan_io;
+pub mod headroom;
+pub mod comparison;
+pub mod sensitivity;
+#[cfg(feature = "binary-cache")]
+pub mod binary_cache;
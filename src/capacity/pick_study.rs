@@ -0,0 +1,327 @@
+//! Multi-lift study of a pick list from one crane position
+//!
+//! Where [`find_placements`](crate::capacity::placement::find_placements)
+//! searches for a position, a study takes the position as decided and
+//! checks every pick on the list from it, so problem picks show up before
+//! the crane is mobilized.
+//!
+//! # File format
+//!
+//! One row (CSV) or object (JSON array) per pick:
+//!
+//! ```text
+//! id,weight,pick_x,pick_y,pick_z,set_x,set_y,set_z
+//! AHU-1,12500,40,0,10,85,62,-5
+//! ```
+//!
+//! - `weight` pounds, including rigging
+//! - Coordinates in feet, in site coordinates (X right, Y up, Z forward)
+
+use crate::capacity::load_chart::*;
+use crate::capacity::placement::*;
+use crate::report::CheckStatus;
+use crate::types::*;
+use nalgebra as na;
+#[cfg(feature = "charts-io")]
+use serde::Deserialize;
+use std::fmt;
+#[cfg(feature = "charts-io")]
+use std::{io::Read, path::Path};
+
+#[cfg(feature = "charts-io")]
+#[derive(Debug, thiserror::Error)]
+pub enum PickListError {
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
+
+    #[error("CSV error: {0}")]
+    CsvError(#[from] csv::Error),
+
+    #[error("JSON error: {0}")]
+    JsonError(#[from] serde_json::Error),
+
+    #[error("Row {row}: {field} is not a valid number")]
+    InvalidValue { row: usize, field: &'static str },
+}
+
+/// Pick list row as written, in pounds and feet
+#[cfg(feature = "charts-io")]
+#[derive(Debug, Deserialize)]
+struct PickRow {
+    id: String,
+    weight: f64,
+    pick_x: f64,
+    pick_y: f64,
+    pick_z: f64,
+    set_x: f64,
+    set_y: f64,
+    set_z: f64,
+}
+
+#[cfg(feature = "charts-io")]
+impl PickRow {
+    fn into_pick(self, row: usize) -> Result<Pick, PickListError> {
+        if !(self.weight.is_finite() && self.weight > 0.0) {
+            return Err(PickListError::InvalidValue { row, field: "weight" });
+        }
+        let coordinates = [
+            (self.pick_x, "pick_x"),
+            (self.pick_y, "pick_y"),
+            (self.pick_z, "pick_z"),
+            (self.set_x, "set_x"),
+            (self.set_y, "set_y"),
+            (self.set_z, "set_z"),
+        ];
+        if let Some(&(_, field)) = coordinates.iter().find(|(value, _)| !value.is_finite()) {
+            return Err(PickListError::InvalidValue { row, field });
+        }
+        Ok(Pick {
+            name: self.id,
+            load: Mass::new::<pound>(self.weight),
+            pick_point: na::Point3::new(self.pick_x, self.pick_y, self.pick_z),
+            set_point: na::Point3::new(self.set_x, self.set_y, self.set_z),
+        })
+    }
+}
+
+/// Read a CSV pick list (see the module docs for the format)
+#[cfg(feature = "charts-io")]
+pub fn picks_from_csv_reader(reader: impl Read) -> Result<Vec<Pick>, PickListError> {
+    let mut csv = csv::ReaderBuilder::new().trim(csv::Trim::All).from_reader(reader);
+    csv.deserialize::<PickRow>()
+        .enumerate()
+        // Data rows start at line 2, after the header
+        .map(|(i, row)| row?.into_pick(i + 2))
+        .collect()
+}
+
+/// Read a JSON array of pick list rows
+#[cfg(feature = "charts-io")]
+pub fn picks_from_json_reader(reader: impl Read) -> Result<Vec<Pick>, PickListError> {
+    let rows: Vec<PickRow> = serde_json::from_reader(reader)?;
+    rows.into_iter()
+        .enumerate()
+        .map(|(i, row)| row.into_pick(i + 1))
+        .collect()
+}
+
+/// Read a pick list file, JSON if the extension is `.json` and CSV otherwise
+#[cfg(feature = "charts-io")]
+pub fn picks_from_file(path: impl AsRef<Path>) -> Result<Vec<Pick>, PickListError> {
+    let path = path.as_ref();
+    let file = std::fs::File::open(path)?;
+    match path.extension().and_then(|e| e.to_str()) {
+        Some(ext) if ext.eq_ignore_ascii_case("json") => picks_from_json_reader(file),
+        _ => picks_from_csv_reader(file),
+    }
+}
+
+/// One pick's result
+#[derive(Debug, Clone)]
+pub struct PickStudyRow {
+    pub pick: String,
+    pub load: Mass,
+
+    /// Best boom length for the pick; `None` if no boom reaches both points
+    pub assessment: Option<PickAssessment>,
+
+    /// Load / governing capacity
+    pub utilization: Option<f64>,
+    pub status: CheckStatus,
+
+    /// Why the pick was flagged
+    pub problem: Option<String>,
+}
+
+/// Every pick on the list checked from one crane position, in list order
+#[derive(Debug, Clone)]
+pub struct PickStudy {
+    pub chart_id: String,
+
+    /// Crane slew center at ground level (feet)
+    pub position: na::Point3<f64>,
+    pub rows: Vec<PickStudyRow>,
+}
+
+impl PickStudy {
+    /// Picks that failed or fell short of the required margin
+    pub fn problems(&self) -> impl Iterator<Item = &PickStudyRow> {
+        self.rows.iter().filter(|r| r.status != CheckStatus::Pass)
+    }
+
+    /// Worst status across the list
+    pub fn status(&self) -> CheckStatus {
+        if self.rows.iter().any(|r| r.status == CheckStatus::Fail) {
+            CheckStatus::Fail
+        } else if self.rows.iter().any(|r| r.status == CheckStatus::Warning) {
+            CheckStatus::Warning
+        } else {
+            CheckStatus::Pass
+        }
+    }
+
+    /// Highest utilization of the picks that can be made
+    pub fn max_utilization(&self) -> Option<f64> {
+        self.rows.iter().filter_map(|r| r.utilization).reduce(f64::max)
+    }
+
+    pub fn row(&self, pick: &str) -> Option<&PickStudyRow> {
+        self.rows.iter().find(|r| r.pick == pick)
+    }
+}
+
+/// Check every pick from `position` with `chart`
+///
+/// Each pick uses the boom length with the most margin. Picks over chart
+/// or out of reach fail; picks under `options.margin_threshold` are
+/// warnings.
+pub fn study_picks(
+    chart: &LoadChart,
+    position: na::Point3<f64>,
+    picks: &[Pick],
+    options: &PlacementOptions,
+) -> Result<PickStudy, LoadChartError> {
+    let booms = chart.boom_lengths()?;
+
+    let rows = picks
+        .iter()
+        .map(|pick| {
+            let assessment = best_pick(chart, &booms, position, pick, options);
+            let utilization = assessment.as_ref().map(|a| pick.load.get::<pound>() / a.capacity.get::<pound>());
+            let (status, problem) = match &assessment {
+                None => (CheckStatus::Fail, Some("Pick or set point out of reach".to_string())),
+                Some(a) if a.margin < 0.0 => (
+                    CheckStatus::Fail,
+                    Some(format!("Over chart: rated {:.0} lbs", a.capacity.get::<pound>())),
+                ),
+                Some(a) if a.margin < options.margin_threshold => (
+                    CheckStatus::Warning,
+                    Some(format!("Margin {:.1}% below the required {:.1}%", a.margin, options.margin_threshold)),
+                ),
+                Some(_) => (CheckStatus::Pass, None),
+            };
+            PickStudyRow {
+                pick: pick.name.clone(),
+                load: pick.load,
+                assessment,
+                utilization,
+                status,
+                problem,
+            }
+        })
+        .collect();
+
+    Ok(PickStudy { chart_id: chart.id.clone(), position, rows })
+}
+
+impl fmt::Display for PickStudy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<16} {:>10} {:>8} {:>8} {:>8} {:>10} {:>7}  Status",
+            "Pick", "Load lbs", "Boom ft", "Pick ft", "Set ft", "Cap lbs", "Util"
+        )?;
+        writeln!(f, "{}", "─".repeat(86))?;
+
+        for row in &self.rows {
+            match &row.assessment {
+                Some(a) => write!(
+                    f,
+                    "{:<16} {:>10.0} {:>8.1} {:>8.1} {:>8.1} {:>10.0} {:>6.1}%",
+                    row.pick,
+                    row.load.get::<pound>(),
+                    a.boom_length.get::<foot>(),
+                    a.pick_radius.get::<foot>(),
+                    a.set_radius.get::<foot>(),
+                    a.capacity.get::<pound>(),
+                    row.utilization.unwrap_or(0.0) * 100.0,
+                )?,
+                None => write!(
+                    f,
+                    "{:<16} {:>10.0} {:>8} {:>8} {:>8} {:>10} {:>7}",
+                    row.pick,
+                    row.load.get::<pound>(),
+                    "-",
+                    "-",
+                    "-",
+                    "-",
+                    "-"
+                )?,
+            }
+            match &row.problem {
+                Some(problem) => writeln!(f, "  {:?}: {}", row.status, problem)?,
+                None => writeln!(f, "  {:?}", row.status)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::capacity::chart_builder::LoadChartBuilder;
+    use approx::assert_relative_eq;
+
+    fn chart() -> LoadChart {
+        LoadChartBuilder::new("full_outriggers")
+            .outriggers_full()
+            .boom(154.2, "ft")
+            .points(&[(20.0, 242_500.0), (40.0, 152_000.0), (60.0, 97_000.0), (80.0, 68_500.0), (100.0, 50_500.0)])
+            .build()
+            .unwrap()
+    }
+
+    fn pick(name: &str, load: f64, pick_x: f64, set_x: f64) -> Pick {
+        Pick {
+            name: name.into(),
+            load: Mass::new::<pound>(load),
+            pick_point: na::Point3::new(pick_x, 0.0, 0.0),
+            set_point: na::Point3::new(set_x, 10.0, 0.0),
+        }
+    }
+
+    #[test]
+    fn test_study_flags_problem_picks() {
+        let picks = [
+            pick("AHU-1", 48_500.0, 20.0, 60.0),
+            pick("AHU-2", 90_000.0, 20.0, 60.0),
+            pick("Chiller", 120_000.0, 20.0, 60.0),
+            pick("Tank", 5_000.0, 20.0, 200.0),
+        ];
+        let study = study_picks(&chart(), na::Point3::origin(), &picks, &PlacementOptions::default()).unwrap();
+
+        // Set at 60 ft governs: 97,000 lbs
+        let ahu = study.row("AHU-1").unwrap();
+        assert_eq!(ahu.status, CheckStatus::Pass);
+        assert_relative_eq!(ahu.utilization.unwrap(), 0.5, max_relative = 1e-9);
+
+        assert_eq!(study.row("AHU-2").unwrap().status, CheckStatus::Warning);
+        assert_eq!(study.row("Chiller").unwrap().status, CheckStatus::Fail);
+        assert!(study.row("Tank").unwrap().assessment.is_none());
+
+        assert_eq!(study.problems().count(), 3);
+        assert_eq!(study.status(), CheckStatus::Fail);
+        assert_relative_eq!(study.max_utilization().unwrap(), 120_000.0 / 97_000.0, max_relative = 1e-9);
+        assert!(study.to_string().contains("out of reach"));
+    }
+
+    #[cfg(feature = "charts-io")]
+    #[test]
+    fn test_pick_list_csv_and_json() {
+        let csv = "\
+id,weight,pick_x,pick_y,pick_z,set_x,set_y,set_z
+AHU-1,12500,40,0,10,85,62,-5
+";
+        let picks = picks_from_csv_reader(csv.as_bytes()).unwrap();
+        assert_eq!(picks[0].name, "AHU-1");
+        assert_relative_eq!(picks[0].load.get::<pound>(), 12_500.0);
+        assert_relative_eq!(picks[0].set_point.y, 62.0);
+
+        let json = r#"[{"id": "P-1", "weight": -1, "pick_x": 0, "pick_y": 0, "pick_z": 0, "set_x": 0, "set_y": 0, "set_z": 0}]"#;
+        assert!(matches!(
+            picks_from_json_reader(json.as_bytes()),
+            Err(PickListError::InvalidValue { row: 1, field: "weight" })
+        ));
+    }
+}
@@ -0,0 +1,445 @@
+//! Crane placement search for a pick list
+//!
+//! Given a set of picks (load, pick point, set point) and the site
+//! boundary, candidate crane positions are laid out on a grid and each
+//! chart in the crane's package is tried from each position. A placement
+//! is feasible when every pick and set point is within chart with at least
+//! the required capacity margin.
+//!
+//! # Coordinates
+//!
+//! Site coordinates follow the crate convention (X right, Y up, Z forward)
+//! and are stored in **FEET**. Crane positions are the slew center at
+//! ground level.
+
+use crate::capacity::load_chart::*;
+use crate::kinematics::survey::{GridCoordinate, SurveyDatum};
+use crate::types::*;
+use nalgebra as na;
+
+/// A single pick: lift a load from one point and set it at another
+#[derive(Debug, Clone)]
+pub struct Pick {
+    pub name: String,
+
+    /// Load weight including rigging
+    pub load: Mass,
+
+    /// Where the load is picked (feet)
+    pub pick_point: na::Point3<f64>,
+
+    /// Where the load is set (feet)
+    pub set_point: na::Point3<f64>,
+}
+
+impl Pick {
+    pub fn new(
+        name: impl Into<String>,
+        load: Mass,
+        pick_point: (Length, Length, Length),
+        set_point: (Length, Length, Length),
+    ) -> Self {
+        let (px, py, pz) = pick_point;
+        let (sx, sy, sz) = set_point;
+        Self {
+            name: name.into(),
+            load,
+            pick_point: point_from_uom_lengths(px, py, pz),
+            set_point: point_from_uom_lengths(sx, sy, sz),
+        }
+    }
+
+    /// Pick with its points given on the survey grid
+    pub fn surveyed(
+        name: impl Into<String>,
+        load: Mass,
+        pick_point: &GridCoordinate,
+        set_point: &GridCoordinate,
+        datum: &SurveyDatum,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            load,
+            pick_point: datum.to_site(pick_point),
+            set_point: datum.to_site(set_point),
+        }
+    }
+}
+
+/// Circular area the crane may not be placed in (structures, excavations)
+#[derive(Debug, Clone)]
+pub struct ExclusionZone {
+    pub name: String,
+
+    /// Center in plan (X, Z feet)
+    pub center: na::Point2<f64>,
+
+    /// Radius of the zone plus any setup clearance
+    pub radius: Length,
+}
+
+/// Where the crane can be set up
+#[derive(Debug, Clone)]
+pub struct SiteConstraints {
+    /// Minimum corner of the setup area (X, Z feet)
+    pub min: na::Point2<f64>,
+
+    /// Maximum corner of the setup area (X, Z feet)
+    pub max: na::Point2<f64>,
+
+    /// Grid spacing for candidate positions
+    pub grid_spacing: Length,
+
+    /// Areas the crane may not occupy
+    pub exclusion_zones: Vec<ExclusionZone>,
+}
+
+impl SiteConstraints {
+    pub fn new(min: (Length, Length), max: (Length, Length), grid_spacing: Length) -> Self {
+        Self {
+            min: na::Point2::new(to_coord(min.0), to_coord(min.1)),
+            max: na::Point2::new(to_coord(max.0), to_coord(max.1)),
+            grid_spacing,
+            exclusion_zones: Vec::new(),
+        }
+    }
+
+    /// Add a circular exclusion zone
+    pub fn add_exclusion_zone(
+        &mut self,
+        name: impl Into<String>,
+        x: Length,
+        z: Length,
+        radius: Length,
+    ) {
+        self.exclusion_zones.push(ExclusionZone {
+            name: name.into(),
+            center: na::Point2::new(to_coord(x), to_coord(z)),
+            radius,
+        });
+    }
+
+    /// Whether a plan position (feet) is outside every exclusion zone
+    pub fn is_allowed(&self, position: na::Point2<f64>) -> bool {
+        self.exclusion_zones
+            .iter()
+            .all(|zone| (position - zone.center).norm() >= to_coord(zone.radius))
+    }
+
+    /// Candidate crane positions on the grid
+    pub fn candidates(&self) -> Vec<na::Point2<f64>> {
+        let step = to_coord(self.grid_spacing);
+        let mut points = Vec::new();
+
+        let nx = ((self.max.x - self.min.x) / step).floor() as usize;
+        let nz = ((self.max.y - self.min.y) / step).floor() as usize;
+
+        for i in 0..=nx {
+            for j in 0..=nz {
+                let p = na::Point2::new(self.min.x + i as f64 * step, self.min.y + j as f64 * step);
+                if self.is_allowed(p) {
+                    points.push(p);
+                }
+            }
+        }
+        points
+    }
+}
+
+/// Search options
+#[derive(Debug, Clone, Copy)]
+pub struct PlacementOptions {
+    /// Minimum capacity margin required on every pick (percent)
+    pub margin_threshold: f64,
+
+    /// Boom pivot height above ground
+    pub pivot_height: Length,
+
+    /// Height needed above the pick/set point for hook block and rigging
+    pub rigging_height: Length,
+
+    /// Maximum number of ranked options returned
+    pub max_results: usize,
+}
+
+impl Default for PlacementOptions {
+    fn default() -> Self {
+        Self {
+            margin_threshold: 10.0,
+            pivot_height: Length::new::<foot>(10.0),
+            rigging_height: Length::new::<foot>(20.0),
+            max_results: 10,
+        }
+    }
+}
+
+/// How one pick is made from a placement
+#[derive(Debug, Clone)]
+pub struct PickAssessment {
+    pub pick: String,
+    pub boom_length: Length,
+    pub pick_radius: Length,
+    pub set_radius: Length,
+
+    /// Governing (lowest) capacity of the pick and set points
+    pub capacity: Mass,
+
+    /// Capacity margin (percent)
+    pub margin: f64,
+}
+
+/// A feasible crane placement
+#[derive(Debug, Clone)]
+pub struct PlacementOption {
+    /// Crane slew center at ground level (feet)
+    pub position: na::Point3<f64>,
+
+    /// Chart (configuration) used
+    pub chart_id: String,
+
+    /// Lowest margin across all picks (percent)
+    pub min_margin: f64,
+
+    /// Assessment of each pick
+    pub picks: Vec<PickAssessment>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PlacementError {
+    #[error("No picks to plan")]
+    NoPicks,
+
+    #[error("Chart package contains no charts")]
+    NoCharts,
+
+    #[error("Invalid site: {0}")]
+    InvalidSite(String),
+}
+
+/// Search the site for crane positions that make every pick
+///
+/// Options are ranked by their lowest pick margin, best first.
+pub fn find_placements(
+    package: &LoadChartPackage,
+    picks: &[Pick],
+    site: &SiteConstraints,
+    options: &PlacementOptions,
+) -> Result<Vec<PlacementOption>, PlacementError> {
+    if picks.is_empty() {
+        return Err(PlacementError::NoPicks);
+    }
+    if package.charts.is_empty() {
+        return Err(PlacementError::NoCharts);
+    }
+    if to_coord(site.grid_spacing) <= 0.0 {
+        return Err(PlacementError::InvalidSite("Grid spacing must be positive".into()));
+    }
+    if site.max.x < site.min.x || site.max.y < site.min.y {
+        return Err(PlacementError::InvalidSite("Maximum corner is below minimum corner".into()));
+    }
+
+    let mut results = Vec::new();
+
+    for candidate in site.candidates() {
+        let position = na::Point3::new(candidate.x, 0.0, candidate.y);
+
+        for chart in &package.charts {
+            if let Some(option) = assess_placement(chart, position, picks, options) {
+                results.push(option);
+            }
+        }
+    }
+
+    results.sort_by(|a, b| b.min_margin.partial_cmp(&a.min_margin).unwrap_or(std::cmp::Ordering::Equal));
+    results.truncate(options.max_results);
+
+    Ok(results)
+}
+
+/// Assess every pick from one position with one chart
+fn assess_placement(
+    chart: &LoadChart,
+    position: na::Point3<f64>,
+    picks: &[Pick],
+    options: &PlacementOptions,
+) -> Option<PlacementOption> {
+    let booms = chart.boom_lengths().ok()?;
+    let mut assessments = Vec::with_capacity(picks.len());
+
+    for pick in picks {
+        let best = best_pick(chart, &booms, position, pick, options)?;
+        if best.margin < options.margin_threshold {
+            return None;
+        }
+        assessments.push(best);
+    }
+
+    let min_margin = assessments
+        .iter()
+        .map(|a| a.margin)
+        .fold(f64::INFINITY, f64::min);
+
+    Some(PlacementOption {
+        position,
+        chart_id: chart.id.clone(),
+        min_margin,
+        picks: assessments,
+    })
+}
+
+/// Boom length with the most margin for a pick, None if no boom makes it
+pub(crate) fn best_pick(
+    chart: &LoadChart,
+    booms: &[Length],
+    position: na::Point3<f64>,
+    pick: &Pick,
+    options: &PlacementOptions,
+) -> Option<PickAssessment> {
+    booms
+        .iter()
+        .filter_map(|&boom| assess_pick(chart, boom, position, pick, options))
+        .max_by(|a, b| a.margin.partial_cmp(&b.margin).unwrap_or(std::cmp::Ordering::Equal))
+}
+
+/// Assess one pick at one boom length, None if it cannot be made
+fn assess_pick(
+    chart: &LoadChart,
+    boom_length: Length,
+    position: na::Point3<f64>,
+    pick: &Pick,
+    options: &PlacementOptions,
+) -> Option<PickAssessment> {
+    let pick_radius = horizontal_distance(position, pick.pick_point);
+    let set_radius = horizontal_distance(position, pick.set_point);
+
+    let mut capacity = Mass::new::<pound>(f64::INFINITY);
+    for (radius, point) in [(pick_radius, pick.pick_point), (set_radius, pick.set_point)] {
+        if !reaches_height(boom_length, radius, point, options) {
+            return None;
+        }
+        let cap = chart.capacity_interpolated(boom_length, radius).ok()?;
+        if cap < capacity {
+            capacity = cap;
+        }
+    }
+
+    let capacity_lb = capacity.get::<pound>();
+    if capacity_lb <= 0.0 {
+        return None;
+    }
+
+    let margin = (capacity_lb - pick.load.get::<pound>()) / capacity_lb * 100.0;
+
+    Some(PickAssessment {
+        pick: pick.name.clone(),
+        boom_length,
+        pick_radius,
+        set_radius,
+        capacity,
+        margin,
+    })
+}
+
+/// Horizontal distance between two site points
+fn horizontal_distance(a: na::Point3<f64>, b: na::Point3<f64>) -> Length {
+    from_coord(((b.x - a.x).powi(2) + (b.z - a.z).powi(2)).sqrt())
+}
+
+/// Whether the boom tip clears the point plus rigging height at this radius
+fn reaches_height(
+    boom_length: Length,
+    radius: Length,
+    point: na::Point3<f64>,
+    options: &PlacementOptions,
+) -> bool {
+    let boom = to_coord(boom_length);
+    let r = to_coord(radius);
+    if r >= boom {
+        return false;
+    }
+    let tip_height = to_coord(options.pivot_height) + (boom * boom - r * r).sqrt();
+    tip_height >= point.y + to_coord(options.rigging_height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::capacity::chart_builder::LoadChartBuilder;
+    use crate::equipment::CraneType;
+
+    fn test_package() -> LoadChartPackage {
+        let mut package = LoadChartPackage::new(CraneInfo {
+            manufacturer: "Grove".into(),
+            model: "GMK5250L".into(),
+            serial_number: None,
+            crane_type: CraneType::AllTerrain,
+            year: None,
+            chart_revision: None,
+        });
+        let chart = LoadChartBuilder::new("full_outriggers")
+            .description("Test chart")
+            .boom(154.2, "ft")
+            .points(&[(20.0, 242500.0), (40.0, 152000.0), (60.0, 97000.0), (80.0, 68500.0), (100.0, 50500.0)])
+            .build();
+        package.add_chart(chart.unwrap());
+        package
+    }
+
+    fn test_picks() -> Vec<Pick> {
+        vec![Pick::new(
+            "AHU-1",
+            Mass::new::<pound>(60000.0),
+            (Length::new::<foot>(0.0), Length::new::<foot>(0.0), Length::new::<foot>(0.0)),
+            (Length::new::<foot>(60.0), Length::new::<foot>(40.0), Length::new::<foot>(0.0)),
+        )]
+    }
+
+    fn test_site() -> SiteConstraints {
+        SiteConstraints::new(
+            (Length::new::<foot>(-100.0), Length::new::<foot>(-100.0)),
+            (Length::new::<foot>(100.0), Length::new::<foot>(100.0)),
+            Length::new::<foot>(10.0),
+        )
+    }
+
+    #[test]
+    fn test_finds_ranked_placements() {
+        let options = PlacementOptions::default();
+        let results = find_placements(&test_package(), &test_picks(), &test_site(), &options).unwrap();
+
+        assert!(!results.is_empty());
+        for pair in results.windows(2) {
+            assert!(pair[0].min_margin >= pair[1].min_margin);
+        }
+        assert!(results.iter().all(|r| r.min_margin >= options.margin_threshold));
+    }
+
+    #[test]
+    fn test_exclusion_zone_respected() {
+        let mut site = test_site();
+        site.add_exclusion_zone("Building", Length::new::<foot>(30.0), Length::new::<foot>(0.0), Length::new::<foot>(40.0));
+
+        let results =
+            find_placements(&test_package(), &test_picks(), &site, &PlacementOptions::default()).unwrap();
+
+        for option in &results {
+            let plan = na::Point2::new(option.position.x, option.position.z);
+            assert!(site.is_allowed(plan));
+        }
+    }
+
+    #[test]
+    fn test_overweight_pick_has_no_placement() {
+        let mut picks = test_picks();
+        picks[0].load = Mass::new::<pound>(500000.0);
+
+        let results =
+            find_placements(&test_package(), &picks, &test_site(), &PlacementOptions::default()).unwrap();
+        assert!(results.is_empty());
+
+        assert!(matches!(
+            find_placements(&test_package(), &[], &test_site(), &PlacementOptions::default()),
+            Err(PlacementError::NoPicks)
+        ));
+    }
+}
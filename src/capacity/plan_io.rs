@@ -0,0 +1,152 @@
+//! Saved lift plans
+//!
+//! Plans are kept for years for audit, so every saved plan carries the
+//! schema version it was written with, and a plan from a newer build is
+//! refused rather than read wrong. Fields added since a document was
+//! written take their defaults.
+
+use crate::capacity::lift_validation::LiftPlan;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::io::{Read, Write};
+
+/// Schema version written by this build
+pub const LIFT_PLAN_SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, thiserror::Error)]
+pub enum PlanIoError {
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
+
+    #[error("JSON error: {0}")]
+    JsonError(#[from] serde_json::Error),
+
+    #[error("Plan schema version {found} is newer than this build supports ({supported})")]
+    UnsupportedVersion { found: u64, supported: u32 },
+
+    #[error("Plan has no schema version")]
+    MissingVersion,
+}
+
+#[derive(Serialize)]
+struct PlanDocumentRef<'a> {
+    schema_version: u32,
+    plan: &'a LiftPlan,
+}
+
+#[derive(Deserialize)]
+struct PlanDocument {
+    plan: LiftPlan,
+}
+
+impl LiftPlan {
+    /// Write the plan as pretty JSON at the current schema version
+    pub fn to_writer(&self, writer: impl Write) -> Result<(), PlanIoError> {
+        let document = PlanDocumentRef { schema_version: LIFT_PLAN_SCHEMA_VERSION, plan: self };
+        Ok(serde_json::to_writer_pretty(writer, &document)?)
+    }
+
+    /// Read a saved plan written at this schema version or an older one
+    pub fn from_reader(reader: impl Read) -> Result<Self, PlanIoError> {
+        let document: Value = serde_json::from_reader(reader)?;
+        let found = document.get("schema_version").and_then(Value::as_u64).ok_or(PlanIoError::MissingVersion)?;
+        if found > LIFT_PLAN_SCHEMA_VERSION as u64 {
+            return Err(PlanIoError::UnsupportedVersion { found, supported: LIFT_PLAN_SCHEMA_VERSION });
+        }
+        Ok(serde_json::from_value::<PlanDocument>(document)?.plan)
+    }
+
+    pub fn to_file(&self, path: impl AsRef<std::path::Path>) -> Result<(), PlanIoError> {
+        self.to_writer(std::io::BufWriter::new(std::fs::File::create(path)?))
+    }
+
+    pub fn from_file(path: impl AsRef<std::path::Path>) -> Result<Self, PlanIoError> {
+        Self::from_reader(std::io::BufReader::new(std::fs::File::open(path)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::capacity::lift_validation::*;
+    use crate::capacity::provenance::ProvenanceError;
+    use crate::rigging::LegSharing;
+    use crate::test_fixtures::{chart_package, lift_plan, mobile_crane};
+    use crate::types::*;
+    use approx::assert_relative_eq;
+
+    // Choker hitch through a 12 lb shackle, rated to EN 13000
+    fn plan() -> LiftPlan {
+        let mut plan = lift_plan(12_000.0);
+        plan.rigging.configuration = RiggingConfig::Choker { efficiency: 0.75 };
+        plan.rigging.hardware[0].weight = Mass::new::<pound>(12.0);
+        plan.environment.temperature = ThermodynamicTemperature::new::<degree_fahrenheit>(50.0);
+        plan.safety_factors = SafetyFactors::en13000();
+        plan
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut bytes = Vec::new();
+        plan().to_writer(&mut bytes).unwrap();
+        let loaded = LiftPlan::from_reader(bytes.as_slice()).unwrap();
+        assert_eq!(loaded.safety_factors, SafetyFactors::en13000());
+        assert_relative_eq!(loaded.hook_load().get::<pound>(), 12012.0, max_relative = 1e-9);
+
+        let newer = r#"{ "schema_version": 99, "plan": {} }"#;
+        assert!(matches!(
+            LiftPlan::from_reader(newer.as_bytes()),
+            Err(PlanIoError::UnsupportedVersion { found: 99, .. })
+        ));
+        assert!(matches!(LiftPlan::from_reader(r#"{ "plan": {} }"#.as_bytes()), Err(PlanIoError::MissingVersion)));
+    }
+
+    #[test]
+    fn test_audit_matches_saved_plan() {
+        let original = plan();
+        let mut crane = mobile_crane();
+        let package = chart_package();
+        let audit = AuditRecord::new(&original, &crane, Some(&package)).unwrap();
+        let report = ValidationReport::new().with_audit(audit);
+        let audit = report.audit.as_ref().unwrap();
+        assert_eq!(audit.crate_version, env!("CARGO_PKG_VERSION"));
+
+        let mut bytes = Vec::new();
+        original.to_writer(&mut bytes).unwrap();
+        let mut loaded = LiftPlan::from_reader(bytes.as_slice()).unwrap();
+        assert!(audit.verify(&loaded, &crane, Some(&package)).is_ok());
+        assert!(audit.verify(&loaded, &crane, None).is_err());
+
+        // The crane's configuration is part of the inputs
+        crane.counterweight = Mass::new::<pound>(20_000.0);
+        assert!(matches!(
+            audit.verify(&loaded, &crane, Some(&package)),
+            Err(ProvenanceError::HashMismatch { .. })
+        ));
+        let crane = mobile_crane();
+
+        loaded.load_weight = Mass::new::<pound>(12500.0);
+        let verified = audit.verify(&loaded, &crane, Some(&package));
+        assert!(matches!(verified, Err(ProvenanceError::HashMismatch { .. })));
+    }
+
+    #[test]
+    fn test_omitted_fields_take_defaults() {
+        let mut plan = plan();
+        let (leg_angle, sharing) = (Angle::new::<degree>(30.0), LegSharing::AnyThree);
+        plan.rigging.configuration = RiggingConfig::Bridle { leg_angle, num_legs: 2, sharing };
+        let mut document = serde_json::to_value(PlanDocumentRef { schema_version: 1, plan: &plan }).unwrap();
+        let rigging = &mut document["plan"]["rigging"];
+        rigging["configuration"]["Bridle"].as_object_mut().unwrap().remove("sharing");
+        rigging["hardware"][0].as_object_mut().unwrap().remove("weight");
+        document["plan"]["ground"].as_object_mut().unwrap().remove("conditions");
+
+        let loaded = LiftPlan::from_reader(document.to_string().as_bytes()).unwrap();
+        let sharing = match loaded.rigging.configuration {
+            RiggingConfig::Bridle { sharing, .. } => sharing,
+            _ => panic!("expected a bridle"),
+        };
+        assert_eq!(sharing, LegSharing::AllLegs);
+        assert_relative_eq!(loaded.rigging.hardware[0].weight.get::<pound>(), 0.0);
+    }
+}
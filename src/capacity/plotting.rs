@@ -0,0 +1,250 @@
+//! Plot data and SVG for load charts and range diagrams
+//!
+//! A [`Plot`] is a set of named x/y series with axis labels, ready for any
+//! plotting library; [`Plot::to_svg`] draws it as a plain line chart.
+//!
+//! - [`capacity_curves`]: capacity (lbs) against radius (ft), one series
+//!   per boom length
+//! - [`range_diagram_plot`]: height (ft) against radius (ft), from a
+//!   [`RangeDiagram`]
+
+use crate::capacity::load_chart::*;
+use crate::capacity::range_diagram::*;
+use crate::report::escape_xml;
+use crate::types::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+
+/// Line colours, cycled through the series
+const PALETTE: [&str; 8] = ["#1f77b4", "#ff7f0e", "#2ca02c", "#d62728", "#9467bd", "#8c564b", "#e377c2", "#7f7f7f"];
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Series {
+    pub label: String,
+    pub points: Vec<(f64, f64)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Plot {
+    pub title: String,
+    pub x_label: String,
+    pub y_label: String,
+
+    /// Draw x and y to the same scale, as for geometry
+    pub equal_aspect: bool,
+    pub series: Vec<Series>,
+}
+
+/// Capacity against radius for every boom length in the chart
+pub fn capacity_curves(chart: &LoadChart) -> Result<Plot, LoadChartError> {
+    let series = chart
+        .boom_lengths()?
+        .iter()
+        .enumerate()
+        .map(|(i, boom)| {
+            let points = chart
+                .capacity_points(i)?
+                .into_iter()
+                .map(|(radius, capacity)| (radius.get::<foot>(), capacity.get::<pound>()))
+                .collect();
+            Ok(Series { label: format!("{:.1} ft boom", boom.get::<foot>()), points })
+        })
+        .collect::<Result<_, LoadChartError>>()?;
+
+    Ok(Plot {
+        title: format!("{} capacity", chart.id),
+        x_label: "Radius (ft)".into(),
+        y_label: "Capacity (lbs)".into(),
+        equal_aspect: false,
+        series,
+    })
+}
+
+/// Every line of a range diagram, envelope first
+pub fn range_diagram_plot(diagram: &RangeDiagram) -> Plot {
+    let series_of = |line: &Polyline| Series {
+        label: line.label.clone(),
+        points: line.points.iter().map(|p| (p.x, p.y)).collect(),
+    };
+    let series = [&diagram.envelope, &diagram.min_radius_line, &diagram.max_radius_line]
+        .into_iter()
+        .chain(&diagram.boom_arcs)
+        .chain(&diagram.angle_lines)
+        .chain(diagram.iso_contours.iter().map(|contour| &contour.line))
+        .map(series_of)
+        .collect();
+
+    Plot {
+        title: format!("{} range diagram", diagram.chart_id),
+        x_label: "Radius (ft)".into(),
+        y_label: "Height (ft)".into(),
+        equal_aspect: true,
+        series,
+    }
+}
+
+impl Plot {
+    /// Data bounds as `(x_min, x_max, y_min, y_max)`; the origin is always
+    /// included
+    pub fn bounds(&self) -> (f64, f64, f64, f64) {
+        self.series.iter().flat_map(|s| &s.points).fold((0.0, 0.0, 0.0, 0.0), |(x0, x1, y0, y1), &(x, y)| {
+            (x0.min(x), x1.max(x), y0.min(y), y1.max(y))
+        })
+    }
+
+    /// Line chart `width` by `height` pixels, with axes and a legend
+    pub fn to_svg(&self, width: f64, height: f64) -> String {
+        let (left, right, top, bottom) = (60.0, 160.0, 30.0, 40.0);
+        let (x_min, x_max, y_min, y_max) = self.bounds();
+        let (x_step, y_step) = (tick_step(x_max - x_min), tick_step(y_max - y_min));
+        let (x_min, x_max) = ((x_min / x_step).floor() * x_step, (x_max / x_step).ceil() * x_step);
+        let (y_min, y_max) = ((y_min / y_step).floor() * y_step, (y_max / y_step).ceil() * y_step);
+
+        let (plot_w, plot_h) = (width - left - right, height - top - bottom);
+        let (mut sx, mut sy) = (plot_w / (x_max - x_min).max(1e-9), plot_h / (y_max - y_min).max(1e-9));
+        if self.equal_aspect {
+            sx = sx.min(sy);
+            sy = sx;
+        }
+        let px = |x: f64| left + (x - x_min) * sx;
+        let py = |y: f64| top + plot_h - (y - y_min) * sy;
+
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width:.0}" height="{height:.0}" viewBox="0 0 {width:.0} {height:.0}" font-family="sans-serif" font-size="11">"#
+        );
+        let _ = writeln!(
+            svg,
+            r#"  <text x="{:.1}" y="18" font-size="14">{}</text>"#,
+            left,
+            escape_xml(&self.title)
+        );
+
+        // Axes and ticks
+        let _ = writeln!(
+            svg,
+            r#"  <path d="M{:.1},{:.1} V{:.1} H{:.1}" fill="none" stroke="black"/>"#,
+            px(x_min),
+            py(y_max),
+            py(y_min),
+            px(x_max)
+        );
+        for i in 0..=((x_max - x_min) / x_step).round() as usize {
+            let x = x_min + i as f64 * x_step;
+            let _ = writeln!(
+                svg,
+                r#"  <text x="{:.1}" y="{:.1}" text-anchor="middle">{}</text>"#,
+                px(x),
+                py(y_min) + 15.0,
+                tick_label(x, x_step)
+            );
+        }
+        for i in 0..=((y_max - y_min) / y_step).round() as usize {
+            let y = y_min + i as f64 * y_step;
+            let _ = writeln!(
+                svg,
+                r#"  <text x="{:.1}" y="{:.1}" text-anchor="end">{}</text>"#,
+                px(x_min) - 5.0,
+                py(y) + 4.0,
+                tick_label(y, y_step)
+            );
+        }
+        let _ = writeln!(
+            svg,
+            r#"  <text x="{:.1}" y="{:.1}" text-anchor="middle">{}</text>"#,
+            left + plot_w / 2.0,
+            height - 5.0,
+            escape_xml(&self.x_label)
+        );
+        let _ = writeln!(
+            svg,
+            r#"  <text x="12" y="{:.1}" transform="rotate(-90 12 {:.1})" text-anchor="middle">{}</text>"#,
+            top + plot_h / 2.0,
+            top + plot_h / 2.0,
+            escape_xml(&self.y_label)
+        );
+
+        // Series and legend
+        for (i, series) in self.series.iter().enumerate() {
+            let colour = PALETTE[i % PALETTE.len()];
+            let points: Vec<String> = series.points.iter().map(|&(x, y)| format!("{:.1},{:.1}", px(x), py(y))).collect();
+            let _ = writeln!(
+                svg,
+                r#"  <polyline points="{}" fill="none" stroke="{colour}"><title>{}</title></polyline>"#,
+                points.join(" "),
+                escape_xml(&series.label)
+            );
+            let legend_y = top + 14.0 * i as f64;
+            let _ = writeln!(
+                svg,
+                r#"  <text x="{:.1}" y="{:.1}" fill="{colour}">{}</text>"#,
+                width - right + 10.0,
+                legend_y,
+                escape_xml(&series.label)
+            );
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+/// Round tick spacing giving about five ticks over `span`
+fn tick_step(span: f64) -> f64 {
+    if span <= 0.0 {
+        return 1.0;
+    }
+    let raw = span / 5.0;
+    let magnitude = 10f64.powf(raw.log10().floor());
+    [1.0, 2.0, 5.0, 10.0]
+        .iter()
+        .map(|m| m * magnitude)
+        .find(|&step| step >= raw)
+        .unwrap_or(10.0 * magnitude)
+}
+
+/// Tick value with as many decimals as the step needs
+fn tick_label(value: f64, step: f64) -> String {
+    let decimals = (-step.log10().floor()).max(0.0) as usize;
+    format!("{:.*}", decimals, value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::capacity::chart_builder::LoadChartBuilder;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_chart_and_range_plots() {
+        let chart = LoadChartBuilder::new("main")
+            .outriggers_full()
+            .boom(100.0, "ft")
+            .points(&[(20.0, 80_000.0), (40.0, 40_000.0), (60.0, 20_000.0)])
+            .build()
+            .unwrap();
+
+        let curves = capacity_curves(&chart).unwrap();
+        assert_eq!(curves.series.len(), 1);
+        assert_eq!(curves.series[0].label, "100.0 ft boom");
+        assert_relative_eq!(curves.series[0].points[1].1, 40_000.0);
+        let (_, x_max, _, y_max) = curves.bounds();
+        assert_relative_eq!(x_max, 60.0, max_relative = 1e-9);
+        assert_relative_eq!(y_max, 80_000.0, max_relative = 1e-9);
+        assert_relative_eq!(tick_step(80_000.0), 20_000.0);
+        assert_eq!(tick_label(0.1 + 0.2, 0.1), "0.3");
+
+        let svg = curves.to_svg(640.0, 400.0);
+        assert_eq!(svg.matches("<polyline").count(), 1);
+        assert!(svg.contains("Capacity (lbs)"));
+
+        let diagram = RangeDiagram::generate(&chart, &RangeDiagramOptions::default()).unwrap();
+        let range = range_diagram_plot(&diagram);
+        assert!(range.equal_aspect);
+        assert_eq!(range.series[0].label, diagram.envelope.label);
+        assert_eq!(range.series.len(), 3 + diagram.boom_arcs.len() + diagram.angle_lines.len());
+    }
+}
@@ -0,0 +1,246 @@
+//! Chart provenance and content hashing
+//!
+//! When charts drive safety decisions, a planner must be able to show that
+//! the data used is exactly the data that was checked against the
+//! manufacturer's document. Each chart and package can record a SHA-256
+//! content hash in its [`ChartProvenance`] and verify against it later.
+//!
+//! The hash covers the canonical JSON form (sorted keys) with every
+//! recorded `content_hash` cleared, so recording a hash doesn't change it.
+
+use crate::capacity::lift_validation::{AuditRecord, LiftPlan};
+use crate::capacity::load_chart::*;
+use crate::types::*;
+use sha2::{Digest, Sha256};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ProvenanceError {
+    #[error("JSON error: {0}")]
+    JsonError(#[from] serde_json::Error),
+
+    #[error("No content hash recorded for {0}")]
+    MissingHash(String),
+
+    #[error("Content hash mismatch for {what}: recorded {expected}, computed {actual}")]
+    HashMismatch {
+        what: String,
+        expected: String,
+        actual: String,
+    },
+}
+
+/// SHA-256 of a value's canonical JSON, hex encoded
+fn canonical_hash<T: serde::Serialize>(value: &T) -> Result<String, ProvenanceError> {
+    // serde_json::Value keeps object keys sorted, so HashMap order can't leak in
+    let canonical = serde_json::to_string(&serde_json::to_value(value)?)?;
+    let digest = Sha256::digest(canonical.as_bytes());
+    Ok(digest.iter().map(|b| format!("{:02x}", b)).collect())
+}
+
+fn check(what: String, recorded: Option<&String>, actual: String) -> Result<(), ProvenanceError> {
+    let expected = recorded.ok_or_else(|| ProvenanceError::MissingHash(what.clone()))?;
+    if !expected.eq_ignore_ascii_case(&actual) {
+        return Err(ProvenanceError::HashMismatch {
+            what,
+            expected: expected.clone(),
+            actual,
+        });
+    }
+    Ok(())
+}
+
+impl LoadChart {
+    /// Hash of this chart's content, excluding its recorded hash
+    pub fn content_hash(&self) -> Result<String, ProvenanceError> {
+        let mut chart = self.clone();
+        chart.provenance.content_hash = None;
+        canonical_hash(&chart)
+    }
+
+    /// Record the current content hash in the provenance
+    pub fn record_hash(&mut self) -> Result<(), ProvenanceError> {
+        self.provenance.content_hash = Some(self.content_hash()?);
+        Ok(())
+    }
+
+    /// Check the content still matches the recorded hash
+    pub fn verify_hash(&self) -> Result<(), ProvenanceError> {
+        check(
+            format!("chart '{}'", self.id),
+            self.provenance.content_hash.as_ref(),
+            self.content_hash()?,
+        )
+    }
+}
+
+impl LoadChartPackage {
+    /// Hash of the whole package, excluding all recorded hashes
+    pub fn content_hash(&self) -> Result<String, ProvenanceError> {
+        let mut package = self.clone();
+        package.provenance.content_hash = None;
+        for chart in &mut package.charts {
+            chart.provenance.content_hash = None;
+        }
+        let mut value = serde_json::to_value(&package)?;
+        // Leave the default out so hashes recorded before the field existed still verify
+        if package.extension_interpolation == ExtensionInterpolation::default()
+            && let Some(fields) = value.as_object_mut()
+        {
+            fields.remove("extension_interpolation");
+        }
+        canonical_hash(&value)
+    }
+
+    /// Record hashes for every chart and for the package
+    pub fn record_hash(&mut self) -> Result<(), ProvenanceError> {
+        for chart in &mut self.charts {
+            chart.record_hash()?;
+        }
+        self.provenance.content_hash = Some(self.content_hash()?);
+        Ok(())
+    }
+
+    /// Check the package and every chart against their recorded hashes
+    pub fn verify_hash(&self) -> Result<(), ProvenanceError> {
+        check(
+            format!("package {} {}", self.crane_info.manufacturer, self.crane_info.model),
+            self.provenance.content_hash.as_ref(),
+            self.content_hash()?,
+        )?;
+        for chart in &self.charts {
+            chart.verify_hash()?;
+        }
+        Ok(())
+    }
+
+    /// Check the package against a hash recorded elsewhere (e.g. a lift plan)
+    pub fn verify_against(&self, expected: &str) -> Result<(), ProvenanceError> {
+        check(
+            format!("package {} {}", self.crane_info.manufacturer, self.crane_info.model),
+            Some(&expected.to_string()),
+            self.content_hash()?,
+        )
+    }
+}
+
+/// Everything a validation run depends on, hashed together for the audit
+#[derive(serde::Serialize)]
+struct AuditInputs<'a, C> {
+    plan: &'a LiftPlan,
+    crane: &'a C,
+    chart_hash: Option<&'a str>,
+}
+
+fn input_hash<C: serde::Serialize>(
+    plan: &LiftPlan,
+    crane: &C,
+    chart_hash: Option<&str>,
+) -> Result<String, ProvenanceError> {
+    canonical_hash(&AuditInputs { plan, crane, chart_hash })
+}
+
+impl AuditRecord {
+    /// Record for validating `plan` on `crane` now against the chart data
+    /// in `package`
+    pub fn new<C: serde::Serialize>(
+        plan: &LiftPlan,
+        crane: &C,
+        package: Option<&LoadChartPackage>,
+    ) -> Result<Self, ProvenanceError> {
+        let since_epoch = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default();
+        let chart_hash = package.map(LoadChartPackage::content_hash).transpose()?;
+        Ok(Self {
+            timestamp: Time::new::<second>(since_epoch.as_secs_f64()),
+            crate_version: env!("CARGO_PKG_VERSION").into(),
+            chart_revision: package.and_then(|p| p.crane_info.chart_revision.clone()),
+            input_hash: input_hash(plan, crane, chart_hash.as_deref())?,
+            chart_hash,
+        })
+    }
+
+    /// Check that `plan`, `crane` and `package` are the inputs this record
+    /// was made from
+    pub fn verify<C: serde::Serialize>(
+        &self,
+        plan: &LiftPlan,
+        crane: &C,
+        package: Option<&LoadChartPackage>,
+    ) -> Result<(), ProvenanceError> {
+        let chart_hash = package.map(LoadChartPackage::content_hash).transpose()?;
+        match (&chart_hash, &self.chart_hash) {
+            (Some(actual), recorded) => check("chart package".into(), recorded.as_ref(), actual.clone())?,
+            (None, None) => {}
+            (None, Some(expected)) => {
+                return Err(ProvenanceError::HashMismatch {
+                    what: "chart package".into(),
+                    expected: expected.clone(),
+                    actual: "no package".into(),
+                });
+            }
+        }
+        check("lift inputs".into(), Some(&self.input_hash), input_hash(plan, crane, chart_hash.as_deref())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::capacity::chart_builder::LoadChartBuilder;
+
+    fn test_package() -> LoadChartPackage {
+        let chart = LoadChartBuilder::new("provenance")
+            .description("Provenance test")
+            .boom(154.2, "ft")
+            .points(&[(20.0, 242500.0), (40.0, 152000.0)])
+            .additional("hook_block", "5-sheave")
+            .additional("jib_stowed", "yes")
+            .provenance(ChartProvenance {
+                source_document: Some("GMK5250L Load Chart Rev 2020-03".into()),
+                page: Some(12),
+                effective_date: Some("2020-03-01".into()),
+                content_hash: None,
+            })
+            .build();
+
+        let mut package = LoadChartPackage::default();
+        package.add_chart(chart.unwrap());
+        package
+    }
+
+    #[test]
+    fn test_record_and_verify() {
+        let mut package = test_package();
+        assert!(matches!(package.verify_hash(), Err(ProvenanceError::MissingHash(_))));
+
+        package.record_hash().unwrap();
+        package.verify_hash().unwrap();
+
+        // Recording a hash doesn't change it; a round trip through JSON doesn't either
+        let json = serde_json::to_string(&package).unwrap();
+        assert!(!json.contains("telescope_mode") && !json.contains("boom_inserts"));
+        let loaded: LoadChartPackage = serde_json::from_str(&json).unwrap();
+        loaded.verify_hash().unwrap();
+        loaded
+            .verify_against(package.provenance.content_hash.as_ref().unwrap())
+            .unwrap();
+    }
+
+    #[test]
+    fn test_tampering_detected() {
+        let mut package = test_package();
+        package.record_hash().unwrap();
+
+        package.charts[0].capacity_data.data[0][1].1 = MassValue::new(162000.0, "lbs");
+
+        assert!(matches!(
+            package.verify_hash(),
+            Err(ProvenanceError::HashMismatch { .. })
+        ));
+        assert!(matches!(
+            package.charts[0].verify_hash(),
+            Err(ProvenanceError::HashMismatch { .. })
+        ));
+    }
+}
@@ -0,0 +1,303 @@
+//! Range diagram geometry for a load chart
+//!
+//! Produces the polylines front-ends need to draw a standard range
+//! diagram: boom length arcs, boom angle lines, the chart's minimum and
+//! maximum radius limits, the working envelope, and capacity iso-contours.
+//!
+//! # Coordinates
+//!
+//! Every point is `(radius, height)` in **FEET**: radius from the slew
+//! center, height above ground. `x` is radius, `y` is height.
+
+use crate::capacity::load_chart::*;
+use crate::types::*;
+use nalgebra as na;
+
+/// A labelled polyline in (radius, height) feet
+#[derive(Debug, Clone)]
+pub struct Polyline {
+    pub label: String,
+    pub points: Vec<na::Point2<f64>>,
+}
+
+/// Capacity iso-contour: boom tip positions where capacity equals `capacity`
+#[derive(Debug, Clone)]
+pub struct IsoContour {
+    pub capacity: Mass,
+    pub line: Polyline,
+}
+
+/// Options controlling diagram resolution
+#[derive(Debug, Clone)]
+pub struct RangeDiagramOptions {
+    /// Boom pivot height above ground
+    pub pivot_height: Length,
+
+    /// Lowest boom angle drawn
+    pub angle_min: Angle,
+
+    /// Highest boom angle drawn
+    pub angle_max: Angle,
+
+    /// Angle increment along boom arcs
+    pub arc_step: Angle,
+
+    /// Spacing of boom angle lines
+    pub angle_line_step: Angle,
+
+    /// Capacities to draw iso-contours for
+    pub iso_capacities: Vec<Mass>,
+}
+
+impl Default for RangeDiagramOptions {
+    fn default() -> Self {
+        Self {
+            pivot_height: Length::new::<foot>(10.0),
+            angle_min: Angle::new::<degree>(0.0),
+            angle_max: Angle::new::<degree>(85.0),
+            arc_step: Angle::new::<degree>(1.0),
+            angle_line_step: Angle::new::<degree>(10.0),
+            iso_capacities: Vec::new(),
+        }
+    }
+}
+
+/// Complete range diagram geometry
+#[derive(Debug, Clone)]
+pub struct RangeDiagram {
+    /// Chart the diagram was generated from
+    pub chart_id: String,
+
+    /// Boom tip path for each chart boom length
+    pub boom_arcs: Vec<Polyline>,
+
+    /// Boom centerlines at regular angles, pivot to longest boom tip
+    pub angle_lines: Vec<Polyline>,
+
+    /// Tip positions at each boom length's minimum chart radius
+    pub min_radius_line: Polyline,
+
+    /// Tip positions at each boom length's maximum chart radius
+    pub max_radius_line: Polyline,
+
+    /// Closed outline of the rated working area
+    pub envelope: Polyline,
+
+    /// Capacity iso-contours
+    pub iso_contours: Vec<IsoContour>,
+}
+
+impl RangeDiagram {
+    /// Generate range diagram geometry for a chart
+    pub fn generate(
+        chart: &LoadChart,
+        options: &RangeDiagramOptions,
+    ) -> Result<Self, LoadChartError> {
+        let mut booms: Vec<(usize, Length)> =
+            chart.boom_lengths()?.into_iter().enumerate().collect();
+        booms.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+
+        let pivot = to_coord(options.pivot_height);
+        let (angle_min, angle_max) = angle_limits(chart, options)?;
+        let step = options.arc_step.get::<degree>().max(0.01);
+
+        // Boom length arcs
+        let mut boom_arcs = Vec::with_capacity(booms.len());
+        for (_, boom) in &booms {
+            let l = to_coord(*boom);
+            let mut points = Vec::new();
+            let mut angle = angle_min;
+            while angle <= angle_max + 1e-9 {
+                let rad = angle.to_radians();
+                points.push(na::Point2::new(l * rad.cos(), pivot + l * rad.sin()));
+                angle += step;
+            }
+            boom_arcs.push(Polyline {
+                label: format!("{:.1} ft boom", l),
+                points,
+            });
+        }
+
+        // Boom angle lines out to the longest boom
+        let longest = booms.last().map(|(_, b)| to_coord(*b)).ok_or(LoadChartError::NoData)?;
+        let line_step = options.angle_line_step.get::<degree>().max(0.01);
+        let mut angle_lines = Vec::new();
+        let mut angle = (angle_min / line_step).ceil() * line_step;
+        while angle <= angle_max + 1e-9 {
+            let rad = angle.to_radians();
+            angle_lines.push(Polyline {
+                label: format!("{:.0}°", angle),
+                points: vec![
+                    na::Point2::new(0.0, pivot),
+                    na::Point2::new(longest * rad.cos(), pivot + longest * rad.sin()),
+                ],
+            });
+            angle += line_step;
+        }
+
+        // Chart radius limits at each boom length
+        let mut min_points = Vec::new();
+        let mut max_points = Vec::new();
+        for (idx, boom) in &booms {
+            let radii = chart.capacity_data.radii_for_boom(*idx)?;
+            let min = radii.iter().map(|r| to_coord(*r)).fold(f64::INFINITY, f64::min);
+            let max = radii.iter().map(|r| to_coord(*r)).fold(f64::NEG_INFINITY, f64::max);
+            if let Some(p) = tip_at_radius(*boom, min, pivot) {
+                min_points.push(p);
+            }
+            if let Some(p) = tip_at_radius(*boom, max, pivot) {
+                max_points.push(p);
+            }
+        }
+
+        let envelope = envelope(&booms, &min_points, &max_points, pivot);
+
+        // Capacity iso-contours
+        let mut iso_contours = Vec::new();
+        for capacity in &options.iso_capacities {
+            let mut points = Vec::new();
+            for (_, boom) in &booms {
+                if let Some(r) = chart.radius_for_capacity(*boom, *capacity)?
+                    && let Some(p) = tip_at_radius(*boom, to_coord(r), pivot)
+                {
+                    points.push(p);
+                }
+            }
+            iso_contours.push(IsoContour {
+                capacity: *capacity,
+                line: Polyline {
+                    label: format!("{:.0} lbs", capacity.get::<pound>()),
+                    points,
+                },
+            });
+        }
+
+        Ok(Self {
+            chart_id: chart.id.clone(),
+            boom_arcs,
+            angle_lines,
+            min_radius_line: Polyline {
+                label: "Minimum radius".into(),
+                points: min_points,
+            },
+            max_radius_line: Polyline {
+                label: "Maximum radius".into(),
+                points: max_points,
+            },
+            envelope,
+            iso_contours,
+        })
+    }
+}
+
+/// Boom angle limits in degrees: the chart's range if given, else the options
+fn angle_limits(chart: &LoadChart, options: &RangeDiagramOptions) -> Result<(f64, f64), UnitError> {
+    match &chart.configuration.boom.angle_range {
+        Some(range) => Ok((
+            range.min.to_angle()?.get::<degree>(),
+            range.max.to_angle()?.get::<degree>(),
+        )),
+        None => Ok((
+            options.angle_min.get::<degree>(),
+            options.angle_max.get::<degree>(),
+        )),
+    }
+}
+
+/// Boom tip (radius, height) for a boom length at a radius, if reachable
+fn tip_at_radius(boom: Length, radius: f64, pivot: f64) -> Option<na::Point2<f64>> {
+    let l = to_coord(boom);
+    if radius > l || radius < 0.0 {
+        return None;
+    }
+    Some(na::Point2::new(radius, pivot + (l * l - radius * radius).sqrt()))
+}
+
+/// Outline: up the minimum radius line, over the longest boom arc, down the maximum radius line
+fn envelope(
+    booms: &[(usize, Length)],
+    min_points: &[na::Point2<f64>],
+    max_points: &[na::Point2<f64>],
+    pivot: f64,
+) -> Polyline {
+    let mut points: Vec<na::Point2<f64>> = min_points.to_vec();
+
+    if let (Some((_, longest)), Some(start), Some(end)) =
+        (booms.last(), min_points.last(), max_points.last())
+    {
+        let samples = 24;
+        for i in 1..samples {
+            let r = start.x + (end.x - start.x) * i as f64 / samples as f64;
+            if let Some(p) = tip_at_radius(*longest, r, pivot) {
+                points.push(p);
+            }
+        }
+    }
+
+    points.extend(max_points.iter().rev().copied());
+    if let Some(first) = points.first().copied() {
+        points.push(first);
+    }
+
+    Polyline {
+        label: "Working envelope".into(),
+        points,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::capacity::chart_builder::LoadChartBuilder;
+    use approx::assert_relative_eq;
+
+    fn test_chart() -> LoadChart {
+        LoadChartBuilder::new("test")
+            .description("Range diagram test")
+            .boom(100.0, "ft")
+            .points(&[(20.0, 100000.0), (60.0, 40000.0), (90.0, 20000.0)])
+            .boom(150.0, "ft")
+            .points(&[(30.0, 80000.0), (80.0, 30000.0), (130.0, 10000.0)])
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_boom_arcs_follow_boom_length() {
+        let diagram = RangeDiagram::generate(&test_chart(), &RangeDiagramOptions::default()).unwrap();
+
+        assert_eq!(diagram.boom_arcs.len(), 2);
+        for p in &diagram.boom_arcs[0].points {
+            let len = (p.x.powi(2) + (p.y - 10.0).powi(2)).sqrt();
+            assert_relative_eq!(len, 100.0, epsilon = 1e-9);
+        }
+        assert_eq!(diagram.angle_lines.len(), 9);
+    }
+
+    #[test]
+    fn test_radius_limits_and_envelope() {
+        let diagram = RangeDiagram::generate(&test_chart(), &RangeDiagramOptions::default()).unwrap();
+
+        assert_relative_eq!(diagram.min_radius_line.points[0].x, 20.0);
+        assert_relative_eq!(diagram.max_radius_line.points[1].x, 130.0);
+
+        let env = &diagram.envelope.points;
+        assert_eq!(env.first(), env.last());
+    }
+
+    #[test]
+    fn test_iso_contour() {
+        let options = RangeDiagramOptions {
+            iso_capacities: vec![Mass::new::<pound>(40000.0)],
+            ..Default::default()
+        };
+        let diagram = RangeDiagram::generate(&test_chart(), &options).unwrap();
+
+        let contour = &diagram.iso_contours[0].line.points;
+        assert_eq!(contour.len(), 2);
+        // 100 ft boom hits 40,000 lbs exactly at 60 ft
+        assert_relative_eq!(contour[0].x, 60.0, epsilon = 1e-9);
+        // 150 ft boom: between 30 ft (80k) and 80 ft (30k) -> 70 ft
+        assert_relative_eq!(contour[1].x, 70.0, epsilon = 1e-9);
+    }
+}
@@ -0,0 +1,298 @@
+//! Sensitivity of lift margins to one parameter
+//!
+//! A plan that passes at 50 ft radius may fail at 52 ft. [`sensitivity`]
+//! sweeps one parameter across a range, holding the rest of the plan
+//! fixed, and returns margin curves for capacity, stability and ground
+//! bearing so planners can see how close to a cliff the plan sits.
+//!
+//! - **Radius** is set by boom angle at the current boom length
+//! - **Boom length** holds the current radius, adjusting boom angle
+//! - **Wind speed** derates capacity as in the lift plan wind check
+//!
+//! Margins are percentages in the same sense as the lift plan checks:
+//! positive is spare, negative is over the limit.
+
+use crate::capacity::lift_validation::*;
+use crate::equipment::Crane;
+use crate::simulation::StabilityModel;
+use crate::types::*;
+
+/// Parameter to sweep, and its range
+#[derive(Debug, Clone, Copy)]
+pub enum SensitivityParameter {
+    Radius { from: Length, to: Length },
+    BoomLength { from: Length, to: Length },
+    WindSpeed { from: Velocity, to: Velocity },
+}
+
+impl SensitivityParameter {
+    /// Range endpoints in feet (radius, boom length) or mph (wind)
+    fn range(&self) -> (f64, f64) {
+        match self {
+            SensitivityParameter::Radius { from, to } | SensitivityParameter::BoomLength { from, to } => {
+                (from.get::<foot>(), to.get::<foot>())
+            }
+            SensitivityParameter::WindSpeed { from, to } => (from.get::<mile_per_hour>(), to.get::<mile_per_hour>()),
+        }
+    }
+}
+
+/// Margins at one parameter value
+#[derive(Debug, Clone, Copy)]
+pub struct SensitivityPoint {
+    /// Feet for radius and boom length, mph for wind speed
+    pub value: f64,
+
+    /// `false` if the radius is beyond the boom; margins are `None`
+    pub reachable: bool,
+
+    /// Wind-derated capacity margin
+    pub capacity_margin: Option<f64>,
+
+    /// Stability factor margin over the model minimum; `None` without a model
+    pub stability_margin: Option<f64>,
+
+    /// Ground pressure margin against allowable soil bearing; `None` if the
+    /// support model failed (a pad lifted off)
+    pub ground_margin: Option<f64>,
+}
+
+impl SensitivityPoint {
+    /// Lowest of the margins that could be evaluated
+    pub fn governing_margin(&self) -> Option<f64> {
+        [self.capacity_margin, self.stability_margin, self.ground_margin]
+            .into_iter()
+            .flatten()
+            .reduce(f64::min)
+    }
+
+    /// Unreachable, or a margin is negative
+    pub fn fails(&self) -> bool {
+        !self.reachable || self.governing_margin().is_some_and(|m| m < 0.0)
+    }
+}
+
+/// Margin curves across the swept range
+#[derive(Debug, Clone)]
+pub struct SensitivityCurve {
+    pub parameter: SensitivityParameter,
+    pub points: Vec<SensitivityPoint>,
+}
+
+impl SensitivityCurve {
+    /// First point along the sweep where the lift fails
+    pub fn first_failure(&self) -> Option<&SensitivityPoint> {
+        self.points.iter().find(|p| p.fails())
+    }
+
+    /// Point with the lowest governing margin
+    pub fn worst(&self) -> Option<&SensitivityPoint> {
+        self.points
+            .iter()
+            .filter_map(|p| p.governing_margin().map(|margin| (p, margin)))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(p, _)| p)
+    }
+
+    /// Parameter value where capacity margin crosses zero, interpolated
+    /// between the points either side
+    pub fn capacity_limit(&self) -> Option<f64> {
+        self.points.windows(2).find_map(|pair| {
+            let (a, b) = (pair[0].capacity_margin?, pair[1].capacity_margin?);
+            if a >= 0.0 && b < 0.0 {
+                Some(pair[0].value + (pair[1].value - pair[0].value) * a / (a - b))
+            } else {
+                None
+            }
+        })
+    }
+}
+
+/// Sweep one parameter across `steps` evenly spaced values (at least 2)
+///
+/// Pass a [`StabilityModel`] to include a stability margin.
+pub fn sensitivity<C: Crane + Clone>(
+    crane: &C,
+    plan: &LiftPlan,
+    parameter: SensitivityParameter,
+    steps: usize,
+    stability: Option<&StabilityModel>,
+) -> SensitivityCurve {
+    let steps = steps.max(2);
+    let (from, to) = parameter.range();
+    let nominal = crane.configuration();
+
+    let points = (0..steps)
+        .map(|i| {
+            let value = from + (to - from) * i as f64 / (steps - 1) as f64;
+            let mut crane = crane.clone();
+            let mut plan = plan.clone();
+            let mut joints = crane.joint_config();
+
+            let (boom_length, radius) = match parameter {
+                SensitivityParameter::Radius { .. } => (nominal.boom_length.get::<foot>(), value),
+                SensitivityParameter::BoomLength { .. } => (value, nominal.radius.get::<foot>()),
+                SensitivityParameter::WindSpeed { .. } => {
+                    plan.environment.wind_speed = Velocity::new::<mile_per_hour>(value);
+                    (nominal.boom_length.get::<foot>(), nominal.radius.get::<foot>())
+                }
+            };
+
+            let boom_length = Length::new::<foot>(boom_length);
+            let Some(boom_angle) = crane
+                .forward_kinematics()
+                .base
+                .boom_angle_for_radius(Length::new::<foot>(radius), boom_length)
+            else {
+                return SensitivityPoint {
+                    value,
+                    reachable: false,
+                    capacity_margin: None,
+                    stability_margin: None,
+                    ground_margin: None,
+                };
+            };
+            joints.boom_length = boom_length;
+            joints.boom_angle = boom_angle;
+            crane.set_joint_config(joints);
+
+            evaluate(&crane, &plan, value, stability)
+        })
+        .collect();
+
+    SensitivityCurve { parameter, points }
+}
+
+fn evaluate<C: Crane>(crane: &C, plan: &LiftPlan, value: f64, stability: Option<&StabilityModel>) -> SensitivityPoint {
+    let config = crane.configuration();
+    let load = plan.dynamic_suspended_load(crane);
+
+    let derating = plan.wind_analysis(crane).derating_factor();
+    let capacity = crane.rated_capacity().get::<pound>() * derating;
+    let capacity_margin = if capacity > 0.0 {
+        (capacity - load.get::<pound>()) / capacity * 100.0
+    } else {
+        f64::NEG_INFINITY
+    };
+
+    let stability_margin =
+        stability.map(|model| (model.stability_factor(load, config.radius) / model.min_factor - 1.0) * 100.0);
+
+    let allowable = plan.allowable_bearing().get::<psi>();
+    let ground_margin = ground_bearing_pressure(crane, plan)
+        .ok()
+        .map(|(pressure, _)| (allowable - pressure.get::<psi>()) / allowable * 100.0);
+
+    SensitivityPoint {
+        value,
+        reachable: true,
+        capacity_margin: Some(capacity_margin),
+        stability_margin,
+        ground_margin,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::equipment::CraneType;
+    use crate::test_fixtures::{lift_plan, mobile_crane as crane};
+    use approx::assert_relative_eq;
+
+    // Still air, so capacity margins are straight off the chart
+    fn plan(load: f64) -> LiftPlan {
+        let mut plan = lift_plan(load);
+        plan.environment.wind_speed = Velocity::new::<mile_per_hour>(0.0);
+        plan
+    }
+
+    #[test]
+    fn test_radius_sweep_stability() {
+        let model = StabilityModel {
+            machine_weight: Mass::new::<pound>(60_000.0),
+            cog_behind_center: Length::new::<foot>(5.0),
+            tipping_radius: Length::new::<foot>(10.0),
+            min_factor: 1.5,
+        };
+        let curve = sensitivity(
+            &crane(),
+            &plan(8_000.0),
+            SensitivityParameter::Radius {
+                from: Length::new::<foot>(40.0),
+                to: Length::new::<foot>(120.0),
+            },
+            9,
+            Some(&model),
+        );
+        assert_eq!(curve.points.len(), 9);
+
+        // 60,000 * 15 / (8,000 * 30) = 3.75 at 40 ft
+        assert_relative_eq!(curve.points[0].stability_margin.unwrap(), 150.0, epsilon = 1e-6);
+        // Stability factor falls with radius
+        let margins: Vec<f64> = curve.points.iter().filter_map(|p| p.stability_margin).collect();
+        assert!(margins.windows(2).all(|w| w[1] < w[0]));
+
+        // The chart runs out first: 7,500 lbs at 60 ft is under the load
+        assert_relative_eq!(curve.points[1].capacity_margin.unwrap(), 20.0, epsilon = 1e-6);
+        assert_relative_eq!(curve.first_failure().unwrap().value, 60.0);
+
+        // Factor 1.5 at 85 ft, so 90 ft is the first point short on stability; 110 ft is past the boom
+        let unstable = curve.points.iter().find(|p| p.stability_margin.is_some_and(|m| m < 0.0)).unwrap();
+        assert_relative_eq!(unstable.value, 90.0);
+        assert!(!curve.points[7].reachable);
+    }
+
+    #[test]
+    fn test_wind_sweep_capacity_limit() {
+        let curve = sensitivity(
+            &crane(),
+            &plan(8_000.0),
+            SensitivityParameter::WindSpeed {
+                from: Velocity::new::<mile_per_hour>(0.0),
+                to: Velocity::new::<mile_per_hour>(30.0),
+            },
+            31,
+            None,
+        );
+
+        assert_relative_eq!(curve.points[0].capacity_margin.unwrap(), 20.0, epsilon = 1e-9);
+        assert!(curve.points[0].stability_margin.is_none());
+
+        // 8,000 / 10,000 = 0.8 = 0.95 * (1 - (w - 20) / 10) at w = 21.6 mph
+        assert_relative_eq!(curve.capacity_limit().unwrap(), 20.0 + 10.0 * (1.0 - 0.8 / 0.95), epsilon = 0.05);
+        assert!(curve.worst().unwrap().value > 29.0);
+
+        // Wind limits come from the crane's own type
+        let mut crawler = crane();
+        crawler.crane_type = CraneType::Crawler;
+        let wind = SensitivityParameter::WindSpeed {
+            from: Velocity::new::<mile_per_hour>(0.0),
+            to: Velocity::new::<mile_per_hour>(30.0),
+        };
+        let crawler_curve = sensitivity(&crawler, &plan(8_000.0), wind, 31, None);
+        assert_ne!(crawler_curve.capacity_limit(), curve.capacity_limit());
+    }
+
+    #[test]
+    fn test_boom_length_sweep_holds_radius() {
+        let curve = sensitivity(
+            &crane(),
+            &plan(5_000.0),
+            SensitivityParameter::BoomLength {
+                from: Length::new::<foot>(40.0),
+                to: Length::new::<foot>(130.0),
+            },
+            4,
+            None,
+        );
+
+        // 50 ft radius doesn't fit on a 40 ft boom
+        assert!(!curve.points[0].reachable);
+        assert!(curve.points[1..].iter().all(|p| p.reachable));
+        assert!(curve.points[2].ground_margin.is_some());
+
+        // 10,000 lbs at 50 ft on the 100 ft chart, 8,500 lbs on the 130 ft chart
+        assert_relative_eq!(curve.points[2].capacity_margin.unwrap(), 50.0, epsilon = 1e-6);
+        assert_relative_eq!(curve.points[3].capacity_margin.unwrap(), 3_500.0 / 8_500.0 * 100.0, epsilon = 1e-6);
+    }
+}
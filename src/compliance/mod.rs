@@ -0,0 +1,366 @@
+//! Safety policy and rule profiles for the standard a lift is planned under
+//!
+//! Every limit the validators apply (capacity and design factors, lift
+//! classes and margins, tandem capacity factor, anti-two-block clearance,
+//! operating wind limits) comes from one [`SafetyFactors`] policy.
+//! Built-in profiles cover OSHA 1926 Subpart CC with ASME B30, EN 13000
+//! and AS 1418/AS 2550; a company policy starts from one of these, or from
+//! a policy file, and overrides what its procedures require.
+//!
+//! The built-in values are typical defaults. Where the crane manufacturer
+//! or the site's lift procedure is stricter, that governs.
+
+use crate::capacity::lift_validation::{LiftClass, RiggingHardware, RiggingKind};
+#[cfg(feature = "charts-io")]
+use crate::capacity::chart_io::{ChartFormat, ChartIoError};
+use crate::physics::wind_loading::{WindAnalysis, WindCondition};
+use crate::rigging::{RopeService, WireRope, WireRopeCheck};
+use crate::types::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RuleSet {
+    /// OSHA 1926 Subpart CC with ASME B30.5/B30.9
+    OshaAsme,
+    En13000,
+    /// AS 1418 design with AS 2550 operation
+    As1418,
+    /// Company or project rules
+    Custom,
+}
+
+/// In-service wind limits applied to every crane type
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WindLimits {
+    pub caution: Velocity,
+    pub shutdown: Velocity,
+}
+
+/// Safety policy a lift is planned under
+///
+/// Applied by [`validate_lift`](crate::capacity::lift_validation::validate_lift)
+/// to capacity, rigging, ground bearing and wind, and by the tandem and
+/// kinematic checks. Fields left out of a policy file take the OSHA/ASME
+/// defaults.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct SafetyFactors {
+    pub name: String,
+    pub rule_set: RuleSet,
+
+    /// Capacity safety factor (typically 1.0, already in load charts)
+    pub capacity: f64,
+
+    /// Factor between ultimate and allowable ground bearing (typically 2.0)
+    pub ground_bearing: f64,
+
+    /// Rigging design factor on minimum breaking strength (typically 5:1
+    /// minimum); slings rated to a lower factor are derated to it
+    pub rigging: f64,
+
+    /// Chart utilization (%) from which a lift is critical
+    pub critical_lift_threshold: f64,
+
+    /// Highest chart utilization (%) allowed for any lift
+    pub max_utilization: f64,
+
+    /// Capacity margin (%) a routine lift keeps before it needs review
+    pub routine_margin: f64,
+
+    /// Capacity margin (%) a critical lift keeps before it needs review
+    pub critical_margin: f64,
+
+    /// Ground bearing margin (%) kept before the check warns
+    pub ground_bearing_margin: f64,
+
+    /// Fraction of chart each crane may use in a multi-crane lift
+    pub tandem_capacity_factor: f64,
+
+    /// Least clearance between hook block and boom tip
+    pub two_block_clearance: Length,
+
+    /// Wind limits for every crane type; `None` uses the crane type's own
+    pub wind_limits: Option<WindLimits>,
+}
+
+impl SafetyFactors {
+    pub fn osha_asme() -> Self {
+        Self {
+            name: "OSHA 1926 / ASME B30".into(),
+            rule_set: RuleSet::OshaAsme,
+            capacity: 1.0,
+            ground_bearing: 2.0,
+            rigging: 5.0,
+            critical_lift_threshold: 75.0,
+            max_utilization: 100.0,
+            routine_margin: 10.0,
+            critical_margin: 20.0,
+            ground_bearing_margin: 20.0,
+            tandem_capacity_factor: 0.75,
+            two_block_clearance: Length::new::<foot>(2.0),
+            wind_limits: None,
+        }
+    }
+
+    /// In-service wind of 9.8 m/s unless the crane is rated otherwise
+    pub fn en13000() -> Self {
+        Self {
+            name: "EN 13000".into(),
+            rule_set: RuleSet::En13000,
+            tandem_capacity_factor: 0.8,
+            two_block_clearance: Length::new::<meter>(0.5),
+            wind_limits: Some(WindLimits {
+                caution: Velocity::new::<meter_per_second>(7.9),
+                shutdown: Velocity::new::<meter_per_second>(9.8),
+            }),
+            ..Self::osha_asme()
+        }
+    }
+
+    pub fn as1418() -> Self {
+        Self {
+            name: "AS 1418 / AS 2550".into(),
+            rule_set: RuleSet::As1418,
+            tandem_capacity_factor: 0.8,
+            two_block_clearance: Length::new::<meter>(0.5),
+            ..Self::osha_asme()
+        }
+    }
+
+    /// Company rules starting from `base`
+    pub fn custom(name: impl Into<String>, base: &SafetyFactors) -> Self {
+        Self {
+            name: name.into(),
+            rule_set: RuleSet::Custom,
+            ..base.clone()
+        }
+    }
+
+    /// Built-in profile by short name: `osha`, `asme`, `en13000` or `as1418`
+    pub fn named(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().replace([' ', '-', '_'], "").as_str() {
+            "osha" | "asme" | "oshaasme" => Some(Self::osha_asme()),
+            "en13000" | "en" => Some(Self::en13000()),
+            "as1418" | "as2550" | "as" => Some(Self::as1418()),
+            _ => None,
+        }
+    }
+
+    /// Class of a lift using `utilization` percent of chart
+    pub fn lift_class(&self, utilization: f64) -> LiftClass {
+        if utilization >= self.critical_lift_threshold {
+            LiftClass::Critical
+        } else {
+            LiftClass::Routine
+        }
+    }
+
+    pub fn required_margin(&self, class: LiftClass) -> f64 {
+        match class {
+            LiftClass::Routine => self.routine_margin,
+            LiftClass::Critical => self.critical_margin,
+        }
+    }
+
+    /// Chart capacity usable under the policy
+    pub fn allowable_capacity(&self, rated: Mass) -> Mass {
+        rated * (self.max_utilization / 100.0) / self.capacity
+    }
+
+    /// Allowable bearing for a soil of ultimate capacity `ultimate`
+    pub fn allowable_bearing(&self, ultimate: Pressure) -> Pressure {
+        ultimate / self.ground_bearing
+    }
+
+    /// Design factor required of wire rope in `service`; slings take the
+    /// policy's rigging factor when it is stricter
+    pub fn rope_design_factor(&self, service: RopeService) -> f64 {
+        match service {
+            RopeService::Sling => service.design_factor().max(self.rigging),
+            RopeService::Running => service.design_factor(),
+        }
+    }
+
+    /// Design factor required of a rigging item of `kind`
+    ///
+    /// The policy's rigging factor is on the 5:1 basis ASME sets for wire
+    /// rope slings; chain, rated at 4:1, is held to the same proportion.
+    pub fn rigging_design_factor(&self, kind: RiggingKind) -> f64 {
+        let basis = RopeService::Sling.design_factor();
+        match kind {
+            RiggingKind::WireRopeSling => self.rope_design_factor(RopeService::Sling),
+            _ => kind.rated_design_factor() * (self.rigging / basis).max(1.0),
+        }
+    }
+
+    /// Capacity of a rigging item under the policy: its rated capacity,
+    /// derated when the policy asks for a higher design factor than the
+    /// item is rated at
+    pub fn allowable_rigging(&self, item: &RiggingHardware) -> Mass {
+        item.capacity * item.kind.rated_design_factor() / self.rigging_design_factor(item.kind)
+    }
+
+    /// Check a wire rope sling or running line against the policy
+    pub fn check_rope(&self, rope: &WireRope, line_pull: Force, service: RopeService) -> WireRopeCheck {
+        rope.check_with_design_factor(line_pull, self.rope_design_factor(service))
+    }
+
+    /// Caution and shutdown wind for the crane in `wind`
+    pub fn operating_wind_limits(&self, wind: &WindAnalysis) -> (Velocity, Velocity) {
+        self.wind_limits
+            .map_or_else(|| wind.operating_limits(), |limits| (limits.caution, limits.shutdown))
+    }
+
+    /// Wind condition of `wind` under this policy's limits
+    pub fn wind_condition(&self, wind: &WindAnalysis) -> WindCondition {
+        let (caution, shutdown) = self.operating_wind_limits(wind);
+        if wind.wind_speed >= wind.out_of_service_limit() {
+            WindCondition::OutOfService
+        } else if wind.wind_speed >= shutdown {
+            WindCondition::Shutdown
+        } else if wind.wind_speed >= caution {
+            WindCondition::Caution
+        } else {
+            WindCondition::Safe
+        }
+    }
+
+    /// Read a policy file in the given format
+    #[cfg(feature = "charts-io")]
+    pub fn from_reader_as(mut reader: impl std::io::Read, format: ChartFormat) -> Result<Self, ChartIoError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        let policy: Self = match format {
+            ChartFormat::Json => serde_json::from_slice(&bytes)?,
+            #[cfg(feature = "yaml")]
+            ChartFormat::Yaml => serde_yaml::from_slice(&bytes)?,
+            #[cfg(feature = "toml")]
+            ChartFormat::Toml => toml::from_str(std::str::from_utf8(&bytes)?)?,
+        };
+        policy.check_ranges()?;
+        Ok(policy)
+    }
+
+    /// Reject factors that would loosen or break the checks they feed,
+    /// e.g. a capacity factor under 1 rating a crane above its chart
+    #[cfg(feature = "charts-io")]
+    fn check_ranges(&self) -> Result<(), ChartIoError> {
+        let (utilization, tandem) = (self.max_utilization, self.tandem_capacity_factor);
+        let factors = [
+            ("capacity", self.capacity, ">= 1", self.capacity >= 1.0),
+            ("max_utilization", utilization, "0 < x <= 100", utilization > 0.0 && utilization <= 100.0),
+            ("ground_bearing", self.ground_bearing, ">= 1", self.ground_bearing >= 1.0),
+            ("rigging", self.rigging, "> 0", self.rigging > 0.0),
+            ("tandem_capacity_factor", tandem, "0 < x <= 1", tandem > 0.0 && tandem <= 1.0),
+        ];
+        for (field, value, range, valid) in factors {
+            if !valid {
+                return Err(ChartIoError::InvalidPolicy { field, value, range });
+            }
+        }
+        Ok(())
+    }
+
+    /// Read a policy file, in the format its extension names
+    #[cfg(feature = "charts-io")]
+    pub fn from_file(path: impl AsRef<std::path::Path>) -> Result<Self, ChartIoError> {
+        let path = path.as_ref();
+        let format = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(ChartFormat::from_extension)
+            .unwrap_or(ChartFormat::Json);
+        Self::from_reader_as(std::fs::File::open(path)?, format)
+    }
+}
+
+impl Default for SafetyFactors {
+    fn default() -> Self {
+        Self::osha_asme()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "charts-io")]
+    use crate::capacity::chart_io::{ChartFormat, ChartIoError};
+    use crate::equipment::CraneType;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_profiles_apply_their_wind_limits() {
+        let wind = WindAnalysis::new(
+            CraneType::AllTerrain,
+            Length::new::<foot>(150.0),
+            Angle::new::<degree>(60.0),
+            Area::new::<square_foot>(50.0),
+            Velocity::new::<mile_per_hour>(23.0),
+        );
+
+        // 23 mph is caution on the all-terrain table, past EN 13000's 9.8 m/s
+        assert_eq!(SafetyFactors::osha_asme().wind_condition(&wind), WindCondition::Caution);
+        assert_eq!(SafetyFactors::en13000().wind_condition(&wind), WindCondition::Shutdown);
+
+        let company = SafetyFactors::custom("Site rules", &SafetyFactors::named("AS-1418").unwrap());
+        assert_eq!(company.rule_set, RuleSet::Custom);
+        assert_relative_eq!(company.tandem_capacity_factor, 0.8);
+        assert!(SafetyFactors::named("ISO").is_none());
+    }
+
+    #[test]
+    fn test_safety_policy() {
+        let policy = SafetyFactors { max_utilization: 90.0, ..SafetyFactors::default() };
+        assert_eq!(policy.lift_class(60.0), LiftClass::Routine);
+        assert_eq!(policy.lift_class(80.0), LiftClass::Critical);
+        assert_relative_eq!(policy.required_margin(LiftClass::Critical), 20.0);
+        let allowable = policy.allowable_capacity(Mass::new::<pound>(10000.0));
+        assert_relative_eq!(allowable.get::<pound>(), 9000.0, max_relative = 1e-9);
+
+        // A stricter company sling factor governs over ASME's 5:1
+        let strict = SafetyFactors { rigging: 6.0, ..SafetyFactors::default() };
+        assert_relative_eq!(strict.rope_design_factor(RopeService::Sling), 6.0);
+        assert_relative_eq!(strict.rope_design_factor(RopeService::Running), 3.5);
+        assert_relative_eq!(strict.rigging_design_factor(RiggingKind::ChainSling), 4.8);
+        assert_relative_eq!(SafetyFactors::default().rigging_design_factor(RiggingKind::ChainSling), 4.0);
+    }
+
+    #[cfg(feature = "charts-io")]
+    #[test]
+    fn test_policy_file_defaults() {
+        let json = r#"{ "rigging": 7.0, "critical_lift_threshold": 50.0 }"#;
+        let policy = SafetyFactors::from_reader_as(json.as_bytes(), ChartFormat::Json).unwrap();
+        assert_relative_eq!(policy.rigging, 7.0);
+        assert_eq!(policy.lift_class(60.0), LiftClass::Critical);
+        assert_relative_eq!(policy.ground_bearing, 2.0);
+    }
+
+    #[cfg(feature = "charts-io")]
+    #[test]
+    fn test_policy_file_rejects_out_of_range_factors() {
+        for (json, field) in [
+            (r#"{ "capacity": 0.9 }"#, "capacity"),
+            (r#"{ "max_utilization": 0.0 }"#, "max_utilization"),
+            (r#"{ "max_utilization": 110.0 }"#, "max_utilization"),
+            (r#"{ "ground_bearing": 0.5 }"#, "ground_bearing"),
+            (r#"{ "rigging": 0.0 }"#, "rigging"),
+            (r#"{ "tandem_capacity_factor": 0.0 }"#, "tandem_capacity_factor"),
+            (r#"{ "tandem_capacity_factor": 1.2 }"#, "tandem_capacity_factor"),
+        ] {
+            let result = SafetyFactors::from_reader_as(json.as_bytes(), ChartFormat::Json);
+            assert!(
+                matches!(result, Err(ChartIoError::InvalidPolicy { field: f, .. }) if f == field),
+                "{json} should be rejected",
+            );
+        }
+
+        // The bounds themselves are allowed
+        let json = r#"{ "capacity": 1.0, "max_utilization": 100.0, "ground_bearing": 1.0,
+            "tandem_capacity_factor": 1.0 }"#;
+        assert!(SafetyFactors::from_reader_as(json.as_bytes(), ChartFormat::Json).is_ok());
+    }
+}
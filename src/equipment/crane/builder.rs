@@ -0,0 +1,402 @@
+//! Checked construction of [`MobileCrane`]
+//!
+//! ```
+//! use crane_core::equipment::MobileCraneBuilder;
+//! use crane_core::types::*;
+//!
+//! let crane = MobileCraneBuilder::new("Grove", "GMK5250L")
+//!     .boom_length(Length::new::<foot>(100.0))
+//!     .boom_base_height(Length::new::<foot>(8.0))
+//!     .outrigger_spread(Length::new::<foot>(27.0))
+//!     .counterweight(Mass::new::<pound>(50_000.0))
+//!     .boom_angle(Angle::new::<degree>(60.0))
+//!     .build()
+//!     .unwrap();
+//! assert_eq!(crane.counterweight, Mass::new::<pound>(50_000.0));
+//! ```
+
+use crate::capacity::load_chart::{LoadChartPackage, OutriggerExtension, SupportConfiguration, TelescopeMode};
+use crate::equipment::crane::{
+    BoomMass, CapacityDeductions, CraneType, DutyClass, JibInstallation, LatticeBoom, MobileCrane,
+};
+use crate::equipment::hook_block::HookBlock;
+use crate::physics::ground_bearing::FloatDimensions;
+use crate::physics::luffing::LuffingGeometry;
+use crate::types::*;
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum CraneBuildError {
+    #[error("Missing required field: {0}")]
+    Missing(&'static str),
+
+    #[error("{field} out of range: {value} ({reason})")]
+    OutOfRange {
+        field: &'static str,
+        value: String,
+        reason: &'static str,
+    },
+}
+
+/// Builds a [`MobileCrane`], requiring the dimensions that vary by model
+///
+/// Boom length, boom base height, outrigger spread and counterweight must
+/// be given. The boom starts at 45° and 0° swing on full outriggers unless
+/// set otherwise.
+#[derive(Debug, Clone)]
+pub struct MobileCraneBuilder {
+    manufacturer: String,
+    model: String,
+    crane_type: CraneType,
+    boom_length: Option<Length>,
+    boom_base_height: Option<Length>,
+    boom_foot_offset: Length,
+    telescope_mode: Option<TelescopeMode>,
+    outrigger_spread: Option<Length>,
+    counterweight: Option<Mass>,
+    counterweight_radius: Length,
+    boom_mass: Option<BoomMass>,
+    boom_angle: Angle,
+    swing_angle: Angle,
+    jib: Option<JibInstallation>,
+    cable_length: Option<Length>,
+    outrigger_extension: OutriggerExtension,
+    outrigger_float: FloatDimensions,
+    on_outriggers: bool,
+    hook_block: Option<HookBlock>,
+    luffing: Option<LuffingGeometry>,
+    deductions: CapacityDeductions,
+    duty: DutyClass,
+    load_charts: Option<LoadChartPackage>,
+}
+
+impl MobileCraneBuilder {
+    pub fn new(manufacturer: impl Into<String>, model: impl Into<String>) -> Self {
+        Self {
+            manufacturer: manufacturer.into(),
+            model: model.into(),
+            crane_type: CraneType::AllTerrain,
+            boom_length: None,
+            boom_base_height: None,
+            boom_foot_offset: Length::new::<foot>(0.0),
+            telescope_mode: None,
+            outrigger_spread: None,
+            counterweight: None,
+            counterweight_radius: Length::new::<foot>(0.0),
+            boom_mass: None,
+            boom_angle: Angle::new::<degree>(45.0),
+            swing_angle: Angle::new::<degree>(0.0),
+            jib: None,
+            cable_length: None,
+            outrigger_extension: OutriggerExtension::Full,
+            outrigger_float: FloatDimensions::default(),
+            on_outriggers: true,
+            hook_block: None,
+            luffing: None,
+            deductions: CapacityDeductions::none(),
+            duty: DutyClass::Lifting,
+            load_charts: None,
+        }
+    }
+
+    /// Seed a builder from a chart package
+    ///
+    /// Takes the manufacturer, model and crane type from the package's [`CraneInfo`],
+    /// the shortest charted boom, the heaviest charted counterweight and
+    /// the support mode of the first chart, and attaches the package. Boom
+    /// base height and outrigger spread aren't in the charts and must
+    /// still be set.
+    ///
+    /// [`CraneInfo`]: crate::capacity::load_chart::CraneInfo
+    pub fn from_chart_package(package: &LoadChartPackage) -> Result<Self, UnitError> {
+        let info = &package.crane_info;
+        let mut builder = Self::new(info.manufacturer.clone(), info.model.clone());
+        builder.crane_type = info.crane_type.clone();
+
+        let mut shortest_boom: Option<Length> = None;
+        let mut heaviest_counterweight: Option<Mass> = None;
+        for chart in &package.charts {
+            let boom = chart.configuration.boom.length.to_distance()?;
+            if shortest_boom.is_none_or(|b| boom < b) {
+                shortest_boom = Some(boom);
+            }
+            if let Some(cw) = &chart.configuration.counterweight {
+                let mass = cw.to_uom_mass()?;
+                if heaviest_counterweight.is_none_or(|m| mass > m) {
+                    heaviest_counterweight = Some(mass);
+                }
+            }
+        }
+        builder.boom_length = shortest_boom;
+        builder.counterweight = heaviest_counterweight;
+
+        if let Some(chart) = package.charts.first() {
+            match &chart.configuration.support {
+                SupportConfiguration::OnOutriggers { extension, .. } => {
+                    builder.outrigger_extension = extension.clone();
+                }
+                _ => builder.on_outriggers = false,
+            }
+        }
+
+        builder.load_charts = Some(package.clone());
+        Ok(builder)
+    }
+
+    pub fn crane_type(mut self, crane_type: CraneType) -> Self {
+        self.crane_type = crane_type;
+        self
+    }
+
+    pub fn boom_length(mut self, length: Length) -> Self {
+        self.boom_length = Some(length);
+        self
+    }
+
+    pub fn boom_base_height(mut self, height: Length) -> Self {
+        self.boom_base_height = Some(height);
+        self
+    }
+
+    /// Boom foot distance ahead of the slew axis (negative if behind)
+    pub fn boom_foot_offset(mut self, offset: Length) -> Self {
+        self.boom_foot_offset = offset;
+        self
+    }
+
+    pub fn outrigger_spread(mut self, spread: Length) -> Self {
+        self.outrigger_spread = Some(spread);
+        self
+    }
+
+    pub fn counterweight(mut self, counterweight: Mass) -> Self {
+        self.counterweight = Some(counterweight);
+        self
+    }
+
+    pub fn telescope_mode(mut self, mode: TelescopeMode) -> Self {
+        self.telescope_mode = Some(mode);
+        self
+    }
+
+    /// Counterweight center of gravity behind the slew axis
+    pub fn counterweight_radius(mut self, radius: Length) -> Self {
+        self.counterweight_radius = radius;
+        self
+    }
+
+    pub fn boom_mass(mut self, boom_mass: BoomMass) -> Self {
+        self.boom_mass = Some(boom_mass);
+        self
+    }
+
+    /// Boom length and mass from an assembled lattice boom
+    pub fn lattice_boom(mut self, boom: &LatticeBoom) -> Self {
+        self.boom_length = Some(boom.total_length());
+        self.boom_mass = Some(boom.boom_mass());
+        self
+    }
+
+    pub fn boom_angle(mut self, angle: Angle) -> Self {
+        self.boom_angle = angle;
+        self
+    }
+
+    pub fn swing_angle(mut self, angle: Angle) -> Self {
+        self.swing_angle = angle;
+        self
+    }
+
+    pub fn jib(mut self, jib: JibInstallation) -> Self {
+        self.jib = Some(jib);
+        self
+    }
+
+    pub fn cable_length(mut self, length: Length) -> Self {
+        self.cable_length = Some(length);
+        self
+    }
+
+    pub fn outrigger_extension(mut self, extension: OutriggerExtension) -> Self {
+        self.outrigger_extension = extension;
+        self
+    }
+
+    pub fn outrigger_float(mut self, float: FloatDimensions) -> Self {
+        self.outrigger_float = float;
+        self
+    }
+
+    /// Rate the crane on rubber instead of outriggers
+    pub fn on_rubber(mut self) -> Self {
+        self.on_outriggers = false;
+        self
+    }
+
+    pub fn hook_block(mut self, block: HookBlock) -> Self {
+        self.hook_block = Some(block);
+        self
+    }
+
+    pub fn luffing(mut self, geometry: LuffingGeometry) -> Self {
+        self.luffing = Some(geometry);
+        self
+    }
+
+    pub fn deductions(mut self, deductions: CapacityDeductions) -> Self {
+        self.deductions = deductions;
+        self
+    }
+
+    pub fn duty(mut self, duty: DutyClass) -> Self {
+        self.duty = duty;
+        self
+    }
+
+    pub fn load_charts(mut self, charts: LoadChartPackage) -> Self {
+        self.load_charts = Some(charts);
+        self
+    }
+
+    pub fn build(self) -> Result<MobileCrane, CraneBuildError> {
+        let boom_length = self.boom_length.ok_or(CraneBuildError::Missing("boom_length"))?;
+        let boom_base_height = self
+            .boom_base_height
+            .ok_or(CraneBuildError::Missing("boom_base_height"))?;
+        let outrigger_spread = self
+            .outrigger_spread
+            .ok_or(CraneBuildError::Missing("outrigger_spread"))?;
+        let counterweight = self.counterweight.ok_or(CraneBuildError::Missing("counterweight"))?;
+
+        check_length("boom_length", boom_length, |v| v > 0.0, "must be positive")?;
+        check_length("boom_base_height", boom_base_height, |v| v >= 0.0, "must not be negative")?;
+        check_length("outrigger_spread", outrigger_spread, |v| v > 0.0, "must be positive")?;
+        let lb = counterweight.get::<pound>();
+        if !(lb.is_finite() && lb >= 0.0) {
+            return Err(CraneBuildError::OutOfRange {
+                field: "counterweight",
+                value: DisplayMass(counterweight).to_string(),
+                reason: "must not be negative",
+            });
+        }
+        let deg = self.boom_angle.get::<degree>();
+        if !(deg.is_finite() && (0.0..=90.0).contains(&deg)) {
+            return Err(CraneBuildError::OutOfRange {
+                field: "boom_angle",
+                value: DisplayAngle(self.boom_angle).to_string(),
+                reason: "must be between 0° and 90°",
+            });
+        }
+        if !self.swing_angle.get::<degree>().is_finite() {
+            return Err(CraneBuildError::OutOfRange {
+                field: "swing_angle",
+                value: DisplayAngle(self.swing_angle).to_string(),
+                reason: "must be finite",
+            });
+        }
+
+        let mut crane = MobileCrane::new(self.manufacturer, self.model, boom_length, boom_base_height);
+        crane.crane_type = self.crane_type;
+        crane.boom_foot_offset = self.boom_foot_offset;
+        crane.telescope_mode = self.telescope_mode;
+        crane.boom_angle = self.boom_angle;
+        crane.swing_angle = self.swing_angle;
+        crane.jib = self.jib;
+        crane.outrigger_spread = outrigger_spread;
+        crane.outrigger_extension = self.outrigger_extension;
+        crane.outrigger_float = self.outrigger_float;
+        crane.counterweight = counterweight;
+        crane.counterweight_radius = self.counterweight_radius;
+        crane.boom_mass = self.boom_mass;
+        crane.on_outriggers = self.on_outriggers;
+        crane.hook_block = self.hook_block;
+        crane.luffing = self.luffing;
+        crane.deductions = self.deductions;
+        crane.duty = self.duty;
+        crane.load_charts = self.load_charts;
+
+        if let Some(cable) = self.cable_length {
+            crane.set_cable_length(cable).map_err(|_| CraneBuildError::OutOfRange {
+                field: "cable_length",
+                value: DisplayLength(cable).to_string(),
+                reason: "longer than the boom tip height allows",
+            })?;
+        }
+        Ok(crane)
+    }
+}
+
+fn check_length(
+    field: &'static str,
+    length: Length,
+    ok: impl Fn(f64) -> bool,
+    reason: &'static str,
+) -> Result<(), CraneBuildError> {
+    let value = length.get::<foot>();
+    if value.is_finite() && ok(value) {
+        Ok(())
+    } else {
+        Err(CraneBuildError::OutOfRange {
+            field,
+            value: DisplayLength(length).to_string(),
+            reason,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::capacity::chart_builder::LoadChartBuilder;
+    use crate::capacity::load_chart::CraneInfo;
+    use crate::equipment::CraneType;
+
+    #[test]
+    fn test_builder_requires_and_checks_dimensions() {
+        let missing = MobileCraneBuilder::new("Test", "T1")
+            .boom_length(Length::new::<foot>(100.0))
+            .build();
+        assert_eq!(missing.unwrap_err(), CraneBuildError::Missing("boom_base_height"));
+
+        let negative = MobileCraneBuilder::new("Test", "T1")
+            .boom_length(Length::new::<foot>(-5.0))
+            .boom_base_height(Length::new::<foot>(8.0))
+            .outrigger_spread(Length::new::<foot>(20.0))
+            .counterweight(Mass::new::<pound>(10_000.0))
+            .build();
+        assert!(matches!(negative, Err(CraneBuildError::OutOfRange { field: "boom_length", .. })));
+    }
+
+    #[test]
+    fn test_builder_from_chart_package() {
+        let mut package = LoadChartPackage::new(CraneInfo {
+            manufacturer: "Grove".into(),
+            model: "GMK5250L".into(),
+            serial_number: None,
+            crane_type: CraneType::AllTerrain,
+            year: None,
+            chart_revision: None,
+        });
+        for boom in [120.0, 60.0] {
+            package.add_chart(
+                LoadChartBuilder::new(format!("boom_{}", boom))
+                    .outriggers_minimum()
+                    .boom(boom, "ft")
+                    .point(20.0, 50_000.0)
+                    .build()
+                    .unwrap(),
+            );
+        }
+
+        let crane = MobileCraneBuilder::from_chart_package(&package)
+            .unwrap()
+            .boom_base_height(Length::new::<foot>(8.0))
+            .outrigger_spread(Length::new::<foot>(20.0))
+            .counterweight(Mass::new::<pound>(20_000.0))
+            .build()
+            .unwrap();
+        assert_eq!(crane.model, "GMK5250L");
+        assert_eq!(crane.boom_length, Length::new::<foot>(60.0));
+        assert!(matches!(crane.outrigger_extension, OutriggerExtension::Minimum));
+        assert!(crane.load_charts.is_some());
+    }
+}
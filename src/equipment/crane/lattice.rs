@@ -0,0 +1,325 @@
+//! Lattice boom assembly from butt, insert and tip sections
+//!
+//! A lattice boom is pinned together on site: a butt at the foot, any
+//! number of inserts, and a tip. Boom length, weight and center of gravity
+//! all follow from the section list, and the manufacturer's assembly chart
+//! limits which inserts may be used and in what order.
+
+use crate::capacity::load_chart::{
+    BoomConfiguration, ChartConfiguration, CounterweightConfiguration, SupportConfiguration,
+};
+use crate::equipment::crane::BoomMass;
+use crate::types::*;
+use std::collections::HashMap;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LatticeSectionKind {
+    Butt,
+    Insert,
+    Tip,
+}
+
+/// One pinned boom section
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LatticeSection {
+    pub part_number: String,
+    pub kind: LatticeSectionKind,
+    /// Pin-to-pin length
+    pub length: Length,
+    pub weight: Mass,
+    /// Center of gravity from the section's foot-end pins
+    pub cg: Length,
+}
+
+impl LatticeSection {
+    /// Section with its center of gravity at mid-length
+    pub fn new(part_number: impl Into<String>, kind: LatticeSectionKind, length: Length, weight: Mass) -> Self {
+        Self {
+            part_number: part_number.into(),
+            kind,
+            length,
+            weight,
+            cg: length * 0.5,
+        }
+    }
+
+    pub fn with_cg(mut self, cg: Length) -> Self {
+        self.cg = cg;
+        self
+    }
+}
+
+/// Assembly chart limits for one boom type
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LatticeBoomRules {
+    /// Maximum count of each insert part number; unlisted inserts are not allowed
+    /// unless the map is empty
+    pub insert_limits: HashMap<String, usize>,
+
+    /// Boom lengths the manufacturer rates; any length when empty
+    pub allowed_lengths: Vec<Length>,
+
+    /// Longer inserts must sit nearer the butt
+    pub longest_inserts_first: bool,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum LatticeBoomError {
+    #[error("Boom assembly must start with a butt section")]
+    MissingButt,
+
+    #[error("Boom assembly must end with a tip section")]
+    MissingTip,
+
+    #[error("Section {index} ({part_number}) is out of place; only inserts go between butt and tip")]
+    SectionOutOfPlace { index: usize, part_number: String },
+
+    #[error("Insert {part_number} is not allowed on this boom")]
+    InsertNotAllowed { part_number: String },
+
+    #[error("{count} × insert {part_number} exceeds the limit of {max}")]
+    TooManyInserts { part_number: String, count: usize, max: usize },
+
+    #[error("Insert {part_number} at position {index} must go nearer the butt than shorter inserts")]
+    InsertOrder { index: usize, part_number: String },
+
+    #[error("Boom length {length} is not a rated combination")]
+    LengthNotRated { length: DisplayLength },
+}
+
+/// Sections from foot to tip
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LatticeBoom {
+    pub sections: Vec<LatticeSection>,
+}
+
+impl LatticeBoom {
+    pub fn new(sections: Vec<LatticeSection>) -> Self {
+        Self { sections }
+    }
+
+    pub fn total_length(&self) -> Length {
+        self.sections
+            .iter()
+            .fold(Length::new::<foot>(0.0), |total, section| total + section.length)
+    }
+
+    pub fn total_weight(&self) -> Mass {
+        self.sections
+            .iter()
+            .fold(Mass::new::<pound>(0.0), |total, section| total + section.weight)
+    }
+
+    /// Center of gravity distance from the boom foot pin
+    pub fn cg_distance(&self) -> Length {
+        let mut start = 0.0;
+        let mut moment = 0.0;
+        for section in &self.sections {
+            moment += section.weight.get::<pound>() * (start + section.cg.get::<foot>());
+            start += section.length.get::<foot>();
+        }
+        let weight = self.total_weight().get::<pound>();
+        if weight > 0.0 {
+            Length::new::<foot>(moment / weight)
+        } else {
+            Length::new::<foot>(0.0)
+        }
+    }
+
+    /// Insert part numbers in order, e.g. for labelling a chart
+    pub fn insert_code(&self) -> String {
+        self.sections
+            .iter()
+            .filter(|s| s.kind == LatticeSectionKind::Insert)
+            .map(|s| s.part_number.as_str())
+            .collect::<Vec<_>>()
+            .join("+")
+    }
+
+    /// Check the section order and the assembly chart limits
+    pub fn validate(&self, rules: &LatticeBoomRules) -> Result<(), LatticeBoomError> {
+        match self.sections.first() {
+            Some(s) if s.kind == LatticeSectionKind::Butt => {}
+            _ => return Err(LatticeBoomError::MissingButt),
+        }
+        match self.sections.last() {
+            Some(s) if s.kind == LatticeSectionKind::Tip && self.sections.len() > 1 => {}
+            _ => return Err(LatticeBoomError::MissingTip),
+        }
+
+        let inner = &self.sections[1..self.sections.len() - 1];
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        let mut previous: Option<Length> = None;
+        for (offset, section) in inner.iter().enumerate() {
+            let index = offset + 1;
+            if section.kind != LatticeSectionKind::Insert {
+                return Err(LatticeBoomError::SectionOutOfPlace {
+                    index,
+                    part_number: section.part_number.clone(),
+                });
+            }
+            if rules.longest_inserts_first && previous.is_some_and(|p| section.length > p) {
+                return Err(LatticeBoomError::InsertOrder {
+                    index,
+                    part_number: section.part_number.clone(),
+                });
+            }
+            previous = Some(section.length);
+            *counts.entry(section.part_number.as_str()).or_default() += 1;
+        }
+
+        if !rules.insert_limits.is_empty() {
+            for (part_number, &count) in &counts {
+                let max = *rules.insert_limits.get(*part_number).ok_or_else(|| {
+                    LatticeBoomError::InsertNotAllowed { part_number: part_number.to_string() }
+                })?;
+                if count > max {
+                    return Err(LatticeBoomError::TooManyInserts {
+                        part_number: part_number.to_string(),
+                        count,
+                        max,
+                    });
+                }
+            }
+        }
+
+        let length = self.total_length();
+        if !rules.allowed_lengths.is_empty()
+            && !rules
+                .allowed_lengths
+                .iter()
+                .any(|allowed| (*allowed - length).abs().get::<foot>() < 0.01)
+        {
+            return Err(LatticeBoomError::LengthNotRated { length: DisplayLength(length) });
+        }
+        Ok(())
+    }
+
+    /// Fixed-length boom mass for the crane's stability calculations
+    pub fn boom_mass(&self) -> BoomMass {
+        let length = self.total_length();
+        let cg = self.cg_distance();
+        BoomMass {
+            weight: self.total_weight(),
+            retracted_length: length,
+            retracted_cg: cg,
+            extended_length: length,
+            extended_cg: cg,
+        }
+    }
+
+    pub fn boom_configuration(&self) -> BoomConfiguration {
+        BoomConfiguration {
+            length: LengthValue::new(self.total_length().get::<foot>(), "ft"),
+            angle_range: None,
+            jib: None,
+            telescope_mode: None,
+            boom_inserts: Some(self.insert_code()),
+        }
+    }
+
+    /// Crawler chart configuration for this boom, insert code included
+    pub fn chart_configuration(
+        &self,
+        track_config: impl Into<String>,
+        counterweight: Option<CounterweightConfiguration>,
+    ) -> ChartConfiguration {
+        ChartConfiguration {
+            support: SupportConfiguration::OnCrawlers { track_config: track_config.into() },
+            boom: self.boom_configuration(),
+            counterweight,
+            additional: HashMap::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::capacity::chart_builder::LoadChartBuilder;
+    use approx::assert_relative_eq;
+
+    fn section(part: &str, kind: LatticeSectionKind, length: f64, weight: f64) -> LatticeSection {
+        LatticeSection::new(part, kind, Length::new::<foot>(length), Mass::new::<pound>(weight))
+    }
+
+    fn assembly() -> LatticeBoom {
+        LatticeBoom::new(vec![
+            section("B40", LatticeSectionKind::Butt, 40.0, 8_000.0),
+            section("I20", LatticeSectionKind::Insert, 20.0, 2_000.0),
+            section("I20", LatticeSectionKind::Insert, 20.0, 2_000.0),
+            section("I10", LatticeSectionKind::Insert, 10.0, 1_200.0),
+            section("T30", LatticeSectionKind::Tip, 30.0, 4_000.0),
+        ])
+    }
+
+    #[test]
+    fn test_assembly_length_weight_and_cg() {
+        let boom = assembly();
+        assert_relative_eq!(boom.total_length().get::<foot>(), 120.0, epsilon = 1e-9);
+        assert_relative_eq!(boom.total_weight().get::<pound>(), 17_200.0, epsilon = 1e-9);
+
+        // Section CGs at 20, 50, 70, 85 and 105 ft from the foot
+        let moment = 8_000.0 * 20.0 + 2_000.0 * 50.0 + 2_000.0 * 70.0 + 1_200.0 * 85.0 + 4_000.0 * 105.0;
+        assert_relative_eq!(boom.cg_distance().get::<foot>(), moment / 17_200.0, epsilon = 1e-9);
+        assert_eq!(boom.insert_code(), "I20+I20+I10");
+
+        let mass = boom.boom_mass();
+        assert_relative_eq!(mass.cg_distance(Length::new::<foot>(120.0)).get::<foot>(), moment / 17_200.0, epsilon = 1e-9);
+
+        let config = boom.chart_configuration("tracks extended", None);
+        assert_relative_eq!(config.boom.length_distance().unwrap().get::<foot>(), 120.0, epsilon = 1e-9);
+        assert!(matches!(config.support, SupportConfiguration::OnCrawlers { .. }));
+        assert_eq!(config.boom.boom_inserts.as_deref(), Some("I20+I20+I10"));
+    }
+
+    #[test]
+    fn test_chart_selection_by_inserts() {
+        let chart = LoadChartBuilder::new("crawler 120 ft")
+            .on_crawlers("tracks extended")
+            .configured_boom(120.0, "ft")
+            .boom_inserts("i20+i20+i10")
+            .boom(120.0, "ft")
+            .points(&[(30.0, 60_000.0), (60.0, 25_000.0)])
+            .build()
+            .unwrap();
+        assert!(chart.matches_configuration(&assembly().chart_configuration("tracks extended", None)));
+
+        // The same 120 ft pinned up from 10 ft inserts first is rated separately
+        let mut reordered = assembly();
+        reordered.sections.swap(1, 3);
+        assert_eq!(reordered.insert_code(), "I10+I20+I20");
+        assert!(!chart.matches_configuration(&reordered.chart_configuration("tracks extended", None)));
+    }
+
+    #[test]
+    fn test_assembly_rules() {
+        let mut rules = LatticeBoomRules {
+            insert_limits: HashMap::from([("I20".to_string(), 2), ("I10".to_string(), 1)]),
+            allowed_lengths: vec![Length::new::<foot>(120.0)],
+            longest_inserts_first: true,
+        };
+        assert!(assembly().validate(&rules).is_ok());
+
+        let mut reordered = assembly();
+        reordered.sections.swap(2, 3);
+        assert!(matches!(reordered.validate(&rules), Err(LatticeBoomError::InsertOrder { index: 3, .. })));
+
+        let mut no_tip = assembly();
+        no_tip.sections.pop();
+        assert!(matches!(no_tip.validate(&rules), Err(LatticeBoomError::MissingTip)));
+
+        rules.insert_limits.insert("I20".to_string(), 1);
+        assert!(matches!(assembly().validate(&rules), Err(LatticeBoomError::TooManyInserts { max: 1, .. })));
+
+        rules.insert_limits.clear();
+        rules.allowed_lengths = vec![Length::new::<foot>(110.0)];
+        assert!(matches!(assembly().validate(&rules), Err(LatticeBoomError::LengthNotRated { .. })));
+    }
+}
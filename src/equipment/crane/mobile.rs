@@ -1,28 +1,54 @@
 use crate::capacity::load_chart::{
-    BoomConfiguration, ChartConfiguration, CounterweightConfiguration, LoadChart, LoadChartPackage,
-    OutriggerExtension, SupportConfiguration, SwingRestriction,
+    AngleRange, BoomConfiguration, ChartConfiguration, CounterweightConfiguration, LoadChart, LoadChartError,
+    JibConfiguration, LoadChartPackage, OutriggerExtension, SupportConfiguration, SwingRestriction,
+    TelescopeMode,
 };
-use crate::equipment::crane::{Crane, CraneConfig, CraneType, LiftError};
-use crate::kinematics::{CraneBase, ForwardKinematics, JointConfig};
+use crate::equipment::crane::{
+    CapacityDeductions, ChartRated, CraneConfig, CraneType, DutyClass, Kinematic, LiftError, RatedCapacity,
+    StabilityAnalyzed,
+};
+use crate::equipment::hook_block::HookBlock;
+use crate::kinematics::{CraneBase, ForwardKinematics, JibConfig, JointConfig};
+use crate::physics::ground_bearing::{FloatDimensions, GroundBearingAnalysis, SupportPoint};
+use crate::physics::statics::center_of_gravity;
+use crate::physics::luffing::{LuffingAnalysis, LuffingError, LuffingGeometry};
 use crate::physics::wind_loading::{WindAnalysis, WindError};
 use crate::types::*;
 use nalgebra as na;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Mobile crane (all-terrain, rough terrain, truck-mounted)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct MobileCrane {
     pub manufacturer: String,
     pub model: String,
 
+    /// All-terrain unless set; wind exposure depends on it
+    #[cfg_attr(feature = "serde", serde(default = "all_terrain"))]
+    pub crane_type: CraneType,
+
     // Physical dimensions
     pub boom_length: Length,
+    /// Boom foot pin height above ground (carrier deck plus foot)
     pub boom_base_height: Length,
+    /// Boom foot distance ahead of the slew axis (negative if behind)
+    #[cfg_attr(feature = "serde", serde(default = "zero_offset"))]
+    pub boom_foot_offset: Length,
+    /// Telescope extension mode; charts for other modes at the same length are skipped
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub telescope_mode: Option<TelescopeMode>,
 
     // Current state
     pub boom_angle: Angle,
     pub swing_angle: Angle,
 
+    /// Jib erected on the boom tip; hook position and chart selection
+    /// follow the jib when set
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub jib: Option<JibInstallation>,
+
     /// Cable length (hoist rope payed out from boom tip)
     /// If None, hook position equals boom tip position
     pub cable_length: Option<Length>,
@@ -30,16 +56,114 @@ pub struct MobileCrane {
     // Stability
     pub outrigger_spread: Length,
     pub outrigger_extension: OutriggerExtension,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub outrigger_float: FloatDimensions,
     pub counterweight: Mass,
+    /// Counterweight center of gravity behind the slew axis
+    #[cfg_attr(feature = "serde", serde(default = "zero_offset"))]
+    pub counterweight_radius: Length,
+    /// Boom weight and center of gravity; the boom is treated as part of
+    /// the carrier estimate when `None`
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub boom_mass: Option<BoomMass>,
 
     // Support mode
     pub on_outriggers: bool,
 
+    /// Hook block fitted; supplies the hook block deduction when set
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub hook_block: Option<HookBlock>,
+
+    /// Boom hoist cylinder or pendant geometry, for member force checks
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub luffing: Option<LuffingGeometry>,
+
+    /// Rigging etc. deducted from chart capacity
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub deductions: CapacityDeductions,
+
+    /// Clamshell, dragline etc. derate the chart
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub duty: DutyClass,
+
     // Load charts
-    #[serde(skip)]
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub load_charts: Option<LoadChartPackage>,
 }
 
+#[cfg(feature = "serde")]
+fn zero_offset() -> Length {
+    Length::new::<foot>(0.0)
+}
+
+#[cfg(feature = "serde")]
+fn all_terrain() -> CraneType {
+    CraneType::AllTerrain
+}
+
+/// Boom weight and how its center of gravity moves out as it telescopes
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BoomMass {
+    pub weight: Mass,
+    /// Fully retracted boom length and its CG distance from the boom foot
+    pub retracted_length: Length,
+    pub retracted_cg: Length,
+    /// Fully extended boom length and its CG distance from the boom foot
+    pub extended_length: Length,
+    pub extended_cg: Length,
+}
+
+impl BoomMass {
+    /// CG distance from the boom foot, linear between retracted and extended
+    pub fn cg_distance(&self, boom_length: Length) -> Length {
+        let span = self.extended_length - self.retracted_length;
+        if span.get::<foot>().abs() < 1e-9 {
+            return self.retracted_cg;
+        }
+        let t = ((boom_length - self.retracted_length) / span).value.clamp(0.0, 1.0);
+        self.retracted_cg + (self.extended_cg - self.retracted_cg) * t
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum JibKind {
+    /// Pinned at a set offset; changed only by re-rigging
+    Fixed,
+    /// Offset set by its own luffing gear while working
+    Luffing,
+}
+
+/// Jib erected on the main boom tip
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct JibInstallation {
+    pub kind: JibKind,
+    pub length: Length,
+    /// Angle below the main boom centerline
+    pub offset: Angle,
+}
+
+impl JibInstallation {
+    pub fn fixed(length: Length, offset: Angle) -> Self {
+        Self { kind: JibKind::Fixed, length, offset }
+    }
+
+    pub fn luffing(length: Length, offset: Angle) -> Self {
+        Self { kind: JibKind::Luffing, length, offset }
+    }
+
+    /// Chart configuration entry, with the offset as the jib angle in degrees
+    pub fn chart_configuration(&self) -> JibConfiguration {
+        JibConfiguration {
+            length: LengthValue::new(self.length.get::<foot>(), "ft"),
+            angle: AngleValue::new(self.offset.get::<degree>(), "deg"),
+            offset: None,
+        }
+    }
+}
+
 /// Two-blocking erros
 #[derive(Debug, thiserror::Error)]
 pub enum TwoBlockError {
@@ -60,6 +184,10 @@ pub enum TwoBlockError {
 }
 
 impl MobileCrane {
+    /// Crane with placeholder setup: 45° boom, 20 ft outrigger spread and
+    /// 10,000 lb counterweight
+    ///
+    /// Use [`MobileCrane::builder`] to give the model's actual dimensions.
     pub fn new(
         manufacturer: impl Into<String>,
         model: impl Into<String>,
@@ -69,19 +197,55 @@ impl MobileCrane {
         Self {
             manufacturer: manufacturer.into(),
             model: model.into(),
+            crane_type: CraneType::AllTerrain,
             boom_length,
             boom_base_height,
+            boom_foot_offset: Length::new::<foot>(0.0),
+            telescope_mode: None,
             boom_angle: Angle::new::<degree>(45.0),
             swing_angle: Angle::new::<degree>(0.0),
+            jib: None,
             cable_length: None,
             outrigger_spread: Length::new::<foot>(20.0),
             outrigger_extension: OutriggerExtension::Full,
+            outrigger_float: FloatDimensions::default(),
             counterweight: Mass::new::<pound>(10000.0),
+            counterweight_radius: Length::new::<foot>(0.0),
+            boom_mass: None,
             on_outriggers: true,
+            hook_block: None,
+            luffing: None,
+            deductions: CapacityDeductions::none(),
+            duty: DutyClass::Lifting,
             load_charts: None,
         }
     }
 
+    /// Start a [`MobileCraneBuilder`](crate::equipment::MobileCraneBuilder)
+    pub fn builder(
+        manufacturer: impl Into<String>,
+        model: impl Into<String>,
+    ) -> crate::equipment::MobileCraneBuilder {
+        crate::equipment::MobileCraneBuilder::new(manufacturer, model)
+    }
+
+    /// Place the boom foot: carrier deck height, foot pin height above the
+    /// deck and distance ahead of the slew axis (negative if behind)
+    pub fn set_boom_foot(&mut self, deck_height: Length, foot_above_deck: Length, offset: Length) {
+        self.boom_base_height = deck_height + foot_above_deck;
+        self.boom_foot_offset = offset;
+    }
+
+    /// Boom hoist cylinder force or pendant tension with `load` on the hook
+    ///
+    /// Hook block and rigging deductions are added to the load. `None`
+    /// when no luffing geometry is set.
+    pub fn luffing_analysis(&self, load: Mass) -> Option<Result<LuffingAnalysis, LuffingError>> {
+        let hook_load = load + RatedCapacity::deductions(self).total();
+        self.luffing
+            .map(|geometry| geometry.check(self.boom_length, self.boom_angle, hook_load))
+    }
+
     /// Set cable length (hoist rope payed out)
     pub fn set_cable_length(&mut self, length: Length) -> Result<(), TwoBlockError> {
         let max = self.max_cable_length();
@@ -101,17 +265,29 @@ impl MobileCrane {
         self.cable_length
     }
 
-    /// Get boom tip position (top of boom, where cable exists)
+    /// Get tip position (boom tip, or jib tip if erected, where the cable hangs)
     pub fn tip_position(&self) -> na::Point3<Length> {
-        let boom_len = self.boom_length;
-        let angle = self.boom_angle;
+        let (horizontal, height) = self.tip_reach();
         let swing = self.swing_angle;
+        na::Point3::new(horizontal * swing.sin(), height, horizontal * swing.cos())
+    }
 
-        na::Point3::new(
-            boom_len * angle.cos() * swing.sin(),
-            self.boom_base_height + boom_len * angle.sin(),
-            boom_len * angle.cos() * swing.cos(),
-        )
+    /// Erect a jib, or stow it with `None`
+    pub fn set_jib(&mut self, jib: Option<JibInstallation>) {
+        self.jib = jib;
+    }
+
+    /// Radius from the slew axis and height above ground of the hoist tip
+    fn tip_reach(&self) -> (Length, Length) {
+        let angle = self.boom_angle;
+        let mut horizontal = self.boom_foot_offset + self.boom_length * angle.cos();
+        let mut height = self.boom_base_height + self.boom_length * angle.sin();
+        if let Some(jib) = &self.jib {
+            let jib_angle = angle - jib.offset;
+            horizontal += jib.length * jib_angle.cos();
+            height += jib.length * jib_angle.sin();
+        }
+        (horizontal, height)
     }
 
     /// Get hook position (accounting for cable length)
@@ -131,6 +307,16 @@ impl MobileCrane {
         self.hook_position().y
     }
 
+    /// Height of the hook saddle above ground, below the hook block
+    ///
+    /// Same as [`hook_height`](Self::hook_height) when no block is fitted.
+    pub fn height_under_hook(&self) -> Length {
+        match &self.hook_block {
+            Some(block) => self.hook_height() - block.length,
+            None => self.hook_height(),
+        }
+    }
+
     /// Maximum safe cable length (before two-blocking)
     ///
     /// This is the boom tip height minus minimum clearance
@@ -194,6 +380,7 @@ impl MobileCrane {
     }
 
     /// Load chart package from file
+    #[cfg(feature = "charts-io")]
     pub fn load_charts_from_file(&mut self, path: &str) -> Result<(), Box<dyn std::error::Error>> {
         let package = LoadChartPackage::from_json_file(path)?;
 
@@ -232,7 +419,9 @@ impl MobileCrane {
             boom: BoomConfiguration {
                 length: LengthValue::new(self.boom_length.get::<foot>(), "ft"),
                 angle_range: None,
-                jib: None,
+                jib: self.jib.map(|jib| jib.chart_configuration()),
+                telescope_mode: self.telescope_mode.clone(),
+                boom_inserts: None,
             },
             counterweight: Some(CounterweightConfiguration {
                 weight: MassValue::new(self.counterweight.get::<pound>(), "lbs"),
@@ -249,23 +438,110 @@ impl MobileCrane {
         charts.find_chart(&config)
     }
 
-    /// Get rated capacity at current boom length and radius
-    pub fn rated_capacity_at_radius(&self, radius: Length) -> Mass {
-        // Try to get from load chart
-        if let Some(chart) = self.get_current_chart() {
-            let capacity = chart.capacity_interpolated(self.boom_length, radius);
-            if capacity.is_ok() {
-                return capacity.unwrap();
+    /// Get chart capacity at current boom length and radius
+    ///
+    /// Errors carry the chart id, crane model and configuration used.
+    /// Intermediate outrigger extensions are rated through
+    /// [`LoadChartPackage::capacity_at_extension`], per-corner setups
+    /// through [`LoadChartPackage::capacity_in_quadrant`] at the current
+    /// swing.
+    pub fn try_rated_capacity_at_radius(&self, radius: Length) -> Result<Mass, LoadChartError> {
+        let model = format!("{} {}", self.manufacturer, self.model);
+        if let (true, Some(charts)) = (self.on_outriggers, &self.load_charts) {
+            let config = self.current_configuration();
+            let rated = match self.outrigger_extension {
+                OutriggerExtension::Intermediate { .. } => {
+                    Some(charts.capacity_at_extension(&config, self.boom_length, radius))
+                }
+                OutriggerExtension::PerCorner { .. } => Some(
+                    charts
+                        .capacity_in_quadrant(&config, self.swing_angle, self.boom_length, radius)
+                        .map(|q| q.rated),
+                ),
+                _ => None,
+            };
+            if let Some(rated) = rated {
+                return rated.map(|rated| rated.capacity).map_err(|e| match e {
+                    LoadChartError::NoMatchingChart { configuration, .. } => LoadChartError::NoMatchingChart {
+                        crane_model: model.clone(),
+                        configuration,
+                    },
+                    e => e.with_crane_model(model.clone()),
+                });
             }
         }
+
+        let chart = self
+            .get_current_chart()
+            .ok_or_else(|| LoadChartError::NoMatchingChart {
+                crane_model: model.clone(),
+                configuration: self.current_configuration().summary(),
+            })?;
+
+        chart
+            .capacity_interpolated(self.boom_length, radius)
+            .map_err(|e| e.with_crane_model(model))
+    }
+
+    /// Check the boom angle against the current chart's angle range
+    ///
+    /// Long booms are often rated only above a minimum angle even where
+    /// the radius columns reach further out. Charts without a range pass.
+    pub fn check_boom_angle(&self) -> Result<(), LiftError> {
+        match self.boom_angle_range() {
+            Some(range) => super::check_boom_angle(range, self.boom_angle),
+            None => Ok(()),
+        }
+    }
+
+    /// Get rated capacity at current boom length and radius
+    pub fn rated_capacity_at_radius(&self, radius: Length) -> Mass {
         // Fallback: conservative placeholder
-        Mass::new::<pound>(10000.0)
+        self.try_rated_capacity_at_radius(radius)
+            .unwrap_or(Mass::new::<pound>(10000.0))
+    }
+
+    /// Crane weight without the load: counterweight, boom (if given) and a
+    /// 50,000 lb carrier and superstructure estimate
+    pub fn machine_weight(&self) -> Mass {
+        let boom = self.boom_mass.map_or(Mass::new::<pound>(0.0), |b| b.weight);
+        self.counterweight + boom + Mass::new::<pound>(50000.0)
+    }
+
+    /// Carrier (at the slew center), counterweight and boom as weights at
+    /// their centers of gravity, in feet
+    pub fn machine_masses(&self) -> Vec<(Mass, na::Point3<f64>)> {
+        let (sin, cos) = self.swing_angle.get::<radian>().sin_cos();
+        let along_boom = |horizontal: f64, height: f64| na::Point3::new(horizontal * sin, height, horizontal * cos);
+
+        let cw = self.counterweight_radius.get::<foot>();
+        let mut masses = vec![
+            (Mass::new::<pound>(50000.0), na::Point3::origin()),
+            (self.counterweight, along_boom(-cw, self.boom_base_height.get::<foot>())),
+        ];
+        if let Some(boom) = self.boom_mass {
+            let cg = boom.cg_distance(self.boom_length).get::<foot>();
+            let angle = self.boom_angle.get::<radian>();
+            masses.push((
+                boom.weight,
+                along_boom(
+                    self.boom_foot_offset.get::<foot>() + cg * angle.cos(),
+                    self.boom_base_height.get::<foot>() + cg * angle.sin(),
+                ),
+            ));
+        }
+        masses
+    }
+
+    /// Center of gravity of the crane without the load, in feet
+    pub fn machine_cog(&self) -> na::Point3<f64> {
+        center_of_gravity(&self.machine_masses())
     }
 
     /// Calculate wind analysis for current configuration
     pub fn wind_analysis(&self, wind_speed: Velocity, load_area: Area) -> WindAnalysis {
         WindAnalysis::new(
-            CraneType::AllTerrain, // Or self.crane_type field
+            self.crane_type.clone(),
             self.boom_length,
             self.boom_angle,
             load_area,
@@ -287,13 +563,13 @@ impl MobileCrane {
     }
 }
 
-impl Crane for MobileCrane {
-    fn configuration(&self) -> CraneConfig {
-        let angle_rad = self.boom_angle;
-        let boom_ft = self.boom_length;
+impl Kinematic for MobileCrane {
+    fn crane_type(&self) -> CraneType {
+        self.crane_type.clone()
+    }
 
-        let radius = boom_ft * angle_rad.cos();
-        let height = self.boom_base_height + boom_ft * angle_rad.sin();
+    fn configuration(&self) -> CraneConfig {
+        let (radius, height) = self.tip_reach();
 
         CraneConfig {
             boom_length: self.boom_length,
@@ -304,78 +580,175 @@ impl Crane for MobileCrane {
     }
 
     fn tip_position(&self) -> na::Point3<Length> {
-        let boom_len = self.boom_length;
-        let angle = self.boom_angle;
-        let swing = self.swing_angle;
-
-        na::Point3::new(
-            boom_len * angle.cos() * swing.sin(),
-            self.boom_base_height + boom_len * angle.sin(),
-            boom_len * angle.cos() * swing.cos(),
-        )
+        MobileCrane::tip_position(self)
     }
 
-    fn load_chart(&self) -> &LoadChart {
-        self.get_current_chart()
-            .expect("No load charts loaded. Call load_charts_from_file() first.")
+    fn forward_kinematics(&self) -> ForwardKinematics {
+        let base = CraneBase {
+            position: na::Point3::origin(),
+            pivot_height: self.boom_base_height,
+            boom_foot_offset: self.boom_foot_offset,
+        };
+        ForwardKinematics::new(base)
     }
 
-    fn system_cog(&self, load: Mass) -> na::Point3<Length> {
-        let hook = self.tip_position();
-        let crane_weight = self.counterweight + Mass::new::<pound>(50000.0);
-        let total_weight = crane_weight + load;
+    fn joint_config(&self) -> JointConfig {
+        JointConfig {
+            swing: self.swing_angle,
+            boom_angle: self.boom_angle,
+            boom_length: self.boom_length,
+            hoist: self.cable_length,
+            trolley: None,
+            jib: self.jib.map(|jib| JibConfig {
+                jib_angle: -jib.offset,
+                jib_length: jib.length,
+                jib_offset: Angle::new::<degree>(0.0),
+            }),
+        }
+    }
 
-        na::Point3::new(
-            hook.coords.x * load / total_weight,
-            hook.coords.y * load / total_weight,
-            hook.coords.z * load / total_weight
-        )
+    /// A luffing jib follows the joint's jib angle; a fixed jib keeps its
+    /// rigged offset
+    fn set_joint_config(&mut self, joints: JointConfig) {
+        self.swing_angle = joints.swing;
+        self.boom_angle = joints.boom_angle;
+        self.boom_length = joints.boom_length;
+        self.cable_length = joints.hoist;
+        if let (Some(jib), Some(joint)) = (&mut self.jib, joints.jib)
+            && jib.kind == JibKind::Luffing
+        {
+            jib.offset = -joint.jib_angle;
+        }
     }
+}
 
-    fn tipping_moment(&self, load: Mass) -> Torque {
+impl RatedCapacity for MobileCrane {
+    fn gross_capacity(&self) -> Mass {
         let config = self.configuration();
-        let radius = config.radius;
-        let load_lbs = load;
+        self.rated_capacity_at_radius(config.radius)
+    }
 
-        Torque::new::<pound_force_foot>(load_lbs.get::<pound>() * radius.get::<foot>())
+    fn gross_capacity_at_radius(&self, radius: Length) -> Result<Mass, LoadChartError> {
+        self.try_rated_capacity_at_radius(radius)
     }
 
-    fn rated_capacity(&self) -> Mass {
-        let config = self.configuration();
-        self.rated_capacity_at_radius(config.radius)
+    fn deductions(&self) -> CapacityDeductions {
+        let mut deductions = self.deductions.clone();
+        if let Some(block) = &self.hook_block {
+            deductions.hook_block = block.weight;
+        }
+        deductions
+    }
+
+    fn duty_class(&self) -> DutyClass {
+        self.duty
+    }
+
+    fn boom_angle_range(&self) -> Option<&AngleRange> {
+        self.get_current_chart()
+            .and_then(|chart| chart.configuration.boom.angle_range.as_ref())
     }
 
     fn validate_lift(&self, load: Mass) -> Result<(), LiftError> {
-        let capacity = self.rated_capacity();
+        self.check_boom_angle()?;
+        let capacity = self.net_capacity();
 
         if load > capacity {
             return Err(LiftError::OverCapacity { load, capacity });
         }
 
+        // Everything below the block counts against its rating
+        if let Some(block) = &self.hook_block
+            && load + self.deductions.rigging > block.rated_capacity
+        {
+            return Err(LiftError::OverCapacity {
+                load,
+                capacity: block.rated_capacity - self.deductions.rigging,
+            });
+        }
+
         Ok(())
     }
+}
 
-    fn forward_kinematics(&self) -> ForwardKinematics {
-        let base = CraneBase {
-            position: na::Point3::origin(),
-            pivot_height: self.boom_base_height,
-        };
-        ForwardKinematics::new(base)
+impl ChartRated for MobileCrane {
+    fn load_chart(&self) -> Option<&LoadChart> {
+        self.get_current_chart()
     }
+}
 
-    fn joint_config(&self) -> JointConfig {
-        JointConfig {
-            swing: self.swing_angle,
-            boom_angle: self.boom_angle,
-            boom_length: self.boom_length,
-            jib: None,
+impl StabilityAnalyzed for MobileCrane {
+    fn system_cog(&self, load: Mass) -> na::Point3<Length> {
+        let hook = self.tip_position();
+        let mut masses = self.machine_masses();
+        masses.push((load, point_from_uom_lengths(hook.x, hook.y, hook.z)));
+
+        let cog = center_of_gravity(&masses);
+        na::Point3::new(from_coord(cog.x), from_coord(cog.y), from_coord(cog.z))
+    }
+
+    /// Net moment about the slew axis toward the boom: load and boom
+    /// forward, counterweight back
+    fn tipping_moment(&self, load: Mass) -> Torque {
+        let radius = self.configuration().radius.get::<foot>();
+        let mut moment = load.get::<pound>() * radius
+            - self.counterweight.get::<pound>() * self.counterweight_radius.get::<foot>();
+        if let Some(boom) = self.boom_mass {
+            let cg = boom.cg_distance(self.boom_length).get::<foot>();
+            let horizontal = self.boom_foot_offset.get::<foot>() + cg * self.boom_angle.get::<radian>().cos();
+            moment += boom.weight.get::<pound>() * horizontal;
         }
+
+        Torque::new::<pound_force_foot>(moment)
     }
 
-    fn set_joint_config(&mut self, joints: JointConfig) {
-        self.swing_angle = joints.swing;
-        self.boom_angle = joints.boom_angle;
-        self.boom_length = joints.boom_length;
+    fn operating_weight(&self) -> Option<Mass> {
+        Some(self.machine_weight())
+    }
+
+    fn support_configuration(&self) -> Option<SupportConfiguration> {
+        Some(self.current_configuration().support)
+    }
+
+    fn support_points(&self) -> Vec<SupportPoint> {
+        if !self.on_outriggers {
+            return Vec::new();
+        }
+
+        let half = self.outrigger_spread.get::<foot>() / 2.0;
+        [
+            ("Front right", half, half),
+            ("Front left", -half, half),
+            ("Rear right", half, -half),
+            ("Rear left", -half, -half),
+        ]
+        .into_iter()
+        .map(|(name, x, z)| SupportPoint {
+            position: na::Point3::new(x, 0.0, z),
+            contact_area: self.outrigger_float.area(),
+            name: name.into(),
+            float: Some(self.outrigger_float),
+        })
+        .collect()
+    }
+
+    fn ground_bearing_analysis(&self, load: Mass, mat_area: Option<Area>) -> Option<GroundBearingAnalysis> {
+        if !self.on_outriggers {
+            return None;
+        }
+
+        // Machine weight at its center of gravity, load at the hook
+        let hook = self.hook_position();
+        let mut analysis = GroundBearingAnalysis::new_na(
+            self.machine_weight(),
+            self.machine_cog(),
+            load,
+            point_from_uom_lengths(hook.x, hook.y, hook.z),
+        );
+        for support in self.support_points() {
+            analysis.add_float_na(support.name, support.position, self.outrigger_float, mat_area);
+        }
+        Some(analysis)
     }
 }
 
@@ -428,6 +801,8 @@ mod tests {
                     length: LengthValue::new(154.2, "ft"),
                     angle_range: None,
                     jib: None,
+                    telescope_mode: None,
+                    boom_inserts: None,
                 },
                 counterweight: None,
                 additional: std::collections::HashMap::new(),
@@ -437,6 +812,7 @@ mod tests {
                 "Capacities are based on freely suspended loads".into(),
                 "Machine must be level within 1%".into(),
             ],
+            provenance: ChartProvenance::default(),
         };
 
         package.add_chart(chart);
@@ -452,9 +828,12 @@ mod tests {
             Length::new::<foot>(10.0),
         );
 
+        assert!(crane.load_chart().is_none());
+
         // Load test charts
         let charts = create_test_chart_package();
         crane.set_load_charts(charts);
+        assert!(crane.load_chart().is_some());
 
         // Get capacity at specific radius
         let capacity = crane.rated_capacity_at_radius(Length::new::<foot>(40.0));
@@ -467,6 +846,103 @@ mod tests {
         assert!(capacity.get::<pound>() < 242500.0);
     }
 
+    #[test]
+    fn test_chart_selection_by_telescope_mode() {
+        // Same 154.2 ft boom under two extension schedules
+        let mut package = create_test_chart_package();
+        let mut reduced = package.charts[0].clone();
+        reduced.id = "gmk5250l_em2".into();
+        reduced.configuration.boom.telescope_mode = Some(TelescopeMode::from_code("0/50/90/100"));
+        for row in &mut reduced.capacity_data.data {
+            for (_, capacity) in row.iter_mut() {
+                capacity.value *= 0.8;
+            }
+        }
+        package.charts[0].configuration.boom.telescope_mode = Some(TelescopeMode::new("EM1", vec![100.0; 4]));
+        package.add_chart(reduced);
+
+        let mut crane = MobileCrane::new(
+            "Grove",
+            "GMK5250L",
+            Length::new::<foot>(154.2),
+            Length::new::<foot>(10.0),
+        );
+        crane.set_load_charts(package);
+
+        crane.telescope_mode = Some(TelescopeMode::new("em1", Vec::new()));
+        assert_eq!(crane.get_current_chart().unwrap().id, "gmk5250l_full_outriggers");
+
+        // Matched on the section schedule even though the code differs
+        crane.telescope_mode = Some(TelescopeMode::new("EM2", vec![0.0, 50.0, 90.0, 100.0]));
+        assert_eq!(crane.get_current_chart().unwrap().id, "gmk5250l_em2");
+        assert_relative_eq!(
+            crane.rated_capacity_at_radius(Length::new::<foot>(40.0)).get::<pound>(),
+            121_600.0,
+            max_relative = 1e-9
+        );
+        assert!(crane.get_current_chart().unwrap().configuration.summary().contains("0/50/90/100"));
+    }
+
+    #[test]
+    fn test_jib_position_and_chart_routing() {
+        let mut package = create_test_chart_package();
+        let mut jib_chart = package.charts[0].clone();
+        jib_chart.id = "gmk5250l_jib_60ft_20deg".into();
+        jib_chart.configuration.boom.jib = Some(JibConfiguration {
+            length: LengthValue::new(60.0, "ft"),
+            angle: AngleValue::new(20.0, "deg"),
+            offset: None,
+        });
+        jib_chart.capacity_data.data = vec![vec![
+            (LengthValue::new(40.0, "ft"), MassValue::new(15000.0, "lbs")),
+            (LengthValue::new(200.0, "ft"), MassValue::new(5000.0, "lbs")),
+        ]];
+        package.add_chart(jib_chart);
+
+        let mut crane = MobileCrane::new(
+            "Grove",
+            "GMK5250L",
+            Length::new::<foot>(154.2),
+            Length::new::<foot>(10.0),
+        );
+        crane.boom_angle = Angle::new::<degree>(60.0);
+        crane.set_load_charts(package);
+        let boom_radius = crane.configuration().radius;
+        assert_eq!(crane.get_current_chart().unwrap().id, "gmk5250l_full_outriggers");
+
+        crane.set_jib(Some(JibInstallation::fixed(Length::new::<foot>(60.0), Angle::new::<degree>(20.0))));
+        assert_eq!(crane.get_current_chart().unwrap().id, "gmk5250l_jib_60ft_20deg");
+
+        // Jib at 40° from horizontal adds 60 cos 40° of radius and 60 sin 40° of height
+        let config = crane.configuration();
+        let jib = 40f64.to_radians();
+        assert_relative_eq!(
+            config.radius.get::<foot>(),
+            boom_radius.get::<foot>() + 60.0 * jib.cos(),
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(
+            config.height.get::<foot>(),
+            10.0 + 154.2 * 60f64.to_radians().sin() + 60.0 * jib.sin(),
+            epsilon = 1e-9
+        );
+
+        // Forward kinematics agrees with the crane's own tip
+        let fk = crane.forward_kinematics().solve(&crane.joint_config());
+        assert_relative_eq!(fk.z, crane.tip_position().z.get::<foot>(), epsilon = 1e-6);
+        assert_relative_eq!(fk.y, crane.tip_position().y.get::<foot>(), epsilon = 1e-6);
+
+        // A fixed jib ignores joint jib angles; a luffing jib follows them
+        let mut joints = crane.joint_config();
+        joints.jib.as_mut().unwrap().jib_angle = Angle::new::<degree>(-5.0);
+        crane.set_joint_config(joints);
+        assert_relative_eq!(crane.jib.unwrap().offset.get::<degree>(), 20.0, epsilon = 1e-9);
+        crane.jib.as_mut().unwrap().kind = JibKind::Luffing;
+        crane.set_joint_config(joints);
+        assert_relative_eq!(crane.jib.unwrap().offset.get::<degree>(), 5.0, epsilon = 1e-9);
+        assert!(crane.get_current_chart().is_none());
+    }
+
     #[test]
     fn test_validate_lift_with_charts() {
         let mut crane = MobileCrane::new(
@@ -496,4 +972,195 @@ mod tests {
         let unsafe_load = Mass::new::<pound>(capacity.get::<pound>() * 1.2);
         assert!(crane.validate_lift(unsafe_load).is_err());
     }
+
+    #[test]
+    fn test_boom_angle_range_rejects_low_angle() {
+        let mut package = create_test_chart_package();
+        package.charts[0].configuration.boom.angle_range = Some(AngleRange {
+            min: AngleValue::new(55.0, "deg"),
+            max: AngleValue::new(82.0, "deg"),
+        });
+        let mut crane = MobileCrane::new(
+            "Grove",
+            "GMK5250L",
+            Length::new::<foot>(154.2),
+            Length::new::<foot>(10.0),
+        );
+        crane.set_load_charts(package);
+        let load = Mass::new::<pound>(10_000.0);
+
+        // 50° puts the hook at ~99 ft, inside the radius columns
+        crane.boom_angle = Angle::new::<degree>(50.0);
+        assert!(crane.configuration().radius < Length::new::<foot>(100.0));
+        assert!(matches!(crane.validate_lift(load), Err(LiftError::BoomAngleOutOfRange { .. })));
+
+        crane.boom_angle = Angle::new::<degree>(60.0);
+        assert!(crane.validate_lift(load).is_ok());
+
+        crane.boom_angle = Angle::new::<degree>(85.0);
+        assert!(matches!(crane.validate_lift(load), Err(LiftError::BoomAngleOutOfRange { .. })));
+    }
+
+    #[test]
+    fn test_gross_and_net_capacity() {
+        let mut crane = MobileCrane::new(
+            "Grove",
+            "GMK5250L",
+            Length::new::<foot>(154.2),
+            Length::new::<foot>(10.0),
+        );
+        crane.set_load_charts(create_test_chart_package());
+        crane.boom_angle = Angle::new::<degree>(60.0);
+
+        let gross = crane.gross_capacity();
+        assert_relative_eq!(crane.net_capacity().get::<pound>(), gross.get::<pound>());
+
+        crane.deductions.hook_block = Mass::new::<pound>(2500.0);
+        crane.deductions.rigging = Mass::new::<pound>(800.0);
+        crane.deductions.other.push(("Headache ball".into(), Mass::new::<pound>(700.0)));
+
+        assert_relative_eq!(crane.deductions().total().get::<pound>(), 4000.0);
+        assert_relative_eq!(crane.net_capacity().get::<pound>(), gross.get::<pound>() - 4000.0);
+        assert_relative_eq!(crane.rated_capacity().get::<pound>(), gross.get::<pound>());
+
+        // A load that fits the gross chart but not after deductions
+        let load = gross - Mass::new::<pound>(1000.0);
+        assert!(matches!(crane.validate_lift(load), Err(LiftError::OverCapacity { .. })));
+
+        // Clamshell duty takes the chart to 80% before deductions
+        crane.duty = DutyClass::Clamshell;
+        let derated = gross.get::<pound>() * 0.8 - 4000.0;
+        assert_relative_eq!(crane.net_capacity().get::<pound>(), derated, max_relative = 1e-12);
+        assert_relative_eq!(crane.gross_capacity().get::<pound>(), gross.get::<pound>());
+
+        // A custom duty factor must be a fraction of chart, from code or a file
+        crane.duty = DutyClass::custom(0.5).unwrap();
+        assert_relative_eq!(crane.net_capacity().get::<pound>(), gross.get::<pound>() * 0.5 - 4000.0);
+        assert!(DutyClass::custom(0.0).is_err());
+        assert!(DutyClass::custom(1.2).is_err());
+        #[cfg(feature = "serde")]
+        {
+            assert!(serde_json::from_str::<DutyClass>(r#"{ "Custom": 0.7 }"#).is_ok());
+            assert!(serde_json::from_str::<DutyClass>(r#"{ "Custom": -0.7 }"#).is_err());
+        }
+    }
+
+    #[test]
+    fn test_hook_block_feeds_deductions_and_headroom() {
+        use crate::equipment::hook_blocks;
+
+        let mut crane = MobileCrane::new(
+            "Grove",
+            "GMK5250L",
+            Length::new::<foot>(154.2),
+            Length::new::<foot>(10.0),
+        );
+        crane.deductions.hook_block = Mass::new::<pound>(9999.0);
+        crane.hook_block = Some(hook_blocks::five_sheave_100t());
+        crane.set_cable_length(Length::new::<foot>(30.0)).unwrap();
+
+        // Block weight replaces the manual hook block deduction
+        assert_relative_eq!(crane.deductions().hook_block.get::<pound>(), 3300.0);
+
+        // 84 in block below the rope attachment
+        assert_relative_eq!(
+            crane.height_under_hook().get::<foot>(),
+            crane.hook_height().get::<foot>() - 7.0,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn test_chart_error_context() {
+        let mut crane = MobileCrane::new(
+            "Grove",
+            "GMK5250L",
+            Length::new::<foot>(154.2),
+            Length::new::<foot>(10.0),
+        );
+
+        assert!(matches!(
+            crane.try_rated_capacity_at_radius(Length::new::<foot>(40.0)),
+            Err(LoadChartError::NoMatchingChart { .. })
+        ));
+
+        crane.set_load_charts(create_test_chart_package());
+        let err = crane
+            .try_rated_capacity_at_radius(Length::new::<foot>(150.0))
+            .unwrap_err();
+
+        let context = err.context().unwrap();
+        assert_eq!(context.chart_id, "gmk5250l_full_outriggers");
+        assert_eq!(context.crane_model.as_deref(), Some("Grove GMK5250L"));
+        assert!(err.to_string().contains("gmk5250l_full_outriggers"));
+    }
+
+    #[test]
+    fn test_outrigger_reactions_follow_swing() {
+        let mut crane = MobileCrane::new("Grove", "GMK5250L", Length::new::<foot>(100.0), Length::new::<foot>(10.0));
+        crane.boom_angle = Angle::new::<degree>(60.0);
+        crane.outrigger_float = FloatDimensions::square(Length::new::<foot>(3.0));
+        let load = Mass::new::<pound>(5000.0);
+
+        let critical = |crane: &MobileCrane| {
+            let result = crane.outrigger_reactions(load).unwrap().unwrap();
+            result.reactions[result.critical_support_index].clone()
+        };
+
+        // Reactions share the whole weight; pressure is over the float itself
+        let result = crane.outrigger_reactions(load).unwrap().unwrap();
+        let total: f64 = result.reactions.iter().map(|r| r.force.get::<pound_force>()).sum();
+        assert_relative_eq!(total, 65000.0, epsilon = 1e-6);
+        let reaction = critical(&crane);
+        assert_relative_eq!(reaction.contact_area.get::<square_foot>(), 9.0, epsilon = 1e-9);
+        assert_relative_eq!(
+            reaction.pressure.get::<psi>(),
+            reaction.force.get::<pound_force>() / (9.0 * 144.0),
+            epsilon = 1e-9
+        );
+
+        crane.swing_angle = Angle::new::<degree>(45.0);
+        assert_eq!(critical(&crane).name, "Front right");
+        crane.swing_angle = Angle::new::<degree>(225.0);
+        assert_eq!(critical(&crane).name, "Rear left");
+
+        // A mat under each float spreads the same reaction wider
+        let analysis = crane
+            .ground_bearing_analysis(load, Some(Area::new::<square_foot>(16.0)))
+            .unwrap();
+        let on_mat = analysis.calculate_reactions().unwrap();
+        assert!(on_mat.max_pressure < critical(&crane).pressure);
+
+        crane.on_outriggers = false;
+        assert!(crane.support_points().is_empty());
+        assert!(crane.outrigger_reactions(load).is_none());
+    }
+
+    #[test]
+    fn test_boom_and_counterweight_moments() {
+        let mut crane = MobileCrane::new("Grove", "GMK5250L", Length::new::<foot>(100.0), Length::new::<foot>(10.0));
+        crane.boom_angle = Angle::new::<degree>(60.0);
+        crane.counterweight = Mass::new::<pound>(40_000.0);
+        crane.counterweight_radius = Length::new::<foot>(12.0);
+        crane.boom_mass = Some(BoomMass {
+            weight: Mass::new::<pound>(30_000.0),
+            retracted_length: Length::new::<foot>(40.0),
+            retracted_cg: Length::new::<foot>(18.0),
+            extended_length: Length::new::<foot>(160.0),
+            extended_cg: Length::new::<foot>(48.0),
+        });
+
+        // Boom CG 33 ft up the 100 ft boom, 16.5 ft out at 60°
+        let boom = crane.boom_mass.unwrap();
+        assert_relative_eq!(boom.cg_distance(crane.boom_length).get::<foot>(), 33.0, epsilon = 1e-9);
+
+        // 10,000 × 50 + 30,000 × 16.5 − 40,000 × 12
+        let moment = crane.tipping_moment(Mass::new::<pound>(10_000.0));
+        assert_relative_eq!(moment.get::<pound_force_foot>(), 515_000.0, max_relative = 1e-6);
+
+        // (30,000 × 16.5 − 40,000 × 12) / 120,000 ahead of the slew axis
+        assert_relative_eq!(crane.machine_cog().z, 0.125, epsilon = 1e-9);
+        let cog = crane.system_cog(Mass::new::<pound>(0.0));
+        assert_relative_eq!(cog.z.get::<foot>(), crane.machine_cog().z, epsilon = 1e-9);
+    }
 }
@@ -1,14 +1,21 @@
 pub mod mobile;
 pub mod tower;
+pub mod lattice;
+mod builder;
 use nalgebra as na;
 use crate::types::*;
-use crate::capacity::load_chart::LoadChart;
+use crate::capacity::load_chart::{AngleRange, LoadChart, LoadChartError, SupportConfiguration};
 use crate::kinematics::{ForwardKinematics, JointConfig};
-pub use mobile::MobileCrane;
+use crate::physics::{GroundBearingAnalysis, GroundBearingError, GroundBearingResult, SupportPoint};
+pub use mobile::{BoomMass, JibInstallation, JibKind, MobileCrane};
+pub use builder::{CraneBuildError, MobileCraneBuilder};
+pub use lattice::{LatticeBoom, LatticeBoomError, LatticeBoomRules, LatticeSection, LatticeSectionKind};
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CraneType {
     MobileTelescopic,
     MobileLattice,
@@ -19,38 +26,278 @@ pub enum CraneType {
     TruckMounted,
 }
 
-/// Core trait all crane types must implement
-pub trait Crane {
+/// Geometry and joint state
+pub trait Kinematic {
     /// Get the crane's current configuration
     fn configuration(&self) -> CraneConfig;
-    
+
     /// Calculate boom tip position given current joint angles
     fn tip_position(&self) -> na::Point3<Length>;
-    
-    /// Get the load chart for current configuration
-    fn load_chart(&self) -> &LoadChart;
-    
-    /// Calculate center of gravity of entire crane + load system
-    fn system_cog(&self, load: Mass) -> na::Point3<Length>;
-    
-    /// Calculate tipping moment for given load at current position
-    fn tipping_moment(&self, load: Mass) -> Torque;
-    
-    /// Maximum rated capacity at current configuration
-    fn rated_capacity(&self) -> Mass;
-    
-    /// Validate if lift is within safety parameters
-    fn validate_lift(&self, load: Mass) -> Result<(), LiftError>;
-    
+
     /// Get forward kinematics solver for this crane
     fn forward_kinematics(&self) -> ForwardKinematics;
-    
+
     /// Get current joint configuration
     fn joint_config(&self) -> JointConfig;
-    
+
     /// Set joint configuration (move crane to position)
     fn set_joint_config(&mut self, joints: JointConfig);
 
+    /// Kind of crane, for wind exposure and other type-specific rules
+    fn crane_type(&self) -> CraneType;
+}
+
+/// Capacity at the current configuration, however the crane is rated
+pub trait RatedCapacity {
+    /// Maximum rated capacity at current configuration
+    ///
+    /// Same as [`gross_capacity`](RatedCapacity::gross_capacity); prefer the
+    /// explicit gross/net methods in new code.
+    fn rated_capacity(&self) -> Mass {
+        self.gross_capacity()
+    }
+
+    /// Chart (or moment-rated) capacity at the current configuration,
+    /// before anything hanging from the hook is deducted
+    fn gross_capacity(&self) -> Mass;
+
+    /// Gross capacity with the hook at `radius`, everything else as it is
+    ///
+    /// Errors when `radius` is off the chart. Cranes that can't rate
+    /// another radius keep the default, which always errors.
+    fn gross_capacity_at_radius(&self, _radius: Length) -> Result<Mass, LoadChartError> {
+        Err(LoadChartError::NoData)
+    }
+
+    /// Weights carried by the hook that count against gross capacity
+    fn deductions(&self) -> CapacityDeductions;
+
+    /// Duty the crane is rigged for; ordinary lifting unless overridden
+    fn duty_class(&self) -> DutyClass {
+        DutyClass::Lifting
+    }
+
+    /// Capacity left for the load itself: gross after duty-cycle derating,
+    /// minus deductions, never negative
+    fn net_capacity(&self) -> Mass {
+        let net = self.gross_capacity() * self.duty_class().capacity_factor() - self.deductions().total();
+        if net > Mass::new::<pound>(0.0) { net } else { Mass::new::<pound>(0.0) }
+    }
+
+    /// Boom angle range the current rating applies to; `None` when unrestricted
+    fn boom_angle_range(&self) -> Option<&AngleRange> {
+        None
+    }
+
+    /// Validate if lift is within safety parameters
+    fn validate_lift(&self, load: Mass) -> Result<(), LiftError>;
+}
+
+/// Check a boom angle against a chart's rated angle range
+pub fn check_boom_angle(range: &AngleRange, angle: Angle) -> Result<(), LiftError> {
+    let (min, max) = range.bounds().map_err(LiftError::UnreadableAngleRange)?;
+    if range.contains(angle).map_err(LiftError::UnreadableAngleRange)? {
+        Ok(())
+    } else {
+        Err(LiftError::BoomAngleOutOfRange {
+            angle: DisplayAngle(angle),
+            min: DisplayAngle(min),
+            max: DisplayAngle(max),
+        })
+    }
+}
+
+/// Cranes rated from manufacturer load charts
+pub trait ChartRated: RatedCapacity {
+    /// Chart for the current configuration, if one is loaded
+    fn load_chart(&self) -> Option<&LoadChart>;
+}
+
+/// Overturning and support reactions
+pub trait StabilityAnalyzed {
+    /// Calculate center of gravity of entire crane + load system
+    fn system_cog(&self, load: Mass) -> na::Point3<Length>;
+
+    /// Calculate tipping moment for given load at current position
+    fn tipping_moment(&self, load: Mass) -> Torque;
+
+    /// Weight of the crane itself, without the load
+    ///
+    /// `None` when the crane doesn't model its own weight.
+    fn operating_weight(&self) -> Option<Mass> {
+        None
+    }
+
+    /// How the crane stands: outriggers and their extension, rubber,
+    /// crawlers or a barge
+    ///
+    /// `None` for cranes that don't model it.
+    fn support_configuration(&self) -> Option<SupportConfiguration> {
+        None
+    }
+
+    /// Supports (outrigger floats) at their current positions
+    ///
+    /// Empty for cranes without a support model.
+    fn support_points(&self) -> Vec<SupportPoint> {
+        Vec::new()
+    }
+
+    /// Ground bearing analysis with `load` on the hook at the current
+    /// swing, each support standing on `mat_area`, or on its own float
+    /// when `None`
+    ///
+    /// `None` for cranes without a support model.
+    fn ground_bearing_analysis(&self, _load: Mass, _mat_area: Option<Area>) -> Option<GroundBearingAnalysis> {
+        None
+    }
+
+    /// Reaction under each float with `load` on the hook at the current swing
+    ///
+    /// `None` for cranes without a support model.
+    fn outrigger_reactions(&self, load: Mass) -> Option<Result<GroundBearingResult, GroundBearingError>> {
+        self.ground_bearing_analysis(load, None)
+            .map(|analysis| analysis.calculate_reactions())
+    }
+}
+
+/// Everything a full lift plan needs; implemented for any type with the
+/// three capability traits
+///
+/// Chart access is separate ([`ChartRated`]) since moment-rated cranes
+/// have no load chart.
+pub trait Crane: Kinematic + RatedCapacity + StabilityAnalyzed {}
+
+impl<T: Kinematic + RatedCapacity + StabilityAnalyzed> Crane for T {}
+
+/// Weights deducted from gross capacity
+///
+/// Manufacturer charts are gross: hook block, rigging and anything else
+/// below the boom tip must be subtracted to get the allowable load.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CapacityDeductions {
+    pub hook_block: Mass,
+    /// Slings, shackles, spreader bars
+    pub rigging: Mass,
+    /// Hoist rope weight below the boom tip
+    pub hoist_rope: Mass,
+    /// Anything else (stowed jib, headache ball), by description
+    pub other: Vec<(String, Mass)>,
+}
+
+impl CapacityDeductions {
+    /// No deductions
+    pub fn none() -> Self {
+        Self {
+            hook_block: Mass::new::<pound>(0.0),
+            rigging: Mass::new::<pound>(0.0),
+            hoist_rope: Mass::new::<pound>(0.0),
+            other: Vec::new(),
+        }
+    }
+
+    pub fn total(&self) -> Mass {
+        self.other
+            .iter()
+            .fold(self.hook_block + self.rigging + self.hoist_rope, |sum, (_, m)| sum + *m)
+    }
+}
+
+impl Default for CapacityDeductions {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+/// What the crane is doing, for duty-cycle derating
+///
+/// Charts rate ordinary lifting. Clamshell, dragline, magnet and
+/// concrete-bucket work cycles the crane hard and repeatedly, and
+/// manufacturers typically limit it to 80% of chart.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DutyClass {
+    #[default]
+    Lifting,
+    Clamshell,
+    Dragline,
+    ConcreteBucket,
+    Magnet,
+
+    /// Other repetitive duty, with the manufacturer's factor on chart
+    Custom(DutyFactor),
+}
+
+impl DutyClass {
+    /// Other repetitive duty; errors unless `factor` is in (0, 1]
+    pub fn custom(factor: f64) -> Result<Self, InvalidDutyFactor> {
+        DutyFactor::new(factor).map(DutyClass::Custom)
+    }
+
+    /// Factor on chart capacity
+    pub fn capacity_factor(&self) -> f64 {
+        match self {
+            DutyClass::Lifting => 1.0,
+            DutyClass::Clamshell | DutyClass::Dragline | DutyClass::ConcreteBucket | DutyClass::Magnet => 0.8,
+            DutyClass::Custom(factor) => factor.get(),
+        }
+    }
+
+    pub fn is_derated(&self) -> bool {
+        self.capacity_factor() < 1.0
+    }
+}
+
+/// Factor on chart capacity for a custom duty, in (0, 1]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "f64", into = "f64"))]
+pub struct DutyFactor(f64);
+
+impl DutyFactor {
+    pub fn new(factor: f64) -> Result<Self, InvalidDutyFactor> {
+        if factor > 0.0 && factor <= 1.0 {
+            Ok(Self(factor))
+        } else {
+            Err(InvalidDutyFactor(factor))
+        }
+    }
+
+    pub fn get(&self) -> f64 {
+        self.0
+    }
+}
+
+impl TryFrom<f64> for DutyFactor {
+    type Error = InvalidDutyFactor;
+
+    fn try_from(factor: f64) -> Result<Self, Self::Error> {
+        Self::new(factor)
+    }
+}
+
+impl From<DutyFactor> for f64 {
+    fn from(factor: DutyFactor) -> Self {
+        factor.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, thiserror::Error)]
+#[error("Duty factor {0} must be above 0 and at most 1")]
+pub struct InvalidDutyFactor(pub f64);
+
+impl std::fmt::Display for DutyClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DutyClass::Lifting => write!(f, "lifting"),
+            DutyClass::Clamshell => write!(f, "clamshell"),
+            DutyClass::Dragline => write!(f, "dragline"),
+            DutyClass::ConcreteBucket => write!(f, "concrete bucket"),
+            DutyClass::Magnet => write!(f, "magnet"),
+            DutyClass::Custom(_) => write!(f, "repetitive"),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -71,4 +318,10 @@ pub enum LiftError {
     
     #[error("Tipping moment {moment} exceeds stability limit {limit}")]
     TippingRisk { moment: f64, limit: f64 },
+
+    #[error("Boom angle {angle} is outside the chart range {min} to {max}")]
+    BoomAngleOutOfRange { angle: DisplayAngle, min: DisplayAngle, max: DisplayAngle },
+
+    #[error("Chart boom angle range can't be read: {0}")]
+    UnreadableAngleRange(UnitError),
 }
@@ -1,7 +1,13 @@
 use nalgebra as na;
-use crate::equipment::crane::{Crane, CraneConfig, LiftError};
+use crate::equipment::crane::{
+    CapacityDeductions, CraneConfig, CraneType, Kinematic, LiftError, RatedCapacity, StabilityAnalyzed,
+};
 use crate::types::*;
+use crate::capacity::load_chart::{ChartContext, LoadChartError};
 use crate::kinematics::{ForwardKinematics, JointConfig, CraneBase};
+use crate::report::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// Tower crane - fixed base with slewing superstructure
 /// 
@@ -42,6 +48,9 @@ pub struct TowerCrane {
     
     /// Load moment limiter settings
     pub moment_limiter: MomentLimiter,
+
+    /// Hook block, rigging etc. deducted from moment-rated capacity
+    pub deductions: CapacityDeductions,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -109,6 +118,7 @@ impl CounterweightConfig {
 /// This is THE critical rating. Tower cranes are limited by moment, not load.
 /// A 10,000 lb load at 100 ft = 1,000,000 ft-lb moment
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TowerMoment(pub f64); // ft-lb
 
 impl TowerMoment {
@@ -142,9 +152,20 @@ impl TowerMoment {
 use std::fmt;
 #[derive(Debug)]
 pub struct DisplayTowerMoment(pub TowerMoment);
+impl PreferredDisplay for DisplayTowerMoment {
+    /// Follows the mass preference
+    fn fmt_with(&self, preferences: &UnitPreferences, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match preferences.mass {
+            UnitChoice::Dual => write!(f, "{:.2} ft-lb ({:.3} ton-m)", self.0.ft_lb(), self.0.ton_meters()),
+            UnitChoice::Imperial => write!(f, "{:.2} ft-lb", self.0.ft_lb()),
+            UnitChoice::Metric => write!(f, "{:.3} ton-m", self.0.ton_meters()),
+        }
+    }
+}
+
 impl fmt::Display for DisplayTowerMoment {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:.2} ft-lb ({:.3} ton-m)", self.0.ft_lb(), self.0.ton_meters())
+        self.fmt_with(&UnitPreferences::global(), f)
     }
 }
 
@@ -308,6 +329,7 @@ impl MomentLimiter {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum LimiterStatus {
     Normal,
     Warning,
@@ -346,6 +368,7 @@ impl TowerCrane {
             ),
             max_moment,
             moment_limiter: MomentLimiter::new(max_moment, SafetyMargins::standard()).unwrap(),
+            deductions: CapacityDeductions::none(),
         }
     }
     
@@ -491,7 +514,8 @@ impl TowerCrane {
 }
 
 /// Tower crane lift analysis results
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TowerLiftAnalysis {
     pub load: Mass,
     pub radius: Length,
@@ -503,6 +527,54 @@ pub struct TowerLiftAnalysis {
     pub is_safe: bool,
 }
 
+impl AnalysisReport for TowerLiftAnalysis {
+    fn title(&self) -> String {
+        "Tower Crane".into()
+    }
+
+    fn checks(&self) -> Vec<ValidationCheck> {
+        vec![
+            ValidationCheck {
+                name: "Moment Limiter".into(),
+                status: match self.limiter_status {
+                    LimiterStatus::Normal => CheckStatus::Pass,
+                    LimiterStatus::Warning | LimiterStatus::Disabled => CheckStatus::Warning,
+                    LimiterStatus::Shutdown => CheckStatus::Fail,
+                },
+                details: format!(
+                    "{:.0} of {:.0} ft-lb ({:?})",
+                    self.load_moment.ft_lb(),
+                    self.max_moment.ft_lb(),
+                    self.limiter_status
+                ),
+                margin: Some((1.0 - self.load_moment.ft_lb() / self.max_moment.ft_lb()) * 100.0),
+            },
+            ValidationCheck {
+                name: "Capacity".into(),
+                status: if self.utilization > 1.0 { CheckStatus::Fail } else { CheckStatus::Pass },
+                details: format!(
+                    "{:.0} lbs of {:.0} lbs at {:.1} ft",
+                    self.load.get::<pound>(),
+                    self.capacity.get::<pound>(),
+                    self.radius.get::<foot>()
+                ),
+                margin: Some((1.0 - self.utilization) * 100.0),
+            },
+        ]
+    }
+
+    fn results(&self) -> Vec<NumericResult> {
+        vec![
+            NumericResult::new("load", self.load.get::<pound>(), "lbs"),
+            NumericResult::new("radius", self.radius.get::<foot>(), "ft"),
+            NumericResult::new("load_moment", self.load_moment.ft_lb(), "ft-lb"),
+            NumericResult::new("max_moment", self.max_moment.ft_lb(), "ft-lb"),
+            NumericResult::new("capacity", self.capacity.get::<pound>(), "lbs"),
+            NumericResult::new("utilization", self.utilization, ""),
+        ]
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum TowerCraneError {
     #[error("Load moment {load_moment} exceeds maximum moment {max_moment}")]
@@ -529,8 +601,12 @@ pub enum TowerCraneError {
     },
 }
 
-// Implement Crane trait for TowerCrane
-impl Crane for TowerCrane {
+// Moment-rated: no ChartRated impl
+impl Kinematic for TowerCrane {
+    fn crane_type(&self) -> CraneType {
+        CraneType::Tower
+    }
+
     fn configuration(&self) -> CraneConfig {
         CraneConfig {
             boom_length: self.jib.length,
@@ -539,19 +615,87 @@ impl Crane for TowerCrane {
             height: self.tower_height,
         }
     }
-    
-    fn tip_position(&self) -> na::Point3<f64> {
-        self.hook_position()
+
+    fn tip_position(&self) -> na::Point3<Length> {
+        let hook = self.hook_position();
+        na::Point3::new(from_coord(hook.x), from_coord(hook.y), from_coord(hook.z))
     }
-    
-    fn load_chart(&self) -> &crate::capacity::load_chart::LoadChart {
-        // Tower cranes don't use traditional load charts
-        // They use moment ratings
-        // Return a dummy for now
-        todo!("Tower cranes use moment ratings, not load charts")
+
+    fn forward_kinematics(&self) -> ForwardKinematics {
+        let base = CraneBase {
+            position: na::Point3::origin(),
+            pivot_height: self.tower_height,
+            boom_foot_offset: Length::new::<foot>(0.0),
+        };
+        ForwardKinematics::new(base)
     }
-    
-    fn system_cog(&self, load: Mass) -> na::Point3<f64> {
+
+    fn joint_config(&self) -> JointConfig {
+        JointConfig {
+            swing: self.slew_angle,
+            boom_angle: self.jib.angle,
+            boom_length: self.jib.length,
+            hoist: Some(self.hook_height),
+            trolley: Some(self.trolley_position),
+            jib: None,
+        }
+    }
+
+    fn set_joint_config(&mut self, joints: JointConfig) {
+        self.slew_angle = joints.swing;
+        self.jib.angle = joints.boom_angle;
+        if let Some(trolley) = joints.trolley {
+            self.trolley_position = trolley;
+        }
+        if let Some(hoist) = joints.hoist {
+            self.hook_height = hoist;
+        }
+    }
+}
+
+impl RatedCapacity for TowerCrane {
+    fn gross_capacity(&self) -> Mass {
+        self.capacity_at_current_position()
+    }
+
+    /// Moment rating between the jib's minimum and maximum radius
+    fn gross_capacity_at_radius(&self, radius: Length) -> Result<Mass, LoadChartError> {
+        if radius < self.jib.min_radius || radius > self.jib.max_radius {
+            return Err(LoadChartError::RadiusOutOfRange {
+                radius: DisplayLength(radius),
+                context: ChartContext {
+                    chart_id: "moment rating".into(),
+                    crane_model: Some(self.model.clone()),
+                    configuration: format!("{:.0} ft jib", self.jib.max_radius.get::<foot>()),
+                },
+            });
+        }
+        Ok(self.capacity_at_radius(radius))
+    }
+
+    fn deductions(&self) -> CapacityDeductions {
+        self.deductions.clone()
+    }
+
+    fn validate_lift(&self, load: Mass) -> Result<(), LiftError> {
+        // The moment limiter sees everything on the hook, not just the load
+        match self.validate_lift(load + self.deductions.total()) {
+            Ok(_) => Ok(()),
+            Err(TowerCraneError::MomentExceeded { .. }) => {
+                Err(LiftError::OverCapacity {
+                    load,
+                    capacity: self.net_capacity(),
+                })
+            }
+            Err(_) => Err(LiftError::LoadChartExceeded {
+                radius: self.trolley_position,
+            }),
+        }
+    }
+}
+
+impl StabilityAnalyzed for TowerCrane {
+    fn system_cog(&self, load: Mass) -> na::Point3<Length> {
         // Calculate system COG including load and counterweight
         let hook = self.hook_position();
         let cw_radius = self.counterweight.radius.get::<foot>();
@@ -569,54 +713,17 @@ impl Crane for TowerCrane {
         let weighted_pos = (hook.coords * load.get::<pound>() + 
                            cw_pos.coords * self.counterweight.weight.get::<pound>()) / total_weight;
         
-        na::Point3::from(weighted_pos)
+        na::Point3::new(
+            from_coord(weighted_pos.x),
+            from_coord(weighted_pos.y),
+            from_coord(weighted_pos.z),
+        )
     }
-    
-    fn tipping_moment(&self, load: Mass) -> f64 {
+
+    fn tipping_moment(&self, load: Mass) -> Torque {
         // Tower cranes don't "tip" in the traditional sense
         // They're rated by moment capacity
-        self.load_moment(load).0
-    }
-    
-    fn rated_capacity(&self) -> Mass {
-        self.capacity_at_current_position()
-    }
-    
-    fn validate_lift(&self, load: Mass) -> Result<(), LiftError> {
-        match self.validate_lift(load) {
-            Ok(_) => Ok(()),
-            Err(TowerCraneError::MomentExceeded { load_moment, max_moment }) => {
-                Err(LiftError::OverCapacity {
-                    load,
-                    capacity: Mass::new::<pound>(max_moment.0.ft_lb() / self.trolley_position.get::<foot>()),
-                })
-            }
-            Err(_) => Err(LiftError::LoadChartExceeded {
-                radius: self.trolley_position,
-            }),
-        }
-    }
-    
-    fn forward_kinematics(&self) -> ForwardKinematics {
-        let base = CraneBase {
-            position: na::Point3::origin(),
-            pivot_height: Length::new::<foot>(0.0),
-        };
-        ForwardKinematics::new(base)
-    }
-    
-    fn joint_config(&self) -> JointConfig {
-        JointConfig {
-            swing: self.slew_angle,
-            boom_angle: self.jib.angle,
-            boom_length: self.jib.length,
-            jib: None,
-        }
-    }
-    
-    fn set_joint_config(&mut self, joints: JointConfig) {
-        self.slew_angle = joints.swing;
-        self.jib.angle = joints.boom_angle;
+        Torque::new::<pound_force_foot>(self.load_moment(load).ft_lb())
     }
 }
 
@@ -668,6 +775,26 @@ mod tests {
         assert_relative_eq!(capacity.get::<pound>(), 10000.0);
     }
     
+    #[test]
+    fn test_net_capacity() {
+        let mut crane = TowerCrane::new(
+            "Liebherr",
+            "280 EC-H 12",
+            TowerCraneType::FlatTop,
+            Length::new::<foot>(200.0),
+            Length::new::<foot>(200.0),
+            TowerMoment::new(1_000_000.0),
+        );
+        crane.deductions.hook_block = Mass::new::<pound>(1500.0);
+        
+        // Trolley at 50 ft: 20,000 lbs gross, 18,500 lbs net
+        assert_relative_eq!(crane.gross_capacity().get::<pound>(), 20000.0);
+        assert_relative_eq!(crane.net_capacity().get::<pound>(), 18500.0);
+        
+        assert!(RatedCapacity::validate_lift(&crane, Mass::new::<pound>(15000.0)).is_ok());
+        assert!(RatedCapacity::validate_lift(&crane, Mass::new::<pound>(19000.0)).is_err());
+    }
+    
     #[test]
     fn test_moment_limiter() {
         let crane = TowerCrane::new(
@@ -714,4 +841,59 @@ mod tests {
         let result = test_crane.validate_lift(Mass::new::<pound>(12000.0));
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_analysis_report() {
+        let mut crane = TowerCrane::new(
+            "Liebherr",
+            "280 EC-H 12",
+            TowerCraneType::FlatTop,
+            Length::new::<foot>(200.0),
+            Length::new::<foot>(200.0),
+            TowerMoment::new(1_000_000.0),
+        );
+        crane.trolley_position = Length::new::<foot>(100.0);
+
+        // 900,000 ft-lb trips the limiter warning
+        let analysis = crane.validate_lift(Mass::new::<pound>(9000.0)).unwrap();
+        assert_eq!(analysis.status(), CheckStatus::Warning);
+
+        let record = analysis.record();
+        assert_eq!(record.title, "Tower Crane");
+        assert_relative_eq!(record.result("utilization").unwrap(), 0.9, epsilon = 1e-9);
+        assert_relative_eq!(record.checks[1].margin.unwrap(), 10.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_joint_config_round_trip() {
+        use crate::kinematics::{InverseKinematics, JointLimits};
+
+        let mut crane = TowerCrane::new(
+            "Liebherr",
+            "280 EC-H 12",
+            TowerCraneType::FlatTop,
+            Length::new::<foot>(200.0),
+            Length::new::<foot>(200.0),
+            TowerMoment::new(1_000_000.0),
+        );
+        crane.trolley_position = Length::new::<foot>(80.0);
+        crane.hook_height = Length::new::<foot>(50.0);
+        crane.slew_angle = Angle::new::<degree>(30.0);
+
+        // FK through the joint config lands where the crane puts the hook
+        let joints = crane.joint_config();
+        let hook = crane.forward_kinematics().hook_position(&joints);
+        let expected = crane.hook_position();
+        assert_relative_eq!(hook, expected, epsilon = 1e-9);
+
+        // IK back to the same trolley and hoist, then applied to a fresh crane
+        let ik = InverseKinematics::new(crane.forward_kinematics().base, JointLimits::default());
+        let solution = ik.solve_trolley(hook, crane.jib.angle, crane.jib.length).unwrap();
+        let mut moved = crane.clone();
+        moved.trolley_position = Length::new::<foot>(0.0);
+        moved.hook_height = Length::new::<foot>(0.0);
+        moved.set_joint_config(solution.joints);
+        assert_relative_eq!(moved.trolley_position.get::<foot>(), 80.0, epsilon = 1e-9);
+        assert_relative_eq!(moved.hook_height.get::<foot>(), 50.0, epsilon = 1e-9);
+    }
 }
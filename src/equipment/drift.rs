@@ -0,0 +1,366 @@
+//! Drift picks: passing a load horizontally from one crane to another
+//!
+//! Both booms stay put while one crane pays out and the other hauls in, so
+//! the load travels on a line between the two tips. Each hoist line leans
+//! off plumb toward its tip, and the load hangs where the two line
+//! tensions and its weight balance. Each crane's share goes from all of the
+//! load to none (or the other way) and is rated at the radius to the load,
+//! not to its own tip, since the leaning line pulls the boom that way.
+
+use nalgebra as na;
+use crate::compliance::SafetyFactors;
+use crate::equipment::crane::Crane;
+use crate::equipment::tandem::TandemLiftError;
+use crate::report::*;
+use crate::types::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A crane in a drift pick and where its slew center sits on site (feet)
+#[derive(Debug)]
+pub struct DriftCrane<C: Crane> {
+    pub crane: C,
+    pub base: na::Point3<f64>,
+}
+
+impl<C: Crane> DriftCrane<C> {
+    /// Boom tip on site, in feet
+    pub fn tip(&self) -> na::Point3<f64> {
+        let tip = self.crane.tip_position();
+        self.base + na::Vector3::new(tip.x.get::<foot>(), tip.y.get::<foot>(), tip.z.get::<foot>())
+    }
+}
+
+/// Transfer from under the first crane's tip to under the second's
+#[derive(Debug)]
+pub struct DriftPick<C: Crane> {
+    pub cranes: [DriftCrane<C>; 2],
+
+    pub load: Mass,
+
+    /// Height of the rigging attachment point above ground through the transfer
+    pub load_height: Length,
+
+    /// Positions checked between the two tips, both ends included
+    pub steps: usize,
+
+    /// Fraction of chart each crane may use (ASME tandem practice: 0.75)
+    pub capacity_factor: f64,
+}
+
+/// One crane at one position of the transfer
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DriftCraneLoad {
+    /// Vertical share of the load
+    pub vertical_load: Mass,
+
+    /// Hoist line tension
+    pub line_tension: Force,
+
+    /// Hoist line angle from plumb
+    pub line_angle: Angle,
+
+    /// Horizontal distance from the slew center to the load
+    pub radius: Length,
+
+    /// Chart capacity at `radius` times the capacity factor; `None` when
+    /// `radius` is off the chart
+    pub allowed: Option<Mass>,
+
+    /// Infinite when the crane carries load off the chart
+    pub utilization: f64,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DriftStep {
+    /// Distance travelled as a fraction of the tip-to-tip span
+    pub travel: f64,
+
+    /// Load attachment point on site, in feet
+    pub load_point: [f64; 3],
+
+    pub cranes: [DriftCraneLoad; 2],
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DriftPickAnalysis {
+    pub load: Mass,
+    pub steps: Vec<DriftStep>,
+}
+
+impl<C: Crane> DriftPick<C> {
+    pub fn new(from: DriftCrane<C>, to: DriftCrane<C>, load: Mass, load_height: Length) -> Self {
+        Self {
+            cranes: [from, to],
+            load,
+            load_height,
+            steps: 11,
+            capacity_factor: SafetyFactors::osha_asme().tandem_capacity_factor,
+        }
+    }
+
+    /// Use the tandem capacity factor of `policy`
+    pub fn with_policy(mut self, policy: &SafetyFactors) -> Self {
+        self.capacity_factor = policy.tandem_capacity_factor;
+        self
+    }
+
+    /// Line tensions and crane shares at every step of the transfer
+    pub fn analyze(&self) -> Result<DriftPickAnalysis, TandemLiftError> {
+        let tips = [self.cranes[0].tip(), self.cranes[1].tip()];
+        let height = self.load_height.get::<foot>();
+        if tips.iter().any(|tip| tip.y <= height) {
+            return Err(TandemLiftError::InvalidGeometry("Load height is above a boom tip".into()));
+        }
+
+        let span = na::Vector2::new(tips[1].x - tips[0].x, tips[1].z - tips[0].z);
+        let span_length = span.norm();
+        if span_length < 0.01 {
+            return Err(TandemLiftError::InvalidGeometry("Boom tips are too close together".into()));
+        }
+        let weight = weight_of(self.load).get::<pound_force>();
+
+        let count = self.steps.max(2);
+        let steps = (0..count)
+            .map(|i| {
+                let travel = i as f64 / (count - 1) as f64;
+                let s = travel * span_length;
+                let point = na::Point3::new(
+                    tips[0].x + span.x * travel,
+                    height,
+                    tips[0].z + span.y * travel,
+                );
+
+                // Lines in the vertical plane through both tips: (along, up)
+                let lines = [
+                    na::Vector2::new(-s, tips[0].y - height),
+                    na::Vector2::new(span_length - s, tips[1].y - height),
+                ];
+                let units = lines.map(|line| line / line.norm());
+
+                // T0·u0 + T1·u1 = (0, W)
+                let det = units[0].x * units[1].y - units[1].x * units[0].y;
+                let tensions = [
+                    -weight * units[1].x / det,
+                    weight * units[0].x / det,
+                ];
+
+                let cranes = [0, 1].map(|c| {
+                    let tension = tensions[c].max(0.0);
+                    let vertical = mass_from_weight(Force::new::<pound_force>(tension * units[c].y));
+                    let base = self.cranes[c].base;
+                    let radius = Length::new::<foot>(
+                        ((point.x - base.x).powi(2) + (point.z - base.z).powi(2)).sqrt(),
+                    );
+                    let allowed = self.cranes[c]
+                        .crane
+                        .gross_capacity_at_radius(radius)
+                        .ok()
+                        .map(|capacity| capacity * self.capacity_factor);
+                    let utilization = if let Some(allowed) = allowed
+                        && allowed.get::<pound>() > 0.0
+                    {
+                        (vertical / allowed).value
+                    } else if vertical.get::<pound>() > 0.0 {
+                        f64::INFINITY
+                    } else {
+                        0.0
+                    };
+                    DriftCraneLoad {
+                        vertical_load: vertical,
+                        line_tension: Force::new::<pound_force>(tension),
+                        line_angle: Angle::new::<radian>(units[c].x.abs().atan2(units[c].y)),
+                        radius,
+                        allowed,
+                        utilization,
+                    }
+                });
+
+                DriftStep { travel, load_point: [point.x, point.y, point.z], cranes }
+            })
+            .collect();
+
+        Ok(DriftPickAnalysis { load: self.load, steps })
+    }
+
+    /// [`analyze`](Self::analyze), failing at the first position where a
+    /// crane's share exceeds its allowed capacity or a loaded crane is off
+    /// its chart
+    pub fn validate(&self) -> Result<DriftPickAnalysis, TandemLiftError> {
+        let analysis = self.analyze()?;
+        for step in &analysis.steps {
+            for (crane_index, crane) in step.cranes.iter().enumerate() {
+                if crane.utilization <= 1.0 {
+                    continue;
+                }
+                return Err(match crane.allowed {
+                    Some(allowed) => TandemLiftError::CraneOverCapacity {
+                        crane_index,
+                        load: DisplayMass(crane.vertical_load),
+                        allowed: DisplayMass(allowed),
+                    },
+                    None => TandemLiftError::OffChart { crane_index, radius: DisplayLength(crane.radius) },
+                });
+            }
+        }
+        Ok(analysis)
+    }
+}
+
+impl DriftPickAnalysis {
+    /// Step with the highest utilization for a crane
+    pub fn worst_step(&self, crane: usize) -> Option<&DriftStep> {
+        self.steps
+            .iter()
+            .max_by(|a, b| a.cranes[crane].utilization.total_cmp(&b.cranes[crane].utilization))
+    }
+}
+
+impl AnalysisReport for DriftPickAnalysis {
+    fn title(&self) -> String {
+        "Drift Pick".into()
+    }
+
+    /// One check per crane at its worst position
+    fn checks(&self) -> Vec<ValidationCheck> {
+        (0..2)
+            .filter_map(|i| {
+                let step = self.worst_step(i)?;
+                let crane = &step.cranes[i];
+                Some(ValidationCheck {
+                    name: format!("Crane {}", i + 1),
+                    status: if crane.utilization > 1.0 { CheckStatus::Fail } else { CheckStatus::Pass },
+                    details: match crane.allowed {
+                        Some(allowed) => format!(
+                            "{:.0} lbs of {:.0} lbs allowed at {:.1} ft radius, {:.0}% through the transfer",
+                            crane.vertical_load.get::<pound>(),
+                            allowed.get::<pound>(),
+                            crane.radius.get::<foot>(),
+                            step.travel * 100.0
+                        ),
+                        None => format!(
+                            "{:.0} lbs off the chart at {:.1} ft radius, {:.0}% through the transfer",
+                            crane.vertical_load.get::<pound>(),
+                            crane.radius.get::<foot>(),
+                            step.travel * 100.0
+                        ),
+                    },
+                    margin: Some((1.0 - crane.utilization) * 100.0),
+                })
+            })
+            .collect()
+    }
+
+    fn results(&self) -> Vec<NumericResult> {
+        let mut results = vec![NumericResult::new("load", self.load.get::<pound>(), "lbs")];
+        for i in 0..2 {
+            if let Some(step) = self.worst_step(i) {
+                results.push(NumericResult::new(
+                    format!("crane_{}_max_utilization", i + 1),
+                    step.cranes[i].utilization,
+                    "",
+                ));
+            }
+        }
+        results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::equipment::crane::{Kinematic, MobileCrane};
+    use crate::test_fixtures::{mobile_crane, BOOM_100_FT};
+    use approx::assert_relative_eq;
+
+    // 100 ft boom at 60°: 50 ft radius, charted out to 90 ft
+    fn crane(base_z: f64, swing: f64) -> DriftCrane<MobileCrane> {
+        let mut crane = mobile_crane();
+        crane.swing_angle = Angle::new::<degree>(swing);
+        DriftCrane { crane, base: na::Point3::new(0.0, 0.0, base_z) }
+    }
+
+    #[test]
+    fn test_drift_transfers_load_between_cranes() {
+        // Two cranes 200 ft apart facing each other: tips 100 ft apart
+        let pick = DriftPick::new(
+            crane(0.0, 0.0),
+            crane(200.0, 180.0),
+            Mass::new::<pound>(10_000.0),
+            Length::new::<foot>(40.0),
+        );
+        let analysis = pick.analyze().unwrap();
+        assert_eq!(analysis.steps.len(), 11);
+
+        let first = &analysis.steps[0];
+        assert_relative_eq!(first.cranes[0].vertical_load.get::<pound>(), 10_000.0, max_relative = 1e-6);
+        assert_relative_eq!(first.cranes[1].vertical_load.get::<pound>(), 0.0, epsilon = 1e-6);
+        assert_relative_eq!(first.cranes[0].radius.get::<foot>(), 50.0, epsilon = 1e-6);
+        assert_relative_eq!(first.cranes[0].line_angle.get::<degree>(), 0.0, epsilon = 1e-9);
+        assert_relative_eq!(first.cranes[0].allowed.unwrap().get::<pound>(), 7_500.0, epsilon = 1e-6);
+
+        // Halfway with equal tips, each carries half and the lines lean equally
+        let middle = &analysis.steps[5];
+        assert_relative_eq!(middle.cranes[0].vertical_load.get::<pound>(), 5_000.0, max_relative = 1e-6);
+        assert_relative_eq!(middle.cranes[1].vertical_load.get::<pound>(), 5_000.0, max_relative = 1e-6);
+        assert_relative_eq!(middle.cranes[0].radius.get::<foot>(), 100.0, epsilon = 1e-6);
+        let tip_height = 10.0 + 100.0 * 60f64.to_radians().sin();
+        assert_relative_eq!(
+            middle.cranes[0].line_angle.get::<radian>(),
+            (50.0 / (tip_height - 40.0)).atan(),
+            epsilon = 1e-9
+        );
+        assert!(middle.cranes[0].line_tension.get::<pound_force>() > 5_000.0);
+
+        // 100 ft is past the last 90 ft column: no rating, and the step fails
+        assert!(middle.cranes[0].allowed.is_none());
+        assert!(middle.cranes[0].utilization.is_infinite());
+        assert!(matches!(pick.validate(), Err(TandemLiftError::CraneOverCapacity { crane_index: 0, .. })));
+
+        let last = analysis.steps.last().unwrap();
+        assert_relative_eq!(last.cranes[1].vertical_load.get::<pound>(), 10_000.0, max_relative = 1e-6);
+        assert_relative_eq!(last.cranes[0].radius.get::<foot>(), 150.0, epsilon = 1e-6);
+        assert!(pick.cranes[0].crane.configuration().radius < last.cranes[0].radius);
+    }
+
+    #[test]
+    fn test_drift_fails_off_chart() {
+        // Tips 40 ft apart: each crane works from 50 ft out to 90 ft
+        let light = DriftPick::new(
+            crane(0.0, 0.0),
+            crane(140.0, 180.0),
+            Mass::new::<pound>(4_000.0),
+            Length::new::<foot>(40.0),
+        );
+        let analysis = light.validate().unwrap();
+        let last = analysis.steps.last().unwrap();
+        let (radius, capacity) = BOOM_100_FT[7];
+        assert_relative_eq!(last.cranes[0].radius.get::<foot>(), radius, epsilon = 1e-6);
+        assert_relative_eq!(last.cranes[0].allowed.unwrap().get::<pound>(), 0.75 * capacity, epsilon = 1e-6);
+
+        // Tips 100 ft apart: the second crane takes up load while it is
+        // still past its 90 ft column
+        let wide = DriftPick::new(
+            crane(0.0, 0.0),
+            crane(200.0, 180.0),
+            Mass::new::<pound>(1_000.0),
+            Length::new::<foot>(40.0),
+        );
+        assert!(matches!(wide.validate(), Err(TandemLiftError::OffChart { crane_index: 1, .. })));
+        assert!(wide.analyze().unwrap().checks()[1].details.contains("off the chart"));
+    }
+
+    #[test]
+    fn test_drift_rejects_load_above_tip() {
+        let pick = DriftPick::new(
+            crane(0.0, 0.0),
+            crane(200.0, 180.0),
+            Mass::new::<pound>(10_000.0),
+            Length::new::<foot>(120.0),
+        );
+        assert!(matches!(pick.analyze(), Err(TandemLiftError::InvalidGeometry(_))));
+    }
+}
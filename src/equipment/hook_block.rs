@@ -0,0 +1,189 @@
+//! Hook blocks and overhaul balls
+//!
+//! The block is one piece of data used in three places: its weight is a
+//! capacity deduction, its sheave count limits the reeving, and its length
+//! eats into the height available under the hook.
+//!
+//! Reeving calculations ignore sheave friction; line pull is the
+//! manufacturer's permissible single-line pull for the hoist rope.
+
+use crate::types::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A hook block (or overhaul ball) hanging from the hoist rope
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HookBlock {
+    pub id: String,
+    pub manufacturer: String,
+
+    /// Block weight, deducted from gross capacity
+    pub weight: Mass,
+
+    /// Number of sheaves; 0 for an overhaul ball
+    pub sheaves: u32,
+
+    /// Rated capacity of the block itself
+    pub rated_capacity: Mass,
+
+    /// Height from the rope attachment to the hook saddle
+    pub length: Length,
+
+    /// Width across the side plates
+    pub width: Length,
+}
+
+impl HookBlock {
+    pub fn new(
+        id: impl Into<String>,
+        manufacturer: impl Into<String>,
+        weight: Mass,
+        sheaves: u32,
+        rated_capacity: Mass,
+        length: Length,
+        width: Length,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            manufacturer: manufacturer.into(),
+            weight,
+            sheaves,
+            rated_capacity,
+            length,
+            width,
+        }
+    }
+
+    /// Most parts of line the block can be reeved with
+    ///
+    /// Two parts per sheave, plus one if the dead end is made fast to the
+    /// block. An overhaul ball takes a single part.
+    pub fn max_parts_of_line(&self) -> u32 {
+        if self.sheaves == 0 { 1 } else { 2 * self.sheaves + 1 }
+    }
+
+    /// Fewest parts of line needed to hoist `load` plus the block itself
+    ///
+    /// `None` if the block can't be reeved with enough parts.
+    pub fn required_parts_of_line(&self, load: Mass, line_pull: Mass) -> Option<u32> {
+        let total = (load + self.weight).get::<pound>();
+        let parts = (total / line_pull.get::<pound>()).ceil().max(1.0) as u32;
+        (parts <= self.max_parts_of_line()).then_some(parts)
+    }
+
+    /// Single-line pull hoisting `load` on `parts` parts of line, block included
+    pub fn line_pull(&self, load: Mass, parts: u32) -> Force {
+        weight_of(load + self.weight) / parts.max(1) as f64
+    }
+
+    /// Load that can be hoisted with `parts` parts of line
+    ///
+    /// Rope capacity less the block weight, capped at the block rating.
+    pub fn reeved_capacity(&self, parts: u32, line_pull: Mass) -> Mass {
+        let parts = parts.min(self.max_parts_of_line());
+        let rope = line_pull * parts as f64 - self.weight;
+        let rope = if rope > Mass::new::<pound>(0.0) { rope } else { Mass::new::<pound>(0.0) };
+        if rope < self.rated_capacity { rope } else { self.rated_capacity }
+    }
+}
+
+/// Typical mobile crane hook blocks
+///
+/// Representative values only; use the manufacturer's data for lift plans.
+pub mod hook_blocks {
+    use super::*;
+
+    /// 7.5 ton overhaul ball
+    pub fn overhaul_ball_7_5t() -> HookBlock {
+        HookBlock::new(
+            "Ball-7.5t",
+            "Generic",
+            Mass::new::<pound>(700.0),
+            0,
+            Mass::new::<pound>(15_000.0),
+            Length::new::<inch>(30.0),
+            Length::new::<inch>(16.0),
+        )
+    }
+
+    /// 30 ton, 1 sheave
+    pub fn one_sheave_30t() -> HookBlock {
+        HookBlock::new(
+            "1S-30t",
+            "Generic",
+            Mass::new::<pound>(1_100.0),
+            1,
+            Mass::new::<pound>(60_000.0),
+            Length::new::<inch>(60.0),
+            Length::new::<inch>(20.0),
+        )
+    }
+
+    /// 60 ton, 3 sheaves
+    pub fn three_sheave_60t() -> HookBlock {
+        HookBlock::new(
+            "3S-60t",
+            "Generic",
+            Mass::new::<pound>(1_800.0),
+            3,
+            Mass::new::<pound>(120_000.0),
+            Length::new::<inch>(72.0),
+            Length::new::<inch>(26.0),
+        )
+    }
+
+    /// 100 ton, 5 sheaves
+    pub fn five_sheave_100t() -> HookBlock {
+        HookBlock::new(
+            "5S-100t",
+            "Generic",
+            Mass::new::<pound>(3_300.0),
+            5,
+            Mass::new::<pound>(200_000.0),
+            Length::new::<inch>(84.0),
+            Length::new::<inch>(32.0),
+        )
+    }
+
+    /// 150 ton, 7 sheaves
+    pub fn seven_sheave_150t() -> HookBlock {
+        HookBlock::new(
+            "7S-150t",
+            "Generic",
+            Mass::new::<pound>(5_000.0),
+            7,
+            Mass::new::<pound>(300_000.0),
+            Length::new::<inch>(96.0),
+            Length::new::<inch>(38.0),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_reeving() {
+        let block = hook_blocks::five_sheave_100t();
+        let line_pull = Mass::new::<pound>(17_000.0);
+
+        assert_eq!(block.max_parts_of_line(), 11);
+        // (100,000 + 3,300) / 17,000 = 6.08 -> 7 parts
+        assert_eq!(block.required_parts_of_line(Mass::new::<pound>(100_000.0), line_pull), Some(7));
+        assert_eq!(block.required_parts_of_line(Mass::new::<pound>(200_000.0), line_pull), None);
+
+        assert_relative_eq!(block.reeved_capacity(7, line_pull).get::<pound>(), 115_700.0);
+        // Capped at the block rating
+        assert_relative_eq!(block.reeved_capacity(11, Mass::new::<pound>(20_000.0)).get::<pound>(), 200_000.0);
+    }
+
+    #[test]
+    fn test_overhaul_ball_single_part() {
+        let ball = hook_blocks::overhaul_ball_7_5t();
+        assert_eq!(ball.max_parts_of_line(), 1);
+        assert_eq!(ball.required_parts_of_line(Mass::new::<pound>(10_000.0), Mass::new::<pound>(17_000.0)), Some(1));
+    }
+}
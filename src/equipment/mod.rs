@@ -1,5 +1,13 @@
 mod crane;
+mod hook_block;
 mod tandem;
+mod drift;
+mod swing_reactions;
+mod transport;
 
-pub use crane::{MobileCrane, Crane, CraneType, CraneConfig};
+pub use crane::{BoomMass, JibInstallation, JibKind, MobileCrane, MobileCraneBuilder, CraneBuildError, Crane, Kinematic, RatedCapacity, ChartRated, StabilityAnalyzed, CraneType, CraneConfig, CapacityDeductions, DutyClass, DutyFactor, InvalidDutyFactor, LatticeBoom, LatticeBoomError, LatticeBoomRules, LatticeSection, LatticeSectionKind, LiftError, check_boom_angle};
+pub use hook_block::*;
 pub use tandem::*;
+pub use drift::*;
+pub use swing_reactions::*;
+pub use transport::*;
@@ -0,0 +1,309 @@
+//! Outrigger reactions through a swing
+//!
+//! The load moves around the slew center from pick to set, and each float's
+//! reaction peaks when the load passes over or near it. Mats are sized for
+//! that peak, which is usually not at either end of the swing.
+//!
+//! A float load chart does the same for the whole chart: at each radius the
+//! rated load is swung through 360° and each float's largest reaction kept,
+//! the way manufacturers publish maximum outrigger loads.
+
+use crate::capacity::load_chart::LoadChartError;
+use crate::equipment::crane::{Kinematic, RatedCapacity, StabilityAnalyzed};
+use crate::physics::GroundBearingError;
+use crate::types::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// One support's reaction at every swing angle of the sweep
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SupportReactionCurve {
+    pub name: String,
+    pub reactions: Vec<Force>,
+    pub pressures: Vec<Pressure>,
+
+    /// Index into the sweep angles of the largest reaction
+    pub peak_index: usize,
+}
+
+impl SupportReactionCurve {
+    pub fn peak_reaction(&self) -> Force {
+        self.reactions[self.peak_index]
+    }
+
+    pub fn peak_pressure(&self) -> Pressure {
+        self.pressures[self.peak_index]
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SwingReactionHistory {
+    /// Swing angles checked, from pick to set
+    pub angles: Vec<Angle>,
+    pub supports: Vec<SupportReactionCurve>,
+}
+
+impl SwingReactionHistory {
+    /// Support carrying the largest reaction anywhere in the swing
+    pub fn critical_support(&self) -> Option<&SupportReactionCurve> {
+        self.supports
+            .iter()
+            .max_by(|a, b| a.peak_reaction().get::<pound_force>().total_cmp(&b.peak_reaction().get::<pound_force>()))
+    }
+
+    /// Swing angle where the critical support peaks
+    pub fn peak_angle(&self) -> Option<Angle> {
+        self.critical_support().map(|support| self.angles[support.peak_index])
+    }
+}
+
+/// Reactions under each float with `load` on the hook as the crane swings
+/// from `pick` to `set`, in `steps` positions with both ends included
+///
+/// The swing runs linearly between the two angles, so pass 350° to 370°
+/// rather than 350° to 10° to swing through the front. Each support stands
+/// on `mat_area`, or on its own float when `None`.
+pub fn swing_reaction_history<C: Kinematic + StabilityAnalyzed + Clone>(
+    crane: &C,
+    load: Mass,
+    mat_area: Option<Area>,
+    pick: Angle,
+    set: Angle,
+    steps: usize,
+) -> Result<SwingReactionHistory, GroundBearingError> {
+    let count = steps.max(2);
+    let (from, to) = (pick.get::<degree>(), set.get::<degree>());
+    let mut crane = crane.clone();
+
+    let mut angles = Vec::with_capacity(count);
+    let mut supports: Vec<SupportReactionCurve> = Vec::new();
+    for i in 0..count {
+        let angle = Angle::new::<degree>(from + (to - from) * i as f64 / (count - 1) as f64);
+        let mut joints = crane.joint_config();
+        joints.swing = angle;
+        crane.set_joint_config(joints);
+
+        let result = crane
+            .ground_bearing_analysis(load, mat_area)
+            .ok_or_else(|| GroundBearingError::InvalidConfiguration("Crane has no support model".into()))?
+            .calculate_reactions()?;
+
+        if supports.is_empty() {
+            supports = result
+                .reactions
+                .iter()
+                .map(|r| SupportReactionCurve {
+                    name: r.name.clone(),
+                    reactions: Vec::with_capacity(count),
+                    pressures: Vec::with_capacity(count),
+                    peak_index: 0,
+                })
+                .collect();
+        }
+        for (curve, reaction) in supports.iter_mut().zip(&result.reactions) {
+            if !curve.reactions.is_empty() && reaction.force > curve.reactions[curve.peak_index] {
+                curve.peak_index = i;
+            }
+            curve.reactions.push(reaction.force);
+            curve.pressures.push(reaction.pressure);
+        }
+        angles.push(angle);
+    }
+
+    Ok(SwingReactionHistory { angles, supports })
+}
+
+/// One float's largest reaction at each radius of a [`FloatLoadChart`]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FloatLoadCurve {
+    pub name: String,
+    pub reactions: Vec<Force>,
+    pub pressures: Vec<Pressure>,
+}
+
+impl FloatLoadCurve {
+    /// Largest reaction anywhere on the chart
+    pub fn max_reaction(&self) -> Force {
+        self.reactions
+            .iter()
+            .copied()
+            .fold(Force::new::<pound_force>(0.0), |max, r| if r > max { r } else { max })
+    }
+}
+
+/// Maximum load on each float with the rated load anywhere in the swing,
+/// radius by radius
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FloatLoadChart {
+    pub boom_length: Length,
+    pub radii: Vec<Length>,
+
+    /// Gross rated load on the hook at each radius
+    pub rated_loads: Vec<Mass>,
+    pub floats: Vec<FloatLoadCurve>,
+}
+
+impl FloatLoadChart {
+    pub fn float(&self, name: &str) -> Option<&FloatLoadCurve> {
+        self.floats.iter().find(|f| f.name == name)
+    }
+
+    /// Largest reaction on any float anywhere on the chart; the load every
+    /// float and its mat must be good for
+    pub fn max_reaction(&self) -> Force {
+        self.floats
+            .iter()
+            .map(FloatLoadCurve::max_reaction)
+            .fold(Force::new::<pound_force>(0.0), |max, r| if r > max { r } else { max })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum FloatLoadChartError {
+    #[error("Radius {0} is out of reach at the current boom length")]
+    Unreachable(DisplayLength),
+
+    #[error(transparent)]
+    Chart(#[from] LoadChartError),
+
+    #[error(transparent)]
+    Support(#[from] GroundBearingError),
+}
+
+/// Float load chart for the crane's current boom length: at each of `radii`
+/// the gross rated load is swung through 360° in `steps` positions and
+/// each float's largest reaction kept
+///
+/// Floats stand on their own pads. Errors when a radius is out of reach or
+/// off the chart, or the crane has no support model.
+pub fn float_load_chart<C: Kinematic + RatedCapacity + StabilityAnalyzed + Clone>(
+    crane: &C,
+    radii: &[Length],
+    steps: usize,
+) -> Result<FloatLoadChart, FloatLoadChartError> {
+    let mut crane = crane.clone();
+    let boom_length = crane.joint_config().boom_length;
+
+    let mut rated_loads = Vec::with_capacity(radii.len());
+    let mut floats: Vec<FloatLoadCurve> = Vec::new();
+    for &radius in radii {
+        let boom_angle = crane
+            .forward_kinematics()
+            .base
+            .boom_angle_for_radius(radius, boom_length)
+            .ok_or(FloatLoadChartError::Unreachable(DisplayLength(radius)))?;
+        let mut joints = crane.joint_config();
+        joints.boom_angle = boom_angle;
+        crane.set_joint_config(joints);
+
+        let load = crane.gross_capacity_at_radius(radius)?;
+        let history = swing_reaction_history(
+            &crane,
+            load,
+            None,
+            Angle::new::<degree>(0.0),
+            Angle::new::<degree>(360.0),
+            steps,
+        )?;
+
+        if floats.is_empty() {
+            floats = history
+                .supports
+                .iter()
+                .map(|s| FloatLoadCurve { name: s.name.clone(), reactions: Vec::new(), pressures: Vec::new() })
+                .collect();
+        }
+        for (curve, support) in floats.iter_mut().zip(&history.supports) {
+            curve.reactions.push(support.peak_reaction());
+            curve.pressures.push(support.peak_pressure());
+        }
+        rated_loads.push(load);
+    }
+
+    Ok(FloatLoadChart { boom_length, radii: radii.to_vec(), rated_loads, floats })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::mobile_crane;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_reaction_peaks_over_corner() {
+        let mut crane = mobile_crane();
+        let load = Mass::new::<pound>(5000.0);
+
+        // Swing from over the front to over the right side, passing the front-right float
+        let history = swing_reaction_history(
+            &crane,
+            load,
+            None,
+            Angle::new::<degree>(0.0),
+            Angle::new::<degree>(90.0),
+            19,
+        )
+        .unwrap();
+        assert_eq!(history.angles.len(), 19);
+        assert_eq!(history.supports.len(), 4);
+
+        let critical = history.critical_support().unwrap();
+        assert_eq!(critical.name, "Front right");
+        assert_relative_eq!(history.peak_angle().unwrap().get::<degree>(), 45.0, epsilon = 1e-9);
+        assert!(critical.peak_reaction() > critical.reactions[0]);
+        assert!(critical.peak_reaction() > *critical.reactions.last().unwrap());
+
+        // The sweep leaves the crane where it was
+        assert_relative_eq!(crane.swing_angle.get::<degree>(), 0.0);
+
+        crane.on_outriggers = false;
+        assert!(swing_reaction_history(&crane, load, None, Angle::new::<degree>(0.0), Angle::new::<degree>(90.0), 5)
+            .is_err());
+    }
+
+    #[test]
+    fn test_float_load_chart() {
+        // Enough counterweight to hold the rated load over a corner
+        let mut crane = mobile_crane();
+        crane.counterweight = Mass::new::<pound>(150_000.0);
+        let radii = [Length::new::<foot>(30.0), Length::new::<foot>(50.0), Length::new::<foot>(90.0)];
+        let chart = float_load_chart(&crane, &radii, 37).unwrap();
+
+        assert_eq!(chart.floats.len(), 4);
+        assert_relative_eq!(chart.rated_loads[1].get::<pound>(), 10_000.0, max_relative = 1e-9);
+
+        // Symmetric outriggers: every float sees the same peak over a full swing
+        let front_right = chart.float("Front right").unwrap();
+        for float in &chart.floats {
+            assert_relative_eq!(
+                float.max_reaction().get::<pound_force>(),
+                front_right.max_reaction().get::<pound_force>(),
+                max_relative = 1e-6
+            );
+        }
+        assert_relative_eq!(
+            chart.max_reaction().get::<pound_force>(),
+            front_right.max_reaction().get::<pound_force>()
+        );
+
+        // Each peak is at least what the float carries at the front
+        let mut at_front = crane.clone();
+        let mut joints = at_front.joint_config();
+        let base = at_front.forward_kinematics().base;
+        joints.boom_angle = base.boom_angle_for_radius(radii[1], joints.boom_length).unwrap();
+        at_front.set_joint_config(joints);
+        let front = at_front.outrigger_reactions(chart.rated_loads[1]).unwrap().unwrap();
+        for (float, reaction) in chart.floats.iter().zip(&front.reactions) {
+            assert!(float.reactions[1].get::<pound_force>() >= reaction.force.get::<pound_force>() - 1e-6);
+        }
+
+        assert!(matches!(
+            float_load_chart(&crane, &[Length::new::<foot>(150.0)], 5),
+            Err(FloatLoadChartError::Unreachable(_))
+        ));
+    }
+}
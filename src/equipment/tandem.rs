@@ -1,6 +1,10 @@
 use nalgebra as na;
+use crate::compliance::SafetyFactors;
 use crate::equipment::crane::{Crane, LiftError};
+use crate::report::*;
 use crate::types::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// A tandem lift configuration with multiple cranes sharing a load
 /// 
@@ -26,6 +30,10 @@ pub struct TandemLift<C: Crane> {
     
     /// Safety factor (ASME requires 0.75 for tandem, meaning 75% of chart capacity)
     pub capacity_factor: f64,
+
+    /// Equalizer beam tilt from the last load distribution, positive with
+    /// the second crane's end higher
+    pub beam_tilt: Option<Angle>,
 }
 
 #[derive(Debug)]
@@ -61,12 +69,95 @@ pub enum TandemRiggingType {
         beam_length: Length,
     },
     
-    /// Equalizer beam - automatic load sharing
+    /// Equalizer beam - load hangs from a pivot between the two crane ends
     EqualizerBeam {
         beam_weight: Mass,
+        geometry: EqualizerGeometry,
+    },
+
+    /// Rope reeved through a sheave block on the load; both parts carry
+    /// the same tension but for sheave friction
+    EqualizerSheave {
+        block_weight: Mass,
+        /// Tension ratio across the sheave is at most 1 + friction
+        sheave_friction: f64,
     },
 }
 
+impl TandemRiggingType {
+    /// Weight of the beam or sheave block the cranes lift with the load
+    pub fn rigging_weight(&self) -> Mass {
+        match *self {
+            Self::Direct => Mass::new::<pound>(0.0),
+            Self::SpreaderBeam { beam_weight, .. } | Self::EqualizerBeam { beam_weight, .. } => beam_weight,
+            Self::EqualizerSheave { block_weight, .. } => block_weight,
+        }
+    }
+}
+
+/// Equalizer beam geometry, first crane's end to second's
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EqualizerGeometry {
+    /// Load pivot to the first crane's end pin, along the beam
+    pub arm_1: Length,
+
+    /// Load pivot to the second crane's end pin, along the beam
+    pub arm_2: Length,
+
+    /// Height of the end pin line above the load pivot
+    pub pivot_drop: Length,
+
+    /// Sling (or link) length from each hook down to its end pin
+    pub sling_lengths: [Length; 2],
+}
+
+impl EqualizerGeometry {
+    /// Centered pivot on the pin line with equal slings
+    pub fn symmetric(span: Length, sling_length: Length) -> Self {
+        Self {
+            arm_1: span * 0.5,
+            arm_2: span * 0.5,
+            pivot_drop: Length::new::<foot>(0.0),
+            sling_lengths: [sling_length; 2],
+        }
+    }
+
+    /// Load shares and beam tilt for plumb hoist lines with the hooks at
+    /// `hook_heights` (feet)
+    ///
+    /// The beam tilts to take up any difference in end pin heights. With
+    /// the pivot below the pin line the higher end moves over the pivot
+    /// and picks up more of the load.
+    pub fn shares(&self, hook_heights: [f64; 2]) -> Result<([f64; 2], Angle), TandemLiftError> {
+        let (a1, a2) = (self.arm_1.get::<foot>(), self.arm_2.get::<foot>());
+        let span = a1 + a2;
+        if a1 < 0.0 || a2 < 0.0 || span < 0.01 {
+            return Err(TandemLiftError::InvalidGeometry("Equalizer beam arms must span the pivot".into()));
+        }
+
+        let rise = (hook_heights[1] - self.sling_lengths[1].get::<foot>())
+            - (hook_heights[0] - self.sling_lengths[0].get::<foot>());
+        if rise.abs() >= span {
+            return Err(TandemLiftError::InvalidGeometry(
+                "Hook height difference exceeds the equalizer beam span".into(),
+            ));
+        }
+
+        let tilt = (rise / span).asin();
+        let (sin, cos) = tilt.sin_cos();
+        let h = self.pivot_drop.get::<foot>();
+
+        // Horizontal end pin positions from the pivot after tilting
+        let x1 = -a1 * cos - h * sin;
+        let x2 = a2 * cos - h * sin;
+        if x1 > 0.0 || x2 < 0.0 {
+            return Err(TandemLiftError::InvalidGeometry("Equalizer beam tips past its pivot".into()));
+        }
+        let width = x2 - x1;
+        Ok(([x2 / width, -x1 / width], Angle::new::<radian>(tilt)))
+    }
+}
+
 impl<C: Crane> TandemLift<C> {
     pub fn new(total_load: Mass, load_cog: na::Point3<f64>) -> Self {
         Self {
@@ -77,9 +168,16 @@ impl<C: Crane> TandemLift<C> {
                 attachment_points: Vec::new(),
                 config_type: TandemRiggingType::Direct,
             },
-            capacity_factor: 0.75, // ASME B30.5 requirement
+            capacity_factor: SafetyFactors::osha_asme().tandem_capacity_factor,
+            beam_tilt: None,
         }
     }
+
+    /// Use the tandem capacity factor of `policy`
+    pub fn with_policy(mut self, policy: &SafetyFactors) -> Self {
+        self.capacity_factor = policy.tandem_capacity_factor;
+        self
+    }
     
     /// Add a crane to the tandem lift
     pub fn add_crane(&mut self, crane: C, hook_position: na::Point3<f64>) {
@@ -108,8 +206,11 @@ impl<C: Crane> TandemLift<C> {
             TandemRiggingType::SpreaderBeam { .. } => {
                 self.calculate_spreader_distribution()
             }
-            TandemRiggingType::EqualizerBeam { .. } => {
-                self.calculate_equalizer_distribution()
+            TandemRiggingType::EqualizerBeam { geometry, .. } => {
+                self.calculate_equalizer_distribution(geometry)
+            }
+            TandemRiggingType::EqualizerSheave { sheave_friction, .. } => {
+                self.calculate_sheave_distribution(sheave_friction)
             }
         }
     }
@@ -166,15 +267,42 @@ impl<C: Crane> TandemLift<C> {
     
     /// Calculate load distribution with equalizer beam
     /// 
-    /// Equalizer beams automatically balance loads (theoretically)
-    fn calculate_equalizer_distribution(&mut self) -> Result<(), TandemLiftError> {
-        // Equalizer beams should distribute evenly
-        // BUT: in practice, geometry and deflection affect this
-        let share_per_crane = 1.0 / self.cranes.len() as f64;
+    /// Shares follow the pivot position, and shift slightly when sling
+    /// lengths or hook heights differ and the beam tilts
+    fn calculate_equalizer_distribution(&mut self, geometry: EqualizerGeometry) -> Result<(), TandemLiftError> {
+        if self.cranes.len() != 2 {
+            return Err(TandemLiftError::UnsupportedConfiguration(
+                "Equalizer beam only supports 2-crane tandem".into()
+            ));
+        }
+
+        let heights = [self.cranes[0].hook_position.y, self.cranes[1].hook_position.y];
+        let (shares, tilt) = geometry.shares(heights)?;
+        self.cranes[0].load_share = shares[0];
+        self.cranes[1].load_share = shares[1];
+        self.beam_tilt = Some(tilt);
+
+        Ok(())
+    }
+
+    /// Calculate load distribution through an equalizing sheave
+    ///
+    /// Friction lets the tension on one side reach (1 + f) times the
+    /// other before the rope slips, and the rope can creep either way, so
+    /// each crane is given the larger share (1 + f) / (2 + f).
+    fn calculate_sheave_distribution(&mut self, sheave_friction: f64) -> Result<(), TandemLiftError> {
+        if self.cranes.len() != 2 {
+            return Err(TandemLiftError::UnsupportedConfiguration(
+                "Equalizer sheave only supports 2-crane tandem".into()
+            ));
+        }
+
+        let friction = sheave_friction.max(0.0);
+        let share = (1.0 + friction) / (2.0 + friction);
         for crane in &mut self.cranes {
-            crane.load_share = share_per_crane;
+            crane.load_share = share;
         }
-        
+
         Ok(())
     }
     
@@ -184,12 +312,13 @@ impl<C: Crane> TandemLift<C> {
         self.calculate_load_distribution()?;
         
         let mut crane_analyses = Vec::new();
+
+        // Each crane also carries its share of the beam or sheave block
+        let lifted = self.total_load + self.rigging.config_type.rigging_weight();
         
         // Validate each crane
         for tandem_crane in &self.cranes {
-            let crane_load = Mass::new::<pound>(
-                self.total_load.get::<pound>() * tandem_crane.load_share
-            );
+            let crane_load = lifted * tandem_crane.load_share;
             
             // Get crane's rated capacity
             let rated_capacity = tandem_crane.crane.rated_capacity();
@@ -228,14 +357,16 @@ impl<C: Crane> TandemLift<C> {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TandemLiftAnalysis {
     pub total_load: Mass,
     pub crane_analyses: Vec<CraneAnalysis>,
     pub is_valid: bool,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CraneAnalysis {
     /// Percentage of total load (0.0 to 1.0)
     pub load_share: f64,
@@ -253,6 +384,40 @@ pub struct CraneAnalysis {
     pub utilization: f64,
 }
 
+impl AnalysisReport for TandemLiftAnalysis {
+    fn title(&self) -> String {
+        "Tandem Lift".into()
+    }
+
+    /// One check per crane against its tandem-derated capacity
+    fn checks(&self) -> Vec<ValidationCheck> {
+        self.crane_analyses
+            .iter()
+            .enumerate()
+            .map(|(i, crane)| ValidationCheck {
+                name: format!("Crane {}", i + 1),
+                status: if crane.utilization > 1.0 { CheckStatus::Fail } else { CheckStatus::Pass },
+                details: format!(
+                    "{:.1}% share, {:.0} lbs of {:.0} lbs allowed",
+                    crane.load_share * 100.0,
+                    crane.crane_load.get::<pound>(),
+                    crane.allowed_capacity.get::<pound>()
+                ),
+                margin: Some((1.0 - crane.utilization) * 100.0),
+            })
+            .collect()
+    }
+
+    fn results(&self) -> Vec<NumericResult> {
+        let mut results = vec![NumericResult::new("total_load", self.total_load.get::<pound>(), "lbs")];
+        for (i, crane) in self.crane_analyses.iter().enumerate() {
+            results.push(NumericResult::new(format!("crane_{}_load", i + 1), crane.crane_load.get::<pound>(), "lbs"));
+            results.push(NumericResult::new(format!("crane_{}_utilization", i + 1), crane.utilization, ""));
+        }
+        results
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum TandemLiftError {
     #[error("Insufficient cranes for tandem lift (need at least 2)")]
@@ -271,6 +436,12 @@ pub enum TandemLiftError {
         allowed: DisplayMass,
     },
     
+    #[error("Crane {crane_index} carries load off its chart at radius {radius}")]
+    OffChart {
+        crane_index: usize,
+        radius: DisplayLength,
+    },
+
     #[error("Crane validation failed: {0}")]
     CraneValidation(#[from] LiftError),
 }
@@ -346,4 +517,77 @@ mod tests {
         assert_relative_eq!(tandem.cranes[0].load_share, 0.7, epsilon = 0.01);
         assert_relative_eq!(tandem.cranes[1].load_share, 0.3, epsilon = 0.01);
     }
+
+    #[test]
+    fn test_equalizer_beam_geometry() {
+        let mut geometry = EqualizerGeometry::symmetric(Length::new::<foot>(20.0), Length::new::<foot>(6.0));
+        let (shares, tilt) = geometry.shares([50.0, 50.0]).unwrap();
+        assert_relative_eq!(shares[0], 0.5, epsilon = 1e-12);
+        assert_relative_eq!(tilt.get::<degree>(), 0.0, epsilon = 1e-12);
+
+        // Offset pivot: 8 ft to the first end, 12 ft to the second
+        geometry.arm_1 = Length::new::<foot>(8.0);
+        geometry.arm_2 = Length::new::<foot>(12.0);
+        let (shares, _) = geometry.shares([50.0, 50.0]).unwrap();
+        assert_relative_eq!(shares[0], 0.6, epsilon = 1e-12);
+
+        // Second hook 2 ft higher with the pivot 1 ft below the pins:
+        // the beam tilts asin(2/20) and the higher crane picks up more
+        let geometry = EqualizerGeometry {
+            pivot_drop: Length::new::<foot>(1.0),
+            ..EqualizerGeometry::symmetric(Length::new::<foot>(20.0), Length::new::<foot>(6.0))
+        };
+        let (shares, tilt) = geometry.shares([50.0, 52.0]).unwrap();
+        let phi = 0.1f64.asin();
+        assert_relative_eq!(tilt.get::<radian>(), phi, epsilon = 1e-12);
+        let (x1, x2) = (-10.0 * phi.cos() - phi.sin(), 10.0 * phi.cos() - phi.sin());
+        assert_relative_eq!(shares[0], x2 / (x2 - x1), epsilon = 1e-12);
+        assert!(shares[1] > 0.5 && shares[1] < 0.51);
+
+        // A longer sling on the higher hook levels the beam again
+        let levelled = EqualizerGeometry {
+            sling_lengths: [Length::new::<foot>(6.0), Length::new::<foot>(8.0)],
+            ..geometry
+        };
+        let (shares, tilt) = levelled.shares([50.0, 52.0]).unwrap();
+        assert_relative_eq!(shares[0], 0.5, epsilon = 1e-12);
+        assert_relative_eq!(tilt.get::<degree>(), 0.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_equalizer_distribution_in_tandem() {
+        let crane = crate::test_fixtures::mobile_crane;
+        let mut tandem = TandemLift::new(Mass::new::<pound>(8000.0), na::Point3::new(50.0, 10.0, 0.0));
+        tandem.add_crane(crane(), na::Point3::new(40.0, 50.0, 0.0));
+        tandem.add_crane(crane(), na::Point3::new(60.0, 52.0, 0.0));
+        tandem.rigging.config_type = TandemRiggingType::EqualizerBeam {
+            beam_weight: Mass::new::<pound>(2000.0),
+            geometry: EqualizerGeometry {
+                pivot_drop: Length::new::<foot>(1.0),
+                ..EqualizerGeometry::symmetric(Length::new::<foot>(20.0), Length::new::<foot>(6.0))
+            },
+        };
+        tandem.calculate_load_distribution().unwrap();
+        assert!(tandem.cranes[1].load_share > tandem.cranes[0].load_share);
+        assert_relative_eq!(tandem.cranes[0].load_share + tandem.cranes[1].load_share, 1.0, epsilon = 1e-12);
+        assert!(tandem.beam_tilt.unwrap() > Angle::new::<degree>(0.0));
+
+        // The 2,000 lb beam is lifted with the load, in the same shares
+        let with_beam = tandem.validate().unwrap().crane_analyses.remove(0);
+        let share = tandem.cranes[0].load_share;
+        assert_relative_eq!(with_beam.crane_load.get::<pound>(), 10_000.0 * share, epsilon = 1e-9);
+        let TandemRiggingType::EqualizerBeam { geometry, .. } = tandem.rigging.config_type else { unreachable!() };
+        tandem.rigging.config_type =
+            TandemRiggingType::EqualizerBeam { beam_weight: Mass::new::<pound>(0.0), geometry };
+        let without_beam = tandem.validate().unwrap().crane_analyses.remove(0);
+        assert_relative_eq!(with_beam.utilization / without_beam.utilization, 1.25, epsilon = 1e-9);
+
+        tandem.rigging.config_type = TandemRiggingType::EqualizerSheave {
+            block_weight: Mass::new::<pound>(500.0),
+            sheave_friction: 0.05,
+        };
+        tandem.calculate_load_distribution().unwrap();
+        assert_relative_eq!(tandem.cranes[0].load_share, 1.05 / 2.05, epsilon = 1e-12);
+        assert_relative_eq!(tandem.cranes[1].load_share, 1.05 / 2.05, epsilon = 1e-12);
+    }
 }
@@ -0,0 +1,445 @@
+//! Carrier axle loads for roading a mobile crane
+//!
+//! Planners need axle loads for travel permits as much as the lift itself.
+//! Components (boom, counterweight, hook block, jib) may be carried on the
+//! carrier or shipped separately, which moves both the gross weight and the
+//! front/rear split.
+//!
+//! Longitudinal positions use the crate convention: Z forward(+) from the
+//! crane's slew center, in feet.
+//!
+//! Axles are arranged in two suspension groups (front and rear). Axles
+//! within a group share load equally, as with interconnected hydraulic or
+//! walking-beam suspensions.
+
+use crate::types::*;
+
+/// A single carrier axle
+#[derive(Debug, Clone)]
+pub struct Axle {
+    pub name: String,
+
+    /// Longitudinal position (Z, forward +)
+    pub position: Length,
+
+    /// Manufacturer's axle rating
+    pub rating: Mass,
+}
+
+impl Axle {
+    pub fn new(name: impl Into<String>, position: Length, rating: Mass) -> Self {
+        Self {
+            name: name.into(),
+            position,
+            rating,
+        }
+    }
+}
+
+/// Axles sharing load through an equalizing suspension
+#[derive(Debug, Clone)]
+pub struct AxleGroup {
+    pub name: String,
+    pub axles: Vec<Axle>,
+}
+
+impl AxleGroup {
+    pub fn new(name: impl Into<String>, axles: Vec<Axle>) -> Self {
+        Self {
+            name: name.into(),
+            axles,
+        }
+    }
+
+    /// Load centroid of the group (equal sharing)
+    pub fn centroid(&self) -> Length {
+        let sum: f64 = self.axles.iter().map(|a| a.position.get::<foot>()).sum();
+        Length::new::<foot>(sum / self.axles.len() as f64)
+    }
+
+    /// Distance between the extreme axles of the group
+    pub fn spread(&self) -> Length {
+        let positions = self.axles.iter().map(|a| a.position.get::<foot>());
+        let max = positions.clone().fold(f64::MIN, f64::max);
+        let min = positions.fold(f64::MAX, f64::min);
+        Length::new::<foot>((max - min).max(0.0))
+    }
+}
+
+/// A component that can be carried on the carrier or removed for transport
+#[derive(Debug, Clone)]
+pub struct TransportComponent {
+    pub name: String,
+    pub weight: Mass,
+
+    /// Longitudinal COG position when carried (Z, forward +)
+    pub position: Length,
+
+    /// Whether the component travels on the carrier
+    pub carried: bool,
+}
+
+/// Legal roading limits (jurisdiction dependent)
+#[derive(Debug, Clone, Copy)]
+pub struct RoadingLimits {
+    /// Maximum load on any single axle
+    pub max_axle_load: Mass,
+
+    /// Maximum gross vehicle weight
+    pub max_gross_weight: Mass,
+
+    /// Check axle groups against the US Federal Bridge Formula
+    pub apply_bridge_formula: bool,
+}
+
+impl RoadingLimits {
+    /// US federal interstate limits without a permit
+    pub fn us_federal() -> Self {
+        Self {
+            max_axle_load: Mass::new::<pound>(20_000.0),
+            max_gross_weight: Mass::new::<pound>(80_000.0),
+            apply_bridge_formula: true,
+        }
+    }
+
+    /// Typical European mobile crane limit (12 t per axle)
+    pub fn eu_twelve_tonne() -> Self {
+        Self {
+            max_axle_load: Mass::new::<metric_ton>(12.0),
+            max_gross_weight: Mass::new::<metric_ton>(96.0),
+            apply_bridge_formula: false,
+        }
+    }
+}
+
+/// Carrier and components for a transport analysis
+#[derive(Debug, Clone)]
+pub struct TransportConfiguration {
+    /// Front suspension group
+    pub front_group: AxleGroup,
+
+    /// Rear suspension group
+    pub rear_group: AxleGroup,
+
+    /// Carrier weight (chassis, superstructure, anything always carried)
+    pub carrier_weight: Mass,
+
+    /// Carrier COG (Z, forward +)
+    pub carrier_cog: Length,
+
+    /// Removable components
+    pub components: Vec<TransportComponent>,
+}
+
+/// Load on a single axle
+#[derive(Debug, Clone)]
+pub struct AxleLoad {
+    pub name: String,
+    pub load: Mass,
+    pub rating: Mass,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RoadingViolation {
+    #[error("Axle {axle}: load {load} exceeds axle rating {rating}")]
+    AxleOverRating {
+        axle: String,
+        load: DisplayMass,
+        rating: DisplayMass,
+    },
+
+    #[error("Axle {axle}: load {load} exceeds legal limit {limit}")]
+    AxleOverLegal {
+        axle: String,
+        load: DisplayMass,
+        limit: DisplayMass,
+    },
+
+    #[error("Gross weight {gross} exceeds legal limit {limit}")]
+    GrossOverLegal { gross: DisplayMass, limit: DisplayMass },
+
+    #[error("{group}: load {load} exceeds bridge formula limit {limit}")]
+    BridgeFormula {
+        group: String,
+        load: DisplayMass,
+        limit: DisplayMass,
+    },
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TransportError {
+    #[error("Axle group {0} has no axles")]
+    EmptyGroup(String),
+
+    #[error("Front and rear axle groups coincide")]
+    InvalidGeometry,
+
+    #[error("COG {cog} lies outside the wheelbase - {group} group would lift off")]
+    CogOutsideWheelbase { cog: DisplayLength, group: String },
+
+    #[error("Component not found: {0}")]
+    ComponentNotFound(String),
+}
+
+/// Result of a transport analysis
+#[derive(Debug)]
+pub struct TransportAnalysis {
+    pub gross_weight: Mass,
+    pub cog: Length,
+    pub front_group_load: Mass,
+    pub rear_group_load: Mass,
+    pub axle_loads: Vec<AxleLoad>,
+    pub violations: Vec<RoadingViolation>,
+}
+
+impl TransportAnalysis {
+    /// True if no roading limits are violated
+    pub fn is_roadable(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Heaviest single axle load
+    pub fn max_axle_load(&self) -> Mass {
+        self.axle_loads
+            .iter()
+            .map(|a| a.load)
+            .fold(Mass::new::<pound>(0.0), |a, b| if b > a { b } else { a })
+    }
+}
+
+impl TransportConfiguration {
+    pub fn new(
+        front_group: AxleGroup,
+        rear_group: AxleGroup,
+        carrier_weight: Mass,
+        carrier_cog: Length,
+    ) -> Self {
+        Self {
+            front_group,
+            rear_group,
+            carrier_weight,
+            carrier_cog,
+            components: Vec::new(),
+        }
+    }
+
+    /// Add a removable component (carried by default)
+    pub fn add_component(&mut self, name: impl Into<String>, weight: Mass, position: Length) {
+        self.components.push(TransportComponent {
+            name: name.into(),
+            weight,
+            position,
+            carried: true,
+        });
+    }
+
+    /// Mark a component as carried or removed
+    pub fn set_carried(&mut self, name: &str, carried: bool) -> Result<(), TransportError> {
+        let component = self
+            .components
+            .iter_mut()
+            .find(|c| c.name == name)
+            .ok_or_else(|| TransportError::ComponentNotFound(name.to_string()))?;
+        component.carried = carried;
+        Ok(())
+    }
+
+    /// Gross weight and COG of everything carried
+    pub fn gross_weight_and_cog(&self) -> (Mass, Length) {
+        let mut weight = self.carrier_weight.get::<pound>();
+        let mut moment = weight * self.carrier_cog.get::<foot>();
+
+        for c in self.components.iter().filter(|c| c.carried) {
+            let w = c.weight.get::<pound>();
+            weight += w;
+            moment += w * c.position.get::<foot>();
+        }
+
+        let cog = if weight > 0.0 { moment / weight } else { 0.0 };
+        (Mass::new::<pound>(weight), Length::new::<foot>(cog))
+    }
+
+    /// Calculate axle loads and check them against roading limits
+    pub fn analyze(&self, limits: &RoadingLimits) -> Result<TransportAnalysis, TransportError> {
+        for group in [&self.front_group, &self.rear_group] {
+            if group.axles.is_empty() {
+                return Err(TransportError::EmptyGroup(group.name.clone()));
+            }
+        }
+
+        let (gross, cog) = self.gross_weight_and_cog();
+        let z_front = self.front_group.centroid().get::<foot>();
+        let z_rear = self.rear_group.centroid().get::<foot>();
+
+        if (z_front - z_rear).abs() < 1e-6 {
+            return Err(TransportError::InvalidGeometry);
+        }
+
+        // Simply supported between the two group centroids
+        let w = gross.get::<pound>();
+        let front = w * (cog.get::<foot>() - z_rear) / (z_front - z_rear);
+        let rear = w - front;
+
+        if front < 0.0 {
+            return Err(TransportError::CogOutsideWheelbase {
+                cog: DisplayLength(cog),
+                group: self.front_group.name.clone(),
+            });
+        }
+        if rear < 0.0 {
+            return Err(TransportError::CogOutsideWheelbase {
+                cog: DisplayLength(cog),
+                group: self.rear_group.name.clone(),
+            });
+        }
+
+        let mut axle_loads = Vec::new();
+        for (group, group_load) in [(&self.front_group, front), (&self.rear_group, rear)] {
+            let per_axle = group_load / group.axles.len() as f64;
+            for axle in &group.axles {
+                axle_loads.push(AxleLoad {
+                    name: axle.name.clone(),
+                    load: Mass::new::<pound>(per_axle),
+                    rating: axle.rating,
+                });
+            }
+        }
+
+        let mut violations = Vec::new();
+        for axle in &axle_loads {
+            if axle.load > axle.rating {
+                violations.push(RoadingViolation::AxleOverRating {
+                    axle: axle.name.clone(),
+                    load: DisplayMass(axle.load),
+                    rating: DisplayMass(axle.rating),
+                });
+            }
+            if axle.load > limits.max_axle_load {
+                violations.push(RoadingViolation::AxleOverLegal {
+                    axle: axle.name.clone(),
+                    load: DisplayMass(axle.load),
+                    limit: DisplayMass(limits.max_axle_load),
+                });
+            }
+        }
+
+        if gross > limits.max_gross_weight {
+            violations.push(RoadingViolation::GrossOverLegal {
+                gross: DisplayMass(gross),
+                limit: DisplayMass(limits.max_gross_weight),
+            });
+        }
+
+        if limits.apply_bridge_formula {
+            for (group, group_load) in [(&self.front_group, front), (&self.rear_group, rear)] {
+                if group.axles.len() < 2 {
+                    continue;
+                }
+                let limit = bridge_formula_limit(group.spread(), group.axles.len() as u32);
+                if group_load > limit.get::<pound>() {
+                    violations.push(RoadingViolation::BridgeFormula {
+                        group: group.name.clone(),
+                        load: DisplayMass(Mass::new::<pound>(group_load)),
+                        limit: DisplayMass(limit),
+                    });
+                }
+            }
+        }
+
+        Ok(TransportAnalysis {
+            gross_weight: gross,
+            cog,
+            front_group_load: Mass::new::<pound>(front),
+            rear_group_load: Mass::new::<pound>(rear),
+            axle_loads,
+            violations,
+        })
+    }
+}
+
+/// US Federal Bridge Formula: W = 500 * (LN/(N-1) + 12N + 36)
+///
+/// `spread` is the distance between the extreme axles of the group,
+/// `num_axles` the number of axles in it.
+pub fn bridge_formula_limit(spread: Length, num_axles: u32) -> Mass {
+    if num_axles < 2 {
+        return Mass::new::<pound>(f64::INFINITY);
+    }
+    let l = spread.get::<foot>().round();
+    let n = num_axles as f64;
+    Mass::new::<pound>(500.0 * (l * n / (n - 1.0) + 12.0 * n + 36.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn test_carrier() -> TransportConfiguration {
+        let rating = Mass::new::<pound>(26_500.0);
+        let front = AxleGroup::new(
+            "Front",
+            vec![
+                Axle::new("Axle 1", Length::new::<foot>(12.0), rating),
+                Axle::new("Axle 2", Length::new::<foot>(7.0), rating),
+            ],
+        );
+        let rear = AxleGroup::new(
+            "Rear",
+            vec![
+                Axle::new("Axle 3", Length::new::<foot>(-8.0), rating),
+                Axle::new("Axle 4", Length::new::<foot>(-13.0), rating),
+            ],
+        );
+
+        let mut config = TransportConfiguration::new(
+            front,
+            rear,
+            Mass::new::<pound>(70_000.0),
+            Length::new::<foot>(-0.5),
+        );
+        config.add_component("Counterweight", Mass::new::<pound>(20_000.0), Length::new::<foot>(-10.5));
+        config
+    }
+
+    #[test]
+    fn test_axle_loads_sum_to_gross() {
+        let analysis = test_carrier().analyze(&RoadingLimits::us_federal()).unwrap();
+
+        let total: f64 = analysis.axle_loads.iter().map(|a| a.load.get::<pound>()).sum();
+        assert_relative_eq!(total, 90_000.0, epsilon = 0.01);
+        assert_relative_eq!(analysis.gross_weight.get::<pound>(), 90_000.0);
+    }
+
+    #[test]
+    fn test_removing_counterweight_unloads_rear() {
+        let mut config = test_carrier();
+        let limits = RoadingLimits::eu_twelve_tonne();
+
+        let carried = config.analyze(&limits).unwrap();
+        config.set_carried("Counterweight", false).unwrap();
+        let removed = config.analyze(&limits).unwrap();
+
+        assert!(removed.rear_group_load < carried.rear_group_load);
+        assert!(config.set_carried("Jib", false).is_err());
+    }
+
+    #[test]
+    fn test_roading_violations() {
+        let analysis = test_carrier().analyze(&RoadingLimits::us_federal()).unwrap();
+
+        // 90,000 lb gross is over the 80,000 lb federal limit
+        assert!(!analysis.is_roadable());
+        assert!(analysis
+            .violations
+            .iter()
+            .any(|v| matches!(v, RoadingViolation::GrossOverLegal { .. })));
+    }
+
+    #[test]
+    fn test_bridge_formula() {
+        // Tandem axle 4 ft apart: 500 * (8 + 24 + 36) = 34,000 lb
+        let limit = bridge_formula_limit(Length::new::<foot>(4.0), 2);
+        assert_relative_eq!(limit.get::<pound>(), 34_000.0);
+    }
+}
@@ -0,0 +1,430 @@
+//! C ABI for on-board displays and simulators
+//!
+//! Built with the `ffi` feature; the declarations are in
+//! `include/crane_core.h`. Cranes and chart packages are opaque handles
+//! created and freed through this API. Every function returns a
+//! [`CraneCoreStatus`]; on failure the message is available from
+//! [`crane_core_last_error`] on the same thread. A panic never unwinds
+//! into the caller: it is caught and reported as `Panic`. Values are
+//! plain doubles in feet, pounds, degrees and psi.
+//!
+//! The layout of the `#[repr(C)]` types and the function signatures are
+//! the stable surface: new functions may be added, existing ones keep
+//! their meaning.
+
+use std::cell::RefCell;
+use std::ffi::{c_char, CStr, CString};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+use crate::capacity::load_chart::LoadChartPackage;
+use crate::equipment::{Kinematic, MobileCrane, RatedCapacity, StabilityAnalyzed};
+use crate::types::*;
+
+/// Result of every FFI call
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CraneCoreStatus {
+    Ok = 0,
+    /// A required pointer argument was null
+    NullPointer = 1,
+    /// A string was not UTF-8, or a value was out of range
+    InvalidArgument = 2,
+    /// The calculation failed, e.g. radius off the chart
+    CalculationError = 3,
+    /// The library panicked; the call had no effect on its outputs
+    Panic = 4,
+}
+
+/// Opaque chart package handle
+pub struct CraneCoreChartPackage(LoadChartPackage);
+
+/// Opaque mobile crane handle
+pub struct CraneCoreCrane(MobileCrane);
+
+/// Outcome of [`crane_core_crane_check_load`]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CraneCoreLoadCheck {
+    /// Working radius at the current configuration, feet
+    pub radius_ft: f64,
+    /// Chart capacity before deductions, pounds
+    pub gross_capacity_lb: f64,
+    /// Capacity after hook block and rigging deductions, pounds
+    pub net_capacity_lb: f64,
+    /// Load as a percentage of net capacity
+    pub utilization_pct: f64,
+    /// 1 if the lift is within capacity, 0 if not
+    pub within_capacity: i32,
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn fail(status: CraneCoreStatus, message: impl ToString) -> CraneCoreStatus {
+    let message = CString::new(message.to_string().replace('\0', " ")).unwrap_or_default();
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(message));
+    status
+}
+
+/// Run a call's body, turning a panic into [`CraneCoreStatus::Panic`]
+/// rather than unwinding into the caller
+fn guard(body: impl FnOnce() -> CraneCoreStatus) -> CraneCoreStatus {
+    panic::catch_unwind(AssertUnwindSafe(body)).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|m| m.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".into());
+        fail(CraneCoreStatus::Panic, format!("panic: {}", message))
+    })
+}
+
+unsafe fn read_str<'a>(text: *const c_char) -> Result<&'a str, CraneCoreStatus> {
+    if text.is_null() {
+        return Err(fail(CraneCoreStatus::NullPointer, "null string argument"));
+    }
+    unsafe { CStr::from_ptr(text) }
+        .to_str()
+        .map_err(|e| fail(CraneCoreStatus::InvalidArgument, e))
+}
+
+macro_rules! try_ffi {
+    ($expr:expr) => {
+        match $expr {
+            Ok(value) => value,
+            Err(status) => return status,
+        }
+    };
+}
+
+macro_rules! non_null {
+    ($ptr:expr) => {
+        if $ptr.is_null() {
+            return fail(CraneCoreStatus::NullPointer, concat!("null argument: ", stringify!($ptr)));
+        }
+    };
+}
+
+/// Message for the last failed call on this thread, or null
+///
+/// The pointer is valid until the next failing call on this thread.
+#[unsafe(no_mangle)]
+pub extern "C" fn crane_core_last_error() -> *const c_char {
+    panic::catch_unwind(|| {
+        LAST_ERROR.with(|last| last.borrow().as_ref().map_or(ptr::null(), |m| m.as_ptr()))
+    })
+    .unwrap_or(ptr::null())
+}
+
+/// Parse a JSON chart package
+///
+/// # Safety
+///
+/// `json` must be a valid NUL-terminated string and `out` a valid pointer.
+/// Free the returned package with [`crane_core_package_free`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn crane_core_package_from_json(
+    json: *const c_char,
+    out: *mut *mut CraneCoreChartPackage,
+) -> CraneCoreStatus {
+    guard(|| {
+        non_null!(out);
+        let json = try_ffi!(unsafe { read_str(json) });
+        match LoadChartPackage::from_bytes(json.as_bytes()) {
+            Ok(package) => {
+                unsafe { *out = Box::into_raw(Box::new(CraneCoreChartPackage(package))) };
+                CraneCoreStatus::Ok
+            }
+            Err(e) => fail(CraneCoreStatus::InvalidArgument, e),
+        }
+    })
+}
+
+/// Free a package; null is ignored
+///
+/// # Safety
+///
+/// `package` must come from [`crane_core_package_from_json`] and not be
+/// used afterwards.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn crane_core_package_free(package: *mut CraneCoreChartPackage) {
+    let _ = panic::catch_unwind(AssertUnwindSafe(|| {
+        if !package.is_null() {
+            drop(unsafe { Box::from_raw(package) });
+        }
+    }));
+}
+
+/// Interpolated capacity of one chart in a package
+///
+/// # Safety
+///
+/// `package` must be a live handle, `chart_id` a NUL-terminated string
+/// and `out_capacity_lb` a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn crane_core_chart_capacity(
+    package: *const CraneCoreChartPackage,
+    chart_id: *const c_char,
+    boom_length_ft: f64,
+    radius_ft: f64,
+    out_capacity_lb: *mut f64,
+) -> CraneCoreStatus {
+    guard(|| {
+        non_null!(package);
+        non_null!(out_capacity_lb);
+        let chart_id = try_ffi!(unsafe { read_str(chart_id) });
+        let package = unsafe { &(*package).0 };
+
+        let Some(chart) = package.charts.iter().find(|c| c.id == chart_id) else {
+            return fail(CraneCoreStatus::InvalidArgument, format!("no chart '{}'", chart_id));
+        };
+        match chart.capacity_interpolated(Length::new::<foot>(boom_length_ft), Length::new::<foot>(radius_ft)) {
+            Ok(capacity) => {
+                unsafe { *out_capacity_lb = capacity.get::<pound>() };
+                CraneCoreStatus::Ok
+            }
+            Err(e) => fail(CraneCoreStatus::CalculationError, e),
+        }
+    })
+}
+
+/// Create a mobile crane on full outriggers
+///
+/// # Safety
+///
+/// `manufacturer` and `model` must be NUL-terminated strings and `out` a
+/// valid pointer. Free the crane with [`crane_core_crane_free`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn crane_core_crane_new(
+    manufacturer: *const c_char,
+    model: *const c_char,
+    boom_length_ft: f64,
+    boom_base_height_ft: f64,
+    out: *mut *mut CraneCoreCrane,
+) -> CraneCoreStatus {
+    guard(|| {
+        non_null!(out);
+        let manufacturer = try_ffi!(unsafe { read_str(manufacturer) });
+        let model = try_ffi!(unsafe { read_str(model) });
+        let crane = MobileCrane::new(
+            manufacturer,
+            model,
+            Length::new::<foot>(boom_length_ft),
+            Length::new::<foot>(boom_base_height_ft),
+        );
+        unsafe { *out = Box::into_raw(Box::new(CraneCoreCrane(crane))) };
+        CraneCoreStatus::Ok
+    })
+}
+
+/// Free a crane; null is ignored
+///
+/// # Safety
+///
+/// `crane` must come from [`crane_core_crane_new`] and not be used
+/// afterwards.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn crane_core_crane_free(crane: *mut CraneCoreCrane) {
+    let _ = panic::catch_unwind(AssertUnwindSafe(|| {
+        if !crane.is_null() {
+            drop(unsafe { Box::from_raw(crane) });
+        }
+    }));
+}
+
+/// Give the crane a copy of a chart package
+///
+/// # Safety
+///
+/// Both handles must be live.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn crane_core_crane_set_load_charts(
+    crane: *mut CraneCoreCrane,
+    package: *const CraneCoreChartPackage,
+) -> CraneCoreStatus {
+    guard(|| {
+        non_null!(crane);
+        non_null!(package);
+        let (crane, package) = unsafe { (&mut (*crane).0, &(*package).0) };
+        crane.set_load_charts(package.clone());
+        CraneCoreStatus::Ok
+    })
+}
+
+/// Move the crane: boom length, boom angle from horizontal and swing
+///
+/// # Safety
+///
+/// `crane` must be a live handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn crane_core_crane_set_configuration(
+    crane: *mut CraneCoreCrane,
+    boom_length_ft: f64,
+    boom_angle_deg: f64,
+    swing_deg: f64,
+) -> CraneCoreStatus {
+    guard(|| {
+        non_null!(crane);
+        if !(boom_length_ft.is_finite() && boom_angle_deg.is_finite() && swing_deg.is_finite()) {
+            return fail(CraneCoreStatus::InvalidArgument, "configuration values must be finite");
+        }
+        let crane = unsafe { &mut (*crane).0 };
+        crane.boom_length = Length::new::<foot>(boom_length_ft);
+        crane.boom_angle = Angle::new::<degree>(boom_angle_deg);
+        crane.swing_angle = Angle::new::<degree>(swing_deg);
+        CraneCoreStatus::Ok
+    })
+}
+
+/// Check a hook load against the current configuration
+///
+/// Fails with `CalculationError` when the charts can't rate the current
+/// configuration, rather than falling back to a placeholder capacity, or
+/// when the deductions leave no net capacity.
+///
+/// # Safety
+///
+/// `crane` must be a live handle and `out` a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn crane_core_crane_check_load(
+    crane: *const CraneCoreCrane,
+    load_lb: f64,
+    out: *mut CraneCoreLoadCheck,
+) -> CraneCoreStatus {
+    guard(|| {
+        non_null!(crane);
+        non_null!(out);
+        let crane = unsafe { &(*crane).0 };
+        let radius = crane.configuration().radius;
+        let gross = match crane.try_rated_capacity_at_radius(radius) {
+            Ok(gross) => gross,
+            Err(e) => return fail(CraneCoreStatus::CalculationError, e),
+        };
+        let net = gross - crane.deductions().total();
+        if net <= Mass::new::<pound>(0.0) {
+            return fail(
+                CraneCoreStatus::CalculationError,
+                format!("deductions leave no net capacity of {:.0} lbs gross", gross.get::<pound>()),
+            );
+        }
+        let load = Mass::new::<pound>(load_lb);
+
+        unsafe {
+            *out = CraneCoreLoadCheck {
+                radius_ft: radius.get::<foot>(),
+                gross_capacity_lb: gross.get::<pound>(),
+                net_capacity_lb: net.get::<pound>(),
+                utilization_pct: (load / net).value * 100.0,
+                within_capacity: crane.validate_lift(load).is_ok() as i32,
+            }
+        };
+        CraneCoreStatus::Ok
+    })
+}
+
+/// Highest outrigger pad pressure for a hook load at the current swing
+///
+/// # Safety
+///
+/// `crane` must be a live handle and `out_psi` a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn crane_core_crane_max_pad_pressure(
+    crane: *const CraneCoreCrane,
+    load_lb: f64,
+    out_psi: *mut f64,
+) -> CraneCoreStatus {
+    guard(|| {
+        non_null!(crane);
+        non_null!(out_psi);
+        let crane = unsafe { &(*crane).0 };
+        match crane.outrigger_reactions(Mass::new::<pound>(load_lb)) {
+            Some(Ok(result)) => {
+                unsafe { *out_psi = result.max_pressure.get::<psi>() };
+                CraneCoreStatus::Ok
+            }
+            Some(Err(e)) => fail(CraneCoreStatus::CalculationError, e),
+            None => fail(CraneCoreStatus::CalculationError, "crane has no support points"),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::capacity::chart_builder::LoadChartBuilder;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_c_abi_round_trip() {
+        let mut package = LoadChartPackage::default();
+        package.add_chart(
+            LoadChartBuilder::new("main")
+                .boom(100.0, "ft")
+                .point(20.0, 80000.0)
+                .point(40.0, 40000.0)
+                .build()
+                .unwrap(),
+        );
+        let mut json = Vec::new();
+        package.to_writer(&mut json).unwrap();
+        let json = CString::new(json).unwrap();
+
+        unsafe {
+            let mut handle = ptr::null_mut();
+            assert_eq!(crane_core_package_from_json(json.as_ptr(), &mut handle), CraneCoreStatus::Ok);
+
+            let mut capacity = 0.0;
+            let status = crane_core_chart_capacity(handle, c"main".as_ptr(), 100.0, 30.0, &mut capacity);
+            assert_eq!(status, CraneCoreStatus::Ok);
+            assert_relative_eq!(capacity, 60000.0, epsilon = 1e-6);
+
+            // Off the chart: the error message is kept for the caller
+            let status = crane_core_chart_capacity(handle, c"main".as_ptr(), 100.0, 60.0, &mut capacity);
+            assert_eq!(status, CraneCoreStatus::CalculationError);
+            assert!(!crane_core_last_error().is_null());
+
+            let mut crane = ptr::null_mut();
+            crane_core_crane_new(c"Test".as_ptr(), c"T1".as_ptr(), 100.0, 8.0, &mut crane);
+            crane_core_crane_set_load_charts(crane, handle);
+            // cos(72.54°) * 100 ft = 30 ft radius
+            crane_core_crane_set_configuration(crane, 100.0, 0.3f64.acos().to_degrees(), 0.0);
+
+            let mut check = CraneCoreLoadCheck::default();
+            assert_eq!(crane_core_crane_check_load(crane, 30000.0, &mut check), CraneCoreStatus::Ok);
+            assert_relative_eq!(check.radius_ft, 30.0, epsilon = 1e-9);
+            assert_relative_eq!(check.gross_capacity_lb, 60000.0, epsilon = 1e-6);
+            assert_eq!(check.within_capacity, 1);
+
+            let mut pressure = 0.0;
+            assert_eq!(crane_core_crane_max_pad_pressure(crane, 30000.0, &mut pressure), CraneCoreStatus::Ok);
+            assert!(pressure > 0.0);
+
+            assert_eq!(
+                crane_core_crane_check_load(ptr::null(), 1.0, &mut check),
+                CraneCoreStatus::NullPointer
+            );
+
+            crane_core_crane_free(crane);
+            crane_core_package_free(handle);
+        }
+    }
+
+    #[test]
+    fn test_no_net_capacity_or_panic_is_an_error() {
+        let mut crane = crate::test_fixtures::mobile_crane();
+        crane.deductions.hook_block = Mass::new::<pound>(12_000.0);
+        let crane = CraneCoreCrane(crane);
+
+        // 10,000 lbs gross at 50 ft, less a 12,000 lb block
+        let mut check = CraneCoreLoadCheck::default();
+        let status = unsafe { crane_core_crane_check_load(&crane, 1_000.0, &mut check) };
+        assert_eq!(status, CraneCoreStatus::CalculationError);
+        assert_eq!(check.utilization_pct, 0.0);
+
+        assert_eq!(guard(|| panic!("chart table corrupt")), CraneCoreStatus::Panic);
+        let message = unsafe { CStr::from_ptr(crane_core_last_error()) };
+        assert_eq!(message.to_str().unwrap(), "panic: chart table corrupt");
+    }
+}
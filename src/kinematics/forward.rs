@@ -16,6 +16,14 @@ pub struct JointConfig {
     /// Boom length (for telescoping booms)
     pub boom_length: Length,
     
+    /// Hoist rope payed out below the boom (or jib) tip to the top of the
+    /// hook block; `None` when not tracked (hook taken at the tip)
+    pub hoist: Option<Length>,
+
+    /// Trolley distance along the boom (tower crane jib) from the pivot;
+    /// when set the hoist hangs from the trolley instead of the tip
+    pub trolley: Option<Length>,
+    
     /// Jib configuration (if present)
     pub jib: Option<JibConfig>,
 }
@@ -32,14 +40,110 @@ pub struct JibConfig {
     pub jib_offset: Angle,
 }
 
+impl JointConfig {
+    /// Joint values as plain numbers in feet and radians
+    pub fn state<T: Real>(&self) -> JointState<T> {
+        JointState {
+            swing: real(self.swing.get::<radian>()),
+            boom_angle: real(self.boom_angle.get::<radian>()),
+            boom_length: real(self.boom_length.get::<foot>()),
+            hoist: self.hoist.map(|h| real(h.get::<foot>())),
+            trolley: self.trolley.map(|t| real(t.get::<foot>())),
+            jib: self.jib.map(|jib| JibState {
+                jib_angle: real(jib.jib_angle.get::<radian>()),
+                jib_length: real(jib.jib_length.get::<foot>()),
+                jib_offset: real(jib.jib_offset.get::<radian>()),
+            }),
+        }
+    }
+}
+
+/// [`JointConfig`] without units: lengths in feet, angles in radians
+///
+/// Used with [`tip_position`] and [`hook_point`] to run forward
+/// kinematics in `f32` on controllers and in visualization loops.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JointState<T = f64> {
+    pub swing: T,
+    pub boom_angle: T,
+    pub boom_length: T,
+    pub hoist: Option<T>,
+    pub trolley: Option<T>,
+    pub jib: Option<JibState<T>>,
+}
+
+/// [`JibConfig`] without units: feet and radians
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JibState<T = f64> {
+    pub jib_angle: T,
+    pub jib_length: T,
+    pub jib_offset: T,
+}
+
+/// Tip position (boom, or jib if fitted) for a boom pivot at `pivot`
+pub fn tip_position<T: Real>(pivot: na::Point3<T>, joints: &JointState<T>) -> na::Point3<T> {
+    // Start at boom pivot
+    let mut position = na::Vector3::zeros();
+
+    // Boom extends in local Z direction (forward) and Y direction (up)
+    // before the swing rotation
+    let boom_local = na::Vector3::new(
+        T::zero(),
+        joints.boom_length * joints.boom_angle.sin(),
+        joints.boom_length * joints.boom_angle.cos(),
+    );
+    let swing_rot = rotation_y_radians(joints.swing);
+    position += swing_rot * boom_local;
+
+    if let Some(jib) = joints.jib {
+        // Jib angle is relative to boom
+        let total_angle = joints.boom_angle + jib.jib_angle;
+        let jib_local = na::Vector3::new(
+            T::zero(),
+            jib.jib_length * total_angle.sin(),
+            jib.jib_length * total_angle.cos(),
+        );
+
+        // Jib offset rotates around the boom axis, then the swing applies
+        let jib_with_offset = rotation_z_radians(jib.jib_offset) * jib_local;
+        position += swing_rot * jib_with_offset;
+    }
+
+    na::Point3::from(pivot.coords + position)
+}
+
+/// Point the hoist rope hangs from: the trolley if set, otherwise the tip
+pub fn suspension_point<T: Real>(pivot: na::Point3<T>, joints: &JointState<T>) -> na::Point3<T> {
+    match joints.trolley {
+        Some(trolley) => tip_position(
+            pivot,
+            &JointState { boom_length: trolley, jib: None, ..*joints },
+        ),
+        None => tip_position(pivot, joints),
+    }
+}
+
+/// Hook block position, hanging `hoist` below the suspension point
+pub fn hook_point<T: Real>(pivot: na::Point3<T>, joints: &JointState<T>) -> na::Point3<T> {
+    let top = suspension_point(pivot, joints);
+    match joints.hoist {
+        Some(hoist) => na::Point3::new(top.x, top.y - hoist, top.z),
+        None => top,
+    }
+}
+
 /// Base position of crane (where the boom pivots)
 #[derive(Debug, Clone, Copy)]
 pub struct CraneBase {
     /// Position of boom pivot point
     pub position: na::Point3<f64>,
     
-    /// Height of boom pivot above ground
+    /// Height of boom pivot above ground (carrier deck plus boom foot)
     pub pivot_height: Length,
+
+    /// Horizontal distance from the slew axis to the boom foot, positive
+    /// toward the boom tip, negative when the foot sits behind the axis
+    pub boom_foot_offset: Length,
 }
 
 impl CraneBase {
@@ -51,10 +155,16 @@ impl CraneBase {
                 z.get::<foot>(),
             ),
             pivot_height,
+            boom_foot_offset: Length::new::<foot>(0.0),
         }
     }
+
+    pub fn with_boom_foot_offset(mut self, offset: Length) -> Self {
+        self.boom_foot_offset = offset;
+        self
+    }
     
-    /// Get the boom pivot point in world space
+    /// Point on the slew axis at boom pivot height, in world space
     pub fn pivot_point(&self) -> na::Point3<f64> {
         na::Point3::new(
             self.position.x,
@@ -62,6 +172,23 @@ impl CraneBase {
             self.position.z,
         )
     }
+
+    /// Boom foot pin in world space; moves with the swing when offset
+    pub fn boom_foot(&self, swing: Angle) -> na::Point3<f64> {
+        let offset = self.boom_foot_offset.get::<foot>();
+        let (sin, cos) = swing.get::<radian>().sin_cos();
+        self.pivot_point() + na::Vector3::new(offset * sin, 0.0, offset * cos)
+    }
+
+    /// Boom angle that puts the tip at `radius` from the slew axis
+    ///
+    /// `None` when the boom is too short to reach, or the radius is inside
+    /// the boom foot and would need the boom past vertical.
+    pub fn boom_angle_for_radius(&self, radius: Length, boom_length: Length) -> Option<Angle> {
+        let from_foot = (radius - self.boom_foot_offset).get::<foot>();
+        let boom = boom_length.get::<foot>();
+        (0.0..=boom).contains(&from_foot).then(|| Angle::new::<radian>((from_foot / boom).acos()))
+    }
 }
 
 /// Forward kinematics solver
@@ -77,74 +204,12 @@ impl ForwardKinematics {
         Self { base }
     }
     
-    /// Calculate hook position from joint configuration
+    /// Calculate tip position (boom, or jib if fitted) from joint configuration
     /// 
-    /// This is the core FK calculation - transforms from joint space to task space
+    /// This is the core FK calculation - transforms from joint space to task space.
+    /// See [`hook_position`](Self::hook_position) for the hook block.
     pub fn solve(&self, joints: &JointConfig) -> na::Point3<f64> {
-        let pivot = self.base.pivot_point();
-        
-        // Start at boom pivot
-        let mut position = na::Vector3::zeros();
-        
-        // 1. Apply boom angle (rotation around X axis in local frame, but we need to 
-        //    account for swing first)
-        let boom_len = joints.boom_length.get::<foot>();
-        let boom_angle = joints.boom_angle.get::<radian>();
-        
-        // Boom extends in local Z direction (forward) and Y direction (up)
-        // Before swing rotation
-        let boom_local = na::Vector3::new(
-            0.0,
-            boom_len * boom_angle.sin(),
-            boom_len * boom_angle.cos(),
-        );
-        
-        // 2. Apply swing rotation
-        let swing_rot = rotation_y_swing(joints.swing);
-        let boom_world = swing_rot * boom_local;
-        
-        position += boom_world;
-        
-        // 3. If there's a jib, apply jib kinematics
-        if let Some(jib) = joints.jib {
-            let jib_position = self.solve_jib(&jib, position, joints.swing, joints.boom_angle);
-            position = jib_position;
-        }
-        
-        // 4. Transform to world coordinates
-        na::Point3::from(pivot.coords + position)
-    }
-    
-    /// Solve jib kinematics (relative to boom tip)
-    fn solve_jib(
-        &self,
-        jib: &JibConfig,
-        boom_tip: na::Vector3<f64>,
-        swing: Angle,
-        boom_angle: Angle,
-    ) -> na::Vector3<f64> {
-        let jib_len = jib.jib_length.get::<foot>();
-        
-        // Jib angle is relative to boom
-        // Total angle from horizontal = boom_angle + jib_angle
-        let total_angle = boom_angle.get::<radian>() + jib.jib_angle.get::<radian>();
-        
-        // Jib position in local frame (before swing and offset)
-        let jib_local = na::Vector3::new(
-            0.0,
-            jib_len * total_angle.sin(),
-            jib_len * total_angle.cos(),
-        );
-        
-        // Apply jib offset (rotation around boom axis)
-        let offset_rot = rotation_z(jib.jib_offset);
-        let jib_with_offset = offset_rot * jib_local;
-        
-        // Apply swing rotation
-        let swing_rot = rotation_y_swing(swing);
-        let jib_world = swing_rot * jib_with_offset;
-        
-        boom_tip + jib_world
+        tip_position(self.base.boom_foot(joints.swing), &joints.state())
     }
     
     /// Calculate boom tip position (without jib)
@@ -154,6 +219,12 @@ impl ForwardKinematics {
         self.solve(&joints_no_jib)
     }
     
+    /// Calculate hook block position, hanging `hoist` below the tip (or
+    /// the trolley when set)
+    pub fn hook_position(&self, joints: &JointConfig) -> na::Point3<f64> {
+        hook_point(self.base.boom_foot(joints.swing), &joints.state())
+    }
+    
     /// Calculate the reach (horizontal distance from crane centerline)
     pub fn reach(&self, joints: &JointConfig) -> Length {
         let hook = self.solve(joints);
@@ -167,7 +238,7 @@ impl ForwardKinematics {
     
     /// Calculate hook height above ground
     pub fn hook_height(&self, joints: &JointConfig) -> Length {
-        let hook = self.solve(joints);
+        let hook = self.hook_position(joints);
         Length::new::<foot>(hook.y)
     }
 }
@@ -193,6 +264,8 @@ mod tests {
             swing: Angle::new::<degree>(0.0),
             boom_angle: Angle::new::<degree>(45.0),
             boom_length: Length::new::<foot>(100.0),
+            hoist: None,
+            trolley: None,
             jib: None,
         };
         
@@ -224,6 +297,8 @@ mod tests {
             swing: Angle::new::<degree>(90.0),
             boom_angle: Angle::new::<degree>(45.0),
             boom_length: Length::new::<foot>(100.0),
+            hoist: None,
+            trolley: None,
             jib: None,
         };
         
@@ -235,6 +310,36 @@ mod tests {
         assert_relative_eq!(hook.z, 0.0, epsilon = 0.1);
     }
     
+    #[test]
+    fn test_hook_position_with_hoist() {
+        let base = CraneBase::new(
+            Length::new::<foot>(0.0),
+            Length::new::<foot>(0.0),
+            Length::new::<foot>(0.0),
+            Length::new::<foot>(10.0),
+        );
+        
+        let fk = ForwardKinematics::new(base);
+        
+        let joints = JointConfig {
+            swing: Angle::new::<degree>(0.0),
+            boom_angle: Angle::new::<degree>(60.0),
+            boom_length: Length::new::<foot>(100.0),
+            hoist: Some(Length::new::<foot>(30.0)),
+            trolley: None,
+            jib: None,
+        };
+        
+        let tip = fk.solve(&joints);
+        let hook = fk.hook_position(&joints);
+        
+        // Hook hangs straight below the tip
+        assert_relative_eq!(hook.x, tip.x, epsilon = 1e-9);
+        assert_relative_eq!(hook.z, tip.z, epsilon = 1e-9);
+        assert_relative_eq!(tip.y - hook.y, 30.0, epsilon = 1e-9);
+        assert_relative_eq!(fk.hook_height(&joints).get::<foot>(), hook.y, epsilon = 1e-9);
+    }
+    
     #[test]
     fn test_reach_calculation() {
         let base = CraneBase::new(
@@ -250,6 +355,8 @@ mod tests {
             swing: Angle::new::<degree>(0.0),
             boom_angle: Angle::new::<degree>(30.0), // Shallow angle
             boom_length: Length::new::<foot>(100.0),
+            hoist: None,
+            trolley: None,
             jib: None,
         };
         
@@ -275,6 +382,8 @@ mod tests {
             swing: Angle::new::<degree>(0.0),
             boom_angle: Angle::new::<degree>(60.0),
             boom_length: Length::new::<foot>(80.0),
+            hoist: None,
+            trolley: None,
             jib: Some(JibConfig {
                 jib_angle: Angle::new::<degree>(-30.0),
                 jib_length: Length::new::<foot>(40.0),
@@ -291,4 +400,69 @@ mod tests {
         
         assert_relative_eq!(jib_length, 40.0, epsilon = 0.5);
     }
+    
+    #[test]
+    fn test_single_precision_matches() {
+        let joints = JointConfig {
+            swing: Angle::new::<degree>(35.0),
+            boom_angle: Angle::new::<degree>(62.0),
+            boom_length: Length::new::<foot>(154.2),
+            hoist: Some(Length::new::<foot>(20.0)),
+            trolley: None,
+            jib: Some(JibConfig {
+                jib_angle: Angle::new::<degree>(-15.0),
+                jib_length: Length::new::<foot>(40.0),
+                jib_offset: Angle::new::<degree>(5.0),
+            }),
+        };
+        let base = CraneBase::new(
+            Length::new::<foot>(0.0),
+            Length::new::<foot>(0.0),
+            Length::new::<foot>(0.0),
+            Length::new::<foot>(10.0),
+        );
+        let hook = ForwardKinematics::new(base).hook_position(&joints);
+
+        let hook_f32 = hook_point(na::Point3::new(0.0f32, 10.0, 0.0), &joints.state::<f32>());
+        assert_relative_eq!(hook_f32.x as f64, hook.x, epsilon = 1e-3);
+        assert_relative_eq!(hook_f32.y as f64, hook.y, epsilon = 1e-3);
+        assert_relative_eq!(hook_f32.z as f64, hook.z, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn test_boom_foot_offset() {
+        use crate::kinematics::{InverseKinematics, JointLimits};
+
+        // Foot 4 ft behind the slew axis
+        let base = CraneBase::new(
+            Length::new::<foot>(0.0),
+            Length::new::<foot>(0.0),
+            Length::new::<foot>(0.0),
+            Length::new::<foot>(10.0),
+        )
+        .with_boom_foot_offset(Length::new::<foot>(-4.0));
+        let fk = ForwardKinematics::new(base);
+
+        let joints = JointConfig {
+            swing: Angle::new::<degree>(90.0),
+            boom_angle: Angle::new::<degree>(60.0),
+            boom_length: Length::new::<foot>(100.0),
+            hoist: None,
+            trolley: None,
+            jib: None,
+        };
+        // 50 ft from the foot is 46 ft from the slew axis, swung to +X
+        assert_relative_eq!(fk.reach(&joints).get::<foot>(), 46.0, epsilon = 1e-9);
+        let tip = fk.solve(&joints);
+        assert_relative_eq!(tip.x, 46.0, epsilon = 1e-9);
+
+        let ik = InverseKinematics::new(base, JointLimits::default());
+        let solution = ik.solve(tip, joints.boom_length).unwrap();
+        assert_relative_eq!(solution.joints.boom_angle.get::<degree>(), 60.0, epsilon = 1e-9);
+
+        let angle = base
+            .boom_angle_for_radius(Length::new::<foot>(46.0), joints.boom_length)
+            .unwrap();
+        assert_relative_eq!(angle.get::<degree>(), 60.0, epsilon = 1e-9);
+    }
 }
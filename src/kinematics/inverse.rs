@@ -1,6 +1,8 @@
 use nalgebra as na;
+use crate::compliance::SafetyFactors;
 use crate::types::*;
 use crate::kinematics::forward::*;
+use crate::kinematics::work_area::WorkArea;
 
 /// Inverse kinematics solver
 /// 
@@ -28,6 +30,9 @@ pub struct JointLimits {
     
     /// Maximum swing angle (typically 360 degrees)
     pub swing_max: Angle,
+    
+    /// Minimum clearance between tip and hook block (anti-two-block)
+    pub min_tip_clearance: Length,
 }
 
 impl Default for JointLimits {
@@ -38,10 +43,43 @@ impl Default for JointLimits {
             boom_length_min: Length::new::<foot>(40.0),
             boom_length_max: Length::new::<foot>(200.0),
             swing_max: Angle::new::<degree>(360.0),
+            min_tip_clearance: SafetyFactors::osha_asme().two_block_clearance,
         }
     }
 }
 
+impl JointLimits {
+    /// Use the anti-two-block clearance of `policy`
+    pub fn with_policy(mut self, policy: &SafetyFactors) -> Self {
+        self.min_tip_clearance = policy.two_block_clearance;
+        self
+    }
+
+    /// Clamp a joint configuration into the limits, as the crane's limit
+    /// switches and anti-two-block would stop it
+    ///
+    /// Returns true if any joint was clamped.
+    pub fn clamp(&self, joints: &mut JointConfig) -> bool {
+        let original = *joints;
+
+        joints.boom_angle = joints.boom_angle.max(self.boom_angle_min).min(self.boom_angle_max);
+        joints.boom_length = joints.boom_length.max(self.boom_length_min).min(self.boom_length_max);
+        joints.swing = joints.swing.max(-self.swing_max).min(self.swing_max);
+        if let Some(hoist) = joints.hoist {
+            joints.hoist = Some(hoist.max(self.min_tip_clearance));
+        }
+        if let Some(trolley) = joints.trolley {
+            joints.trolley = Some(trolley.max(Length::new::<foot>(0.0)).min(joints.boom_length));
+        }
+
+        joints.boom_angle != original.boom_angle
+            || joints.boom_length != original.boom_length
+            || joints.swing != original.swing
+            || joints.hoist != original.hoist
+            || joints.trolley != original.trolley
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct IKSolution {
     pub joints: JointConfig,
@@ -62,39 +100,49 @@ pub enum IKError {
     
     #[error("No solution found")]
     NoSolution,
+    
+    #[error("Solution violates work area: {0}")]
+    WorkAreaViolation(String),
+    
+    #[error("Hook block {clearance} below tip, less than minimum {minimum}")]
+    TwoBlocking {
+        clearance: DisplayLength,
+        minimum: DisplayLength,
+    },
 }
 
 impl InverseKinematics {
     pub fn new(base: CraneBase, limits: JointLimits) -> Self {
         Self { base, limits }
     }
+
+    /// Swing to face `target`, horizontal reach from the boom foot (negative
+    /// if the target is behind it) and the boom foot itself
+    fn swing_and_reach(&self, target: na::Point3<f64>) -> (Angle, f64, na::Point3<f64>) {
+        let axis = self.base.pivot_point();
+        let dx = target.x - axis.x;
+        let dz = target.z - axis.z;
+        let swing = if dz.abs() < 1e-6 && dx.abs() < 1e-6 {
+            Angle::new::<degree>(0.0) // Default to forward if at crane position
+        } else {
+            Angle::new::<radian>(dx.atan2(dz))
+        };
+        let reach = (dx*dx + dz*dz).sqrt() - self.base.boom_foot_offset.get::<foot>();
+        (swing, reach, self.base.boom_foot(swing))
+    }
     
     /// Solve IK for a target hook position (no jib)
     /// 
     /// Returns joint configuration to reach target
     pub fn solve(&self, target: na::Point3<f64>, boom_length: Length) -> Result<IKSolution, IKError> {
-        let pivot = self.base.pivot_point();
-        
-        // 1. Calculate swing angle (trivial - just arctan2)
-        let dx = target.x - pivot.x;
-        let dz = target.z - pivot.z;
-        let swing = Angle::new::<radian>(dz.atan2(dx).abs()); // Modified to ensure forward is 0
-        
-        // Actually, let me reconsider the swing calculation
-        // If Z is forward and X is right:
+        // 1. Swing from the slew axis. Z is forward and X is right:
         // - Target directly forward (Z+): swing = 0
         // - Target to right (X+): swing = 90
         // - Target backward (Z-): swing = 180
         // - Target to left (X-): swing = 270
+        let (swing, reach, pivot) = self.swing_and_reach(target);
         
-        let swing = if dz.abs() < 1e-6 && dx.abs() < 1e-6 {
-            Angle::new::<degree>(0.0) // Default to forward if at crane position
-        } else {
-            Angle::new::<radian>(dx.atan2(dz))
-        };
-        
-        // 2. Calculate horizontal reach and vertical height
-        let reach = (dx*dx + dz*dz).sqrt();
+        // 2. Horizontal reach and vertical height from the boom foot
         let height = target.y - pivot.y;
         
         // 3. Calculate boom angle using geometry
@@ -117,6 +165,8 @@ impl InverseKinematics {
             swing,
             boom_angle,
             boom_length,
+            hoist: None,
+            trolley: None,
             jib: None,
         };
         
@@ -127,23 +177,131 @@ impl InverseKinematics {
         })
     }
     
+    /// Solve IK for a target, rejecting solutions outside the work area
+    pub fn solve_in_work_area(
+        &self,
+        target: na::Point3<f64>,
+        boom_length: Length,
+        work_area: &WorkArea,
+    ) -> Result<IKSolution, IKError> {
+        let solution = self.solve(target, boom_length)?;
+        let fk = ForwardKinematics::new(self.base);
+        
+        let violations = work_area.violations(&fk, &solution.joints);
+        if !violations.is_empty() {
+            let limits: Vec<String> = violations.iter().map(|v| v.limit.to_string()).collect();
+            return Err(IKError::WorkAreaViolation(limits.join(", ")));
+        }
+        
+        Ok(solution)
+    }
+    
+    /// Solve IK for a hook block position (no jib)
+    /// 
+    /// The tip is placed directly above the target at the target's radius;
+    /// the hoist is whatever rope reaches down to it. Rejects solutions
+    /// that would bring the block within the minimum tip clearance.
+    pub fn solve_hook(&self, target: na::Point3<f64>, boom_length: Length) -> Result<IKSolution, IKError> {
+        let (swing, reach, pivot) = self.swing_and_reach(target);
+        
+        let boom_len = boom_length.get::<foot>();
+        if reach.abs() > boom_len {
+            return Err(IKError::Unreachable);
+        }
+        
+        let boom_angle = Angle::new::<radian>((reach / boom_len).acos());
+        let tip_height = pivot.y + boom_len * boom_angle.get::<radian>().sin();
+        
+        let joints = JointConfig {
+            swing,
+            boom_angle,
+            boom_length,
+            hoist: Some(Length::new::<foot>(tip_height - target.y)),
+            trolley: None,
+            jib: None,
+        };
+        self.check_two_block(&joints)?;
+        
+        let within_limits = self.check_limits(boom_angle, boom_length, swing);
+        
+        Ok(IKSolution {
+            joints,
+            reachable: true,
+            within_limits,
+        })
+    }
+    
+    /// Solve IK for a hook block under a trolley on a fixed jib
+    ///
+    /// Swing and trolley put the trolley directly above the target; the
+    /// hoist reaches down to it.
+    pub fn solve_trolley(
+        &self,
+        target: na::Point3<f64>,
+        jib_angle: Angle,
+        jib_length: Length,
+    ) -> Result<IKSolution, IKError> {
+        let (swing, reach, pivot) = self.swing_and_reach(target);
+
+        let angle = jib_angle.get::<radian>();
+        let trolley = reach / angle.cos();
+        if !(0.0..=jib_length.get::<foot>()).contains(&trolley) {
+            return Err(IKError::Unreachable);
+        }
+        let trolley_height = pivot.y + trolley * angle.sin();
+
+        let joints = JointConfig {
+            swing,
+            boom_angle: jib_angle,
+            boom_length: jib_length,
+            hoist: Some(Length::new::<foot>(trolley_height - target.y)),
+            trolley: Some(Length::new::<foot>(trolley)),
+            jib: None,
+        };
+        self.check_two_block(&joints)?;
+
+        let within_limits = self.check_limits(jib_angle, jib_length, swing);
+
+        Ok(IKSolution {
+            joints,
+            reachable: true,
+            within_limits,
+        })
+    }
+
+    /// Check every waypoint of a joint trajectory
+    /// 
+    /// Returns the index of the first waypoint that breaks a joint limit or
+    /// the anti-two-block clearance.
+    pub fn check_trajectory(&self, path: &[JointConfig]) -> Result<(), (usize, IKError)> {
+        for (i, joints) in path.iter().enumerate() {
+            self.check_two_block(joints).map_err(|e| (i, e))?;
+            if !self.check_limits(joints.boom_angle, joints.boom_length, joints.swing) {
+                return Err((i, IKError::JointLimitViolation));
+            }
+        }
+        Ok(())
+    }
+    
+    /// Check the hook block is clear of the tip (when the hoist is tracked)
+    pub fn check_two_block(&self, joints: &JointConfig) -> Result<(), IKError> {
+        match joints.hoist {
+            Some(hoist) if hoist < self.limits.min_tip_clearance => Err(IKError::TwoBlocking {
+                clearance: DisplayLength(hoist),
+                minimum: DisplayLength(self.limits.min_tip_clearance),
+            }),
+            _ => Ok(()),
+        }
+    }
+    
     /// Solve IK with telescoping boom (variable length)
     /// 
     /// Finds the boom length and angle to reach target
     pub fn solve_telescoping(&self, target: na::Point3<f64>) -> Result<IKSolution, IKError> {
-        let pivot = self.base.pivot_point();
-        
         // Calculate swing first
-        let dx = target.x - pivot.x;
-        let dz = target.z - pivot.z;
-        let swing = if dz.abs() < 1e-6 && dx.abs() < 1e-6 {
-            Angle::new::<degree>(0.0)
-        } else {
-            Angle::new::<radian>(dx.atan2(dz))
-        };
+        let (swing, reach, pivot) = self.swing_and_reach(target);
         
         // Calculate required boom length
-        let reach = (dx*dx + dz*dz).sqrt();
         let height = target.y - pivot.y;
         let required_length = (reach*reach + height*height).sqrt();
         
@@ -163,6 +321,8 @@ impl InverseKinematics {
             swing,
             boom_angle,
             boom_length,
+            hoist: None,
+            trolley: None,
             jib: None,
         };
         
@@ -270,6 +430,78 @@ mod tests {
         );
     }
     
+    #[test]
+    fn test_ik_rejects_work_area_violation() {
+        let base = CraneBase::new(
+            Length::new::<foot>(0.0),
+            Length::new::<foot>(0.0),
+            Length::new::<foot>(0.0),
+            Length::new::<foot>(10.0),
+        );
+        
+        let ik = InverseKinematics::new(base, JointLimits::default());
+        let work_area = WorkArea::new().with_max_radius(Length::new::<foot>(60.0));
+        let boom_length = Length::new::<foot>(100.0);
+        
+        assert!(ik.solve_in_work_area(na::Point3::new(0.0, 80.0, 50.0), boom_length, &work_area).is_ok());
+        assert!(matches!(
+            ik.solve_in_work_area(na::Point3::new(0.0, 60.0, 70.0), boom_length, &work_area),
+            Err(IKError::WorkAreaViolation(_))
+        ));
+    }
+    
+    #[test]
+    fn test_solve_hook_two_block() {
+        let base = CraneBase::new(
+            Length::new::<foot>(0.0),
+            Length::new::<foot>(0.0),
+            Length::new::<foot>(0.0),
+            Length::new::<foot>(10.0),
+        );
+        
+        let fk = ForwardKinematics::new(base);
+        let ik = InverseKinematics::new(base, JointLimits::default());
+        let boom_length = Length::new::<foot>(100.0);
+        
+        // 50 ft radius puts the tip at 10 + 86.6 ft
+        let solution = ik.solve_hook(na::Point3::new(0.0, 60.0, 50.0), boom_length).unwrap();
+        let hook = fk.hook_position(&solution.joints);
+        assert_relative_eq!(hook.y, 60.0, epsilon = 1e-6);
+        assert_relative_eq!(hook.z, 50.0, epsilon = 1e-6);
+        
+        // One foot below the tip is inside the 2 ft clearance
+        assert!(matches!(
+            ik.solve_hook(na::Point3::new(0.0, 95.6, 50.0), boom_length),
+            Err(IKError::TwoBlocking { .. })
+        ));
+    }
+    
+    #[test]
+    fn test_check_trajectory() {
+        let base = CraneBase::new(
+            Length::new::<foot>(0.0),
+            Length::new::<foot>(0.0),
+            Length::new::<foot>(0.0),
+            Length::new::<foot>(10.0),
+        );
+        
+        let ik = InverseKinematics::new(base, JointLimits::default());
+        let waypoint = |hoist: f64| JointConfig {
+            swing: Angle::new::<degree>(0.0),
+            boom_angle: Angle::new::<degree>(60.0),
+            boom_length: Length::new::<foot>(100.0),
+            hoist: Some(Length::new::<foot>(hoist)),
+            trolley: None,
+            jib: None,
+        };
+        
+        assert!(ik.check_trajectory(&[waypoint(40.0), waypoint(10.0)]).is_ok());
+        
+        // Hoisting up into the tip on the third waypoint
+        let result = ik.check_trajectory(&[waypoint(40.0), waypoint(10.0), waypoint(1.0)]);
+        assert!(matches!(result, Err((2, IKError::TwoBlocking { .. }))));
+    }
+    
     #[test]
     fn test_ik_with_swing() {
         let base = CraneBase::new(
@@ -358,6 +590,8 @@ mod tests {
             swing: Angle::new::<degree>(30.0),
             boom_angle: Angle::new::<degree>(50.0),
             boom_length: Length::new::<foot>(120.0),
+            hoist: None,
+            trolley: None,
             jib: None,
         };
         
@@ -1,7 +1,11 @@
 pub mod forward;
 pub mod inverse;
 pub mod transforms;
+pub mod survey;
+pub mod work_area;
 
 pub use forward::*;
 pub use inverse::*;
 pub use transforms::*;
+pub use survey::*;
+pub use work_area::*;
@@ -0,0 +1,221 @@
+//! Survey grid coordinates
+//!
+//! Surveys give points as northing, easting and elevation on a projected
+//! grid, often in US survey feet or meters, with grid distances differing
+//! from ground distances by the combined scale factor. A [`SurveyDatum`]
+//! ties the grid to the site frame so surveyed points can be pasted in and
+//! analysed in local feet.
+//!
+//! # Pasted points
+//!
+//! [`parse_survey_points`] reads one point per line, comma, tab or space
+//! separated, in the common PNEZD (point, northing, easting, elevation,
+//! description) or PENZD order. A header line is skipped.
+
+use crate::types::*;
+use nalgebra as na;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Length unit of a survey file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SurveyUnit {
+    /// 0.3048 m
+    InternationalFoot,
+
+    /// 1200/3937 m, used by older US state plane grids
+    UsSurveyFoot,
+    Meter,
+}
+
+impl SurveyUnit {
+    /// Value in this unit in (international) feet
+    pub fn to_feet(&self, value: f64) -> f64 {
+        match self {
+            SurveyUnit::InternationalFoot => value,
+            SurveyUnit::UsSurveyFoot => value * 1200.0 / 3937.0 / 0.3048,
+            SurveyUnit::Meter => value / 0.3048,
+        }
+    }
+
+    pub fn from_feet(&self, feet: f64) -> f64 {
+        feet / self.to_feet(1.0)
+    }
+}
+
+/// Grid position in the survey's units
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GridCoordinate {
+    pub northing: f64,
+    pub easting: f64,
+    pub elevation: f64,
+}
+
+impl GridCoordinate {
+    pub fn new(northing: f64, easting: f64, elevation: f64) -> Self {
+        Self { northing, easting, elevation }
+    }
+}
+
+/// Where the site frame sits on the survey grid
+///
+/// Site +Z lies on `bearing`, clockwise from grid north, site +X is to its
+/// right and site Y is elevation above the origin.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SurveyDatum {
+    /// Grid coordinates of the site origin
+    pub origin: GridCoordinate,
+    pub bearing: Angle,
+    pub unit: SurveyUnit,
+
+    /// Combined scale factor, grid distance over ground distance; applies
+    /// to horizontal distances only
+    pub scale_factor: f64,
+}
+
+impl SurveyDatum {
+    pub fn new(origin: GridCoordinate, bearing: Angle, unit: SurveyUnit) -> Self {
+        Self { origin, bearing, unit, scale_factor: 1.0 }
+    }
+
+    pub fn with_scale_factor(mut self, scale_factor: f64) -> Self {
+        self.scale_factor = scale_factor;
+        self
+    }
+
+    /// Site coordinates (feet) of a grid point
+    pub fn to_site(&self, grid: &GridCoordinate) -> na::Point3<f64> {
+        let horizontal = |grid_delta: f64| self.unit.to_feet(grid_delta) / self.scale_factor;
+        let north = horizontal(grid.northing - self.origin.northing);
+        let east = horizontal(grid.easting - self.origin.easting);
+        let (sin, cos) = self.bearing.get::<radian>().sin_cos();
+        na::Point3::new(
+            east * cos - north * sin,
+            self.unit.to_feet(grid.elevation - self.origin.elevation),
+            east * sin + north * cos,
+        )
+    }
+
+    /// Grid coordinates of a site point (feet)
+    pub fn to_grid(&self, site: &na::Point3<f64>) -> GridCoordinate {
+        let grid = |ground: f64| self.unit.from_feet(ground * self.scale_factor);
+        let (sin, cos) = self.bearing.get::<radian>().sin_cos();
+        GridCoordinate {
+            northing: self.origin.northing + grid(site.z * cos - site.x * sin),
+            easting: self.origin.easting + grid(site.z * sin + site.x * cos),
+            elevation: self.origin.elevation + self.unit.from_feet(site.y),
+        }
+    }
+
+    /// Site heading (from site +Z toward +X) of a grid bearing
+    pub fn site_heading(&self, grid_bearing: Angle) -> Angle {
+        grid_bearing - self.bearing
+    }
+}
+
+/// A point from a survey file
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SurveyPoint {
+    pub id: String,
+    pub grid: GridCoordinate,
+    pub description: String,
+}
+
+/// Column order of a survey point file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointOrder {
+    /// Point, northing, easting, elevation, description
+    Pnezd,
+
+    /// Point, easting, northing, elevation, description
+    Penzd,
+}
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum SurveyError {
+    #[error("Line {line}: expected point, two coordinates and an elevation")]
+    MissingColumns { line: usize },
+
+    #[error("Line {line}: '{value}' is not a number")]
+    InvalidNumber { line: usize, value: String },
+}
+
+/// Read pasted survey points; blank lines and a header line are skipped
+pub fn parse_survey_points(text: &str, order: PointOrder) -> Result<Vec<SurveyPoint>, SurveyError> {
+    let mut points = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let line_no = i + 1;
+        let fields: Vec<&str> = if line.contains([',', '\t']) {
+            line.split([',', '\t']).map(str::trim).collect()
+        } else {
+            line.split_whitespace().collect()
+        };
+        if fields.iter().all(|f| f.is_empty()) {
+            continue;
+        }
+        if fields.len() < 4 {
+            return Err(SurveyError::MissingColumns { line: line_no });
+        }
+        let number = |value: &str| {
+            value.parse::<f64>().map_err(|_| SurveyError::InvalidNumber { line: line_no, value: value.to_string() })
+        };
+        let (a_coord, b_coord, elevation) = match (number(fields[1]), number(fields[2]), number(fields[3])) {
+            (Ok(a), Ok(b), Ok(c)) => (a, b, c),
+            // Column names rather than numbers: the header
+            (Err(_), Err(_), _) if points.is_empty() => continue,
+            (a, b, c) => (a?, b?, c?),
+        };
+        let (northing, easting) = match order {
+            PointOrder::Pnezd => (a_coord, b_coord),
+            PointOrder::Penzd => (b_coord, a_coord),
+        };
+        points.push(SurveyPoint {
+            id: fields[0].to_string(),
+            grid: GridCoordinate::new(northing, easting, elevation),
+            description: fields[4..].join(" ").trim().to_string(),
+        });
+    }
+    Ok(points)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_survey_points_to_site() {
+        let text = "\
+Point,Northing,Easting,Elevation,Description
+101,  2000148.250, 6001063.500, 512.30, CRANE CL
+102,2000100.000,6001100.000,510.00,SET PT
+";
+        let points = parse_survey_points(text, PointOrder::Pnezd).unwrap();
+        assert_eq!(points.len(), 2);
+        assert_eq!(points[0].description, "CRANE CL");
+        assert_relative_eq!(points[1].grid.easting, 6_001_100.0);
+
+        // Site origin on point 102, site +Z due north, US survey feet
+        let datum = SurveyDatum::new(points[1].grid, Angle::new::<degree>(0.0), SurveyUnit::UsSurveyFoot)
+            .with_scale_factor(0.9999);
+        let crane = datum.to_site(&points[0].grid);
+        let us_foot = 1200.0 / 3937.0 / 0.3048;
+        assert_relative_eq!(crane.x, -36.5 * us_foot / 0.9999, max_relative = 1e-12);
+        assert_relative_eq!(crane.y, 2.3 * us_foot, max_relative = 1e-9);
+        assert_relative_eq!(crane.z, 48.25 * us_foot / 0.9999, max_relative = 1e-12);
+
+        let back = datum.to_grid(&crane);
+        assert_relative_eq!(back.northing, points[0].grid.northing, epsilon = 1e-6);
+        assert_relative_eq!(back.easting, points[0].grid.easting, epsilon = 1e-6);
+
+        assert_relative_eq!(SurveyUnit::Meter.to_feet(0.3048), 1.0);
+        assert_eq!(
+            parse_survey_points("7 100.0 abc 5.0", PointOrder::Penzd),
+            Err(SurveyError::InvalidNumber { line: 1, value: "abc".into() })
+        );
+    }
+}
@@ -1,17 +1,14 @@
 use nalgebra as na;
 use crate::types::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use crate::kinematics::survey::{GridCoordinate, SurveyDatum};
+use std::collections::HashMap;
+use std::fmt;
 
 /// Create a rotation matrix around Y axis (vertical, for boom angle)
 pub fn rotation_y(angle: Angle) -> na::Matrix3<f64> {
-    let theta = angle.get::<radian>();
-    let c = theta.cos();
-    let s = theta.sin();
-    
-    na::Matrix3::new(
-        c,  0.0, s,
-        0.0, 1.0, 0.0,
-        -s, 0.0, c,
-    )
+    rotation_y_radians(angle.get::<radian>())
 }
 
 /// Create a rotation matrix around Y axis (vertical, for swing/slew)
@@ -21,35 +18,55 @@ pub fn rotation_y_swing(angle: Angle) -> na::Matrix3<f64> {
 
 /// Create a rotation matrix around X axis (for jib offset)
 pub fn rotation_x(angle: Angle) -> na::Matrix3<f64> {
-    let theta = angle.get::<radian>();
-    let c = theta.cos();
-    let s = theta.sin();
-    
-    na::Matrix3::new(
-        1.0, 0.0, 0.0,
-        0.0, c,  -s,
-        0.0, s,   c,
-    )
+    rotation_x_radians(angle.get::<radian>())
 }
 
 /// Create a rotation matrix around Z axis
 pub fn rotation_z(angle: Angle) -> na::Matrix3<f64> {
-    let theta = angle.get::<radian>();
-    let c = theta.cos();
-    let s = theta.sin();
-    
+    rotation_z_radians(angle.get::<radian>())
+}
+
+/// [`rotation_y`] in any scalar type, angle in radians
+pub fn rotation_y_radians<T: Real>(theta: T) -> na::Matrix3<T> {
+    let (s, c) = theta.sin_cos();
+    let (zero, one) = (T::zero(), T::one());
+
     na::Matrix3::new(
-        c,  -s, 0.0,
-        s,   c, 0.0,
-        0.0, 0.0, 1.0,
+        c,  zero, s,
+        zero, one, zero,
+        -s, zero, c,
+    )
+}
+
+/// [`rotation_x`] in any scalar type, angle in radians
+pub fn rotation_x_radians<T: Real>(theta: T) -> na::Matrix3<T> {
+    let (s, c) = theta.sin_cos();
+    let (zero, one) = (T::zero(), T::one());
+
+    na::Matrix3::new(
+        one, zero, zero,
+        zero, c,  -s,
+        zero, s,   c,
+    )
+}
+
+/// [`rotation_z`] in any scalar type, angle in radians
+pub fn rotation_z_radians<T: Real>(theta: T) -> na::Matrix3<T> {
+    let (s, c) = theta.sin_cos();
+    let (zero, one) = (T::zero(), T::one());
+
+    na::Matrix3::new(
+        c,  -s, zero,
+        s,   c, zero,
+        zero, zero, one,
     )
 }
 
 /// Create a 4x4 transformation matrix (rotation + translation)
-pub fn transform_matrix(
-    rotation: na::Matrix3<f64>,
-    translation: na::Vector3<f64>,
-) -> na::Matrix4<f64> {
+pub fn transform_matrix<T: Real>(
+    rotation: na::Matrix3<T>,
+    translation: na::Vector3<T>,
+) -> na::Matrix4<T> {
     let mut mat = na::Matrix4::identity();
     mat.fixed_view_mut::<3, 3>(0, 0).copy_from(&rotation);
     mat.fixed_view_mut::<3, 1>(0, 3).copy_from(&translation);
@@ -57,11 +74,216 @@ pub fn transform_matrix(
 }
 
 /// Apply a transformation to a point
-pub fn transform_point(
-    transform: &na::Matrix4<f64>,
-    point: na::Point3<f64>,
-) -> na::Point3<f64> {
-    let homogeneous = na::Vector4::new(point.x, point.y, point.z, 1.0);
+pub fn transform_point<T: Real>(
+    transform: &na::Matrix4<T>,
+    point: na::Point3<T>,
+) -> na::Point3<T> {
+    let homogeneous = na::Vector4::new(point.x, point.y, point.z, T::one());
     let transformed = transform * homogeneous;
     na::Point3::new(transformed.x, transformed.y, transformed.z)
 }
+
+/// Named coordinate frame
+///
+/// Cranes and loads are identified by name so several can share a site.
+/// Points in a crane's base frame are what the forward kinematics return:
+/// origin on the slew axis at ground level, +Z toward the carrier front.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Frame {
+    /// Project site coordinates; the root every other frame resolves to
+    Site,
+
+    /// Crane carrier, origin on the slew axis at ground level
+    CraneBase(String),
+
+    /// Crane upper works, turned by the slew angle about the slew axis
+    Superstructure(String),
+
+    /// Load, origin at its center of gravity
+    Load(String),
+}
+
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Frame::Site => write!(f, "site"),
+            Frame::CraneBase(crane) => write!(f, "{} base", crane),
+            Frame::Superstructure(crane) => write!(f, "{} superstructure", crane),
+            Frame::Load(load) => write!(f, "load {}", load),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum FrameError {
+    #[error("Unknown frame: {0}")]
+    UnknownFrame(Frame),
+
+    #[error("The site frame is the root and has no parent")]
+    SiteIsRoot,
+
+    #[error("Placing {0} there would make it its own ancestor")]
+    Cycle(Frame),
+
+    #[error("No survey datum set for the site")]
+    NoSurveyDatum,
+}
+
+/// Frames in a site and how each sits in its parent
+#[derive(Debug, Clone, Default)]
+pub struct FrameRegistry {
+    /// Frame to (parent, transform from the frame to its parent)
+    frames: HashMap<Frame, (Frame, na::Isometry3<f64>)>,
+    pub survey: Option<SurveyDatum>,
+}
+
+impl FrameRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Place `frame` in `parent`; replaces any earlier placement
+    pub fn set(&mut self, frame: Frame, parent: Frame, transform: na::Isometry3<f64>) -> Result<(), FrameError> {
+        if frame == Frame::Site {
+            return Err(FrameError::SiteIsRoot);
+        }
+        if !self.contains(&parent) {
+            return Err(FrameError::UnknownFrame(parent));
+        }
+        if self.ancestors(&parent).any(|ancestor| *ancestor == frame) {
+            return Err(FrameError::Cycle(frame));
+        }
+        self.frames.insert(frame, (parent, transform));
+        Ok(())
+    }
+
+    pub fn contains(&self, frame: &Frame) -> bool {
+        *frame == Frame::Site || self.frames.contains_key(frame)
+    }
+
+    /// Set up a crane with its slew axis at `position` on site, carrier
+    /// front on `heading` (site +Z toward +X), slewed to `swing`
+    pub fn add_crane(&mut self, crane: &str, position: na::Point3<f64>, heading: Angle, swing: Angle) {
+        let base = na::Isometry3::from_parts(position.coords.into(), yaw(heading));
+        self.frames.insert(Frame::CraneBase(crane.into()), (Frame::Site, base));
+        self.set_swing(crane, swing);
+    }
+
+    /// Slew a crane's superstructure to `swing`
+    pub fn set_swing(&mut self, crane: &str, swing: Angle) {
+        let superstructure = na::Isometry3::from_parts(na::Translation3::identity(), yaw(swing));
+        self.frames
+            .insert(Frame::Superstructure(crane.into()), (Frame::CraneBase(crane.into()), superstructure));
+    }
+
+    /// Transform from `frame` to site coordinates
+    pub fn to_site(&self, frame: &Frame) -> Result<na::Isometry3<f64>, FrameError> {
+        if !self.contains(frame) {
+            return Err(FrameError::UnknownFrame(frame.clone()));
+        }
+        let mut transform = na::Isometry3::identity();
+        let mut current = frame;
+        while let Some((parent, local)) = self.frames.get(current) {
+            transform = local * transform;
+            current = parent;
+        }
+        Ok(transform)
+    }
+
+    /// Transform taking coordinates in `from` to coordinates in `to`
+    pub fn transform(&self, from: &Frame, to: &Frame) -> Result<na::Isometry3<f64>, FrameError> {
+        Ok(self.to_site(to)?.inverse() * self.to_site(from)?)
+    }
+
+    pub fn convert_point(&self, point: &na::Point3<f64>, from: &Frame, to: &Frame) -> Result<na::Point3<f64>, FrameError> {
+        Ok(self.transform(from, to)? * point)
+    }
+
+    /// Directions and forces rotate but do not translate
+    pub fn convert_vector(&self, vector: &na::Vector3<f64>, from: &Frame, to: &Frame) -> Result<na::Vector3<f64>, FrameError> {
+        Ok(self.transform(from, to)? * vector)
+    }
+
+    /// Survey grid coordinates of a point in `frame`
+    pub fn to_survey(&self, point: &na::Point3<f64>, frame: &Frame) -> Result<GridCoordinate, FrameError> {
+        let datum = self.survey.as_ref().ok_or(FrameError::NoSurveyDatum)?;
+        Ok(datum.to_grid(&(self.to_site(frame)? * point)))
+    }
+
+    /// Point in `frame` from survey grid coordinates
+    pub fn from_survey(&self, grid: &GridCoordinate, frame: &Frame) -> Result<na::Point3<f64>, FrameError> {
+        let datum = self.survey.as_ref().ok_or(FrameError::NoSurveyDatum)?;
+        Ok(self.to_site(frame)?.inverse() * datum.to_site(grid))
+    }
+
+    /// Set up a crane with its slew axis at a surveyed point, carrier front
+    /// on a grid bearing
+    pub fn add_surveyed_crane(
+        &mut self,
+        crane: &str,
+        position: &GridCoordinate,
+        grid_bearing: Angle,
+        swing: Angle,
+    ) -> Result<(), FrameError> {
+        let datum = self.survey.as_ref().ok_or(FrameError::NoSurveyDatum)?;
+        let (position, heading) = (datum.to_site(position), datum.site_heading(grid_bearing));
+        self.add_crane(crane, position, heading, swing);
+        Ok(())
+    }
+
+    /// `frame`'s parent, its parent's parent, and so on up to the site
+    fn ancestors<'a>(&'a self, frame: &'a Frame) -> impl Iterator<Item = &'a Frame> + 'a {
+        std::iter::successors(Some(frame), move |f| self.frames.get(*f).map(|(parent, _)| parent))
+    }
+}
+
+/// Rotation about the vertical axis, +Z toward +X as in [`rotation_y`]
+fn yaw(angle: Angle) -> na::UnitQuaternion<f64> {
+    na::UnitQuaternion::from_axis_angle(&na::Vector3::y_axis(), angle.get::<radian>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kinematics::survey::SurveyUnit;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_frames_between_cranes_and_survey() {
+        let mut frames = FrameRegistry::new();
+        frames.add_crane("A", na::Point3::new(100.0, 0.0, 50.0), Angle::new::<degree>(90.0), Angle::new::<degree>(0.0));
+        frames.add_crane("B", na::Point3::new(160.0, 0.0, 50.0), Angle::new::<degree>(-90.0), Angle::new::<degree>(0.0));
+        let (a, b) = (Frame::CraneBase("A".into()), Frame::CraneBase("B".into()));
+
+        // A faces +X, so 30 ft ahead of it is the point midway to B, which faces back
+        let midway = frames.convert_point(&na::Point3::new(0.0, 10.0, 30.0), &a, &b).unwrap();
+        assert_relative_eq!(midway, na::Point3::new(0.0, 10.0, 30.0), epsilon = 1e-9);
+
+        // Slewing A back 90° swings its boom round to site +Z
+        frames.set_swing("A", Angle::new::<degree>(-90.0));
+        let tip = frames
+            .convert_point(&na::Point3::new(0.0, 0.0, 20.0), &Frame::Superstructure("A".into()), &Frame::Site)
+            .unwrap();
+        assert_relative_eq!(tip, na::Point3::new(100.0, 0.0, 70.0), epsilon = 1e-9);
+
+        let load = Frame::Load("Beam".into());
+        frames.set(load.clone(), b.clone(), na::Isometry3::translation(0.0, 5.0, 30.0)).unwrap();
+        assert_eq!(frames.set(b.clone(), load.clone(), na::Isometry3::identity()), Err(FrameError::Cycle(b)));
+        assert_eq!(frames.set(Frame::Site, load.clone(), na::Isometry3::identity()), Err(FrameError::SiteIsRoot));
+
+        assert_eq!(frames.to_survey(&na::Point3::origin(), &load), Err(FrameError::NoSurveyDatum));
+        frames.survey = Some(SurveyDatum::new(
+            GridCoordinate::new(2_000.0, 5_000.0, 600.0),
+            Angle::new::<degree>(90.0),
+            SurveyUnit::InternationalFoot,
+        ));
+        // Site +Z points east, so site +X points south
+        let survey = frames.to_survey(&na::Point3::origin(), &load).unwrap();
+        assert_relative_eq!(survey.northing, 1_870.0, epsilon = 1e-9);
+        assert_relative_eq!(survey.easting, 5_050.0, epsilon = 1e-9);
+        assert_relative_eq!(survey.elevation, 605.0, epsilon = 1e-9);
+        let back = frames.from_survey(&survey, &load).unwrap();
+        assert_relative_eq!(back, na::Point3::origin(), epsilon = 1e-9);
+    }
+}
@@ -0,0 +1,375 @@
+//! Work-area limitation (virtual walls)
+//!
+//! Operating envelopes set up for a job: allowed slew sectors, a maximum
+//! radius, a maximum tip height, and keep-out zones around structures or
+//! power lines. A [`WorkArea`] answers whether a joint configuration is
+//! allowed and how far each limit is from the boom, so control UIs can show
+//! approach warnings before a limit stops the crane.
+//!
+//! # Coordinates
+//!
+//! Crate convention (X right, Y up, Z forward), in **FEET**. Keep-out
+//! polygons are in plan (X, Z). Slew angles follow [`JointConfig::swing`]:
+//! 0° forward (Z+), 90° right (X+).
+//!
+//! Distances are signed: positive while inside the envelope, negative once
+//! a limit is violated. Slew distances are arc lengths travelled by the
+//! boom tip at its current radius.
+
+use crate::kinematics::forward::*;
+use crate::types::*;
+use nalgebra as na;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Allowed slew sector, from `start` sweeping clockwise (increasing swing) to `end`
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SlewWindow {
+    pub start: Angle,
+    pub end: Angle,
+}
+
+impl SlewWindow {
+    pub fn new(start: Angle, end: Angle) -> Self {
+        Self { start, end }
+    }
+
+    /// Signed angular distance to the nearest edge (radians, negative outside)
+    fn margin(&self, swing: Angle) -> f64 {
+        let tau = std::f64::consts::TAU;
+        let start = self.start.get::<radian>();
+        let span = (self.end.get::<radian>() - start).rem_euclid(tau);
+        let offset = (swing.get::<radian>() - start).rem_euclid(tau);
+
+        if offset <= span {
+            offset.min(span - offset)
+        } else {
+            -(offset - span).min(tau - offset)
+        }
+    }
+}
+
+/// Area in plan the boom may not enter, up to an optional height
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct KeepOutZone {
+    pub name: String,
+
+    /// Plan outline (X, Z feet), either winding
+    pub polygon: Vec<na::Point2<f64>>,
+
+    /// Top of the zone; `None` extends it upward without limit
+    pub top: Option<Length>,
+}
+
+impl KeepOutZone {
+    pub fn new(name: impl Into<String>, polygon: Vec<na::Point2<f64>>, top: Option<Length>) -> Self {
+        Self {
+            name: name.into(),
+            polygon,
+            top,
+        }
+    }
+
+    /// Signed distance from a point to the zone (feet, negative inside)
+    fn distance(&self, point: na::Point3<f64>) -> f64 {
+        let plan = signed_plan_distance(&self.polygon, na::Point2::new(point.x, point.z));
+        let Some(top) = self.top else {
+            return plan;
+        };
+        let above = point.y - top.get::<foot>();
+
+        if plan > 0.0 && above > 0.0 {
+            (plan * plan + above * above).sqrt()
+        } else {
+            // Gap when clear in one direction; shallower way out when inside
+            plan.max(above)
+        }
+    }
+}
+
+/// Which limit a distance refers to
+#[derive(Debug, Clone, PartialEq)]
+pub enum WorkAreaLimit {
+    Slew,
+    Radius,
+    TipHeight,
+    KeepOut(String),
+}
+
+impl fmt::Display for WorkAreaLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WorkAreaLimit::Slew => write!(f, "slew limit"),
+            WorkAreaLimit::Radius => write!(f, "radius limit"),
+            WorkAreaLimit::TipHeight => write!(f, "tip height limit"),
+            WorkAreaLimit::KeepOut(name) => write!(f, "keep-out zone '{}'", name),
+        }
+    }
+}
+
+/// Distance from the boom to one limit
+#[derive(Debug, Clone)]
+pub struct LimitDistance {
+    pub limit: WorkAreaLimit,
+
+    /// Positive inside the envelope, negative when violated
+    pub distance: Length,
+}
+
+impl LimitDistance {
+    pub fn is_violated(&self) -> bool {
+        self.distance < Length::new::<foot>(0.0)
+    }
+}
+
+/// Operating envelope for a crane setup
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WorkArea {
+    /// Allowed slew sectors; empty allows full rotation
+    pub slew_windows: Vec<SlewWindow>,
+
+    pub max_radius: Option<Length>,
+
+    pub max_tip_height: Option<Length>,
+
+    pub keep_out: Vec<KeepOutZone>,
+
+    /// Distance at which an approaching limit is reported as a warning
+    pub warning_distance: Length,
+
+    /// Points checked along the boom against keep-out zones
+    pub boom_samples: usize,
+}
+
+impl Default for WorkArea {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WorkArea {
+    /// Unrestricted work area with a 10 ft warning distance
+    pub fn new() -> Self {
+        Self {
+            slew_windows: Vec::new(),
+            max_radius: None,
+            max_tip_height: None,
+            keep_out: Vec::new(),
+            warning_distance: Length::new::<foot>(10.0),
+            boom_samples: 20,
+        }
+    }
+
+    pub fn with_slew_window(mut self, start: Angle, end: Angle) -> Self {
+        self.slew_windows.push(SlewWindow::new(start, end));
+        self
+    }
+
+    pub fn with_max_radius(mut self, radius: Length) -> Self {
+        self.max_radius = Some(radius);
+        self
+    }
+
+    pub fn with_max_tip_height(mut self, height: Length) -> Self {
+        self.max_tip_height = Some(height);
+        self
+    }
+
+    pub fn with_keep_out(mut self, zone: KeepOutZone) -> Self {
+        self.keep_out.push(zone);
+        self
+    }
+
+    pub fn with_warning_distance(mut self, distance: Length) -> Self {
+        self.warning_distance = distance;
+        self
+    }
+
+    /// Distance to every configured limit
+    pub fn limit_distances(&self, fk: &ForwardKinematics, joints: &JointConfig) -> Vec<LimitDistance> {
+        let pivot = fk.base.boom_foot(joints.swing);
+        let tip = fk.solve(joints);
+        let base = fk.base.position;
+        let radius = ((tip.x - base.x).powi(2) + (tip.z - base.z).powi(2)).sqrt();
+
+        let mut distances = Vec::new();
+        let mut push = |limit, feet: f64| {
+            distances.push(LimitDistance {
+                limit,
+                distance: Length::new::<foot>(feet),
+            })
+        };
+
+        if !self.slew_windows.is_empty() {
+            let margin = self
+                .slew_windows
+                .iter()
+                .map(|w| w.margin(joints.swing))
+                .fold(f64::NEG_INFINITY, f64::max);
+            push(WorkAreaLimit::Slew, margin * radius);
+        }
+
+        if let Some(max) = self.max_radius {
+            push(WorkAreaLimit::Radius, max.get::<foot>() - radius);
+        }
+
+        if let Some(max) = self.max_tip_height {
+            push(WorkAreaLimit::TipHeight, max.get::<foot>() - tip.y);
+        }
+
+        // Keep-out zones apply to the whole boom, not just the tip
+        let samples = self.boom_samples.max(1);
+        for zone in &self.keep_out {
+            let distance = (0..=samples)
+                .map(|i| pivot + (tip - pivot) * (i as f64 / samples as f64))
+                .map(|p| zone.distance(p))
+                .fold(f64::INFINITY, f64::min);
+            push(WorkAreaLimit::KeepOut(zone.name.clone()), distance);
+        }
+
+        distances
+    }
+
+    /// Is this joint configuration inside the work area
+    pub fn is_allowed(&self, fk: &ForwardKinematics, joints: &JointConfig) -> bool {
+        self.violations(fk, joints).is_empty()
+    }
+
+    /// Limits the configuration violates
+    pub fn violations(&self, fk: &ForwardKinematics, joints: &JointConfig) -> Vec<LimitDistance> {
+        self.limit_distances(fk, joints)
+            .into_iter()
+            .filter(|d| d.is_violated())
+            .collect()
+    }
+
+    /// Limits within the warning distance but not yet violated
+    pub fn warnings(&self, fk: &ForwardKinematics, joints: &JointConfig) -> Vec<LimitDistance> {
+        self.limit_distances(fk, joints)
+            .into_iter()
+            .filter(|d| !d.is_violated() && d.distance < self.warning_distance)
+            .collect()
+    }
+
+    /// The closest limit, if any are configured
+    pub fn nearest_limit(&self, fk: &ForwardKinematics, joints: &JointConfig) -> Option<LimitDistance> {
+        self.limit_distances(fk, joints)
+            .into_iter()
+            .min_by(|a, b| a.distance.partial_cmp(&b.distance).unwrap_or(std::cmp::Ordering::Equal))
+    }
+}
+
+/// Signed distance from a point to a polygon outline (negative inside)
+fn signed_plan_distance(polygon: &[na::Point2<f64>], point: na::Point2<f64>) -> f64 {
+    if polygon.len() < 3 {
+        return f64::INFINITY;
+    }
+
+    let mut inside = false;
+    let mut min_distance = f64::INFINITY;
+
+    for i in 0..polygon.len() {
+        let a = polygon[i];
+        let b = polygon[(i + 1) % polygon.len()];
+
+        // Ray cast along +X
+        if (a.y > point.y) != (b.y > point.y) {
+            let x = a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x);
+            if point.x < x {
+                inside = !inside;
+            }
+        }
+
+        let ab = b - a;
+        let t = ((point - a).dot(&ab) / ab.norm_squared()).clamp(0.0, 1.0);
+        min_distance = min_distance.min((point - (a + ab * t)).norm());
+    }
+
+    if inside { -min_distance } else { min_distance }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn fk() -> ForwardKinematics {
+        ForwardKinematics::new(CraneBase::new(
+            Length::new::<foot>(0.0),
+            Length::new::<foot>(0.0),
+            Length::new::<foot>(0.0),
+            Length::new::<foot>(10.0),
+        ))
+    }
+
+    fn joints(swing: f64, boom_angle: f64) -> JointConfig {
+        JointConfig {
+            swing: Angle::new::<degree>(swing),
+            boom_angle: Angle::new::<degree>(boom_angle),
+            boom_length: Length::new::<foot>(100.0),
+            hoist: None,
+            trolley: None,
+            jib: None,
+        }
+    }
+
+    #[test]
+    fn test_slew_window() {
+        // Allowed from 300° through 0° to 60°
+        let area = WorkArea::new()
+            .with_slew_window(Angle::new::<degree>(300.0), Angle::new::<degree>(60.0));
+
+        assert!(area.is_allowed(&fk(), &joints(0.0, 60.0)));
+        assert!(area.is_allowed(&fk(), &joints(-50.0, 60.0)));
+        assert!(!area.is_allowed(&fk(), &joints(90.0, 60.0)));
+
+        // 10° from the edge at 50 ft radius
+        let d = &area.limit_distances(&fk(), &joints(50.0, 60.0))[0];
+        assert_relative_eq!(d.distance.get::<foot>(), 10f64.to_radians() * 50.0, epsilon = 1e-6);
+        assert_eq!(area.warnings(&fk(), &joints(55.0, 60.0)).len(), 1);
+    }
+
+    #[test]
+    fn test_radius_and_height() {
+        let area = WorkArea::new()
+            .with_max_radius(Length::new::<foot>(60.0))
+            .with_max_tip_height(Length::new::<foot>(100.0));
+
+        // 45°: radius 70.7 ft, tip 80.7 ft
+        let violations = area.violations(&fk(), &joints(0.0, 45.0));
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].limit, WorkAreaLimit::Radius);
+
+        // 80°: radius 17.4 ft, tip 108.5 ft
+        let nearest = area.nearest_limit(&fk(), &joints(0.0, 80.0)).unwrap();
+        assert_eq!(nearest.limit, WorkAreaLimit::TipHeight);
+        assert!(nearest.is_violated());
+    }
+
+    #[test]
+    fn test_keep_out_zone() {
+        // Building 40-60 ft in front of the crane, 50 ft tall
+        let building = KeepOutZone::new(
+            "Building",
+            vec![
+                na::Point2::new(-20.0, 40.0),
+                na::Point2::new(20.0, 40.0),
+                na::Point2::new(20.0, 60.0),
+                na::Point2::new(-20.0, 60.0),
+            ],
+            Some(Length::new::<foot>(50.0)),
+        );
+        let area = WorkArea::new().with_keep_out(building);
+
+        // Low boom passes through the building
+        assert!(!area.is_allowed(&fk(), &joints(0.0, 30.0)));
+        // Steeper boom passes over the roof
+        assert!(area.is_allowed(&fk(), &joints(0.0, 60.0)));
+        // Slewed away
+        assert!(area.is_allowed(&fk(), &joints(180.0, 30.0)));
+    }
+}
@@ -1,8 +1,58 @@
+//! Crane lift planning: kinematics, load charts, rigging and physics
+//!
+//! # Features
+//!
+//! - `serde` (default): `Serialize`/`Deserialize` on cranes, charts, unit
+//!   values and analysis results.
+//! - `charts-io` (default): chart files and libraries, content hashes and
+//!   telemetry CSV import, saved lift plans. Pulls in `serde_json`, `csv`
+//!   and `sha2`.
+//! - `yaml`, `toml`, `tokio`, `binary-cache`: extra chart formats and
+//!   loaders, each implying `charts-io`.
+//! - `pyo3`: Python bindings for chart lookups, lift validation, ground
+//!   bearing and rigging (the `python` module).
+//! - `ffi`: C ABI with opaque crane and chart handles, declared in
+//!   `include/crane_core.h`.
+//! - `rayon`, `monte-carlo`: parallel sweeps and probabilistic analysis.
+//! - `plotting`: load chart curves and range diagrams as plot series and
+//!   SVG (`capacity::plotting`).
+//!
+//! With `default-features = false` the kinematics, rigging, physics and
+//! capacity math build without serde or any file IO, e.g. for WASM
+//! simulators. The crate needs `std`; there is no `no_std` build.
+//!
+//! # Scalar type
+//!
+//! Forward kinematics on unitless joint values ([`kinematics::tip_position`],
+//! [`kinematics::hook_point`]), the rotation and transform helpers and the
+//! force and moment statics are generic over [`Real`], so they run in `f32`
+//! on controllers and in visualization loops. Everything else, including
+//! every unit quantity, is `f64`.
+//!
+//! Start with [`prelude`] for the common crane, chart and unit types; the
+//! full set lives in the individual modules.
+
+pub mod prelude;
 pub mod types;
 pub mod equipment;
 pub mod rigging;
 pub mod physics;
 pub mod kinematics;
 pub mod capacity;
+pub mod compliance;
+pub mod safety;
+pub mod monitoring;
+pub mod simulation;
+pub mod scene;
+pub mod report;
+#[cfg(feature = "pyo3")]
+pub mod python;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(test)]
+mod test_fixtures;
 
-pub use types::*;
\ No newline at end of file
+// Root glob of `types`, kept until the next release for existing code.
+// Use `prelude` or `types` instead.
+#[doc(hidden)]
+pub use types::*;
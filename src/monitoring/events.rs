@@ -0,0 +1,141 @@
+//! Monitoring events and observers
+
+use crate::physics::WindCondition;
+use crate::safety::LmiState;
+use crate::types::*;
+use std::fmt;
+use std::sync::mpsc::Sender;
+
+/// How urgently an event needs attention
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Info,
+    Warning,
+    Alarm,
+}
+
+/// A threshold crossing seen by a [`CraneMonitor`](super::CraneMonitor)
+///
+/// Events are edge-triggered: each is emitted once when a condition starts
+/// or clears, not on every update while it holds.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MonitorEvent {
+    /// Hook load rose above the capacity alarm fraction
+    CapacityHigh {
+        utilization: f64,
+        load: Mass,
+        capacity: Mass,
+    },
+
+    /// Hook load dropped back below the capacity alarm fraction
+    CapacityNormal { utilization: f64 },
+
+    /// Wind moved to a different operating condition
+    WindConditionChanged {
+        previous: WindCondition,
+        current: WindCondition,
+        wind_speed: Velocity,
+    },
+
+    /// Load moment indicator changed state
+    LimiterStateChanged {
+        previous: LmiState,
+        current: LmiState,
+        utilization: f64,
+    },
+
+    /// Hook block came within the two-block warning distance of the tip
+    TwoBlockWarning { clearance: Length },
+
+    /// Hook block lowered clear of the two-block warning distance
+    TwoBlockCleared { clearance: Length },
+}
+
+impl MonitorEvent {
+    pub fn severity(&self) -> Severity {
+        match self {
+            MonitorEvent::CapacityHigh { .. } | MonitorEvent::TwoBlockWarning { .. } => Severity::Warning,
+            MonitorEvent::CapacityNormal { .. } | MonitorEvent::TwoBlockCleared { .. } => Severity::Info,
+            MonitorEvent::WindConditionChanged { current, .. } => match current {
+                WindCondition::Safe => Severity::Info,
+                WindCondition::Caution => Severity::Warning,
+                WindCondition::Shutdown | WindCondition::OutOfService => Severity::Alarm,
+            },
+            MonitorEvent::LimiterStateChanged { current, .. } => match current {
+                LmiState::Normal => Severity::Info,
+                LmiState::PreWarning | LmiState::Warning => Severity::Warning,
+                LmiState::Cutout => Severity::Alarm,
+            },
+        }
+    }
+}
+
+impl fmt::Display for MonitorEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MonitorEvent::CapacityHigh {
+                utilization,
+                load,
+                capacity,
+            } => write!(
+                f,
+                "Load {} is {:.0}% of capacity {}",
+                DisplayMass(*load),
+                utilization * 100.0,
+                DisplayMass(*capacity)
+            ),
+            MonitorEvent::CapacityNormal { utilization } => {
+                write!(f, "Load back to {:.0}% of capacity", utilization * 100.0)
+            }
+            MonitorEvent::WindConditionChanged {
+                previous,
+                current,
+                wind_speed,
+            } => write!(
+                f,
+                "Wind {} - condition {:?} -> {:?}",
+                DisplayVelocity(*wind_speed),
+                previous,
+                current
+            ),
+            MonitorEvent::LimiterStateChanged {
+                previous,
+                current,
+                utilization,
+            } => write!(
+                f,
+                "LMI {:?} -> {:?} at {:.0}% utilization",
+                previous,
+                current,
+                utilization * 100.0
+            ),
+            MonitorEvent::TwoBlockWarning { clearance } => {
+                write!(f, "Two-block warning: hook block {} below tip", DisplayLength(*clearance))
+            }
+            MonitorEvent::TwoBlockCleared { clearance } => {
+                write!(f, "Two-block cleared: hook block {} below tip", DisplayLength(*clearance))
+            }
+        }
+    }
+}
+
+/// Receives monitoring events
+///
+/// Implemented for closures and for channel senders, so events can be
+/// handled inline or forwarded to another thread.
+pub trait MonitorObserver: Send {
+    fn on_event(&mut self, event: &MonitorEvent);
+}
+
+impl<F: FnMut(&MonitorEvent) + Send> MonitorObserver for F {
+    fn on_event(&mut self, event: &MonitorEvent) {
+        self(event)
+    }
+}
+
+impl MonitorObserver for Sender<MonitorEvent> {
+    fn on_event(&mut self, event: &MonitorEvent) {
+        // A dropped receiver just means nobody is listening any more
+        let _ = self.send(event.clone());
+    }
+}
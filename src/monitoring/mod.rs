@@ -0,0 +1,11 @@
+//! Live monitoring with push notifications
+//!
+//! Rather than polling each analysis, applications embedding the crate feed
+//! a [`CraneMonitor`] with sensor readings and subscribe to the
+//! [`MonitorEvent`]s it raises when a threshold is crossed.
+
+pub mod events;
+pub mod monitor;
+
+pub use events::*;
+pub use monitor::*;
@@ -0,0 +1,261 @@
+//! Crane monitor: tracks live state and notifies observers of threshold breaches
+
+use super::events::*;
+use crate::equipment::Crane;
+use crate::kinematics::JointConfig;
+use crate::physics::{WindAnalysis, WindCondition};
+use crate::safety::{LmiReading, LoadMomentIndicator};
+use crate::types::*;
+use std::sync::mpsc::{self, Receiver};
+
+/// Thresholds for events the load moment indicator doesn't cover
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MonitorConfig {
+    /// Utilization above which `CapacityHigh` is raised
+    pub capacity_alarm: f64,
+
+    /// Hook block to tip distance below which `TwoBlockWarning` is raised
+    pub two_block_warning: Length,
+}
+
+impl Default for MonitorConfig {
+    /// 90% capacity, 5 ft two-block warning
+    fn default() -> Self {
+        Self {
+            capacity_alarm: 0.9,
+            two_block_warning: Length::new::<foot>(5.0),
+        }
+    }
+}
+
+/// Wraps a load moment indicator and pushes events to observers
+///
+/// Feed it joint configurations, load cell readings and wind readings as
+/// they arrive; each update returns the events it raised and also sends
+/// them to every subscribed observer.
+pub struct CraneMonitor<C: Crane> {
+    lmi: LoadMomentIndicator<C>,
+    config: MonitorConfig,
+    observers: Vec<Box<dyn MonitorObserver>>,
+
+    capacity_high: bool,
+    two_blocking: bool,
+    wind: WindCondition,
+}
+
+impl<C: Crane> CraneMonitor<C> {
+    pub fn new(lmi: LoadMomentIndicator<C>, config: MonitorConfig) -> Self {
+        Self {
+            lmi,
+            config,
+            observers: Vec::new(),
+            capacity_high: false,
+            two_blocking: false,
+            wind: WindCondition::Safe,
+        }
+    }
+
+    /// Monitor with standard LMI thresholds and default config
+    pub fn standard(crane: C) -> Self {
+        Self::new(LoadMomentIndicator::standard(crane), MonitorConfig::default())
+    }
+
+    pub fn lmi(&self) -> &LoadMomentIndicator<C> {
+        &self.lmi
+    }
+
+    pub fn crane(&self) -> &C {
+        self.lmi.crane()
+    }
+
+    pub fn config(&self) -> &MonitorConfig {
+        &self.config
+    }
+
+    pub fn wind_condition(&self) -> WindCondition {
+        self.wind
+    }
+
+    /// Add an observer (a closure or a channel sender)
+    pub fn subscribe(&mut self, observer: impl MonitorObserver + 'static) {
+        self.observers.push(Box::new(observer));
+    }
+
+    /// Subscribe a new channel and return its receiving end
+    pub fn channel(&mut self) -> Receiver<MonitorEvent> {
+        let (sender, receiver) = mpsc::channel();
+        self.subscribe(sender);
+        receiver
+    }
+
+    /// Move the crane and evaluate the measured hook load
+    pub fn update(&mut self, joints: JointConfig, measured_load: Mass) -> Vec<MonitorEvent> {
+        let hoist = joints.hoist;
+        let reading = self.lmi.update(joints, measured_load);
+
+        let mut events = self.load_events(&reading);
+        if let Some(clearance) = hoist {
+            events.extend(self.two_block_event(clearance));
+        }
+        self.publish(&events);
+        events
+    }
+
+    /// Evaluate a new wind reading
+    pub fn update_wind(&mut self, wind: &WindAnalysis) -> Vec<MonitorEvent> {
+        let current = wind.wind_condition();
+        let mut events = Vec::new();
+
+        if current != self.wind {
+            events.push(MonitorEvent::WindConditionChanged {
+                previous: self.wind,
+                current,
+                wind_speed: wind.wind_speed,
+            });
+            self.wind = current;
+        }
+        self.publish(&events);
+        events
+    }
+
+    fn load_events(&mut self, reading: &LmiReading) -> Vec<MonitorEvent> {
+        let mut events = Vec::new();
+
+        let high = reading.utilization > self.config.capacity_alarm;
+        if high && !self.capacity_high {
+            events.push(MonitorEvent::CapacityHigh {
+                utilization: reading.utilization,
+                load: reading.measured_load,
+                capacity: reading.capacity,
+            });
+        } else if !high && self.capacity_high {
+            events.push(MonitorEvent::CapacityNormal {
+                utilization: reading.utilization,
+            });
+        }
+        self.capacity_high = high;
+
+        if reading.changed() {
+            events.push(MonitorEvent::LimiterStateChanged {
+                previous: reading.previous,
+                current: reading.state,
+                utilization: reading.utilization,
+            });
+        }
+        events
+    }
+
+    fn two_block_event(&mut self, clearance: Length) -> Option<MonitorEvent> {
+        let close = clearance < self.config.two_block_warning;
+        let event = match (close, self.two_blocking) {
+            (true, false) => Some(MonitorEvent::TwoBlockWarning { clearance }),
+            (false, true) => Some(MonitorEvent::TwoBlockCleared { clearance }),
+            _ => None,
+        };
+        self.two_blocking = close;
+        event
+    }
+
+    fn publish(&mut self, events: &[MonitorEvent]) {
+        for event in events {
+            for observer in &mut self.observers {
+                observer.on_event(event);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::equipment::{CraneType, Kinematic, MobileCrane};
+    use crate::safety::LmiState;
+    use crate::test_fixtures::mobile_crane;
+    use std::sync::{Arc, Mutex};
+
+    // 50 ft radius: 10,000 lbs on the fixture chart
+    fn monitor() -> CraneMonitor<MobileCrane> {
+        CraneMonitor::standard(mobile_crane())
+    }
+
+    fn joints(monitor: &CraneMonitor<MobileCrane>, hoist: f64) -> JointConfig {
+        let mut joints = monitor.crane().joint_config();
+        joints.hoist = Some(Length::new::<foot>(hoist));
+        joints
+    }
+
+    fn lbs(value: f64) -> Mass {
+        Mass::new::<pound>(value)
+    }
+
+    #[test]
+    fn test_capacity_events_are_edge_triggered() {
+        let mut monitor = monitor();
+        let receiver = monitor.channel();
+        let j = joints(&monitor, 40.0);
+
+        assert!(monitor.update(j, lbs(5_000.0)).is_empty());
+
+        let events = monitor.update(j, lbs(9_500.0));
+        assert!(matches!(events[0], MonitorEvent::CapacityHigh { .. }));
+        assert!(matches!(
+            events[1],
+            MonitorEvent::LimiterStateChanged { current: LmiState::Warning, .. }
+        ));
+
+        // Still high: nothing new
+        assert!(monitor.update(j, lbs(9_400.0)).is_empty());
+
+        let events = monitor.update(j, lbs(2_000.0));
+        assert!(matches!(events[0], MonitorEvent::CapacityNormal { .. }));
+
+        // The channel saw everything the updates returned
+        assert_eq!(receiver.try_iter().count(), 4);
+    }
+
+    #[test]
+    fn test_two_block_warning() {
+        let mut monitor = monitor();
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&seen);
+        monitor.subscribe(move |event: &MonitorEvent| sink.lock().unwrap().push(event.clone()));
+
+        monitor.update(joints(&monitor, 20.0), lbs(1_000.0));
+        monitor.update(joints(&monitor, 3.0), lbs(1_000.0));
+        monitor.update(joints(&monitor, 2.0), lbs(1_000.0));
+        monitor.update(joints(&monitor, 10.0), lbs(1_000.0));
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 2);
+        assert!(matches!(seen[0], MonitorEvent::TwoBlockWarning { .. }));
+        assert_eq!(seen[0].severity(), Severity::Warning);
+        assert!(matches!(seen[1], MonitorEvent::TwoBlockCleared { .. }));
+    }
+
+    #[test]
+    fn test_wind_entering_caution() {
+        let mut monitor = monitor();
+        let wind = |mph: f64| {
+            WindAnalysis::new(
+                CraneType::AllTerrain,
+                Length::new::<foot>(100.0),
+                Angle::new::<degree>(60.0),
+                Area::new::<square_foot>(50.0),
+                Velocity::new::<mile_per_hour>(mph),
+            )
+        };
+
+        assert!(monitor.update_wind(&wind(5.0)).is_empty());
+
+        let events = monitor.update_wind(&wind(25.0));
+        assert_eq!(
+            events,
+            vec![MonitorEvent::WindConditionChanged {
+                previous: WindCondition::Safe,
+                current: WindCondition::Caution,
+                wind_speed: Velocity::new::<mile_per_hour>(25.0),
+            }]
+        );
+        assert_eq!(monitor.wind_condition(), WindCondition::Caution);
+    }
+}
@@ -0,0 +1,238 @@
+//! Buried utilities and vaults near outrigger pads
+//!
+//! Pad pressure spreads with depth at 2 vertical to 1 horizontal: at depth
+//! `z` under a pad `B` across, the reaction `P` acts over `(B + z)²`. The
+//! spread is taken as a circle of diameter `B + z` in plan, centered on the
+//! pad. A utility inside that circle at its cover depth is within the
+//! pressure bulb and the stress reaching it is checked against what it may
+//! carry.
+//!
+//! Plan coordinates are X and Z in **FEET**, as for support points.
+
+use crate::physics::ground_bearing::{GroundBearingAnalysis, GroundBearingError};
+use crate::report::*;
+use crate::types::*;
+use nalgebra as na;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A pipe, duct bank or vault, as a straight run of constant width
+#[derive(Debug, Clone)]
+pub struct BuriedUtility {
+    pub name: String,
+
+    /// Ends of the centerline in plan (X, Z feet); the same point for a vault or manhole
+    pub start: na::Point2<f64>,
+    pub end: na::Point2<f64>,
+
+    /// Pipe diameter or vault width
+    pub width: Length,
+
+    /// Depth of soil over the top of the utility
+    pub cover_depth: Length,
+
+    /// Largest vertical pressure the utility may carry at its top
+    pub allowable_pressure: Pressure,
+}
+
+impl BuriedUtility {
+    pub fn new(
+        name: impl Into<String>,
+        start: (Length, Length),
+        end: (Length, Length),
+        width: Length,
+        cover_depth: Length,
+        allowable_pressure: Pressure,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            start: na::Point2::new(to_coord(start.0), to_coord(start.1)),
+            end: na::Point2::new(to_coord(end.0), to_coord(end.1)),
+            width,
+            cover_depth,
+            allowable_pressure,
+        }
+    }
+
+    /// Horizontal distance from a plan point (feet) to the near edge of the utility
+    pub fn edge_distance(&self, point: na::Point2<f64>) -> Length {
+        let run = self.end - self.start;
+        let length_sq = run.norm_squared();
+        let t = if length_sq > 0.0 { ((point - self.start).dot(&run) / length_sq).clamp(0.0, 1.0) } else { 0.0 };
+        let nearest = self.start + run * t;
+        from_coord(((point - nearest).norm() - to_coord(self.width) / 2.0).max(0.0))
+    }
+}
+
+/// A pad whose pressure bulb reaches a utility
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UtilityProximity {
+    pub support: String,
+    pub utility: String,
+
+    /// Horizontal distance from the pad center to the utility's near edge
+    pub distance: Length,
+
+    /// Radius of the spread at the utility's cover depth
+    pub bulb_radius: Length,
+
+    /// Vertical stress reaching the top of the utility
+    pub stress: Pressure,
+
+    pub allowable_pressure: Pressure,
+}
+
+impl UtilityProximity {
+    pub fn utilization(&self) -> f64 {
+        self.stress.get::<psi>() / self.allowable_pressure.get::<psi>()
+    }
+
+    pub fn passes(&self) -> bool {
+        self.utilization() <= 1.0
+    }
+}
+
+/// Pads whose pressure bulbs reach a buried utility
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UtilityCheck {
+    pub proximities: Vec<UtilityProximity>,
+}
+
+impl UtilityCheck {
+    /// Every impinged utility carries its stress
+    pub fn passes(&self) -> bool {
+        self.proximities.iter().all(UtilityProximity::passes)
+    }
+}
+
+/// Check every support of `analysis` against `utilities`
+///
+/// Each pad is taken as square over its contact area (the mat, if there is
+/// one). Only pad and utility pairs within the pressure bulb are returned.
+pub fn check_buried_utilities(
+    analysis: &GroundBearingAnalysis,
+    utilities: &[BuriedUtility],
+) -> Result<UtilityCheck, GroundBearingError> {
+    let result = analysis.calculate_reactions()?;
+    let mut proximities = Vec::new();
+
+    for (support, reaction) in analysis.support_points.iter().zip(&result.reactions) {
+        let side = support.contact_area.get::<square_foot>().sqrt();
+        let center = na::Point2::new(support.position.x, support.position.z);
+
+        for utility in utilities {
+            let depth = utility.cover_depth.get::<foot>();
+            let spread = side + depth;
+            let distance = utility.edge_distance(center);
+            if distance.get::<foot>() > spread / 2.0 {
+                continue;
+            }
+            proximities.push(UtilityProximity {
+                support: support.name.clone(),
+                utility: utility.name.clone(),
+                distance,
+                bulb_radius: Length::new::<foot>(spread / 2.0),
+                stress: Pressure::new::<pound_force_per_square_foot>(
+                    reaction.force.get::<pound_force>() / (spread * spread),
+                ),
+                allowable_pressure: utility.allowable_pressure,
+            });
+        }
+    }
+
+    Ok(UtilityCheck { proximities })
+}
+
+impl AnalysisReport for UtilityCheck {
+    fn title(&self) -> String {
+        "Buried Utilities".into()
+    }
+
+    /// One check per impinged utility; none when every pad is clear
+    fn checks(&self) -> Vec<ValidationCheck> {
+        self.proximities
+            .iter()
+            .map(|p| ValidationCheck {
+                name: format!("{} over {}", p.support, p.utility),
+                status: if p.passes() { CheckStatus::Warning } else { CheckStatus::Fail },
+                details: format!(
+                    "{:.0} psf at cover depth, allowable {:.0} psf; {:.1} ft from pad center, bulb radius {:.1} ft",
+                    p.stress.get::<pound_force_per_square_foot>(),
+                    p.allowable_pressure.get::<pound_force_per_square_foot>(),
+                    p.distance.get::<foot>(),
+                    p.bulb_radius.get::<foot>(),
+                ),
+                margin: Some((1.0 - p.utilization()) * 100.0),
+            })
+            .collect()
+    }
+
+    fn results(&self) -> Vec<NumericResult> {
+        let worst = self.proximities.iter().map(UtilityProximity::utilization).fold(0.0, f64::max);
+        vec![
+            NumericResult::new("impinged_utilities", self.proximities.len() as f64, ""),
+            NumericResult::new("max_utility_utilization", worst, ""),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn analysis() -> GroundBearingAnalysis {
+        let mut analysis = GroundBearingAnalysis::new_na(
+            Mass::new::<pound>(100000.0),
+            na::Point3::origin(),
+            Mass::new::<pound>(20000.0),
+            na::Point3::new(0.0, 50.0, 30.0),
+        );
+        let pad_area = Area::new::<square_foot>(4.0);
+        for (name, x, z) in [("FR", 10.0, 10.0), ("FL", -10.0, 10.0), ("RR", 10.0, -10.0), ("RL", -10.0, -10.0)] {
+            analysis.add_support_na(name, na::Point3::new(x, 0.0, z), pad_area);
+        }
+        analysis
+    }
+
+    #[test]
+    fn test_pressure_bulb_reaches_utility() {
+        let ft = Length::new::<foot>;
+        // Pipe running in X, 2 ft in from the front pads, 4 ft down: bulb radius (2 + 4)/2 = 3 ft
+        let pipe = BuriedUtility::new(
+            "Water main",
+            (ft(-30.0), ft(12.5)),
+            (ft(30.0), ft(12.5)),
+            ft(1.0),
+            ft(4.0),
+            Pressure::new::<pound_force_per_square_foot>(1000.0),
+        );
+        let check = check_buried_utilities(&analysis(), std::slice::from_ref(&pipe)).unwrap();
+        assert_eq!(check.proximities.len(), 2);
+
+        // Front pads carry 45,000 lbs over 6 ft × 6 ft at depth
+        let front = &check.proximities[0];
+        assert_relative_eq!(front.distance.get::<foot>(), 2.0, epsilon = 1e-9);
+        assert_relative_eq!(front.stress.get::<pound_force_per_square_foot>(), 45000.0 / 36.0, epsilon = 1e-6);
+        assert!(!check.passes());
+        assert_eq!(check.status(), CheckStatus::Fail);
+
+        // Deeper, the same pipe is clear of the rear pads and carries less
+        let deep = BuriedUtility { cover_depth: ft(8.0), ..pipe };
+        let check = check_buried_utilities(&analysis(), &[deep]).unwrap();
+        assert!(check.passes());
+        assert_eq!(check.status(), CheckStatus::Warning);
+
+        let vault = BuriedUtility::new(
+            "Vault",
+            (ft(0.0), ft(0.0)),
+            (ft(0.0), ft(0.0)),
+            ft(4.0),
+            ft(2.0),
+            Pressure::new::<pound_force_per_square_foot>(500.0),
+        );
+        assert!(check_buried_utilities(&analysis(), &[vault]).unwrap().proximities.is_empty());
+    }
+}
@@ -0,0 +1,382 @@
+//! Crawler travel with a suspended load
+//!
+//! Walking a crawler with the load on the hook (pick and carry) is checked
+//! at each station along the travel path:
+//!
+//! - Grade: travel and cross slope within the manufacturer's limits
+//! - Boom orientation: boom within a sector of the track line, over the
+//!   front or rear
+//! - Ground pressure: peak pressure under the leading track, the one on
+//!   the load side, with the dynamic allowance on the hook load
+//! - Stability: the resultant stays inside the track footprint
+//!
+//! Along the track, pressure is trapezoidal while the resultant lies in the
+//! middle third and triangular beyond it:
+//!
+//! p_max = R / (b·L) · (1 + 6e/L)          (e ≤ L/6)
+//! p_max = 2R / (3b·(L/2 − e))             (e > L/6)
+//!
+//! On a slope the suspended load and the machine's center of gravity both
+//! act plumb, so each shifts downhill across the footprint by its height
+//! times the tangent of the slope. Forces are taken as vertical on the
+//! track, which is conservative for the small slopes travel allows.
+
+use crate::report::*;
+use crate::types::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Crawler undercarriage, machine weight and travel limits
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CrawlerTravel {
+    /// Track centerline to centerline
+    pub gauge: Length,
+
+    /// Track length in contact with the ground, tumbler to idler
+    pub contact_length: Length,
+    pub shoe_width: Length,
+
+    /// Crane weight without the hook load, counterweight and boom included
+    pub machine_weight: Mass,
+
+    /// Machine CG from the slew center along the boom, negative toward the
+    /// counterweight
+    pub cg_offset: Length,
+
+    /// Machine CG height above the track bearing surface
+    pub cg_height: Length,
+
+    /// Steepest travel grade allowed with load
+    pub max_grade: Angle,
+    pub max_cross_slope: Angle,
+
+    /// Largest boom angle off the track line, over the front or rear
+    pub boom_sector: Angle,
+
+    /// Factor on the hook load for travel motion
+    pub dynamic_allowance: f64,
+    pub allowable_pressure: Option<Pressure>,
+}
+
+impl CrawlerTravel {
+    /// Machine CG on the slew center, 1° grade and cross slope, boom within
+    /// 15° of the tracks and a 1.1 dynamic allowance; use the crane
+    /// manufacturer's limits where they are known
+    pub fn new(gauge: Length, contact_length: Length, shoe_width: Length, machine_weight: Mass) -> Self {
+        Self {
+            gauge,
+            contact_length,
+            shoe_width,
+            machine_weight,
+            cg_offset: Length::new::<foot>(0.0),
+            cg_height: Length::new::<foot>(0.0),
+            max_grade: Angle::new::<degree>(1.0),
+            max_cross_slope: Angle::new::<degree>(1.0),
+            boom_sector: Angle::new::<degree>(15.0),
+            dynamic_allowance: 1.1,
+            allowable_pressure: None,
+        }
+    }
+
+    pub fn with_machine_cg(mut self, offset: Length, height: Length) -> Self {
+        self.cg_offset = offset;
+        self.cg_height = height;
+        self
+    }
+
+    pub fn with_slope_limits(mut self, max_grade: Angle, max_cross_slope: Angle) -> Self {
+        self.max_grade = max_grade;
+        self.max_cross_slope = max_cross_slope;
+        self
+    }
+
+    pub fn with_boom_sector(mut self, sector: Angle) -> Self {
+        self.boom_sector = sector;
+        self
+    }
+
+    pub fn with_dynamic_allowance(mut self, factor: f64) -> Self {
+        self.dynamic_allowance = factor;
+        self
+    }
+
+    pub fn with_allowable_pressure(mut self, allowable: Pressure) -> Self {
+        self.allowable_pressure = Some(allowable);
+        self
+    }
+
+    /// Check every station of `path` with `hook_load` at `radius`, hanging
+    /// from a boom tip `tip_height` above the tracks
+    pub fn analyze(
+        &self,
+        path: &[TravelStation],
+        hook_load: Mass,
+        radius: Length,
+        tip_height: Length,
+    ) -> CrawlerTravelAnalysis {
+        let stations = path.iter().map(|s| self.analyze_station(s, hook_load, radius, tip_height)).collect();
+        CrawlerTravelAnalysis {
+            stations,
+            allowable_pressure: self.allowable_pressure,
+            dynamic_allowance: self.dynamic_allowance,
+        }
+    }
+
+    fn analyze_station(
+        &self,
+        station: &TravelStation,
+        hook_load: Mass,
+        radius: Length,
+        tip_height: Length,
+    ) -> TravelStationResult {
+        let ft = |l: Length| l.get::<foot>();
+        let machine = self.machine_weight.get::<pound>();
+        let load = hook_load.get::<pound>() * self.dynamic_allowance;
+        let total = machine + load;
+
+        // x across the tracks to the right, z along them toward the front
+        let (boom_sin, boom_cos) = station.boom_direction.get::<radian>().sin_cos();
+        let (grade, cross) = (station.grade.get::<radian>().tan(), station.cross_slope.get::<radian>().tan());
+        let moment_x = machine * (ft(self.cg_offset) * boom_sin + ft(self.cg_height) * cross)
+            + load * (ft(radius) * boom_sin + ft(tip_height) * cross);
+        let moment_z = machine * (ft(self.cg_offset) * boom_cos - ft(self.cg_height) * grade)
+            + load * (ft(radius) * boom_cos - ft(tip_height) * grade);
+        let (e_x, e_z) = (moment_x / total, moment_z / total);
+
+        let (gauge, length, width) = (ft(self.gauge), ft(self.contact_length), ft(self.shoe_width));
+        let leading_track = if e_x >= 0.0 { TrackSide::Right } else { TrackSide::Left };
+        let reaction = total * (0.5 + e_x.abs() / gauge);
+        let e = e_z.abs();
+        let peak = if e <= length / 6.0 {
+            reaction / (width * length) * (1.0 + 6.0 * e / length)
+        } else if e < length / 2.0 {
+            2.0 * reaction / (3.0 * width * (length / 2.0 - e))
+        } else {
+            f64::INFINITY
+        };
+
+        let mut problems = Vec::new();
+        let deg = |a: Angle| a.get::<degree>();
+        if station.grade.abs() > self.max_grade {
+            problems.push(format!(
+                "Grade {:.1}° over the {:.1}° allowed",
+                deg(station.grade.abs()),
+                deg(self.max_grade)
+            ));
+        }
+        if station.cross_slope.abs() > self.max_cross_slope {
+            problems.push(format!(
+                "Cross slope {:.1}° over the {:.1}° allowed",
+                deg(station.cross_slope.abs()),
+                deg(self.max_cross_slope)
+            ));
+        }
+
+        // Off the track line, whether over the front or the rear
+        let off_front = deg(station.boom_direction).rem_euclid(360.0);
+        let boom_offset = Angle::new::<degree>(off_front.min((off_front - 180.0).abs()).min(360.0 - off_front));
+        if boom_offset > self.boom_sector {
+            problems.push(format!(
+                "Boom {:.0}° off the tracks, over the {:.0}° allowed",
+                deg(boom_offset),
+                deg(self.boom_sector)
+            ));
+        }
+
+        if e_x.abs() >= gauge / 2.0 || e >= length / 2.0 {
+            problems.push("Resultant outside the track footprint".into());
+        } else if let Some(allowable) = self.allowable_pressure
+            && peak > allowable.get::<pound_force_per_square_foot>()
+        {
+            problems.push(format!(
+                "Track pressure {:.0} psf over the {:.0} psf allowable",
+                peak,
+                allowable.get::<pound_force_per_square_foot>()
+            ));
+        }
+
+        TravelStationResult {
+            station: station.station,
+            leading_track,
+            leading_reaction: Force::new::<pound_force>(reaction),
+            peak_pressure: Pressure::new::<pound_force_per_square_foot>(peak),
+            boom_offset,
+            status: if problems.is_empty() { CheckStatus::Pass } else { CheckStatus::Fail },
+            problems,
+        }
+    }
+}
+
+/// A position along the travel path
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TravelStation {
+    /// Distance along the path from its start
+    pub station: Length,
+
+    /// Ground slope along the tracks, positive uphill in the travel direction
+    pub grade: Angle,
+
+    /// Ground slope across the tracks, positive falling to the right
+    pub cross_slope: Angle,
+
+    /// Boom direction seen from above, clockwise from the travel direction
+    pub boom_direction: Angle,
+}
+
+impl TravelStation {
+    pub fn new(station: Length, grade: Angle, boom_direction: Angle) -> Self {
+        Self { station, grade, cross_slope: Angle::new::<degree>(0.0), boom_direction }
+    }
+
+    pub fn with_cross_slope(mut self, cross_slope: Angle) -> Self {
+        self.cross_slope = cross_slope;
+        self
+    }
+}
+
+/// Track side, facing the travel direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TrackSide {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TravelStationResult {
+    pub station: Length,
+
+    /// More heavily loaded track
+    pub leading_track: TrackSide,
+    pub leading_reaction: Force,
+
+    /// Peak pressure under the leading track, with the dynamic allowance
+    pub peak_pressure: Pressure,
+
+    /// Boom angle off the track line
+    pub boom_offset: Angle,
+    pub status: CheckStatus,
+    pub problems: Vec<String>,
+}
+
+/// Every station of a travel path, in path order
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CrawlerTravelAnalysis {
+    pub stations: Vec<TravelStationResult>,
+    pub allowable_pressure: Option<Pressure>,
+    pub dynamic_allowance: f64,
+}
+
+impl CrawlerTravelAnalysis {
+    /// Station with the highest track pressure
+    pub fn governing(&self) -> Option<&TravelStationResult> {
+        self.stations.iter().max_by(|a, b| a.peak_pressure.value.total_cmp(&b.peak_pressure.value))
+    }
+
+    pub fn max_pressure(&self) -> Option<Pressure> {
+        self.governing().map(|s| s.peak_pressure)
+    }
+
+    /// Highest track pressure over the allowable
+    pub fn utilization(&self) -> Option<f64> {
+        Some(self.max_pressure()?.get::<pound_force_per_square_foot>()
+            / self.allowable_pressure?.get::<pound_force_per_square_foot>())
+    }
+
+    /// Stations that fail any check
+    pub fn problems(&self) -> impl Iterator<Item = &TravelStationResult> {
+        self.stations.iter().filter(|s| s.status != CheckStatus::Pass)
+    }
+
+    pub fn is_acceptable(&self) -> bool {
+        self.problems().next().is_none()
+    }
+}
+
+impl AnalysisReport for CrawlerTravelAnalysis {
+    fn title(&self) -> String {
+        "Crawler Travel".into()
+    }
+
+    fn checks(&self) -> Vec<ValidationCheck> {
+        self.stations
+            .iter()
+            .map(|s| ValidationCheck {
+                name: format!("Travel Station {:.1} ft", s.station.get::<foot>()),
+                status: s.status,
+                details: if s.problems.is_empty() {
+                    format!(
+                        "{:.0} psf under the {:?} track",
+                        s.peak_pressure.get::<pound_force_per_square_foot>(),
+                        s.leading_track
+                    )
+                } else {
+                    s.problems.join("; ")
+                },
+                margin: self.allowable_pressure.map(|allowable| {
+                    (1.0 - s.peak_pressure.get::<pound_force_per_square_foot>()
+                        / allowable.get::<pound_force_per_square_foot>())
+                        * 100.0
+                }),
+            })
+            .collect()
+    }
+
+    fn results(&self) -> Vec<NumericResult> {
+        let mut results = vec![NumericResult::new("travel_dynamic_allowance", self.dynamic_allowance, "")];
+        if let Some(pressure) = self.max_pressure() {
+            results.push(NumericResult::new(
+                "max_track_pressure",
+                pressure.get::<pound_force_per_square_foot>(),
+                "psf",
+            ));
+        }
+        results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_travel_path_checks() {
+        let ft = Length::new::<foot>;
+        let deg = Angle::new::<degree>;
+        let crawler = CrawlerTravel::new(ft(16.0), ft(20.0), ft(3.0), Mass::new::<pound>(200_000.0))
+            .with_machine_cg(ft(-2.0), ft(5.0))
+            .with_allowable_pressure(Pressure::new::<pound_force_per_square_foot>(3000.0));
+        let path = [
+            TravelStation::new(ft(0.0), deg(0.0), deg(0.0)),
+            TravelStation::new(ft(50.0), deg(2.0), deg(0.0)),
+            TravelStation::new(ft(100.0), deg(0.0), deg(90.0)),
+            TravelStation::new(ft(150.0), deg(0.0), deg(185.0)),
+        ];
+        let analysis = crawler.analyze(&path, Mass::new::<pound>(20_000.0), ft(30.0), ft(80.0));
+
+        // 222,000 lbs over both tracks, e = 260,000 / 222,000 ft forward:
+        // 111,000 / 60 · (1 + 6e/20) = 2,500 psf
+        let flat = &analysis.stations[0];
+        assert_eq!(flat.status, CheckStatus::Pass);
+        assert_relative_eq!(flat.peak_pressure.get::<pound_force_per_square_foot>(), 2500.0, max_relative = 1e-9);
+
+        assert!(analysis.stations[1].problems[0].starts_with("Grade 2.0°"));
+
+        // Boom over the side puts the load on the right track and off the tracks
+        let side = &analysis.stations[2];
+        assert_eq!(side.leading_track, TrackSide::Right);
+        assert_relative_eq!(side.boom_offset.get::<degree>(), 90.0, epsilon = 1e-9);
+        assert_eq!(side.status, CheckStatus::Fail);
+
+        // Over the rear is within the sector
+        assert_relative_eq!(analysis.stations[3].boom_offset.get::<degree>(), 5.0, epsilon = 1e-9);
+        assert_eq!(analysis.problems().count(), 2);
+        assert!(!analysis.is_acceptable());
+        assert_eq!(analysis.status(), CheckStatus::Fail);
+    }
+}
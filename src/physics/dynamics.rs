@@ -1,4 +1,6 @@
 use crate::types::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// Dynamic load calculations (for future implementation)
 /// 
@@ -41,6 +43,152 @@ pub fn pendulum_period(cable_length: Length) -> f64 {
     2.0 * std::f64::consts::PI * (l / g).sqrt()
 }
 
+/// Pendulum natural frequency of a load hanging `cable_length` below the tip, Hz
+pub fn pendulum_frequency(cable_length: Length) -> f64 {
+    1.0 / pendulum_period(cable_length)
+}
+
+/// Axial (bounce) natural frequency of `load` on a hoist rope, Hz
+///
+/// `stiffness` is the reeved rope stiffness in lbf/ft, e.g. from
+/// [`rope_stiffness`](crate::physics::rope_stiffness).
+pub fn rope_axial_frequency(load: Mass, stiffness: f64) -> f64 {
+    let slugs = weight_of(load).get::<pound_force>() / 32.174;
+    if slugs <= 0.0 {
+        return f64::INFINITY;
+    }
+    (stiffness / slugs).sqrt() / (2.0 * std::f64::consts::PI)
+}
+
+/// Whether an excitation is close enough to a natural frequency to build
+/// up motion: within 0.8 to 1.25 times it
+pub fn is_resonant(excitation_hz: f64, natural_hz: f64) -> bool {
+    if natural_hz <= 0.0 || !natural_hz.is_finite() {
+        return false;
+    }
+    (0.8..=1.25).contains(&(excitation_hz / natural_hz))
+}
+
+/// Peak force from a sudden load transfer
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ShockLoad {
+    /// Force once everything settles
+    pub static_force: Force,
+
+    /// Largest force during the transfer
+    pub peak_force: Force,
+
+    /// Peak over static force
+    pub factor: f64,
+}
+
+impl ShockLoad {
+    fn new(static_lbf: f64, peak_lbf: f64) -> Self {
+        Self {
+            static_force: Force::new::<pound_force>(static_lbf),
+            peak_force: Force::new::<pound_force>(peak_lbf),
+            factor: if static_lbf > 0.0 { peak_lbf / static_lbf } else { 1.0 },
+        }
+    }
+
+    /// Whether the shock stays within the plan's allowance for `lift_type`
+    pub fn within_allowance(&self, lift_type: LiftType) -> bool {
+        self.factor <= dynamic_amplification_factor(lift_type)
+    }
+}
+
+/// Snatch when slack rigging comes taut with the hoist running
+///
+/// The hoist keeps paying in at `hoist_speed` while the rigging stretches;
+/// once the load lifts off it bounces on the rigging, peaking at
+/// F = W + v·√(k·m). `stiffness` is the rope and rigging stiffness in
+/// lbf/ft, e.g. from [`rope_stiffness`](crate::physics::rope_stiffness).
+pub fn snatch_load(load: Mass, hoist_speed: Velocity, stiffness: f64) -> ShockLoad {
+    let weight = weight_of(load).get::<pound_force>();
+    let slugs = weight / 32.174;
+    let v = hoist_speed.get::<foot_per_second>().abs();
+    ShockLoad::new(weight, weight + v * (stiffness * slugs).sqrt())
+}
+
+/// Load falling `drop` before the rigging catches it
+///
+/// F = W·(1 + √(1 + 2kh/W)); twice the weight for a load released with
+/// no drop at all.
+pub fn drop_shock_load(load: Mass, drop: Length, stiffness: f64) -> ShockLoad {
+    let weight = weight_of(load).get::<pound_force>();
+    ShockLoad::new(weight, sudden_peak(weight, drop, stiffness))
+}
+
+/// Load on the remaining crane when the other crane in a tandem lets go
+///
+/// The crane's share jumps from `share_before` to `share_after` (usually
+/// the whole load); the jump is applied suddenly, after the load drops
+/// `drop` onto this crane's rigging.
+pub fn tandem_trip_load(share_before: Mass, share_after: Mass, drop: Length, stiffness: f64) -> ShockLoad {
+    let before = weight_of(share_before).get::<pound_force>();
+    let after = weight_of(share_after).get::<pound_force>();
+    let jump = (after - before).max(0.0);
+    ShockLoad::new(after, before + sudden_peak(jump, drop, stiffness))
+}
+
+/// Peak force when `weight` is applied suddenly after falling `drop`
+fn sudden_peak(weight: f64, drop: Length, stiffness: f64) -> f64 {
+    if weight <= 0.0 {
+        return 0.0;
+    }
+    let h = drop.get::<foot>().max(0.0);
+    weight * (1.0 + (1.0 + 2.0 * stiffness * h / weight).sqrt())
+}
+
 // TODO: Implement full swing dynamics when needed
 // TODO: Wind loading calculations
 // TODO: Acceleration-based load shifts
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_snatch_and_drop() {
+        // 10,000 lbs at 1 ft/s on 100,000 lbf/ft rigging
+        let load = Mass::new::<pound>(10_000.0);
+        let snatch = snatch_load(load, Velocity::new::<foot_per_second>(1.0), 100_000.0);
+        let expected = 10_000.0 + (100_000.0 * 10_000.0 / 32.174f64).sqrt();
+        assert_relative_eq!(snatch.peak_force.get::<pound_force>(), expected, max_relative = 1e-6);
+        assert!(!snatch.within_allowance(LiftType::Shock));
+
+        let creep = snatch_load(load, Velocity::new::<foot_per_second>(0.05), 100_000.0);
+        assert!(creep.within_allowance(LiftType::Smooth));
+
+        let released = drop_shock_load(load, Length::new::<foot>(0.0), 100_000.0);
+        assert_relative_eq!(released.factor, 2.0, max_relative = 1e-6);
+    }
+
+    #[test]
+    fn test_natural_frequencies() {
+        let length = Length::new::<foot>(40.0);
+        assert_relative_eq!(pendulum_frequency(length), 1.0 / pendulum_period(length), max_relative = 1e-12);
+
+        // 32,174 lbs is 1,000 slugs: √(1e6 / 1e3) / 2π
+        let axial = rope_axial_frequency(Mass::new::<pound>(32_174.0), 1e6);
+        assert_relative_eq!(axial, 1000f64.sqrt() / (2.0 * std::f64::consts::PI), max_relative = 1e-6);
+
+        assert!(is_resonant(0.15, 0.14));
+        assert!(!is_resonant(0.5, 0.14));
+    }
+
+    #[test]
+    fn test_tandem_trip() {
+        // Even split, then the partner lets go with 2 in of slack
+        let share = Mass::new::<pound>(20_000.0);
+        let whole = Mass::new::<pound>(40_000.0);
+        let trip = tandem_trip_load(share, whole, Length::new::<inch>(2.0), 200_000.0);
+
+        let jump: f64 = 20_000.0;
+        let expected = 20_000.0 + jump * (1.0 + (1.0 + 2.0 * 200_000.0 * (2.0 / 12.0) / jump).sqrt());
+        assert_relative_eq!(trip.peak_force.get::<pound_force>(), expected, max_relative = 1e-6);
+        assert_relative_eq!(trip.static_force.get::<pound_force>(), 40_000.0, max_relative = 1e-6);
+    }
+}
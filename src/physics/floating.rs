@@ -0,0 +1,549 @@
+//! Floating (barge-mounted) crane support analysis
+//!
+//! # Coordinate system
+//!
+//! Same axes as the ground bearing analysis:
+//! - **X-axis**: Port(-) / Starboard(+) - athwartships from barge centerline
+//! - **Y-axis**: Up(+) - vertical from the keel
+//! - **Z-axis**: Aft(-) / Forward(+) - longitudinal from midships
+//!
+//! Origin is at the keel, on the centerline, midships.
+//!
+//! # Internal units
+//!
+//! All `Point3` coordinates are stored in **FEET**, weights in **POUNDS**.
+//!
+//! The hydrostatics assume a rectangular box barge with small angles of
+//! list and trim (initial stability), which is how barge crane lifts are
+//! normally planned.
+
+use crate::types::*;
+use nalgebra as na;
+
+/// Unit weight of seawater (lb/ft³)
+pub const SEAWATER_DENSITY: f64 = 64.0;
+
+/// Unit weight of fresh water (lb/ft³)
+pub const FRESHWATER_DENSITY: f64 = 62.4;
+
+/// Water the barge is floating in
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WaterType {
+    Sea,
+    Fresh,
+    /// Custom unit weight (lb/ft³)
+    Custom(f64),
+}
+
+impl WaterType {
+    /// Unit weight in lb/ft³
+    pub fn density(&self) -> f64 {
+        match self {
+            WaterType::Sea => SEAWATER_DENSITY,
+            WaterType::Fresh => FRESHWATER_DENSITY,
+            WaterType::Custom(d) => *d,
+        }
+    }
+}
+
+/// Rectangular barge hull
+#[derive(Debug, Clone)]
+pub struct Barge {
+    /// Length overall
+    pub length: Length,
+
+    /// Beam (width)
+    pub beam: Length,
+
+    /// Moulded depth (keel to deck)
+    pub depth: Length,
+
+    /// Lightship weight (empty barge)
+    pub lightship_weight: Mass,
+
+    /// Lightship center of gravity height above keel
+    pub lightship_kg: Length,
+
+    /// Water the barge is floating in
+    pub water: WaterType,
+}
+
+impl Barge {
+    pub fn new(
+        length: Length,
+        beam: Length,
+        depth: Length,
+        lightship_weight: Mass,
+        lightship_kg: Length,
+    ) -> Self {
+        Self {
+            length,
+            beam,
+            depth,
+            lightship_weight,
+            lightship_kg,
+            water: WaterType::Sea,
+        }
+    }
+}
+
+/// A weight carried on the barge (crane, load, ballast)
+#[derive(Debug, Clone)]
+pub struct FloatingWeight {
+    /// Name/identifier
+    pub name: String,
+
+    /// Weight
+    pub weight: Mass,
+
+    /// Center of gravity in barge coordinates (feet)
+    pub position: na::Point3<f64>,
+}
+
+/// Floating support analysis for a crane on a barge
+#[derive(Debug, Clone)]
+pub struct FloatingAnalysis {
+    /// Barge hull
+    pub barge: Barge,
+
+    /// Crane weight (excluding load)
+    pub crane_weight: Mass,
+
+    /// Crane center of gravity (barge coordinates, feet)
+    pub crane_cog: na::Point3<f64>,
+
+    /// Load weight
+    pub load_weight: Mass,
+
+    /// Point the load hangs from: the boom tip sheaves, barge coordinates,
+    /// feet. A freely suspended load's weight acts there as the barge heels,
+    /// not at the hook, so this is the height stability is figured with.
+    pub suspension_point: na::Point3<f64>,
+
+    /// Ballast and other deck weights
+    pub ballast: Vec<FloatingWeight>,
+}
+
+/// Result of floating stability analysis
+#[derive(Debug, Clone)]
+pub struct FloatingResult {
+    /// Total displacement (barge + crane + load + ballast)
+    pub displacement: Mass,
+
+    /// Mean draft
+    pub draft: Length,
+
+    /// Freeboard at zero list and trim
+    pub freeboard: Length,
+
+    /// Transverse metacentric height
+    pub gm_transverse: Length,
+
+    /// Longitudinal metacentric height
+    pub gm_longitudinal: Length,
+
+    /// List angle (positive = down to starboard)
+    pub list: Angle,
+
+    /// Trim angle (positive = down by the bow)
+    pub trim: Angle,
+
+    /// Combined out-of-level angle from list and trim
+    pub out_of_level: Angle,
+
+    /// Minimum freeboard at the lowest deck corner
+    pub min_freeboard: Length,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum FloatingError {
+    #[error("Invalid barge: {0}")]
+    InvalidBarge(String),
+
+    #[error("Negative metacentric height {gm} - barge is unstable")]
+    Unstable { gm: DisplayLength },
+
+    #[error("Draft {draft} exceeds barge depth {depth}")]
+    Submerged { draft: DisplayLength, depth: DisplayLength },
+
+    #[error("Deck edge immersed: minimum freeboard {freeboard}")]
+    DeckEdgeImmersed { freeboard: DisplayLength },
+
+    #[error("Out-of-level {actual} exceeds chart limit {limit}")]
+    ListExceedsLimit { actual: DisplayAngle, limit: DisplayAngle },
+
+    #[error("Ballast tank at {0} cannot counter the heeling moment")]
+    BallastWrongSide(DisplayLength),
+}
+
+impl FloatingAnalysis {
+    /// Create new analysis
+    ///
+    /// # Arguments
+    /// * `barge` - Barge hull
+    /// * `crane_weight` - Total crane weight
+    /// * `crane_cog` - Crane center of gravity
+    /// * `load_weight` - Load weight
+    /// * `suspension_point` - Boom tip the load hangs from
+    pub fn new(
+        barge: Barge,
+        crane_weight: Mass,
+        crane_cog: (Length, Length, Length),
+        load_weight: Mass,
+        suspension_point: (Length, Length, Length),
+    ) -> Self {
+        let (cx, cy, cz) = crane_cog;
+        let (lx, ly, lz) = suspension_point;
+
+        Self::new_na(
+            barge,
+            crane_weight,
+            point_from_uom_lengths(cx, cy, cz),
+            load_weight,
+            point_from_uom_lengths(lx, ly, lz),
+        )
+    }
+
+    /// Create new analysis using Point3 for raw coordinates (feet)
+    pub fn new_na(
+        barge: Barge,
+        crane_weight: Mass,
+        crane_cog: na::Point3<f64>,
+        load_weight: Mass,
+        suspension_point: na::Point3<f64>,
+    ) -> Self {
+        Self {
+            barge,
+            crane_weight,
+            crane_cog,
+            load_weight,
+            suspension_point,
+            ballast: Vec::new(),
+        }
+    }
+
+    /// Add ballast (or any other deck weight)
+    pub fn add_ballast(
+        &mut self,
+        name: impl Into<String>,
+        weight: Mass,
+        x: Length,
+        y: Length,
+        z: Length,
+    ) {
+        self.ballast.push(FloatingWeight {
+            name: name.into(),
+            weight,
+            position: point_from_uom_lengths(x, y, z),
+        });
+    }
+
+    /// All weights on the barge as (weight lb, position ft), including lightship
+    fn weights(&self) -> Vec<(f64, na::Point3<f64>)> {
+        let mut weights = vec![
+            (
+                self.barge.lightship_weight.get::<pound>(),
+                na::Point3::new(0.0, to_coord(self.barge.lightship_kg), 0.0),
+            ),
+            (self.crane_weight.get::<pound>(), self.crane_cog),
+            (self.load_weight.get::<pound>(), self.suspension_point),
+        ];
+        weights.extend(
+            self.ballast
+                .iter()
+                .map(|b| (b.weight.get::<pound>(), b.position)),
+        );
+        weights
+    }
+
+    /// Calculate draft, stability, list and trim
+    pub fn calculate(&self) -> Result<FloatingResult, FloatingError> {
+        let length = to_coord(self.barge.length);
+        let beam = to_coord(self.barge.beam);
+        let depth = to_coord(self.barge.depth);
+        let density = self.barge.water.density();
+
+        if length <= 0.0 || beam <= 0.0 || depth <= 0.0 || density <= 0.0 {
+            return Err(FloatingError::InvalidBarge(
+                "Length, beam, depth and water density must be positive".into(),
+            ));
+        }
+
+        let weights = self.weights();
+        let displacement: f64 = weights.iter().map(|(w, _)| w).sum();
+        if displacement <= 0.0 {
+            return Err(FloatingError::InvalidBarge("Zero displacement".into()));
+        }
+
+        // Box barge hydrostatics
+        let draft = displacement / (density * length * beam);
+        if draft >= depth {
+            return Err(FloatingError::Submerged {
+                draft: DisplayLength(from_coord(draft)),
+                depth: DisplayLength(self.barge.depth),
+            });
+        }
+
+        let kb = draft / 2.0;
+        let bm_t = beam.powi(2) / (12.0 * draft);
+        let bm_l = length.powi(2) / (12.0 * draft);
+
+        let moments = weights
+            .iter()
+            .map(|(w, p)| p.coords * *w)
+            .sum::<na::Vector3<f64>>();
+        let kg = moments.y / displacement;
+
+        let gm_t = kb + bm_t - kg;
+        let gm_l = kb + bm_l - kg;
+        if gm_t <= 0.0 {
+            return Err(FloatingError::Unstable { gm: DisplayLength(from_coord(gm_t)) });
+        }
+
+        // Heeling and trimming moments about the centerline / midships
+        let list = (moments.x / (displacement * gm_t)).atan();
+        let trim = (moments.z / (displacement * gm_l)).atan();
+        let out_of_level = (list.tan().powi(2) + trim.tan().powi(2)).sqrt().atan();
+
+        let freeboard = depth - draft;
+        let min_freeboard =
+            freeboard - (beam / 2.0) * list.tan().abs() - (length / 2.0) * trim.tan().abs();
+
+        Ok(FloatingResult {
+            displacement: Mass::new::<pound>(displacement),
+            draft: from_coord(draft),
+            freeboard: from_coord(freeboard),
+            gm_transverse: from_coord(gm_t),
+            gm_longitudinal: from_coord(gm_l),
+            list: Angle::new::<radian>(list),
+            trim: Angle::new::<radian>(trim),
+            out_of_level: Angle::new::<radian>(out_of_level),
+            min_freeboard: from_coord(min_freeboard),
+        })
+    }
+
+    /// Calculate and reject results with the deck edge in the water
+    pub fn validate(&self) -> Result<FloatingResult, FloatingError> {
+        let result = self.calculate()?;
+        if result.min_freeboard.get::<foot>() <= 0.0 {
+            return Err(FloatingError::DeckEdgeImmersed {
+                freeboard: DisplayLength(result.min_freeboard),
+            });
+        }
+        Ok(result)
+    }
+
+    /// Additional ballast required at a tank's lateral position to bring list to zero
+    ///
+    /// The tank must be on the opposite side of the centerline from the heeling moment.
+    pub fn ballast_to_level(&self, tank_x: Length) -> Result<Mass, FloatingError> {
+        let heeling_moment: f64 = self.weights().iter().map(|(w, p)| w * p.x).sum();
+        let arm = to_coord(tank_x);
+
+        if heeling_moment.abs() < 1e-6 {
+            return Ok(Mass::new::<pound>(0.0));
+        }
+
+        if arm.abs() < 1e-6 || arm.signum() == heeling_moment.signum() {
+            return Err(FloatingError::BallastWrongSide(DisplayLength(tank_x)));
+        }
+
+        Ok(Mass::new::<pound>(-heeling_moment / arm))
+    }
+}
+
+impl FloatingResult {
+    /// Radius increase caused by list and trim
+    ///
+    /// The hook hangs plumb while the crane tilts with the deck, so the
+    /// boom tip moves outboard when the barge heels toward the load.
+    ///
+    /// # Arguments
+    /// * `radius` - Radius measured with the barge level
+    /// * `tip_height` - Boom tip height above the slew pivot
+    /// * `swing` - Swing angle (0 = forward, 90° = starboard)
+    pub fn radius_increase(&self, radius: Length, tip_height: Length, swing: Angle) -> Length {
+        let swing = swing.get::<radian>();
+        let tilt = self.list.get::<radian>() * swing.sin() + self.trim.get::<radian>() * swing.cos();
+
+        let r = to_coord(radius);
+        let h = to_coord(tip_height);
+        from_coord(r * (tilt.cos() - 1.0) + h * tilt.sin())
+    }
+
+    /// Radius with the barge at its computed list and trim
+    pub fn effective_radius(&self, radius: Length, tip_height: Length, swing: Angle) -> Length {
+        radius + self.radius_increase(radius, tip_height, swing)
+    }
+}
+
+/// Manufacturer-style list derating table
+///
+/// Barge charts are published for a maximum list; capacities at
+/// intermediate angles are reduced from the land (level) chart.
+#[derive(Debug, Clone)]
+pub struct ListDerating {
+    /// (out-of-level degrees, capacity factor) pairs in ascending order
+    pub points: Vec<(f64, f64)>,
+}
+
+impl ListDerating {
+    pub fn new(points: Vec<(f64, f64)>) -> Self {
+        Self { points }
+    }
+
+    /// Typical values for a telescopic or lattice crane on a barge
+    ///
+    /// Use the manufacturer's barge chart where one is published.
+    pub fn standard() -> Self {
+        Self::new(vec![
+            (0.0, 1.00),
+            (1.0, 0.90),
+            (2.0, 0.80),
+            (3.0, 0.70),
+            (5.0, 0.50),
+        ])
+    }
+
+    /// Maximum out-of-level angle covered by the table
+    pub fn max_angle(&self) -> Angle {
+        Angle::new::<degree>(self.points.last().map(|p| p.0).unwrap_or(0.0))
+    }
+
+    /// Capacity factor at the given out-of-level angle
+    ///
+    /// Returns 0.0 beyond the end of the table (lift not permitted).
+    pub fn factor(&self, out_of_level: Angle) -> f64 {
+        let deg = out_of_level.get::<degree>().abs();
+
+        let Some(first) = self.points.first() else {
+            return 0.0;
+        };
+        if deg <= first.0 {
+            return first.1;
+        }
+
+        for pair in self.points.windows(2) {
+            let (a0, f0) = pair[0];
+            let (a1, f1) = pair[1];
+            if deg <= a1 {
+                let ratio = (deg - a0) / (a1 - a0);
+                return f0 + ratio * (f1 - f0);
+            }
+        }
+
+        0.0
+    }
+
+    /// Derated capacity for a floating result
+    pub fn derated_capacity(
+        &self,
+        rated_capacity: Mass,
+        result: &FloatingResult,
+    ) -> Result<Mass, FloatingError> {
+        if result.out_of_level > self.max_angle() {
+            return Err(FloatingError::ListExceedsLimit {
+                actual: DisplayAngle(result.out_of_level),
+                limit: DisplayAngle(self.max_angle()),
+            });
+        }
+
+        let factor = self.factor(result.out_of_level);
+        Ok(Mass::new::<pound>(rated_capacity.get::<pound>() * factor))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn test_barge() -> Barge {
+        Barge::new(
+            Length::new::<foot>(200.0),
+            Length::new::<foot>(60.0),
+            Length::new::<foot>(12.0),
+            Mass::new::<pound>(1_000_000.0),
+            Length::new::<foot>(6.0),
+        )
+    }
+
+    // Load hanging from a boom tip 40 ft up
+    fn test_analysis(load_x: f64) -> FloatingAnalysis {
+        FloatingAnalysis::new(
+            test_barge(),
+            Mass::new::<pound>(300_000.0),
+            (Length::new::<foot>(0.0), Length::new::<foot>(18.0), Length::new::<foot>(0.0)),
+            Mass::new::<pound>(50_000.0),
+            (Length::new::<foot>(load_x), Length::new::<foot>(40.0), Length::new::<foot>(0.0)),
+        )
+    }
+
+    #[test]
+    fn test_centered_load_no_list() {
+        let result = test_analysis(0.0).validate().unwrap();
+
+        // 1.35M lb / (64 * 200 * 60) = 1.758 ft
+        assert_relative_eq!(result.draft.get::<foot>(), 1.758, epsilon = 0.001);
+        assert_relative_eq!(result.list.get::<degree>(), 0.0);
+        assert!(result.gm_transverse.get::<foot>() > 0.0);
+
+        // The hanging load acts at the tip: a higher tip costs GM however low the hook is
+        let mut higher = test_analysis(0.0);
+        higher.suspension_point.y = 80.0;
+        let higher = higher.calculate().unwrap();
+        assert!(higher.gm_transverse < result.gm_transverse);
+    }
+
+    #[test]
+    fn test_offset_load_lists_toward_load() {
+        let result = test_analysis(50.0).validate().unwrap();
+
+        assert!(result.list.get::<degree>() > 0.0);
+        assert!(result.min_freeboard < result.freeboard);
+
+        // Heeling toward a load at 90° swing pushes the radius out
+        let increase = result.radius_increase(
+            Length::new::<foot>(50.0),
+            Length::new::<foot>(80.0),
+            Angle::new::<degree>(90.0),
+        );
+        assert!(increase.get::<foot>() > 0.0);
+    }
+
+    #[test]
+    fn test_ballast_to_level() {
+        let mut analysis = test_analysis(50.0);
+        let ballast = analysis.ballast_to_level(Length::new::<foot>(-25.0)).unwrap();
+
+        // 50,000 lb at 50 ft needs 100,000 lb at 25 ft
+        assert_relative_eq!(ballast.get::<pound>(), 100_000.0, epsilon = 0.1);
+
+        analysis.add_ballast(
+            "Port tank",
+            ballast,
+            Length::new::<foot>(-25.0),
+            Length::new::<foot>(2.0),
+            Length::new::<foot>(0.0),
+        );
+        let result = analysis.calculate().unwrap();
+        assert_relative_eq!(result.list.get::<degree>(), 0.0, epsilon = 1e-9);
+
+        assert!(analysis.ballast_to_level(Length::new::<foot>(25.0)).is_ok());
+    }
+
+    #[test]
+    fn test_list_derating() {
+        let derating = ListDerating::standard();
+
+        assert_relative_eq!(derating.factor(Angle::new::<degree>(0.0)), 1.0);
+        assert_relative_eq!(derating.factor(Angle::new::<degree>(1.5)), 0.85);
+        assert_relative_eq!(derating.factor(Angle::new::<degree>(6.0)), 0.0);
+
+        let result = test_analysis(50.0).calculate().unwrap();
+        let derated = derating
+            .derated_capacity(Mass::new::<pound>(100_000.0), &result)
+            .unwrap();
+        assert!(derated.get::<pound>() < 100_000.0);
+    }
+}
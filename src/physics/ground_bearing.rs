@@ -17,8 +17,14 @@
 //!
 //! Public APIs accept UOM types and convert at boundaries
 
+use crate::physics::soil::SoilConditions;
+use crate::physics::stability::rigid_base_reactions;
+use crate::report::*;
+pub use crate::physics::soil::soil_capacities;
 use crate::types::*;
 use nalgebra as na;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 
 /// Ground bearing pressure calculation and validation
@@ -38,6 +44,35 @@ pub struct GroundBearingAnalysis {
 
     /// Load position (hook position)
     pub load_position: na::Point3<f64>,
+
+    /// Impact factor on the load for hoisting and travel dynamics; the
+    /// crane's own weight is not factored
+    pub dynamic_factor: f64,
+
+    /// Ring the supports sit on, for ring and pedestal cranes
+    pub ring: Option<RingSupport>,
+}
+
+/// Continuous ring of rollers or pads under a ring crane
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RingSupport {
+    /// Ring center in crane coordinates (feet); the upper works slew about it
+    pub center: na::Point3<f64>,
+    pub radius: Length,
+
+    /// Rollers or pads, equally spaced with the first straight ahead
+    pub pads: usize,
+
+    /// Bearing area under each pad
+    pub pad_area: Area,
+}
+
+impl RingSupport {
+    /// Ring length served by each pad
+    pub fn pad_spacing(&self) -> Length {
+        self.radius * std::f64::consts::TAU / self.pads as f64
+    }
 }
 
 /// A support point (outrigger or track content contact point)
@@ -51,10 +86,42 @@ pub struct SupportPoint {
 
     /// Name/identifier
     pub name: String,
+
+    /// Outrigger float, if the support is one
+    pub float: Option<FloatDimensions>,
+}
+
+/// Footprint of an outrigger float (pad)
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FloatDimensions {
+    pub length: Length,
+    pub width: Length,
+}
+
+impl FloatDimensions {
+    pub fn square(side: Length) -> Self {
+        Self {
+            length: side,
+            width: side,
+        }
+    }
+
+    pub fn area(&self) -> Area {
+        self.length * self.width
+    }
+}
+
+impl Default for FloatDimensions {
+    /// 2 ft square float
+    fn default() -> Self {
+        Self::square(Length::new::<foot>(2.0))
+    }
 }
 
 /// Result of ground bearing analysis
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GroundBearingResult {
     /// Reaction force at each support point
     pub reactions: Vec<SupportReaction>,
@@ -62,19 +129,58 @@ pub struct GroundBearingResult {
     /// Maximum reaction force
     pub max_reaction: Force,
 
-    /// Maximum ground pressure
+    /// Maximum ground pressure, with the dynamic factor applied
     pub max_pressure: Pressure,
 
+    /// Maximum reaction with the load at its static weight
+    pub static_max_reaction: Force,
+
+    /// Maximum ground pressure with the load at its static weight
+    pub static_max_pressure: Pressure,
+
+    /// Dynamic factor applied to the load
+    pub dynamic_factor: f64,
+
     /// Index of most loaded support
     pub critical_support_index: usize,
+
+    /// Allowable pressure the supports were checked against, if any
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub allowable_pressure: Option<Pressure>,
+
+    /// Site conditions that reduced the allowable or need attention
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub warnings: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SupportReaction {
     pub name: String,
     pub force: Force,
     pub pressure: Pressure,
     pub contact_area: Area,
+
+    /// Pressure as a percentage of allowable; `None` until checked
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub utilization: Option<f64>,
+
+    /// Pressure within allowable; `None` until checked
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub passes: Option<bool>,
+}
+
+impl SupportReaction {
+    fn new(name: String, force: Force, contact_area: Area) -> Self {
+        Self {
+            name,
+            force,
+            pressure: Pressure::new::<psi>(force.get::<pound_force>() / contact_area.get::<square_inch>()),
+            contact_area,
+            utilization: None,
+            passes: None,
+        }
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -139,9 +245,17 @@ impl GroundBearingAnalysis {
             crane_cog,
             load_weight,
             load_position,
+            dynamic_factor: 1.0,
+            ring: None,
         }
     }
 
+    /// Scale the load for hoisting or travel dynamics
+    pub fn with_dynamic_factor(mut self, factor: f64) -> Self {
+        self.dynamic_factor = factor;
+        self
+    }
+
     /// Add a support point (outrigger)
     pub fn add_support(
         &mut self,
@@ -174,9 +288,71 @@ impl GroundBearingAnalysis {
             position,
             contact_area,
             name: name.into(),
+            float: None,
+        });
+    }
+
+    /// Add an outrigger float, bearing directly on the ground or on a mat
+    ///
+    /// # Arguments
+    /// * `name` - Name of the support
+    /// * `position` - Center of the float (feet)
+    /// * `float` - Float footprint
+    /// * `mat_area` - Mat under the float, if any; replaces the float area
+    pub fn add_float_na(
+        &mut self,
+        name: impl Into<String>,
+        position: na::Point3<f64>,
+        float: FloatDimensions,
+        mat_area: Option<Area>,
+    ) {
+        self.support_points.push(SupportPoint {
+            position,
+            contact_area: mat_area.unwrap_or_else(|| float.area()),
+            name: name.into(),
+            float: Some(float),
         });
     }
 
+    /// Stand the crane on a ring, replacing any other supports
+    pub fn set_ring(&mut self, ring: RingSupport) {
+        let r = ring.radius.get::<foot>();
+        self.support_points = (0..ring.pads)
+            .map(|i| {
+                let (sin, cos) = (std::f64::consts::TAU * i as f64 / ring.pads as f64).sin_cos();
+                SupportPoint {
+                    position: ring.center + na::Vector3::new(r * sin, 0.0, r * cos),
+                    contact_area: ring.pad_area,
+                    name: format!("Ring {}", i + 1),
+                    float: None,
+                }
+            })
+            .collect();
+        self.ring = Some(ring);
+    }
+
+    /// Reactions with the upper works (crane COG and load) slewed through a
+    /// full turn about the ring center in `steps` increments
+    pub fn slew_ring(&self, steps: usize) -> Result<Vec<(Angle, GroundBearingResult)>, GroundBearingError> {
+        let ring = self.ring.ok_or_else(|| {
+            GroundBearingError::InvalidConfiguration("Slewing needs a ring support".into())
+        })?;
+        let steps = steps.max(1);
+        (0..steps)
+            .map(|i| {
+                let angle = Angle::new::<degree>(360.0 * i as f64 / steps as f64);
+                let rotation = na::Rotation3::from_axis_angle(&na::Vector3::y_axis(), angle.get::<radian>());
+                let slew = |p: na::Point3<f64>| ring.center + rotation * (p - ring.center);
+                let slewed = Self {
+                    crane_cog: slew(self.crane_cog),
+                    load_position: slew(self.load_position),
+                    ..self.clone()
+                };
+                Ok((angle, slewed.calculate_reactions()?))
+            })
+            .collect()
+    }
+
     /// Get crane COG
     pub fn crane_cog(&self) -> (Length, Length, Length) {
         (
@@ -192,21 +368,37 @@ impl GroundBearingAnalysis {
             return Err(GroundBearingError::InsufficientSupports);
         }
 
+        let load_lb = self.load_weight.get::<pound>();
+        let mut result = self.reactions_for_load(load_lb * self.dynamic_factor)?;
+        if self.dynamic_factor != 1.0 {
+            let static_result = self.reactions_for_load(load_lb)?;
+            result.static_max_reaction = static_result.max_reaction;
+            result.static_max_pressure = static_result.max_pressure;
+        }
+        result.dynamic_factor = self.dynamic_factor;
+        Ok(result)
+    }
+
+    fn reactions_for_load(&self, load_lb: f64) -> Result<GroundBearingResult, GroundBearingError> {
+        if self.ring.is_some() {
+            return self.calculate_ring_reactions(load_lb);
+        }
+
         // For 4-point support (most common case), use simplified analytical solution 
         if self.support_points.len() == 4 {
-            return self.calculate_four_point_reactions();
+            return self.calculate_four_point_reactions(load_lb);
         }
 
         // For other cases, use general method
-        self.calculate_general_reactions()
+        self.calculate_general_reactions(load_lb)
     }
 
     /// Simplified calculations for 4 outriggers (most common case)
-    fn calculate_four_point_reactions(&self) -> Result<GroundBearingResult, GroundBearingError> {
-        let total_weight = self.crane_weight.get::<pound>() + self.load_weight.get::<pound>();
+    fn calculate_four_point_reactions(&self, load_lb: f64) -> Result<GroundBearingResult, GroundBearingError> {
+        let total_weight = self.crane_weight.get::<pound>() + load_lb;
 
         let crane_moment = self.crane_cog.coords * self.crane_weight.get::<pound>();
-        let load_moment = self.load_position.coords * self.load_weight.get::<pound>();
+        let load_moment = self.load_position.coords * load_lb;
         
         let combined_cog = (crane_moment + load_moment) / total_weight;
 
@@ -219,30 +411,70 @@ impl GroundBearingAnalysis {
         let mut critical_idx = 0;
 
         for (i, (support, &reaction_lb)) in self.support_points.iter().zip(reactions.iter()).enumerate() {
-            let reaction = Force::new::<pound_force>(reaction_lb);
-            let area_sq_in = support.contact_area.get::<square_inch>();
-            let pressure = Pressure::new::<psi>(reaction_lb / area_sq_in);
-
-            if reaction > max_reaction {
-                max_reaction = reaction;
-                max_pressure = pressure;
+            let reaction = SupportReaction::new(
+                support.name.clone(),
+                Force::new::<pound_force>(reaction_lb),
+                support.contact_area,
+            );
+
+            if reaction.force > max_reaction {
+                max_reaction = reaction.force;
+                max_pressure = reaction.pressure;
                 critical_idx = i;
             }
 
-            support_reactions.push(SupportReaction {
-                name: support.name.clone(),
-                force: reaction,
-                pressure,
-                contact_area: support.contact_area,
-            });
+            support_reactions.push(reaction);
         }
 
         Ok(GroundBearingResult { 
             reactions: support_reactions,
             max_reaction,
             max_pressure,
+            static_max_reaction: max_reaction,
+            static_max_pressure: max_pressure,
+            dynamic_factor: 1.0,
             critical_support_index: critical_idx,
+            allowable_pressure: None,
+            warnings: Vec::new(),
+        })
+    }
+
+    /// Ring as a rigid base on equally stiff pads
+    fn calculate_ring_reactions(&self, load_lb: f64) -> Result<GroundBearingResult, GroundBearingError> {
+        let crane_lb = self.crane_weight.get::<pound>();
+        let total_weight = crane_lb + load_lb;
+        let cog = (self.crane_cog.coords * crane_lb + self.load_position.coords * load_lb) / total_weight;
+        let positions: Vec<na::Point3<f64>> = self.support_points.iter().map(|s| s.position).collect();
+        let reactions = rigid_base_reactions(&positions, na::Point3::from(cog), total_weight);
 
+        let mut support_reactions = Vec::with_capacity(reactions.len());
+        let mut critical_idx = 0;
+        for (i, (support, &reaction_lb)) in self.support_points.iter().zip(&reactions).enumerate() {
+            if reaction_lb < 0.0 {
+                return Err(GroundBearingError::UnstableConfiguration(support.name.clone()));
+            }
+            if reaction_lb > reactions[critical_idx] {
+                critical_idx = i;
+            }
+            support_reactions.push(SupportReaction::new(
+                support.name.clone(),
+                Force::new::<pound_force>(reaction_lb),
+                support.contact_area,
+            ));
+        }
+
+        let max_reaction = support_reactions[critical_idx].force;
+        let max_pressure = support_reactions[critical_idx].pressure;
+        Ok(GroundBearingResult {
+            reactions: support_reactions,
+            max_reaction,
+            max_pressure,
+            static_max_reaction: max_reaction,
+            static_max_pressure: max_pressure,
+            dynamic_factor: 1.0,
+            critical_support_index: critical_idx,
+            allowable_pressure: None,
+            warnings: Vec::new(),
         })
     }
 
@@ -310,9 +542,9 @@ impl GroundBearingAnalysis {
     }
 
     /// General method for any number of supports
-    fn calculate_general_reactions(&self) -> Result<GroundBearingResult, GroundBearingError> {
+    fn calculate_general_reactions(&self, load_lb: f64) -> Result<GroundBearingResult, GroundBearingError> {
         // Conservative approach: assume worst-case loading
-        let total_weight = self.crane_weight.get::<pound>() + self.load_weight.get::<pound>();
+        let total_weight = self.crane_weight.get::<pound>() + load_lb;
         let worst_case_reaction = Force::new::<pound_force>(total_weight);
 
         let mut critical_idx = 0;
@@ -326,36 +558,51 @@ impl GroundBearingAnalysis {
             }
         }
 
-        let critical_support = &self.support_points[critical_idx];
-        let area_sq_in = critical_support.contact_area.get::<square_inch>();
-        let worst_pressure = Pressure::new::<psi>(total_weight / area_sq_in);
-
-        let mut support_reactions = Vec::new();
-        for (i, support) in self.support_points.iter().enumerate() {
-            let reaction = if i == critical_idx {
-                worst_case_reaction
-            } else {
-                Force::new::<pound_force>(0.0)
-            };
-
-            let area = support.contact_area.get::<uom::si::area::square_inch>();
-            let pressure = Pressure::new::<psi>(reaction.get::<pound_force>() / area);
-            support_reactions.push(SupportReaction {
-                name: support.name.clone(),
-                force: reaction,
-                pressure,
-                contact_area: support.contact_area,
-            });
-        }
+        let support_reactions: Vec<SupportReaction> = self
+            .support_points
+            .iter()
+            .enumerate()
+            .map(|(i, support)| {
+                let reaction = if i == critical_idx {
+                    worst_case_reaction
+                } else {
+                    Force::new::<pound_force>(0.0)
+                };
+                SupportReaction::new(support.name.clone(), reaction, support.contact_area)
+            })
+            .collect();
 
+        let max_pressure = support_reactions[critical_idx].pressure;
         Ok(GroundBearingResult {
-            reactions: support_reactions,
             max_reaction: worst_case_reaction,
-            max_pressure: worst_pressure,
+            max_pressure,
+            static_max_reaction: worst_case_reaction,
+            static_max_pressure: max_pressure,
+            dynamic_factor: 1.0,
+            reactions: support_reactions,
             critical_support_index: critical_idx,
+            allowable_pressure: None,
+            warnings: Vec::new(),
         })
     }
 
+    /// Calculate reactions and check each support against an allowable pressure
+    pub fn evaluate(&self, allowable_pressure: Pressure) -> Result<GroundBearingResult, GroundBearingError> {
+        Ok(self.calculate_reactions()?.with_allowable(allowable_pressure))
+    }
+
+    /// [`evaluate`](Self::evaluate) with the allowable reduced for site
+    /// conditions, whose warnings are carried into the result
+    pub fn evaluate_with_conditions(
+        &self,
+        allowable_pressure: Pressure,
+        conditions: &SoilConditions,
+    ) -> Result<GroundBearingResult, GroundBearingError> {
+        let mut result = self.evaluate(conditions.apply(allowable_pressure))?;
+        result.warnings = conditions.warnings();
+        Ok(result)
+    }
+
     /// Validate against allowable soid bearing pressure
     pub fn validate_soil_capacity(
         &self, 
@@ -392,6 +639,23 @@ impl GroundBearingAnalysis {
 }
 
 impl GroundBearingResult {
+    /// Fill in per-support utilization and pass/fail against an allowable pressure
+    pub fn with_allowable(mut self, allowable: Pressure) -> Self {
+        for reaction in &mut self.reactions {
+            let utilization = reaction.pressure.get::<psi>() / allowable.get::<psi>() * 100.0;
+            reaction.utilization = Some(utilization);
+            reaction.passes = Some(utilization <= 100.0);
+        }
+        self.allowable_pressure = Some(allowable);
+        self
+    }
+
+    /// Every support within allowable; `None` until checked
+    pub fn passes(&self) -> Option<bool> {
+        self.allowable_pressure?;
+        Some(self.reactions.iter().all(|r| r.passes == Some(true)))
+    }
+
     /// Format results for display
     pub fn summary(&self) -> String {
         let mut s = String::new();
@@ -402,58 +666,75 @@ impl GroundBearingResult {
             self.max_reaction.get::<pound_force>()));
         s.push_str(&format!(" Max Pressure: {:.1} PSI\n", 
             self.max_pressure.get::<psi>()));
+        if self.dynamic_factor != 1.0 {
+            s.push_str(&format!(" Static Max Pressure: {:.1} PSI (dynamic factor {:.2})\n",
+                self.static_max_pressure.get::<psi>(), self.dynamic_factor));
+        }
 
         s.push_str("\nAll Supports:\n");
         for reaction in &self.reactions {
-            s.push_str(&format!(" {}: {:.0} lbs ({:.1} PSI over {:.1} sq ft)\n", 
+            s.push_str(&format!(" {}: {:.0} lbs ({:.1} PSI over {:.1} sq ft)", 
                 reaction.name,
                 reaction.force.get::<pound_force>(),
                 reaction.pressure.get::<psi>(),
                 reaction.contact_area.get::<square_foot>(),
             ));
+            if let (Some(utilization), Some(passes)) = (reaction.utilization, reaction.passes) {
+                s.push_str(&format!(" {:.1}% {}", utilization, if passes { "PASS" } else { "FAIL" }));
+            }
+            s.push('\n');
+        }
+        for warning in &self.warnings {
+            s.push_str(&format!("WARNING: {}\n", warning));
         }
         s
     }
 }
 
-/// Common soil bearing capabilities
-pub mod soil_capacities {
-    use crate::types::*;
-
-    pub fn soft_clay() -> Pressure {
-        Pressure::new::<psi>(10.0)
-    }
-
-    pub fn medium_clay() -> Pressure {
-        Pressure::new::<psi>(25.0)
-    }
-
-    pub fn stiff_clay() -> Pressure {
-        Pressure::new::<psi>(40.0)
-    }
-
-    pub fn loose_sand() -> Pressure {
-        Pressure::new::<psi>(20.0)
-    }
-
-    pub fn dense_sand() -> Pressure {
-        Pressure::new::<psi>(50.0)
-    }
-
-    pub fn gravel() -> Pressure {
-        Pressure::new::<psi>(80.0)
-    }
-
-    pub fn soft_rock() -> Pressure {
-        Pressure::new::<psi>(150.0)
+impl AnalysisReport for GroundBearingResult {
+    fn title(&self) -> String {
+        "Ground Bearing".into()
     }
 
-    pub fn hard_rock() -> Pressure {
-        Pressure::new::<psi>(300.0)
+    /// One check per support; supports pass until checked against an
+    /// allowable pressure
+    fn checks(&self) -> Vec<ValidationCheck> {
+        self.reactions
+            .iter()
+            .map(|r| ValidationCheck {
+                name: r.name.clone(),
+                status: match r.passes {
+                    Some(false) => CheckStatus::Fail,
+                    _ => CheckStatus::Pass,
+                },
+                details: format!(
+                    "{:.0} lbs, {:.1} PSI over {:.1} sq ft",
+                    r.force.get::<pound_force>(),
+                    r.pressure.get::<psi>(),
+                    r.contact_area.get::<square_foot>()
+                ),
+                margin: r.utilization.map(|u| 100.0 - u),
+            })
+            .chain(self.warnings.iter().map(|warning| ValidationCheck {
+                name: "Ground Conditions".into(),
+                status: CheckStatus::Warning,
+                details: warning.clone(),
+                margin: None,
+            }))
+            .collect()
     }
 
-    pub fn paved_surface() -> Pressure {
-        Pressure::new::<psi>(100.0)
+    fn results(&self) -> Vec<NumericResult> {
+        let mut results = vec![
+            NumericResult::new("max_reaction", self.max_reaction.get::<pound_force>(), "lbf"),
+            NumericResult::new("max_pressure", self.max_pressure.get::<psi>(), "psi"),
+            NumericResult::new("static_max_pressure", self.static_max_pressure.get::<psi>(), "psi"),
+            NumericResult::new("dynamic_factor", self.dynamic_factor, ""),
+        ];
+        if let Some(allowable) = self.allowable_pressure {
+            results.push(NumericResult::new("allowable_pressure", allowable.get::<psi>(), "psi"));
+        }
+        results
     }
 }
 
@@ -486,6 +767,122 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_evaluate_against_allowable() {
+        let mut analysis = GroundBearingAnalysis::new_na(
+            Mass::new::<pound>(100000.0),
+            na::Point3::origin(),
+            Mass::new::<pound>(20000.0),
+            na::Point3::new(0.0, 50.0, 30.0),
+        );
+        let pad_area = Area::new::<square_foot>(4.0);
+        for (name, x, z) in [("FR", 10.0, 10.0), ("FL", -10.0, 10.0), ("RR", 10.0, -10.0), ("RL", -10.0, -10.0)] {
+            analysis.add_support_na(name, na::Point3::new(x, 0.0, z), pad_area);
+        }
+
+        let unchecked = analysis.calculate_reactions().unwrap();
+        assert!(unchecked.passes().is_none());
+        assert!(unchecked.reactions.iter().all(|r| r.utilization.is_none()));
+
+        // Front pads carry 45,000 lbs: 78.1 psi, over a 75 psi allowable; rear 15,000 lbs
+        let result = analysis.evaluate(Pressure::new::<psi>(75.0)).unwrap();
+        assert_eq!(result.passes(), Some(false));
+        let front = &result.reactions[0];
+        assert_relative_eq!(front.utilization.unwrap(), 45000.0 / 576.0 / 75.0 * 100.0, epsilon = 1e-9);
+        assert_eq!(front.passes, Some(false));
+        assert_eq!(result.reactions[2].passes, Some(true));
+        assert!(result.summary().contains("FAIL"));
+
+        #[cfg(feature = "serde")]
+        {
+            let json = serde_json::to_string(&result).unwrap();
+            let restored: GroundBearingResult = serde_json::from_str(&json).unwrap();
+            assert_eq!(restored.reactions[0].passes, Some(false));
+            assert_relative_eq!(restored.max_pressure.get::<psi>(), result.max_pressure.get::<psi>(), epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_dynamic_factor_on_load() {
+        let mut analysis = GroundBearingAnalysis::new_na(
+            Mass::new::<pound>(100000.0),
+            na::Point3::origin(),
+            Mass::new::<pound>(20000.0),
+            na::Point3::new(0.0, 50.0, 30.0),
+        )
+        .with_dynamic_factor(1.25);
+        let pad_area = Area::new::<square_foot>(4.0);
+        for (name, x, z) in [("FR", 10.0, 10.0), ("FL", -10.0, 10.0), ("RR", 10.0, -10.0), ("RL", -10.0, -10.0)] {
+            analysis.add_support_na(name, na::Point3::new(x, 0.0, z), pad_area);
+        }
+
+        // 25,000 lbs dynamic load puts 50,000 lbs on each front pad; 45,000 lbs static
+        let result = analysis.calculate_reactions().unwrap();
+        assert_relative_eq!(result.max_reaction.get::<pound_force>(), 50000.0, epsilon = 1e-6);
+        assert_relative_eq!(result.static_max_reaction.get::<pound_force>(), 45000.0, epsilon = 1e-6);
+        assert_relative_eq!(result.static_max_pressure.get::<psi>(), 45000.0 / 576.0, epsilon = 1e-9);
+        assert_relative_eq!(result.dynamic_factor, 1.25);
+        assert!(result.summary().contains("Static Max Pressure"));
+    }
+
+    #[test]
+    fn test_ring_reactions_follow_slew() {
+        // Upper works balanced over the center, 100,000 lbs at 80 ft ahead
+        let mut analysis = GroundBearingAnalysis::new_na(
+            Mass::new::<pound>(1_100_000.0),
+            na::Point3::new(0.0, 30.0, 0.0),
+            Mass::new::<pound>(100_000.0),
+            na::Point3::new(0.0, 100.0, 80.0),
+        );
+        analysis.set_ring(RingSupport {
+            center: na::Point3::origin(),
+            radius: Length::new::<foot>(30.0),
+            pads: 12,
+            pad_area: Area::new::<square_foot>(20.0),
+        });
+        assert_eq!(analysis.support_points.len(), 12);
+
+        // COG 80 × 100k / 1.2M forward; Σz² = 5,400 ft²
+        let result = analysis.calculate_reactions().unwrap();
+        assert_eq!(result.critical_support_index, 0);
+        let e = 80.0 * 100_000.0 / 1_200_000.0;
+        assert_relative_eq!(
+            result.max_reaction.get::<pound_force>(),
+            100_000.0 + 1_200_000.0 * e * 30.0 / 5400.0,
+            max_relative = 1e-9
+        );
+        let total: f64 = result.reactions.iter().map(|r| r.force.get::<pound_force>()).sum();
+        assert_relative_eq!(total, 1_200_000.0, max_relative = 1e-9);
+
+        // Slewed a quarter turn the peak moves three pads round to the right
+        let slew = analysis.slew_ring(4).unwrap();
+        assert_relative_eq!(slew[1].0.get::<degree>(), 90.0);
+        assert_eq!(slew[1].1.critical_support_index, 3);
+        assert_relative_eq!(slew[1].1.max_reaction.get::<pound_force>(), result.max_reaction.get::<pound_force>(), max_relative = 1e-9);
+    }
+
+    #[test]
+    fn test_soil_conditions_reduce_allowable() {
+        let mut analysis = GroundBearingAnalysis::new_na(
+            Mass::new::<pound>(100000.0),
+            na::Point3::origin(),
+            Mass::new::<pound>(20000.0),
+            na::Point3::new(0.0, 50.0, 30.0),
+        );
+        let pad_area = Area::new::<square_foot>(4.0);
+        for (name, x, z) in [("FR", 10.0, 10.0), ("FL", -10.0, 10.0), ("RR", 10.0, -10.0), ("RL", -10.0, -10.0)] {
+            analysis.add_support_na(name, na::Point3::new(x, 0.0, z), pad_area);
+        }
+
+        // Front pads at 78.1 psi pass 100 psi dry, fail at 50 psi saturated
+        let saturated = SoilConditions { saturated: true, ..Default::default() };
+        assert_eq!(analysis.evaluate(Pressure::new::<psi>(100.0)).unwrap().passes(), Some(true));
+        let result = analysis.evaluate_with_conditions(Pressure::new::<psi>(100.0), &saturated).unwrap();
+        assert_eq!(result.passes(), Some(false));
+        assert_relative_eq!(result.allowable_pressure.unwrap().get::<psi>(), 50.0, epsilon = 1e-9);
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.checks().iter().any(|c| c.name == "Ground Conditions" && c.status == CheckStatus::Warning));
+    }
 }
 
 
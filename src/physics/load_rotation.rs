@@ -0,0 +1,253 @@
+//! Wind torque on suspended loads and the rotation it drives
+//!
+//! A load whose sail area is not balanced about the hook turns in the wind.
+//! How far, and how fast, depends on what resists the turn:
+//!
+//! - a single-part line offers only the rope's own torsional stiffness,
+//!   GJ / L, which is small and often lets the load spin;
+//! - two or more falls spread apart lift the load slightly as it turns,
+//!   giving a restoring torque of W · b² / (4L) · sin θ for falls `b`
+//!   apart. It peaks at W · b² / (4L) a quarter turn round, and a wind
+//!   torque above that turns the load however stiff it starts;
+//! - a spreader beam or twin-hook pick does the same with a much wider
+//!   spread.
+//!
+//! Rotations are small-angle: under a suddenly applied wind torque the
+//! load overshoots to twice its static rotation and passes the static
+//! position at ω_n · θ_static.
+
+use crate::physics::wind_loading::{AIR_DENSITY, LOAD_DRAG_COEFFICIENT};
+use crate::types::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Wind-facing area at a horizontal arm from the hook, positive on one
+/// side and negative on the other
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WindPanel {
+    pub area: Area,
+    pub arm: Length,
+}
+
+/// What resists the load turning
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RotationalRestraint {
+    /// Single-part line; torsional rigidity GJ from the rope maker, lbf·ft² per radian
+    SinglePart { torsional_rigidity: f64 },
+
+    /// Multi-part reeving with the falls `fall_spread` apart at the block
+    MultiPart { fall_spread: Length },
+
+    /// Spreader beam or twin hooks picking `spread` apart
+    TwoPoint { spread: Length },
+}
+
+impl RotationalRestraint {
+    /// Restoring torque per radian, lbf·ft, for `hook_load` hanging `length` below the tip
+    pub fn stiffness(&self, hook_load: Mass, length: Length) -> f64 {
+        let length = length.get::<foot>().max(1e-6);
+        let weight = weight_of(hook_load).get::<pound_force>();
+        match *self {
+            RotationalRestraint::SinglePart { torsional_rigidity } => torsional_rigidity / length,
+            RotationalRestraint::MultiPart { fall_spread: spread } | RotationalRestraint::TwoPoint { spread } => {
+                let b = spread.get::<foot>();
+                weight * b * b / (4.0 * length)
+            }
+        }
+    }
+
+    /// Largest torque the restraint can resist, or `None` if it keeps
+    /// rising with rotation
+    ///
+    /// Spread falls give W · b² / (4L) · sin θ, which tops out at the
+    /// small-angle stiffness times one radian.
+    pub fn max_restoring_torque(&self, hook_load: Mass, length: Length) -> Option<Torque> {
+        match self {
+            RotationalRestraint::SinglePart { .. } => None,
+            RotationalRestraint::MultiPart { .. } | RotationalRestraint::TwoPoint { .. } => {
+                Some(Torque::new::<pound_force_foot>(self.stiffness(hook_load, length)))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct LoadRotationAnalysis {
+    pub wind_torque: Torque,
+
+    /// Restoring torque per radian, lbf·ft
+    pub stiffness: f64,
+
+    /// Largest torque the restraint resists, `None` for a single-part line
+    pub max_restoring_torque: Option<Torque>,
+
+    /// Rotation where the restraint balances the wind
+    pub static_rotation: Angle,
+
+    /// Largest rotation after a gust, twice the static rotation
+    pub peak_rotation: Angle,
+
+    /// Natural period of torsional oscillation, seconds
+    pub period: f64,
+
+    /// Rotation rate as the load swings through the static position
+    pub peak_rate: AngularVelocity,
+}
+
+impl LoadRotationAnalysis {
+    /// Whether the peak rotation stays within `limit`
+    pub fn is_controlled(&self, limit: Angle) -> bool {
+        self.peak_rotation <= limit
+    }
+
+    /// Whether the load would keep turning
+    ///
+    /// With spread falls that is a wind torque above the restraint's
+    /// largest restoring torque; a single-part line has no such limit, so
+    /// the load spins if it would turn past a full revolution.
+    pub fn spins(&self) -> bool {
+        match self.max_restoring_torque {
+            Some(limit) => self.wind_torque.abs() > limit,
+            None => self.peak_rotation.get::<degree>() >= 360.0,
+        }
+    }
+}
+
+/// Net wind torque about the hook from `panels` at `wind_speed`
+///
+/// Uses the same air density and bluff-body drag coefficient as
+/// [`WindAnalysis::wind_force_on_load`](crate::physics::WindAnalysis::wind_force_on_load).
+pub fn wind_torque(wind_speed: Velocity, panels: &[WindPanel]) -> Torque {
+    let q = 0.5 * AIR_DENSITY * wind_speed.get::<foot_per_second>().powi(2) * LOAD_DRAG_COEFFICIENT;
+    let moment: f64 = panels
+        .iter()
+        .map(|p| q * p.area.get::<square_foot>() * p.arm.get::<foot>())
+        .sum();
+    Torque::new::<pound_force_foot>(moment)
+}
+
+/// Rotation of a box-shaped load `length` × `width` in plan under `torque`
+pub fn load_rotation(
+    torque: Torque,
+    load: Mass,
+    length: Length,
+    width: Length,
+    suspension_length: Length,
+    restraint: RotationalRestraint,
+) -> LoadRotationAnalysis {
+    let stiffness = restraint.stiffness(load, suspension_length);
+    let torque_lbf_ft = torque.get::<pound_force_foot>().abs();
+
+    // Polar moment of inertia about the vertical axis, slug·ft²
+    let (l, w) = (length.get::<foot>(), width.get::<foot>());
+    let slugs = weight_of(load).get::<pound_force>() / 32.174;
+    let inertia = slugs * (l * l + w * w) / 12.0;
+
+    let (theta, period, omega) = if stiffness > 0.0 {
+        let theta = torque_lbf_ft / stiffness;
+        let natural = (stiffness / inertia).sqrt();
+        (theta, 2.0 * std::f64::consts::PI / natural, theta * natural)
+    } else {
+        (f64::INFINITY, f64::INFINITY, f64::INFINITY)
+    };
+
+    LoadRotationAnalysis {
+        wind_torque: torque,
+        stiffness,
+        max_restoring_torque: restraint.max_restoring_torque(load, suspension_length),
+        static_rotation: Angle::new::<radian>(theta),
+        peak_rotation: Angle::new::<radian>(2.0 * theta),
+        period,
+        peak_rate: AngularVelocity::new::<radian_per_second>(omega),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_wind_torque_balances_symmetric_panels() {
+        let wind = Velocity::new::<mile_per_hour>(20.0);
+        let panel = |area: f64, arm: f64| WindPanel {
+            area: Area::new::<square_foot>(area),
+            arm: Length::new::<foot>(arm),
+        };
+        let balanced = wind_torque(wind, &[panel(100.0, 10.0), panel(100.0, -10.0)]);
+        assert_relative_eq!(balanced.get::<pound_force_foot>(), 0.0, epsilon = 1e-9);
+
+        let q = 0.5 * AIR_DENSITY * wind.get::<foot_per_second>().powi(2) * LOAD_DRAG_COEFFICIENT;
+        let unbalanced = wind_torque(wind, &[panel(150.0, 10.0), panel(50.0, -10.0)]);
+        assert_relative_eq!(unbalanced.get::<pound_force_foot>(), q * 1000.0, max_relative = 1e-6);
+    }
+
+    #[test]
+    fn test_restraint_stiffness_and_rotation() {
+        let load = Mass::new::<pound>(20_000.0);
+        let torque = Torque::new::<pound_force_foot>(500.0);
+        let (length, width) = (Length::new::<foot>(30.0), Length::new::<foot>(8.0));
+        let drop = Length::new::<foot>(50.0);
+
+        // Falls 1 ft apart: 20,000 × 1 / 200 = 100 lbf·ft/rad, 5 rad static
+        let multi = load_rotation(
+            torque,
+            load,
+            length,
+            width,
+            drop,
+            RotationalRestraint::MultiPart { fall_spread: Length::new::<foot>(1.0) },
+        );
+        assert_relative_eq!(multi.stiffness, 100.0, max_relative = 1e-6);
+        assert_relative_eq!(multi.static_rotation.get::<radian>(), 5.0, max_relative = 1e-6);
+        assert_relative_eq!(multi.peak_rotation.get::<radian>(), 10.0, max_relative = 1e-6);
+
+        let inertia: f64 = 20_000.0 / 32.174 * (900.0 + 64.0) / 12.0;
+        let natural = (100.0 / inertia).sqrt();
+        assert_relative_eq!(multi.period, 2.0 * std::f64::consts::PI / natural, max_relative = 1e-6);
+        assert_relative_eq!(multi.peak_rate.get::<radian_per_second>(), 5.0 * natural, max_relative = 1e-6);
+        assert!(multi.spins());
+
+        // 200 lbf·ft is past the 100 lbf·ft the falls can resist, though the
+        // small-angle peak is only 4 rad; 90 lbf·ft is held
+        let falls = RotationalRestraint::MultiPart { fall_spread: Length::new::<foot>(1.0) };
+        let rotation = |torque: f64| {
+            load_rotation(Torque::new::<pound_force_foot>(torque), load, length, width, drop, falls)
+        };
+        assert_relative_eq!(
+            rotation(200.0).max_restoring_torque.unwrap().get::<pound_force_foot>(),
+            100.0,
+            max_relative = 1e-6
+        );
+        assert!(rotation(200.0).peak_rotation.get::<degree>() < 360.0);
+        assert!(rotation(200.0).spins());
+        assert!(!rotation(90.0).spins());
+
+        // A spreader 20 ft wide is 400 times stiffer
+        let spreader = load_rotation(
+            torque,
+            load,
+            length,
+            width,
+            drop,
+            RotationalRestraint::TwoPoint { spread: Length::new::<foot>(20.0) },
+        );
+        assert_relative_eq!(spreader.stiffness, 40_000.0, max_relative = 1e-6);
+        assert!(spreader.is_controlled(Angle::new::<degree>(5.0)));
+
+        // Single-part rope with little torsional rigidity lets it spin
+        let single = load_rotation(
+            torque,
+            load,
+            length,
+            width,
+            drop,
+            RotationalRestraint::SinglePart { torsional_rigidity: 2_000.0 },
+        );
+        assert_relative_eq!(single.stiffness, 40.0, max_relative = 1e-6);
+        assert!(single.max_restoring_torque.is_none());
+        assert!(single.spins());
+    }
+}
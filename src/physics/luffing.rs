@@ -0,0 +1,231 @@
+//! Boom hoist cylinder force and pendant line tension
+//!
+//! Works in the vertical plane of the boom with the origin at the boom foot
+//! pin: `r` forward toward the tip, `y` up. The boom is held by a single
+//! member (the hoist cylinder of a telescopic boom, or the pendants of a
+//! lattice boom) whose moment about the foot pin balances the boom weight
+//! and the load at the tip:
+//!
+//! F = (W_boom · d_cg + W_hook · L) · cos θ / arm
+//!
+//! where `arm` is the perpendicular distance from the foot pin to the
+//! member's line of action.
+
+use crate::types::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The member that holds the boom up
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LuffingMember {
+    /// Hydraulic cylinder between the superstructure and the boom underside
+    Cylinder {
+        /// Cylinder base pin, forward of (`r`) and above (`y`) the boom foot
+        base_r: Length,
+        base_y: Length,
+        /// Rod-end pin distance along the boom from the foot
+        boom_attachment: Length,
+        /// Rod-end pin distance below the boom centerline
+        boom_offset: Length,
+    },
+
+    /// Pendant lines from the gantry (or mast) top to the boom
+    Pendant {
+        /// Gantry top, forward of (`r`, usually negative) and above (`y`) the boom foot
+        gantry_r: Length,
+        gantry_y: Length,
+        /// Pendant attachment distance along the boom from the foot
+        boom_attachment: Length,
+    },
+}
+
+/// Luffing geometry and ratings for one boom
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LuffingGeometry {
+    pub member: LuffingMember,
+
+    /// Boom weight, including fly sections and stowed attachments
+    pub boom_weight: Mass,
+
+    /// Boom center of gravity as a fraction of boom length from the foot
+    pub boom_cg_fraction: f64,
+
+    /// Rated cylinder thrust or pendant tension; unchecked when `None`
+    pub rated_force: Option<Force>,
+}
+
+/// Member force at one boom angle and hook load
+#[derive(Debug, Clone, Copy)]
+pub struct LuffingAnalysis {
+    /// Cylinder thrust (compression) or pendant tension
+    pub member_force: Force,
+
+    /// Pin-to-pin length of the cylinder or pendant
+    pub member_length: Length,
+
+    /// Perpendicular distance from the foot pin to the member
+    pub moment_arm: Length,
+
+    /// Moment about the foot pin from boom weight and hook load
+    pub boom_moment: Torque,
+
+    /// Member force over its rating, if rated
+    pub utilization: Option<f64>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum LuffingError {
+    #[error("Luffing member passes through the boom foot pin at this angle")]
+    NoMomentArm,
+
+    #[error("Boom hoist force {force} exceeds rating {rating}")]
+    OverRated { force: DisplayForce, rating: DisplayForce },
+}
+
+impl LuffingMember {
+    /// Pin positions (fixed end, boom end) in feet for a boom angle
+    pub(crate) fn pins(&self, boom_angle: Angle) -> ([f64; 2], [f64; 2]) {
+        let (sin, cos) = boom_angle.get::<radian>().sin_cos();
+        match *self {
+            LuffingMember::Cylinder { base_r, base_y, boom_attachment, boom_offset } => {
+                let a = boom_attachment.get::<foot>();
+                let e = boom_offset.get::<foot>();
+                (
+                    [base_r.get::<foot>(), base_y.get::<foot>()],
+                    [a * cos + e * sin, a * sin - e * cos],
+                )
+            }
+            LuffingMember::Pendant { gantry_r, gantry_y, boom_attachment } => {
+                let a = boom_attachment.get::<foot>();
+                ([gantry_r.get::<foot>(), gantry_y.get::<foot>()], [a * cos, a * sin])
+            }
+        }
+    }
+}
+
+impl LuffingGeometry {
+    /// Member force with `hook_load` (load plus everything below the tip)
+    /// on a boom of `boom_length` at `boom_angle`
+    pub fn analyze(
+        &self,
+        boom_length: Length,
+        boom_angle: Angle,
+        hook_load: Mass,
+    ) -> Result<LuffingAnalysis, LuffingError> {
+        let (fixed, boom_pin) = self.member.pins(boom_angle);
+        let dr = boom_pin[0] - fixed[0];
+        let dy = boom_pin[1] - fixed[1];
+        let length = (dr * dr + dy * dy).sqrt();
+
+        // |pin × direction| with the foot pin at the origin
+        let arm = (boom_pin[0] * dy - boom_pin[1] * dr).abs() / length;
+        if !arm.is_finite() || arm < 1e-9 {
+            return Err(LuffingError::NoMomentArm);
+        }
+
+        let horizontal = boom_length.get::<foot>() * boom_angle.get::<radian>().cos();
+        let moment = weight_of(self.boom_weight).get::<pound_force>() * self.boom_cg_fraction * horizontal
+            + weight_of(hook_load).get::<pound_force>() * horizontal;
+        let member_force = Force::new::<pound_force>(moment / arm);
+
+        Ok(LuffingAnalysis {
+            member_force,
+            member_length: Length::new::<foot>(length),
+            moment_arm: Length::new::<foot>(arm),
+            boom_moment: Torque::new::<pound_force_foot>(moment),
+            utilization: self.rated_force.map(|rated| (member_force / rated).value),
+        })
+    }
+
+    /// [`analyze`](Self::analyze), failing when the force exceeds the rating
+    pub fn check(
+        &self,
+        boom_length: Length,
+        boom_angle: Angle,
+        hook_load: Mass,
+    ) -> Result<LuffingAnalysis, LuffingError> {
+        let analysis = self.analyze(boom_length, boom_angle, hook_load)?;
+        match self.rated_force {
+            Some(rating) if analysis.member_force > rating => Err(LuffingError::OverRated {
+                force: DisplayForce(analysis.member_force),
+                rating: DisplayForce(rating),
+            }),
+            _ => Ok(analysis),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_cylinder_force() {
+        // Rod end 10 ft up a horizontal boom on its centerline, base 5 ft
+        // straight below: the cylinder is vertical with a 10 ft arm
+        let geometry = LuffingGeometry {
+            member: LuffingMember::Cylinder {
+                base_r: Length::new::<foot>(10.0),
+                base_y: Length::new::<foot>(-5.0),
+                boom_attachment: Length::new::<foot>(10.0),
+                boom_offset: Length::new::<foot>(0.0),
+            },
+            boom_weight: Mass::new::<pound>(20_000.0),
+            boom_cg_fraction: 0.5,
+            rated_force: Some(Force::new::<pound_force>(500_000.0)),
+        };
+
+        let analysis = geometry
+            .analyze(Length::new::<foot>(100.0), Angle::new::<degree>(0.0), Mass::new::<pound>(10_000.0))
+            .unwrap();
+        assert_relative_eq!(analysis.moment_arm.get::<foot>(), 10.0, epsilon = 1e-9);
+        assert_relative_eq!(analysis.member_length.get::<foot>(), 5.0, epsilon = 1e-9);
+        // (20,000 × 50 + 10,000 × 100) / 10 = 200,000 lbf
+        assert_relative_eq!(analysis.member_force.get::<pound_force>(), 200_000.0, max_relative = 1e-6);
+        assert_relative_eq!(analysis.utilization.unwrap(), 0.4, max_relative = 1e-6);
+
+        // Raising the boom shortens the load's lever arm
+        let raised = geometry
+            .analyze(Length::new::<foot>(100.0), Angle::new::<degree>(60.0), Mass::new::<pound>(10_000.0))
+            .unwrap();
+        assert!(raised.boom_moment < analysis.boom_moment);
+
+        let over = geometry.check(
+            Length::new::<foot>(100.0),
+            Angle::new::<degree>(0.0),
+            Mass::new::<pound>(50_000.0),
+        );
+        assert!(matches!(over, Err(LuffingError::OverRated { .. })));
+    }
+
+    #[test]
+    fn test_pendant_tension() {
+        // Gantry 20 ft above the foot; pendant to the tip of a 100 ft boom at 0°
+        let geometry = LuffingGeometry {
+            member: LuffingMember::Pendant {
+                gantry_r: Length::new::<foot>(0.0),
+                gantry_y: Length::new::<foot>(20.0),
+                boom_attachment: Length::new::<foot>(100.0),
+            },
+            boom_weight: Mass::new::<pound>(0.0),
+            boom_cg_fraction: 0.5,
+            rated_force: None,
+        };
+        let analysis = geometry
+            .analyze(Length::new::<foot>(100.0), Angle::new::<degree>(0.0), Mass::new::<pound>(1_000.0))
+            .unwrap();
+
+        // Arm = 100 × 20 / √(100² + 20²)
+        let arm = 2000.0 / 10_400f64.sqrt();
+        assert_relative_eq!(analysis.moment_arm.get::<foot>(), arm, epsilon = 1e-9);
+        assert_relative_eq!(
+            analysis.member_force.get::<pound_force>(),
+            1_000.0 * 100.0 / arm,
+            max_relative = 1e-6
+        );
+        assert!(analysis.utilization.is_none());
+    }
+}
@@ -0,0 +1,355 @@
+//! Choosing crane mats from what is on hand
+//!
+//! Each support gets one mat size, laid `count` side by side and `layers`
+//! deep. The mats are taken as rigid in bearing, so the reaction plus mat
+//! weight spreads evenly over the footprint. Along its length each mat
+//! cantilevers past the float edge; per unit width the moment there is
+//! q·c²/2 against a section of layers·t²/6, with stacked layers not acting
+//! compositely.
+//!
+//! Supports are assigned in order of reaction, heaviest first, each taking
+//! the arrangement with the fewest mats and then the lightest that passes
+//! from what is left in the inventory. When that leaves a later support
+//! short, earlier supports back off to their next choice, so a layout is
+//! found whenever the inventory can cover every support.
+
+use crate::physics::ground_bearing::{GroundBearingAnalysis, GroundBearingError};
+use crate::report::*;
+use crate::types::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Most mats side by side under one float
+const MAX_SIDE_BY_SIDE: usize = 3;
+
+/// Most layers stacked under one float
+const MAX_LAYERS: usize = 2;
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CraneMat {
+    pub name: String,
+    pub length: Length,
+    pub width: Length,
+    pub thickness: Length,
+    pub weight: Mass,
+
+    /// Allowable extreme fiber bending stress
+    pub allowable_bending: Pressure,
+}
+
+impl CraneMat {
+    /// Dense hardwood at 60 lb/ft³ and 1,200 psi in bending
+    pub fn hardwood(name: impl Into<String>, length: Length, width: Length, thickness: Length) -> Self {
+        let volume = length.get::<foot>() * width.get::<foot>() * thickness.get::<foot>();
+        Self {
+            name: name.into(),
+            length,
+            width,
+            thickness,
+            weight: Mass::new::<pound>(60.0 * volume),
+            allowable_bending: Pressure::new::<psi>(1200.0),
+        }
+    }
+}
+
+/// Mats of one size available for the job
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MatStock {
+    pub mat: CraneMat,
+    pub quantity: usize,
+}
+
+/// Mats under one support
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MatAssignment {
+    pub support: String,
+
+    /// Index into the inventory
+    pub stock: usize,
+    pub mat: String,
+    pub count: usize,
+    pub layers: usize,
+
+    /// Support reaction plus mat weight over the footprint
+    pub bearing_pressure: Pressure,
+
+    /// Bending stress at the float edge
+    pub bending_stress: Pressure,
+}
+
+impl MatAssignment {
+    pub fn mats(&self) -> usize {
+        self.count * self.layers
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MatLayout {
+    pub assignments: Vec<MatAssignment>,
+    pub allowable_pressure: Pressure,
+    pub total_mats: usize,
+    pub total_weight: Mass,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum MatError {
+    #[error("No mats on hand can carry support {support}")]
+    NoArrangement { support: String },
+
+    #[error("Not enough mats on hand to cover every support")]
+    ShortInventory,
+
+    #[error(transparent)]
+    GroundBearing(#[from] GroundBearingError),
+}
+
+/// Assign mats from `inventory` to every support of `analysis` so that
+/// bearing stays within `allowable_pressure` and each mat within its
+/// bending allowable
+pub fn optimize_mats(
+    analysis: &GroundBearingAnalysis,
+    inventory: &[MatStock],
+    allowable_pressure: Pressure,
+) -> Result<MatLayout, MatError> {
+    let result = analysis.calculate_reactions()?;
+
+    let mut order: Vec<usize> = (0..result.reactions.len()).collect();
+    order.sort_by(|&a, &b| {
+        result.reactions[b].force.get::<pound_force>().total_cmp(&result.reactions[a].force.get::<pound_force>())
+    });
+
+    let allowable_psf = allowable_pressure.get::<pound_force_per_square_foot>();
+    let mut candidates = Vec::with_capacity(order.len());
+    for i in order {
+        let support = &analysis.support_points[i];
+        let reaction = result.reactions[i].force.get::<pound_force>();
+        // The mat cantilevers past the float's narrower side
+        let float = support.float.map_or_else(
+            || support.contact_area.get::<square_foot>().sqrt(),
+            |float| float.length.get::<foot>().min(float.width.get::<foot>()),
+        );
+
+        let mut options: Vec<(MatAssignment, f64)> = Vec::new();
+        for (stock, mat) in inventory.iter().enumerate() {
+            let mat = &mat.mat;
+            let (length, width) = (mat.length.get::<foot>(), mat.width.get::<foot>());
+            if length < float {
+                continue;
+            }
+            let thickness = mat.thickness.get::<foot>();
+            let overhang = (length - float) / 2.0;
+
+            for count in 1..=MAX_SIDE_BY_SIDE {
+                for layers in 1..=MAX_LAYERS {
+                    let mats = count * layers;
+                    if mats > inventory[stock].quantity {
+                        continue;
+                    }
+                    let weight = mat.weight.get::<pound>() * mats as f64;
+                    let pressure = (reaction + weight) / (length * width * count as f64);
+                    // q·c²/2 over layers·t²/6, per foot of width, in psf
+                    let bending = 3.0 * pressure * overhang * overhang / (layers as f64 * thickness * thickness);
+                    if pressure > allowable_psf || bending > mat.allowable_bending.get::<pound_force_per_square_foot>() {
+                        continue;
+                    }
+                    options.push((
+                        MatAssignment {
+                            support: support.name.clone(),
+                            stock,
+                            mat: mat.name.clone(),
+                            count,
+                            layers,
+                            bearing_pressure: Pressure::new::<pound_force_per_square_foot>(pressure),
+                            bending_stress: Pressure::new::<pound_force_per_square_foot>(bending),
+                        },
+                        weight,
+                    ));
+                }
+            }
+        }
+
+        if options.is_empty() {
+            return Err(MatError::NoArrangement { support: support.name.clone() });
+        }
+        // Fewest mats, then lightest
+        options.sort_by(|(a, a_weight), (b, b_weight)| a.mats().cmp(&b.mats()).then(a_weight.total_cmp(b_weight)));
+        candidates.push(options.into_iter().map(|(assignment, _)| assignment).collect::<Vec<_>>());
+    }
+
+    let mut remaining: Vec<usize> = inventory.iter().map(|stock| stock.quantity).collect();
+    let mut assignments = Vec::with_capacity(candidates.len());
+    if !assign(&candidates, &mut remaining, &mut assignments) {
+        return Err(MatError::ShortInventory);
+    }
+
+    let total_mats = assignments.iter().map(MatAssignment::mats).sum();
+    let total_weight = assignments
+        .iter()
+        .map(|a| inventory[a.stock].mat.weight * a.mats() as f64)
+        .fold(Mass::new::<pound>(0.0), |sum, weight| sum + weight);
+
+    Ok(MatLayout { assignments, allowable_pressure, total_mats, total_weight })
+}
+
+/// Depth-first over the supports in order, each trying its choices from
+/// most to least preferred; true once every support has mats
+fn assign(
+    candidates: &[Vec<MatAssignment>],
+    remaining: &mut [usize],
+    assignments: &mut Vec<MatAssignment>,
+) -> bool {
+    let Some(options) = candidates.get(assignments.len()) else {
+        return true;
+    };
+    for option in options {
+        if option.mats() > remaining[option.stock] {
+            continue;
+        }
+        remaining[option.stock] -= option.mats();
+        assignments.push(option.clone());
+        if assign(candidates, remaining, assignments) {
+            return true;
+        }
+        assignments.pop();
+        remaining[option.stock] += option.mats();
+    }
+    false
+}
+
+impl AnalysisReport for MatLayout {
+    fn title(&self) -> String {
+        "Crane Mats".into()
+    }
+
+    /// One check per support
+    fn checks(&self) -> Vec<ValidationCheck> {
+        self.assignments
+            .iter()
+            .map(|a| ValidationCheck {
+                name: a.support.clone(),
+                status: CheckStatus::Pass,
+                details: format!(
+                    "{} x {} {}: {:.1} PSI bearing, {:.0} PSI bending",
+                    a.count,
+                    a.layers,
+                    a.mat,
+                    a.bearing_pressure.get::<psi>(),
+                    a.bending_stress.get::<psi>()
+                ),
+                margin: Some((1.0 - a.bearing_pressure.get::<psi>() / self.allowable_pressure.get::<psi>()) * 100.0),
+            })
+            .collect()
+    }
+
+    fn results(&self) -> Vec<NumericResult> {
+        vec![
+            NumericResult::new("total_mats", self.total_mats as f64, ""),
+            NumericResult::new("total_mat_weight", self.total_weight.get::<pound>(), "lbs"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physics::ground_bearing::FloatDimensions;
+    use approx::assert_relative_eq;
+    use nalgebra as na;
+
+    fn analysis() -> GroundBearingAnalysis {
+        let mut analysis = GroundBearingAnalysis::new_na(
+            Mass::new::<pound>(100000.0),
+            na::Point3::origin(),
+            Mass::new::<pound>(20000.0),
+            na::Point3::new(0.0, 50.0, 30.0),
+        );
+        let float = FloatDimensions::square(Length::new::<foot>(2.0));
+        for (name, x, z) in [("FR", 10.0, 10.0), ("FL", -10.0, 10.0), ("RR", 10.0, -10.0), ("RL", -10.0, -10.0)] {
+            analysis.add_float_na(name, na::Point3::new(x, 0.0, z), float, None);
+        }
+        analysis
+    }
+
+    fn mat(length: f64, width: f64) -> CraneMat {
+        let ft = Length::new::<foot>;
+        CraneMat::hardwood(format!("{length}x{width}"), ft(length), ft(width), Length::new::<inch>(8.0))
+    }
+
+    #[test]
+    fn test_assigns_fewest_mats() {
+        // Front pads carry 45,000 lbs, rear 15,000 lbs; soil allows 2,000 psf
+        let inventory = vec![
+            MatStock { mat: mat(8.0, 4.0), quantity: 1 },
+            MatStock { mat: mat(4.0, 4.0), quantity: 6 },
+        ];
+        let allowable = Pressure::new::<pound_force_per_square_foot>(2000.0);
+        let layout = optimize_mats(&analysis(), &inventory, allowable).unwrap();
+        assert_eq!(layout.assignments.len(), 4);
+
+        // The one 8x4 goes under the first front pad: 45,000 + 1,280 over 32 sq ft
+        let fr = layout.assignments.iter().find(|a| a.support == "FR").unwrap();
+        assert_eq!((fr.stock, fr.mats()), (0, 1));
+        assert_relative_eq!(
+            fr.bearing_pressure.get::<pound_force_per_square_foot>(),
+            46280.0 / 32.0,
+            max_relative = 1e-9
+        );
+        assert!(fr.bending_stress <= inventory[0].mat.allowable_bending);
+
+        // The other front pad doubles up 4x4s; a single one would be at 2,850 psf
+        let fl = layout.assignments.iter().find(|a| a.support == "FL").unwrap();
+        assert_eq!((fl.stock, fl.count, fl.layers), (1, 2, 1));
+
+        let rear = layout.assignments.iter().find(|a| a.support == "RR").unwrap();
+        assert_eq!((rear.stock, rear.mats()), (1, 1));
+        assert_eq!(layout.total_mats, 5);
+        assert_relative_eq!(layout.total_weight.get::<pound>(), 1280.0 + 4.0 * 640.0, max_relative = 1e-9);
+        assert_eq!(layout.status(), CheckStatus::Pass);
+
+        // One 4x4 short for the rear pads
+        let short = vec![
+            MatStock { mat: mat(8.0, 4.0), quantity: 1 },
+            MatStock { mat: mat(4.0, 4.0), quantity: 3 },
+        ];
+        assert!(matches!(optimize_mats(&analysis(), &short, allowable), Err(MatError::ShortInventory)));
+    }
+
+    #[test]
+    fn test_backs_off_when_greedy_runs_short() {
+        // Front pads 55,000 lbs, rear 40,000 lbs
+        let mut analysis = GroundBearingAnalysis::new_na(
+            Mass::new::<pound>(150000.0),
+            na::Point3::origin(),
+            Mass::new::<pound>(40000.0),
+            na::Point3::new(0.0, 50.0, 7.5),
+        );
+        let float = FloatDimensions::square(Length::new::<foot>(2.0));
+        for (name, x, z) in [("FR", 10.0, 10.0), ("FL", -10.0, 10.0), ("RR", 10.0, -10.0), ("RL", -10.0, -10.0)] {
+            analysis.add_float_na(name, na::Point3::new(x, 0.0, z), float, None);
+        }
+        let thick = |length: f64, weight: f64| CraneMat {
+            thickness: Length::new::<foot>(1.0),
+            weight: Mass::new::<pound>(weight),
+            ..mat(length, 4.0)
+        };
+
+        // Two 6x4s or two 4x4s carry a front pad; a rear pad takes one 6x4
+        // or two 4x4s. Giving the front pads the lighter 6x4s leaves a rear
+        // pad short, so the front pads take the 4x4s instead.
+        let inventory = vec![
+            MatStock { mat: thick(6.0, 100.0), quantity: 2 },
+            MatStock { mat: thick(4.0, 200.0), quantity: 4 },
+        ];
+        let allowable = Pressure::new::<pound_force_per_square_foot>(2000.0);
+        let layout = optimize_mats(&analysis, &inventory, allowable).unwrap();
+        for assignment in &layout.assignments {
+            let expected = if assignment.support.starts_with('F') { (1, 2) } else { (0, 1) };
+            assert_eq!((assignment.stock, assignment.mats()), expected, "{}", assignment.support);
+        }
+        assert_eq!(layout.total_mats, 6);
+    }
+}
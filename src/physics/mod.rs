@@ -2,10 +2,36 @@ pub mod statics;
 pub mod stability;
 pub mod dynamics;
 pub mod ground_bearing;
+pub mod soil;
+pub mod buried_utilities;
+pub mod mats;
+pub mod out_of_service;
+pub mod weather;
+pub mod wind_data;
 pub mod wind_loading;
+pub mod floating;
+pub mod offshore;
+pub mod luffing;
+pub mod side_load;
+pub mod temperature;
+pub mod load_rotation;
+pub mod crawler_travel;
 
 pub use statics::*;
 pub use stability::*;
 pub use dynamics::*;
 pub use ground_bearing::*;
+pub use soil::*;
+pub use buried_utilities::*;
+pub use mats::*;
+pub use out_of_service::*;
+pub use weather::*;
+pub use wind_data::*;
 pub use wind_loading::*;
+pub use floating::*;
+pub use offshore::*;
+pub use luffing::*;
+pub use side_load::*;
+pub use temperature::*;
+pub use load_rotation::*;
+pub use crawler_travel::*;
@@ -0,0 +1,243 @@
+//! Offshore dynamic amplification per DNV-ST-N001
+//!
+//! Lifts from or onto a floating vessel see dynamic hook loads well above
+//! the static weight. DNV-ST-N001 sets a minimum dynamic amplification
+//! factor (DAF) by static hook load and location, and requires a higher
+//! value where crane tip motion and hoisting speed give a larger snatch load.
+//!
+//! The snatch load follows the simplified method in DNV-ST-N001 / DNV-RP-H103:
+//! F_dyn = v_r * √(K * M), where v_r is the relative velocity at lift-off
+//! and K the hoist rope stiffness.
+
+use crate::types::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Where the lift takes place (selects the DNV minimum DAF column)
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LiftLocation {
+    /// Onshore lift
+    Onshore,
+    /// Sheltered water (inshore)
+    Inshore,
+    /// Open sea (offshore)
+    Offshore,
+}
+
+/// Irregular sea state
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SeaState {
+    /// Significant wave height (Hs)
+    pub significant_wave_height: Length,
+
+    /// Peak wave period (Tp)
+    pub peak_period: Time,
+}
+
+/// Vertical motion of the crane boom tip
+#[derive(Debug, Clone, Copy)]
+pub struct CraneTipMotion {
+    /// Single amplitude of vertical boom tip motion
+    pub heave_amplitude: Length,
+
+    /// Period of the motion
+    pub period: Time,
+}
+
+impl CraneTipMotion {
+    /// Estimate crane tip motion from a sea state
+    ///
+    /// Uses the most probable maximum amplitude (1.86 × Hs/2) scaled by the
+    /// vessel's heave response at the boom tip. A `heave_rao` of 1.0 means
+    /// the boom tip follows the waves; 0.0 is a fixed platform.
+    pub fn from_sea_state(sea_state: &SeaState, heave_rao: f64) -> Self {
+        let hs = sea_state.significant_wave_height.get::<foot>();
+        Self {
+            heave_amplitude: Length::new::<foot>(heave_rao * 1.86 * hs / 2.0),
+            period: sea_state.peak_period,
+        }
+    }
+
+    /// Peak vertical velocity of the boom tip (harmonic motion)
+    pub fn velocity(&self) -> Velocity {
+        let period = self.period.get::<second>();
+        if period <= 0.0 {
+            return Velocity::new::<foot_per_second>(0.0);
+        }
+        let amplitude = self.heave_amplitude.get::<foot>();
+        Velocity::new::<foot_per_second>(2.0 * std::f64::consts::PI * amplitude / period)
+    }
+}
+
+/// Offshore environment input for a lift plan
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OffshoreConditions {
+    /// Lift location
+    pub location: LiftLocation,
+
+    /// Sea state during the lift
+    pub sea_state: SeaState,
+
+    /// Boom tip heave response (boom tip amplitude / wave amplitude)
+    pub heave_rao: f64,
+
+    /// Hook hoisting velocity at lift-off
+    pub hoisting_velocity: Velocity,
+
+    /// Hoist rope stiffness (lbf/ft), see [`rope_stiffness`]
+    pub rope_stiffness: f64,
+}
+
+impl OffshoreConditions {
+    /// Crane tip motion for the configured sea state
+    pub fn crane_tip_motion(&self) -> CraneTipMotion {
+        CraneTipMotion::from_sea_state(&self.sea_state, self.heave_rao)
+    }
+
+    /// Relative velocity between hook and load at lift-off
+    ///
+    /// Conservatively sums hoisting and crane tip velocity.
+    pub fn relative_velocity(&self) -> Velocity {
+        self.hoisting_velocity + self.crane_tip_motion().velocity()
+    }
+
+    /// DAF from snatch loading at lift-off
+    pub fn snatch_daf(&self, static_hook_load: Mass) -> f64 {
+        let weight_lbf = static_hook_load.get::<pound>();
+        if weight_lbf <= 0.0 {
+            return 1.0;
+        }
+
+        // Convert weight to slugs so F = v * √(K * m) comes out in lbf
+        let mass_slug = weight_lbf / 32.174;
+        let v = self.relative_velocity().get::<foot_per_second>();
+        let dynamic_force = v * (self.rope_stiffness.max(0.0) * mass_slug).sqrt();
+
+        1.0 + dynamic_force / weight_lbf
+    }
+
+    /// Governing DAF: the larger of the DNV minimum and the snatch DAF
+    pub fn dynamic_amplification_factor(&self, static_hook_load: Mass) -> f64 {
+        dnv_minimum_daf(static_hook_load, self.location).max(self.snatch_daf(static_hook_load))
+    }
+
+    /// Dynamic hook load (static hook load × DAF)
+    pub fn dynamic_hook_load(&self, static_hook_load: Mass) -> Mass {
+        static_hook_load * self.dynamic_amplification_factor(static_hook_load)
+    }
+}
+
+/// Minimum DAF for lifts in air (DNV-ST-N001 Table 16-2)
+///
+/// Static hook load bands are in metric tonnes.
+pub fn dnv_minimum_daf(static_hook_load: Mass, location: LiftLocation) -> f64 {
+    let shl_t = static_hook_load.get::<metric_ton>();
+
+    // (upper SHL bound in tonnes, onshore, inshore, offshore)
+    let table = [
+        (100.0, 1.10, 1.15, 1.30),
+        (300.0, 1.05, 1.10, 1.20),
+        (1000.0, 1.05, 1.05, 1.15),
+        (2500.0, 1.03, 1.05, 1.10),
+        (f64::INFINITY, 1.03, 1.05, 1.05),
+    ];
+
+    let row = table
+        .iter()
+        .find(|(limit, ..)| shl_t <= *limit)
+        .unwrap_or(&table[table.len() - 1]);
+
+    match location {
+        LiftLocation::Onshore => row.1,
+        LiftLocation::Inshore => row.2,
+        LiftLocation::Offshore => row.3,
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum OffshoreError {
+    #[error("Hoist rope length {0} must be positive")]
+    InvalidRopeLength(DisplayLength),
+}
+
+/// Axial stiffness of a reeved hoist rope (lbf/ft)
+///
+/// K = n * EA / L, where n is the number of falls and EA the axial
+/// stiffness of a single part of line. Errors unless the rope has length.
+pub fn rope_stiffness(
+    axial_stiffness: Force,
+    rope_length: Length,
+    parts_of_line: u32,
+) -> Result<f64, OffshoreError> {
+    let length_ft = rope_length.get::<foot>();
+    if length_ft.is_nan() || length_ft <= 0.0 {
+        return Err(OffshoreError::InvalidRopeLength(DisplayLength(rope_length)));
+    }
+    Ok(parts_of_line as f64 * axial_stiffness.get::<pound_force>() / length_ft)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn conditions(hs_ft: f64) -> OffshoreConditions {
+        OffshoreConditions {
+            location: LiftLocation::Offshore,
+            sea_state: SeaState {
+                significant_wave_height: Length::new::<foot>(hs_ft),
+                peak_period: Time::new::<second>(8.0),
+            },
+            heave_rao: 0.5,
+            hoisting_velocity: Velocity::new::<foot_per_minute>(30.0),
+            rope_stiffness: rope_stiffness(
+                Force::new::<pound_force>(5.0e6),
+                Length::new::<foot>(150.0),
+                4,
+            )
+            .unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_dnv_minimum_daf_table() {
+        let small = Mass::new::<metric_ton>(50.0);
+        let large = Mass::new::<metric_ton>(3000.0);
+
+        assert_relative_eq!(dnv_minimum_daf(small, LiftLocation::Offshore), 1.30);
+        assert_relative_eq!(dnv_minimum_daf(small, LiftLocation::Onshore), 1.10);
+        assert_relative_eq!(dnv_minimum_daf(large, LiftLocation::Offshore), 1.05);
+    }
+
+    #[test]
+    fn test_crane_tip_velocity() {
+        let motion = conditions(4.0).crane_tip_motion();
+
+        // 0.5 * 1.86 * 2 ft = 1.86 ft amplitude over 8 s
+        assert_relative_eq!(motion.heave_amplitude.get::<foot>(), 1.86, epsilon = 1e-9);
+        assert_relative_eq!(
+            motion.velocity().get::<foot_per_second>(),
+            2.0 * std::f64::consts::PI * 1.86 / 8.0,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn test_daf_grows_with_sea_state() {
+        let load = Mass::new::<pound>(100_000.0);
+
+        let calm = conditions(0.0).dynamic_amplification_factor(load);
+        let rough = conditions(8.0).dynamic_amplification_factor(load);
+
+        assert!(calm >= 1.30);
+        assert!(rough > calm);
+        assert!(conditions(8.0).dynamic_hook_load(load) > load);
+
+        // Two-blocked: no rope to stretch
+        let stiffness = rope_stiffness(Force::new::<pound_force>(5.0e6), Length::new::<foot>(0.0), 4);
+        assert!(matches!(stiffness, Err(OffshoreError::InvalidRopeLength(_))));
+    }
+}
@@ -0,0 +1,232 @@
+//! Storm configuration for a crane left unattended
+//!
+//! Given the forecast wind, the crane is checked as parked against its
+//! out-of-service limit. When the forecast reaches the limit, the boom is
+//! brought down step by step until it is secure:
+//!
+//! - Telescopic cranes lower to 45°, then retract and lower onto the rest
+//! - Lattice booms and crawlers are laid down
+//! - Tower cranes are always left free to weathervane
+//!
+//! Overturning moments are with nothing on the hook.
+
+use crate::equipment::CraneType;
+use crate::physics::wind_loading::WindAnalysis;
+use crate::report::*;
+use crate::types::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Highest boom angle with the boom-down out-of-service rating
+const STORM_BOOM_ANGLE: f64 = 45.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum StormAction {
+    LowerBoom { angle: Angle },
+    RetractBoom { length: Length },
+    LayDownBoom,
+    /// Release the slew brake so the jib turns with the wind
+    Weathervane,
+}
+
+impl StormAction {
+    pub fn description(&self) -> String {
+        match self {
+            StormAction::LowerBoom { angle } if angle.get::<degree>() <= 0.0 => {
+                "Lower the boom onto the boom rest".into()
+            }
+            StormAction::LowerBoom { angle } => format!("Lower the boom to {:.0}° or below", angle.get::<degree>()),
+            StormAction::RetractBoom { length } => format!("Retract the boom to {:.1} ft", length.get::<foot>()),
+            StormAction::LayDownBoom => "Lay the boom down on the ground or cribbing".into(),
+            StormAction::Weathervane => "Release the slew brake to let the crane weathervane".into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OutOfServiceAnalysis {
+    pub crane_type: CraneType,
+    pub forecast_wind: Velocity,
+
+    /// Out-of-service limit as the crane was left
+    pub parked_limit: Velocity,
+
+    /// Out-of-service limit in the storm configuration; `None` with the boom
+    /// stowed or laid down
+    pub storm_limit: Option<Velocity>,
+
+    /// Steps to reach the storm configuration, in order
+    pub actions: Vec<StormAction>,
+
+    pub storm_boom_length: Length,
+    pub storm_boom_angle: Angle,
+
+    /// Wind overturning moment at the forecast speed as parked (lb·ft)
+    pub parked_moment: f64,
+
+    /// Wind overturning moment at the forecast speed in the storm configuration (lb·ft)
+    pub storm_moment: f64,
+}
+
+impl OutOfServiceAnalysis {
+    /// Storm configuration for the crane in `parked` under `forecast_wind`;
+    /// a telescopic boom retracts to `retracted_length`
+    pub fn new(parked: &WindAnalysis, forecast_wind: Velocity, retracted_length: Length) -> Self {
+        let parked = WindAnalysis {
+            wind_speed: forecast_wind,
+            load_area: Area::new::<square_foot>(0.0),
+            ..parked.clone()
+        };
+        let parked_limit = parked.out_of_service_limit();
+        let exceeds = |limit: Velocity| forecast_wind >= limit;
+
+        let mut storm = parked.clone();
+        let mut actions = Vec::new();
+        let mut storm_limit = Some(parked_limit);
+        match parked.crane_type {
+            CraneType::Tower => actions.push(StormAction::Weathervane),
+            _ if !exceeds(parked_limit) => {}
+            CraneType::MobileLattice | CraneType::Crawler => {
+                actions.push(StormAction::LayDownBoom);
+                storm.boom_angle = Angle::new::<degree>(0.0);
+                storm_limit = None;
+            }
+            CraneType::MobileTelescopic | CraneType::AllTerrain | CraneType::RoughTerrain | CraneType::TruckMounted => {
+                let lowered = Angle::new::<degree>(STORM_BOOM_ANGLE);
+                if storm.boom_angle > lowered {
+                    storm.boom_angle = lowered;
+                    let limit = storm.out_of_service_limit();
+                    if !exceeds(limit) {
+                        actions.push(StormAction::LowerBoom { angle: lowered });
+                        storm_limit = Some(limit);
+                    }
+                }
+                if storm_limit == Some(parked_limit) {
+                    if storm.boom_length > retracted_length {
+                        actions.push(StormAction::RetractBoom { length: retracted_length });
+                        storm.boom_length = retracted_length;
+                    }
+                    actions.push(StormAction::LowerBoom { angle: Angle::new::<degree>(0.0) });
+                    storm.boom_angle = Angle::new::<degree>(0.0);
+                    storm_limit = None;
+                }
+            }
+        }
+
+        Self {
+            crane_type: parked.crane_type.clone(),
+            forecast_wind,
+            parked_limit,
+            storm_limit,
+            actions,
+            storm_boom_length: storm.boom_length,
+            storm_boom_angle: storm.boom_angle,
+            parked_moment: parked.wind_overturning_moment(),
+            storm_moment: storm.wind_overturning_moment(),
+        }
+    }
+
+    /// The storm configuration rides out the forecast
+    pub fn is_secure(&self) -> bool {
+        self.storm_limit.is_none_or(|limit| self.forecast_wind < limit)
+    }
+}
+
+impl AnalysisReport for OutOfServiceAnalysis {
+    fn title(&self) -> String {
+        "Out of Service".into()
+    }
+
+    fn checks(&self) -> Vec<ValidationCheck> {
+        let forecast = self.forecast_wind.get::<mile_per_hour>();
+        let (status, details) = if !self.is_secure() {
+            (CheckStatus::Fail, format!("{:.0} mph forecast exceeds every storm configuration", forecast))
+        } else if self.actions.is_empty() {
+            (
+                CheckStatus::Pass,
+                format!("{:.0} mph forecast, {:.0} mph limit as parked", forecast, self.parked_limit.get::<mile_per_hour>()),
+            )
+        } else {
+            (
+                CheckStatus::Warning,
+                format!("{:.0} mph forecast needs {} storm step(s)", forecast, self.actions.len()),
+            )
+        };
+        vec![ValidationCheck {
+            name: "Storm Configuration".into(),
+            status,
+            details,
+            margin: self
+                .storm_limit
+                .map(|limit| (1.0 - forecast / limit.get::<mile_per_hour>()) * 100.0),
+        }]
+    }
+
+    fn results(&self) -> Vec<NumericResult> {
+        vec![
+            NumericResult::new("forecast_wind", self.forecast_wind.get::<mile_per_hour>(), "mph"),
+            NumericResult::new("parked_overturning_moment", self.parked_moment, "lb·ft"),
+            NumericResult::new("storm_overturning_moment", self.storm_moment, "lb·ft"),
+        ]
+    }
+
+    fn recommendations(&self) -> Vec<String> {
+        self.actions.iter().map(StormAction::description).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parked(crane_type: CraneType, angle: f64) -> WindAnalysis {
+        WindAnalysis::new(
+            crane_type,
+            Length::new::<foot>(150.0),
+            Angle::new::<degree>(angle),
+            Area::new::<square_foot>(50.0),
+            Velocity::new::<mile_per_hour>(10.0),
+        )
+    }
+
+    #[test]
+    fn test_telescopic_storm_steps() {
+        let mph = Velocity::new::<mile_per_hour>;
+        let retracted = Length::new::<foot>(45.0);
+
+        let calm = OutOfServiceAnalysis::new(&parked(CraneType::AllTerrain, 60.0), mph(30.0), retracted);
+        assert!(calm.actions.is_empty());
+        assert_eq!(calm.status(), CheckStatus::Pass);
+
+        // Over the 45 mph boom-up limit but under 70 mph boom-down
+        let lower = OutOfServiceAnalysis::new(&parked(CraneType::AllTerrain, 60.0), mph(55.0), retracted);
+        assert_eq!(lower.actions, vec![StormAction::LowerBoom { angle: Angle::new::<degree>(45.0) }]);
+        assert!(lower.is_secure());
+        assert_eq!(lower.status(), CheckStatus::Warning);
+
+        // Past 70 mph the boom comes in and down
+        let stow = OutOfServiceAnalysis::new(&parked(CraneType::AllTerrain, 60.0), mph(80.0), retracted);
+        assert_eq!(stow.actions.len(), 2);
+        assert_eq!(stow.actions[0], StormAction::RetractBoom { length: retracted });
+        assert!(stow.storm_limit.is_none());
+        assert!(stow.storm_moment < stow.parked_moment);
+        assert_eq!(stow.recommendations().len(), 2);
+    }
+
+    #[test]
+    fn test_lattice_and_tower_storm_configurations() {
+        let mph = Velocity::new::<mile_per_hour>;
+        let retracted = Length::new::<foot>(45.0);
+
+        let crawler = OutOfServiceAnalysis::new(&parked(CraneType::Crawler, 70.0), mph(70.0), retracted);
+        assert_eq!(crawler.actions, vec![StormAction::LayDownBoom]);
+        assert_eq!(crawler.storm_moment, 0.0);
+
+        let tower = OutOfServiceAnalysis::new(&parked(CraneType::Tower, 0.0), mph(90.0), retracted);
+        assert_eq!(tower.actions, vec![StormAction::Weathervane]);
+        assert!(!tower.is_secure());
+        assert_eq!(tower.status(), CheckStatus::Fail);
+    }
+}
@@ -0,0 +1,178 @@
+//! Boom side load from off-plumb hoist rope and tagline pull
+//!
+//! Booms are rated for vertical loads in the plane of the boom; they have
+//! little strength sideways. Any horizontal force at the tip across that
+//! plane is side load:
+//!
+//! F_side = W_hook · tan(side lead) + P_tagline · sin(tagline angle)
+//!
+//! Side lead is the hoist rope's angle from plumb across the boom plane
+//! with the taglines slack, e.g. from a hook not centered over the load at
+//! pick-up. Taglines are taken as horizontal, their pull carried to the tip
+//! through the rope. Off-lead (rope out of plumb along the boom plane)
+//! moves the load in or out on the radius rather than sideways; it is
+//! reported as an in-plane force.
+
+use crate::types::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rope angles, tagline pull and the allowable side load for a lift
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SideLoadConditions {
+    /// Rope angle from plumb across the boom plane
+    pub side_lead: Angle,
+
+    /// Rope angle from plumb along the boom plane, positive outward
+    pub off_lead: Angle,
+
+    /// Tagline pull on the load
+    pub tagline_pull: Force,
+
+    /// Tagline direction from the boom plane, seen from above (90° is square across it)
+    pub tagline_angle: Angle,
+
+    /// Allowable side load as a percent of rated capacity
+    pub allowable_percent: f64,
+}
+
+impl SideLoadConditions {
+    /// Rope angles only, no tagline, 2% allowable
+    pub fn new(side_lead: Angle, off_lead: Angle) -> Self {
+        Self {
+            side_lead,
+            off_lead,
+            tagline_pull: Force::new::<pound_force>(0.0),
+            tagline_angle: Angle::new::<degree>(90.0),
+            allowable_percent: 2.0,
+        }
+    }
+
+    pub fn with_tagline(mut self, pull: Force, angle: Angle) -> Self {
+        self.tagline_pull = pull;
+        self.tagline_angle = angle;
+        self
+    }
+
+    /// Allowable side load from the manufacturer, as a percent of rated capacity
+    pub fn with_allowable_percent(mut self, percent: f64) -> Self {
+        self.allowable_percent = percent;
+        self
+    }
+
+    /// Side and in-plane forces at the tip with `hook_load` on the rope,
+    /// against `rated_capacity`
+    ///
+    /// Errors unless the allowable side load is a positive percent.
+    pub fn analyze(&self, hook_load: Mass, rated_capacity: Mass) -> Result<SideLoadAnalysis, SideLoadError> {
+        if !(self.allowable_percent.is_finite() && self.allowable_percent > 0.0) {
+            return Err(SideLoadError::InvalidAllowable(self.allowable_percent));
+        }
+        let weight = weight_of(hook_load);
+        let (tag_sin, tag_cos) = self.tagline_angle.get::<radian>().sin_cos();
+
+        let side_force = weight * self.side_lead.get::<radian>().tan() + self.tagline_pull * tag_sin;
+        let in_plane_force = weight * self.off_lead.get::<radian>().tan() + self.tagline_pull * tag_cos;
+
+        let rated = weight_of(rated_capacity).get::<pound_force>();
+        let side_load_percent = if rated > 0.0 {
+            side_force.get::<pound_force>().abs() / rated * 100.0
+        } else {
+            f64::INFINITY
+        };
+
+        Ok(SideLoadAnalysis {
+            side_force,
+            in_plane_force,
+            side_load_percent,
+            allowable_percent: self.allowable_percent,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SideLoadError {
+    #[error("Allowable side load {0}% must be positive")]
+    InvalidAllowable(f64),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SideLoadAnalysis {
+    /// Horizontal force at the tip across the boom plane
+    pub side_force: Force,
+
+    /// Horizontal force at the tip along the boom plane, positive outward
+    pub in_plane_force: Force,
+
+    /// Side force as a percent of rated capacity
+    pub side_load_percent: f64,
+
+    pub allowable_percent: f64,
+}
+
+impl SideLoadAnalysis {
+    /// Side load over the allowable
+    pub fn utilization(&self) -> f64 {
+        self.side_load_percent / self.allowable_percent
+    }
+
+    pub fn is_acceptable(&self) -> bool {
+        self.side_load_percent <= self.allowable_percent
+    }
+
+    /// Sideways bending moment at the boom foot from the side force
+    pub fn foot_moment(&self, boom_length: Length) -> Torque {
+        Torque::new::<pound_force_foot>(self.side_force.get::<pound_force>().abs() * boom_length.get::<foot>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_side_lead_and_tagline() {
+        let load = Mass::new::<pound>(20_000.0);
+        let rated = Mass::new::<pound>(40_000.0);
+
+        // 1° side lead alone: 20,000 × tan 1° ≈ 349 lbf, 0.87% of rated
+        let analysis = SideLoadConditions::new(Angle::new::<degree>(1.0), Angle::new::<degree>(0.0))
+            .analyze(load, rated)
+            .unwrap();
+        let side = 20_000.0 * 1f64.to_radians().tan();
+        assert_relative_eq!(analysis.side_force.get::<pound_force>(), side, max_relative = 1e-6);
+        assert_relative_eq!(analysis.side_load_percent, side / 40_000.0 * 100.0, max_relative = 1e-6);
+        assert!(analysis.is_acceptable());
+        assert_relative_eq!(analysis.foot_moment(Length::new::<foot>(100.0)).get::<pound_force_foot>(), side * 100.0, max_relative = 1e-6);
+
+        // A 600 lbf tagline square to the boom pushes it over 2%
+        let analysis = SideLoadConditions::new(Angle::new::<degree>(1.0), Angle::new::<degree>(0.0))
+            .with_tagline(Force::new::<pound_force>(600.0), Angle::new::<degree>(90.0))
+            .analyze(load, rated)
+            .unwrap();
+        assert_relative_eq!(analysis.side_force.get::<pound_force>(), side + 600.0, max_relative = 1e-6);
+        assert!(!analysis.is_acceptable());
+        assert!(analysis.utilization() > 1.0);
+
+        // Off-lead and an in-line tagline stay in the boom plane
+        let analysis = SideLoadConditions::new(Angle::new::<degree>(0.0), Angle::new::<degree>(2.0))
+            .with_tagline(Force::new::<pound_force>(500.0), Angle::new::<degree>(0.0))
+            .analyze(load, rated)
+            .unwrap();
+        assert_relative_eq!(analysis.side_force.get::<pound_force>(), 0.0, epsilon = 1e-9);
+        assert_relative_eq!(
+            analysis.in_plane_force.get::<pound_force>(),
+            20_000.0 * 2f64.to_radians().tan() + 500.0,
+            max_relative = 1e-6
+        );
+
+        // No allowable side load to measure against
+        for percent in [0.0, -2.0, f64::NAN] {
+            let conditions = SideLoadConditions::new(Angle::new::<degree>(1.0), Angle::new::<degree>(0.0))
+                .with_allowable_percent(percent);
+            assert!(matches!(conditions.analyze(load, rated), Err(SideLoadError::InvalidAllowable(_))));
+        }
+    }
+}
@@ -0,0 +1,494 @@
+//! Allowable soil bearing pressure from classification or field tests
+//!
+//! Presumptive values by USCS class follow IBC Table 1806.2. Blow count and
+//! cone correlations are for shallow bearing at the ground surface (no
+//! embedment), which is how outrigger pads and mats sit:
+//!
+//! - Meyerhof (1956), SPT in sand, 1 in of settlement:
+//!   q_a = N/4 ksf for B ≤ 4 ft, (N/6)·((B + 1)/B)² ksf wider
+//! - Bowles (1977), the same with the less conservative N/2.5 and N/4
+//! - Terzaghi & Peck, SPT in clay: s_u = N/16 tsf
+//! - Meyerhof (1956), CPT in sand: q_a = q_c/30
+//! - Undrained strength from CPT in clay: s_u = q_c/N_kt
+//!
+//! Clay capacities are 5.14·s_u with a factor of safety of 3.
+
+use crate::types::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+#[derive(Debug, thiserror::Error)]
+pub enum SoilError {
+    #[error("Unknown USCS soil class '{0}'")]
+    UnknownClass(String),
+
+    #[error("Cone factor N_kt must be positive, got {0}")]
+    InvalidConeFactor(f64),
+}
+
+/// Unified Soil Classification System group
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum UscsClass {
+    /// Well-graded gravel
+    GW,
+    /// Poorly graded gravel
+    GP,
+    /// Silty gravel
+    GM,
+    /// Clayey gravel
+    GC,
+    /// Well-graded sand
+    SW,
+    /// Poorly graded sand
+    SP,
+    /// Silty sand
+    SM,
+    /// Clayey sand
+    SC,
+    /// Silt
+    ML,
+    /// Lean clay
+    CL,
+    /// Organic silt or clay, low plasticity
+    OL,
+    /// Elastic silt
+    MH,
+    /// Fat clay
+    CH,
+    /// Organic clay, high plasticity
+    OH,
+    /// Peat
+    PT,
+}
+
+impl UscsClass {
+    /// Gravels and sands, whose capacity comes from friction rather than cohesion
+    pub fn is_granular(&self) -> bool {
+        matches!(
+            self,
+            UscsClass::GW | UscsClass::GP | UscsClass::GM | UscsClass::GC
+                | UscsClass::SW | UscsClass::SP | UscsClass::SM | UscsClass::SC
+        )
+    }
+
+    /// Organic soils and peat, which have no presumptive capacity
+    pub fn is_organic(&self) -> bool {
+        matches!(self, UscsClass::OL | UscsClass::OH | UscsClass::PT)
+    }
+
+    /// IBC presumptive allowable bearing; zero for organic soils, which
+    /// need a geotechnical assessment
+    pub fn presumptive_bearing(&self) -> Pressure {
+        let psf = match self {
+            UscsClass::GW | UscsClass::GP => 3000.0,
+            UscsClass::GM | UscsClass::GC | UscsClass::SW | UscsClass::SP | UscsClass::SM | UscsClass::SC => 2000.0,
+            UscsClass::ML | UscsClass::CL | UscsClass::MH | UscsClass::CH => 1500.0,
+            UscsClass::OL | UscsClass::OH | UscsClass::PT => 0.0,
+        };
+        Pressure::new::<pound_force_per_square_foot>(psf)
+    }
+}
+
+impl FromStr for UscsClass {
+    type Err = SoilError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim().to_ascii_uppercase().as_str() {
+            "GW" => UscsClass::GW,
+            "GP" => UscsClass::GP,
+            "GM" => UscsClass::GM,
+            "GC" => UscsClass::GC,
+            "SW" => UscsClass::SW,
+            "SP" => UscsClass::SP,
+            "SM" => UscsClass::SM,
+            "SC" => UscsClass::SC,
+            "ML" => UscsClass::ML,
+            "CL" => UscsClass::CL,
+            "OL" => UscsClass::OL,
+            "MH" => UscsClass::MH,
+            "CH" => UscsClass::CH,
+            "OH" => UscsClass::OH,
+            "PT" => UscsClass::PT,
+            _ => return Err(SoilError::UnknownClass(s.into())),
+        })
+    }
+}
+
+/// Correlation from SPT blow count to allowable bearing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SptCorrelation {
+    /// Sand, 1 in of settlement
+    Meyerhof,
+    /// Sand, Meyerhof's relation raised about 50%
+    Bowles,
+    /// Clay, from undrained shear strength
+    TerzaghiPeck,
+}
+
+/// Correlation from CPT cone tip resistance to allowable bearing
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CptCorrelation {
+    /// Sand: q_c/30
+    Meyerhof,
+    /// Clay: undrained strength q_c/N_kt; N_kt is usually 10 to 20
+    Undrained { nkt: ConeFactor },
+}
+
+impl CptCorrelation {
+    /// Clay correlation; errors unless `nkt` is positive
+    pub fn undrained(nkt: f64) -> Result<Self, SoilError> {
+        Ok(CptCorrelation::Undrained { nkt: ConeFactor::new(nkt)? })
+    }
+}
+
+/// Cone factor N_kt relating tip resistance to undrained strength; positive
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "f64", into = "f64"))]
+pub struct ConeFactor(f64);
+
+impl ConeFactor {
+    pub fn new(nkt: f64) -> Result<Self, SoilError> {
+        if nkt > 0.0 && nkt.is_finite() {
+            Ok(Self(nkt))
+        } else {
+            Err(SoilError::InvalidConeFactor(nkt))
+        }
+    }
+
+    pub fn get(&self) -> f64 {
+        self.0
+    }
+}
+
+impl TryFrom<f64> for ConeFactor {
+    type Error = SoilError;
+
+    fn try_from(nkt: f64) -> Result<Self, Self::Error> {
+        Self::new(nkt)
+    }
+}
+
+impl From<ConeFactor> for f64 {
+    fn from(nkt: ConeFactor) -> Self {
+        nkt.0
+    }
+}
+
+/// Source of the allowable bearing pressure at a site
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SoilCapacity {
+    /// Stated value, e.g. from a geotechnical report
+    Allowable(Pressure),
+
+    /// Presumptive value for the soil class
+    Uscs(UscsClass),
+
+    /// Standard penetration test, corrected to 60% hammer efficiency
+    Spt { n60: f64, correlation: SptCorrelation },
+
+    /// Cone penetration test
+    Cpt { tip_resistance: Pressure, correlation: CptCorrelation },
+}
+
+impl SoilCapacity {
+    /// Allowable bearing under a pad or mat `width` across
+    ///
+    /// Only the Meyerhof and Bowles SPT correlations depend on width.
+    pub fn allowable_bearing(&self, width: Length) -> Pressure {
+        match *self {
+            SoilCapacity::Allowable(pressure) => pressure,
+            SoilCapacity::Uscs(class) => class.presumptive_bearing(),
+            SoilCapacity::Spt { n60, correlation } => {
+                let n = n60.max(0.0);
+                let b = width.get::<foot>();
+                let width_factor = ((b + 1.0) / b).powi(2);
+                let ksf = match correlation {
+                    SptCorrelation::Meyerhof if b <= 4.0 => n / 4.0,
+                    SptCorrelation::Meyerhof => n / 6.0 * width_factor,
+                    SptCorrelation::Bowles if b <= 4.0 => n / 2.5,
+                    SptCorrelation::Bowles => n / 4.0 * width_factor,
+                    // N/16 tsf = N/8 ksf
+                    SptCorrelation::TerzaghiPeck => return clay_bearing(n / 8.0 * 1000.0),
+                };
+                Pressure::new::<pound_force_per_square_foot>(ksf * 1000.0)
+            }
+            SoilCapacity::Cpt { tip_resistance, correlation } => {
+                let qc = tip_resistance.get::<pound_force_per_square_foot>().max(0.0);
+                match correlation {
+                    CptCorrelation::Meyerhof => Pressure::new::<pound_force_per_square_foot>(qc / 30.0),
+                    CptCorrelation::Undrained { nkt } => clay_bearing(qc / nkt.get()),
+                }
+            }
+        }
+    }
+
+    pub fn soft_clay() -> Self {
+        SoilCapacity::Allowable(soil_capacities::soft_clay())
+    }
+
+    pub fn medium_clay() -> Self {
+        SoilCapacity::Allowable(soil_capacities::medium_clay())
+    }
+
+    pub fn stiff_clay() -> Self {
+        SoilCapacity::Allowable(soil_capacities::stiff_clay())
+    }
+
+    pub fn loose_sand() -> Self {
+        SoilCapacity::Allowable(soil_capacities::loose_sand())
+    }
+
+    pub fn dense_sand() -> Self {
+        SoilCapacity::Allowable(soil_capacities::dense_sand())
+    }
+
+    pub fn gravel() -> Self {
+        SoilCapacity::Allowable(soil_capacities::gravel())
+    }
+
+    pub fn soft_rock() -> Self {
+        SoilCapacity::Allowable(soil_capacities::soft_rock())
+    }
+
+    pub fn hard_rock() -> Self {
+        SoilCapacity::Allowable(soil_capacities::hard_rock())
+    }
+
+    pub fn paved_surface() -> Self {
+        SoilCapacity::Allowable(soil_capacities::paved_surface())
+    }
+}
+
+/// Frozen ground state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FrostState {
+    /// No credit is taken for frozen strength; it is lost on thaw
+    Frozen,
+
+    /// Thaw-weakened with excess pore water
+    Thawing,
+}
+
+/// Slope or excavation below the setup
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Slope {
+    /// Crest to toe
+    pub height: Length,
+
+    /// Nearest pad edge to the crest
+    pub crest_distance: Length,
+}
+
+impl Slope {
+    /// Pads stay outside a 1:1 line up from the toe
+    pub fn required_setback(&self) -> Length {
+        self.height
+    }
+}
+
+/// Conditions that reduce the allowable bearing at a site
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SoilConditions {
+    /// Water table at or near the surface
+    pub saturated: bool,
+    pub frost: Option<FrostState>,
+    pub slope: Option<Slope>,
+}
+
+impl SoilConditions {
+    /// Multiplier on the allowable bearing
+    ///
+    /// Saturation and thaw each halve it. Inside the slope setback it falls
+    /// linearly to half at the crest.
+    pub fn factor(&self) -> f64 {
+        let mut factor = 1.0;
+        if self.saturated {
+            factor *= 0.5;
+        }
+        if self.frost == Some(FrostState::Thawing) {
+            factor *= 0.5;
+        }
+        if let Some(slope) = self.slope {
+            let setback = slope.required_setback().get::<foot>();
+            if setback > 0.0 {
+                let ratio = (slope.crest_distance.get::<foot>() / setback).clamp(0.0, 1.0);
+                factor *= 0.5 + 0.5 * ratio;
+            }
+        }
+        factor
+    }
+
+    pub fn apply(&self, allowable: Pressure) -> Pressure {
+        allowable * self.factor()
+    }
+
+    /// Setback still needed from the slope crest; zero when clear
+    pub fn additional_setback(&self) -> Length {
+        self.slope.map_or(Length::new::<foot>(0.0), |slope| {
+            let short = slope.required_setback() - slope.crest_distance;
+            if short > Length::new::<foot>(0.0) { short } else { Length::new::<foot>(0.0) }
+        })
+    }
+
+    /// One line per condition that affects the setup
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if self.saturated {
+            warnings.push("Saturated ground: allowable bearing halved".to_string());
+        }
+        match self.frost {
+            Some(FrostState::Frozen) => warnings.push(
+                "Frozen ground: no credit taken for frost; recheck if the ground may thaw during the lift".to_string(),
+            ),
+            Some(FrostState::Thawing) => warnings.push("Thawing ground: allowable bearing halved".to_string()),
+            None => {}
+        }
+        if let Some(slope) = self.slope {
+            let short = self.additional_setback();
+            if short.get::<foot>() > 0.0 {
+                warnings.push(format!(
+                    "Pads {:.1} ft from a {:.1} ft slope crest: move back {:.1} ft to clear the 1:1 line from the toe",
+                    slope.crest_distance.get::<foot>(),
+                    slope.height.get::<foot>(),
+                    short.get::<foot>(),
+                ));
+            }
+        }
+        warnings
+    }
+}
+
+/// 5.14·s_u over a factor of safety of 3
+fn clay_bearing(undrained_strength_psf: f64) -> Pressure {
+    Pressure::new::<pound_force_per_square_foot>(5.14 * undrained_strength_psf / 3.0)
+}
+
+/// Common soil bearing capabilities
+pub mod soil_capacities {
+    use crate::types::*;
+
+    pub fn soft_clay() -> Pressure {
+        Pressure::new::<psi>(10.0)
+    }
+
+    pub fn medium_clay() -> Pressure {
+        Pressure::new::<psi>(25.0)
+    }
+
+    pub fn stiff_clay() -> Pressure {
+        Pressure::new::<psi>(40.0)
+    }
+
+    pub fn loose_sand() -> Pressure {
+        Pressure::new::<psi>(20.0)
+    }
+
+    pub fn dense_sand() -> Pressure {
+        Pressure::new::<psi>(50.0)
+    }
+
+    pub fn gravel() -> Pressure {
+        Pressure::new::<psi>(80.0)
+    }
+
+    pub fn soft_rock() -> Pressure {
+        Pressure::new::<psi>(150.0)
+    }
+
+    pub fn hard_rock() -> Pressure {
+        Pressure::new::<psi>(300.0)
+    }
+
+    pub fn paved_surface() -> Pressure {
+        Pressure::new::<psi>(100.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_uscs_presumptive_bearing() {
+        let gravel: UscsClass = "gw".parse().unwrap();
+        assert!(gravel.is_granular());
+        assert_relative_eq!(gravel.presumptive_bearing().get::<pound_force_per_square_foot>(), 3000.0, epsilon = 1e-9);
+        let clay = SoilCapacity::Uscs(UscsClass::CL).allowable_bearing(Length::new::<foot>(4.0));
+        assert_relative_eq!(clay.get::<pound_force_per_square_foot>(), 1500.0, epsilon = 1e-9);
+        assert_eq!(UscsClass::PT.presumptive_bearing().get::<psi>(), 0.0);
+        assert!("XX".parse::<UscsClass>().is_err());
+    }
+
+    #[test]
+    fn test_spt_and_cpt_correlations() {
+        let narrow = Length::new::<foot>(3.0);
+        let wide = Length::new::<foot>(8.0);
+        let spt = |correlation| SoilCapacity::Spt { n60: 20.0, correlation };
+
+        // Meyerhof: 20/4 = 5 ksf narrow; 20/6 × (9/8)² wide
+        let psf = |p: Pressure| p.get::<pound_force_per_square_foot>();
+        assert_relative_eq!(psf(spt(SptCorrelation::Meyerhof).allowable_bearing(narrow)), 5000.0, epsilon = 1e-9);
+        assert_relative_eq!(
+            psf(spt(SptCorrelation::Meyerhof).allowable_bearing(wide)),
+            20.0 / 6.0 * 1.265625 * 1000.0,
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(psf(spt(SptCorrelation::Bowles).allowable_bearing(narrow)), 8000.0, epsilon = 1e-9);
+
+        // Clay at N = 20: s_u = 2.5 ksf
+        assert_relative_eq!(
+            psf(spt(SptCorrelation::TerzaghiPeck).allowable_bearing(narrow)),
+            5.14 * 2500.0 / 3.0,
+            epsilon = 1e-9
+        );
+
+        let qc = Pressure::new::<pound_force_per_square_foot>(150_000.0);
+        let cpt = SoilCapacity::Cpt { tip_resistance: qc, correlation: CptCorrelation::Meyerhof };
+        assert_relative_eq!(psf(cpt.allowable_bearing(narrow)), 5000.0, epsilon = 1e-9);
+        let cpt = SoilCapacity::Cpt { tip_resistance: qc, correlation: CptCorrelation::undrained(15.0).unwrap() };
+        assert_relative_eq!(psf(cpt.allowable_bearing(narrow)), 5.14 * 10_000.0 / 3.0, epsilon = 1e-9);
+        assert!(matches!(CptCorrelation::undrained(0.0), Err(SoilError::InvalidConeFactor(_))));
+        #[cfg(feature = "serde")]
+        assert!(serde_json::from_str::<CptCorrelation>(r#"{ "Undrained": { "nkt": -5.0 } }"#).is_err());
+
+        assert_eq!(SoilCapacity::gravel().allowable_bearing(narrow), soil_capacities::gravel());
+    }
+
+    #[test]
+    fn test_condition_modifiers() {
+        let allowable = Pressure::new::<pound_force_per_square_foot>(4000.0);
+        assert_eq!(SoilConditions::default().apply(allowable), allowable);
+        assert!(SoilConditions::default().warnings().is_empty());
+
+        let frozen = SoilConditions { frost: Some(FrostState::Frozen), ..Default::default() };
+        assert_relative_eq!(frozen.factor(), 1.0);
+        assert_eq!(frozen.warnings().len(), 1);
+
+        // Saturated, 5 ft back from a 10 ft slope crest: 0.5 × 0.75
+        let conditions = SoilConditions {
+            saturated: true,
+            frost: None,
+            slope: Some(Slope { height: Length::new::<foot>(10.0), crest_distance: Length::new::<foot>(5.0) }),
+        };
+        assert_relative_eq!(conditions.factor(), 0.375);
+        assert_relative_eq!(conditions.additional_setback().get::<foot>(), 5.0, epsilon = 1e-9);
+        assert_eq!(conditions.warnings().len(), 2);
+
+        let clear = SoilConditions {
+            slope: Some(Slope { height: Length::new::<foot>(10.0), crest_distance: Length::new::<foot>(12.0) }),
+            ..Default::default()
+        };
+        assert_relative_eq!(clear.factor(), 1.0);
+        assert!(clear.warnings().is_empty());
+    }
+}
@@ -106,38 +106,115 @@ impl OutriggerConfig {
             max_reactions: vec![max_load_per_pad; 4],
         }
     }
+
+    /// `pads` rollers or pads equally spaced on a ring, the first straight ahead
+    pub fn ring(radius: Length, pads: usize, max_load_per_pad: Force) -> Self {
+        let r = radius.get::<foot>();
+        Self {
+            positions: (0..pads)
+                .map(|i| {
+                    let (sin, cos) = (std::f64::consts::TAU * i as f64 / pads as f64).sin_cos();
+                    na::Point3::new(r * sin, 0.0, r * cos)
+                })
+                .collect(),
+            max_reactions: vec![max_load_per_pad; pads],
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum StabilityError {
+    /// The system COG is outside the supports: an outrigger would have to
+    /// pull down on the ground
+    #[error("Outrigger {index} lifts off: equilibrium needs {uplift} of uplift")]
+    Uplift { index: usize, uplift: DisplayForce },
 }
 
 /// Calculate outrigger reaction forces for static equilibrium
 /// 
 /// Uses method of joints - solves for reaction forces at each support point
-/// that satisfy ΣF = 0 and ΣM = 0
+/// that satisfy ΣF = 0 and ΣM = 0. Errors when equilibrium needs a
+/// negative (uplift) reaction, since supports can only push.
 pub fn calculate_outrigger_reactions(
     config: &OutriggerConfig,
     system_cog: na::Point3<f64>,
     total_weight: Mass,
-) -> Vec<Force> {
+) -> Result<Vec<Force>, StabilityError> {
     let num_outriggers = config.positions.len();
     
     // For 4 outriggers (statically determinate):
     // We have 3 equilibrium equations (ΣFy=0, ΣMx=0, ΣMz=0) plus assumption of no uplift
     // This gives us 4 reactions
     
-    if num_outriggers == 4 {
+    let reactions = if num_outriggers == 4 {
         // Use moment equilibrium about X and Z axes
-        return solve_four_point_reactions(config, system_cog, total_weight);
+        solve_four_point_reactions(config, system_cog, total_weight)
+    } else {
+        // Other layouts (rings) as a rigid base on equal supports
+        rigid_base_reactions(&config.positions, system_cog, total_weight.get::<pound>())
+    };
+
+    // Report the support with the most uplift
+    if let Some((index, &r)) = reactions.iter().enumerate().min_by(|a, b| a.1.total_cmp(b.1))
+        && r < 0.0
+    {
+        return Err(StabilityError::Uplift { index, uplift: DisplayForce(Force::new::<pound_force>(-r)) });
     }
-    
-    // For other configurations, need more sophisticated solver
-    // TODO: Implement least-squares or matrix inversion method
-    vec![Force::new::<pound_force>(0.0); num_outriggers]
+    Ok(reactions.into_iter().map(Force::new::<pound_force>).collect())
+}
+
+/// Reactions (lbs) under a rigid base on equally stiff supports
+///
+/// Each support carries the average plus a share of the moment in
+/// proportion to its distance from the support centroid:
+/// R_i = W/n + W·e·I⁻¹·d_i, with `I` the second moment of the support
+/// positions in plan. Negative values are uplift.
+pub(crate) fn rigid_base_reactions(positions: &[na::Point3<f64>], cog: na::Point3<f64>, weight: f64) -> Vec<f64> {
+    let n = positions.len() as f64;
+    let centroid = positions.iter().fold(na::Vector2::zeros(), |sum, p| sum + na::Vector2::new(p.x, p.z)) / n;
+    let offsets: Vec<na::Vector2<f64>> =
+        positions.iter().map(|p| na::Vector2::new(p.x, p.z) - centroid).collect();
+    let second_moment = offsets.iter().fold(na::Matrix2::zeros(), |sum, d| sum + d * d.transpose());
+    let eccentricity = na::Vector2::new(cog.x, cog.z) - centroid;
+    let moment = second_moment.try_inverse().map_or(na::Vector2::zeros(), |inv| inv * eccentricity * weight);
+
+    offsets.iter().map(|d| weight / n + moment.dot(d)).collect()
+}
+
+/// Stability of a ring-supported crane against tipping over the ring
+/// toward the load
+///
+/// The tipping edge is the tangent to the ring at the point nearest the
+/// load's plan position.
+pub fn calculate_ring_stability(
+    crane_cog: na::Point3<f64>,
+    crane_weight: Mass,
+    load_position: na::Point3<f64>,
+    load_weight: Mass,
+    ring_center: na::Point3<f64>,
+    ring_radius: Length,
+) -> StabilityAnalysis {
+    let toward = na::Vector3::new(load_position.x - ring_center.x, 0.0, load_position.z - ring_center.z);
+    let direction = if toward.norm() > 1e-9 { toward.normalize() } else { na::Vector3::z() };
+    let edge = ring_center + direction * ring_radius.get::<foot>();
+    let axis = na::Unit::new_normalize(na::Vector3::y().cross(&direction));
+
+    let mut analysis = calculate_stability(crane_cog, crane_weight, load_position, load_weight, edge, axis);
+    analysis.tipping_edge = if direction.z.abs() >= direction.x.abs() {
+        if direction.z >= 0.0 { TippingEdge::Front } else { TippingEdge::Rear }
+    } else if direction.x >= 0.0 {
+        TippingEdge::Right
+    } else {
+        TippingEdge::Left
+    };
+    analysis
 }
 
 fn solve_four_point_reactions(
     config: &OutriggerConfig,
     cog: na::Point3<f64>,
     weight: Mass,
-) -> Vec<Force> {
+) -> Vec<f64> {
     let w = weight.get::<pound>();
     let positions = &config.positions;
     
@@ -158,12 +235,7 @@ fn solve_four_point_reactions(
     let r3 = w * 0.25 * (1.0 + 2.0*x/x_spread) * (1.0 - 2.0*z/z_spread);
     let r4 = w * 0.25 * (1.0 - 2.0*x/x_spread) * (1.0 - 2.0*z/z_spread);
     
-    vec![
-        Force::new::<pound_force>(r1.max(0.0)),
-        Force::new::<pound_force>(r2.max(0.0)),
-        Force::new::<pound_force>(r3.max(0.0)),
-        Force::new::<pound_force>(r4.max(0.0)),
-    ]
+    vec![r1, r2, r3, r4]
 }
 
 #[cfg(test)]
@@ -218,7 +290,7 @@ mod tests {
         let cog = na::Point3::new(0.0, 5.0, 0.0);
         let weight = Mass::new::<pound>(40000.0);
         
-        let reactions = calculate_outrigger_reactions(&config, cog, weight);
+        let reactions = calculate_outrigger_reactions(&config, cog, weight).unwrap();
         
         // Each outrigger should carry 1/4 of the load
         for reaction in reactions {
@@ -229,4 +301,34 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_ring_reactions_and_stability() {
+        // 12 pads on a 30 ft radius; COG 5 ft forward of center
+        let config = OutriggerConfig::ring(Length::new::<foot>(30.0), 12, Force::new::<pound_force>(200000.0));
+        let weight = Mass::new::<pound>(1_200_000.0);
+        let reactions = calculate_outrigger_reactions(&config, na::Point3::new(0.0, 20.0, 5.0), weight).unwrap();
+        let total: f64 = reactions.iter().map(|r| r.get::<pound_force>()).sum();
+        assert_relative_eq!(total, 1_200_000.0, max_relative = 1e-9);
+
+        // Σz² = 12 × 30²/2 = 5,400: front pad 100,000 + 1.2M × 5 × 30 / 5,400
+        assert_relative_eq!(reactions[0].get::<pound_force>(), 100_000.0 + 1_200_000.0 * 150.0 / 5400.0, max_relative = 1e-9);
+        assert_relative_eq!(reactions[6].get::<pound_force>(), 100_000.0 - 1_200_000.0 * 150.0 / 5400.0, max_relative = 1e-9);
+
+        // COG past the front pad: the rear pad would have to hold the ring down
+        let uplift = calculate_outrigger_reactions(&config, na::Point3::new(0.0, 20.0, 40.0), weight);
+        assert!(matches!(uplift, Err(StabilityError::Uplift { index: 6, .. })));
+
+        // Load 100 ft out to the right, ring edge at 30 ft
+        let stability = calculate_ring_stability(
+            na::Point3::new(0.0, 20.0, 0.0),
+            Mass::new::<pound>(1_000_000.0),
+            na::Point3::new(100.0, 50.0, 0.0),
+            Mass::new::<pound>(200_000.0),
+            na::Point3::origin(),
+            Length::new::<foot>(30.0),
+        );
+        assert!(matches!(stability.tipping_edge, TippingEdge::Right));
+        assert_relative_eq!(stability.stability_factor, 30.0 * 1_000_000.0 / (70.0 * 200_000.0), max_relative = 1e-9);
+    }
 }
@@ -1,22 +1,33 @@
 use nalgebra as na;
+use crate::physics::luffing::{LuffingError, LuffingGeometry, LuffingMember};
 use crate::types::*;
 
 /// A force vector in 3D space with magnitude and direction
+///
+/// `f64` unless built in another [`Real`] scalar such as `f32`.
 #[derive(Debug, Clone, Copy)]
-pub struct ForceVector {
+pub struct ForceVector<T: Real = f64> {
     /// Point of application (Y-up, Z-forward)
-    pub point: na::Point3<f64>,
+    pub point: na::Point3<T>,
     /// Force vector (N or lbf, depending on your religion)
-    pub force: na::Vector3<f64>,
+    pub force: na::Vector3<T>,
 }
 
-impl ForceVector {
+impl<T: Real> ForceVector<T> {
     /// Create a new force vector
     /// point: location in feet, force: magnitude in pounds-force
-    pub fn new(point: na::Point3<f64>, force: na::Vector3<f64>) -> Self {
+    pub fn new(point: na::Point3<T>, force: na::Vector3<T>) -> Self {
         Self { point, force }
     }
-    
+
+    /// Magnitude of the force
+    pub fn magnitude(&self) -> T {
+        self.force.magnitude()
+    }
+}
+
+impl ForceVector {
+
     /// Gravity force for a mass at a point
     pub fn from_weight(weight: Mass, point: na::Point3<f64>) -> Self {
         let force_lbf = weight.get::<pound>(); // Mass in lbf = mass in lb on Earth
@@ -25,21 +36,16 @@ impl ForceVector {
             force: na::Vector3::new(0.0, -force_lbf, 0.0), // Down is -Y
         }
     }
-    
-    /// Magnitude of the force
-    pub fn magnitude(&self) -> f64 {
-        self.force.magnitude()
-    }
 }
 
 /// Calculate moment (torque) about a point
 /// 
 /// Moment = r × F (cross product)
 /// Returns moment vector (right-hand rule)
-pub fn moment_about_point(
-    force: &ForceVector,
-    pivot: na::Point3<f64>,
-) -> na::Vector3<f64> {
+pub fn moment_about_point<T: Real>(
+    force: &ForceVector<T>,
+    pivot: na::Point3<T>,
+) -> na::Vector3<T> {
     let r = force.point - pivot; // Position vector from pivot to force application
     r.cross(&force.force)
 }
@@ -47,22 +53,22 @@ pub fn moment_about_point(
 /// Calculate scalar moment about an axis
 /// 
 /// For tipping calculations, we care about moment magnitude around a specific axis
-pub fn moment_about_axis(
-    force: &ForceVector,
-    pivot: na::Point3<f64>,
-    axis: na::Unit<na::Vector3<f64>>,
-) -> f64 {
+pub fn moment_about_axis<T: Real>(
+    force: &ForceVector<T>,
+    pivot: na::Point3<T>,
+    axis: na::Unit<na::Vector3<T>>,
+) -> T {
     let moment = moment_about_point(force, pivot);
     moment.dot(&axis.into_inner())
 }
 
 /// Sum all forces in a system
-pub fn sum_forces(forces: &[ForceVector]) -> na::Vector3<f64> {
+pub fn sum_forces<T: Real>(forces: &[ForceVector<T>]) -> na::Vector3<T> {
     forces.iter().map(|f| f.force).sum()
 }
 
 /// Sum all moments about a point
-pub fn sum_moments(forces: &[ForceVector], pivot: na::Point3<f64>) -> na::Vector3<f64> {
+pub fn sum_moments<T: Real>(forces: &[ForceVector<T>], pivot: na::Point3<T>) -> na::Vector3<T> {
     forces.iter()
         .map(|f| moment_about_point(f, pivot))
         .sum()
@@ -73,11 +79,11 @@ pub fn sum_moments(forces: &[ForceVector], pivot: na::Point3<f64>) -> na::Vector
 /// For equilibrium:
 /// - Sum of forces = 0
 /// - Sum of moments = 0
-pub fn is_in_equilibrium(
-    forces: &[ForceVector],
-    pivot: na::Point3<f64>,
-    force_tolerance: f64,
-    moment_tolerance: f64,
+pub fn is_in_equilibrium<T: Real>(
+    forces: &[ForceVector<T>],
+    pivot: na::Point3<T>,
+    force_tolerance: T,
+    moment_tolerance: T,
 ) -> bool {
     let net_force = sum_forces(forces);
     let net_moment = sum_moments(forces, pivot);
@@ -103,6 +109,61 @@ pub fn center_of_gravity(masses: &[(Mass, na::Point3<f64>)]) -> na::Point3<f64>
     na::Point3::from(weighted_sum / total_weight)
 }
 
+/// Forces at the boom foot pin, in the boom's vertical plane
+#[derive(Debug, Clone, Copy)]
+pub struct BoomFootReactions {
+    /// Pin force on the boom along the ground, positive toward the tip
+    pub horizontal: Force,
+    /// Pin force on the boom, positive up
+    pub vertical: Force,
+    /// Axial compression in the boom at the foot
+    pub axial_compression: Force,
+    /// Pin force across the boom
+    pub shear: Force,
+    /// Cylinder thrust or pendant tension holding the boom
+    pub member_force: Force,
+}
+
+/// Boom foot pin reactions and boom compression for `hook_load` on a boom
+/// of `boom_length` at `boom_angle`
+///
+/// The boom is a free body loaded by its own weight at its center of
+/// gravity, the hook load at the tip and the luffing member; the pin
+/// carries the balance.
+pub fn boom_foot_reactions(
+    geometry: &LuffingGeometry,
+    boom_length: Length,
+    boom_angle: Angle,
+    hook_load: Mass,
+) -> Result<BoomFootReactions, LuffingError> {
+    let analysis = geometry.analyze(boom_length, boom_angle, hook_load)?;
+    let member = analysis.member_force.get::<pound_force>();
+
+    // Member force on the boom: a cylinder pushes from its base, pendants
+    // pull toward the gantry
+    let (fixed, boom_pin) = geometry.member.pins(boom_angle);
+    let along = na::Vector2::new(boom_pin[0] - fixed[0], boom_pin[1] - fixed[1]).normalize();
+    let member_on_boom = match geometry.member {
+        LuffingMember::Cylinder { .. } => along * member,
+        LuffingMember::Pendant { .. } => -along * member,
+    };
+
+    let weights = weight_of(geometry.boom_weight + hook_load).get::<pound_force>();
+    let pin = -(member_on_boom + na::Vector2::new(0.0, -weights));
+
+    let (sin, cos) = boom_angle.get::<radian>().sin_cos();
+    let axis = na::Vector2::new(cos, sin);
+    let normal = na::Vector2::new(-sin, cos);
+
+    Ok(BoomFootReactions {
+        horizontal: Force::new::<pound_force>(pin.x),
+        vertical: Force::new::<pound_force>(pin.y),
+        axial_compression: Force::new::<pound_force>(pin.dot(&axis)),
+        shear: Force::new::<pound_force>(pin.dot(&normal)),
+        member_force: analysis.member_force,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -159,4 +220,58 @@ mod tests {
         
         assert!(is_in_equilibrium(&forces, na::Point3::origin(), 0.1, 0.1));
     }
+    
+    #[test]
+    fn test_single_precision_equilibrium() {
+        let forces: [ForceVector<f32>; 3] = [
+            ForceVector::new(na::Point3::new(-10.0, 0.0, 0.0), na::Vector3::new(0.0, -1000.0, 0.0)),
+            ForceVector::new(na::Point3::new(10.0, 0.0, 0.0), na::Vector3::new(0.0, -1000.0, 0.0)),
+            ForceVector::new(na::Point3::origin(), na::Vector3::new(0.0, 2000.0, 0.0)),
+        ];
+        assert!(is_in_equilibrium(&forces, na::Point3::origin(), 0.1, 0.1));
+
+        let axis = na::Vector3::z_axis();
+        assert_relative_eq!(moment_about_axis(&forces[1], na::Point3::origin(), axis), -10000.0f32);
+    }
+
+    #[test]
+    fn test_boom_foot_reactions_balance() {
+        use crate::physics::luffing::{LuffingGeometry, LuffingMember};
+
+        // Horizontal 100 ft boom, pendant from a gantry 20 ft above the foot to the tip
+        let geometry = LuffingGeometry {
+            member: LuffingMember::Pendant {
+                gantry_r: Length::new::<foot>(0.0),
+                gantry_y: Length::new::<foot>(20.0),
+                boom_attachment: Length::new::<foot>(100.0),
+            },
+            boom_weight: Mass::new::<pound>(10_000.0),
+            boom_cg_fraction: 0.5,
+            rated_force: None,
+        };
+        let boom = Length::new::<foot>(100.0);
+        let reactions =
+            boom_foot_reactions(&geometry, boom, Angle::new::<degree>(0.0), Mass::new::<pound>(5_000.0)).unwrap();
+
+        // Pendant lifts the tip (10,000 × 50 + 5,000 × 100) / 100 = 10,000 lbf and,
+        // at 20 ft rise over 100 ft, pulls back 5 × that; the pin takes the rest
+        assert_relative_eq!(reactions.vertical.get::<pound_force>(), 5_000.0, max_relative = 1e-6);
+        assert_relative_eq!(reactions.axial_compression.get::<pound_force>(), 50_000.0, max_relative = 1e-6);
+        assert_relative_eq!(reactions.shear.get::<pound_force>(), 5_000.0, max_relative = 1e-6);
+
+        // The boom is a free body in equilibrium
+        let tension = reactions.member_force.get::<pound_force>();
+        let to_gantry = na::Vector3::new(-100.0, 20.0, 0.0).normalize();
+        let forces = [
+            ForceVector::new(na::Point3::origin(), na::Vector3::new(
+                reactions.horizontal.get::<pound_force>(),
+                reactions.vertical.get::<pound_force>(),
+                0.0,
+            )),
+            ForceVector::new(na::Point3::new(100.0, 0.0, 0.0), to_gantry * tension),
+            ForceVector::new(na::Point3::new(50.0, 0.0, 0.0), na::Vector3::new(0.0, -10_000.0, 0.0)),
+            ForceVector::new(na::Point3::new(100.0, 0.0, 0.0), na::Vector3::new(0.0, -5_000.0, 0.0)),
+        ];
+        assert!(is_in_equilibrium(&forces, na::Point3::origin(), 1.0, 1.0));
+    }
 }
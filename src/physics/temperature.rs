@@ -0,0 +1,177 @@
+//! Cold-weather derating
+//!
+//! Temperature limits vary by manufacturer, so each rule is configurable.
+//! The defaults only warn and never change capacity; derating is opt-in
+//! with the crane manufacturer's figure.
+//!
+//! - Hydraulics: cold oil is sluggish below a threshold, and some
+//!   manufacturers reduce capacity there
+//! - Steel: impact toughness drops below −20°F, so shock loading is a risk
+//! - Synthetic slings: ASME B30.9 limits nylon and polyester web to −40°F
+//!   through 194°F
+
+use crate::report::*;
+use crate::types::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TemperatureRule {
+    /// Multiply rated capacity by `capacity_factor` below `below`; 1.0
+    /// only warns
+    Hydraulic { below: ThermodynamicTemperature, capacity_factor: f64 },
+
+    /// Warn of brittle steel below `below`
+    SteelToughness { below: ThermodynamicTemperature },
+
+    /// Synthetic slings may only be used from `min` to `max`
+    SyntheticSlings { min: ThermodynamicTemperature, max: ThermodynamicTemperature },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TemperatureRules {
+    pub rules: Vec<TemperatureRule>,
+}
+
+impl Default for TemperatureRules {
+    fn default() -> Self {
+        let f = ThermodynamicTemperature::new::<degree_fahrenheit>;
+        Self {
+            rules: vec![
+                TemperatureRule::Hydraulic { below: f(0.0), capacity_factor: 1.0 },
+                TemperatureRule::SteelToughness { below: f(-20.0) },
+                TemperatureRule::SyntheticSlings { min: f(-40.0), max: f(194.0) },
+            ],
+        }
+    }
+}
+
+impl TemperatureRules {
+    /// Derate capacity to `capacity_factor` below `below`, replacing any
+    /// other hydraulic rule
+    pub fn with_hydraulic_derating(mut self, below: ThermodynamicTemperature, capacity_factor: f64) -> Self {
+        self.rules.retain(|rule| !matches!(rule, TemperatureRule::Hydraulic { .. }));
+        self.rules.push(TemperatureRule::Hydraulic { below, capacity_factor });
+        self
+    }
+
+    /// Apply the rules at `temperature`; `synthetic_slings` when the
+    /// rigging uses any
+    pub fn assess(&self, temperature: ThermodynamicTemperature, synthetic_slings: bool) -> TemperatureAssessment {
+        let mut assessment = TemperatureAssessment {
+            temperature,
+            capacity_factor: 1.0,
+            warnings: Vec::new(),
+            failures: Vec::new(),
+        };
+        let f = |t: ThermodynamicTemperature| t.get::<degree_fahrenheit>();
+        for rule in &self.rules {
+            match *rule {
+                TemperatureRule::Hydraulic { below, capacity_factor } if temperature < below => {
+                    assessment.capacity_factor = assessment.capacity_factor.min(capacity_factor);
+                    assessment.warnings.push(if capacity_factor < 1.0 {
+                        format!(
+                            "Below {:.0}°F: warm up hydraulic oil, capacity taken at {:.0}%",
+                            f(below),
+                            capacity_factor * 100.0
+                        )
+                    } else {
+                        format!("Below {:.0}°F: warm up hydraulic oil before lifting", f(below))
+                    });
+                }
+                TemperatureRule::SteelToughness { below } if temperature < below => {
+                    assessment.warnings.push(format!(
+                        "Below {:.0}°F: steel impact toughness reduced, avoid shock loading",
+                        f(below)
+                    ));
+                }
+                TemperatureRule::SyntheticSlings { min, max } if synthetic_slings && (temperature < min || temperature > max) => {
+                    assessment.failures.push(format!(
+                        "Synthetic slings are limited to {:.0}°F through {:.0}°F",
+                        f(min),
+                        f(max)
+                    ));
+                }
+                _ => {}
+            }
+        }
+        assessment
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TemperatureAssessment {
+    pub temperature: ThermodynamicTemperature,
+
+    /// Lowest capacity factor of the rules that apply
+    pub capacity_factor: f64,
+    pub warnings: Vec<String>,
+
+    /// Equipment that may not be used at this temperature
+    pub failures: Vec<String>,
+}
+
+impl AnalysisReport for TemperatureAssessment {
+    fn title(&self) -> String {
+        "Temperature".into()
+    }
+
+    fn checks(&self) -> Vec<ValidationCheck> {
+        let temperature = self.temperature.get::<degree_fahrenheit>();
+        let (status, details) = if !self.failures.is_empty() {
+            (CheckStatus::Fail, self.failures.join("; "))
+        } else if !self.warnings.is_empty() {
+            (CheckStatus::Warning, self.warnings.join("; "))
+        } else {
+            (CheckStatus::Pass, "No cold-weather limits apply".into())
+        };
+        vec![ValidationCheck {
+            name: "Cold Weather".into(),
+            status,
+            details: format!("{:.0}°F: {}", temperature, details),
+            margin: None,
+        }]
+    }
+
+    fn results(&self) -> Vec<NumericResult> {
+        vec![
+            NumericResult::new("temperature", self.temperature.get::<degree_fahrenheit>(), "°F"),
+            NumericResult::new("temperature_capacity_factor", self.capacity_factor, ""),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_default_rules() {
+        let rules = TemperatureRules::default();
+        let f = ThermodynamicTemperature::new::<degree_fahrenheit>;
+
+        let mild = rules.assess(f(40.0), true);
+        assert_relative_eq!(mild.capacity_factor, 1.0);
+        assert_eq!(mild.status(), CheckStatus::Pass);
+
+        // The defaults warn without touching capacity
+        let cold = rules.assess(f(-10.0), true);
+        assert_relative_eq!(cold.capacity_factor, 1.0);
+        assert_eq!(cold.warnings.len(), 1);
+        assert_eq!(cold.status(), CheckStatus::Warning);
+
+        let derated = rules.clone().with_hydraulic_derating(f(0.0), 0.9).assess(f(-10.0), true);
+        assert_relative_eq!(derated.capacity_factor, 0.9);
+        assert!(derated.warnings[0].contains("capacity taken at 90%"));
+
+        // Synthetic slings only fail when they are in the rigging
+        let arctic = rules.assess(f(-45.0), true);
+        assert_eq!(arctic.warnings.len(), 2);
+        assert_eq!(arctic.status(), CheckStatus::Fail);
+        assert_eq!(rules.assess(f(-45.0), false).status(), CheckStatus::Warning);
+    }
+}
@@ -0,0 +1,268 @@
+//! Finding lift windows in a wind forecast
+//!
+//! A [`WeatherProvider`] supplies forecast samples over a time range; any
+//! forecast service can back it. Each sample holds until the next one.
+//! Periods are classed by the higher of the mean speed and gust against the
+//! crane's operating limits, and a lift fits where a run of acceptable
+//! periods is at least as long as the lift.
+//!
+//! Times are measured from any fixed reference the provider and caller
+//! agree on, e.g. the Unix epoch.
+
+use crate::physics::wind_loading::{WindAnalysis, WindCondition};
+use crate::types::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Forecast wind at one time
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WindSample {
+    pub time: Time,
+    pub wind_speed: Velocity,
+    pub gust: Velocity,
+
+    /// Direction the wind blows from
+    pub direction: Angle,
+}
+
+impl WindSample {
+    /// Higher of the mean speed and gust
+    pub fn peak(&self) -> Velocity {
+        if self.gust > self.wind_speed { self.gust } else { self.wind_speed }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum WeatherError {
+    #[error("Forecast unavailable: {0}")]
+    Provider(String),
+
+    #[error("Forecast samples are not in time order")]
+    Unordered,
+}
+
+/// Source of forecast wind
+pub trait WeatherProvider {
+    /// Samples covering `from` to `to`, in time order
+    fn forecast(&self, from: Time, to: Time) -> Result<Vec<WindSample>, WeatherError>;
+}
+
+/// A fixed forecast already in hand
+impl WeatherProvider for [WindSample] {
+    fn forecast(&self, from: Time, to: Time) -> Result<Vec<WindSample>, WeatherError> {
+        // Keep the sample in force at `from`
+        let start = self.iter().rposition(|s| s.time <= from).unwrap_or(0);
+        Ok(self[start..].iter().take_while(|s| s.time < to).copied().collect())
+    }
+}
+
+/// Stretch of forecast in one wind condition
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WeatherPeriod {
+    pub start: Time,
+    pub end: Time,
+    pub condition: WindCondition,
+
+    /// Highest mean speed or gust in the period
+    pub peak_wind: Velocity,
+}
+
+/// Time the lift fits in
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LiftWindow {
+    pub start: Time,
+    pub end: Time,
+
+    /// Some of the window is above the caution speed
+    pub marginal: bool,
+}
+
+impl LiftWindow {
+    pub fn duration(&self) -> Time {
+        self.end - self.start
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WeatherPlan {
+    pub periods: Vec<WeatherPeriod>,
+
+    /// Windows long enough for the lift, acceptable and marginal, in time order
+    pub windows: Vec<LiftWindow>,
+}
+
+impl WeatherPlan {
+    /// Earliest window entirely below the caution speed
+    pub fn first_window(&self) -> Option<&LiftWindow> {
+        self.windows.iter().find(|w| !w.marginal)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct WeatherWindowPlanner {
+    pub caution: Velocity,
+    pub shutdown: Velocity,
+    pub lift_duration: Time,
+}
+
+impl WeatherWindowPlanner {
+    /// Planner using the operating limits of the crane in `wind`
+    pub fn for_crane(wind: &WindAnalysis, lift_duration: Time) -> Self {
+        let (caution, shutdown) = wind.operating_limits();
+        Self { caution, shutdown, lift_duration }
+    }
+
+    fn classify(&self, wind: Velocity) -> WindCondition {
+        if wind >= self.shutdown {
+            WindCondition::Shutdown
+        } else if wind >= self.caution {
+            WindCondition::Caution
+        } else {
+            WindCondition::Safe
+        }
+    }
+
+    /// Classify the forecast from `from` to `to` and find the lift windows
+    pub fn plan<P: WeatherProvider + ?Sized>(
+        &self,
+        provider: &P,
+        from: Time,
+        to: Time,
+    ) -> Result<WeatherPlan, WeatherError> {
+        let samples = provider.forecast(from, to)?;
+        if samples.windows(2).any(|pair| pair[1].time <= pair[0].time) {
+            return Err(WeatherError::Unordered);
+        }
+
+        let mut periods: Vec<WeatherPeriod> = Vec::new();
+        for (i, sample) in samples.iter().enumerate() {
+            let start = if sample.time > from { sample.time } else { from };
+            let end = samples.get(i + 1).map_or(to, |next| if next.time < to { next.time } else { to });
+            if end <= start {
+                continue;
+            }
+            let peak = sample.peak();
+            let condition = self.classify(peak);
+            match periods.last_mut() {
+                Some(last) if last.condition == condition && last.end == start => {
+                    last.end = end;
+                    if peak > last.peak_wind {
+                        last.peak_wind = peak;
+                    }
+                }
+                _ => periods.push(WeatherPeriod { start, end, condition, peak_wind: peak }),
+            }
+        }
+
+        let windows = self.windows(&periods, false).into_iter().chain(self.windows(&periods, true)).collect();
+        let mut plan = WeatherPlan { periods, windows };
+        plan.windows.sort_by(|a: &LiftWindow, b| a.start.get::<second>().total_cmp(&b.start.get::<second>()));
+        Ok(plan)
+    }
+
+    /// Runs of usable periods long enough for the lift; with `marginal`,
+    /// only the runs that need caution periods to fit
+    fn windows(&self, periods: &[WeatherPeriod], marginal: bool) -> Vec<LiftWindow> {
+        let usable = |p: &WeatherPeriod| match p.condition {
+            WindCondition::Safe => true,
+            WindCondition::Caution => marginal,
+            _ => false,
+        };
+        let mut windows = Vec::new();
+        let mut run: Option<(Time, Time, bool)> = None;
+        for period in periods.iter().map(Some).chain([None]) {
+            match period {
+                Some(p) if usable(p) && run.is_some_and(|(_, end, _)| end == p.start) => {
+                    let (start, _, caution) = run.unwrap();
+                    run = Some((start, p.end, caution || p.condition == WindCondition::Caution));
+                }
+                _ => {
+                    if let Some((start, end, caution)) = run.take()
+                        && end - start >= self.lift_duration
+                        && caution == marginal
+                    {
+                        windows.push(LiftWindow { start, end, marginal });
+                    }
+                    if let Some(p) = period.filter(|p| usable(p)) {
+                        run = Some((p.start, p.end, p.condition == WindCondition::Caution));
+                    }
+                }
+            }
+        }
+        windows
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::equipment::CraneType;
+
+    fn sample(at: f64, mph: f64, gust: f64) -> WindSample {
+        WindSample {
+            time: Time::new::<hour>(at),
+            wind_speed: Velocity::new::<mile_per_hour>(mph),
+            gust: Velocity::new::<mile_per_hour>(gust),
+            direction: Angle::new::<degree>(270.0),
+        }
+    }
+
+    #[test]
+    fn test_finds_lift_windows() {
+        // All terrain: caution at 20 mph, shutdown at 30 mph
+        let wind = WindAnalysis::new(
+            CraneType::AllTerrain,
+            Length::new::<foot>(150.0),
+            Angle::new::<degree>(60.0),
+            Area::new::<square_foot>(50.0),
+            Velocity::new::<mile_per_hour>(0.0),
+        );
+        let planner = WeatherWindowPlanner::for_crane(&wind, Time::new::<hour>(3.0));
+        let forecast = [
+            sample(0.0, 10.0, 15.0),
+            sample(2.0, 12.0, 18.0),
+            sample(4.0, 15.0, 32.0),
+            sample(6.0, 12.0, 22.0),
+            sample(8.0, 14.0, 24.0),
+            sample(10.0, 8.0, 12.0),
+            sample(14.0, 25.0, 35.0),
+        ];
+        let plan = planner
+            .plan(&forecast[..], Time::new::<hour>(0.0), Time::new::<hour>(16.0))
+            .unwrap();
+
+        // Safe to 4 h, gusts past shutdown to 6 h, caution to 10 h, safe to 14 h
+        let conditions: Vec<WindCondition> = plan.periods.iter().map(|p| p.condition).collect();
+        assert_eq!(
+            conditions,
+            vec![
+                WindCondition::Safe,
+                WindCondition::Shutdown,
+                WindCondition::Caution,
+                WindCondition::Safe,
+                WindCondition::Shutdown
+            ]
+        );
+        assert_eq!(plan.periods[2].peak_wind, Velocity::new::<mile_per_hour>(24.0));
+
+        let hours = |t: Time| t.get::<hour>();
+        assert_eq!(plan.windows.len(), 3);
+        let first = plan.first_window().unwrap();
+        assert_eq!((hours(first.start), hours(first.end)), (0.0, 4.0));
+
+        // 6 to 14 h fits with caution; 10 to 14 h fits clear
+        let marginal = plan.windows.iter().find(|w| w.marginal).unwrap();
+        assert_eq!((hours(marginal.start), hours(marginal.end)), (6.0, 14.0));
+        assert!(plan.windows.iter().any(|w| !w.marginal && hours(w.start) == 10.0));
+
+        let shuffled = [sample(2.0, 10.0, 15.0), sample(1.0, 10.0, 15.0)];
+        assert!(matches!(
+            planner.plan(&shuffled[..], Time::new::<hour>(0.0), Time::new::<hour>(4.0)),
+            Err(WeatherError::Unordered)
+        ));
+    }
+}
@@ -0,0 +1,201 @@
+//! Converting the wind data sites actually have
+//!
+//! Forecasts and weather stations report means over different periods,
+//! while crane wind limits are 3-second gusts. Speeds convert through the
+//! Durst curve's ratio of each averaging period to the hourly mean in open
+//! terrain: 3 s 1.52, 1 min 1.25, 10 min 1.06. Beaufort forces are 10-minute
+//! means at 10 m, per the WMO scale.
+//!
+//! Feed [`WindAnalysis`](crate::physics::WindAnalysis) the 3-second gust.
+
+use crate::types::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Beaufort force upper bounds in m/s, forces 0 to 11
+const BEAUFORT_UPPER: [f64; 12] = [0.5, 1.5, 3.3, 5.5, 7.9, 10.7, 13.8, 17.1, 20.7, 24.4, 28.4, 32.6];
+
+/// Period a reported wind speed is averaged over
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AveragingPeriod {
+    ThreeSecond,
+    OneMinute,
+    TenMinute,
+    Hourly,
+}
+
+impl AveragingPeriod {
+    /// Ratio to the hourly mean
+    pub fn gust_factor(&self) -> f64 {
+        match self {
+            AveragingPeriod::ThreeSecond => 1.52,
+            AveragingPeriod::OneMinute => 1.25,
+            AveragingPeriod::TenMinute => 1.06,
+            AveragingPeriod::Hourly => 1.0,
+        }
+    }
+}
+
+/// Convert `speed` averaged over `from` to the equivalent over `to`
+pub fn convert_averaging(speed: Velocity, from: AveragingPeriod, to: AveragingPeriod) -> Velocity {
+    speed * (to.gust_factor() / from.gust_factor())
+}
+
+/// A reported wind speed and its averaging period
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WindReading {
+    pub speed: Velocity,
+    pub period: AveragingPeriod,
+}
+
+impl WindReading {
+    pub fn new(speed: Velocity, period: AveragingPeriod) -> Self {
+        Self { speed, period }
+    }
+
+    /// Mean wind of a Beaufort force, taken at the middle of its range
+    pub fn beaufort(force: u8) -> Self {
+        let (low, high) = beaufort_range(force);
+        let high = high.unwrap_or(low);
+        Self::new((low + high) / 2.0, AveragingPeriod::TenMinute)
+    }
+
+    /// Equivalent 3-second gust, for comparing with crane wind limits
+    pub fn three_second_gust(&self) -> Velocity {
+        convert_averaging(self.speed, self.period, AveragingPeriod::ThreeSecond)
+    }
+
+    pub fn ten_minute_mean(&self) -> Velocity {
+        convert_averaging(self.speed, self.period, AveragingPeriod::TenMinute)
+    }
+
+    pub fn beaufort_force(&self) -> u8 {
+        beaufort_force(self.ten_minute_mean())
+    }
+}
+
+/// Beaufort force of a 10-minute mean speed
+pub fn beaufort_force(ten_minute_mean: Velocity) -> u8 {
+    let mps = ten_minute_mean.get::<meter_per_second>();
+    BEAUFORT_UPPER.iter().position(|&upper| mps < upper).unwrap_or(12) as u8
+}
+
+/// 10-minute mean speed range of a Beaufort force; no upper bound for 12
+pub fn beaufort_range(force: u8) -> (Velocity, Option<Velocity>) {
+    let force = force.min(12) as usize;
+    let low = if force == 0 { 0.0 } else { BEAUFORT_UPPER[force - 1] };
+    (
+        Velocity::new::<meter_per_second>(low),
+        BEAUFORT_UPPER.get(force).map(|&high| Velocity::new::<meter_per_second>(high)),
+    )
+}
+
+pub fn beaufort_description(force: u8) -> &'static str {
+    match force {
+        0 => "Calm",
+        1 => "Light air",
+        2 => "Light breeze",
+        3 => "Gentle breeze",
+        4 => "Moderate breeze",
+        5 => "Fresh breeze",
+        6 => "Strong breeze",
+        7 => "Near gale",
+        8 => "Gale",
+        9 => "Strong gale",
+        10 => "Storm",
+        11 => "Violent storm",
+        _ => "Hurricane force",
+    }
+}
+
+/// Statistics of a run of anemometer samples
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GustStatistics {
+    pub mean: Velocity,
+    pub max: Velocity,
+    pub std_dev: Velocity,
+}
+
+impl GustStatistics {
+    /// `None` for no samples
+    pub fn from_samples(samples: &[Velocity]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let values: Vec<f64> = samples.iter().map(|v| v.get::<meter_per_second>()).collect();
+        let n = values.len() as f64;
+        let mean = values.iter().sum::<f64>() / n;
+        let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n;
+        let max = values.iter().copied().fold(f64::MIN, f64::max);
+        Some(Self {
+            mean: Velocity::new::<meter_per_second>(mean),
+            max: Velocity::new::<meter_per_second>(max),
+            std_dev: Velocity::new::<meter_per_second>(variance.sqrt()),
+        })
+    }
+
+    /// Standard deviation over the mean
+    pub fn turbulence_intensity(&self) -> f64 {
+        let mean = self.mean.get::<meter_per_second>();
+        if mean > 0.0 { self.std_dev.get::<meter_per_second>() / mean } else { 0.0 }
+    }
+
+    /// Highest sample over the mean
+    pub fn gust_factor(&self) -> f64 {
+        let mean = self.mean.get::<meter_per_second>();
+        if mean > 0.0 { self.max.get::<meter_per_second>() / mean } else { 1.0 }
+    }
+
+    /// Expected peak gust, mean plus `peak_factor` standard deviations
+    /// (3 to 4 for 3-second gusts over a 10-minute record)
+    pub fn expected_gust(&self, peak_factor: f64) -> Velocity {
+        self.mean + self.std_dev * peak_factor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_averaging_conversions_and_beaufort() {
+        let mph = Velocity::new::<mile_per_hour>;
+        let reading = WindReading::new(mph(20.0), AveragingPeriod::TenMinute);
+        assert_relative_eq!(reading.three_second_gust().get::<mile_per_hour>(), 20.0 * 1.52 / 1.06, max_relative = 1e-9);
+
+        let gust = WindReading::new(reading.three_second_gust(), AveragingPeriod::ThreeSecond);
+        assert_relative_eq!(gust.ten_minute_mean().get::<mile_per_hour>(), 20.0, max_relative = 1e-9);
+        assert_relative_eq!(
+            convert_averaging(mph(25.0), AveragingPeriod::OneMinute, AveragingPeriod::Hourly).get::<mile_per_hour>(),
+            20.0,
+            max_relative = 1e-9
+        );
+
+        // 20 mph = 8.9 m/s: force 5
+        assert_eq!(reading.beaufort_force(), 5);
+        assert_eq!(beaufort_description(5), "Fresh breeze");
+        assert_eq!(beaufort_force(Velocity::new::<meter_per_second>(40.0)), 12);
+        let (low, high) = beaufort_range(6);
+        assert_relative_eq!(low.get::<meter_per_second>(), 10.7);
+        assert_relative_eq!(high.unwrap().get::<meter_per_second>(), 13.8);
+        assert!(beaufort_range(12).1.is_none());
+        assert_eq!(WindReading::beaufort(6).beaufort_force(), 6);
+    }
+
+    #[test]
+    fn test_gust_statistics() {
+        let samples: Vec<Velocity> =
+            [8.0, 10.0, 12.0, 10.0].iter().map(|&v| Velocity::new::<meter_per_second>(v)).collect();
+        let stats = GustStatistics::from_samples(&samples).unwrap();
+        assert_relative_eq!(stats.mean.get::<meter_per_second>(), 10.0, max_relative = 1e-9);
+        assert_relative_eq!(stats.std_dev.get::<meter_per_second>(), 2f64.sqrt(), max_relative = 1e-9);
+        assert_relative_eq!(stats.gust_factor(), 1.2, max_relative = 1e-9);
+        assert_relative_eq!(stats.turbulence_intensity(), 2f64.sqrt() / 10.0, max_relative = 1e-9);
+        assert_relative_eq!(stats.expected_gust(3.5).get::<meter_per_second>(), 10.0 + 3.5 * 2f64.sqrt(), max_relative = 1e-9);
+        assert!(GustStatistics::from_samples(&[]).is_none());
+    }
+}
@@ -1,13 +1,67 @@
 use crate::types::*;
 use crate::equipment::CraneType;
+use crate::report::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Air density at sea level, slug/ft³
+pub(crate) const AIR_DENSITY: f64 = 0.00237;
+
+/// Drag coefficient of a suspended load, taken as a bluff body
+pub(crate) const LOAD_DRAG_COEFFICIENT: f64 = 1.5;
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct WindAnalysis {
     pub crane_type: CraneType,
     pub boom_length: Length,
     pub boom_angle: Angle,
     pub load_area: Area,
     pub wind_speed: Velocity,
+
+    /// Lattice boom section; without it the boom is a solid 3 ft (5 ft
+    /// for lattice cranes) member
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub lattice: Option<LatticeGeometry>,
+}
+
+/// Square lattice boom section for solidity-based drag
+///
+/// Drag follows ASCE 7 for trussed towers of square section:
+/// Cf = 4.0φ² − 5.9φ + 4.0 on the solid area of one face, reduced by
+/// (0.51φ² + 0.57) for round members. The two faces are assumed alike
+/// with one diagonal per panel.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LatticeGeometry {
+    /// Chord outside diameter
+    pub chord_diameter: Length,
+
+    /// Lacing outside diameter
+    pub lacing_diameter: Length,
+
+    /// Face width, chord center to chord center
+    pub width: Length,
+
+    /// Chord length between lacing nodes
+    pub panel_length: Length,
+}
+
+impl LatticeGeometry {
+    /// Solid area of one face over its enclosed area
+    pub fn solidity(&self) -> f64 {
+        let (d_c, d_l) = (self.chord_diameter.get::<foot>(), self.lacing_diameter.get::<foot>());
+        let (w, l) = (self.width.get::<foot>(), self.panel_length.get::<foot>());
+        let diagonal = (w * w + l * l).sqrt();
+        ((2.0 * d_c * l + d_l * diagonal) / (w * l)).min(1.0)
+    }
+
+    /// Force coefficient on the solid area of one face
+    pub fn drag_coefficient(&self) -> f64 {
+        let phi = self.solidity();
+        let square = 4.0 * phi * phi - 5.9 * phi + 4.0;
+        square * (0.51 * phi * phi + 0.57).min(1.0)
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -23,6 +77,7 @@ pub enum WindError {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum WindCondition {
     Safe,
     Caution,
@@ -44,9 +99,16 @@ impl WindAnalysis {
             boom_angle,
             load_area,
             wind_speed,
+            lattice: None,
         }
     }
 
+    /// Use a lattice section for the boom's wind area and drag
+    pub fn with_lattice(mut self, lattice: LatticeGeometry) -> Self {
+        self.lattice = Some(lattice);
+        self
+    }
+
     /// Calculate wind derating factor (multiply capacity by this)
     /// 
     /// Returns a factor between 0.0 and 1.0
@@ -218,14 +280,21 @@ impl WindAnalysis {
     /// - v = wind velocity
     /// - Cd = drag coefficient (~1.2 for lattice, ~0.8 for telescopic)
     /// - A = projected area
+    ///
+    /// With a [`LatticeGeometry`], Cd and A come from its solidity instead.
     pub fn wind_force_on_boom(&self) -> Force {
         let wind_fps = self.wind_speed.get::<foot_per_second>();
         let boom_len_ft = self.boom_length.get::<foot>();
         let angle_rad = self.boom_angle.get::<radian>();
         
-        // Air density (slug/ft³)
-        let rho = 0.00237;
+        let rho = AIR_DENSITY;
         
+        if let Some(lattice) = self.lattice {
+            let solid_area = lattice.solidity() * lattice.width.get::<foot>() * boom_len_ft * angle_rad.sin().abs();
+            let force_lbf = 0.5 * rho * wind_fps.powi(2) * lattice.drag_coefficient() * solid_area;
+            return Force::new::<pound_force>(force_lbf);
+        }
+
         // Drag coefficient
         let cd = match self.crane_type {
             CraneType::MobileLattice | CraneType::Crawler => 1.2,
@@ -252,8 +321,8 @@ impl WindAnalysis {
         let wind_fps = self.wind_speed.get::<foot_per_second>();
         let area_sqft = self.load_area.get::<square_foot>();
         
-        let rho = 0.00237;  // Air density
-        let cd = 1.5;  // Drag coefficient for bluff body
+        let rho = AIR_DENSITY;
+        let cd = LOAD_DRAG_COEFFICIENT;
         
         let force_lbf = 0.5 * rho * wind_fps.powi(2) * cd * area_sqft;
         
@@ -311,6 +380,43 @@ impl WindAnalysis {
     }
 }
 
+impl AnalysisReport for WindAnalysis {
+    fn title(&self) -> String {
+        "Wind".into()
+    }
+
+    fn checks(&self) -> Vec<ValidationCheck> {
+        let condition = self.wind_condition();
+        let (_, shutdown) = self.operating_limits();
+        let wind_mph = self.wind_speed.get::<mile_per_hour>();
+        let shutdown_mph = shutdown.get::<mile_per_hour>();
+
+        vec![ValidationCheck {
+            name: "Wind Speed".into(),
+            status: match condition {
+                WindCondition::Safe => CheckStatus::Pass,
+                WindCondition::Caution => CheckStatus::Warning,
+                WindCondition::Shutdown | WindCondition::OutOfService => CheckStatus::Fail,
+            },
+            details: format!(
+                "{:.1} mph ({:?}), shutdown at {:.0} mph",
+                wind_mph, condition, shutdown_mph
+            ),
+            margin: Some((shutdown_mph - wind_mph) / shutdown_mph * 100.0),
+        }]
+    }
+
+    fn results(&self) -> Vec<NumericResult> {
+        vec![
+            NumericResult::new("wind_speed", self.wind_speed.get::<mile_per_hour>(), "mph"),
+            NumericResult::new("derating_factor", self.derating_factor(), ""),
+            NumericResult::new("boom_force", self.wind_force_on_boom().get::<pound_force>(), "lbf"),
+            NumericResult::new("load_force", self.wind_force_on_load().get::<pound_force>(), "lbf"),
+            NumericResult::new("overturning_moment", self.wind_overturning_moment(), "lb·ft"),
+        ]
+    }
+}
+
 /// Common wind speeds for reference
 pub mod wind_speeds {
     use crate::types::*;
@@ -442,4 +548,32 @@ mod tests {
         // Should be more than zero (in caution range)
         assert!(derated.get::<pound>() > 0.0);
     }
+
+    #[test]
+    fn test_lattice_boom_drag_from_solidity() {
+        let inches = Length::new::<inch>;
+        let lattice = LatticeGeometry {
+            chord_diameter: inches(4.0),
+            lacing_diameter: inches(2.0),
+            width: Length::new::<foot>(6.0),
+            panel_length: Length::new::<foot>(8.0),
+        };
+        // (2 × 1/3 × 8 + 1/6 × 10) / 48
+        let phi = (16.0 / 3.0 + 10.0 / 6.0) / 48.0;
+        assert_relative_eq!(lattice.solidity(), phi, max_relative = 1e-9);
+        let cf = (4.0 * phi * phi - 5.9 * phi + 4.0) * (0.51 * phi * phi + 0.57);
+        assert_relative_eq!(lattice.drag_coefficient(), cf, max_relative = 1e-9);
+
+        let solid = WindAnalysis::new(
+            CraneType::Crawler,
+            Length::new::<foot>(200.0),
+            Angle::new::<degree>(90.0),
+            Area::new::<square_foot>(50.0),
+            Velocity::new::<foot_per_second>(30.0),
+        );
+        let open = solid.clone().with_lattice(lattice);
+        let expected = 0.5 * 0.00237 * 900.0 * cf * phi * 6.0 * 200.0;
+        assert_relative_eq!(open.wind_force_on_boom().get::<pound_force>(), expected, max_relative = 1e-9);
+        assert!(open.wind_force_on_boom() < solid.wind_force_on_boom());
+    }
 }
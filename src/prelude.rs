@@ -0,0 +1,26 @@
+//! Common types for lift planning
+//!
+//! ```
+//! use crane_core::prelude::*;
+//!
+//! let crane = MobileCrane::new("Grove", "GMK5250L", Length::new::<foot>(100.0), Length::new::<foot>(8.0));
+//! assert!(crane.boom_length > Length::new::<foot>(0.0));
+//! ```
+
+pub use crate::types::{
+    na, Angle, Area, Force, Length, Mass, Pressure, Velocity,
+    degree, foot, inch, kilogram, kilonewton, meter, metric_ton, mile_per_hour, millimeter,
+    newton, pound, pound_force, psi, radian, square_foot, ton_short,
+    PreferredDisplay, UnitPreferences, UnitSystem, WithUnit, mass_from_weight, weight_of,
+};
+
+pub use crate::equipment::{
+    ChartRated, Crane, CraneConfig, CraneType, HookBlock, Kinematic, MobileCrane, MobileCraneBuilder,
+    RatedCapacity, StabilityAnalyzed,
+};
+pub use crate::capacity::load_chart::{LoadChart, LoadChartPackage};
+pub use crate::capacity::chart_builder::LoadChartBuilder;
+pub use crate::capacity::lift_validation::{LiftPlan, SoilType, ValidationStatus};
+pub use crate::rigging::{Bridle, Hardware, HitchType, Sling};
+pub use crate::physics::{GroundBearingAnalysis, GroundBearingResult};
+pub use crate::safety::LoadMomentIndicator;
@@ -0,0 +1,487 @@
+//! Python bindings
+//!
+//! Built with the `pyo3` feature, e.g. `maturin develop --features pyo3`,
+//! and imported as `crane_core`. Values cross the boundary as plain floats
+//! in feet, pounds, degrees, mph and psi, the same units the crate uses
+//! internally; argument names carry the unit (`radius_ft`, `load_lb`).
+//! Analysis results come back as dicts shaped like
+//! [`AnalysisRecord`](crate::report::AnalysisRecord).
+//!
+//! ```python
+//! import crane_core
+//!
+//! package = crane_core.LoadChartPackage.from_file("gmk5250l.json")
+//! crane = crane_core.MobileCrane("Grove", "GMK5250L", 154.2, 8.0)
+//! crane.set_load_charts(package)
+//! crane.boom_angle_deg = 60.0
+//! print(crane.rated_capacity_lb(crane.radius_ft))
+//! ```
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::capacity::lift_validation::{self, *};
+use crate::capacity::load_chart::{self as chart, LoadChartPackage as Package};
+use crate::equipment::{self, Kinematic, StabilityAnalyzed};
+use crate::physics::{SoilCapacity, SoilConditions, TemperatureRules, UscsClass};
+use crate::report::{AnalysisRecord, AnalysisReport};
+use crate::rigging::{self, asme_angle_factor, SlingMaterial, WireRopeConstruction};
+use crate::types::*;
+
+fn value_error(error: impl std::fmt::Display) -> PyErr {
+    PyValueError::new_err(error.to_string())
+}
+
+fn record_to_dict<'py>(py: Python<'py>, record: &AnalysisRecord) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new(py);
+    dict.set_item("title", &record.title)?;
+    dict.set_item("status", format!("{:?}", record.status))?;
+
+    let checks = record
+        .checks
+        .iter()
+        .map(|check| {
+            let item = PyDict::new(py);
+            item.set_item("name", &check.name)?;
+            item.set_item("status", format!("{:?}", check.status))?;
+            item.set_item("details", &check.details)?;
+            item.set_item("margin", check.margin)?;
+            Ok(item)
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+    dict.set_item("checks", checks)?;
+
+    let results = PyDict::new(py);
+    for result in &record.results {
+        results.set_item(&result.name, result.value)?;
+    }
+    dict.set_item("results", results)?;
+    Ok(dict)
+}
+
+/// A manufacturer's chart package for one crane model
+#[pyclass(name = "LoadChartPackage", module = "crane_core")]
+#[derive(Clone)]
+pub struct PyLoadChartPackage {
+    inner: Package,
+}
+
+#[pymethods]
+impl PyLoadChartPackage {
+    #[staticmethod]
+    fn from_json(text: &str) -> PyResult<Self> {
+        let inner = Package::from_bytes(text.as_bytes()).map_err(value_error)?;
+        Ok(Self { inner })
+    }
+
+    #[staticmethod]
+    fn from_file(path: &str) -> PyResult<Self> {
+        let inner = Package::from_json_file(path).map_err(value_error)?;
+        Ok(Self { inner })
+    }
+
+    #[getter]
+    fn manufacturer(&self) -> &str {
+        &self.inner.crane_info.manufacturer
+    }
+
+    #[getter]
+    fn model(&self) -> &str {
+        &self.inner.crane_info.model
+    }
+
+    fn chart_ids(&self) -> Vec<String> {
+        self.inner.charts.iter().map(|c| c.id.clone()).collect()
+    }
+
+    fn chart(&self, id: &str) -> PyResult<PyLoadChart> {
+        self.inner
+            .charts
+            .iter()
+            .find(|c| c.id == id)
+            .map(|c| PyLoadChart { inner: c.clone() })
+            .ok_or_else(|| value_error(format!("no chart '{}'", id)))
+    }
+}
+
+/// One load chart: capacities by boom length and radius
+#[pyclass(name = "LoadChart", module = "crane_core")]
+#[derive(Clone)]
+pub struct PyLoadChart {
+    inner: chart::LoadChart,
+}
+
+#[pymethods]
+impl PyLoadChart {
+    #[getter]
+    fn id(&self) -> &str {
+        &self.inner.id
+    }
+
+    fn boom_lengths_ft(&self) -> PyResult<Vec<f64>> {
+        let booms = self.inner.capacity_data.boom_lengths().map_err(value_error)?;
+        Ok(booms.iter().map(|b| b.get::<foot>()).collect())
+    }
+
+    /// Capacity interpolated between boom lengths and radii
+    fn capacity_lb(&self, boom_length_ft: f64, radius_ft: f64) -> PyResult<f64> {
+        self.inner
+            .capacity_interpolated(Length::new::<foot>(boom_length_ft), Length::new::<foot>(radius_ft))
+            .map(|c| c.get::<pound>())
+            .map_err(value_error)
+    }
+
+    /// Capacity at a boom length and radius printed on the chart
+    fn capacity_exact_lb(&self, boom_length_ft: f64, radius_ft: f64) -> PyResult<f64> {
+        self.inner
+            .capacity_exact(Length::new::<foot>(boom_length_ft), Length::new::<foot>(radius_ft))
+            .map(|c| c.get::<pound>())
+            .map_err(value_error)
+    }
+}
+
+/// A mobile crane on outriggers
+#[pyclass(name = "MobileCrane", module = "crane_core")]
+pub struct PyMobileCrane {
+    inner: equipment::MobileCrane,
+}
+
+#[pymethods]
+impl PyMobileCrane {
+    #[new]
+    fn new(manufacturer: &str, model: &str, boom_length_ft: f64, boom_base_height_ft: f64) -> Self {
+        Self {
+            inner: equipment::MobileCrane::new(
+                manufacturer,
+                model,
+                Length::new::<foot>(boom_length_ft),
+                Length::new::<foot>(boom_base_height_ft),
+            ),
+        }
+    }
+
+    fn set_load_charts(&mut self, package: &PyLoadChartPackage) {
+        self.inner.set_load_charts(package.inner.clone());
+    }
+
+    #[getter]
+    fn get_boom_length_ft(&self) -> f64 {
+        self.inner.boom_length.get::<foot>()
+    }
+
+    #[setter]
+    fn set_boom_length_ft(&mut self, value: f64) {
+        self.inner.boom_length = Length::new::<foot>(value);
+    }
+
+    #[getter]
+    fn get_boom_angle_deg(&self) -> f64 {
+        self.inner.boom_angle.get::<degree>()
+    }
+
+    #[setter]
+    fn set_boom_angle_deg(&mut self, value: f64) {
+        self.inner.boom_angle = Angle::new::<degree>(value);
+    }
+
+    #[getter]
+    fn get_swing_deg(&self) -> f64 {
+        self.inner.swing_angle.get::<degree>()
+    }
+
+    #[setter]
+    fn set_swing_deg(&mut self, value: f64) {
+        self.inner.swing_angle = Angle::new::<degree>(value);
+    }
+
+    #[getter]
+    fn get_counterweight_lb(&self) -> f64 {
+        self.inner.counterweight.get::<pound>()
+    }
+
+    #[setter]
+    fn set_counterweight_lb(&mut self, value: f64) {
+        self.inner.counterweight = Mass::new::<pound>(value);
+    }
+
+    #[getter]
+    fn get_outrigger_spread_ft(&self) -> f64 {
+        self.inner.outrigger_spread.get::<foot>()
+    }
+
+    #[setter]
+    fn set_outrigger_spread_ft(&mut self, value: f64) {
+        self.inner.outrigger_spread = Length::new::<foot>(value);
+    }
+
+    /// Working radius at the current boom angle
+    #[getter]
+    fn radius_ft(&self) -> f64 {
+        self.inner.configuration().radius.get::<foot>()
+    }
+
+    /// Tip position (x, y, z) in crane coordinates
+    fn tip_position_ft(&self) -> (f64, f64, f64) {
+        let tip = self.inner.tip_position();
+        (tip.x.get::<foot>(), tip.y.get::<foot>(), tip.z.get::<foot>())
+    }
+
+    fn rated_capacity_lb(&self, radius_ft: f64) -> PyResult<f64> {
+        self.inner
+            .try_rated_capacity_at_radius(Length::new::<foot>(radius_ft))
+            .map(|c| c.get::<pound>())
+            .map_err(value_error)
+    }
+
+    /// Outrigger reactions and pad pressures for a hook load
+    #[pyo3(signature = (load_lb, mat_area_ft2=None, allowable_psi=None))]
+    fn ground_bearing<'py>(
+        &self,
+        py: Python<'py>,
+        load_lb: f64,
+        mat_area_ft2: Option<f64>,
+        allowable_psi: Option<f64>,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let analysis = self
+            .inner
+            .ground_bearing_analysis(Mass::new::<pound>(load_lb), mat_area_ft2.map(Area::new::<square_foot>))
+            .ok_or_else(|| value_error("crane has no support points"))?;
+        let result = match allowable_psi {
+            Some(allowable) => analysis.evaluate(Pressure::new::<psi>(allowable)),
+            None => analysis.calculate_reactions(),
+        }
+        .map_err(value_error)?;
+        record_to_dict(py, &result.record())
+    }
+}
+
+fn soil_type(name: &str) -> PyResult<SoilType> {
+    Ok(match name.to_ascii_lowercase().replace([' ', '-'], "_").as_str() {
+        "soft_clay" => SoilType::SoftClay,
+        "medium_clay" => SoilType::MediumClay,
+        "stiff_clay" => SoilType::StiffClay,
+        "loose_sand" => SoilType::LooseSand,
+        "dense_sand" => SoilType::DenseSand,
+        "gravel" => SoilType::Gravel,
+        "rock" => SoilType::Rock,
+        "paved" => SoilType::Paved,
+        _ => match name.parse::<UscsClass>() {
+            Ok(class) => SoilType::Site(SoilCapacity::Uscs(class)),
+            Err(_) => return Err(value_error(format!("unknown soil type '{}'", name))),
+        },
+    })
+}
+
+/// A lift plan with a vertical hitch
+#[pyclass(name = "LiftPlan", module = "crane_core")]
+pub struct PyLiftPlan {
+    inner: LiftPlan,
+}
+
+#[pymethods]
+impl PyLiftPlan {
+    /// `rigging_lb` lists the rated capacity of each rigging component; `soil`
+    /// is a preset name or a USCS class such as "SM"; `standard` is "osha",
+    /// "en13000" or "as1418"
+    #[new]
+    #[pyo3(signature = (
+        load_lb,
+        length_ft,
+        width_ft,
+        height_ft,
+        rigging_lb,
+        soil="dense_sand",
+        mat_area_ft2=16.0,
+        wind_mph=0.0,
+        standard="osha",
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        load_lb: f64,
+        length_ft: f64,
+        width_ft: f64,
+        height_ft: f64,
+        rigging_lb: Vec<f64>,
+        soil: &str,
+        mat_area_ft2: f64,
+        wind_mph: f64,
+        standard: &str,
+    ) -> PyResult<Self> {
+        let safety_factors = SafetyFactors::named(standard)
+            .ok_or_else(|| PyValueError::new_err(format!("unknown standard: {standard}")))?;
+        let hardware = rigging_lb
+            .iter()
+            .enumerate()
+            .map(|(i, capacity)| RiggingHardware {
+                item_type: "Rigging".into(),
+                kind: RiggingKind::Other,
+                capacity: Mass::new::<pound>(*capacity),
+                description: format!("component {}", i + 1),
+                weight: Mass::new::<pound>(0.0),
+            })
+            .collect();
+
+        Ok(Self {
+            inner: LiftPlan {
+                load_weight: Mass::new::<pound>(load_lb),
+                load_dimensions: LoadDimensions {
+                    length: Length::new::<foot>(length_ft),
+                    width: Length::new::<foot>(width_ft),
+                    height: Length::new::<foot>(height_ft),
+                },
+                rigging: RiggingConfiguration {
+                    configuration: RiggingConfig::Vertical,
+                    hardware,
+                },
+                ground: GroundConditions {
+                    soil_type: soil_type(soil)?,
+                    mat_area: Area::new::<square_foot>(mat_area_ft2),
+                    conditions: SoilConditions::default(),
+                    notes: String::new(),
+                },
+                environment: EnvironmentalConditions {
+                    wind_speed: Velocity::new::<mile_per_hour>(wind_mph),
+                    temperature: ThermodynamicTemperature::new::<degree_fahrenheit>(70.0),
+                    temperature_rules: TemperatureRules::default(),
+                    visibility: "Good".into(),
+                    notes: String::new(),
+                    offshore: None,
+                },
+                safety_factors,
+                headroom: None,
+                work_area: None,
+                side_load: None,
+                duty: equipment::DutyClass::Lifting,
+            },
+        })
+    }
+
+    /// Run every lift check against the crane in its current configuration
+    fn validate<'py>(&self, py: Python<'py>, crane: &PyMobileCrane) -> PyResult<Bound<'py, PyDict>> {
+        let report = lift_validation::validate_lift(&crane.inner, &self.inner);
+        let dict = record_to_dict(py, &report.record())?;
+        dict.set_item("warnings", &report.warnings)?;
+        dict.set_item("critical_issues", &report.critical_issues)?;
+        dict.set_item("recommendations", &report.recommendations)?;
+        Ok(dict)
+    }
+}
+
+/// ASME B30.9 capacity factor for a sling leg at an angle from vertical
+#[pyfunction]
+fn sling_angle_factor(angle_from_vertical_deg: f64) -> f64 {
+    asme_angle_factor(Angle::new::<degree>(angle_from_vertical_deg))
+}
+
+/// Leg tensions for a bridle of wire rope slings meeting at the hook
+///
+/// Points are (x, y, z) in feet; attachments are relative to the load
+/// center of gravity at the origin.
+#[pyfunction]
+fn bridle_tensions<'py>(
+    py: Python<'py>,
+    load_lb: f64,
+    hook_ft: (f64, f64, f64),
+    attachments_ft: Vec<(f64, f64, f64)>,
+    sling_capacity_lb: f64,
+) -> PyResult<Bound<'py, PyDict>> {
+    let hook = na::Point3::new(hook_ft.0, hook_ft.1, hook_ft.2);
+    let mut bridle = rigging::Bridle::new(Mass::new::<pound>(load_lb), na::Point3::origin(), hook);
+    for (i, (x, y, z)) in attachments_ft.into_iter().enumerate() {
+        let attachment = na::Point3::new(x, y, z);
+        let sling = rigging::Sling::new(
+            format!("leg {}", i + 1),
+            SlingMaterial::WireRope {
+                diameter: Length::new::<inch>(0.5),
+                construction: WireRopeConstruction::SixByNineteen,
+            },
+            Mass::new::<pound>(sling_capacity_lb),
+            Length::new::<foot>((hook - attachment).magnitude()),
+        );
+        bridle.add_dead_leg(sling, attachment);
+    }
+
+    let analysis = bridle.calculate_load_distribution().map_err(value_error)?;
+    record_to_dict(py, &analysis.record())
+}
+
+#[pymodule]
+#[pyo3(name = "crane_core")]
+fn crane_core_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyLoadChartPackage>()?;
+    m.add_class::<PyLoadChart>()?;
+    m.add_class::<PyMobileCrane>()?;
+    m.add_class::<PyLiftPlan>()?;
+    m.add_function(wrap_pyfunction!(sling_angle_factor, m)?)?;
+    m.add_function(wrap_pyfunction!(bridle_tensions, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::capacity::chart_builder::LoadChartBuilder;
+
+    #[test]
+    fn test_python_workflow() {
+        let mut package = Package::default();
+        package.add_chart(
+            LoadChartBuilder::new("main")
+                .boom(100.0, "ft")
+                .point(20.0, 80000.0)
+                .point(40.0, 40000.0)
+                .build()
+                .unwrap(),
+        );
+        let mut json = Vec::new();
+        package.to_writer(&mut json).unwrap();
+
+        Python::initialize();
+        Python::attach(|py| {
+            let module = PyModule::new(py, "crane_core").unwrap();
+            crane_core_module(&module).unwrap();
+            let locals = PyDict::new(py);
+            locals.set_item("crane_core", module).unwrap();
+            locals.set_item("package_json", String::from_utf8(json).unwrap()).unwrap();
+
+            py.run(
+                c"
+package = crane_core.LoadChartPackage.from_json(package_json)
+chart = package.chart('main')
+assert [round(b, 6) for b in chart.boom_lengths_ft()] == [100.0]
+assert abs(chart.capacity_lb(100.0, 30.0) - 60000.0) < 1e-6
+
+factor = crane_core.sling_angle_factor(60.0)
+assert abs(factor - 0.5) < 1e-9
+
+bridle = crane_core.bridle_tensions(10000.0, (0.0, 10.0, 0.0), [(-5.0, 0.0, 0.0), (5.0, 0.0, 0.0)], 20000.0)
+assert bridle['status'] == 'Pass'
+assert bridle['results']['dead_leg_1_tension'] > 5000.0
+
+crane = crane_core.MobileCrane('Test', 'T1', 100.0, 8.0)
+crane.set_load_charts(package)
+crane.boom_angle_deg = 72.0
+assert abs(crane.radius_ft - 30.9) < 0.1
+assert crane.rated_capacity_lb(30.0) > 59000.0
+
+pads = crane.ground_bearing(10000.0, allowable_psi=500.0)
+assert pads['status'] == 'Pass'
+
+plan = crane_core.LiftPlan(10000.0, 10.0, 8.0, 6.0, [50000.0], soil='gravel')
+report = plan.validate(crane)
+assert report['checks'] and report['status'] in ('Pass', 'Warning', 'Fail')
+
+try:
+    chart.capacity_lb(100.0, 60.0)
+    raise AssertionError('expected ValueError')
+except ValueError:
+    pass
+",
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+        });
+    }
+}
@@ -0,0 +1,145 @@
+//! Common interface for analysis results
+//!
+//! Wind, ground bearing, rigging, tandem and tower analyses each produce
+//! their own result type. [`AnalysisReport`] gives them one shape: a
+//! title, a list of [`ValidationCheck`]s, an overall [`CheckStatus`] and
+//! named numeric results. [`AnalysisRecord`] is the serializable snapshot
+//! for report generators, and `ValidationReport::add_analysis` folds any
+//! report into a lift validation.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CheckStatus {
+    Pass,
+    Warning,
+    Fail,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ValidationCheck {
+    pub name: String,
+    pub status: CheckStatus,
+    pub details: String,
+    pub margin: Option<f64>,  // Percentage margin (if applicable)
+}
+
+/// A named numeric result, e.g. maximum pad pressure in psi
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NumericResult {
+    pub name: String,
+    pub value: f64,
+    pub unit: String,
+}
+
+impl NumericResult {
+    pub fn new(name: impl Into<String>, value: f64, unit: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value,
+            unit: unit.into(),
+        }
+    }
+}
+
+/// Uniform view of an analysis result
+pub trait AnalysisReport {
+    /// Short name of the analysis, e.g. "Wind"
+    fn title(&self) -> String;
+
+    /// Individual checks
+    fn checks(&self) -> Vec<ValidationCheck>;
+
+    /// Named numeric results
+    fn results(&self) -> Vec<NumericResult>;
+
+    /// Actions the analysis calls for, e.g. a storm configuration
+    fn recommendations(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Worst status across the checks; `Pass` with no checks
+    fn status(&self) -> CheckStatus {
+        self.checks()
+            .iter()
+            .map(|c| c.status)
+            .max()
+            .unwrap_or(CheckStatus::Pass)
+    }
+
+    /// Serializable snapshot
+    fn record(&self) -> AnalysisRecord {
+        AnalysisRecord {
+            title: self.title(),
+            status: self.status(),
+            checks: self.checks(),
+            results: self.results(),
+            recommendations: self.recommendations(),
+        }
+    }
+}
+
+/// Serializable form of any [`AnalysisReport`]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AnalysisRecord {
+    pub title: String,
+    pub status: CheckStatus,
+    pub checks: Vec<ValidationCheck>,
+    pub results: Vec<NumericResult>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub recommendations: Vec<String>,
+}
+
+impl AnalysisRecord {
+    pub fn result(&self, name: &str) -> Option<f64> {
+        self.results.iter().find(|r| r.name == name).map(|r| r.value)
+    }
+}
+
+/// Escapes text for SVG and other XML output
+pub(crate) fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::capacity::lift_validation::{ValidationReport, ValidationStatus};
+    use crate::equipment::CraneType;
+    use crate::physics::WindAnalysis;
+    use crate::types::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_aggregate_sub_analyses() {
+        let wind = WindAnalysis::new(
+            CraneType::AllTerrain,
+            Length::new::<foot>(150.0),
+            Angle::new::<degree>(45.0),
+            Area::new::<square_foot>(50.0),
+            Velocity::new::<mile_per_hour>(25.0),
+        );
+        assert_eq!(wind.status(), CheckStatus::Warning);
+
+        let mut report = ValidationReport::new();
+        report.add_analysis(&wind);
+        assert_eq!(report.overall_status, ValidationStatus::ApprovedWithWarnings);
+        let check = report.check("Wind: Wind Speed").unwrap();
+        // 25 mph against a 30 mph shutdown
+        assert_relative_eq!(check.margin.unwrap(), 100.0 / 6.0, epsilon = 1e-9);
+
+        // The validation report is itself an analysis, and records serialize
+        #[cfg(feature = "serde")]
+        {
+            let json = serde_json::to_string(&report.record()).unwrap();
+            let restored: AnalysisRecord = serde_json::from_str(&json).unwrap();
+            assert_eq!(restored.status, CheckStatus::Warning);
+            assert_eq!(restored.checks.len(), report.checks.len());
+        }
+    }
+}
@@ -1,7 +1,10 @@
 extern crate uom;
 
-use crate::rigging::{LiveLeg, Sling};
+use crate::report::*;
+use crate::rigging::{LiveLeg, LiveRiggingError, Sling};
 use crate::types::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use nalgebra as na;
 
 
@@ -24,6 +27,38 @@ pub struct Bridle {
 
     /// Hook position (where all legs meet)
     pub hook_position: na::Point3<f64>,
+
+    /// How many dead legs are assumed to carry the load
+    pub leg_sharing: LegSharing,
+}
+
+/// Load-sharing assumption for dead legs
+///
+/// A rigid bridle with more than two legs rarely loads them all evenly;
+/// rigging practice rates it as if only two or three legs carry the load.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LegSharing {
+    /// Every leg carries an equal share
+    #[default]
+    AllLegs,
+
+    /// Only two diagonally opposite legs carry the load
+    DiagonalPair,
+
+    /// Any three legs carry the load
+    AnyThree,
+}
+
+impl LegSharing {
+    /// Legs assumed to carry the load out of `legs` rigged
+    pub fn load_bearing_legs(&self, legs: usize) -> usize {
+        match self {
+            LegSharing::AllLegs => legs,
+            LegSharing::DiagonalPair => legs.min(2),
+            LegSharing::AnyThree => legs.min(3),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -46,9 +81,16 @@ impl Bridle {
             dead_legs: Vec::new(),
             live_legs: Vec::new(),
             hook_position,
+            leg_sharing: LegSharing::AllLegs,
         }
     }
 
+    /// Rate the dead legs on a reduced load-sharing assumption
+    pub fn with_leg_sharing(mut self, leg_sharing: LegSharing) -> Self {
+        self.leg_sharing = leg_sharing;
+        self
+    }
+
     /// Add a dead leg (sling) to the bridle
     pub fn add_dead_leg(&mut self, sling: Sling, attachment_point: na::Point3<f64>) {
         self.dead_legs.push(BridleLeg {
@@ -58,11 +100,192 @@ impl Bridle {
         });
     }
 
-    /// Add a live leg to the bridle
-    pub fn add_live_leg(&mut self, live_leg: LiveLeg) {
+    /// Add a live leg to the bridle; a leg without a length takes the
+    /// distance from its attachment point to the hook
+    pub fn add_live_leg(&mut self, mut live_leg: LiveLeg) {
+        if live_leg.length.is_none() {
+            let reach = (self.hook_position - (self.load_cog + live_leg.attachment_point.coords)).magnitude();
+            live_leg.length = Some(Length::new::<foot>(reach));
+        }
         self.live_legs.push(live_leg);
     }
 
+    /// Work live leg `index` by `strokes` and re-solve the bridle from its
+    /// new geometry
+    ///
+    /// The live leg shortens by the take-up and the hook moves to where the
+    /// legs meet again, dead legs keeping their lengths; with more than three
+    /// legs it goes where the lengths fit best. Each leg's tension is then
+    /// its share of holding the load up through the hook at its new angle.
+    /// The leg is left adjusted even if a leg ends up over capacity, so it
+    /// can be backed off.
+    pub fn adjust_live_leg(&mut self, index: usize, strokes: f64) -> Result<BridleAnalysis, BridleError> {
+        let leg = self.live_legs.get(index).ok_or(BridleError::NoSuchLiveLeg { index })?;
+        let take_up = leg.device.travel_per_stroke().ok_or(LiveRiggingError::NotStroked)? * strokes;
+
+        let hook = self.hook_position;
+        let attachment = |r: &na::Point3<f64>| self.load_cog + r.coords;
+        let mut legs: Vec<(na::Point3<f64>, f64)> = self
+            .dead_legs
+            .iter()
+            .map(|leg| attachment(&leg.attachment_point))
+            .map(|a| (a, (hook - a).magnitude()))
+            .collect();
+        legs.extend(self.live_legs.iter().map(|leg| {
+            let a = attachment(&leg.attachment_point);
+            (a, leg.length.map_or((hook - a).magnitude(), |l| l.get::<foot>()))
+        }));
+
+        let live = self.dead_legs.len() + index;
+        let leg = &mut self.live_legs[index];
+        leg.length = Some(Length::new::<foot>(legs[live].1));
+        let (travel, length) = leg.travel_after(take_up)?;
+        leg.travel = travel;
+        leg.length = length;
+        legs[live].1 -= take_up.get::<foot>();
+        self.hook_position = meeting_point(&legs, hook);
+
+        // Hook equilibrium: the legs together hold the load's weight up
+        let hook = self.hook_position;
+        let units: Vec<na::Vector3<f64>> = legs.iter().map(|(a, _)| (hook - a).normalize()).collect();
+        let weight = weight_of(self.load).get::<pound_force>();
+        let a = na::DMatrix::from_fn(3, units.len(), |row, col| units[col][row]);
+        let b = na::DVector::from_column_slice(&[0.0, weight, 0.0]);
+        let pinv = a
+            .clone()
+            .pseudo_inverse(1e-9)
+            .map_err(|e| BridleError::UnsupportedConfiguration(e.into()))?;
+        let tensions = &pinv * &b;
+        // A leg that would have to push has gone slack; the load won't hang as solved
+        let balanced = (&a * &tensions - b).norm() < weight * 1e-6 && tensions.iter().all(|t| *t > -1e-6);
+
+        for (leg, tension) in self.dead_legs.iter_mut().zip(tensions.iter()) {
+            leg.tension = Force::new::<pound_force>(*tension);
+        }
+        for (leg, tension) in self.live_legs.iter_mut().zip(tensions.iter().skip(self.dead_legs.len())) {
+            leg.set_tension(Force::new::<pound_force>(tension.max(0.0)))?;
+        }
+        for leg in &self.dead_legs {
+            if !leg.sling.is_safe(leg.tension) {
+                return Err(BridleError::LegOverCapacity {
+                    leg_id: leg.sling.id.clone(),
+                    tension: DisplayForce(leg.tension),
+                    capacity: DisplayMass(leg.sling.effective_capacity()),
+                });
+            }
+        }
+
+        Ok(BridleAnalysis {
+            total_load: self.load,
+            dead_leg_tensions: self.dead_legs.iter().map(|l| l.tension).collect(),
+            live_leg_tensions: self.live_legs.iter().map(|l| l.tension).collect(),
+            leg_sharing: self.leg_sharing,
+            is_balanced: balanced,
+        })
+    }
+
+    /// Live leg take-up and tensions that bring a tilted load level
+    ///
+    /// `pitch` is the load's rotation about X (positive lowers the +Z end)
+    /// and `roll` its rotation about Z (positive raises the +X end), with
+    /// the COG at `load_cog` and attachment points in the load's own frame.
+    /// Dead legs keep their lengths, so the level load hangs from the ones
+    /// that come taut first; leg tensions are the least-squares share that
+    /// holds the load up through the hook.
+    pub fn level_load(&self, pitch: Angle, roll: Angle) -> Result<LevelingSolution, BridleError> {
+        if self.live_legs.is_empty() {
+            return Err(BridleError::UnsupportedConfiguration("No live legs to level with".into()));
+        }
+        let tilt = na::Rotation3::from_axis_angle(&na::Vector3::x_axis(), pitch.get::<radian>())
+            * na::Rotation3::from_axis_angle(&na::Vector3::z_axis(), roll.get::<radian>());
+        let hook = self.hook_position;
+        let current_length = |r: &na::Point3<f64>| (hook - (self.load_cog + tilt * r.coords)).magnitude();
+
+        // Level COG height: each dead leg holds it at least as high as its length allows
+        let mut cog_height = if self.dead_legs.is_empty() { Some(self.load_cog.y) } else { None };
+        let mut dead_heights = Vec::with_capacity(self.dead_legs.len());
+        for (i, leg) in self.dead_legs.iter().enumerate() {
+            let r = leg.attachment_point;
+            let length = current_length(&r);
+            let reach = ((r.x).powi(2) + (r.z).powi(2)).sqrt();
+            if length < reach {
+                return Err(BridleError::SlingTooShort {
+                    leg: i + 1,
+                    length: DisplayLength(Length::new::<foot>(length)),
+                    required: DisplayLength(Length::new::<foot>(reach)),
+                });
+            }
+            let height = hook.y - (length * length - reach * reach).sqrt() - r.y;
+            dead_heights.push(height);
+            cog_height = Some(cog_height.map_or(height, |h: f64| h.max(height)));
+        }
+        let cog_level = na::Point3::new(hook.x, cog_height.unwrap_or(self.load_cog.y), hook.z);
+
+        // Taut legs and their unit vectors toward the hook in the level pose
+        let unit = |r: &na::Point3<f64>| (hook - (cog_level + r.coords)).normalize();
+        let mut units = Vec::new();
+        for (leg, height) in self.dead_legs.iter().zip(&dead_heights) {
+            if (height - cog_level.y).abs() < 0.01 {
+                units.push(unit(&leg.attachment_point));
+            }
+        }
+        let taut_dead = units.len();
+        units.extend(self.live_legs.iter().map(|leg| unit(&leg.attachment_point)));
+
+        let weight = weight_of(self.load).get::<pound_force>();
+        let a = na::DMatrix::from_fn(3, units.len(), |row, col| units[col][row]);
+        let b = na::DVector::from_column_slice(&[0.0, weight, 0.0]);
+        let pinv = a
+            .clone()
+            .pseudo_inverse(1e-9)
+            .map_err(|e| BridleError::UnsupportedConfiguration(e.into()))?;
+        let tensions = &pinv * &b;
+        // Legs that can't hold the load up (all in one vertical line, say) leave a residual
+        let balanced = (&a * &tensions - b).norm() < weight * 1e-6;
+
+        let mut taut = tensions.iter().take(taut_dead);
+        let dead_leg_tensions: Vec<Force> = dead_heights
+            .iter()
+            .map(|height| {
+                let tension = if (height - cog_level.y).abs() < 0.01 { *taut.next().unwrap_or(&0.0) } else { 0.0 };
+                Force::new::<pound_force>(tension)
+            })
+            .collect();
+
+        let adjustments: Vec<LevelingAdjustment> = self
+            .live_legs
+            .iter()
+            .enumerate()
+            .map(|(index, leg)| {
+                let r = leg.attachment_point;
+                let level_length = (hook - (cog_level + r.coords)).magnitude();
+                let take_up = Length::new::<foot>(current_length(&r) - level_length);
+                let tension = Force::new::<pound_force>(tensions[taut_dead + index]);
+                let travel = leg.travel + take_up;
+                LevelingAdjustment {
+                    index,
+                    take_up,
+                    tension,
+                    within_travel: travel >= Length::new::<foot>(0.0)
+                        && leg.device.max_travel().is_none_or(|max| travel <= max),
+                    within_capacity: tension <= weight_of(leg.device.capacity()),
+                }
+            })
+            .collect();
+
+        let dead_legs_safe = self
+            .dead_legs
+            .iter()
+            .zip(&dead_leg_tensions)
+            .all(|(leg, tension)| leg.sling.is_safe(*tension));
+        let achievable = balanced
+            && dead_legs_safe
+            && tensions.iter().all(|t| *t >= 0.0)
+            && adjustments.iter().all(|a| a.within_travel && a.within_capacity);
+
+        Ok(LevelingSolution { adjustments, dead_leg_tensions, achievable })
+    }
+
     /// Calculate load distribution in the bridle
     ///
     /// For all dead legs: solve using geometry (static equilibrium)
@@ -105,8 +328,9 @@ impl Bridle {
         // TODO: Implement full moment equilibrium solver for asymmetric cases
 
         if self.is_symmetric() {
-            // Symmetric case: equal load sharing
-            let load_per_leg = self.load.get::<pound>() / num_legs as f64;
+            // Symmetric case: equal load sharing among the load-bearing legs
+            let bearing_legs = self.leg_sharing.load_bearing_legs(num_legs);
+            let load_per_leg = self.load.get::<pound>() / bearing_legs as f64;
 
             for leg in &mut self.dead_legs {
                 // Calculate angle from vertical
@@ -145,6 +369,7 @@ impl Bridle {
             total_load: self.load,
             dead_leg_tensions: self.dead_legs.iter().map(|l| l.tension).collect(),
             live_leg_tensions: Vec::new(),
+            leg_sharing: self.leg_sharing,
             is_balanced: true,
         })
     }
@@ -206,6 +431,7 @@ impl Bridle {
             total_load: self.load,
             dead_leg_tensions: Vec::new(),
             live_leg_tensions: self.live_legs.iter().map(|l| l.tension).collect(),
+            leg_sharing: self.leg_sharing,
             is_balanced: true,
         })
     }
@@ -230,8 +456,8 @@ impl Bridle {
         }
 
         // Distribute remaining load to dead legs
-        let num_dead_legs = self.dead_legs.len();
-        let load_per_dead_leg = remaining_load / num_dead_legs as f64;
+        let bearing_legs = self.leg_sharing.load_bearing_legs(self.dead_legs.len());
+        let load_per_dead_leg = remaining_load / bearing_legs as f64;
 
         for leg in &mut self.dead_legs {
             let attachment_world = self.load_cog + leg.attachment_point.coords;
@@ -261,19 +487,189 @@ impl Bridle {
             total_load: self.load,
             dead_leg_tensions: self.dead_legs.iter().map(|l| l.tension).collect(),
             live_leg_tensions: self.live_legs.iter().map(|l| l.tension).collect(),
+            leg_sharing: self.leg_sharing,
             is_balanced: true,
         })
     }
 }
 
-#[derive(Debug)]
+/// Point `length` from each attachment point, found from `start` so the
+/// hook stays on its side of the load; the least-squares fit when the
+/// lengths over-constrain it
+fn meeting_point(legs: &[(na::Point3<f64>, f64)], start: na::Point3<f64>) -> na::Point3<f64> {
+    let mut point = start;
+    for _ in 0..50 {
+        let residual = legs.iter().map(|(a, l)| (point - a).magnitude() - l);
+        let residual = na::DVector::from_iterator(legs.len(), residual);
+        let jacobian = na::DMatrix::from_fn(legs.len(), 3, |row, col| {
+            let d = point - legs[row].0;
+            d[col] / d.magnitude()
+        });
+        let Ok(pinv) = jacobian.pseudo_inverse(1e-12) else { break };
+        let step = pinv * residual;
+        point -= na::Vector3::new(step[0], step[1], step[2]);
+        if step.norm() < 1e-12 {
+            break;
+        }
+    }
+    point
+}
+
+/// One live leg's part in leveling a load
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LevelingAdjustment {
+    /// Index into the bridle's live legs
+    pub index: usize,
+
+    /// Length to take up; negative to pay out
+    pub take_up: Length,
+
+    /// Leg tension once level
+    pub tension: Force,
+
+    pub within_travel: bool,
+    pub within_capacity: bool,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LevelingSolution {
+    pub adjustments: Vec<LevelingAdjustment>,
+
+    /// Dead leg tensions once level; slack legs carry nothing
+    pub dead_leg_tensions: Vec<Force>,
+
+    /// Whether the live legs can level the load with every leg in tension
+    /// and within its device travel and capacity
+    pub achievable: bool,
+}
+
+/// A bridle leg as rigged, for deriving its angle from the geometry
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BridleLegGeometry {
+    /// Attachment point relative to the load CG, in feet
+    pub attachment: na::Point3<f64>,
+
+    /// Sling length, pin to pin
+    pub sling_length: Length,
+}
+
+/// A leg's angle with the hook over the CG
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DerivedLegAngle {
+    /// Leg angle from vertical
+    pub angle_from_vertical: Angle,
+
+    /// Straight-line distance from the attachment to the hook
+    pub required_length: Length,
+
+    /// Sling length beyond what is needed to reach the hook
+    pub slack: Length,
+}
+
+impl DerivedLegAngle {
+    /// Slings within about an inch of the reach are taken as taut
+    pub fn is_taut(&self) -> bool {
+        self.slack.get::<foot>() <= 0.1
+    }
+}
+
+/// Leg angles for a hook directly over the CG, `hook_height` above it
+///
+/// A sling too short to reach the hook is an error; one that is longer
+/// hangs slack and carries nothing, which the caller should flag.
+pub fn derive_leg_angles(
+    legs: &[BridleLegGeometry],
+    hook_height: Length,
+) -> Result<Vec<DerivedLegAngle>, BridleError> {
+    if legs.is_empty() {
+        return Err(BridleError::NoLegs);
+    }
+    let hook = hook_height.get::<foot>();
+
+    legs.iter()
+        .enumerate()
+        .map(|(i, leg)| {
+            let rise = hook - leg.attachment.y;
+            if rise <= 0.0 {
+                return Err(BridleError::HookBelowAttachment { leg: i + 1 });
+            }
+            let reach = leg.attachment.x.hypot(leg.attachment.z);
+            let required = reach.hypot(rise);
+            let length = leg.sling_length.get::<foot>();
+            if length < required - 0.1 {
+                return Err(BridleError::SlingTooShort {
+                    leg: i + 1,
+                    length: DisplayLength(leg.sling_length),
+                    required: DisplayLength(Length::new::<foot>(required)),
+                });
+            }
+            Ok(DerivedLegAngle {
+                angle_from_vertical: Angle::new::<radian>(reach.atan2(rise)),
+                required_length: Length::new::<foot>(required),
+                slack: Length::new::<foot>((length - required).max(0.0)),
+            })
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BridleAnalysis {
     pub total_load: Mass,
     pub dead_leg_tensions: Vec<Force>,
     pub live_leg_tensions: Vec<Force>,
+
+    /// Assumption the dead leg tensions were rated on
+    pub leg_sharing: LegSharing,
+
     pub is_balanced: bool,
 }
 
+impl AnalysisReport for BridleAnalysis {
+    fn title(&self) -> String {
+        "Bridle".into()
+    }
+
+    /// Leg capacities are checked while solving; an over-capacity leg is
+    /// an error, not an analysis
+    fn checks(&self) -> Vec<ValidationCheck> {
+        let tensions = || self.dead_leg_tensions.iter().chain(&self.live_leg_tensions);
+        let max_tension = tensions().map(|t| t.get::<pound_force>()).fold(0.0, f64::max);
+        let sharing = match self.leg_sharing {
+            LegSharing::AllLegs => "",
+            LegSharing::DiagonalPair => " (rated on a diagonal pair)",
+            LegSharing::AnyThree => " (rated on any three legs)",
+        };
+        vec![ValidationCheck {
+            name: "Balance".into(),
+            status: if self.is_balanced { CheckStatus::Pass } else { CheckStatus::Fail },
+            details: format!(
+                "{} legs for {:.0} lbs load, max tension {:.0} lbs{}",
+                tensions().count(),
+                self.total_load.get::<pound>(),
+                max_tension,
+                sharing
+            ),
+            margin: None,
+        }]
+    }
+
+    fn results(&self) -> Vec<NumericResult> {
+        let mut results = vec![NumericResult::new("total_load", self.total_load.get::<pound>(), "lbs")];
+        for (i, tension) in self.dead_leg_tensions.iter().enumerate() {
+            results.push(NumericResult::new(format!("dead_leg_{}_tension", i + 1), tension.get::<pound_force>(), "lbf"));
+        }
+        for (i, tension) in self.live_leg_tensions.iter().enumerate() {
+            results.push(NumericResult::new(format!("live_leg_{}_tension", i + 1), tension.get::<pound_force>(), "lbf"));
+        }
+        results
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum BridleError {
     #[error("No legs in bridle configuration")]
@@ -302,6 +698,22 @@ pub enum BridleError {
 
     #[error("Unsupported configuration: {0}")]
     UnsupportedConfiguration(String),
+
+    #[error("Leg {leg} sling is too short to reach the hook: {length} < {required}")]
+    SlingTooShort {
+        leg: usize,
+        length: DisplayLength,
+        required: DisplayLength,
+    },
+
+    #[error("Hook is not above leg {leg} attachment")]
+    HookBelowAttachment { leg: usize },
+
+    #[error("No live leg {index} in the bridle")]
+    NoSuchLiveLeg { index: usize },
+
+    #[error("Live leg: {0}")]
+    LiveLeg(#[from] LiveRiggingError),
 }
 
 #[cfg(test)]
@@ -349,4 +761,178 @@ mod tests {
             assert!(tension.get::<pound_force>() < 5000.0);
         }
     }
+
+    fn square_bridle(sharing: LegSharing, capacity: f64) -> Bridle {
+        let mut bridle = Bridle::new(
+            Mass::new::<pound>(12000.0),
+            na::Point3::origin(),
+            na::Point3::new(0.0, 20.0, 0.0),
+        )
+        .with_leg_sharing(sharing);
+        for (x, z) in [(5.0, 5.0), (-5.0, 5.0), (5.0, -5.0), (-5.0, -5.0)] {
+            let sling = Sling::new(
+                "Test",
+                SlingMaterial::WireRope {
+                    diameter: Length::new::<inch>(0.5),
+                    construction: WireRopeConstruction::SixByNineteen,
+                },
+                Mass::new::<pound>(capacity),
+                Length::new::<foot>(25.0),
+            );
+            bridle.add_dead_leg(sling, na::Point3::new(x, 0.0, z));
+        }
+        bridle
+    }
+
+    #[test]
+    fn test_leg_sharing_assumption() {
+        // Corners sqrt(50) ft out, hook 20 ft up: cos = 20 / sqrt(450)
+        let cos = 20.0 / 450f64.sqrt();
+
+        let analysis = square_bridle(LegSharing::AnyThree, 10000.0)
+            .calculate_load_distribution()
+            .unwrap();
+        assert_eq!(analysis.leg_sharing, LegSharing::AnyThree);
+        for tension in &analysis.dead_leg_tensions {
+            assert_relative_eq!(tension.get::<pound_force>(), 4000.0 / cos, max_relative = 1e-9);
+        }
+
+        // Same slings pass on three legs but not on a diagonal pair
+        let three = square_bridle(LegSharing::AnyThree, 5000.0).calculate_load_distribution();
+        assert!(three.is_ok());
+        assert!(matches!(
+            square_bridle(LegSharing::DiagonalPair, 5000.0).calculate_load_distribution(),
+            Err(BridleError::LegOverCapacity { .. })
+        ));
+    }
+
+    #[test]
+    fn test_leg_angles_from_geometry() {
+        let leg = |x: f64, z: f64, length: f64| BridleLegGeometry {
+            attachment: na::Point3::new(x, 0.0, z),
+            sling_length: Length::new::<foot>(length),
+        };
+        // 3-4-5: attachments 6 ft out, hook 8 ft up, 10 ft slings
+        let legs = [leg(6.0, 0.0, 10.0), leg(-6.0, 0.0, 10.0), leg(0.0, 6.0, 12.0)];
+        let derived = derive_leg_angles(&legs, Length::new::<foot>(8.0)).unwrap();
+        assert_relative_eq!(derived[0].angle_from_vertical.get::<radian>(), 0.75f64.atan(), epsilon = 1e-12);
+        assert!(derived[0].is_taut());
+        assert_relative_eq!(derived[2].slack.get::<foot>(), 2.0, epsilon = 1e-12);
+        assert!(!derived[2].is_taut());
+
+        assert!(matches!(
+            derive_leg_angles(&[leg(6.0, 0.0, 9.0)], Length::new::<foot>(8.0)),
+            Err(BridleError::SlingTooShort { leg: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_live_leg_adjustment_shifts_dead_legs() {
+        let mut bridle = Bridle::new(
+            Mass::new::<pound>(4000.0),
+            na::Point3::origin(),
+            na::Point3::new(0.0, 20.0, 0.0),
+        );
+        for x in [5.0, -5.0] {
+            let sling = Sling::new(
+                "Dead",
+                SlingMaterial::WireRope {
+                    diameter: Length::new::<inch>(0.5),
+                    construction: WireRopeConstruction::SixByNineteen,
+                },
+                Mass::new::<pound>(5000.0),
+                Length::new::<foot>(25.0),
+            );
+            bridle.add_dead_leg(sling, na::Point3::new(x, 0.0, 0.0));
+        }
+        let fall = LiveLeg::new(crate::rigging::chain_fall_specs::one_ton(), na::Point3::new(0.0, 0.0, 5.0))
+            .with_stiffness(16_000.0);
+        bridle.add_live_leg(fall);
+
+        // Hook straight over the dead legs: they carry everything, the fall nothing
+        let slack = bridle.adjust_live_leg(0, 0.0).unwrap();
+        assert_relative_eq!(slack.live_leg_tensions[0].get::<pound_force>(), 0.0, epsilon = 1e-6);
+        let cos = 20.0 / 425f64.sqrt();
+        for tension in &slack.dead_leg_tensions {
+            assert_relative_eq!(tension.get::<pound_force>(), 2000.0 / cos, max_relative = 1e-6);
+        }
+
+        // 8 strokes take up 6 in: the hook moves toward the fall, which picks up load
+        let analysis = bridle.adjust_live_leg(0, 8.0).unwrap();
+        assert!(analysis.is_balanced);
+        let hook = bridle.hook_position;
+        assert!(hook.z > 0.0);
+        assert_relative_eq!(hook.x, 0.0, epsilon = 1e-9);
+        let fall = &bridle.live_legs[0];
+        assert_relative_eq!(fall.length.unwrap().get::<foot>(), 425f64.sqrt() - 0.5, epsilon = 1e-9);
+        assert_relative_eq!((hook - fall.attachment_point).magnitude(), 425f64.sqrt() - 0.5, epsilon = 1e-9);
+        for leg in &bridle.dead_legs {
+            assert_relative_eq!((hook - leg.attachment_point).magnitude(), 425f64.sqrt(), epsilon = 1e-9);
+        }
+        let live = analysis.live_leg_tensions[0].get::<pound_force>();
+        assert!(live > 0.0);
+
+        // The three legs hold the load up at their new angles
+        let units = bridle
+            .dead_legs
+            .iter()
+            .map(|leg| leg.attachment_point)
+            .chain([fall.attachment_point])
+            .map(|a| (hook - a).normalize());
+        let tensions = analysis.dead_leg_tensions.iter().chain(&analysis.live_leg_tensions);
+        let total = units.zip(tensions).fold(na::Vector3::zeros(), |sum, (u, t)| sum + u * t.get::<pound_force>());
+        assert_relative_eq!(total, na::Vector3::new(0.0, 4000.0, 0.0), epsilon = 1e-3);
+
+        // Out of chain
+        assert!(matches!(bridle.adjust_live_leg(0, 200.0), Err(BridleError::LiveLeg(_))));
+        assert!(matches!(bridle.adjust_live_leg(3, 1.0), Err(BridleError::NoSuchLiveLeg { index: 3 })));
+    }
+
+    #[test]
+    fn test_level_tilted_load() {
+        let mut bridle = Bridle::new(
+            Mass::new::<pound>(3000.0),
+            na::Point3::origin(),
+            na::Point3::new(0.0, 20.0, 0.0),
+        );
+        for x in [5.0, -5.0] {
+            let sling = Sling::new(
+                "Dead",
+                SlingMaterial::WireRope {
+                    diameter: Length::new::<inch>(0.5),
+                    construction: WireRopeConstruction::SixByNineteen,
+                },
+                Mass::new::<pound>(5000.0),
+                Length::new::<foot>(25.0),
+            );
+            bridle.add_dead_leg(sling, na::Point3::new(x, 0.0, -5.0));
+        }
+        bridle.add_live_leg(LiveLeg::new(crate::rigging::chain_fall_specs::one_ton(), na::Point3::new(0.0, 0.0, 5.0)));
+
+        // Already level: nothing to take up
+        let level = bridle.level_load(Angle::new::<degree>(0.0), Angle::new::<degree>(0.0)).unwrap();
+        assert_relative_eq!(level.adjustments[0].take_up.get::<foot>(), 0.0, epsilon = 1e-9);
+        assert!(level.achievable);
+
+        // Live end hanging low: take it up, and the three legs hold the load up
+        let tilted = bridle.level_load(Angle::new::<degree>(3.0), Angle::new::<degree>(0.0)).unwrap();
+        let fall = tilted.adjustments[0];
+        assert!(fall.take_up > Length::new::<foot>(0.0));
+        assert!(fall.within_travel && fall.within_capacity);
+        assert_relative_eq!(
+            tilted.dead_leg_tensions[0].get::<pound_force>(),
+            tilted.dead_leg_tensions[1].get::<pound_force>(),
+            max_relative = 1e-9
+        );
+        assert!(tilted.achievable);
+
+        // A jack with a 1/2 in stroke can't make up the difference
+        bridle.live_legs[0].device = crate::rigging::LiveRiggingDevice::HydraulicJack {
+            capacity: Mass::new::<pound>(10_000.0),
+            stroke: Length::new::<inch>(0.5),
+        };
+        let jacked = bridle.level_load(Angle::new::<degree>(3.0), Angle::new::<degree>(0.0)).unwrap();
+        assert!(!jacked.adjustments[0].within_travel);
+        assert!(!jacked.achievable);
+    }
 }
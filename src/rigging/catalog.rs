@@ -0,0 +1,352 @@
+//! Vendor hardware catalogs
+//!
+//! The constructor modules in [`hardware`](crate::rigging::hardware) cover a
+//! handful of common sizes. A [`HardwareCatalog`] holds a vendor's full
+//! range, read from CSV or JSON, and looks items up by type and size.
+//!
+//! # File format
+//!
+//! One row (CSV) or object (JSON array) per item:
+//!
+//! ```text
+//! id,manufacturer,type,size,wll,material,pin,latch,weight,inside_width,inside_length
+//! G-209-1/2,Crosby,anchor_shackle,0.5,4000,grade80,screw,,0.72,0.81,1.88
+//! S-320-1,Crosby,eye_hook,1.0,10000,grade80,,true,4.9,,
+//! ```
+//!
+//! - `size` inches: shackle body, hook throat, eye bolt thread or link stock
+//! - `wll` pounds; `weight` pounds; `inside_width`, `inside_length` inches
+//! - `type` one of `anchor_shackle`, `bow_shackle`, `chain_shackle`,
+//!   `eye_hook`, `grab_hook`, `sorting_hook`, `swivel_hook`, `foundry_hook`,
+//!   `master_link`, `hammerlok`, `oblong_link`, `turnbuckle_hook_hook`,
+//!   `turnbuckle_hook_eye`, `turnbuckle_eye_eye`, `turnbuckle_jaw_jaw`,
+//!   `eye_bolt`, `shoulder_eye_bolt`, `swivel_eye_bolt`, `quick_link`
+//! - `material` one of `grade80`, `grade100`, `grade120`, `carbon`,
+//!   `ss304`, `ss316`, `galvanized`
+//! - `pin` (`screw`, `bolt`, `safety`), `latch` and the dimension columns
+//!   are optional
+
+use crate::capacity::lift_validation::RiggingHardware;
+use crate::rigging::hardware::*;
+use crate::types::*;
+#[cfg(feature = "charts-io")]
+use serde::Deserialize;
+#[cfg(feature = "charts-io")]
+use std::{io::Read, path::Path};
+
+#[derive(Debug, thiserror::Error)]
+pub enum CatalogError {
+    #[cfg(feature = "charts-io")]
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
+
+    #[cfg(feature = "charts-io")]
+    #[error("CSV error: {0}")]
+    CsvError(#[from] csv::Error),
+
+    #[cfg(feature = "charts-io")]
+    #[error("JSON error: {0}")]
+    JsonError(#[from] serde_json::Error),
+
+    #[error("Row {row}: unknown hardware type '{kind}'")]
+    UnknownType { row: usize, kind: String },
+
+    #[error("Row {row}: unknown material '{material}'")]
+    UnknownMaterial { row: usize, material: String },
+
+    #[error("Row {row}: {field} is not a positive number")]
+    InvalidValue { row: usize, field: &'static str },
+}
+
+/// One catalog item with the data the [`Hardware`] record doesn't carry
+#[derive(Debug, Clone)]
+pub struct CatalogEntry {
+    pub hardware: Hardware,
+
+    /// Nominal size as catalogued
+    pub size: Length,
+
+    pub weight: Option<Mass>,
+
+    /// Inside width at the pin or throat
+    pub inside_width: Option<Length>,
+
+    /// Inside length, bearing point to bearing point
+    pub inside_length: Option<Length>,
+}
+
+impl CatalogEntry {
+    /// Catalog type code, e.g. `anchor_shackle`
+    pub fn kind(&self) -> &'static str {
+        type_code(&self.hardware.hardware_type)
+    }
+}
+
+impl From<&CatalogEntry> for RiggingHardware {
+    fn from(entry: &CatalogEntry) -> Self {
+        Self {
+            weight: entry.weight.unwrap_or(Mass::new::<pound>(0.0)),
+            ..RiggingHardware::from(&entry.hardware)
+        }
+    }
+}
+
+/// A vendor's hardware range
+#[derive(Debug, Clone, Default)]
+pub struct HardwareCatalog {
+    pub entries: Vec<CatalogEntry>,
+}
+
+impl HardwareCatalog {
+    /// Catalog of existing hardware records, e.g. from the constructor modules
+    ///
+    /// Items without a nominal size ([`Hardware::size`]) are skipped.
+    pub fn from_hardware(hardware: impl IntoIterator<Item = Hardware>) -> Self {
+        let entries = hardware
+            .into_iter()
+            .filter_map(|hardware| {
+                Some(CatalogEntry {
+                    size: hardware.size()?,
+                    hardware,
+                    weight: None,
+                    inside_width: None,
+                    inside_length: None,
+                })
+            })
+            .collect();
+        Self { entries }
+    }
+
+    /// Item by catalog id (part number)
+    pub fn get(&self, id: &str) -> Option<&CatalogEntry> {
+        self.entries.iter().find(|e| e.hardware.id == id)
+    }
+
+    /// Item of type `kind` (a catalog type code) at nominal `size`
+    pub fn lookup(&self, kind: &str, size: Length) -> Option<&CatalogEntry> {
+        // Sizes within 1/64" are the same size
+        let tolerance = Length::new::<inch>(1.0 / 64.0);
+        self.entries
+            .iter()
+            .find(|e| e.kind().eq_ignore_ascii_case(kind) && (e.size - size).abs() < tolerance)
+    }
+
+    /// Items of type `kind`, in catalog order
+    pub fn of_kind<'a>(&'a self, kind: &'a str) -> impl Iterator<Item = &'a CatalogEntry> + 'a {
+        self.entries.iter().filter(move |e| e.kind().eq_ignore_ascii_case(kind))
+    }
+
+    /// Read a CSV catalog (see the module docs for the format)
+    #[cfg(feature = "charts-io")]
+    pub fn from_csv_reader(reader: impl Read) -> Result<Self, CatalogError> {
+        let mut csv = csv::ReaderBuilder::new().trim(csv::Trim::All).from_reader(reader);
+        let entries = csv
+            .deserialize::<CatalogRow>()
+            .enumerate()
+            // Data rows start at line 2, after the header
+            .map(|(i, row)| row?.into_entry(i + 2))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { entries })
+    }
+
+    /// Read a JSON array of catalog rows
+    #[cfg(feature = "charts-io")]
+    pub fn from_json_reader(reader: impl Read) -> Result<Self, CatalogError> {
+        let rows: Vec<CatalogRow> = serde_json::from_reader(reader)?;
+        let entries = rows
+            .into_iter()
+            .enumerate()
+            .map(|(i, row)| row.into_entry(i + 1))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { entries })
+    }
+
+    /// Read a catalog file, JSON if the extension is `.json` and CSV otherwise
+    #[cfg(feature = "charts-io")]
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, CatalogError> {
+        let path = path.as_ref();
+        let file = std::fs::File::open(path)?;
+        match path.extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("json") => Self::from_json_reader(file),
+            _ => Self::from_csv_reader(file),
+        }
+    }
+}
+
+/// Catalog row as published, in inches and pounds
+#[cfg(feature = "charts-io")]
+#[derive(Debug, Deserialize)]
+struct CatalogRow {
+    id: String,
+    manufacturer: String,
+    #[serde(rename = "type")]
+    kind: String,
+    size: f64,
+    wll: f64,
+    material: String,
+    #[serde(default)]
+    pin: Option<String>,
+    #[serde(default)]
+    latch: Option<bool>,
+    #[serde(default)]
+    weight: Option<f64>,
+    #[serde(default)]
+    inside_width: Option<f64>,
+    #[serde(default)]
+    inside_length: Option<f64>,
+}
+
+#[cfg(feature = "charts-io")]
+impl CatalogRow {
+    fn into_entry(self, row: usize) -> Result<CatalogEntry, CatalogError> {
+        let positive = |value: f64, field| {
+            if value.is_finite() && value > 0.0 {
+                Ok(value)
+            } else {
+                Err(CatalogError::InvalidValue { row, field })
+            }
+        };
+        let optional = |value: Option<f64>, field| value.map(|v| positive(v, field)).transpose();
+
+        let size = Length::new::<inch>(positive(self.size, "size")?);
+        let hardware_type = parse_type(&self.kind, size, self.pin.as_deref(), self.latch.unwrap_or(false))
+            .ok_or_else(|| CatalogError::UnknownType { row, kind: self.kind.clone() })?;
+        let material = parse_material(&self.material)
+            .ok_or_else(|| CatalogError::UnknownMaterial { row, material: self.material.clone() })?;
+
+        Ok(CatalogEntry {
+            hardware: Hardware {
+                id: self.id,
+                hardware_type,
+                rated_capacity: Mass::new::<pound>(positive(self.wll, "wll")?),
+                material,
+                manufacturer: self.manufacturer,
+                side_load_curve: None,
+            },
+            size,
+            weight: optional(self.weight, "weight")?.map(Mass::new::<pound>),
+            inside_width: optional(self.inside_width, "inside_width")?.map(Length::new::<inch>),
+            inside_length: optional(self.inside_length, "inside_length")?.map(Length::new::<inch>),
+        })
+    }
+}
+
+/// Hardware type from a catalog type code; `None` if the code is unknown
+pub fn parse_type(kind: &str, size: Length, pin: Option<&str>, latch: bool) -> Option<HardwareType> {
+    let pin_type = match pin.map(|p| p.to_ascii_lowercase()).as_deref() {
+        Some("bolt") => ShacklePinType::BoltType,
+        Some("safety") => ShacklePinType::SafetyBolt,
+        _ => ShacklePinType::ScrewPin,
+    };
+    let hardware_type = match kind.to_ascii_lowercase().as_str() {
+        "anchor_shackle" => HardwareType::Shackle(ShackleType::Anchor { size, pin_type }),
+        "bow_shackle" => HardwareType::Shackle(ShackleType::Bow { size, pin_type }),
+        "chain_shackle" => HardwareType::Shackle(ShackleType::Chain { size }),
+        "eye_hook" => HardwareType::Hook(HookType::Eye { throat_opening: size, has_latch: latch }),
+        "grab_hook" => HardwareType::Hook(HookType::Grab { throat_opening: size }),
+        "sorting_hook" => HardwareType::Hook(HookType::Sorting { throat_opening: size }),
+        "swivel_hook" => HardwareType::Hook(HookType::Swivel { throat_opening: size, has_latch: latch }),
+        "foundry_hook" => HardwareType::Hook(HookType::Foundry { throat_opening: size }),
+        "master_link" => HardwareType::MasterLink(MasterLinkType::Forged { num_attachments: 4 }),
+        "hammerlok" => HardwareType::MasterLink(MasterLinkType::Hammerlok),
+        "oblong_link" => HardwareType::MasterLink(MasterLinkType::Oblong),
+        "turnbuckle_hook_hook" => HardwareType::Turnbuckle(TurnbuckleType::HookHook),
+        "turnbuckle_hook_eye" => HardwareType::Turnbuckle(TurnbuckleType::HookEye),
+        "turnbuckle_eye_eye" => HardwareType::Turnbuckle(TurnbuckleType::EyeEye),
+        "turnbuckle_jaw_jaw" => HardwareType::Turnbuckle(TurnbuckleType::JawJaw),
+        "eye_bolt" => HardwareType::EyeBolt(EyeBoltType::Regular { thread_diameter: size }),
+        "shoulder_eye_bolt" => HardwareType::EyeBolt(EyeBoltType::Shoulder { thread_diameter: size }),
+        "swivel_eye_bolt" => HardwareType::EyeBolt(EyeBoltType::Swivel { thread_diameter: size }),
+        "quick_link" => HardwareType::QuickLink(match pin_type {
+            ShacklePinType::ScrewPin => QuickLinkType::ScrewPin,
+            _ => QuickLinkType::BoltType,
+        }),
+        _ => return None,
+    };
+    Some(hardware_type)
+}
+
+/// Catalog type code for a hardware type
+pub fn type_code(hardware_type: &HardwareType) -> &'static str {
+    match hardware_type {
+        HardwareType::Shackle(ShackleType::Anchor { .. }) => "anchor_shackle",
+        HardwareType::Shackle(ShackleType::Bow { .. }) => "bow_shackle",
+        HardwareType::Shackle(ShackleType::Chain { .. }) => "chain_shackle",
+        HardwareType::Hook(HookType::Eye { .. }) => "eye_hook",
+        HardwareType::Hook(HookType::Grab { .. }) => "grab_hook",
+        HardwareType::Hook(HookType::Sorting { .. }) => "sorting_hook",
+        HardwareType::Hook(HookType::Swivel { .. }) => "swivel_hook",
+        HardwareType::Hook(HookType::Foundry { .. }) => "foundry_hook",
+        HardwareType::MasterLink(MasterLinkType::Forged { .. }) => "master_link",
+        HardwareType::MasterLink(MasterLinkType::Hammerlok) => "hammerlok",
+        HardwareType::MasterLink(MasterLinkType::Oblong) => "oblong_link",
+        HardwareType::Turnbuckle(TurnbuckleType::HookHook) => "turnbuckle_hook_hook",
+        HardwareType::Turnbuckle(TurnbuckleType::HookEye) => "turnbuckle_hook_eye",
+        HardwareType::Turnbuckle(TurnbuckleType::EyeEye) => "turnbuckle_eye_eye",
+        HardwareType::Turnbuckle(TurnbuckleType::JawJaw) => "turnbuckle_jaw_jaw",
+        HardwareType::EyeBolt(EyeBoltType::Regular { .. }) => "eye_bolt",
+        HardwareType::EyeBolt(EyeBoltType::Shoulder { .. }) => "shoulder_eye_bolt",
+        HardwareType::EyeBolt(EyeBoltType::Swivel { .. }) => "swivel_eye_bolt",
+        HardwareType::QuickLink(_) => "quick_link",
+    }
+}
+
+/// Material from a catalog material code; `None` if the code is unknown
+pub fn parse_material(material: &str) -> Option<HardwareMaterial> {
+    let material = match material.to_ascii_lowercase().as_str() {
+        "grade80" => HardwareMaterial::AlloySteel { grade: SteelGrade::Grade80 },
+        "grade100" => HardwareMaterial::AlloySteel { grade: SteelGrade::Grade100 },
+        "grade120" => HardwareMaterial::AlloySteel { grade: SteelGrade::Grade120 },
+        "carbon" => HardwareMaterial::CarbonSteel,
+        "ss304" => HardwareMaterial::StainlessSteel { grade: StainlessGrade::SS304 },
+        "ss316" => HardwareMaterial::StainlessSteel { grade: StainlessGrade::SS316 },
+        "galvanized" => HardwareMaterial::Galvanized,
+        _ => return None,
+    };
+    Some(material)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "charts-io")]
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_catalog_from_constructors() {
+        let catalog = HardwareCatalog::from_hardware([
+            crosby_shackles::half_inch_anchor_screw(),
+            crosby_shackles::five_eighths_anchor_screw(),
+            eye_bolts::half_inch_shoulder(),
+        ]);
+        let shackle = catalog.lookup("anchor_shackle", Length::new::<inch>(0.625)).unwrap();
+        assert_eq!(shackle.hardware.id, "G-209-5/8");
+        assert!(catalog.lookup("anchor_shackle", Length::new::<inch>(0.75)).is_none());
+        assert_eq!(catalog.of_kind("shoulder_eye_bolt").count(), 1);
+    }
+
+    #[cfg(feature = "charts-io")]
+    #[test]
+    fn test_catalog_csv_and_json() {
+        let csv = "\
+id,manufacturer,type,size,wll,material,pin,latch,weight,inside_width,inside_length
+G-2130-1,Crosby,bow_shackle,1.0,17000,grade80,bolt,,5.0,1.69,4.12
+S-320-1,Crosby,eye_hook,1.0,10000,grade80,,true,4.9,,
+";
+        let catalog = HardwareCatalog::from_csv_reader(csv.as_bytes()).unwrap();
+        let shackle = catalog.lookup("bow_shackle", Length::new::<inch>(1.0)).unwrap();
+        assert!(matches!(
+            shackle.hardware.hardware_type,
+            HardwareType::Shackle(ShackleType::Bow { pin_type: ShacklePinType::BoltType, .. })
+        ));
+        assert_relative_eq!(shackle.hardware.rated_capacity.get::<pound>(), 17_000.0);
+        assert_relative_eq!(RiggingHardware::from(shackle).weight.get::<pound>(), 5.0);
+        assert!(catalog.get("S-320-1").unwrap().inside_width.is_none());
+
+        let json = r#"[{"id": "X-1", "manufacturer": "Acme", "type": "widget", "size": 1.0, "wll": 1000, "material": "grade80"}]"#;
+        assert!(matches!(
+            HardwareCatalog::from_json_reader(json.as_bytes()),
+            Err(CatalogError::UnknownType { row: 1, .. })
+        ));
+    }
+}
@@ -0,0 +1,333 @@
+//! Rigging geometry for drawing tools
+//!
+//! A [`RiggingDrawing`] lays the rigging out as points, lines and outlines
+//! in feet, load CG at the origin unless built from a [`Bridle`]'s own
+//! coordinates (X right, Y up, Z forward). CAD and diagram tools can read
+//! the primitives as JSON, or take an SVG elevation from
+//! [`RiggingDrawing::to_svg`].
+
+use crate::capacity::lift_validation::{LiftPlan, RiggingConfig};
+use crate::report::escape_xml;
+use crate::rigging::Bridle;
+use crate::types::*;
+use nalgebra as na;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "kind", rename_all = "snake_case"))]
+pub enum Primitive {
+    Point { label: String, at: na::Point3<f64> },
+    Line { label: String, from: na::Point3<f64>, to: na::Point3<f64> },
+
+    /// Closed outline
+    Polygon { label: String, points: Vec<na::Point3<f64>> },
+}
+
+impl Primitive {
+    pub fn label(&self) -> &str {
+        match self {
+            Primitive::Point { label, .. } | Primitive::Line { label, .. } | Primitive::Polygon { label, .. } => label,
+        }
+    }
+
+    fn points(&self) -> Vec<na::Point3<f64>> {
+        match self {
+            Primitive::Point { at, .. } => vec![*at],
+            Primitive::Line { from, to, .. } => vec![*from, *to],
+            Primitive::Polygon { points, .. } => points.clone(),
+        }
+    }
+}
+
+/// Direction an elevation is viewed from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ElevationView {
+    /// Looking along Z: X across, Y up
+    Front,
+
+    /// Looking along X: Z across, Y up
+    Side,
+}
+
+impl ElevationView {
+    /// Drawing coordinates of a point, Y up
+    pub fn project(&self, point: &na::Point3<f64>) -> na::Point2<f64> {
+        match self {
+            ElevationView::Front => na::Point2::new(point.x, point.y),
+            ElevationView::Side => na::Point2::new(point.z, point.y),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RiggingDrawing {
+    pub primitives: Vec<Primitive>,
+}
+
+impl RiggingDrawing {
+    /// Hook, legs and attachment points of a bridle, in its own coordinates
+    pub fn from_bridle(bridle: &Bridle) -> Self {
+        let mut drawing = Self::default();
+        drawing.point("Hook", bridle.hook_position);
+        drawing.point("Load CG", bridle.load_cog);
+
+        let dead = bridle.dead_legs.iter().map(|leg| (leg.sling.id.clone(), leg.attachment_point));
+        let live = bridle
+            .live_legs
+            .iter()
+            .enumerate()
+            .map(|(i, leg)| (format!("Live leg {}", i + 1), leg.attachment_point));
+        for (label, attachment) in dead.chain(live) {
+            let at = bridle.load_cog + attachment.coords;
+            drawing.line(label.clone(), bridle.hook_position, at);
+            drawing.point(format!("{} attachment", label), at);
+        }
+        drawing
+    }
+
+    /// Load outline and rigging of a lift plan, load CG at the origin
+    ///
+    /// Bridles given only by leg angle are drawn with the legs to the top
+    /// corners of the load: the ends for two legs, one end's middle and
+    /// the far corners for three. Hitches are drawn as a single line to the
+    /// top of the load.
+    pub fn from_plan(plan: &LiftPlan) -> Self {
+        let dims = &plan.load_dimensions;
+        let (half_x, half_y, half_z) =
+            (to_coord(dims.length) / 2.0, to_coord(dims.height) / 2.0, to_coord(dims.width) / 2.0);
+
+        let mut drawing = Self::default();
+        drawing.box_outline("Load", na::Point3::origin(), half_x, half_y, half_z);
+        drawing.point("Load CG", na::Point3::origin());
+
+        let legs: Vec<na::Point3<f64>> = match &plan.rigging.configuration {
+            RiggingConfig::BridleGeometry { legs, .. } => legs.iter().map(|leg| leg.attachment).collect(),
+            RiggingConfig::Bridle { num_legs, .. } => match num_legs {
+                0 | 1 => vec![na::Point3::new(0.0, half_y, 0.0)],
+                2 => vec![na::Point3::new(-half_x, half_y, 0.0), na::Point3::new(half_x, half_y, 0.0)],
+                3 => vec![
+                    na::Point3::new(half_x, half_y, 0.0),
+                    na::Point3::new(-half_x, half_y, -half_z),
+                    na::Point3::new(-half_x, half_y, half_z),
+                ],
+                _ => [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)]
+                    .iter()
+                    .map(|(sx, sz)| na::Point3::new(sx * half_x, half_y, sz * half_z))
+                    .collect(),
+            },
+            _ => vec![na::Point3::new(0.0, half_y, 0.0)],
+        };
+        let hook_height = match &plan.rigging.configuration {
+            RiggingConfig::BridleGeometry { hook_height, .. } => to_coord(*hook_height),
+            RiggingConfig::Bridle { leg_angle, .. } if leg_angle.get::<degree>() > 0.0 => {
+                let reach = legs.iter().map(|p| p.x.hypot(p.z)).fold(0.0, f64::max);
+                half_y + reach / leg_angle.get::<radian>().tan()
+            }
+            // Hitches drawn with the hook a load height above the top
+            _ => 3.0 * half_y,
+        };
+
+        let hook = na::Point3::new(0.0, hook_height, 0.0);
+        drawing.point("Hook", hook);
+        for (i, at) in legs.into_iter().enumerate() {
+            let label = format!("Leg {}", i + 1);
+            drawing.line(label.clone(), hook, at);
+            drawing.point(format!("{} attachment", label), at);
+        }
+        drawing
+    }
+
+    pub fn point(&mut self, label: impl Into<String>, at: na::Point3<f64>) {
+        self.primitives.push(Primitive::Point { label: label.into(), at });
+    }
+
+    pub fn line(&mut self, label: impl Into<String>, from: na::Point3<f64>, to: na::Point3<f64>) {
+        self.primitives.push(Primitive::Line { label: label.into(), from, to });
+    }
+
+    /// Spreader beam or frame outline: `length` along X and `width` along
+    /// Z, level, centered at `center`
+    pub fn add_spreader(&mut self, center: na::Point3<f64>, length: Length, width: Length) {
+        let (half_x, half_z) = (to_coord(length) / 2.0, to_coord(width) / 2.0);
+        let points = [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)]
+            .iter()
+            .map(|(sx, sz)| center + na::Vector3::new(sx * half_x, 0.0, sz * half_z))
+            .collect();
+        self.primitives.push(Primitive::Polygon { label: "Spreader".into(), points });
+    }
+
+    /// Top and bottom faces of a box, as two outlines
+    fn box_outline(&mut self, label: &str, center: na::Point3<f64>, half_x: f64, half_y: f64, half_z: f64) {
+        for (face, sy) in [("top", 1.0), ("bottom", -1.0)] {
+            let points = [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)]
+                .iter()
+                .map(|(sx, sz)| center + na::Vector3::new(sx * half_x, sy * half_y, sz * half_z))
+                .collect();
+            self.primitives.push(Primitive::Polygon { label: format!("{} {}", label, face), points });
+        }
+    }
+
+    /// Primitives with the given label
+    pub fn find<'a>(&'a self, label: &'a str) -> impl Iterator<Item = &'a Primitive> + 'a {
+        self.primitives.iter().filter(move |p| p.label() == label)
+    }
+
+    /// SVG elevation, `scale` pixels per foot
+    pub fn to_svg(&self, view: ElevationView, scale: f64) -> String {
+        let projected: Vec<na::Point2<f64>> =
+            self.primitives.iter().flat_map(|p| p.points()).map(|p| view.project(&p)).collect();
+        let (mut min, mut max) = (na::Point2::new(0.0, 0.0), na::Point2::new(0.0, 0.0));
+        if let Some(first) = projected.first() {
+            (min, max) = (*first, *first);
+        }
+        for p in &projected {
+            min = min.inf(p);
+            max = max.sup(p);
+        }
+        let margin = 2.0;
+        let width = (max.x - min.x + 2.0 * margin) * scale;
+        let height = (max.y - min.y + 2.0 * margin) * scale;
+        // SVG y runs down the page
+        let to_svg = |p: &na::Point3<f64>| {
+            let q = view.project(p);
+            ((q.x - min.x + margin) * scale, (max.y - q.y + margin) * scale)
+        };
+
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{:.0}" height="{:.0}" viewBox="0 0 {:.1} {:.1}">"#,
+            width, height, width, height
+        );
+        for primitive in &self.primitives {
+            let label = escape_xml(primitive.label());
+            let _ = match primitive {
+                Primitive::Point { at, .. } => {
+                    let (x, y) = to_svg(at);
+                    writeln!(
+                        svg,
+                        r#"  <circle cx="{x:.1}" cy="{y:.1}" r="3"><title>{label}</title></circle>"#
+                    )
+                }
+                Primitive::Line { from, to, .. } => {
+                    let ((x1, y1), (x2, y2)) = (to_svg(from), to_svg(to));
+                    writeln!(
+                        svg,
+                        r#"  <line x1="{x1:.1}" y1="{y1:.1}" x2="{x2:.1}" y2="{y2:.1}" stroke="black"><title>{label}</title></line>"#
+                    )
+                }
+                Primitive::Polygon { points, .. } => {
+                    let points: Vec<String> = points
+                        .iter()
+                        .map(|p| {
+                            let (x, y) = to_svg(p);
+                            format!("{x:.1},{y:.1}")
+                        })
+                        .collect();
+                    writeln!(
+                        svg,
+                        r#"  <polygon points="{}" fill="none" stroke="black"><title>{label}</title></polygon>"#,
+                        points.join(" ")
+                    )
+                }
+            };
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::capacity::lift_validation::*;
+    use crate::rigging::{BridleLegGeometry, LegSharing};
+    use crate::test_fixtures::lift_plan;
+    use approx::assert_relative_eq;
+
+    // 8 x 4 x 4 ft load
+    fn plan(configuration: RiggingConfig) -> LiftPlan {
+        let mut plan = lift_plan(20_000.0);
+        plan.load_dimensions = LoadDimensions {
+            length: Length::new::<foot>(8.0),
+            width: Length::new::<foot>(4.0),
+            height: Length::new::<foot>(4.0),
+        };
+        plan.rigging.configuration = configuration;
+        plan
+    }
+
+    #[test]
+    fn test_drawing_from_plan_geometry() {
+        let leg = |x: f64, z: f64| BridleLegGeometry {
+            attachment: na::Point3::new(x, 2.0, z),
+            sling_length: Length::new::<foot>(12.0),
+        };
+        let configuration = RiggingConfig::BridleGeometry {
+            legs: vec![leg(-4.0, -2.0), leg(4.0, -2.0), leg(4.0, 2.0), leg(-4.0, 2.0)],
+            hook_height: Length::new::<foot>(10.0),
+            sharing: LegSharing::AnyThree,
+        };
+        let mut drawing = RiggingDrawing::from_plan(&plan(configuration));
+        drawing.add_spreader(na::Point3::new(0.0, 6.0, 0.0), Length::new::<foot>(8.0), Length::new::<foot>(1.0));
+
+        let Some(Primitive::Point { at, .. }) = drawing.find("Hook").next() else { panic!("no hook") };
+        assert_relative_eq!(at.y, 10.0, epsilon = 1e-9);
+        let Some(Primitive::Line { to, .. }) = drawing.find("Leg 3").next() else { panic!("no leg 3") };
+        assert_relative_eq!(to.z, 2.0);
+        assert_eq!(drawing.find("Spreader").count(), 1);
+
+        let svg = drawing.to_svg(ElevationView::Front, 10.0);
+        assert!(svg.starts_with("<svg"));
+        assert_eq!(svg.matches("<line").count(), 4);
+        assert_eq!(svg.matches("<polygon").count(), 3);
+    }
+
+    #[test]
+    fn test_three_leg_bridle_has_three_legs() {
+        let configuration = RiggingConfig::Bridle {
+            leg_angle: Angle::new::<degree>(45.0),
+            num_legs: 3,
+            sharing: LegSharing::AllLegs,
+        };
+        let drawing = RiggingDrawing::from_plan(&plan(configuration));
+        assert!(drawing.find("Leg 3").next().is_some());
+        assert!(drawing.find("Leg 4").next().is_none());
+
+        // One end's middle and the far corners
+        let ends: Vec<na::Point3<f64>> = (1..=3)
+            .map(|i| match drawing.find(&format!("Leg {}", i)).next() {
+                Some(Primitive::Line { to, .. }) => *to,
+                _ => panic!("no leg {}", i),
+            })
+            .collect();
+        assert_relative_eq!(ends[0].x, 4.0);
+        assert_relative_eq!(ends[0].z, 0.0);
+        assert_relative_eq!(ends[1].z, -ends[2].z);
+        assert_relative_eq!(ends[1].x, -4.0);
+    }
+
+    #[cfg(feature = "charts-io")]
+    #[test]
+    fn test_drawing_json_is_tagged() {
+        let configuration = RiggingConfig::Bridle {
+            leg_angle: Angle::new::<degree>(45.0),
+            num_legs: 2,
+            sharing: LegSharing::AllLegs,
+        };
+        let drawing = RiggingDrawing::from_plan(&plan(configuration));
+
+        // Legs to the load ends 4 ft out, top 2 ft above the CG: hook at 6 ft
+        let Some(Primitive::Point { at, .. }) = drawing.find("Hook").next() else { panic!("no hook") };
+        assert_relative_eq!(at.y, 6.0, epsilon = 1e-9);
+
+        let json = serde_json::to_value(&drawing).unwrap();
+        assert_eq!(json["primitives"][0]["kind"], "polygon");
+        assert_eq!(serde_json::from_value::<RiggingDrawing>(json).unwrap(), drawing);
+    }
+}
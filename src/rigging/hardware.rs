@@ -1,4 +1,6 @@
 use crate::types::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// Types of shackles
 #[derive(Debug, Clone, PartialEq)]
@@ -41,6 +43,111 @@ pub struct Hardware {
     pub rated_capacity: Mass,
     pub material: HardwareMaterial,
     pub manufacturer: String,
+
+    /// Manufacturer's side-load curve; the generic shackle curve applies when `None`
+    pub side_load_curve: Option<SideLoadCurve>,
+}
+
+/// Capacity factor against side-load angle, linear between points
+///
+/// Angles beyond the last point take its factor. Two points at the same
+/// angle make a step: the angle itself takes the first factor, anything
+/// past it the second.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(try_from = "Vec<(f64, f64)>", into = "Vec<(f64, f64)>")
+)]
+pub struct SideLoadCurve {
+    /// (angle from in-line in degrees, capacity factor), sorted by angle
+    points: Vec<(f64, f64)>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SideLoadCurveError {
+    #[error("Side-load curve has no points")]
+    Empty,
+
+    #[error("Side-load factor {factor} at {angle}° is not between 0 and 1")]
+    InvalidFactor { angle: f64, factor: f64 },
+
+    #[error("Side-load angle {0}° is not a finite angle")]
+    InvalidAngle(f64),
+}
+
+impl SideLoadCurve {
+    /// Curve through `points`, in any order
+    ///
+    /// Needs at least one point, with every factor in (0, 1].
+    pub fn new(points: impl IntoIterator<Item = (Angle, f64)>) -> Result<Self, SideLoadCurveError> {
+        Self::try_from(
+            points
+                .into_iter()
+                .map(|(angle, factor)| (angle.get::<degree>(), factor))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// ASME B30.26 shackle reductions: 100% in line, 70% at 45°, 50% at
+    /// 90°, and 30% past 90°
+    pub fn shackle() -> Self {
+        Self::new([
+            (Angle::new::<degree>(0.0), 1.0),
+            (Angle::new::<degree>(45.0), 0.7),
+            (Angle::new::<degree>(90.0), 0.5),
+            (Angle::new::<degree>(90.0), 0.3),
+        ])
+        .expect("published shackle curve is valid")
+    }
+
+    /// Capacity factor at `angle` from in-line
+    pub fn factor(&self, angle: Angle) -> f64 {
+        let degrees = angle.get::<degree>().abs();
+        let (first, last) = (self.points[0], self.points[self.points.len() - 1]);
+        if degrees <= first.0 {
+            return first.1;
+        }
+        if degrees > last.0 {
+            return last.1;
+        }
+        self.points
+            .windows(2)
+            .find(|pair| degrees <= pair[1].0)
+            .map(|pair| {
+                let (a, b) = (pair[0], pair[1]);
+                a.1 + (b.1 - a.1) * (degrees - a.0) / (b.0 - a.0)
+            })
+            .unwrap_or(last.1)
+    }
+}
+
+impl TryFrom<Vec<(f64, f64)>> for SideLoadCurve {
+    type Error = SideLoadCurveError;
+
+    /// Points as (degrees, factor)
+    fn try_from(mut points: Vec<(f64, f64)>) -> Result<Self, Self::Error> {
+        if points.is_empty() {
+            return Err(SideLoadCurveError::Empty);
+        }
+        for &(angle, factor) in &points {
+            if !angle.is_finite() {
+                return Err(SideLoadCurveError::InvalidAngle(angle));
+            }
+            if !(factor > 0.0 && factor <= 1.0) {
+                return Err(SideLoadCurveError::InvalidFactor { angle, factor });
+            }
+        }
+        // Stable, so a step keeps its points in the order given
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Ok(Self { points })
+    }
+}
+
+impl From<SideLoadCurve> for Vec<(f64, f64)> {
+    fn from(curve: SideLoadCurve) -> Self {
+        curve.points
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -53,6 +160,19 @@ pub enum HardwareType {
     QuickLink(QuickLinkType),
 }
 
+impl HardwareType {
+    pub fn category(&self) -> &'static str {
+        match self {
+            HardwareType::Shackle(_) => "Shackle",
+            HardwareType::Hook(_) => "Hook",
+            HardwareType::MasterLink(_) => "Master Link",
+            HardwareType::Turnbuckle(_) => "Turnbuckle",
+            HardwareType::EyeBolt(_) => "Eye Bolt",
+            HardwareType::QuickLink(_) => "Quick Link",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum HookType {
     /// Eye hook - simple hook with eye for attachment
@@ -192,8 +312,15 @@ impl Hardware {
             rated_capacity,
             material,
             manufacturer: "Generic".into(),
+            side_load_curve: None,
         }
     }
+
+    /// Rate side loads on a manufacturer's curve instead of the generic one
+    pub fn with_side_load_curve(mut self, curve: SideLoadCurve) -> Self {
+        self.side_load_curve = Some(curve);
+        self
+    }
     
     /// Calculate effective capacity based on loading conditions
     /// 
@@ -247,16 +374,12 @@ impl Hardware {
     
     /// Shackle side load reduction factor
     /// 
-    /// Per ASME B30.26, side loading reduces shackle capacity significantly
+    /// Per ASME B30.26, side loading reduces shackle capacity significantly;
+    /// interpolated between published angles rather than stepped
     fn shackle_side_load_factor(&self, angle: Angle) -> f64 {
-        let degrees = angle.get::<degree>();
-        
-        // ASME B30.26 side load factors
-        match degrees {
-            d if d <= 0.0 => 1.0,   // In-line
-            d if d <= 45.0 => 0.7,  // Light side load - 30% reduction
-            d if d <= 90.0 => 0.5,  // 90° side load - 50% reduction
-            _ => 0.3,               // Severe side load - 70% reduction
+        match &self.side_load_curve {
+            Some(curve) => curve.factor(angle),
+            None => SideLoadCurve::shackle().factor(angle),
         }
     }
     
@@ -275,6 +398,51 @@ impl Hardware {
         }
     }
     
+    /// Nominal size: shackle body, hook throat or eye bolt thread
+    pub fn size(&self) -> Option<Length> {
+        match &self.hardware_type {
+            HardwareType::Shackle(
+                ShackleType::Anchor { size, .. } | ShackleType::Bow { size, .. } | ShackleType::Chain { size },
+            ) => Some(*size),
+            HardwareType::Hook(
+                HookType::Eye { throat_opening, .. }
+                | HookType::Grab { throat_opening }
+                | HookType::Sorting { throat_opening }
+                | HookType::Swivel { throat_opening, .. }
+                | HookType::Foundry { throat_opening },
+            ) => Some(*throat_opening),
+            HardwareType::EyeBolt(
+                EyeBoltType::Regular { thread_diameter }
+                | EyeBoltType::Shoulder { thread_diameter }
+                | EyeBoltType::Swivel { thread_diameter },
+            ) => Some(*thread_diameter),
+            _ => None,
+        }
+    }
+
+    /// Short description the way riggers call it, e.g. `5/8" anchor shackle (G-209-5/8)`
+    ///
+    /// The size follows the length [`UnitPreferences`], in fractional inches
+    /// and/or millimeters.
+    pub fn description(&self) -> String {
+        let kind = match &self.hardware_type {
+            HardwareType::Shackle(ShackleType::Anchor { .. }) => "anchor shackle",
+            HardwareType::Shackle(ShackleType::Bow { .. }) => "bow shackle",
+            HardwareType::Shackle(ShackleType::Chain { .. }) => "chain shackle",
+            HardwareType::Hook(_) => "hook",
+            HardwareType::MasterLink(_) => "master link",
+            HardwareType::Turnbuckle(_) => "turnbuckle",
+            HardwareType::EyeBolt(EyeBoltType::Regular { .. }) => "eye bolt",
+            HardwareType::EyeBolt(EyeBoltType::Shoulder { .. }) => "shoulder eye bolt",
+            HardwareType::EyeBolt(EyeBoltType::Swivel { .. }) => "swivel eye bolt",
+            HardwareType::QuickLink(_) => "quick link",
+        };
+        match self.size() {
+            Some(size) => format!("{} {} ({})", DisplayFractionalInches(size), kind, self.id),
+            None => format!("{} ({})", kind, self.id),
+        }
+    }
+
     /// Check if this hardware is safe for the given load and conditions
     pub fn is_safe(&self, load: Mass, loading: LoadingCondition) -> bool {
         load <= self.effective_capacity(loading)
@@ -316,6 +484,7 @@ pub mod crosby_shackles {
             rated_capacity: Mass::new::<pound>(1000.0),
             material: HardwareMaterial::AlloySteel { grade: SteelGrade::Grade80 },
             manufacturer: "Crosby".into(),
+            side_load_curve: None,
         }
     }
     
@@ -330,6 +499,7 @@ pub mod crosby_shackles {
             rated_capacity: Mass::new::<pound>(2000.0),
             material: HardwareMaterial::AlloySteel { grade: SteelGrade::Grade80 },
             manufacturer: "Crosby".into(),
+            side_load_curve: None,
         }
     }
     
@@ -344,6 +514,7 @@ pub mod crosby_shackles {
             rated_capacity: Mass::new::<pound>(3250.0),
             material: HardwareMaterial::AlloySteel { grade: SteelGrade::Grade80 },
             manufacturer: "Crosby".into(),
+            side_load_curve: None,
         }
     }
     
@@ -358,6 +529,7 @@ pub mod crosby_shackles {
             rated_capacity: Mass::new::<pound>(4750.0),
             material: HardwareMaterial::AlloySteel { grade: SteelGrade::Grade80 },
             manufacturer: "Crosby".into(),
+            side_load_curve: None,
         }
     }
     
@@ -372,6 +544,7 @@ pub mod crosby_shackles {
             rated_capacity: Mass::new::<pound>(6500.0),
             material: HardwareMaterial::AlloySteel { grade: SteelGrade::Grade80 },
             manufacturer: "Crosby".into(),
+            side_load_curve: None,
         }
     }
     
@@ -386,6 +559,7 @@ pub mod crosby_shackles {
             rated_capacity: Mass::new::<pound>(8500.0),
             material: HardwareMaterial::AlloySteel { grade: SteelGrade::Grade80 },
             manufacturer: "Crosby".into(),
+            side_load_curve: None,
         }
     }
     
@@ -400,6 +574,7 @@ pub mod crosby_shackles {
             rated_capacity: Mass::new::<pound>(9500.0),
             material: HardwareMaterial::AlloySteel { grade: SteelGrade::Grade80 },
             manufacturer: "Crosby".into(),
+            side_load_curve: None,
         }
     }
     
@@ -414,6 +589,7 @@ pub mod crosby_shackles {
             rated_capacity: Mass::new::<pound>(12000.0),
             material: HardwareMaterial::AlloySteel { grade: SteelGrade::Grade80 },
             manufacturer: "Crosby".into(),
+            side_load_curve: None,
         }
     }
     
@@ -428,6 +604,7 @@ pub mod crosby_shackles {
             rated_capacity: Mass::new::<pound>(13500.0),
             material: HardwareMaterial::AlloySteel { grade: SteelGrade::Grade80 },
             manufacturer: "Crosby".into(),
+            side_load_curve: None,
         }
     }
     
@@ -442,6 +619,7 @@ pub mod crosby_shackles {
             rated_capacity: Mass::new::<pound>(17000.0),
             material: HardwareMaterial::AlloySteel { grade: SteelGrade::Grade80 },
             manufacturer: "Crosby".into(),
+            side_load_curve: None,
         }
     }
     
@@ -456,6 +634,7 @@ pub mod crosby_shackles {
             rated_capacity: Mass::new::<pound>(25000.0),
             material: HardwareMaterial::AlloySteel { grade: SteelGrade::Grade80 },
             manufacturer: "Crosby".into(),
+            side_load_curve: None,
         }
     }
 }
@@ -467,13 +646,14 @@ pub mod master_links {
     /// Crosby S-5287 forged master link - 4 attachment points
     pub fn crosby_s5287(size: Length, capacity: Mass) -> Hardware {
         Hardware {
-            id: format!("S-5287-{}", size.get::<inch>()),
+            id: format!("S-5287-{}", inch_fraction(size)),
             hardware_type: HardwareType::MasterLink(MasterLinkType::Forged {
                 num_attachments: 4,
             }),
             rated_capacity: capacity,
             material: HardwareMaterial::AlloySteel { grade: SteelGrade::Grade80 },
             manufacturer: "Crosby".into(),
+            side_load_curve: None,
         }
     }
     
@@ -510,6 +690,7 @@ pub mod hooks {
             rated_capacity: capacity,
             material: HardwareMaterial::AlloySteel { grade: SteelGrade::Grade80 },
             manufacturer: "Crosby".into(),
+            side_load_curve: None,
         }
     }
     
@@ -542,26 +723,28 @@ pub mod eye_bolts {
     /// Regular eye bolt - VERTICAL LOAD ONLY
     pub fn regular_eye_bolt(thread_size: Length, capacity: Mass) -> Hardware {
         Hardware {
-            id: format!("EYE-REG-{}", thread_size.get::<inch>()),
+            id: format!("EYE-REG-{}", inch_fraction(thread_size)),
             hardware_type: HardwareType::EyeBolt(EyeBoltType::Regular {
                 thread_diameter: thread_size,
             }),
             rated_capacity: capacity,
             material: HardwareMaterial::CarbonSteel,
             manufacturer: "Generic".into(),
+            side_load_curve: None,
         }
     }
     
     /// Shoulder eye bolt - can handle angular loads
     pub fn shoulder_eye_bolt(thread_size: Length, capacity: Mass) -> Hardware {
         Hardware {
-            id: format!("EYE-SHOULDER-{}", thread_size.get::<inch>()),
+            id: format!("EYE-SHOULDER-{}", inch_fraction(thread_size)),
             hardware_type: HardwareType::EyeBolt(EyeBoltType::Shoulder {
                 thread_diameter: thread_size,
             }),
             rated_capacity: capacity,
             material: HardwareMaterial::AlloySteel { grade: SteelGrade::Grade80 },
             manufacturer: "Generic".into(),
+            side_load_curve: None,
         }
     }
     
@@ -609,6 +792,20 @@ mod tests {
     use super::*;
     use approx::assert_relative_eq;
     
+    #[test]
+    fn test_fractional_sizes() {
+        let shackle = crosby_shackles::one_quarter_anchor_screw();
+        let imperial = UnitPreferences::imperial().scoped(|| shackle.description());
+        assert_eq!(imperial, "1-1/4\" anchor shackle (G-209-1-1/4)");
+        let metric = UnitPreferences::metric().scoped(|| shackle.description());
+        assert_eq!(metric, "31.8mm anchor shackle (G-209-1-1/4)");
+
+        let eye_bolt = eye_bolts::five_eighths_shoulder();
+        assert_eq!(eye_bolt.id, "EYE-SHOULDER-5/8");
+        assert_eq!(master_links::two_ton().id, "S-5287-3/4");
+        assert_eq!(master_links::two_ton().description(), "master link (S-5287-3/4)");
+    }
+
     #[test]
     fn test_shackle_inline_capacity() {
         let shackle = crosby_shackles::half_inch_anchor_screw();
@@ -689,4 +886,44 @@ mod tests {
             }
         ));
     }
+
+    #[test]
+    fn test_side_load_curve_interpolates() {
+        let shackle = crosby_shackles::half_inch_anchor_screw();
+        let at = |hardware: &Hardware, degrees: f64| {
+            hardware
+                .effective_capacity(LoadingCondition::SideLoad { angle: Angle::new::<degree>(degrees) })
+                .get::<pound>()
+        };
+
+        // 46° is barely worse than 45°, nowhere near 90°
+        assert_relative_eq!(at(&shackle, 46.0), 3250.0 * (0.7 - 0.2 / 45.0), epsilon = 1e-9);
+        assert_relative_eq!(at(&shackle, 22.5), 3250.0 * 0.85, epsilon = 1e-9);
+        assert_relative_eq!(at(&shackle, 90.0), 3250.0 * 0.5, epsilon = 1e-9);
+
+        // Past square the generic curve drops to 30%, as the stepped rule did
+        assert_relative_eq!(at(&shackle, 90.5), 3250.0 * 0.3, epsilon = 1e-9);
+        assert_relative_eq!(at(&shackle, 135.0), 3250.0 * 0.3, epsilon = 1e-9);
+
+        let custom = shackle.with_side_load_curve(
+            SideLoadCurve::new([(Angle::new::<degree>(0.0), 1.0), (Angle::new::<degree>(90.0), 0.6)]).unwrap(),
+        );
+        assert_relative_eq!(at(&custom, 45.0), 3250.0 * 0.8, epsilon = 1e-9);
+        assert_relative_eq!(at(&custom, 120.0), 3250.0 * 0.6, epsilon = 1e-9);
+
+        assert!(matches!(SideLoadCurve::new([]), Err(SideLoadCurveError::Empty)));
+        assert!(matches!(
+            SideLoadCurve::new([(Angle::new::<degree>(45.0), 1.5)]),
+            Err(SideLoadCurveError::InvalidFactor { .. })
+        ));
+    }
+
+    #[cfg(feature = "charts-io")]
+    #[test]
+    fn test_side_load_curve_deserialize_validates() {
+        let curve: SideLoadCurve = serde_json::from_str("[[90, 0.6], [0, 1.0]]").unwrap();
+        assert_relative_eq!(curve.factor(Angle::new::<degree>(45.0)), 0.8, epsilon = 1e-9);
+        assert!(serde_json::from_str::<SideLoadCurve>("[]").is_err());
+        assert!(serde_json::from_str::<SideLoadCurve>("[[0, 0.0]]").is_err());
+    }
 }
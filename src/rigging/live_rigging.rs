@@ -8,6 +8,7 @@ pub enum LiveRiggingDevice {
         capacity: Mass,
         lift_height: Length,
         is_powered: bool,
+        takeup_per_stroke: Length, // Load chain taken up per hand-over-hand pull
     },
     
     /// Lever hoist (come-along)
@@ -15,6 +16,14 @@ pub enum LiveRiggingDevice {
         capacity: Mass,
         lift_height: Length,
         lever_ratio: f64, // Mechanical advantage
+        takeup_per_stroke: Length, // Load chain taken up per lever stroke
+    },
+    
+    /// Turnbuckle - right- and left-hand threads, so each turn takes up two pitches
+    Turnbuckle {
+        capacity: Mass,
+        thread_pitch: Length,
+        take_up: Length, // Total thread travel
     },
     
     /// Hydraulic jack
@@ -36,11 +45,36 @@ impl LiveRiggingDevice {
         match self {
             LiveRiggingDevice::ChainFall { capacity, .. } => *capacity,
             LiveRiggingDevice::LeverHoist { capacity, .. } => *capacity,
+            LiveRiggingDevice::Turnbuckle { capacity, .. } => *capacity,
             LiveRiggingDevice::HydraulicJack { capacity, .. } => *capacity,
             LiveRiggingDevice::Winch { capacity, .. } => *capacity,
         }
     }
     
+    /// Leg length taken up by one stroke, turn or jack stroke
+    ///
+    /// `None` for a winch, which pays in continuously.
+    pub fn travel_per_stroke(&self) -> Option<Length> {
+        match self {
+            LiveRiggingDevice::ChainFall { takeup_per_stroke, .. } => Some(*takeup_per_stroke),
+            LiveRiggingDevice::LeverHoist { takeup_per_stroke, .. } => Some(*takeup_per_stroke),
+            LiveRiggingDevice::Turnbuckle { thread_pitch, .. } => Some(*thread_pitch * 2.0),
+            LiveRiggingDevice::HydraulicJack { stroke, .. } => Some(*stroke),
+            LiveRiggingDevice::Winch { .. } => None,
+        }
+    }
+    
+    /// Total adjustment the device has, from fully paid out; `None` if unlimited
+    pub fn max_travel(&self) -> Option<Length> {
+        match self {
+            LiveRiggingDevice::ChainFall { lift_height, .. } => Some(*lift_height),
+            LiveRiggingDevice::LeverHoist { lift_height, .. } => Some(*lift_height),
+            LiveRiggingDevice::Turnbuckle { take_up, .. } => Some(*take_up),
+            LiveRiggingDevice::HydraulicJack { stroke, .. } => Some(*stroke),
+            LiveRiggingDevice::Winch { .. } => None,
+        }
+    }
+    
     /// Check if device can handle the given load
     pub fn can_handle(&self, load: Mass) -> bool {
         load <= self.capacity()
@@ -51,11 +85,11 @@ impl LiveRiggingDevice {
         match self {
             LiveRiggingDevice::LeverHoist { lever_ratio, .. } => {
                 // Pull force = Load / mechanical advantage
-                Force::new::<pound_force>(load.get::<pound>() / lever_ratio)
+                weight_of(load) / *lever_ratio
             }
             _ => {
                 // For powered devices or other types, return full load
-                Force::new::<pound_force>(load.get::<pound>())
+                weight_of(load)
             }
         }
     }
@@ -72,6 +106,17 @@ pub struct LiveLeg {
     
     /// Attachment point on load (relative to load COG)
     pub attachment_point: nalgebra::Point3<f64>,
+    
+    /// Length taken up by the device, from fully paid out
+    pub travel: Length,
+
+    /// Current length from the attachment point to the hook, when known;
+    /// take-up shortens it
+    pub length: Option<Length>,
+    
+    /// Axial stiffness of the leg (device and sling), lbf per foot of take-up;
+    /// 0 until set, in which case take-up moves the leg without loading it
+    pub stiffness: f64,
 }
 
 impl LiveLeg {
@@ -80,14 +125,68 @@ impl LiveLeg {
             device,
             tension: Force::new::<pound_force>(0.0),
             attachment_point,
+            travel: Length::new::<foot>(0.0),
+            length: None,
+            stiffness: 0.0,
+        }
+    }
+
+    /// Set the leg's current length, attachment point to hook
+    pub fn with_length(mut self, length: Length) -> Self {
+        self.length = Some(length);
+        self
+    }
+    
+    /// Set the leg's axial stiffness, lbf per foot
+    pub fn with_stiffness(mut self, stiffness: f64) -> Self {
+        self.stiffness = stiffness;
+        self
+    }
+    
+    /// Take up (positive) or pay out (negative) `length` of leg with both
+    /// ends held where they are
+    ///
+    /// The leg shortens by `length` and, since its ends can't move, tension
+    /// changes by stiffness × take-up and never goes below zero. A leg in a
+    /// bridle moves the hook instead; see
+    /// [`Bridle::adjust_live_leg`](crate::rigging::Bridle::adjust_live_leg).
+    /// Nothing changes if the device would run out of travel or capacity.
+    pub fn take_up(&mut self, length: Length) -> Result<(), LiveRiggingError> {
+        let (travel, leg_length) = self.travel_after(length)?;
+        let tension = (self.tension.get::<pound_force>() + self.stiffness * length.get::<foot>()).max(0.0);
+        self.set_tension(Force::new::<pound_force>(tension))?;
+        self.travel = travel;
+        self.length = leg_length;
+        Ok(())
+    }
+
+    /// Device travel and leg length after taking up `length`, or an error
+    /// if the device runs out of travel or the leg would vanish
+    pub(crate) fn travel_after(&self, length: Length) -> Result<(Length, Option<Length>), LiveRiggingError> {
+        let travel = self.travel + length;
+        let max = self.device.max_travel();
+        let leg_length = self.length.map(|l| l - length);
+        if travel < Length::new::<foot>(0.0)
+            || max.is_some_and(|max| travel > max)
+            || leg_length.is_some_and(|l| l <= Length::new::<foot>(0.0))
+        {
+            return Err(LiveRiggingError::TravelExceeded {
+                requested: DisplayLength(travel),
+                available: DisplayLength(max.unwrap_or(Length::new::<foot>(0.0))),
+            });
         }
+        Ok((travel, leg_length))
+    }
+    
+    /// Work the device `strokes` times (negative to pay out); see [`take_up`](Self::take_up)
+    pub fn operate(&mut self, strokes: f64) -> Result<(), LiveRiggingError> {
+        let per_stroke = self.device.travel_per_stroke().ok_or(LiveRiggingError::NotStroked)?;
+        self.take_up(per_stroke * strokes)
     }
     
     /// Set the tension on this leg
     pub fn set_tension(&mut self, tension: Force) -> Result<(), LiveRiggingError> {
-        let capacity_as_force = Force::new::<pound_force>(
-            self.device.capacity().get::<pound>()
-        );
+        let capacity_as_force = weight_of(self.device.capacity());
         
         if tension > capacity_as_force {
             return Err(LiveRiggingError::OverCapacity {
@@ -102,8 +201,7 @@ impl LiveLeg {
     
     /// Check if this leg is within safe operating limits
     pub fn is_safe(&self) -> bool {
-        let capacity = Force::new::<pound_force>(self.device.capacity().get::<pound>());
-        self.tension <= capacity
+        self.tension <= weight_of(self.device.capacity())
     }
 }
 
@@ -117,6 +215,15 @@ pub enum LiveRiggingError {
     
     #[error("Cannot achieve load balance with given configuration")]
     UnbalancedLoad,
+    
+    #[error("Take-up to {requested} is outside the device travel of {available}")]
+    TravelExceeded {
+        requested: DisplayLength,
+        available: DisplayLength,
+    },
+    
+    #[error("Device pays in continuously and has no stroke")]
+    NotStroked,
 }
 
 /// Common chain fall capacities per manufacturer specs
@@ -129,6 +236,7 @@ pub mod chain_fall_specs {
             capacity: Mass::new::<pound>(500.0),
             lift_height: Length::new::<foot>(10.0),
             is_powered: false,
+            takeup_per_stroke: Length::new::<inch>(0.75),
         }
     }
     
@@ -138,6 +246,7 @@ pub mod chain_fall_specs {
             capacity: Mass::new::<pound>(1000.0),
             lift_height: Length::new::<foot>(10.0),
             is_powered: false,
+            takeup_per_stroke: Length::new::<inch>(0.75),
         }
     }
     
@@ -147,6 +256,7 @@ pub mod chain_fall_specs {
             capacity: Mass::new::<pound>(2000.0),
             lift_height: Length::new::<foot>(10.0),
             is_powered: false,
+            takeup_per_stroke: Length::new::<inch>(0.75),
         }
     }
     
@@ -156,6 +266,7 @@ pub mod chain_fall_specs {
             capacity: Mass::new::<pound>(4000.0),
             lift_height: Length::new::<foot>(10.0),
             is_powered: false,
+            takeup_per_stroke: Length::new::<inch>(0.5),
         }
     }
     
@@ -165,6 +276,42 @@ pub mod chain_fall_specs {
             capacity: Mass::new::<pound>(6000.0),
             lift_height: Length::new::<foot>(10.0),
             is_powered: false,
+            takeup_per_stroke: Length::new::<inch>(0.375),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_turnbuckle_and_chain_fall_travel() {
+        let turnbuckle = LiveRiggingDevice::Turnbuckle {
+            capacity: Mass::new::<pound>(5200.0),
+            thread_pitch: Length::new::<inch>(0.125),
+            take_up: Length::new::<inch>(6.0),
+        };
+        let mut leg = LiveLeg::new(turnbuckle, nalgebra::Point3::origin())
+            .with_stiffness(12_000.0)
+            .with_length(Length::new::<foot>(4.0));
+
+        // Four turns take up an inch and load the leg 1,000 lbf
+        leg.operate(4.0).unwrap();
+        assert_relative_eq!(leg.travel.get::<inch>(), 1.0, epsilon = 1e-9);
+        assert_relative_eq!(leg.length.unwrap().get::<inch>(), 47.0, epsilon = 1e-9);
+        assert_relative_eq!(leg.tension.get::<pound_force>(), 1000.0, epsilon = 1e-6);
+
+        // Out of thread
+        assert!(matches!(leg.operate(100.0), Err(LiveRiggingError::TravelExceeded { .. })));
+        // Over capacity leaves the leg as it was
+        assert!(matches!(leg.operate(17.0), Err(LiveRiggingError::OverCapacity { .. })));
+        assert_relative_eq!(leg.travel.get::<inch>(), 1.0, epsilon = 1e-9);
+
+        let mut fall = LiveLeg::new(chain_fall_specs::one_ton(), nalgebra::Point3::origin());
+        fall.operate(8.0).unwrap();
+        assert_relative_eq!(fall.travel.get::<inch>(), 6.0, epsilon = 1e-9);
+        assert_relative_eq!(fall.tension.get::<pound_force>(), 0.0);
+    }
+}
@@ -1,9 +1,19 @@
 pub mod slings;
 pub mod hardware;
+pub mod catalog;
 pub mod bridles;
 pub mod live_rigging;
+pub mod taglines;
+pub mod wire_rope;
+pub mod trunnion;
+pub mod drawing;
 
 pub use slings::*;
 pub use hardware::*;
+pub use catalog::*;
 pub use bridles::*;
 pub use live_rigging::*;
+pub use taglines::*;
+pub use wire_rope::*;
+pub use trunnion::*;
+pub use drawing::*;
@@ -1,4 +1,6 @@
 use crate::types::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// Types of sling materials
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -23,6 +25,7 @@ pub enum SlingMaterial {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum WireRopeConstruction {
     /// 6x19 construction (standard)
     SixByNineteen,
@@ -122,6 +125,22 @@ impl Sling {
         }
     }
     
+    /// Short description with the rope, chain or webbing size, e.g.
+    /// `1/2" wire rope sling (WR-1)`, sized in the preferred length units
+    pub fn description(&self) -> String {
+        match self.material {
+            SlingMaterial::WireRope { diameter, .. } => {
+                format!("{} wire rope sling ({})", DisplayFractionalInches(diameter), self.id)
+            }
+            SlingMaterial::Synthetic { width, plies } => {
+                format!("{} {}-ply synthetic sling ({})", DisplayFractionalInches(width), plies, self.id)
+            }
+            SlingMaterial::Chain { size, .. } => {
+                format!("{} chain sling ({})", DisplayFractionalInches(size), self.id)
+            }
+        }
+    }
+
     /// Calculate effective capacity based on hitch type and angle
     /// 
     /// This is CRITICAL - sling angle dramatically affects capacity
@@ -269,6 +288,11 @@ mod tests {
         
         let capacity = sling.effective_capacity();
         assert_relative_eq!(capacity.get::<pound>(), 5000.0);
+
+        let description = UnitPreferences::imperial().scoped(|| sling.description());
+        assert_eq!(description, "1/2\" wire rope sling (Test-1)");
+        let description = UnitPreferences::dual().scoped(|| sling.description());
+        assert_eq!(description, "1/2\" (12.7mm) wire rope sling (Test-1)");
     }
     
     #[test]
@@ -0,0 +1,195 @@
+//! Tagline planning: line pull and handlers needed to control a load in wind
+//!
+//! Wind on a suspended load acts at its center of pressure, which is rarely
+//! under the hook. The offset turns the wind force into a yawing moment that
+//! the taglines have to hold:
+//!
+//! T = F_wind · e / (a · cos θ)
+//!
+//! where `e` is the center of pressure offset from the load CG, `a` the
+//! tagline attachment distance from the CG and `θ` the tagline angle below
+//! horizontal. Drift of the load as a whole is left to the pendulum of the
+//! hoist rope; taglines only control rotation.
+
+use crate::capacity::lift_validation::LoadDimensions;
+use crate::types::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Where the taglines attach and how they run to the handlers
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TaglineAttachment {
+    /// Horizontal distance from the load CG to the attachment point
+    pub distance_from_cg: Length,
+
+    /// Tagline angle below horizontal from the load to the handler
+    pub angle_below_horizontal: Angle,
+}
+
+impl TaglineAttachment {
+    /// Lines on the ends of the load's long side, run out at 45°
+    pub fn at_ends(dimensions: &LoadDimensions) -> Self {
+        Self {
+            distance_from_cg: dimensions.length * 0.5,
+            angle_below_horizontal: Angle::new::<degree>(45.0),
+        }
+    }
+}
+
+/// Limits for one tagline plan
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TaglineLimits {
+    /// Sustained pull one handler can hold on a tagline
+    pub pull_per_handler: Force,
+
+    /// Handlers that can work one line together
+    pub max_handlers_per_line: usize,
+
+    /// Center of pressure offset from the CG as a fraction of load length
+    pub pressure_offset_fraction: f64,
+}
+
+impl Default for TaglineLimits {
+    fn default() -> Self {
+        Self {
+            pull_per_handler: Force::new::<pound_force>(50.0),
+            max_handlers_per_line: 2,
+            pressure_offset_fraction: 0.1,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TaglineError {
+    #[error("Tagline attachment has no lever arm about the load CG")]
+    NoLeverArm,
+
+    #[error("Tagline angle {angle} leaves no horizontal pull")]
+    TooSteep { angle: DisplayAngle },
+}
+
+/// Taglines and handlers for a lift
+#[derive(Debug, Clone)]
+pub struct TaglineAnalysis {
+    /// Yawing moment from wind about the hook
+    pub wind_moment: Torque,
+
+    /// Tension needed in each working line
+    pub line_tension: Force,
+
+    /// Lines to rig; one on each end so the load can be held either way
+    pub taglines: usize,
+
+    pub handlers_per_line: usize,
+
+    pub handlers: usize,
+
+    /// Whether the handlers can hold the load within the limits
+    pub controllable: bool,
+
+    pub warnings: Vec<String>,
+}
+
+/// Tagline tension and crew for `wind_force` on a load of `dimensions`
+pub fn plan_taglines(
+    dimensions: &LoadDimensions,
+    wind_force: Force,
+    attachment: &TaglineAttachment,
+    limits: &TaglineLimits,
+) -> Result<TaglineAnalysis, TaglineError> {
+    let arm = attachment.distance_from_cg.get::<foot>();
+    if arm <= 0.0 {
+        return Err(TaglineError::NoLeverArm);
+    }
+    let cos = attachment.angle_below_horizontal.get::<radian>().cos();
+    if cos < 1e-3 {
+        return Err(TaglineError::TooSteep { angle: DisplayAngle(attachment.angle_below_horizontal) });
+    }
+
+    let offset = dimensions.length.get::<foot>() * limits.pressure_offset_fraction;
+    let moment = wind_force.get::<pound_force>().abs() * offset;
+    let tension = moment / (arm * cos);
+
+    let per_handler = limits.pull_per_handler.get::<pound_force>();
+    // Small allowance so round-off on an exact multiple doesn't add a handler
+    let handlers_per_line = ((tension / per_handler - 1e-9).ceil() as usize).max(1);
+    let taglines = 2;
+
+    let mut warnings = Vec::new();
+    let controllable = handlers_per_line <= limits.max_handlers_per_line;
+    if !controllable {
+        warnings.push(format!(
+            "Tagline tension {:.0} lbs needs {} handlers per line, more than {} can work one line; \
+             wait for lower wind or reduce the load's sail area",
+            tension, handlers_per_line, limits.max_handlers_per_line
+        ));
+    }
+    if attachment.angle_below_horizontal > Angle::new::<degree>(60.0) {
+        warnings.push("Taglines steeper than 60° give little control; handlers should stand further out".into());
+    }
+
+    Ok(TaglineAnalysis {
+        wind_moment: Torque::new::<pound_force_foot>(moment),
+        line_tension: Force::new::<pound_force>(tension),
+        taglines,
+        handlers_per_line,
+        handlers: taglines * handlers_per_line,
+        controllable,
+        warnings,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn dimensions() -> LoadDimensions {
+        LoadDimensions {
+            length: Length::new::<foot>(40.0),
+            width: Length::new::<foot>(8.0),
+            height: Length::new::<foot>(8.0),
+        }
+    }
+
+    #[test]
+    fn test_tagline_tension_and_handlers() {
+        let dims = dimensions();
+        let attachment = TaglineAttachment {
+            distance_from_cg: Length::new::<foot>(20.0),
+            angle_below_horizontal: Angle::new::<degree>(0.0),
+        };
+
+        // 200 lbf at 4 ft offset: 800 lb·ft over a 20 ft arm = 40 lbf, one handler each
+        let plan = plan_taglines(&dims, Force::new::<pound_force>(200.0), &attachment, &TaglineLimits::default())
+            .unwrap();
+        assert_relative_eq!(plan.wind_moment.get::<pound_force_foot>(), 800.0, epsilon = 1e-9);
+        assert_relative_eq!(plan.line_tension.get::<pound_force>(), 40.0, epsilon = 1e-9);
+        assert_eq!(plan.handlers, 2);
+        assert!(plan.controllable);
+
+        // Five times the wind needs four handlers a line
+        let plan = plan_taglines(&dims, Force::new::<pound_force>(1000.0), &attachment, &TaglineLimits::default())
+            .unwrap();
+        assert_eq!(plan.handlers_per_line, 4);
+        assert!(!plan.controllable);
+        assert!(!plan.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_tagline_angle_increases_tension() {
+        let dims = dimensions();
+        let attachment = TaglineAttachment::at_ends(&dims);
+        let plan = plan_taglines(&dims, Force::new::<pound_force>(200.0), &attachment, &TaglineLimits::default())
+            .unwrap();
+        assert_relative_eq!(plan.line_tension.get::<pound_force>(), 40.0 * 2f64.sqrt(), max_relative = 1e-9);
+
+        let steep = TaglineAttachment { angle_below_horizontal: Angle::new::<degree>(90.0), ..attachment };
+        assert!(matches!(
+            plan_taglines(&dims, Force::new::<pound_force>(200.0), &steep, &TaglineLimits::default()),
+            Err(TaglineError::TooSteep { .. })
+        ));
+    }
+}
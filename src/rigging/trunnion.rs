@@ -0,0 +1,195 @@
+//! Rotating a load about a fixed trunnion (tailing up on a turning frame)
+//!
+//! One end of the load pivots on trunnions while the crane lifts the other
+//! end with a plumb hoist line. Moments about the trunnion give the hook
+//! load at each rotation angle θ above horizontal:
+//!
+//! F · a · cos θ = W · (c · cos θ − e · sin θ) + μ · r · (W − F)
+//!
+//! where `a` and `c` are the lifting point and CG distances along the load
+//! from the trunnion, `e` the CG offset square to the load axis (positive
+//! on the top side when horizontal), and `μ · r` the trunnion pin friction
+//! coefficient times pin radius. Friction resists the rotation, so it adds
+//! to the hook load while the load is being raised.
+
+use crate::types::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Load geometry about the trunnion
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TrunnionLift {
+    pub load: Mass,
+
+    /// Lifting point distance along the load from the trunnion
+    pub lift_point: Length,
+
+    /// CG distance along the load from the trunnion
+    pub cg_along: Length,
+
+    /// CG offset square to the load axis, positive on the top side when horizontal
+    pub cg_offset: Length,
+
+    /// Trunnion pin radius
+    pub pin_radius: Length,
+
+    /// Pin friction coefficient
+    pub friction: f64,
+
+    /// Rigging and spreader weight carried by the hook
+    pub rigging: Mass,
+}
+
+/// Hook load at one rotation angle
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TrunnionStep {
+    /// Load axis angle above horizontal
+    pub angle: Angle,
+
+    /// Hook load including rigging
+    pub hook_load: Mass,
+
+    /// Vertical reaction on the trunnion
+    pub trunnion_reaction: Mass,
+
+    /// Lifting point horizontal distance from the trunnion
+    pub lift_point_reach: Length,
+
+    /// Lifting point height above the trunnion
+    pub lift_point_height: Length,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TrunnionAnalysis {
+    pub steps: Vec<TrunnionStep>,
+
+    /// Smallest angle where the CG passes over the trunnion and the load
+    /// would topple onward with no hook load, if reached
+    pub tipping_angle: Option<Angle>,
+}
+
+impl TrunnionAnalysis {
+    /// Step with the largest hook load; check this against the chart
+    pub fn peak(&self) -> Option<&TrunnionStep> {
+        self.steps
+            .iter()
+            .max_by(|a, b| a.hook_load.get::<pound>().total_cmp(&b.hook_load.get::<pound>()))
+    }
+
+    pub fn peak_hook_load(&self) -> Mass {
+        self.peak().map_or(Mass::new::<pound>(0.0), |step| step.hook_load)
+    }
+}
+
+impl TrunnionLift {
+    /// Load with its CG on the axis, frictionless trunnion and no rigging weight
+    pub fn new(load: Mass, lift_point: Length, cg_along: Length) -> Self {
+        Self {
+            load,
+            lift_point,
+            cg_along,
+            cg_offset: Length::new::<foot>(0.0),
+            pin_radius: Length::new::<foot>(0.0),
+            friction: 0.0,
+            rigging: Mass::new::<pound>(0.0),
+        }
+    }
+
+    /// Load on the hook, not counting rigging, at `angle` above horizontal
+    pub fn hook_load_at(&self, angle: Angle) -> Mass {
+        let (sin, cos) = angle.get::<radian>().sin_cos();
+        let w = self.load.get::<pound>();
+        let a = self.lift_point.get::<foot>();
+        let c = self.cg_along.get::<foot>();
+        let e = self.cg_offset.get::<foot>();
+        let friction_arm = self.friction * self.pin_radius.get::<foot>();
+
+        let arm = a * cos + friction_arm;
+        let hook = if arm > 1e-9 { w * (c * cos - e * sin + friction_arm) / arm } else { 0.0 };
+        Mass::new::<pound>(hook.max(0.0))
+    }
+
+    /// Hook loads from `from` to `to` in `steps` increments, both ends included
+    pub fn analyze(&self, from: Angle, to: Angle, steps: usize) -> TrunnionAnalysis {
+        let count = steps.max(2);
+        let (from_deg, to_deg) = (from.get::<degree>(), to.get::<degree>());
+
+        let steps: Vec<TrunnionStep> = (0..count)
+            .map(|i| {
+                let angle = Angle::new::<degree>(from_deg + (to_deg - from_deg) * i as f64 / (count - 1) as f64);
+                let (sin, cos) = angle.get::<radian>().sin_cos();
+                let load = self.hook_load_at(angle);
+                TrunnionStep {
+                    angle,
+                    hook_load: load + self.rigging,
+                    trunnion_reaction: self.load - load,
+                    lift_point_reach: self.lift_point * cos,
+                    lift_point_height: self.lift_point * sin,
+                }
+            })
+            .collect();
+
+        // CG moment arm c·cos θ − e·sin θ reaches zero
+        let (c, e) = (self.cg_along.get::<foot>(), self.cg_offset.get::<foot>());
+        let tipping = Angle::new::<radian>(c.atan2(e));
+        let tipping_angle = (tipping >= from.min(to) && tipping <= from.max(to)).then_some(tipping);
+
+        TrunnionAnalysis { steps, tipping_angle }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_hook_load_through_rotation() {
+        // 40 ft vessel lifted at the top, CG at mid-length on the axis:
+        // half the weight at every angle short of vertical
+        let lift = TrunnionLift::new(
+            Mass::new::<pound>(50_000.0),
+            Length::new::<foot>(40.0),
+            Length::new::<foot>(20.0),
+        );
+        let analysis = lift.analyze(Angle::new::<degree>(0.0), Angle::new::<degree>(80.0), 9);
+        for step in &analysis.steps {
+            assert_relative_eq!(step.hook_load.get::<pound>(), 25_000.0, max_relative = 1e-9);
+        }
+        assert_relative_eq!(analysis.steps[0].lift_point_reach.get::<foot>(), 40.0, epsilon = 1e-9);
+        assert!(analysis.tipping_angle.is_none());
+
+        // CG 2 ft above the axis moves toward the trunnion as it rises
+        let offset = TrunnionLift { cg_offset: Length::new::<foot>(2.0), ..lift };
+        let at_60 = offset.hook_load_at(Angle::new::<degree>(60.0)).get::<pound>();
+        let expected = 50_000.0 * (20.0 * 0.5 - 2.0 * 3f64.sqrt() / 2.0) / (40.0 * 0.5);
+        assert_relative_eq!(at_60, expected, max_relative = 1e-9);
+        let analysis = offset.analyze(Angle::new::<degree>(0.0), Angle::new::<degree>(90.0), 10);
+        assert_relative_eq!(
+            analysis.tipping_angle.unwrap().get::<degree>(),
+            20f64.atan2(2.0).to_degrees(),
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn test_friction_and_rigging_raise_peak() {
+        let lift = TrunnionLift {
+            pin_radius: Length::new::<foot>(0.5),
+            friction: 0.2,
+            rigging: Mass::new::<pound>(1_000.0),
+            ..TrunnionLift::new(Mass::new::<pound>(50_000.0), Length::new::<foot>(40.0), Length::new::<foot>(20.0))
+        };
+        // μr = 0.1 ft: at 0°, 50,000 × 20.1 / 40.1
+        let flat = lift.hook_load_at(Angle::new::<degree>(0.0)).get::<pound>();
+        assert_relative_eq!(flat, 50_000.0 * 20.1 / 40.1, max_relative = 1e-9);
+
+        let analysis = lift.analyze(Angle::new::<degree>(0.0), Angle::new::<degree>(85.0), 18);
+        let peak = analysis.peak().unwrap();
+        assert_relative_eq!(peak.angle.get::<degree>(), 85.0, epsilon = 1e-9);
+        assert!(analysis.peak_hook_load() > Mass::new::<pound>(26_000.0));
+    }
+}
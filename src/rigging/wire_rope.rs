@@ -0,0 +1,189 @@
+//! Wire rope breaking strength and design factors
+//!
+//! Minimum breaking force is estimated as `k · d²` with `k` fitted to the
+//! published IWRC tables for each construction and grade; the fits hold to
+//! within a few percent from 1/4" to 2". Use the manufacturer's certified
+//! figure when there is one.
+//!
+//! Design factors follow ASME B30.5 for running rope (3.5) and B30.9 for
+//! wire rope slings (5).
+
+use crate::equipment::HookBlock;
+use crate::rigging::slings::WireRopeConstruction;
+use crate::types::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Steel grade of the wires
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RopeGrade {
+    /// Improved plow steel
+    Ips,
+
+    /// Extra improved plow steel, the usual grade for crane and sling rope
+    Eips,
+
+    /// Extra extra improved plow steel
+    Eeips,
+}
+
+/// What the rope is used for, which sets the design factor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RopeService {
+    /// Hoist and luffing lines running over sheaves
+    Running,
+
+    /// Wire rope slings
+    Sling,
+}
+
+impl RopeService {
+    /// Minimum ratio of breaking force to working load
+    pub fn design_factor(&self) -> f64 {
+        match self {
+            RopeService::Running => 3.5,
+            RopeService::Sling => 5.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WireRope {
+    pub diameter: Length,
+    pub construction: WireRopeConstruction,
+    pub grade: RopeGrade,
+
+    /// Manufacturer's certified minimum breaking force, used instead of
+    /// the estimate when set
+    pub certified_breaking_force: Option<Force>,
+}
+
+/// A working load against a rope's breaking force
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WireRopeCheck {
+    pub line_pull: Force,
+    pub minimum_breaking_force: Force,
+
+    /// Design factor required for the service
+    pub required_design_factor: f64,
+
+    /// Breaking force over line pull
+    pub design_factor: f64,
+
+    /// Line pull over the allowable pull
+    pub utilization: f64,
+}
+
+impl WireRopeCheck {
+    pub fn is_acceptable(&self) -> bool {
+        self.design_factor >= self.required_design_factor
+    }
+}
+
+impl WireRope {
+    pub fn new(diameter: Length, construction: WireRopeConstruction, grade: RopeGrade) -> Self {
+        Self {
+            diameter,
+            construction,
+            grade,
+            certified_breaking_force: None,
+        }
+    }
+
+    /// Use the manufacturer's certified breaking force
+    pub fn with_certified_breaking_force(mut self, force: Force) -> Self {
+        self.certified_breaking_force = Some(force);
+        self
+    }
+
+    /// Minimum breaking force, certified or estimated
+    pub fn minimum_breaking_force(&self) -> Force {
+        if let Some(force) = self.certified_breaking_force {
+            return force;
+        }
+        // lbf per in² of nominal diameter squared
+        let k = match (self.construction, self.grade) {
+            // Aircraft cable is rated on its own; grade doesn't apply
+            (WireRopeConstruction::SevenByNineteen, _) => 112_000.0,
+            (_, RopeGrade::Ips) => 89_800.0,
+            (_, RopeGrade::Eips) => 103_400.0,
+            (_, RopeGrade::Eeips) => 113_800.0,
+        };
+        let d = self.diameter.get::<inch>();
+        Force::new::<pound_force>(k * d * d)
+    }
+
+    /// Largest working pull for `service`
+    pub fn allowable_pull(&self, service: RopeService) -> Force {
+        self.minimum_breaking_force() / service.design_factor()
+    }
+
+    /// Check `line_pull` against the breaking force for `service`
+    pub fn check(&self, line_pull: Force, service: RopeService) -> WireRopeCheck {
+        self.check_with_design_factor(line_pull, service.design_factor())
+    }
+
+    /// Check `line_pull` against the breaking force with a required design
+    /// factor of `required`, e.g. from a company policy
+    pub fn check_with_design_factor(&self, line_pull: Force, required: f64) -> WireRopeCheck {
+        let mbf = self.minimum_breaking_force().get::<pound_force>();
+        let pull = line_pull.get::<pound_force>();
+        let design_factor = if pull > 0.0 { mbf / pull } else { f64::INFINITY };
+        WireRopeCheck {
+            line_pull,
+            minimum_breaking_force: Force::new::<pound_force>(mbf),
+            required_design_factor: required,
+            design_factor,
+            utilization: pull * required / mbf,
+        }
+    }
+
+    /// Check the hoist line lifting `load` through `block` on `parts` parts of line
+    pub fn check_hoist_line(&self, block: &HookBlock, load: Mass, parts: u32) -> WireRopeCheck {
+        self.check(block.line_pull(load, parts), RopeService::Running)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::equipment::hook_blocks;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_breaking_force_and_design_factor() {
+        let rope = WireRope::new(Length::new::<inch>(1.0), WireRopeConstruction::SixByNineteen, RopeGrade::Eips);
+        assert_relative_eq!(rope.minimum_breaking_force().get::<pound_force>(), 103_400.0, max_relative = 1e-9);
+        assert_relative_eq!(
+            rope.allowable_pull(RopeService::Sling).get::<pound_force>(),
+            20_680.0,
+            max_relative = 1e-9
+        );
+
+        let certified = rope.with_certified_breaking_force(Force::new::<pound_force>(120_000.0));
+        assert_relative_eq!(certified.minimum_breaking_force().get::<pound_force>(), 120_000.0);
+    }
+
+    #[test]
+    fn test_hoist_line_check() {
+        // (100,000 + 3,300) / 7 = 14,757 lbf per part
+        let block = hook_blocks::five_sheave_100t();
+        let load = Mass::new::<pound>(100_000.0);
+
+        let three_quarter =
+            WireRope::new(Length::new::<inch>(0.75), WireRopeConstruction::SixByNineteen, RopeGrade::Eips);
+        let check = three_quarter.check_hoist_line(&block, load, 7);
+        assert_relative_eq!(check.line_pull.get::<pound_force>(), 103_300.0 / 7.0, max_relative = 1e-6);
+        assert_relative_eq!(check.design_factor, 103_400.0 * 0.5625 * 7.0 / 103_300.0, max_relative = 1e-6);
+        assert!(check.is_acceptable());
+
+        let five_eighths = WireRope { diameter: Length::new::<inch>(0.625), ..three_quarter };
+        let check = five_eighths.check_hoist_line(&block, load, 7);
+        assert!(!check.is_acceptable());
+        assert!(check.utilization > 1.0);
+    }
+}
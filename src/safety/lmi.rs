@@ -0,0 +1,299 @@
+//! Load moment indicator (rated capacity limiter) simulation
+//!
+//! The mobile crane counterpart of the tower crane `MomentLimiter`. The
+//! indicator wraps a crane, is fed joint configurations and load cell
+//! readings as the crane moves, and reports the limiter state:
+//!
+//! - **Normal** below the pre-warning threshold
+//! - **PreWarning** approaching rated capacity (typically 85-90%)
+//! - **Warning** close to rated capacity (typically 90-100%)
+//! - **Cutout** at or over rated capacity; motions that increase load
+//!   (hoist up, boom down, telescope out) are locked out
+//!
+//! The measured load is the total hook load from the load cell, including
+//! hook block and rigging, so it is compared against gross capacity.
+//!
+//! States escalate as soon as a threshold is crossed, but only step back
+//! down once utilization drops below the threshold by the hysteresis band,
+//! so the display doesn't chatter around a threshold.
+
+use crate::equipment::Crane;
+use crate::kinematics::JointConfig;
+use crate::types::*;
+
+/// Rated capacity limiter state, in order of severity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LmiState {
+    Normal,
+    PreWarning,
+    Warning,
+    Cutout,
+}
+
+/// Utilization fractions at which each state is entered
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LmiThresholds {
+    pub pre_warning: f64,
+    pub warning: f64,
+    pub cutout: f64,
+
+    /// Utilization drop below a threshold needed to leave its state
+    pub hysteresis: f64,
+}
+
+impl Default for LmiThresholds {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl LmiThresholds {
+    /// 85% pre-warning, 90% warning, 100% cutout, 2% hysteresis
+    pub const fn standard() -> Self {
+        Self {
+            pre_warning: 0.85,
+            warning: 0.9,
+            cutout: 1.0,
+            hysteresis: 0.02,
+        }
+    }
+
+    /// Validate configuration
+    pub fn validate(&self) -> Result<(), String> {
+        if self.pre_warning <= 0.0 {
+            return Err(format!("Pre-warning threshold must be > 0.0, got {}", self.pre_warning));
+        }
+
+        if !(self.pre_warning < self.warning && self.warning < self.cutout) {
+            return Err(format!(
+                "Thresholds must increase: pre-warning {}, warning {}, cutout {}",
+                self.pre_warning, self.warning, self.cutout
+            ));
+        }
+
+        if self.cutout > 1.0 {
+            return Err(format!("Cutout threshold must be <= 1.0, got {}", self.cutout));
+        }
+
+        if self.hysteresis < 0.0 || self.hysteresis >= self.pre_warning {
+            return Err(format!("Hysteresis must be 0.0 <= x < pre-warning, got {}", self.hysteresis));
+        }
+
+        Ok(())
+    }
+
+    fn entry(&self, state: LmiState) -> f64 {
+        match state {
+            LmiState::Normal => f64::NEG_INFINITY,
+            LmiState::PreWarning => self.pre_warning,
+            LmiState::Warning => self.warning,
+            LmiState::Cutout => self.cutout,
+        }
+    }
+
+    /// State for a utilization with no history
+    fn classify(&self, utilization: f64) -> LmiState {
+        if utilization >= self.cutout {
+            LmiState::Cutout
+        } else if utilization >= self.warning {
+            LmiState::Warning
+        } else if utilization >= self.pre_warning {
+            LmiState::PreWarning
+        } else {
+            LmiState::Normal
+        }
+    }
+
+    /// Next state from the current one, applying hysteresis on the way down
+    fn next(&self, current: LmiState, utilization: f64) -> LmiState {
+        let raw = self.classify(utilization);
+        if raw >= current {
+            return raw;
+        }
+        // Stay in the current state until clearly below its entry threshold
+        if utilization >= self.entry(current) - self.hysteresis {
+            current
+        } else {
+            self.classify(utilization + self.hysteresis).min(current)
+        }
+    }
+}
+
+/// One limiter update
+#[derive(Debug, Clone, Copy)]
+pub struct LmiReading {
+    pub state: LmiState,
+
+    /// State before this update
+    pub previous: LmiState,
+
+    /// Measured load / gross capacity
+    pub utilization: f64,
+
+    pub measured_load: Mass,
+    pub capacity: Mass,
+    pub radius: Length,
+}
+
+impl LmiReading {
+    /// The state changed on this update
+    pub fn changed(&self) -> bool {
+        self.state != self.previous
+    }
+
+    /// Motions that increase load moment are locked out
+    pub fn is_cutout(&self) -> bool {
+        self.state == LmiState::Cutout
+    }
+}
+
+/// Load moment indicator wrapping a crane
+#[derive(Debug, Clone)]
+pub struct LoadMomentIndicator<C: Crane> {
+    crane: C,
+    thresholds: LmiThresholds,
+    state: LmiState,
+    last: Option<LmiReading>,
+}
+
+impl<C: Crane> LoadMomentIndicator<C> {
+    pub fn new(crane: C, thresholds: LmiThresholds) -> Result<Self, String> {
+        thresholds.validate()?;
+
+        Ok(Self {
+            crane,
+            thresholds,
+            state: LmiState::Normal,
+            last: None,
+        })
+    }
+
+    /// Create with standard thresholds
+    pub fn standard(crane: C) -> Self {
+        Self::new(crane, LmiThresholds::standard()).unwrap()
+    }
+
+    pub fn crane(&self) -> &C {
+        &self.crane
+    }
+
+    pub fn into_crane(self) -> C {
+        self.crane
+    }
+
+    pub fn thresholds(&self) -> &LmiThresholds {
+        &self.thresholds
+    }
+
+    pub fn state(&self) -> LmiState {
+        self.state
+    }
+
+    /// Most recent reading, if any
+    pub fn last_reading(&self) -> Option<&LmiReading> {
+        self.last.as_ref()
+    }
+
+    /// Move the crane and evaluate the measured hook load
+    pub fn update(&mut self, joints: JointConfig, measured_load: Mass) -> LmiReading {
+        self.crane.set_joint_config(joints);
+        self.evaluate(measured_load)
+    }
+
+    /// Evaluate a new load cell reading at the current configuration
+    ///
+    /// Capacity is the gross chart capacity after duty-cycle derating.
+    pub fn evaluate(&mut self, measured_load: Mass) -> LmiReading {
+        let capacity = self.crane.gross_capacity() * self.crane.duty_class().capacity_factor();
+        let utilization = if capacity.get::<pound>() > 0.0 {
+            measured_load.get::<pound>() / capacity.get::<pound>()
+        } else {
+            // No capacity at this configuration: anything on the hook cuts out
+            f64::INFINITY
+        };
+
+        let previous = self.state;
+        self.state = self.thresholds.next(previous, utilization);
+
+        let reading = LmiReading {
+            state: self.state,
+            previous,
+            utilization,
+            measured_load,
+            capacity,
+            radius: self.crane.configuration().radius,
+        };
+        self.last = Some(reading);
+        reading
+    }
+
+    /// Reset to Normal, e.g. after the operator acknowledges a cutout
+    pub fn reset(&mut self) {
+        self.state = LmiState::Normal;
+        self.last = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::equipment::{Kinematic, MobileCrane};
+    use crate::test_fixtures::mobile_crane;
+
+    // 50 ft radius: 10,000 lbs on the fixture chart
+    fn lmi() -> LoadMomentIndicator<MobileCrane> {
+        LoadMomentIndicator::standard(mobile_crane())
+    }
+
+    fn lbs(value: f64) -> Mass {
+        Mass::new::<pound>(value)
+    }
+
+    #[test]
+    fn test_state_escalation() {
+        let mut lmi = lmi();
+
+        assert_eq!(lmi.evaluate(lbs(5_000.0)).state, LmiState::Normal);
+        assert_eq!(lmi.evaluate(lbs(8_600.0)).state, LmiState::PreWarning);
+        assert_eq!(lmi.evaluate(lbs(9_200.0)).state, LmiState::Warning);
+
+        let reading = lmi.evaluate(lbs(10_500.0));
+        assert!(reading.is_cutout());
+        assert!(reading.changed());
+        assert_eq!(reading.previous, LmiState::Warning);
+    }
+
+    #[test]
+    fn test_hysteresis() {
+        let mut lmi = lmi();
+        lmi.evaluate(lbs(10_000.0));
+        assert_eq!(lmi.state(), LmiState::Cutout);
+
+        // Just under cutout: held by hysteresis
+        assert_eq!(lmi.evaluate(lbs(9_900.0)).state, LmiState::Cutout);
+        // Clearly below cutout, still above warning
+        assert_eq!(lmi.evaluate(lbs(9_500.0)).state, LmiState::Warning);
+        // Straight back to normal from a large drop
+        assert_eq!(lmi.evaluate(lbs(2_000.0)).state, LmiState::Normal);
+    }
+
+    #[test]
+    fn test_update_moves_crane() {
+        let mut lmi = lmi();
+        let mut joints = lmi.crane().joint_config();
+        joints.boom_angle = Angle::new::<degree>(70.0);
+
+        let reading = lmi.update(joints, lbs(1_000.0));
+        assert!((lmi.crane().boom_angle.get::<degree>() - 70.0).abs() < 1e-9);
+        assert!((reading.radius.get::<foot>() - 100.0 * 70f64.to_radians().cos()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_invalid_thresholds() {
+        let thresholds = LmiThresholds {
+            warning: 0.8,
+            ..LmiThresholds::standard()
+        };
+        assert!(LoadMomentIndicator::new(lmi().into_crane(), thresholds).is_err());
+    }
+}
@@ -0,0 +1,5 @@
+pub mod lmi;
+pub mod telemetry;
+
+pub use lmi::*;
+pub use telemetry::*;
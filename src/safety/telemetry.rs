@@ -0,0 +1,448 @@
+//! Crane telemetry import and post-lift analysis
+//!
+//! Data loggers record the crane's configuration and load cell at intervals
+//! through a job. [`TelemetryLog`] reads those logs from CSV, and
+//! [`analyze`] replays them through a [`LoadMomentIndicator`] to produce a
+//! [`TelemetryReport`] for post-job review: when the crane went over its
+//! rating, how close it came, and how hard it worked.
+//!
+//! # CSV format
+//!
+//! One row per sample, with a header row:
+//!
+//! ```text
+//! timestamp,swing,boom_angle,load,boom_length
+//! 0.0,0.0,60.0,0,100.0
+//! 0.5,1.2,60.0,8500,100.0
+//! ```
+//!
+//! - `timestamp` seconds, increasing
+//! - `swing`, `boom_angle` degrees
+//! - `load` pounds, total hook load from the load cell
+//! - `boom_length` feet, optional; when the column is missing or empty the
+//!   crane's current boom length is kept
+//!
+//! Each sample is taken to hold until the next one, so the last sample in a
+//! log contributes no time to the statistics.
+
+use crate::equipment::Crane;
+use crate::safety::lmi::*;
+use crate::types::*;
+#[cfg(feature = "charts-io")]
+use serde::Deserialize;
+use std::f64::consts::PI;
+#[cfg(feature = "charts-io")]
+use std::{io::Read, path::Path};
+
+#[derive(Debug, thiserror::Error)]
+pub enum TelemetryError {
+    #[cfg(feature = "charts-io")]
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
+
+    #[cfg(feature = "charts-io")]
+    #[error("CSV error: {0}")]
+    CsvError(#[from] csv::Error),
+
+    #[error("Row {row}: timestamp {timestamp} s is not after the previous sample")]
+    NonMonotonicTimestamp { row: usize, timestamp: f64 },
+
+    #[error("Row {row}: {field} is not a finite number")]
+    InvalidValue { row: usize, field: &'static str },
+}
+
+/// One logged sample
+#[derive(Debug, Clone, Copy)]
+pub struct TelemetrySample {
+    /// Time since the start of the log
+    pub timestamp: Time,
+    pub swing: Angle,
+    pub boom_angle: Angle,
+
+    /// Total hook load from the load cell
+    pub load: Mass,
+
+    /// Boom length, if logged
+    pub boom_length: Option<Length>,
+}
+
+/// CSV row as logged, in seconds, degrees, pounds and feet
+#[cfg(feature = "charts-io")]
+#[derive(Debug, Deserialize)]
+struct TelemetryRow {
+    timestamp: f64,
+    swing: f64,
+    boom_angle: f64,
+    load: f64,
+    #[serde(default)]
+    boom_length: Option<f64>,
+}
+
+#[cfg(feature = "charts-io")]
+impl TelemetryRow {
+    fn into_sample(self, row: usize) -> Result<TelemetrySample, TelemetryError> {
+        let check = |value: f64, field| {
+            if value.is_finite() {
+                Ok(value)
+            } else {
+                Err(TelemetryError::InvalidValue { row, field })
+            }
+        };
+
+        Ok(TelemetrySample {
+            timestamp: Time::new::<second>(check(self.timestamp, "timestamp")?),
+            swing: Angle::new::<degree>(check(self.swing, "swing")?),
+            boom_angle: Angle::new::<degree>(check(self.boom_angle, "boom_angle")?),
+            load: Mass::new::<pound>(check(self.load, "load")?),
+            boom_length: self
+                .boom_length
+                .map(|l| check(l, "boom_length").map(Length::new::<foot>))
+                .transpose()?,
+        })
+    }
+}
+
+/// Time series of samples, in timestamp order
+#[derive(Debug, Clone, Default)]
+pub struct TelemetryLog {
+    pub samples: Vec<TelemetrySample>,
+}
+
+impl TelemetryLog {
+    /// Read a CSV log (see the module docs for the format)
+    #[cfg(feature = "charts-io")]
+    pub fn from_reader(reader: impl Read) -> Result<Self, TelemetryError> {
+        let mut csv = csv::ReaderBuilder::new().trim(csv::Trim::All).from_reader(reader);
+        let mut samples: Vec<TelemetrySample> = Vec::new();
+
+        for (i, row) in csv.deserialize::<TelemetryRow>().enumerate() {
+            // Data rows start at line 2, after the header
+            let row_number = i + 2;
+            let sample = row?.into_sample(row_number)?;
+
+            if let Some(last) = samples.last()
+                && sample.timestamp <= last.timestamp
+            {
+                return Err(TelemetryError::NonMonotonicTimestamp {
+                    row: row_number,
+                    timestamp: sample.timestamp.get::<second>(),
+                });
+            }
+            samples.push(sample);
+        }
+
+        Ok(Self { samples })
+    }
+
+    #[cfg(feature = "charts-io")]
+    pub fn from_csv_file(path: impl AsRef<Path>) -> Result<Self, TelemetryError> {
+        Self::from_reader(std::fs::File::open(path)?)
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Time from the first sample to the last
+    pub fn duration(&self) -> Time {
+        match (self.samples.first(), self.samples.last()) {
+            (Some(first), Some(last)) => last.timestamp - first.timestamp,
+            _ => Time::new::<second>(0.0),
+        }
+    }
+}
+
+/// A continuous period at or over rated capacity (LMI cutout)
+#[derive(Debug, Clone, Copy)]
+pub struct Exceedance {
+    pub start: Time,
+
+    /// Time of the first sample back under the cutout, or of the last
+    /// sample if the log ends in cutout
+    pub end: Time,
+
+    pub peak_utilization: f64,
+    pub peak_load: Mass,
+
+    /// Radius at the peak
+    pub radius: Length,
+}
+
+impl Exceedance {
+    pub fn duration(&self) -> Time {
+        self.end - self.start
+    }
+}
+
+/// Highest utilization seen in a log
+#[derive(Debug, Clone, Copy)]
+pub struct PeakUtilization {
+    pub timestamp: Time,
+    pub utilization: f64,
+    pub load: Mass,
+    pub capacity: Mass,
+    pub radius: Length,
+}
+
+/// How hard the crane worked over a log
+#[derive(Debug, Clone, Copy)]
+pub struct DutyCycle {
+    /// Time with more than the loaded threshold on the hook
+    pub loaded_time: Time,
+
+    /// Number of picks: transitions from unloaded to loaded
+    pub lift_cycles: usize,
+
+    /// Time-weighted mean utilization while loaded
+    pub mean_loaded_utilization: f64,
+
+    /// Total slew travel, either direction
+    pub slew_travel: Angle,
+
+    /// Time spent in each LMI state, indexed Normal, PreWarning, Warning, Cutout
+    pub time_in_state: [Time; 4],
+}
+
+impl DutyCycle {
+    pub fn time_in(&self, state: LmiState) -> Time {
+        self.time_in_state[state as usize]
+    }
+}
+
+/// Post-lift analysis of a telemetry log
+#[derive(Debug, Clone)]
+pub struct TelemetryReport {
+    pub samples: usize,
+    pub duration: Time,
+
+    /// `None` for an empty log
+    pub peak: Option<PeakUtilization>,
+
+    pub exceedances: Vec<Exceedance>,
+
+    /// Number of times the LMI entered the warning state from below
+    pub warnings: usize,
+
+    pub duty: DutyCycle,
+}
+
+impl TelemetryReport {
+    /// The crane never reached rated capacity
+    pub fn is_clean(&self) -> bool {
+        self.exceedances.is_empty()
+    }
+
+    /// Fraction of the log spent loaded
+    pub fn loaded_fraction(&self) -> f64 {
+        let duration = self.duration.get::<second>();
+        if duration > 0.0 {
+            self.duty.loaded_time.get::<second>() / duration
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Replay a log through a load moment indicator
+///
+/// The indicator's crane is moved to each logged configuration in turn, so
+/// capacity follows the crane's own charts and hook block. A hook load above
+/// `loaded_threshold` counts as loaded for the duty-cycle statistics.
+pub fn analyze<C: Crane>(
+    lmi: &mut LoadMomentIndicator<C>,
+    log: &TelemetryLog,
+    loaded_threshold: Mass,
+) -> TelemetryReport {
+    let zero_time = Time::new::<second>(0.0);
+    let mut peak: Option<PeakUtilization> = None;
+    let mut exceedances: Vec<Exceedance> = Vec::new();
+    let mut open: Option<Exceedance> = None;
+    let mut warnings = 0;
+
+    let mut time_in_state = [zero_time; 4];
+    let mut loaded_time = zero_time;
+    let mut loaded_utilization_seconds = 0.0;
+    let mut lift_cycles = 0;
+    let mut was_loaded = false;
+    let mut slew_travel = 0.0;
+
+    for (i, sample) in log.samples.iter().enumerate() {
+        let mut joints = lmi.crane().joint_config();
+        joints.swing = sample.swing;
+        joints.boom_angle = sample.boom_angle;
+        if let Some(boom_length) = sample.boom_length {
+            joints.boom_length = boom_length;
+        }
+        let reading = lmi.update(joints, sample.load);
+
+        if peak.is_none_or(|p| reading.utilization > p.utilization) {
+            peak = Some(PeakUtilization {
+                timestamp: sample.timestamp,
+                utilization: reading.utilization,
+                load: sample.load,
+                capacity: reading.capacity,
+                radius: reading.radius,
+            });
+        }
+
+        if reading.changed() && reading.state == LmiState::Warning && reading.previous < LmiState::Warning {
+            warnings += 1;
+        }
+
+        // Exceedances follow the raw cutout threshold, not the LMI's
+        // hysteresis, so they record time actually over capacity
+        let over = reading.utilization >= lmi.thresholds().cutout;
+        match (&mut open, over) {
+            (None, true) => {
+                open = Some(Exceedance {
+                    start: sample.timestamp,
+                    end: sample.timestamp,
+                    peak_utilization: reading.utilization,
+                    peak_load: sample.load,
+                    radius: reading.radius,
+                });
+            }
+            (Some(exceedance), true) => {
+                exceedance.end = sample.timestamp;
+                if reading.utilization > exceedance.peak_utilization {
+                    exceedance.peak_utilization = reading.utilization;
+                    exceedance.peak_load = sample.load;
+                    exceedance.radius = reading.radius;
+                }
+            }
+            (Some(exceedance), false) => {
+                exceedance.end = sample.timestamp;
+                exceedances.extend(open.take());
+            }
+            (None, false) => {}
+        }
+
+        let loaded = sample.load > loaded_threshold;
+        if loaded && !was_loaded {
+            lift_cycles += 1;
+        }
+        was_loaded = loaded;
+
+        // Sample holds until the next one
+        if let Some(next) = log.samples.get(i + 1) {
+            let dt = next.timestamp - sample.timestamp;
+            time_in_state[reading.state as usize] += dt;
+            if loaded {
+                loaded_time += dt;
+                if reading.utilization.is_finite() {
+                    loaded_utilization_seconds += reading.utilization * dt.get::<second>();
+                }
+            }
+            // Shortest way round, so a swing logged 359° then 1° is 2° of travel
+            let step = (next.swing - sample.swing).get::<radian>();
+            slew_travel += ((step + PI).rem_euclid(2.0 * PI) - PI).abs();
+        }
+    }
+    exceedances.extend(open);
+
+    let mean_loaded_utilization = if loaded_time > zero_time {
+        loaded_utilization_seconds / loaded_time.get::<second>()
+    } else {
+        0.0
+    };
+
+    TelemetryReport {
+        samples: log.len(),
+        duration: log.duration(),
+        peak,
+        exceedances,
+        warnings,
+        duty: DutyCycle {
+            loaded_time,
+            lift_cycles,
+            mean_loaded_utilization,
+            slew_travel: Angle::new::<radian>(slew_travel),
+            time_in_state,
+        },
+    }
+}
+
+#[cfg(all(test, feature = "charts-io"))]
+mod tests {
+    use super::*;
+    use crate::equipment::MobileCrane;
+    use crate::test_fixtures::mobile_crane;
+    use approx::assert_relative_eq;
+
+    // 100 ft boom at 60°: 10,000 lbs at 50 ft on the fixture chart
+    fn lmi() -> LoadMomentIndicator<MobileCrane> {
+        LoadMomentIndicator::standard(mobile_crane())
+    }
+
+    const LOG: &str = "\
+timestamp,swing,boom_angle,load,boom_length
+0,0,60,0,
+10,0,60,5000,
+20,30,60,9200,
+30,60,60,10500,100
+40,90,60,10200,100
+50,90,60,4000,100
+60,90,60,0,100
+70,90,60,8000,100
+80,90,60,0,100
+";
+
+    #[test]
+    fn test_parse() {
+        let log = TelemetryLog::from_reader(LOG.as_bytes()).unwrap();
+        assert_eq!(log.len(), 9);
+        assert_relative_eq!(log.duration().get::<second>(), 80.0);
+        assert!(log.samples[0].boom_length.is_none());
+        assert_relative_eq!(log.samples[3].boom_length.unwrap().get::<foot>(), 100.0);
+        assert_relative_eq!(log.samples[2].load.get::<pound>(), 9200.0);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let backwards = "timestamp,swing,boom_angle,load\n0,0,60,0\n10,0,60,0\n5,0,60,0\n";
+        assert!(matches!(
+            TelemetryLog::from_reader(backwards.as_bytes()),
+            Err(TelemetryError::NonMonotonicTimestamp { row: 4, .. })
+        ));
+
+        let garbled = "timestamp,swing,boom_angle,load\n0,0,sixty,0\n";
+        assert!(matches!(TelemetryLog::from_reader(garbled.as_bytes()), Err(TelemetryError::CsvError(_))));
+    }
+
+    #[test]
+    fn test_analyze() {
+        let log = TelemetryLog::from_reader(LOG.as_bytes()).unwrap();
+        let report = analyze(&mut lmi(), &log, Mass::new::<pound>(100.0));
+
+        let peak = report.peak.unwrap();
+        assert_relative_eq!(peak.utilization, 1.05, epsilon = 1e-9);
+        assert_relative_eq!(peak.timestamp.get::<second>(), 30.0);
+
+        // Over capacity from 30 s until back under at 50 s
+        assert_eq!(report.exceedances.len(), 1);
+        let exceedance = report.exceedances[0];
+        assert_relative_eq!(exceedance.start.get::<second>(), 30.0);
+        assert_relative_eq!(exceedance.duration().get::<second>(), 20.0);
+        assert!(!report.is_clean());
+        assert_eq!(report.warnings, 1);
+
+        // Two picks, loaded 10-60 s and 70-80 s
+        assert_eq!(report.duty.lift_cycles, 2);
+        assert_relative_eq!(report.duty.loaded_time.get::<second>(), 60.0);
+        assert_relative_eq!(report.loaded_fraction(), 0.75);
+        // (0.5 + 0.92 + 1.05 + 1.02 + 0.4 + 0.8) * 10 s / 60 s
+        assert_relative_eq!(report.duty.mean_loaded_utilization, 4.69 / 6.0, epsilon = 1e-9);
+        assert_relative_eq!(report.duty.slew_travel.get::<degree>(), 90.0, epsilon = 1e-9);
+        assert_relative_eq!(report.duty.time_in(LmiState::Cutout).get::<second>(), 20.0);
+
+        // Swinging through north: 350° to 10° is 20° of travel, not 340°
+        let through_north = "timestamp,swing,boom_angle,load\n0,350,60,0\n10,10,60,0\n20,350,60,0\n";
+        let log = TelemetryLog::from_reader(through_north.as_bytes()).unwrap();
+        let report = analyze(&mut lmi(), &log, Mass::new::<pound>(100.0));
+        assert_relative_eq!(report.duty.slew_travel.get::<degree>(), 40.0, epsilon = 1e-9);
+    }
+}
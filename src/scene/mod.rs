@@ -0,0 +1,318 @@
+//! Crane scene graph for 3D viewers
+//!
+//! [`CraneScene::new`] builds a transform tree from a crane's current state:
+//!
+//! ```text
+//! Carrier ─┬─ outrigger pads
+//!          └─ Superstructure (slew) ── Boom (luff) ─┬─ boom sections
+//!                                                   └─ Boom tip ─ Jib ─ Jib tip
+//!                                                                   └─ Hoist ─ Hook ─ Load
+//! ```
+//!
+//! The hoist hangs from the trolley instead of the tip on cranes that have
+//! one. Each node's transform is relative to its parent, so a viewer only
+//! needs to update the joint nodes to animate the crane; the world
+//! positions of the tips and hook match the crate's forward kinematics.
+//! Lengths are in feet, in crate coordinates (X right, Y up, Z forward).
+
+use crate::capacity::lift_validation::LoadDimensions;
+use crate::equipment::Crane;
+use crate::kinematics::rotation_z_radians;
+use crate::types::*;
+use nalgebra as na;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Primitive a node is drawn with, in the node's own frame (feet)
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Shape {
+    /// Box of `size` (X, Y, Z) centered at `center`
+    Box { center: na::Point3<f64>, size: na::Vector3<f64> },
+
+    /// Cylinder with its axis along Y, centered at `center`
+    Cylinder { center: na::Point3<f64>, radius: f64, height: f64 },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SceneNode {
+    pub name: String,
+
+    /// Transform from this node's frame to its parent's
+    pub transform: na::Isometry3<f64>,
+    pub shape: Option<Shape>,
+    pub children: Vec<SceneNode>,
+}
+
+impl SceneNode {
+    pub fn new(name: impl Into<String>, transform: na::Isometry3<f64>) -> Self {
+        Self { name: name.into(), transform, shape: None, children: Vec::new() }
+    }
+
+    pub fn with_shape(mut self, shape: Shape) -> Self {
+        self.shape = Some(shape);
+        self
+    }
+
+    pub fn with_child(mut self, child: SceneNode) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// First node named `name`, depth first
+    pub fn find(&self, name: &str) -> Option<&SceneNode> {
+        if self.name == name {
+            return Some(self);
+        }
+        self.children.iter().find_map(|child| child.find(name))
+    }
+
+    /// World transform of every node, depth first, with this node's parent
+    /// at `parent`
+    pub fn world_transforms(&self, parent: &na::Isometry3<f64>) -> Vec<(&str, na::Isometry3<f64>)> {
+        let world = parent * self.transform;
+        let mut transforms = vec![(self.name.as_str(), world)];
+        for child in &self.children {
+            transforms.extend(child.world_transforms(&world));
+        }
+        transforms
+    }
+}
+
+/// Proportions of the parts the crane model doesn't carry
+#[derive(Debug, Clone)]
+pub struct SceneOptions {
+    /// Carrier (or tower base) length along Z, width along X and height
+    pub carrier_length: Length,
+    pub carrier_width: Length,
+    pub carrier_height: Length,
+
+    /// Upper works length, width and height, sitting on the carrier
+    pub superstructure_length: Length,
+    pub superstructure_width: Length,
+    pub superstructure_height: Length,
+
+    /// Width and depth of the base boom section
+    pub boom_width: Length,
+
+    /// Telescoping sections drawn; each is narrower than the one before
+    pub boom_sections: usize,
+
+    pub hook_block_height: Length,
+
+    /// Hook to top of load
+    pub rigging_height: Length,
+
+    /// Load under the hook, if any
+    pub load: Option<LoadDimensions>,
+}
+
+impl Default for SceneOptions {
+    fn default() -> Self {
+        Self {
+            carrier_length: Length::new::<foot>(45.0),
+            carrier_width: Length::new::<foot>(9.0),
+            carrier_height: Length::new::<foot>(8.0),
+            superstructure_length: Length::new::<foot>(20.0),
+            superstructure_width: Length::new::<foot>(9.0),
+            superstructure_height: Length::new::<foot>(6.0),
+            boom_width: Length::new::<foot>(3.0),
+            boom_sections: 4,
+            hook_block_height: Length::new::<foot>(4.0),
+            rigging_height: Length::new::<foot>(10.0),
+            load: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CraneScene {
+    pub root: SceneNode,
+}
+
+impl CraneScene {
+    /// Scene for `crane` in its current state
+    pub fn new<C: Crane>(crane: &C, options: &SceneOptions) -> Self {
+        let fk = crane.forward_kinematics();
+        let joints = crane.joint_config().state::<f64>();
+        let ft = |length: Length| length.get::<foot>();
+        let cuboid =
+            |center: na::Point3<f64>, x: f64, y: f64, z: f64| Shape::Box { center, size: na::Vector3::new(x, y, z) };
+        let translation = |x: f64, y: f64, z: f64| na::Isometry3::translation(x, y, z);
+        let rotation = |r: na::UnitQuaternion<f64>| na::Isometry3::from_parts(na::Translation3::identity(), r);
+
+        let carrier_height = ft(options.carrier_height);
+        let base = fk.base.position;
+        let mut carrier = SceneNode::new("Carrier", translation(base.x, base.y, base.z))
+            .with_shape(cuboid(
+                na::Point3::new(0.0, carrier_height / 2.0, 0.0),
+                ft(options.carrier_width),
+                carrier_height,
+                ft(options.carrier_length),
+            ));
+        for support in crane.support_points() {
+            let radius = (support.contact_area.get::<square_foot>() / std::f64::consts::PI).sqrt();
+            let p = support.position;
+            carrier.children.push(
+                SceneNode::new(support.name, translation(p.x, p.y, p.z))
+                    .with_shape(Shape::Cylinder { center: na::Point3::new(0.0, 0.25, 0.0), radius, height: 0.5 }),
+            );
+        }
+
+        // Boom frame: +Z along the boom, luffed up about X
+        let luff = na::UnitQuaternion::from_axis_angle(&na::Vector3::x_axis(), -joints.boom_angle);
+        let boom_length = joints.boom_length;
+        let sections = options.boom_sections.max(1);
+        let section_length = boom_length / sections as f64;
+        let foot_height = ft(fk.base.pivot_height) - carrier_height;
+        let mut boom = SceneNode::new(
+            "Boom",
+            translation(0.0, foot_height, ft(fk.base.boom_foot_offset)) * rotation(luff),
+        );
+        // Sections chained so each extends from the one below
+        let mut section: Option<SceneNode> = None;
+        for i in (0..sections).rev() {
+            let width = ft(options.boom_width) * (1.0 - 0.1 * i as f64).max(0.3);
+            let offset = if i == 0 { 0.0 } else { section_length };
+            let center = na::Point3::new(0.0, 0.0, section_length / 2.0);
+            let mut node = SceneNode::new(format!("Boom section {}", i + 1), translation(0.0, 0.0, offset))
+                .with_shape(cuboid(center, width, width, section_length));
+            if let Some(child) = section.take() {
+                node.children.push(child);
+            }
+            section = Some(node);
+        }
+        boom.children.extend(section);
+
+        // Jib heading relative to the superstructure, as the forward
+        // kinematics lay it out
+        let mut tip = SceneNode::new("Boom tip", translation(0.0, 0.0, boom_length));
+        let jib = joints.jib.map(|jib| {
+            let total = joints.boom_angle + jib.jib_angle;
+            let direction = rotation_z_radians(jib.jib_offset) * na::Vector3::new(0.0, total.sin(), total.cos());
+            let heading = na::UnitQuaternion::rotation_between(&na::Vector3::z(), &direction)
+                .unwrap_or_else(na::UnitQuaternion::identity);
+            (jib.jib_length, heading)
+        });
+
+        let hoist = joints.hoist.unwrap_or(0.0);
+        let block = ft(options.hook_block_height);
+        let mut hook = SceneNode::new("Hook", translation(0.0, -hoist, 0.0))
+            .with_shape(cuboid(na::Point3::new(0.0, -block / 2.0, 0.0), block / 2.0, block, block / 2.0));
+        if let Some(load) = &options.load {
+            let (length, width, height) = (ft(load.length), ft(load.width), ft(load.height));
+            hook.children.push(
+                SceneNode::new("Load", translation(0.0, -block - ft(options.rigging_height), 0.0))
+                    .with_shape(cuboid(na::Point3::new(0.0, -height / 2.0, 0.0), width, height, length)),
+            );
+        }
+        // Undoes the rotation of the frame it hangs from, relative to the
+        // superstructure, so the rope hangs plumb
+        let hoist_node = |hung_from: na::UnitQuaternion<f64>| {
+            let rope = Shape::Cylinder { center: na::Point3::new(0.0, -hoist / 2.0, 0.0), radius: 0.05, height: hoist };
+            SceneNode::new("Hoist", rotation(hung_from.inverse())).with_shape(rope).with_child(hook.clone())
+        };
+
+        match (joints.trolley, jib) {
+            (Some(trolley), _) => {
+                let trolley = SceneNode::new("Trolley", translation(0.0, 0.0, trolley)).with_child(hoist_node(luff));
+                boom.children.push(trolley);
+            }
+            (None, Some((length, heading))) => {
+                let jib_tip = SceneNode::new("Jib tip", translation(0.0, 0.0, length)).with_child(hoist_node(heading));
+                let jib = SceneNode::new("Jib", rotation(luff.inverse() * heading))
+                    .with_shape(cuboid(na::Point3::new(0.0, 0.0, length / 2.0), 1.5, 1.5, length))
+                    .with_child(jib_tip);
+                tip.children.push(jib);
+            }
+            (None, None) => tip.children.push(hoist_node(luff)),
+        }
+        boom.children.push(tip);
+
+        let (upper_length, upper_height) = (ft(options.superstructure_length), ft(options.superstructure_height));
+        let superstructure = SceneNode::new(
+            "Superstructure",
+            translation(0.0, carrier_height, 0.0)
+                * rotation(na::UnitQuaternion::from_axis_angle(&na::Vector3::y_axis(), joints.swing)),
+        )
+        // Counterweight end behind the slew axis
+        .with_shape(cuboid(
+            na::Point3::new(0.0, upper_height / 2.0, -upper_length / 4.0),
+            ft(options.superstructure_width),
+            upper_height,
+            upper_length,
+        ))
+        .with_child(boom);
+        carrier.children.push(superstructure);
+
+        Self { root: carrier }
+    }
+
+    pub fn find(&self, name: &str) -> Option<&SceneNode> {
+        self.root.find(name)
+    }
+
+    /// World transform of every node, depth first
+    pub fn world_transforms(&self) -> Vec<(&str, na::Isometry3<f64>)> {
+        self.root.world_transforms(&na::Isometry3::identity())
+    }
+
+    /// World position of a node's origin
+    pub fn world_position(&self, name: &str) -> Option<na::Point3<f64>> {
+        self.world_transforms()
+            .into_iter()
+            .find(|(node, _)| *node == name)
+            .map(|(_, transform)| transform * na::Point3::origin())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::equipment::{JibInstallation, Kinematic, MobileCrane};
+    use approx::assert_relative_eq;
+
+    fn assert_points_eq(a: na::Point3<f64>, b: na::Point3<f64>) {
+        assert_relative_eq!(a, b, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_scene_matches_kinematics() {
+        let mut crane = MobileCrane::new("Grove", "GMK5250L", Length::new::<foot>(120.0), Length::new::<foot>(10.0));
+        crane.boom_angle = Angle::new::<degree>(55.0);
+        crane.swing_angle = Angle::new::<degree>(30.0);
+        crane.boom_foot_offset = Length::new::<foot>(4.0);
+        crane.cable_length = Some(Length::new::<foot>(40.0));
+
+        let options = SceneOptions {
+            load: Some(LoadDimensions {
+                length: Length::new::<foot>(10.0),
+                width: Length::new::<foot>(6.0),
+                height: Length::new::<foot>(4.0),
+            }),
+            ..SceneOptions::default()
+        };
+        let scene = CraneScene::new(&crane, &options);
+        let fk = crane.forward_kinematics();
+        let joints = crane.joint_config();
+
+        assert_points_eq(scene.world_position("Boom tip").unwrap(), fk.solve(&joints));
+        assert_points_eq(scene.world_position("Hook").unwrap(), fk.hook_position(&joints));
+        assert_eq!(scene.find("Boom section 4").map(|n| n.children.len()), Some(0));
+        assert!(scene.find("Load").is_some());
+
+        // The hook hangs plumb: the load sits straight below it
+        let hook = scene.world_position("Hook").unwrap();
+        let load = scene.world_position("Load").unwrap();
+        assert_relative_eq!(load.x, hook.x, epsilon = 1e-9);
+        assert_relative_eq!(load.y, hook.y - 14.0, epsilon = 1e-9);
+
+        crane.set_jib(Some(JibInstallation::fixed(Length::new::<foot>(40.0), Angle::new::<degree>(15.0))));
+        let scene = CraneScene::new(&crane, &options);
+        let joints = crane.joint_config();
+        assert_points_eq(scene.world_position("Jib tip").unwrap(), fk.solve(&joints));
+        assert_points_eq(scene.world_position("Hook").unwrap(), fk.hook_position(&joints));
+    }
+}
@@ -0,0 +1,5 @@
+pub mod stepper;
+#[cfg(feature = "monte-carlo")]
+pub mod monte_carlo;
+
+pub use stepper::*;
@@ -0,0 +1,399 @@
+//! Monte Carlo uncertainty analysis for lift margins
+//!
+//! A deterministic lift check uses one value for each input. In practice
+//! the load weight comes from a bill of materials, the center of gravity is
+//! estimated, the radius is measured, and the wind does what it likes.
+//! [`analyze`] samples those inputs, checks capacity (and optionally
+//! stability) for each sample, and reports the probability of exceeding a
+//! limit along with percentile margins for critical-lift review.
+//!
+//! Sampled radii are set on the crane by changing boom angle at the
+//! current boom length, so capacity follows the crane's own charts.
+//!
+//! Results are reproducible for a given seed.
+
+use crate::equipment::{Crane, CraneType};
+use crate::physics::WindAnalysis;
+use crate::simulation::StabilityModel;
+use crate::types::*;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand_distr::{Distribution, Normal, Triangular, Uniform, Weibull};
+
+#[derive(Debug, thiserror::Error)]
+pub enum UncertaintyError {
+    #[error("Invalid distribution for {input}: {reason}")]
+    InvalidDistribution { input: &'static str, reason: String },
+
+    #[error("At least one sample is required")]
+    NoSamples,
+}
+
+/// Distribution of one uncertain input, in the units the input documents
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InputDistribution {
+    Fixed(f64),
+    Uniform { min: f64, max: f64 },
+    Normal { mean: f64, std_dev: f64 },
+    Triangular { min: f64, mode: f64, max: f64 },
+
+    /// The usual model for mean wind speed
+    Weibull { scale: f64, shape: f64 },
+}
+
+/// Sampler built from a validated [`InputDistribution`]
+enum Sampler {
+    Fixed(f64),
+    Uniform(Uniform<f64>),
+    Normal(Normal<f64>),
+    Triangular(Triangular<f64>),
+    Weibull(Weibull<f64>),
+}
+
+impl Sampler {
+    fn new(input: &'static str, distribution: InputDistribution) -> Result<Self, UncertaintyError> {
+        let invalid = |reason: String| UncertaintyError::InvalidDistribution { input, reason };
+
+        Ok(match distribution {
+            InputDistribution::Fixed(value) => Sampler::Fixed(value),
+            InputDistribution::Uniform { min, max } => {
+                Sampler::Uniform(Uniform::new_inclusive(min, max).map_err(|e| invalid(e.to_string()))?)
+            }
+            InputDistribution::Normal { mean, std_dev } => {
+                if std_dev.is_nan() || std_dev < 0.0 {
+                    return Err(invalid(format!("standard deviation must be >= 0, got {}", std_dev)));
+                }
+                Sampler::Normal(Normal::new(mean, std_dev).map_err(|e| invalid(e.to_string()))?)
+            }
+            InputDistribution::Triangular { min, mode, max } => {
+                Sampler::Triangular(Triangular::new(min, max, mode).map_err(|e| invalid(e.to_string()))?)
+            }
+            InputDistribution::Weibull { scale, shape } => {
+                Sampler::Weibull(Weibull::new(scale, shape).map_err(|e| invalid(e.to_string()))?)
+            }
+        })
+    }
+
+    fn sample(&self, rng: &mut StdRng) -> f64 {
+        match self {
+            Sampler::Fixed(value) => *value,
+            Sampler::Uniform(d) => d.sample(rng),
+            Sampler::Normal(d) => d.sample(rng),
+            Sampler::Triangular(d) => d.sample(rng),
+            Sampler::Weibull(d) => d.sample(rng),
+        }
+    }
+}
+
+/// A lift with uncertain inputs
+#[derive(Debug, Clone)]
+pub struct UncertainLift {
+    /// Nominal load, including rigging
+    pub load: Mass,
+
+    /// Fractional load error, e.g. `Uniform { min: -0.1, max: 0.1 }` for ±10%
+    pub load_error: InputDistribution,
+
+    /// Nominal radius
+    pub radius: Length,
+
+    /// Radius measurement error, feet
+    pub radius_error: InputDistribution,
+
+    /// Load center of gravity offset from the hook, feet, positive outward
+    pub cog_offset: InputDistribution,
+
+    /// Wind speed, mph
+    pub wind_speed: InputDistribution,
+
+    pub load_area: Area,
+    pub crane_type: CraneType,
+
+    /// Tipping check; `None` skips stability
+    pub stability: Option<StabilityModel>,
+}
+
+impl UncertainLift {
+    /// Lift with all inputs known exactly and no wind
+    pub fn new(load: Mass, radius: Length, crane_type: CraneType) -> Self {
+        Self {
+            load,
+            load_error: InputDistribution::Fixed(0.0),
+            radius,
+            radius_error: InputDistribution::Fixed(0.0),
+            cog_offset: InputDistribution::Fixed(0.0),
+            wind_speed: InputDistribution::Fixed(0.0),
+            load_area: Area::new::<square_foot>(0.0),
+            crane_type,
+            stability: None,
+        }
+    }
+
+    /// Load weight known to ±`fraction`, uniformly
+    pub fn with_load_tolerance(mut self, fraction: f64) -> Self {
+        self.load_error = InputDistribution::Uniform {
+            min: -fraction,
+            max: fraction,
+        };
+        self
+    }
+
+    /// Normally distributed radius measurement error
+    pub fn with_radius_error(mut self, std_dev: Length) -> Self {
+        self.radius_error = InputDistribution::Normal {
+            mean: 0.0,
+            std_dev: std_dev.get::<foot>(),
+        };
+        self
+    }
+
+    /// Normally distributed center of gravity offset
+    pub fn with_cog_offset(mut self, std_dev: Length) -> Self {
+        self.cog_offset = InputDistribution::Normal {
+            mean: 0.0,
+            std_dev: std_dev.get::<foot>(),
+        };
+        self
+    }
+
+    /// Wind speed distribution (mph) acting on `load_area`
+    pub fn with_wind(mut self, wind_speed: InputDistribution, load_area: Area) -> Self {
+        self.wind_speed = wind_speed;
+        self.load_area = load_area;
+        self
+    }
+
+    pub fn with_stability(mut self, model: StabilityModel) -> Self {
+        self.stability = Some(model);
+        self
+    }
+}
+
+/// Outcome of a Monte Carlo run
+#[derive(Debug, Clone)]
+pub struct UncertaintyReport {
+    pub samples: usize,
+
+    /// Fraction of samples over wind-derated net capacity
+    pub capacity_exceedance: f64,
+
+    /// Fraction of samples under the stability model minimum; 0 without a model
+    pub stability_exceedance: f64,
+
+    /// Fraction of samples exceeding either limit
+    pub any_exceedance: f64,
+
+    /// Sampled utilizations, ascending
+    utilizations: Vec<f64>,
+
+    /// Sampled stability factors, ascending; empty without a model
+    stability_factors: Vec<f64>,
+}
+
+impl UncertaintyReport {
+    /// Utilization not exceeded in `p` percent of samples
+    pub fn utilization_percentile(&self, p: f64) -> f64 {
+        percentile(&self.utilizations, p)
+    }
+
+    /// Capacity margin (1 - utilization) met in `p` percent of samples
+    ///
+    /// `capacity_margin(95.0)` is the margin available in 95% of cases.
+    pub fn capacity_margin(&self, p: f64) -> f64 {
+        1.0 - self.utilization_percentile(p)
+    }
+
+    /// Stability factor met in `p` percent of samples; `None` without a model
+    pub fn stability_factor(&self, p: f64) -> Option<f64> {
+        (!self.stability_factors.is_empty()).then(|| percentile(&self.stability_factors, 100.0 - p))
+    }
+
+    pub fn mean_utilization(&self) -> f64 {
+        self.utilizations.iter().sum::<f64>() / self.utilizations.len() as f64
+    }
+}
+
+/// Linear interpolated percentile of ascending values
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = (p.clamp(0.0, 100.0) / 100.0) * (sorted.len() - 1) as f64;
+    let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+    let (a, b) = (sorted[lower], sorted[upper]);
+    if a.is_infinite() || b.is_infinite() {
+        // Avoid inf - inf when interpolating into unreachable samples
+        return if rank - lower as f64 > 0.5 { b } else { a };
+    }
+    a + (b - a) * (rank - lower as f64)
+}
+
+/// Sample the lift `samples` times
+pub fn analyze<C: Crane + Clone>(
+    crane: &C,
+    lift: &UncertainLift,
+    samples: usize,
+    seed: u64,
+) -> Result<UncertaintyReport, UncertaintyError> {
+    if samples == 0 {
+        return Err(UncertaintyError::NoSamples);
+    }
+
+    let load_error = Sampler::new("load error", lift.load_error)?;
+    let radius_error = Sampler::new("radius error", lift.radius_error)?;
+    let cog_offset = Sampler::new("COG offset", lift.cog_offset)?;
+    let wind_speed = Sampler::new("wind speed", lift.wind_speed)?;
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut crane = crane.clone();
+    let mut joints = crane.joint_config();
+    let base = crane.forward_kinematics().base;
+
+    let mut utilizations = Vec::with_capacity(samples);
+    let mut stability_factors = Vec::new();
+    let (mut over_capacity, mut unstable, mut either) = (0, 0, 0);
+
+    for _ in 0..samples {
+        let load = lift.load * (1.0 + load_error.sample(&mut rng)).max(0.0);
+        let radius = (lift.radius.get::<foot>() + radius_error.sample(&mut rng)).max(0.0);
+        let load_radius = (radius + cog_offset.sample(&mut rng)).max(0.0);
+        let wind = Velocity::new::<mile_per_hour>(wind_speed.sample(&mut rng).max(0.0));
+
+        // Capacity is rated at the hook radius; the load acts at its COG
+        let boom_angle = base.boom_angle_for_radius(Length::new::<foot>(radius), joints.boom_length);
+        let utilization = if let Some(boom_angle) = boom_angle {
+            joints.boom_angle = boom_angle;
+            crane.set_joint_config(joints);
+
+            let derating = WindAnalysis::new(
+                lift.crane_type.clone(),
+                joints.boom_length,
+                joints.boom_angle,
+                lift.load_area,
+                wind,
+            )
+            .derating_factor();
+            let capacity = crane.net_capacity().get::<pound>() * derating;
+            let moment_ratio = if radius > 0.0 { (load_radius / radius).max(1.0) } else { 1.0 };
+
+            if capacity > 0.0 {
+                load.get::<pound>() / capacity * moment_ratio
+            } else {
+                f64::INFINITY
+            }
+        } else {
+            f64::INFINITY
+        };
+        utilizations.push(utilization);
+
+        let exceeds_capacity = utilization > 1.0;
+        let mut exceeds_stability = false;
+        if let Some(model) = &lift.stability {
+            let factor = model.stability_factor(load, Length::new::<foot>(load_radius));
+            exceeds_stability = factor < model.min_factor;
+            stability_factors.push(factor);
+        }
+
+        over_capacity += exceeds_capacity as usize;
+        unstable += exceeds_stability as usize;
+        either += (exceeds_capacity || exceeds_stability) as usize;
+    }
+
+    utilizations.sort_by(f64::total_cmp);
+    stability_factors.sort_by(f64::total_cmp);
+    let fraction = |count: usize| count as f64 / samples as f64;
+
+    Ok(UncertaintyReport {
+        samples,
+        capacity_exceedance: fraction(over_capacity),
+        stability_exceedance: fraction(unstable),
+        any_exceedance: fraction(either),
+        utilizations,
+        stability_factors,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::equipment::MobileCrane;
+    use crate::test_fixtures::mobile_crane;
+    use approx::assert_relative_eq;
+
+    // 10,000 lbs at the nominal 50 ft radius on the fixture chart
+    fn crane() -> MobileCrane {
+        mobile_crane()
+    }
+
+    fn lift(load: f64) -> UncertainLift {
+        UncertainLift::new(Mass::new::<pound>(load), Length::new::<foot>(50.0), CraneType::AllTerrain)
+    }
+
+    #[test]
+    fn test_fixed_inputs() {
+        let report = analyze(&crane(), &lift(8_000.0), 100, 1).unwrap();
+        assert_relative_eq!(report.capacity_exceedance, 0.0);
+        assert_relative_eq!(report.utilization_percentile(50.0), 0.8, epsilon = 1e-9);
+        assert_relative_eq!(report.capacity_margin(95.0), 0.2, epsilon = 1e-9);
+        assert!(report.stability_factor(95.0).is_none());
+    }
+
+    #[test]
+    fn test_load_tolerance() {
+        // 9,500 ± 10%: over 10,000 in 450 / 1,900 of the range
+        let lift = lift(9_500.0).with_load_tolerance(0.1);
+        let report = analyze(&crane(), &lift, 20_000, 42).unwrap();
+
+        assert_relative_eq!(report.capacity_exceedance, 450.0 / 1_900.0, epsilon = 0.02);
+        assert_relative_eq!(report.utilization_percentile(100.0), 1.045, epsilon = 0.01);
+        assert!(report.capacity_margin(95.0) < 0.0);
+
+        // Same seed, same answer
+        let again = analyze(&crane(), &lift, 20_000, 42).unwrap();
+        assert_eq!(report.capacity_exceedance, again.capacity_exceedance);
+    }
+
+    #[test]
+    fn test_wind_and_stability() {
+        let model = StabilityModel {
+            machine_weight: Mass::new::<pound>(30_000.0),
+            cog_behind_center: Length::new::<foot>(5.0),
+            tipping_radius: Length::new::<foot>(15.0),
+            min_factor: 1.5,
+        };
+        // Nominal factor 30,000 * 20 / (8,000 * 35) = 2.14
+        let lift = lift(8_000.0)
+            .with_cog_offset(Length::new::<foot>(5.0))
+            .with_radius_error(Length::new::<foot>(2.0))
+            .with_stability(model);
+        let calm = analyze(&crane(), &lift, 5_000, 7).unwrap();
+        assert!(calm.stability_exceedance > 0.0);
+        assert!(calm.stability_factor(95.0).unwrap() < calm.stability_factor(50.0).unwrap());
+        assert_relative_eq!(calm.stability_factor(50.0).unwrap(), 600_000.0 / 280_000.0, epsilon = 0.05);
+
+        // Caution-range wind derates capacity and raises the exceedance rate
+        let windy = analyze(
+            &crane(),
+            &lift.clone().with_wind(
+                InputDistribution::Weibull { scale: 22.0, shape: 2.0 },
+                Area::new::<square_foot>(100.0),
+            ),
+            5_000,
+            7,
+        )
+        .unwrap();
+        assert!(windy.capacity_exceedance > calm.capacity_exceedance);
+        assert!(windy.any_exceedance >= windy.stability_exceedance);
+    }
+
+    #[test]
+    fn test_invalid_distribution() {
+        let mut lift = lift(8_000.0);
+        lift.radius_error = InputDistribution::Normal {
+            mean: 0.0,
+            std_dev: -1.0,
+        };
+        assert!(matches!(
+            analyze(&crane(), &lift, 10, 0),
+            Err(UncertaintyError::InvalidDistribution { input: "radius error", .. })
+        ));
+        assert!(matches!(analyze(&crane(), &lift, 0, 0), Err(UncertaintyError::NoSamples)));
+    }
+}
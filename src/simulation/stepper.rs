@@ -0,0 +1,650 @@
+//! Fixed-step lift simulation
+//!
+//! Each tick the stepper:
+//!
+//! 1. Integrates the commanded joint velocities, clamping to the joint
+//!    limits and anti-two-block as the crane's limit switches would
+//! 2. Moves the crane and solves forward kinematics for the boom tip
+//! 3. Integrates the load pendulum, driven by the tip's acceleration
+//! 4. Evaluates capacity, stability and limits for the new state
+//!
+//! # Load swing
+//!
+//! The load hangs `hoist` below the tip as two planar pendulums, one in
+//! each horizontal axis (X and Z). Each obeys
+//!
+//! ```text
+//! φ'' = -(g sin φ + a cos φ + 2 L' φ') / L - c φ'
+//! ```
+//!
+//! where `a` is the tip acceleration along that axis, `L` the rope length,
+//! `L'` the hoist rate and `c` a linear damping coefficient. Decoupling
+//! the axes is accurate for the small swing angles of normal operation.
+//! Without a tracked hoist the load is taken rigidly at the tip.
+//!
+//! # Resonance
+//!
+//! Each reversal of the slew or hoist command is timed against the last
+//! one; two reversals make a cycle. A slew cycle near the load's pendulum
+//! frequency, or a hoist cycle near the rope's axial frequency, raises a
+//! [`SimulationAlert::Resonance`] on the tick that completes it.
+//!
+//! Internal units are feet, pounds, radians and seconds.
+
+use crate::equipment::Crane;
+use crate::kinematics::{JointConfig, JointLimits};
+use crate::physics::{is_resonant, pendulum_frequency, rope_axial_frequency, rope_stiffness};
+use crate::types::*;
+
+/// Gravity, ft/s²
+const G: f64 = 32.174;
+
+/// Commanded joint rates
+#[derive(Debug, Clone, Copy)]
+pub struct JointVelocity {
+    pub swing: AngularVelocity,
+    pub boom_angle: AngularVelocity,
+    pub boom_length: Velocity,
+
+    /// Rope pay-out rate; positive lowers the hook
+    pub hoist: Velocity,
+}
+
+impl JointVelocity {
+    /// All joints stationary
+    pub fn zero() -> Self {
+        Self {
+            swing: AngularVelocity::new::<radian_per_second>(0.0),
+            boom_angle: AngularVelocity::new::<radian_per_second>(0.0),
+            boom_length: Velocity::new::<foot_per_second>(0.0),
+            hoist: Velocity::new::<foot_per_second>(0.0),
+        }
+    }
+
+    pub fn swing(rate: AngularVelocity) -> Self {
+        Self { swing: rate, ..Self::zero() }
+    }
+
+    pub fn boom(rate: AngularVelocity) -> Self {
+        Self {
+            boom_angle: rate,
+            ..Self::zero()
+        }
+    }
+
+    pub fn telescope(rate: Velocity) -> Self {
+        Self {
+            boom_length: rate,
+            ..Self::zero()
+        }
+    }
+
+    pub fn hoist(rate: Velocity) -> Self {
+        Self { hoist: rate, ..Self::zero() }
+    }
+}
+
+impl Default for JointVelocity {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+/// Simplified tipping check about the front tipping line
+///
+/// Treats the crane as a rigid machine weight with the tipping line
+/// perpendicular to the boom, which holds for the over-the-side or
+/// over-the-front working areas a chart is rated for.
+#[derive(Debug, Clone, Copy)]
+pub struct StabilityModel {
+    /// Crane weight without the load, including counterweight
+    pub machine_weight: Mass,
+
+    /// Distance of the machine center of gravity behind the slew center
+    pub cog_behind_center: Length,
+
+    /// Distance from the slew center to the tipping line (outrigger or track edge)
+    pub tipping_radius: Length,
+
+    /// Stability factor below which the configuration is flagged
+    pub min_factor: f64,
+}
+
+impl StabilityModel {
+    /// Restoring moment / overturning moment for a load at `load_radius`
+    pub fn stability_factor(&self, load: Mass, load_radius: Length) -> f64 {
+        let arm = (load_radius - self.tipping_radius).get::<foot>();
+        let overturning = load.get::<pound>() * arm;
+        if overturning <= 0.0 {
+            return f64::INFINITY;
+        }
+        let restoring =
+            self.machine_weight.get::<pound>() * (self.tipping_radius + self.cog_behind_center).get::<foot>();
+        restoring / overturning
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SimulationConfig {
+    /// Integration time step
+    pub dt: Time,
+
+    pub limits: JointLimits,
+
+    /// Pendulum damping coefficient, 1/s
+    pub damping: f64,
+
+    /// Tipping check; `None` skips stability
+    pub stability: Option<StabilityModel>,
+
+    /// Axial stiffness of the reeved hoist rope, parts of line × EA; `None`
+    /// skips the hoist resonance check
+    pub hoist_rope_stiffness: Option<Force>,
+}
+
+impl Default for SimulationConfig {
+    /// 20 Hz, default joint limits, light damping, no stability model
+    fn default() -> Self {
+        Self {
+            dt: Time::new::<second>(0.05),
+            limits: JointLimits::default(),
+            damping: 0.02,
+            stability: None,
+            hoist_rope_stiffness: None,
+        }
+    }
+}
+
+/// Something a tick flagged
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SimulationAlert {
+    /// Load over net capacity, at the swung-out load radius
+    OverCapacity { utilization: f64 },
+
+    /// Stability factor below the model minimum
+    Unstable { factor: f64 },
+
+    /// A joint command was stopped at a joint limit
+    JointLimit,
+
+    /// Hoist stopped by the anti-two-block
+    TwoBlock,
+
+    /// Command cycle rate near a natural frequency, both in Hz
+    Resonance { mode: ResonanceMode, excitation: f64, natural: f64 },
+}
+
+/// Which natural motion a command cycle excites
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResonanceMode {
+    /// Load swinging under the tip, excited by slewing back and forth
+    Pendulum,
+
+    /// Load bouncing on the hoist rope, excited by hoisting up and down
+    RopeAxial,
+}
+
+/// Natural frequencies of the hanging load, Hz
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NaturalFrequencies {
+    /// `None` without a tracked hoist
+    pub pendulum: Option<f64>,
+
+    /// `None` without a tracked hoist or rope stiffness
+    pub rope_axial: Option<f64>,
+}
+
+/// Crane and load state after one tick
+#[derive(Debug, Clone)]
+pub struct SimulationFrame {
+    pub time: Time,
+    pub joints: JointConfig,
+
+    /// Boom (or jib) tip, feet
+    pub tip: na::Point3<f64>,
+
+    /// Load (hook) position including swing, feet
+    pub load_position: na::Point3<f64>,
+
+    /// Load deflection from vertical
+    pub swing_angle: Angle,
+
+    /// Horizontal distance from slew center to the swinging load
+    pub load_radius: Length,
+
+    pub capacity: Mass,
+
+    /// Load / net capacity, scaled by load radius over tip radius to account
+    /// for the swing
+    pub utilization: f64,
+
+    /// `None` without a stability model
+    pub stability_factor: Option<f64>,
+
+    pub alerts: Vec<SimulationAlert>,
+}
+
+impl SimulationFrame {
+    pub fn is_clean(&self) -> bool {
+        self.alerts.is_empty()
+    }
+}
+
+/// Every frame of a run, for playback or automated checks
+#[derive(Debug, Clone, Default)]
+pub struct SimulationTrace {
+    pub frames: Vec<SimulationFrame>,
+}
+
+impl SimulationTrace {
+    pub fn duration(&self) -> Time {
+        self.frames.last().map_or(Time::new::<second>(0.0), |f| f.time)
+    }
+
+    /// No frame raised an alert
+    pub fn is_clean(&self) -> bool {
+        self.frames.iter().all(|f| f.is_clean())
+    }
+
+    /// First frame that raised an alert
+    pub fn first_alert(&self) -> Option<&SimulationFrame> {
+        self.frames.iter().find(|f| !f.is_clean())
+    }
+
+    pub fn max_utilization(&self) -> f64 {
+        self.frames.iter().map(|f| f.utilization).fold(0.0, f64::max)
+    }
+
+    pub fn max_swing_angle(&self) -> Angle {
+        Angle::new::<radian>(
+            self.frames
+                .iter()
+                .map(|f| f.swing_angle.get::<radian>())
+                .fold(0.0, f64::max),
+        )
+    }
+
+    /// `None` without a stability model
+    pub fn min_stability_factor(&self) -> Option<f64> {
+        self.frames
+            .iter()
+            .filter_map(|f| f.stability_factor)
+            .reduce(f64::min)
+    }
+}
+
+/// Direction reversals of one commanded joint
+#[derive(Debug, Clone, Copy, Default)]
+struct CommandCycle {
+    /// Sign of the last non-zero command
+    direction: f64,
+
+    /// Time of the last reversal, seconds
+    last_reversal: Option<f64>,
+}
+
+impl CommandCycle {
+    /// Cycle rate in Hz if `rate` reverses the command and an earlier
+    /// reversal gives the half period
+    fn update(&mut self, rate: f64, time: f64) -> Option<f64> {
+        if rate == 0.0 {
+            return None;
+        }
+        let direction = rate.signum();
+        let previous = std::mem::replace(&mut self.direction, direction);
+        if previous == 0.0 || previous == direction {
+            return None;
+        }
+        let half_period = self.last_reversal.replace(time).map(|last| time - last)?;
+        (half_period > 0.0).then(|| 1.0 / (2.0 * half_period))
+    }
+}
+
+/// Load pendulum state, per horizontal axis (X, Z)
+#[derive(Debug, Clone, Copy, Default)]
+struct Pendulum {
+    angle: [f64; 2],
+    rate: [f64; 2],
+}
+
+/// Steps a crane and its load through commanded motions
+#[derive(Debug, Clone)]
+pub struct LiftSimulator<C: Crane> {
+    crane: C,
+    load: Mass,
+    config: SimulationConfig,
+
+    time: Time,
+    pendulum: Pendulum,
+    tip: na::Point3<f64>,
+    tip_velocity: na::Vector3<f64>,
+    slew_cycle: CommandCycle,
+    hoist_cycle: CommandCycle,
+}
+
+impl<C: Crane> LiftSimulator<C> {
+    /// Start at the crane's current configuration, load hanging still
+    pub fn new(crane: C, load: Mass, config: SimulationConfig) -> Self {
+        let tip = crane.forward_kinematics().solve(&crane.joint_config());
+        Self {
+            crane,
+            load,
+            config,
+            time: Time::new::<second>(0.0),
+            pendulum: Pendulum::default(),
+            tip,
+            tip_velocity: na::Vector3::zeros(),
+            slew_cycle: CommandCycle::default(),
+            hoist_cycle: CommandCycle::default(),
+        }
+    }
+
+    /// Pendulum and rope bounce frequencies at the current hoist length
+    pub fn natural_frequencies(&self) -> NaturalFrequencies {
+        let hoist = self.crane.joint_config().hoist;
+        NaturalFrequencies {
+            pendulum: hoist.map(pendulum_frequency),
+            rope_axial: hoist.zip(self.config.hoist_rope_stiffness).and_then(|(length, stiffness)| {
+                rope_stiffness(stiffness, length, 1).ok().map(|k| rope_axial_frequency(self.load, k))
+            }),
+        }
+    }
+
+    pub fn crane(&self) -> &C {
+        &self.crane
+    }
+
+    pub fn into_crane(self) -> C {
+        self.crane
+    }
+
+    pub fn time(&self) -> Time {
+        self.time
+    }
+
+    pub fn config(&self) -> &SimulationConfig {
+        &self.config
+    }
+
+    /// Advance one time step under a commanded joint velocity
+    pub fn step(&mut self, command: &JointVelocity) -> SimulationFrame {
+        let dt = self.config.dt.get::<second>();
+        let mut alerts = Vec::new();
+
+        // 1. Joints
+        let mut joints = self.crane.joint_config();
+        joints.swing += Angle::new::<radian>(command.swing.get::<radian_per_second>() * dt);
+        joints.boom_angle += Angle::new::<radian>(command.boom_angle.get::<radian_per_second>() * dt);
+        joints.boom_length += Length::new::<foot>(command.boom_length.get::<foot_per_second>() * dt);
+        joints.hoist = joints
+            .hoist
+            .map(|h| h + Length::new::<foot>(command.hoist.get::<foot_per_second>() * dt));
+
+        let requested = joints;
+        if self.config.limits.clamp(&mut joints) {
+            if joints.hoist != requested.hoist {
+                alerts.push(SimulationAlert::TwoBlock);
+            }
+            if joints.swing != requested.swing
+                || joints.boom_angle != requested.boom_angle
+                || joints.boom_length != requested.boom_length
+            {
+                alerts.push(SimulationAlert::JointLimit);
+            }
+        }
+        let hoist_rate = match (joints.hoist, self.crane.joint_config().hoist) {
+            (Some(new), Some(old)) => (new - old).get::<foot>() / dt,
+            _ => 0.0,
+        };
+
+        // 2. Kinematics
+        self.crane.set_joint_config(joints);
+        let fk = self.crane.forward_kinematics();
+        let tip = fk.solve(&joints);
+        let tip_velocity = (tip - self.tip) / dt;
+        let tip_acceleration = (tip_velocity - self.tip_velocity) / dt;
+        self.tip = tip;
+        self.tip_velocity = tip_velocity;
+
+        // 3. Load swing
+        let load_position = match joints.hoist {
+            Some(hoist) => {
+                let length = hoist.get::<foot>().max(1e-3);
+                let acceleration = [tip_acceleration.x, tip_acceleration.z];
+                let pendulum = &mut self.pendulum;
+                for ((phi, rate), a) in pendulum.angle.iter_mut().zip(&mut pendulum.rate).zip(acceleration) {
+                    let angular = -(G * phi.sin() + a * phi.cos() + 2.0 * hoist_rate * *rate) / length
+                        - self.config.damping * *rate;
+                    *rate += angular * dt;
+                    *phi += *rate * dt;
+                }
+                let (sx, sz) = (self.pendulum.angle[0].sin(), self.pendulum.angle[1].sin());
+                let vertical = (1.0 - sx * sx - sz * sz).max(0.0).sqrt();
+                na::Point3::new(tip.x + length * sx, tip.y - length * vertical, tip.z + length * sz)
+            }
+            None => tip,
+        };
+        let swing_angle = {
+            let (sx, sz) = (self.pendulum.angle[0].sin(), self.pendulum.angle[1].sin());
+            Angle::new::<radian>((sx * sx + sz * sz).sqrt().min(1.0).asin())
+        };
+
+        // 4. Checks
+        let base = fk.base.position;
+        let radius_of = |p: na::Point3<f64>| ((p.x - base.x).powi(2) + (p.z - base.z).powi(2)).sqrt();
+        let tip_radius = radius_of(tip);
+        let load_radius = radius_of(load_position);
+
+        let capacity = self.crane.net_capacity();
+        let utilization = if capacity.get::<pound>() > 0.0 {
+            // Capacity is rated at the tip radius; the load acts at its own
+            let radius_ratio = if tip_radius > 0.0 { load_radius / tip_radius } else { 1.0 };
+            self.load.get::<pound>() / capacity.get::<pound>() * radius_ratio.max(1.0)
+        } else {
+            f64::INFINITY
+        };
+        if utilization > 1.0 {
+            alerts.push(SimulationAlert::OverCapacity { utilization });
+        }
+
+        let stability_factor = self.config.stability.map(|model| {
+            let factor = model.stability_factor(self.load, Length::new::<foot>(load_radius));
+            if factor < model.min_factor {
+                alerts.push(SimulationAlert::Unstable { factor });
+            }
+            factor
+        });
+
+        // 5. Resonance
+        let natural = self.natural_frequencies();
+        let now = self.time.get::<second>();
+        let slew = self.slew_cycle.update(command.swing.get::<radian_per_second>(), now);
+        let hoist = self.hoist_cycle.update(command.hoist.get::<foot_per_second>(), now);
+        let cycles = [
+            (slew, natural.pendulum, ResonanceMode::Pendulum),
+            (hoist, natural.rope_axial, ResonanceMode::RopeAxial),
+        ];
+        for (excitation, natural, mode) in cycles {
+            if let (Some(excitation), Some(natural)) = (excitation, natural)
+                && is_resonant(excitation, natural)
+            {
+                alerts.push(SimulationAlert::Resonance { mode, excitation, natural });
+            }
+        }
+
+        self.time += self.config.dt;
+
+        SimulationFrame {
+            time: self.time,
+            joints,
+            tip,
+            load_position,
+            swing_angle,
+            load_radius: Length::new::<foot>(load_radius),
+            capacity,
+            utilization,
+            stability_factor,
+            alerts,
+        }
+    }
+
+    /// Step for `duration`, asking `command` for the joint velocity at each tick
+    pub fn run(
+        &mut self,
+        duration: Time,
+        mut command: impl FnMut(Time, &JointConfig) -> JointVelocity,
+    ) -> SimulationTrace {
+        let steps = (duration.get::<second>() / self.config.dt.get::<second>()).round() as usize;
+        let mut trace = SimulationTrace::default();
+        for _ in 0..steps {
+            let velocity = command(self.time, &self.crane.joint_config());
+            trace.frames.push(self.step(&velocity));
+        }
+        trace
+    }
+
+    /// Run a sequence of constant-velocity segments
+    pub fn run_segments(&mut self, segments: &[(Time, JointVelocity)]) -> SimulationTrace {
+        let mut trace = SimulationTrace::default();
+        for (duration, velocity) in segments {
+            trace.frames.extend(self.run(*duration, |_, _| *velocity).frames);
+        }
+        trace
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::equipment::{Kinematic, MobileCrane};
+    use crate::test_fixtures::mobile_crane;
+    use approx::assert_relative_eq;
+
+    // 100 ft boom at 60°: 10,000 lbs at 50 ft on the fixture chart
+    fn simulator(load: f64, config: SimulationConfig) -> LiftSimulator<MobileCrane> {
+        let mut crane = mobile_crane();
+        let mut joints = crane.joint_config();
+        joints.hoist = Some(Length::new::<foot>(40.0));
+        crane.set_joint_config(joints);
+        LiftSimulator::new(crane, Mass::new::<pound>(load), config)
+    }
+
+    fn stability_model() -> StabilityModel {
+        StabilityModel {
+            machine_weight: Mass::new::<pound>(100_000.0),
+            cog_behind_center: Length::new::<foot>(5.0),
+            tipping_radius: Length::new::<foot>(15.0),
+            min_factor: 1.5,
+        }
+    }
+
+    fn seconds(value: f64) -> Time {
+        Time::new::<second>(value)
+    }
+
+    #[test]
+    fn test_static_hang() {
+        let mut sim = simulator(5_000.0, SimulationConfig::default());
+        let trace = sim.run(seconds(2.0), |_, _| JointVelocity::zero());
+
+        assert_eq!(trace.frames.len(), 40);
+        assert_relative_eq!(trace.duration().get::<second>(), 2.0, epsilon = 1e-9);
+        assert!(trace.is_clean());
+
+        let frame = trace.frames.last().unwrap();
+        assert_relative_eq!(frame.swing_angle.get::<degree>(), 0.0, epsilon = 1e-9);
+        assert_relative_eq!(frame.tip.y - frame.load_position.y, 40.0, epsilon = 1e-9);
+        assert_relative_eq!(frame.utilization, 0.5, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_slew_swings_load() {
+        let mut sim = simulator(5_000.0, SimulationConfig::default());
+        let trace = sim.run_segments(&[
+            (seconds(2.0), JointVelocity::swing(AngularVelocity::new::<degree_per_second>(10.0))),
+            (seconds(4.0), JointVelocity::zero()),
+        ]);
+
+        assert_relative_eq!(
+            trace.frames.last().unwrap().joints.swing.get::<degree>(),
+            20.0,
+            epsilon = 1e-6
+        );
+        // Starting and stopping the slew sets the load swinging
+        assert!(trace.max_swing_angle().get::<degree>() > 0.5);
+        assert!(trace.frames.iter().any(|f| f.load_radius.get::<foot>() > 50.0 + 0.1));
+    }
+
+    #[test]
+    fn test_boom_limit_and_two_block() {
+        let mut sim = simulator(1_000.0, SimulationConfig::default());
+        let trace = sim.run(seconds(1.0), |_, _| JointVelocity {
+            boom_angle: AngularVelocity::new::<degree_per_second>(40.0),
+            hoist: Velocity::new::<foot_per_second>(-50.0),
+            ..JointVelocity::zero()
+        });
+
+        let last = trace.frames.last().unwrap();
+        assert_relative_eq!(last.joints.boom_angle.get::<degree>(), 85.0, epsilon = 1e-9);
+        assert_relative_eq!(last.joints.hoist.unwrap().get::<foot>(), 2.0, epsilon = 1e-9);
+        assert!(last.alerts.contains(&SimulationAlert::JointLimit));
+        assert!(last.alerts.contains(&SimulationAlert::TwoBlock));
+    }
+
+    #[test]
+    fn test_capacity_and_stability_alerts() {
+        let config = SimulationConfig {
+            stability: Some(stability_model()),
+            ..SimulationConfig::default()
+        };
+        // Boom down from 60° to 30°: radius 50 -> 86.6 ft, where the chart
+        // rates 3,840 lbs
+        let mut sim = simulator(3_500.0, config);
+        let trace = sim.run(seconds(3.0), |_, _| {
+            JointVelocity::boom(AngularVelocity::new::<degree_per_second>(-10.0))
+        });
+
+        // 100,000 * 20 / (3,500 * 35) = 16.3 at 50 ft
+        assert_relative_eq!(trace.frames[0].stability_factor.unwrap(), 2_000_000.0 / (3_500.0 * 35.0), epsilon = 0.05);
+        // 100,000 * 20 / (3,500 * 71.6) = 8.0 at 86.6 ft: still stable
+        assert!(trace.min_stability_factor().unwrap() > 1.5);
+        assert!(trace.max_utilization() > 0.85);
+        assert!(trace.first_alert().is_none());
+
+        // Light machine, overloaded: 20,000 * 20 / (12,000 * 35) = 0.95
+        let heavy = SimulationConfig {
+            stability: Some(StabilityModel {
+                machine_weight: Mass::new::<pound>(20_000.0),
+                ..stability_model()
+            }),
+            ..SimulationConfig::default()
+        };
+        let mut sim = simulator(12_000.0, heavy);
+        let frame = sim.step(&JointVelocity::zero());
+        assert!(frame.alerts.iter().any(|a| matches!(a, SimulationAlert::OverCapacity { .. })));
+        assert!(frame.alerts.iter().any(|a| matches!(a, SimulationAlert::Unstable { .. })));
+    }
+
+    #[test]
+    fn test_slew_reversals_at_pendulum_frequency() {
+        // 40 ft of rope swings at 1 / 7.0 s; reverse every half period
+        let half = 0.5 / pendulum_frequency(Length::new::<foot>(40.0));
+        let rate = AngularVelocity::new::<degree_per_second>(2.0);
+        let cycle = |half: f64| {
+            [1.0, -1.0, 1.0, -1.0].map(|sign| (seconds(half), JointVelocity::swing(rate * sign)))
+        };
+
+        let mut sim = simulator(1_000.0, SimulationConfig::default());
+        assert_relative_eq!(sim.natural_frequencies().pendulum.unwrap(), 1.0 / (2.0 * half), max_relative = 1e-9);
+        assert!(sim.natural_frequencies().rope_axial.is_none());
+        let trace = sim.run_segments(&cycle(half));
+        assert!(trace.frames.iter().flat_map(|f| &f.alerts).any(|a| matches!(
+            a,
+            SimulationAlert::Resonance { mode: ResonanceMode::Pendulum, .. }
+        )));
+
+        let mut sim = simulator(1_000.0, SimulationConfig::default());
+        let trace = sim.run_segments(&cycle(1.0));
+        assert!(trace.is_clean());
+    }
+}
@@ -0,0 +1,101 @@
+//! Fixtures shared by the unit tests
+//!
+//! One chart-rated mobile crane and one lift plan, so tests across modules
+//! rate against chart data rather than each pasting its own setup.
+
+use crate::capacity::chart_builder::LoadChartBuilder;
+use crate::capacity::lift_validation::*;
+use crate::capacity::load_chart::{CraneInfo, LoadChartPackage};
+use crate::equipment::{CraneType, DutyClass, MobileCrane};
+use crate::physics::{SoilConditions, TemperatureRules};
+use crate::types::*;
+
+/// 100 ft main boom on full outriggers, radius (ft) against capacity (lbs)
+pub(crate) const BOOM_100_FT: [(f64, f64); 8] = [
+    (20.0, 30_000.0),
+    (30.0, 20_000.0),
+    (40.0, 14_000.0),
+    (50.0, 10_000.0),
+    (60.0, 7_500.0),
+    (70.0, 5_800.0),
+    (80.0, 4_500.0),
+    (90.0, 3_500.0),
+];
+
+/// 130 ft main boom on full outriggers
+pub(crate) const BOOM_130_FT: [(f64, f64); 8] = [
+    (30.0, 16_000.0),
+    (40.0, 11_500.0),
+    (50.0, 8_500.0),
+    (60.0, 6_500.0),
+    (70.0, 5_000.0),
+    (80.0, 4_000.0),
+    (100.0, 2_600.0),
+    (120.0, 1_700.0),
+];
+
+/// Chart package with a chart for each fixture boom length
+pub(crate) fn chart_package() -> LoadChartPackage {
+    let mut package = LoadChartPackage::new(CraneInfo {
+        manufacturer: "Grove".into(),
+        model: "GMK5250L".into(),
+        serial_number: None,
+        crane_type: CraneType::AllTerrain,
+        year: Some(2020),
+        chart_revision: None,
+    });
+    for (id, boom, points) in [("main_100", 100.0, &BOOM_100_FT), ("main_130", 130.0, &BOOM_130_FT)] {
+        let chart = LoadChartBuilder::new(id).outriggers_full().boom(boom, "ft").points(points).build();
+        package.add_chart(chart.expect("fixture chart is valid"));
+    }
+    package
+}
+
+/// 100 ft boom at 60° on full outriggers: 50 ft radius, 10,000 lbs on the chart
+pub(crate) fn mobile_crane() -> MobileCrane {
+    let mut crane = MobileCrane::new("Grove", "GMK5250L", Length::new::<foot>(100.0), Length::new::<foot>(10.0));
+    crane.boom_angle = Angle::new::<degree>(60.0);
+    crane.set_load_charts(chart_package());
+    crane
+}
+
+/// Vertical hitch through one 50,000 lb shackle, paved ground, 5 mph wind
+pub(crate) fn lift_plan(load: f64) -> LiftPlan {
+    LiftPlan {
+        load_weight: Mass::new::<pound>(load),
+        load_dimensions: LoadDimensions {
+            length: Length::new::<foot>(10.0),
+            width: Length::new::<foot>(5.0),
+            height: Length::new::<foot>(5.0),
+        },
+        rigging: RiggingConfiguration {
+            configuration: RiggingConfig::Vertical,
+            hardware: vec![RiggingHardware {
+                item_type: "Shackle".into(),
+                kind: RiggingKind::Hardware,
+                capacity: Mass::new::<pound>(50_000.0),
+                description: "17t shackle".into(),
+                weight: Mass::new::<pound>(0.0),
+            }],
+        },
+        ground: GroundConditions {
+            soil_type: SoilType::Paved,
+            mat_area: Area::new::<square_foot>(16.0),
+            conditions: SoilConditions::default(),
+            notes: String::new(),
+        },
+        environment: EnvironmentalConditions {
+            wind_speed: Velocity::new::<mile_per_hour>(5.0),
+            temperature: ThermodynamicTemperature::new::<degree_fahrenheit>(60.0),
+            temperature_rules: TemperatureRules::default(),
+            visibility: "Good".into(),
+            notes: String::new(),
+            offshore: None,
+        },
+        safety_factors: SafetyFactors::default(),
+        headroom: None,
+        work_area: None,
+        side_load: None,
+        duty: DutyClass::Lifting,
+    }
+}
@@ -1,4 +1,8 @@
-use uom::si::{f64::Length, length::foot};
+use uom::si::{
+    acceleration::standard_gravity,
+    f64::{Acceleration, Force, Length, Mass},
+    length::foot,
+};
 use nalgebra as na;
 
 
@@ -36,3 +40,30 @@ pub fn y_uom_length(point: &na::Point3<f64>) -> Length {
 pub fn z_uom_length(point: &na::Point3<f64>) -> Length {
     from_coord(point.z)
 }
+
+/// Weight of a mass at standard gravity (1 lbm weighs 1 lbf)
+#[inline]
+pub fn weight_of(mass: Mass) -> Force {
+    mass * Acceleration::new::<standard_gravity>(1.0)
+}
+
+/// Mass whose weight at standard gravity is `force` (1 lbf lifts 1 lbm)
+#[inline]
+pub fn mass_from_weight(force: Force) -> Mass {
+    force / Acceleration::new::<standard_gravity>(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use uom::si::{force::pound_force, mass::{kilogram, pound}, force::newton};
+
+    #[test]
+    fn test_weight_mass_round_trip() {
+        let weight = weight_of(Mass::new::<pound>(10_000.0));
+        assert_relative_eq!(weight.get::<pound_force>(), 10_000.0, max_relative = 1e-6);
+        assert_relative_eq!(weight_of(Mass::new::<kilogram>(1.0)).get::<newton>(), 9.80665, epsilon = 1e-9);
+        assert_relative_eq!(mass_from_weight(weight).get::<pound>(), 10_000.0, max_relative = 1e-6);
+    }
+}
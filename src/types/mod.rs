@@ -1,12 +1,13 @@
-pub use uom::si::f64::*;
 pub use uom::si::{length, mass, angle, force, pressure, area};
 
 mod units;
 mod conversion;
+mod scalar;
 // Re-export nalgebra
 pub use nalgebra as na;
 pub use units::*;
 pub use conversion::*;
+pub use scalar::*;
 
 // Standard units we use internally (just documentation)
 /// Internal standard: feet
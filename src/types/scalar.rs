@@ -0,0 +1,15 @@
+use nalgebra as na;
+
+/// Float type the forward kinematics and statics core can run in
+///
+/// Implemented for `f64`, used everywhere else in the crate, and `f32` for
+/// embedded controllers and real-time visualization.
+pub trait Real: na::RealField + Copy {}
+
+impl<T: na::RealField + Copy> Real for T {}
+
+/// An `f64` constant or coordinate in the scalar type `T`
+#[inline]
+pub fn real<T: Real>(value: f64) -> T {
+    na::convert(value)
+}
@@ -1,5 +1,5 @@
 use std::fmt;
-use uom::si::{angle::degree, force::{newton, pound_force}, length::{inch, meter}, mass::{kilogram, pound}, pressure::{bar, kilopascal, psi}, velocity::{kilometer_per_hour, mile_per_hour}};
+use uom::si::{angle::degree, force::{kilonewton, newton, pound_force}, length::{inch, meter, millimeter}, mass::{kilogram, megagram, pound}, pressure::{bar, kilopascal, psi}, velocity::{kilometer_per_hour, mile_per_hour}};
 
 use crate::types::units::*;
 #[derive(Debug)]
@@ -16,64 +16,239 @@ pub struct DisplayHydraulicPressure(pub Pressure);
 pub struct DisplayGroundBearingPressure(pub Pressure);
 #[derive(Debug)]
 pub struct DisplayVelocity(pub Velocity);
+/// Hardware and rope sizes in fractional inches, e.g. `1-1/4"`
+#[derive(Debug)]
+pub struct DisplayFractionalInches(pub Length);
+
+/// Finest fraction used for hardware sizes
+pub const INCH_FRACTION_DENOMINATOR: u32 = 16;
+
+/// Inches as a reduced fraction to the nearest 1/16, without the inch mark
+///
+/// 0.625 in gives "5/8", 1.25 in gives "1-1/4" and 2 in gives "2".
+pub fn inch_fraction(length: Length) -> String {
+    let sixteenths = (length.get::<inch>() * INCH_FRACTION_DENOMINATOR as f64).round() as i64;
+    let sign = if sixteenths < 0 { "-" } else { "" };
+    let sixteenths = sixteenths.unsigned_abs() as u32;
+    let whole = sixteenths / INCH_FRACTION_DENOMINATOR;
+    let mut numerator = sixteenths % INCH_FRACTION_DENOMINATOR;
+    let mut denominator = INCH_FRACTION_DENOMINATOR;
+    while numerator > 0 && numerator.is_multiple_of(2) {
+        numerator /= 2;
+        denominator /= 2;
+    }
+
+    match (whole, numerator) {
+        (_, 0) => format!("{sign}{whole}"),
+        (0, _) => format!("{sign}{numerator}/{denominator}"),
+        _ => format!("{sign}{whole}-{numerator}/{denominator}"),
+    }
+}
+
+/// Formatting in the units chosen by [`UnitPreferences`]
+///
+/// `Display` uses the global preferences; [`Self::with_preferences`]
+/// overrides them for one value.
+pub trait PreferredDisplay {
+    fn fmt_with(&self, preferences: &UnitPreferences, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+
+    fn with_preferences(&self, preferences: UnitPreferences) -> WithPreferences<'_, Self> {
+        WithPreferences {
+            value: self,
+            preferences,
+        }
+    }
+}
 
-impl fmt::Display for DisplayForce {
+/// A display wrapper formatted with explicit preferences
+pub struct WithPreferences<'a, D: ?Sized> {
+    value: &'a D,
+    preferences: UnitPreferences,
+}
+
+impl<D: PreferredDisplay + ?Sized> fmt::Display for WithPreferences<'_, D> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt_with(&self.preferences, f)
+    }
+}
+
+macro_rules! display_with_global_preferences {
+    ($($ty:ty),*) => {
+        $(
+            impl fmt::Display for $ty {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    self.fmt_with(&UnitPreferences::global(), f)
+                }
+            }
+        )*
+    };
+}
+
+display_with_global_preferences!(
+    DisplayForce,
+    DisplayMass,
+    DisplayAngle,
+    DisplayLength,
+    DisplayVelocity,
+    DisplayHydraulicPressure,
+    DisplayGroundBearingPressure,
+    DisplayFractionalInches
+);
+
+impl PreferredDisplay for DisplayForce {
+    fn fmt_with(&self, preferences: &UnitPreferences, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let lbf = self.0.get::<pound_force>();
-        let newtons = self.0.get::<newton>();
-        write!(f, "{:.0} lbf ({:.2}) N", lbf, newtons)
+        match preferences.force {
+            UnitChoice::Dual => write!(f, "{:.0} lbf ({:.2}) N", lbf, self.0.get::<newton>()),
+            UnitChoice::Imperial => write!(f, "{:.0} lbf", lbf),
+            UnitChoice::Metric => write!(f, "{:.2} kN", self.0.get::<kilonewton>()),
+        }
     }
 }
 
-impl fmt::Display for DisplayMass {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl PreferredDisplay for DisplayMass {
+    fn fmt_with(&self, preferences: &UnitPreferences, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let lbs = self.0.get::<pound>();
-        let kg = self.0.get::<kilogram>();
-        write!(f, "{:.0} lbs ({:.0}kg)", lbs, kg)
-        
+        match preferences.mass {
+            UnitChoice::Dual => write!(f, "{:.0} lbs ({:.0}kg)", lbs, self.0.get::<kilogram>()),
+            UnitChoice::Imperial => write!(f, "{:.0} lbs", lbs),
+            UnitChoice::Metric => write!(f, "{:.2} t", self.0.get::<megagram>()),
+        }
     }
 }
 
-impl fmt::Display for DisplayAngle {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl PreferredDisplay for DisplayAngle {
+    /// Degrees in every unit system
+    fn fmt_with(&self, _preferences: &UnitPreferences, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:.2}°", self.0.get::<degree>())
     }
 }
 
-impl fmt::Display for DisplayLength {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl DisplayLength {
+    /// The same length in fractional inches, for hardware sizes
+    pub fn fractional(&self) -> DisplayFractionalInches {
+        DisplayFractionalInches(self.0)
+    }
+}
+
+impl PreferredDisplay for DisplayLength {
+    fn fmt_with(&self, preferences: &UnitPreferences, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let total_inches = self.0.get::<inch>();
         let feet = (total_inches / 12.0).floor();
         let inches = total_inches - (feet * 12.0);
         let meters = self.0.get::<meter>();
-        write!(f, "{}' {:.3}\" ({:.3}m)", feet, inches, meters)
+        match preferences.length {
+            UnitChoice::Dual => write!(f, "{}' {:.3}\" ({:.3}m)", feet, inches, meters),
+            UnitChoice::Imperial => write!(f, "{}' {:.3}\"", feet, inches),
+            UnitChoice::Metric => write!(f, "{:.3}m", meters),
+        }
     }
 }
 
-impl fmt::Display for DisplayVelocity {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl PreferredDisplay for DisplayVelocity {
+    fn fmt_with(&self, preferences: &UnitPreferences, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mph_f64 = self.0.get::<mile_per_hour>();
         let kph_f64 = self.0.get::<kilometer_per_hour>();
-
-        write!(f, "{:1}mph ({:.1})kph", mph_f64, kph_f64)
+        match preferences.velocity {
+            UnitChoice::Dual => write!(f, "{:1}mph ({:.1})kph", mph_f64, kph_f64),
+            UnitChoice::Imperial => write!(f, "{:.1}mph", mph_f64),
+            UnitChoice::Metric => write!(f, "{:.1}km/h", kph_f64),
+        }
     }
 }
 
-impl fmt::Display for DisplayHydraulicPressure {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl PreferredDisplay for DisplayHydraulicPressure {
+    fn fmt_with(&self, preferences: &UnitPreferences, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let psi_f64 = self.0.get::<psi>();
         let bar_f64 = self.0.get::<bar>();
-        
-        write!(f, "{:.0}psi ({:.1}bar)", psi_f64, bar_f64)
+        match preferences.hydraulic_pressure {
+            UnitChoice::Dual => write!(f, "{:.0}psi ({:.1}bar)", psi_f64, bar_f64),
+            UnitChoice::Imperial => write!(f, "{:.0}psi", psi_f64),
+            UnitChoice::Metric => write!(f, "{:.1}bar", bar_f64),
+        }
     }
 }
 
-impl fmt::Display for DisplayGroundBearingPressure {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl PreferredDisplay for DisplayGroundBearingPressure {
+    fn fmt_with(&self, preferences: &UnitPreferences, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let psi_f64 = self.0.get::<psi>();
         let kpa_f64 = self.0.get::<kilopascal>();
+        match preferences.ground_pressure {
+            UnitChoice::Dual => write!(f, "{:.0}psi ({:.0}kPa)", psi_f64, kpa_f64),
+            UnitChoice::Imperial => write!(f, "{:.0}psi", psi_f64),
+            UnitChoice::Metric => write!(f, "{:.0}kPa", kpa_f64),
+        }
+    }
+}
 
-        write!(f, "{:.0}psi ({:.0}kPa)", psi_f64, kpa_f64)
+impl PreferredDisplay for DisplayFractionalInches {
+    fn fmt_with(&self, preferences: &UnitPreferences, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mm = self.0.get::<millimeter>();
+        match preferences.length {
+            UnitChoice::Dual => write!(f, "{}\" ({:.1}mm)", inch_fraction(self.0), mm),
+            UnitChoice::Imperial => write!(f, "{}\"", inch_fraction(self.0)),
+            UnitChoice::Metric => write!(f, "{:.1}mm", mm),
+        }
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_explicit_preferences() {
+        let mass = DisplayMass(Mass::new::<kilogram>(12_500.0));
+        assert_eq!(mass.with_preferences(UnitPreferences::metric()).to_string(), "12.50 t");
+        assert_eq!(mass.with_preferences(UnitPreferences::imperial()).to_string(), "27558 lbs");
+        assert_eq!(mass.with_preferences(UnitPreferences::dual()).to_string(), "27558 lbs (12500kg)");
+
+        let force = DisplayForce(Force::new::<kilonewton>(45.0));
+        assert_eq!(force.with_preferences(UnitPreferences::metric()).to_string(), "45.00 kN");
+
+        // Per-quantity choices
+        let custom = UnitPreferences {
+            length: UnitChoice::Metric,
+            ..UnitPreferences::imperial()
+        };
+        let length = DisplayLength(Length::new::<meter>(3.0));
+        assert_eq!(length.with_preferences(custom).to_string(), "3.000m");
+        assert_eq!(
+            DisplayGroundBearingPressure(Pressure::new::<psi>(50.0)).with_preferences(custom).to_string(),
+            "50psi"
+        );
+    }
+
+    #[test]
+    fn test_scoped_preferences() {
+        let mass = DisplayMass(Mass::new::<kilogram>(2_000.0));
+        let metric = UnitPreferences::metric().scoped(|| {
+            // Nested scopes restore the outer one
+            let imperial = UnitPreferences::imperial().scoped(|| mass.to_string());
+            assert_eq!(imperial, "4409 lbs");
+            mass.to_string()
+        });
+        assert_eq!(metric, "2.00 t");
+        assert_eq!(UnitPreferences::global(), UnitPreferences::dual());
+        assert_eq!(mass.to_string(), "4409 lbs (2000kg)");
+    }
+
+    #[test]
+    fn test_default_is_dual() {
+        assert_eq!(UnitPreferences::default(), UnitPreferences::dual());
+        assert_eq!(UnitPreferences::from(UnitSystem::Metric), UnitPreferences::metric());
+    }
+
+    #[test]
+    fn test_fractional_inches() {
+        assert_eq!(inch_fraction(Length::new::<inch>(0.625)), "5/8");
+        assert_eq!(inch_fraction(Length::new::<inch>(1.25)), "1-1/4");
+        assert_eq!(inch_fraction(Length::new::<inch>(2.0)), "2");
+        // Rounded to the nearest 1/16
+        assert_eq!(inch_fraction(Length::new::<millimeter>(16.0)), "5/8");
+
+        let size = DisplayFractionalInches(Length::new::<inch>(1.125));
+        assert_eq!(size.with_preferences(UnitPreferences::imperial()).to_string(), "1-1/8\"");
+        assert_eq!(size.with_preferences(UnitPreferences::dual()).to_string(), "1-1/8\" (28.6mm)");
+    }
+}
@@ -1,11 +1,15 @@
+#[cfg(feature = "serde")]
 use serde::{self, Deserialize, Serialize};
-use uom::si::{angle::{degree, radian}, f64::{Angle, Length, Mass, Pressure}, length::{centimeter, foot, inch, meter, millimeter, yard}, mass::{gram, kilogram, pound, ton, ton_long, ton_short}, pressure::{bar, kilopascal, pascal, psi}};
-use std::{marker::PhantomData};
-#[derive(Debug, Clone, Deserialize, Serialize)]
+use uom::si::{angle::radian, f64::{Angle, Force, Length, Mass, Pressure}, force::newton, length::{foot, meter}, mass::kilogram, pressure::pascal};
+
+use crate::types::units::registry::{UnitKind, UnitRegistry};
+use std::{cmp::Ordering, marker::PhantomData, ops::{Add, Mul, Sub}, str::FromStr};
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct WithUnit<T> {
     pub value: f64,
     pub unit: String,
-    #[serde(skip)]
+    #[cfg_attr(feature = "serde", serde(skip))]
     _marker: PhantomData<T>,
 }
 
@@ -14,6 +18,7 @@ pub type MassValue = WithUnit<Mass>;
 pub type AngleValue = WithUnit<Angle>;
 pub type GroundBearingPressureValue = WithUnit<Pressure>;
 pub type HydraulicPressureValue = WithUnit<Pressure>;
+pub type ForceValue = WithUnit<Force>;
 
 impl<T> WithUnit<T> {
     pub fn new(value: f64, unit: impl Into<String>) -> Self {
@@ -25,6 +30,36 @@ impl<T> WithUnit<T> {
     }
 }
 
+/// Consistent set of units for stored values
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum UnitSystem {
+    /// Feet, pounds, degrees
+    Imperial,
+    /// Meters, kilograms, degrees
+    Metric,
+}
+
+impl UnitSystem {
+    pub fn length_unit(&self) -> &'static str {
+        match self {
+            UnitSystem::Imperial => "ft",
+            UnitSystem::Metric => "m",
+        }
+    }
+
+    pub fn mass_unit(&self) -> &'static str {
+        match self {
+            UnitSystem::Imperial => "lbs",
+            UnitSystem::Metric => "kg",
+        }
+    }
+
+    pub fn angle_unit(&self) -> &'static str {
+        "deg"
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum UnitError {
     #[error("Unknown length unit: {0}")]
@@ -37,191 +72,500 @@ pub enum UnitError {
     UnknownAngleUnit(String),
 
     #[error("Unknown pressure unit: {0}")]
-    UnknownPressureUnit(String)
+    UnknownPressureUnit(String),
+
+    #[error("Unknown force unit: {0}")]
+    UnknownForceUnit(String),
+
+    #[error("Invalid number: {0}")]
+    InvalidNumber(String),
+
+    #[error("Unit {0} must be a positive size in SI base units, got {1}")]
+    InvalidUnitSize(String, f64),
+}
+
+/// A uom quantity a [`WithUnit`] can hold, measured in SI base units
+pub trait UnitQuantity: Sized {
+    const KIND: UnitKind;
+
+    fn from_si(value: f64) -> Self;
+    fn to_si(&self) -> f64;
+}
+
+macro_rules! unit_quantity {
+    ($($ty:ty => $kind:ident, $si:ty;)*) => {
+        $(
+            impl UnitQuantity for $ty {
+                const KIND: UnitKind = UnitKind::$kind;
+
+                fn from_si(value: f64) -> Self {
+                    <$ty>::new::<$si>(value)
+                }
+
+                fn to_si(&self) -> f64 {
+                    self.get::<$si>()
+                }
+            }
+        )*
+    };
+}
+
+unit_quantity! {
+    Length => Length, meter;
+    Mass => Mass, kilogram;
+    Angle => Angle, radian;
+    Pressure => Pressure, pascal;
+    Force => Force, newton;
+}
+
+impl UnitKind {
+    fn unknown_unit(self, unit: &str) -> UnitError {
+        let unit = unit.to_string();
+        match self {
+            UnitKind::Length => UnitError::UnknownLengthUnit(unit),
+            UnitKind::Mass => UnitError::UnknownMassUnit(unit),
+            UnitKind::Angle => UnitError::UnknownAngleUnit(unit),
+            UnitKind::Pressure => UnitError::UnknownPressureUnit(unit),
+            UnitKind::Force => UnitError::UnknownForceUnit(unit),
+        }
+    }
+}
+
+fn si_per_unit<T: UnitQuantity>(unit: &str) -> Result<f64, UnitError> {
+    UnitRegistry::lookup(T::KIND, unit).ok_or_else(|| T::KIND.unknown_unit(unit))
+}
+
+impl<T: UnitQuantity> WithUnit<T> {
+    /// The value as a uom quantity
+    pub fn quantity(&self) -> Result<T, UnitError> {
+        Ok(T::from_si(self.value * si_per_unit::<T>(&self.unit)?))
+    }
+
+    /// A quantity expressed in `unit`
+    pub fn from_quantity(quantity: T, unit: &str) -> Result<Self, UnitError> {
+        Ok(Self::new(quantity.to_si() / si_per_unit::<T>(unit)?, unit))
+    }
+
+    /// The same quantity expressed in `unit`
+    pub fn convert_to(&self, unit: &str) -> Result<Self, UnitError> {
+        Self::from_quantity(self.quantity()?, unit)
+    }
 }
 
 impl WithUnit<Length> {
     pub fn to_distance(&self) -> Result<Length, UnitError> {
-        match self.unit.as_str() {
-            "ft" | "Ft" | "FT" 
-            | "foot" | "Foot" | "FOOT" 
-            | "feet" | "Feet" | "FEET" => Ok(Length::new::<foot>(self.value)),
-            "in" | "In" | "IN" 
-            | "inch" | "Inch" | "INCH"
-            | "inches" | "Inches" | "INCHES" => Ok(Length::new::<inch>(self.value)),
-            "m" | "M" 
-            | "meter" | "Meter" | "METER" 
-            | "metre" | "Metre" | "METRE" 
-            | "meters" | "Meters" | "METERS"
-            | "metres" | "Metres" | "METRES" => Ok(Length::new::<meter>(self.value)),
-            "cm" | "Cm" | "CM"
-            | "centimeter" | "Centimeter" | "CENTIMETER"
-            | "centimetre" | "Centimetre" | "CENTIMETRE"
-            | "centimeters" | "Centimeters" | "CENTIMETERS"
-            | "centimetres" | "Centimetres" | "CENTIMETRES" => Ok(Length::new::<centimeter>(self.value)),
-            "mm" | "Mm" | "MM" 
-            | "millimeter" | "Millimeter" | "MILLIMETER"
-            | "millimetre" | "Millinetre" | "MILLIMETRE"
-            | "millimeters" | "Millimeters" | "MILLIMETERS"
-            | "millimetres" | "Millimetres" | "MILLIMETRES" => Ok(Length::new::<millimeter>(self.value)),
-            "yd" | "Yd" | "YD"
-            | "yard" | "Yard" | "YARD"
-            | "yards" |"Yards" | "YARDS" => Ok(Length::new::<yard>(self.value)),
-            _ => Err(UnitError::UnknownLengthUnit(self.unit.clone()))
-        }
+        self.quantity()
     }
     
+    /// Convert in place to the unit system's length unit
+    pub fn normalize(&mut self, system: UnitSystem) -> Result<(), UnitError> {
+        *self = self.convert_to(system.length_unit())?;
+        Ok(())
+    }
+
     pub fn from_length(length: Length, unit: &str) -> Result<Self, UnitError> {
-        let value = match unit {
-            "ft" | "Ft" | "FT" 
-            | "foot" | "Foot" | "FOOT" 
-            | "feet" | "Feet" | "FEET" => length.get::<foot>(),
-            "in" | "In" | "IN" 
-            | "inch" | "Inch" | "INCH"
-            | "inches" | "Inches" | "INCHES" => length.get::<inch>(),
-            "m" | "M" 
-            | "meter" | "Meter" | "METER" 
-            | "metre" | "Metre" | "METRE" 
-            | "meters" | "Meters" | "METERS"
-            | "metres" | "Metres" | "METRES" => length.get::<meter>(),
-            "cm" | "Cm" | "CM"
-            | "centimeter" | "Centimeter" | "CENTIMETER"
-            | "centimetre" | "Centimetre" | "CENTIMETRE"
-            | "centimeters" | "Centimeters" | "CENTIMETERS"
-            | "centimetres" | "Centimetres" | "CENTIMETRES" => length.get::<centimeter>(),
-            "mm" | "Mm" | "MM" 
-            | "millimeter" | "Millimeter" | "MILLIMETER"
-            | "millimetre" | "Millinetre" | "MILLIMETRE"
-            | "millimeters" | "Millimeters" | "MILLIMETERS"
-            | "millimetres" | "Millimetres" | "MILLIMETRES" => length.get::<millimeter>(),
-            "yd" | "Yd" | "YD"
-            | "yard" | "Yard" | "YARD"
-            | "yards" |"Yards" | "YARDS" => length.get::<yard>(),
-            _ => return Err(UnitError::UnknownLengthUnit(unit.to_string()))
-        };
-        
-        Ok(Self::new(value, unit))
+        Self::from_quantity(length, unit)
     }
 }
 
 impl WithUnit<Mass> {
     pub fn to_mass(&self) -> Result<Mass, UnitError> {
-        match self.unit.as_str() {
-            "lb" | "Lb" | "LB"
-            | "lbs" | "Lbs" | "LBS"
-            | "pound" | "Pound" | "POUND"
-            | "pounds" | "Pounds" | "POUNDS" => Ok(Mass::new::<pound>(self.value)), 
-            "kg" | "Kg" | "KG"
-            | "kgs" | "Kgs" | "KGS"
-            | "kilogram" | "Kilogram" | "KILOGRAN"
-            | "kilograms" | "Kilograms" | "KILOGRANS" => Ok(Mass::new::<kilogram>(self.value)),
-            | "short ton" | "Short Ton" | "SHORT TON"
-            | "short tons" | "Short Tons" | "SHORT TONS" => Ok(Mass::new::<ton_short>(self.value)),
-            "metric ton" | "Metric Ton" | "METRIC TON"
-            | "metric tons" | "Metric Tons" | "METRIC TONS" => Ok(Mass::new::<ton>(self.value)),
-            "long ton" | "Long Ton" | "LONG TON"
-            | "long tons" | "Long Tons" | "LONG TONS" => Ok(Mass::new::<ton_long>(self.value)),
-            "g" | "G"
-            | "gram" | "Gram" | "GRAM"
-            | "grams" | "Grams" | "GRAMS" => Ok(Mass::new::<gram>(self.value)),
-            _ => Err(UnitError::UnknownMassUnit(self.unit.clone())),
-        }
+        self.quantity()
     }
     
+    /// Convert in place to the unit system's mass unit
+    pub fn normalize(&mut self, system: UnitSystem) -> Result<(), UnitError> {
+        *self = self.convert_to(system.mass_unit())?;
+        Ok(())
+    }
+
     pub fn from_mass(mass: Mass, unit: &str) -> Result<Self, UnitError> {
-        let value = match unit {
-            "lb" | "Lb" | "LB"
-            | "lbs" | "Lbs" | "LBS"
-            | "pound" | "Pound" | "POUND"
-            | "pounds" | "Pounds" | "POUNDS" => mass.get::<pound>(), 
-            "kg" | "Kg" | "KG"
-            | "kgs" | "Kgs" | "KGS"
-            | "kilogram" | "Kilogram" | "KILOGRAN"
-            | "kilograms" | "Kilograms" | "KILOGRANS" => mass.get::<kilogram>(),
-            | "short ton" | "Short Ton" | "SHORT TON"
-            | "short tons" | "Short Tons" | "SHORT TONS" => mass.get::<ton_short>(),
-            "metric ton" | "Metric Ton" | "METRIC TON"
-            | "metric tons" | "Metric Tons" | "METRIC TONS" => mass.get::<ton>(),
-            "long ton" | "Long Ton" | "LONG TON"
-            | "long tons" | "Long Tons" | "LONG TONS" => mass.get::<ton_long>(),
-            "g" | "G"
-            | "gram" | "Gram" | "GRAM"
-            | "grams" | "Grams" | "GRAMS" => mass.get::<gram>(),
-            _ => return Err(UnitError::UnknownMassUnit(unit.to_string()))
-        };
-        
-        Ok(Self::new(value, unit))
+        Self::from_quantity(mass, unit)
     }
 }
 
 impl WithUnit<Angle> {
     pub fn to_angle(&self) -> Result<Angle, UnitError> {
-        match self.unit.as_str() {
-            "deg" | "Deg" | "DEG"
-            | "degree" | "Degree" | "DEGREE"
-            | "degrees" | "Degrees" | "DEGREES" | "°" => Ok(Angle::new::<degree>(self.value)),
-            "rad" | "Rad" | "RAD"
-            | "rads" | "Rads" | "RADS"
-            | "radian" | "Radian" | "RADIAN"
-            | "radians" | "Radians" | "RADIANS" => Ok(Angle::new::<radian>(self.value)),
-            _ => Err(UnitError::UnknownAngleUnit(self.unit.clone()))
-        }
+        self.quantity()
     }
     
+    /// Convert in place to the unit system's angle unit
+    pub fn normalize(&mut self, system: UnitSystem) -> Result<(), UnitError> {
+        *self = self.convert_to(system.angle_unit())?;
+        Ok(())
+    }
+
     pub fn from_angle(angle: Angle, unit: &str) -> Result<Self, UnitError> {
-        let value = match unit {
-            "deg" | "Deg" | "DEG"
-            | "degree" | "Degree" | "DEGREE"
-            | "degrees" | "Degrees" | "DEGREES" | "°" => angle.get::<degree>(),
-            "rad" | "Rad" | "RAD"
-            | "rads" | "Rads" | "RADS"
-            | "radian" | "Radian" | "RADIAN"
-            | "radians" | "Radians" | "RADIANS" => angle.get::<radian>(),
-            _ => return Err(UnitError::UnknownAngleUnit(unit.to_string())),
-        };
-        
-        Ok(Self::new(value, unit))
+        Self::from_quantity(angle, unit)
     }
 }
 
 impl WithUnit<Pressure> {
     pub fn to_pressure(&self) -> Result<Pressure, UnitError> {
-        match self.unit.as_str() {
-            "psi" | "lbf/in^2" | "lbf/in²" 
-            | "lb/in²" | "lb/in^2" | "pound per square inch"
-            | "pounds per square inch" => Ok(Pressure::new::<psi>(self.value)),
-            "pa" | "Pa" | "PA" 
-            | "pascal" | "Pascal" | "PASCAL" 
-            | "pascals" | "Pascals" | "PASCALS"
-            | "N/m²" | "N/m^2" => Ok(Pressure::new::<pascal>(self.value)),
-            "kilopascal" | "Kilopascal" | "KILOPASCAL"
-            | "kilopascals" | "Kilopascals" | "KILOPASCALS"
-            | "kPa" | "KPa" | "kPA"
-            | "kPas" | "KPas" | "KPAs" | "KPAS" => Ok(Pressure::new::<kilopascal>(self.value)),
-            "bar" | "BAR" => Ok(Pressure::new::<bar>(self.value)),
-            _ => return Err(UnitError::UnknownPressureUnit(self.unit.clone())),
-        }
+        self.quantity()
     }
 
     pub fn from_pressure(pressure: Pressure, unit: &str) -> Result<Self, UnitError> {
-        let value = match unit {
-            "psi" | "lbf/in^2" | "lbf/in²" 
-            | "lb/in²" | "lb/in^2" | "pound per square inch"
-            | "pounds per square inch" => pressure.get::<psi>(),
-            "pa" | "Pa" | "PA" 
-            | "pascal" | "Pascal" | "PASCAL" 
-            | "pascals" | "Pascals" | "PASCALS"
-            | "N/m²" | "N/m^2" => pressure.get::<pascal>(),
-            "kilopascal" | "Kilopascal" | "KILOPASCAL"
-            | "kilopascals" | "Kilopascals" | "KILOPASCALS"
-            | "kPa" | "KPa" | "kPA"
-            | "kPas" | "KPas" | "KPAs" | "KPAS" => pressure.get::<kilopascal>(),
-            "bar" | "BAR" => pressure.get::<bar>(),
-            _ => return Err(UnitError::UnknownPressureUnit(unit.to_string())),
-        };
+        Self::from_quantity(pressure, unit)
+    }
+}
+
+impl WithUnit<Force> {
+    pub fn to_force(&self) -> Result<Force, UnitError> {
+        self.quantity()
+    }
+
+    pub fn from_force(force: Force, unit: &str) -> Result<Self, UnitError> {
+        Self::from_quantity(force, unit)
+    }
+}
+
+/// Sum in the left operand's unit; fails if either unit is unknown
+impl<T: UnitQuantity> Add for &WithUnit<T> {
+    type Output = Result<WithUnit<T>, UnitError>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let sum = self.quantity()?.to_si() + rhs.quantity()?.to_si();
+        WithUnit::from_quantity(T::from_si(sum), &self.unit)
+    }
+}
+
+/// Difference in the left operand's unit; fails if either unit is unknown
+impl<T: UnitQuantity> Sub for &WithUnit<T> {
+    type Output = Result<WithUnit<T>, UnitError>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let difference = self.quantity()?.to_si() - rhs.quantity()?.to_si();
+        WithUnit::from_quantity(T::from_si(difference), &self.unit)
+    }
+}
+
+impl<T: UnitQuantity> Add for WithUnit<T> {
+    type Output = Result<WithUnit<T>, UnitError>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl<T: UnitQuantity> Sub for WithUnit<T> {
+    type Output = Result<WithUnit<T>, UnitError>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        &self - &rhs
+    }
+}
+
+impl<T> Mul<f64> for WithUnit<T> {
+    type Output = WithUnit<T>;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        WithUnit::new(self.value * rhs, self.unit)
+    }
+}
+
+impl<T> Mul<f64> for &WithUnit<T> {
+    type Output = WithUnit<T>;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        WithUnit::new(self.value * rhs, self.unit.clone())
+    }
+}
+
+/// Equal quantities compare equal across units ("12 in" == "1 ft");
+/// values with unknown units are never equal
+impl<T: UnitQuantity> PartialEq for WithUnit<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+/// Values within a relative 1e-12 of each other are equal, so round-off
+/// from the unit factors doesn't decide the order
+impl<T: UnitQuantity> PartialOrd for WithUnit<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let a = self.quantity().ok()?.to_si();
+        let b = other.quantity().ok()?.to_si();
+        if (a - b).abs() <= 1e-12 * a.abs().max(b.abs()) {
+            return Some(Ordering::Equal);
+        }
+        a.partial_cmp(&b)
+    }
+}
+
+/// Characters accepted between digit groups: comma, underscore and the
+/// no-break and thin spaces
+const GROUP_SEPARATORS: [char; 5] = [',', '_', '\u{00A0}', '\u{202F}', '\u{2009}'];
+
+/// Split text like "242,500 lbs" or "−3.5°" into a number and unit
+///
+/// Digit groups must be three digits long, so "47,5 m" (decimal comma)
+/// is rejected rather than read as 475.
+fn split_quantity(text: &str) -> Result<(f64, String), UnitError> {
+    let text = text.trim();
+    let end = text
+        .char_indices()
+        .find(|&(i, c)| {
+            !(c.is_ascii_digit()
+                || c == '.'
+                || GROUP_SEPARATORS.contains(&c)
+                || (i == 0 && matches!(c, '-' | '+' | '\u{2212}')))
+        })
+        .map_or(text.len(), |(i, _)| i);
+    let (number, unit) = text.split_at(end);
+    let invalid = || UnitError::InvalidNumber(text.to_string());
+
+    let (sign, digits) = match number.chars().next() {
+        Some('-') | Some('\u{2212}') => (-1.0, &number[number.chars().next().unwrap().len_utf8()..]),
+        Some('+') => (1.0, &number[1..]),
+        _ => (1.0, number),
+    };
+    let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    if fraction.contains(GROUP_SEPARATORS) {
+        return Err(invalid());
+    }
+    let groups: Vec<&str> = integer.split(GROUP_SEPARATORS).collect();
+    if (groups[0].is_empty() && groups.len() > 1) || groups[1..].iter().any(|g| g.len() != 3) {
+        return Err(invalid());
+    }
+
+    let plain = format!("{}.{}", groups.concat(), fraction);
+    if plain == "." {
+        return Err(invalid());
+    }
+    let value: f64 = plain.parse().map_err(|_| invalid())?;
+    Ok((sign * value, unit.trim().to_string()))
+}
+
+/// Split fractional sizes like `5/8"`, `1-1/4"` or "1 1/4 in"
+///
+/// Returns `None` when the number has no fraction.
+fn split_fraction(text: &str) -> Option<Result<(f64, String), UnitError>> {
+    let text = text.trim();
+    let end = text
+        .find(|c: char| !(c.is_ascii_digit() || matches!(c, '-' | ' ' | '/')))
+        .unwrap_or(text.len());
+    let number = text[..end].trim_end();
+    if !number.contains('/') {
+        return None;
+    }
+    let invalid = || UnitError::InvalidNumber(text.to_string());
+
+    let (sign, number) = match number.strip_prefix('-') {
+        Some(rest) => (-1.0, rest),
+        None => (1.0, number),
+    };
+    let (whole, fraction) = number.rsplit_once(['-', ' ']).unwrap_or(("", number));
+    let parse = |digits: &str| digits.trim().parse::<u32>().map_err(|_| invalid());
+    let result = (|| {
+        let whole = if whole.trim().is_empty() { 0 } else { parse(whole)? };
+        let (numerator, denominator) = fraction.split_once('/').ok_or_else(invalid)?;
+        let (numerator, denominator) = (parse(numerator)?, parse(denominator)?);
+        if denominator == 0 {
+            return Err(invalid());
+        }
+        let value = whole as f64 + numerator as f64 / denominator as f64;
+        Ok((sign * value, text[end..].trim().to_string()))
+    })();
+    Some(result)
+}
+
+impl FromStr for WithUnit<Length> {
+    type Err = UnitError;
+
+    /// Parse "47 m", "154.2ft", fractions like `1-1/4"` or feet and
+    /// inches like `5' 6-1/2"`
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        if let Some((feet, inches)) = text.split_once(['\'', '′'])
+            && !inches.trim().is_empty()
+        {
+            let (feet, _) = split_quantity(feet)?;
+            let inches: Self = inches.parse()?;
+            let total = Length::new::<foot>(feet) + inches.to_distance()?;
+            return Ok(Self::new(total.get::<foot>(), "ft"));
+        }
+
+        let (value, unit) = split_fraction(text).unwrap_or_else(|| split_quantity(text))?;
+        let parsed = Self::new(value, unit);
+        parsed.to_distance()?;
+        Ok(parsed)
+    }
+}
+
+impl FromStr for WithUnit<Mass> {
+    type Err = UnitError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let (value, unit) = split_quantity(text)?;
+        let parsed = Self::new(value, unit);
+        parsed.to_mass()?;
+        Ok(parsed)
+    }
+}
+
+impl FromStr for WithUnit<Angle> {
+    type Err = UnitError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let (value, unit) = split_quantity(text)?;
+        let parsed = Self::new(value, unit);
+        parsed.to_angle()?;
+        Ok(parsed)
+    }
+}
+
+impl FromStr for WithUnit<Pressure> {
+    type Err = UnitError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let (value, unit) = split_quantity(text)?;
+        let parsed = Self::new(value, unit);
+        parsed.to_pressure()?;
+        Ok(parsed)
+    }
+}
+
+impl FromStr for WithUnit<Force> {
+    type Err = UnitError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let (value, unit) = split_quantity(text)?;
+        let parsed = Self::new(value, unit);
+        parsed.to_force()?;
+        Ok(parsed)
+    }
+}
+
+impl<T> TryFrom<&str> for WithUnit<T>
+where
+    WithUnit<T>: FromStr<Err = UnitError>,
+{
+    type Error = UnitError;
 
-        Ok(Self::new(value, unit))
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        text.parse()
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_parse_with_separators() {
+        let mass: MassValue = "242,500 lbs".parse().unwrap();
+        assert_relative_eq!(mass.value, 242500.0);
+        assert_eq!(mass.unit, "lbs");
+
+        let mass = MassValue::try_from("12\u{202F}500 kg").unwrap();
+        assert_relative_eq!(mass.to_mass().unwrap().get::<kilogram>(), 12500.0);
+
+        let mass: MassValue = "1_000.5t".parse().unwrap();
+        assert_relative_eq!(mass.to_mass().unwrap().get::<kilogram>(), 1_000_500.0, epsilon = 1e-6);
+
+        // Decimal commas are ambiguous, not silently misread
+        assert!(matches!("47,5 m".parse::<LengthValue>(), Err(UnitError::InvalidNumber(_))));
+        assert!(matches!("lbs".parse::<MassValue>(), Err(UnitError::InvalidNumber(_))));
+    }
+
+    #[test]
+    fn test_parse_units() {
+        let length: LengthValue = "47 m".parse().unwrap();
+        assert_relative_eq!(length.to_distance().unwrap().get::<meter>(), 47.0);
+
+        let length: LengthValue = "5' 6\"".parse().unwrap();
+        assert_relative_eq!(length.to_distance().unwrap().get::<inch>(), 66.0, epsilon = 1e-9);
+        let length: LengthValue = "12′".parse().unwrap();
+        assert_relative_eq!(length.to_distance().unwrap().get::<foot>(), 12.0);
+
+        let angle: AngleValue = "\u{2212}3.5°".parse().unwrap();
+        assert_relative_eq!(angle.to_angle().unwrap().get::<degree>(), -3.5);
+
+        let pressure: GroundBearingPressureValue = "2,000 kPa".parse().unwrap();
+        assert_relative_eq!(pressure.to_pressure().unwrap().get::<kilopascal>(), 2000.0);
+
+        assert!(matches!("47 furlongs".parse::<LengthValue>(), Err(UnitError::UnknownLengthUnit(_))));
+        assert!(matches!("10 psi".parse::<MassValue>(), Err(UnitError::UnknownMassUnit(_))));
+    }
+
+    #[test]
+    fn test_registry_aliases() {
+        // Case-insensitive, including spellings the old tables missed
+        assert_relative_eq!(MassValue::new(2.0, "TONNES").to_mass().unwrap().get::<kilogram>(), 2000.0);
+        assert_relative_eq!(MassValue::new(1.0, "Stone").to_mass().unwrap().get::<pound>(), 14.0, epsilon = 1e-9);
+        assert_relative_eq!(LengthValue::new(3.0, "FEET").to_distance().unwrap().get::<foot>(), 3.0, epsilon = 1e-12);
 
+        let force: ForceValue = "1,200 daN".parse().unwrap();
+        assert_relative_eq!(force.to_force().unwrap().get::<kilonewton>(), 12.0, epsilon = 1e-9);
+        let force = ForceValue::new(2.0, "kips").to_force().unwrap();
+        assert_relative_eq!(force.get::<pound_force>(), 2000.0, epsilon = 1e-9);
+
+        let round_trip = ForceValue::from_force(Force::new::<kilonewton>(4.5), "kN").unwrap();
+        assert_relative_eq!(round_trip.value, 4.5, epsilon = 1e-12);
+
+        UnitRegistry::register(UnitKind::Mass, "Sack", 50.0).unwrap();
+        assert_relative_eq!(MassValue::new(3.0, "sack").to_mass().unwrap().get::<kilogram>(), 150.0);
+        assert!(matches!(ForceValue::new(1.0, "sack").to_force(), Err(UnitError::UnknownForceUnit(_))));
+
+        // A zero, negative or non-finite size would divide or flip every conversion
+        for size in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                UnitRegistry::register(UnitKind::Mass, "bag", size),
+                Err(UnitError::InvalidUnitSize(..))
+            ));
+        }
+        assert!(MassValue::new(1.0, "bag").to_mass().is_err());
+    }
+
+    #[test]
+    fn test_arithmetic_across_units() {
+        let boom = LengthValue::new(100.0, "ft");
+        let jib = LengthValue::new(10.0, "m");
+        let total = (&boom + &jib).unwrap();
+        assert_eq!(total.unit, "ft");
+        assert_relative_eq!(total.value, 100.0 + 10.0 / 0.3048, epsilon = 1e-9);
+
+        let remaining = (MassValue::new(5.0, "t") - MassValue::new(1000.0, "kg")).unwrap();
+        assert_eq!(remaining.unit, "t");
+        assert_relative_eq!(remaining.value, 4.0, epsilon = 1e-12);
+
+        let doubled = MassValue::new(2_500.0, "lbs") * 2.0;
+        assert_relative_eq!(doubled.value, 5_000.0);
+        assert_eq!(doubled.unit, "lbs");
+
+        assert!(matches!(&boom + &LengthValue::new(1.0, "furlongs"), Err(UnitError::UnknownLengthUnit(_))));
+    }
+
+    #[test]
+    fn test_compare_and_convert() {
+        assert!(LengthValue::new(12.0, "in") == LengthValue::new(1.0, "ft"));
+        assert!(MassValue::new(1.0, "t") > MassValue::new(2_000.0, "lbs"));
+        assert!(AngleValue::new(1.0, "rad") < AngleValue::new(60.0, "deg"));
+        assert_eq!(LengthValue::new(1.0, "ft").partial_cmp(&LengthValue::new(1.0, "furlongs")), None);
+
+        let radius = LengthValue::new(30.0, "m").convert_to("ft").unwrap();
+        assert_eq!(radius.unit, "ft");
+        assert_relative_eq!(radius.value, 30.0 / 0.3048, epsilon = 1e-9);
+        assert!(matches!(radius.convert_to("kg"), Err(UnitError::UnknownLengthUnit(_))));
+    }
+
+    #[test]
+    fn test_parse_fractional_inches() {
+        let size: LengthValue = "5/8\"".parse().unwrap();
+        assert_relative_eq!(size.value, 0.625);
+        assert_eq!(size.unit, "\"");
+
+        let size: LengthValue = "1-1/4\"".parse().unwrap();
+        assert_relative_eq!(size.to_distance().unwrap().get::<inch>(), 1.25, epsilon = 1e-12);
+        let size: LengthValue = "1 1/4 in".parse().unwrap();
+        assert_relative_eq!(size.value, 1.25);
+
+        let height: LengthValue = "5' 6-1/2\"".parse().unwrap();
+        assert_relative_eq!(height.value, 5.0 + 6.5 / 12.0, epsilon = 1e-12);
+
+        assert!(matches!("1/0\"".parse::<LengthValue>(), Err(UnitError::InvalidNumber(_))));
+        assert!(matches!("1-/4\"".parse::<LengthValue>(), Err(UnitError::InvalidNumber(_))));
+    }
+}
@@ -1,12 +1,22 @@
 mod display;
 mod explicit_unit_values;
-use uom::si::f64::*;
+mod preferences;
+mod registry;
 
 pub use uom::si::f64::{
-    Acceleration, Angle, Area, Energy, Force, Length, Mass, Momentum, Power, Pressure,
-    ThermodynamicTemperature, Time, Torque, Velocity, Volume,
+    Acceleration, Angle, AngularVelocity, Area, Energy, Force, Frequency, Length, Mass,
+    MassDensity, Momentum, Power, Pressure, Ratio, ThermodynamicTemperature, Time, Torque,
+    Velocity, Volume,
 };
 
+/// Old name for [`Mass`]; the crate uses `Mass` for loads and capacities
+#[deprecated(note = "use `Mass`; convert to a force with `weight_of`")]
+pub type Weight = Mass;
+
+/// Old name for [`Length`]
+#[deprecated(note = "use `Length`")]
+pub type Distance = Length;
+
 pub use uom::si::{
     acceleration::{
         foot_per_second_squared, inch_per_second_squared, kilometer_per_second_squared,
@@ -54,10 +64,13 @@ pub use uom::si::{
 };
 
 pub use display::{
-    DisplayAngle, DisplayForce, DisplayGroundBearingPressure, DisplayHydraulicPressure,
-    DisplayLength, DisplayVelocity, DisplayMass,
+    inch_fraction, DisplayAngle, DisplayForce, DisplayFractionalInches, DisplayGroundBearingPressure, DisplayHydraulicPressure,
+    DisplayLength, DisplayVelocity, DisplayMass, PreferredDisplay, WithPreferences,
+    INCH_FRACTION_DENOMINATOR,
 };
+pub use preferences::{UnitChoice, UnitPreferences};
 pub use explicit_unit_values::{
-    AngleValue, GroundBearingPressureValue, HydraulicPressureValue, LengthValue, UnitError,
-    MassValue, WithUnit,
+    AngleValue, ForceValue, GroundBearingPressureValue, HydraulicPressureValue, LengthValue, UnitError,
+    MassValue, UnitQuantity, UnitSystem, WithUnit,
 };
+pub use registry::{UnitKind, UnitRegistry};
@@ -0,0 +1,122 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::cell::Cell;
+use std::sync::RwLock;
+
+use crate::types::units::UnitSystem;
+
+/// Units to show one quantity in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum UnitChoice {
+    /// Imperial first, metric in parentheses
+    Dual,
+    Imperial,
+    /// Meters, tonnes, kN, km/h, bar, kPa
+    Metric,
+}
+
+/// Units used by the `Display*` wrappers in errors and reports
+///
+/// Set once with [`UnitPreferences::set_global`], override on the current
+/// thread with [`UnitPreferences::scoped`], or pass to a single wrapper with
+/// `with_preferences`. Fields can be mixed per quantity:
+///
+/// ```
+/// use crane_core::types::{UnitChoice, UnitPreferences};
+///
+/// let prefs = UnitPreferences {
+///     length: UnitChoice::Metric,
+///     ..UnitPreferences::imperial()
+/// };
+/// assert_eq!(prefs.mass, UnitChoice::Imperial);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UnitPreferences {
+    pub length: UnitChoice,
+    pub mass: UnitChoice,
+    pub force: UnitChoice,
+    pub velocity: UnitChoice,
+    pub hydraulic_pressure: UnitChoice,
+    pub ground_pressure: UnitChoice,
+}
+
+static GLOBAL: RwLock<UnitPreferences> = RwLock::new(UnitPreferences::all(UnitChoice::Dual));
+
+thread_local! {
+    static SCOPED: Cell<Option<UnitPreferences>> = const { Cell::new(None) };
+}
+
+/// Puts the previous thread override back, even if the scope panics
+struct ScopeGuard(Option<UnitPreferences>);
+
+impl Drop for ScopeGuard {
+    fn drop(&mut self) {
+        SCOPED.with(|scoped| scoped.set(self.0));
+    }
+}
+
+impl UnitPreferences {
+    /// Every quantity in the same units
+    pub const fn all(choice: UnitChoice) -> Self {
+        Self {
+            length: choice,
+            mass: choice,
+            force: choice,
+            velocity: choice,
+            hydraulic_pressure: choice,
+            ground_pressure: choice,
+        }
+    }
+
+    pub const fn dual() -> Self {
+        Self::all(UnitChoice::Dual)
+    }
+
+    pub const fn imperial() -> Self {
+        Self::all(UnitChoice::Imperial)
+    }
+
+    pub const fn metric() -> Self {
+        Self::all(UnitChoice::Metric)
+    }
+
+    /// Preferences used when none are passed explicitly
+    ///
+    /// A [`scoped`](Self::scoped) override on this thread wins over the
+    /// process-wide setting.
+    pub fn global() -> Self {
+        SCOPED
+            .with(Cell::get)
+            .unwrap_or_else(|| *GLOBAL.read().unwrap_or_else(|e| e.into_inner()))
+    }
+
+    pub fn set_global(preferences: Self) {
+        *GLOBAL.write().unwrap_or_else(|e| e.into_inner()) = preferences;
+    }
+
+    /// Runs `f` with these preferences as the default on the current thread
+    ///
+    /// Other threads keep seeing the global setting, and the previous
+    /// default comes back when `f` returns.
+    pub fn scoped<R>(self, f: impl FnOnce() -> R) -> R {
+        let _guard = ScopeGuard(SCOPED.with(|scoped| scoped.replace(Some(self))));
+        f()
+    }
+}
+
+impl Default for UnitPreferences {
+    fn default() -> Self {
+        Self::dual()
+    }
+}
+
+impl From<UnitSystem> for UnitPreferences {
+    fn from(system: UnitSystem) -> Self {
+        match system {
+            UnitSystem::Imperial => Self::imperial(),
+            UnitSystem::Metric => Self::metric(),
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+use uom::si::f64::{Angle, Force, Length, Mass, Pressure};
+use uom::si::{angle, force, length, mass, pressure};
+
+use crate::types::units::explicit_unit_values::UnitError;
+
+/// Quantity a unit alias measures
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnitKind {
+    Length,
+    Mass,
+    Angle,
+    Pressure,
+    Force,
+}
+
+/// Unit aliases for [`WithUnit`](crate::types::WithUnit) conversions
+///
+/// Aliases are matched case-insensitively with surrounding whitespace
+/// ignored. Each maps to its size in SI base units (meters, kilograms,
+/// radians, pascals, newtons). More can be added at runtime:
+///
+/// ```
+/// use crane_core::types::{meter, LengthValue, UnitKind, UnitRegistry};
+///
+/// UnitRegistry::register(UnitKind::Length, "furlong", 201.168).unwrap();
+/// let length: LengthValue = "2 Furlong".parse().unwrap();
+/// assert!((length.to_distance().unwrap().get::<meter>() - 402.336).abs() < 1e-9);
+/// ```
+pub struct UnitRegistry;
+
+type Aliases = HashMap<(UnitKind, String), f64>;
+
+fn registry() -> &'static RwLock<Aliases> {
+    static REGISTRY: OnceLock<RwLock<Aliases>> = OnceLock::new();
+    REGISTRY.get_or_init(|| RwLock::new(builtin_aliases()))
+}
+
+fn normalize(alias: &str) -> String {
+    alias.trim().to_lowercase()
+}
+
+impl UnitRegistry {
+    /// Add or replace an alias
+    ///
+    /// Errors unless `si_per_unit` is positive and finite.
+    pub fn register(kind: UnitKind, alias: &str, si_per_unit: f64) -> Result<(), UnitError> {
+        if !(si_per_unit.is_finite() && si_per_unit > 0.0) {
+            return Err(UnitError::InvalidUnitSize(alias.to_string(), si_per_unit));
+        }
+        registry()
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert((kind, normalize(alias)), si_per_unit);
+        Ok(())
+    }
+
+    /// Size of one `alias` in SI base units
+    pub fn lookup(kind: UnitKind, alias: &str) -> Option<f64> {
+        registry()
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&(kind, normalize(alias)))
+            .copied()
+    }
+}
+
+fn builtin_aliases() -> Aliases {
+    let mut aliases = HashMap::new();
+    let mut add = |kind: UnitKind, names: &[&str], si_per_unit: f64| {
+        for name in names {
+            aliases.insert((kind, normalize(name)), si_per_unit);
+        }
+    };
+
+    let length = |l: Length| l.get::<length::meter>();
+    add(UnitKind::Length, &["ft", "foot", "feet", "'", "′"], length(Length::new::<length::foot>(1.0)));
+    add(UnitKind::Length, &["in", "inch", "inches", "\"", "″"], length(Length::new::<length::inch>(1.0)));
+    add(UnitKind::Length, &["yd", "yard", "yards"], length(Length::new::<length::yard>(1.0)));
+    add(UnitKind::Length, &["m", "meter", "metre", "meters", "metres"], 1.0);
+    add(
+        UnitKind::Length,
+        &["cm", "centimeter", "centimetre", "centimeters", "centimetres"],
+        length(Length::new::<length::centimeter>(1.0)),
+    );
+    add(
+        UnitKind::Length,
+        &["mm", "millimeter", "millimetre", "millimeters", "millimetres"],
+        length(Length::new::<length::millimeter>(1.0)),
+    );
+    add(
+        UnitKind::Length,
+        &["km", "kilometer", "kilometre", "kilometers", "kilometres"],
+        length(Length::new::<length::kilometer>(1.0)),
+    );
+
+    let mass = |m: Mass| m.get::<mass::kilogram>();
+    add(UnitKind::Mass, &["lb", "lbs", "pound", "pounds"], mass(Mass::new::<mass::pound>(1.0)));
+    add(UnitKind::Mass, &["kg", "kgs", "kilogram", "kilograms"], 1.0);
+    add(UnitKind::Mass, &["g", "gram", "grams"], mass(Mass::new::<mass::gram>(1.0)));
+    add(
+        UnitKind::Mass,
+        &["t", "tonne", "tonnes", "metric ton", "metric tons"],
+        mass(Mass::new::<mass::ton>(1.0)),
+    );
+    add(UnitKind::Mass, &["short ton", "short tons", "us ton", "us tons"], mass(Mass::new::<mass::ton_short>(1.0)));
+    add(UnitKind::Mass, &["long ton", "long tons"], mass(Mass::new::<mass::ton_long>(1.0)));
+    add(UnitKind::Mass, &["kip", "kips"], mass(Mass::new::<mass::pound>(1000.0)));
+    add(UnitKind::Mass, &["st", "stone", "stones"], mass(Mass::new::<mass::pound>(14.0)));
+
+    let angle = |a: Angle| a.get::<angle::radian>();
+    add(UnitKind::Angle, &["deg", "degree", "degrees", "°", "º", "˚"], angle(Angle::new::<angle::degree>(1.0)));
+    add(UnitKind::Angle, &["rad", "rads", "radian", "radians"], 1.0);
+
+    let pressure = |p: Pressure| p.get::<pressure::pascal>();
+    add(
+        UnitKind::Pressure,
+        &[
+            "psi",
+            "lbf/in^2",
+            "lbf/in²",
+            "lbf/in2",
+            "lb/in^2",
+            "lb/in²",
+            "pound per square inch",
+            "pounds per square inch",
+        ],
+        pressure(Pressure::new::<pressure::psi>(1.0)),
+    );
+    add(UnitKind::Pressure, &["ksi"], pressure(Pressure::new::<pressure::kip_per_square_inch>(1.0)));
+    add(UnitKind::Pressure, &["pa", "pascal", "pascals", "n/m²", "n/m^2"], 1.0);
+    add(
+        UnitKind::Pressure,
+        &["kpa", "kpas", "kilopascal", "kilopascals"],
+        pressure(Pressure::new::<pressure::kilopascal>(1.0)),
+    );
+    add(
+        UnitKind::Pressure,
+        &["mpa", "megapascal", "megapascals"],
+        pressure(Pressure::new::<pressure::megapascal>(1.0)),
+    );
+    add(UnitKind::Pressure, &["bar"], pressure(Pressure::new::<pressure::bar>(1.0)));
+
+    let force = |f: Force| f.get::<force::newton>();
+    add(UnitKind::Force, &["n", "newton", "newtons"], 1.0);
+    add(UnitKind::Force, &["dan", "decanewton", "decanewtons"], force(Force::new::<force::decanewton>(1.0)));
+    add(UnitKind::Force, &["kn", "kilonewton", "kilonewtons"], force(Force::new::<force::kilonewton>(1.0)));
+    add(
+        UnitKind::Force,
+        &["lbf", "pound-force", "pounds-force", "pound force", "pounds force"],
+        force(Force::new::<force::pound_force>(1.0)),
+    );
+    add(UnitKind::Force, &["kip", "kips"], force(Force::new::<force::kip>(1.0)));
+    add(
+        UnitKind::Force,
+        &["kgf", "kilogram-force", "kilograms-force"],
+        force(Force::new::<force::kilogram_force>(1.0)),
+    );
+    add(UnitKind::Force, &["tf", "tonne-force", "tonnes-force"], force(Force::new::<force::kilogram_force>(1000.0)));
+
+    aliases
+}